@@ -11,6 +11,7 @@ const int FLAG_MOD_IS_OUTDATED = 31;
 const int FLAG_MOD_DATA_IS_OLDER_THAN_SECONDARY = 32;
 const int FLAG_MOD_DATA_IS_OLDER_THAN_CONTENT = 33;
 const int FLAG_MOD_SECONDARY_IS_OLDER_THAN_CONTENT = 34;
+const int FLAG_MOD_IS_MISFILED_MOVIE_PACK = 35;
 
 extern "C" void flags_item_delegate(QObject *parent, const int column) {
     FlagsItemDelegate* delegate = new FlagsItemDelegate(parent);
@@ -59,6 +60,11 @@ void FlagsItemDelegate::paint(QPainter *painter, const QStyleOptionViewItem &opt
         pos_x += 3;
     }
 
+    if (index.data(FLAG_MOD_IS_MISFILED_MOVIE_PACK).toBool()) {
+        paintIcon(painter, option, index, "misfiled_movie_pack.png", iconWidth, pos_x, margin);
+        pos_x += 3;
+    }
+
     painter->restore();
 }
 
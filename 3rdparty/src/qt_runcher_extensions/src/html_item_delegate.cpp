@@ -1,6 +1,7 @@
 #include "html_item_delegate.h"
 #include <QAbstractItemView>
 #include <QAbstractTextDocumentLayout>
+#include <QColor>
 #include <QPainter>
 #include <QTextDocument>
 #include <QTreeView>
@@ -22,6 +23,14 @@ void HtmlItemDelegate::paint(QPainter *painter, const QStyleOptionViewItem &opti
     // Remove indentation for category items.
     if (index.column() == 0 && index.data(40).toBool()) {
         opt.rect.adjust(-5, 0, 0, 0);
+
+        // Tint the background of categories with a custom color.
+        QColor color = QColor(index.data(41).toString());
+        if (color.isValid()) {
+            color.setAlpha(60);
+            painter->fillRect(opt.rect, color);
+        }
+
         QStyledItemDelegate::paint(painter, opt, index);
     } else {
         initStyleOption(&opt, index);
@@ -34,6 +43,12 @@ void HtmlItemDelegate::paint(QPainter *painter, const QStyleOptionViewItem &opti
         opt.text = "";
         opt.widget->style()->drawControl(QStyle::CE_ItemViewItem, &opt, painter, opt.widget);
 
+        // Mods get a stripe with the color of their category. As we read it from the parent, it follows the mod when it's moved.
+        QColor color = QColor(index.parent().data(41).toString());
+        if (index.column() == 0 && color.isValid()) {
+            painter->fillRect(QRect(opt.rect.left(), opt.rect.top(), 3, opt.rect.height()), color);
+        }
+
         QTreeView* view = dynamic_cast<QTreeView*>(parent());
         if (view != nullptr) {
             opt.rect.adjust(view->indentation(), 0, 0, 0);
@@ -1,6 +1,7 @@
 #include "kicontheme.h"
 #include "launcher_window.h"
 #include <QSettings>
+#include <QUrl>
 
 // Fuction to be able to create a custom QMainWindow.
 extern "C" QMainWindow* launcher_window(bool use_dark_theme) {
@@ -8,6 +9,8 @@ extern "C" QMainWindow* launcher_window(bool use_dark_theme) {
 }
 
 LauncherWindow::LauncherWindow(QWidget *parent, bool use_dark_theme) : QMainWindow(parent) {
+    setAcceptDrops(true);
+
     #ifdef _WIN32
 
         // Initialize the icon theme. Holy shit this took way too much research to find how it works.
@@ -63,3 +66,42 @@ void LauncherWindow::closeEvent(QCloseEvent *event) {
 
     event->accept();
 }
+
+void LauncherWindow::dragEnterEvent(QDragEnterEvent *event) {
+    if (!droppedFiles(event->mimeData()).isEmpty()) {
+        event->acceptProposedAction();
+    } else {
+        event->ignore();
+    }
+}
+
+// NOTE: This only reports the dropped files. The installation itself is done in rust.
+void LauncherWindow::dropEvent(QDropEvent *event) {
+    QStringList files = droppedFiles(event->mimeData());
+    if (files.isEmpty()) {
+        event->ignore();
+        return;
+    }
+
+    event->acceptProposedAction();
+    emit filesDropped(files);
+}
+
+// Only local packs and zips are accepted. Anything else is ignored.
+QStringList LauncherWindow::droppedFiles(const QMimeData *mimeData) {
+    QStringList files;
+    if (mimeData == nullptr || !mimeData->hasUrls()) {
+        return files;
+    }
+
+    for (const QUrl &url : mimeData->urls()) {
+        if (url.isLocalFile()) {
+            QString path = url.toLocalFile();
+            if (path.endsWith(QStringLiteral(".pack"), Qt::CaseInsensitive) || path.endsWith(QStringLiteral(".zip"), Qt::CaseInsensitive)) {
+                files.append(path);
+            }
+        }
+    }
+
+    return files;
+}
@@ -4,18 +4,31 @@
 #include <QApplication>
 #include <QCloseEvent>
 #include <QDebug>
+#include <QDragEnterEvent>
+#include <QDropEvent>
 #include <QFileInfo>
 #include <QIcon>
 #include <QResource>
 #include <QMainWindow>
+#include <QMimeData>
+#include <QStringList>
 
 extern "C" QMainWindow* launcher_window(bool use_dark_theme);
 
 class LauncherWindow: public QMainWindow {
     Q_OBJECT
+signals:
+    void filesDropped(QStringList const &);
 public:
     explicit LauncherWindow(QWidget *parent = nullptr, bool use_dark_theme = false);
     void closeEvent(QCloseEvent *event) override;
+
+protected:
+    void dragEnterEvent(QDragEnterEvent *event) override;
+    void dropEvent(QDropEvent *event) override;
+
+private:
+    QStringList droppedFiles(const QMimeData *mimeData);
 };
 
 #endif // LAUNCHERWINDOW_H
@@ -31,7 +31,7 @@ bool ModListFilter::filterAcceptsRow(int source_row, const QModelIndex &source_p
 }
 
 void ModListFilter::sort(int column, Qt::SortOrder order) {
-    if (column == 6 || column == 7 || column == 8) {
+    if (column == 6 || column == 7 || column == 8 || column == 9) {
         setSortRole(30);
         QSortFilterProxyModel::sort(column, order);
     } else {
@@ -69,7 +69,7 @@ open_in_explorer = Open in Explorer
 open_in_steam = Open in Steam Workshop
 
 language = Language
-dark_mode = Dark Mode
+theme = Theme
 reload = Reload Mod List
 check_updates_on_start = Check Updates on Start
 
@@ -129,6 +129,15 @@ mod_secondary_older_than_content = <li>
         <p/>
     </li>
 
+mod_misfiled_movie_pack = <li>
+        <p>
+            <img src="data:image/png;base64,{"{"}{"}"}"/>
+            <b>Misfiled Movie Pack</b>: This mod is marked as a Movie pack, but contains tables, scripts or texts, which usually means it was uploaded with the wrong type.
+            Movie packs are always loaded before any mod pack, ignoring the load order, so mods meant to overwrite this one may not work, and this one may overwrite mods it shouldn't.
+            You can fix this with "Treat as Mod Pack" in the context menu.
+        <p/>
+    </li>
+
 unit_multiplier = Unit Multiplier
 
 update_schema_checker = Update Schema Checker
@@ -156,10 +165,13 @@ updater_info = <p>This is the central update manager for Runcher. What each butt
         <li>
             <b>Program Updates</b>: Updates to the program itself. After updating it, you can click it again to restart into the updated program. Some notes on these updates:<ul>
                 <li>To see the changes, after updating you can <a href='file:///{"{"}{"}"}'>click here</a> or you can open the CHANGELOG.md file in Runcher's folder.</li>
-                <li>Note that there are two update channels: beta and stable. <b>You're currently on the {"{"}{"}"} channel</b>. You can change the channel in the settings.</li>
+                <li>Note that there are two update channels: beta and stable. <b>You're currently on the {"{"}{"}"} channel</b>. You can change the channel below or in the settings.</li>
                 <li>If you select "Stable" channel and you're in a beta, the latest Stable will be always shown as an available update, EVEN IF IT'S OLDER THAN YOUR BETA. This is to allow rollbacks. So if you want to use betas, make sure to select the "Beta" channel.</li>
+                <li>If you don't want an update, you can skip it. Skipped updates do not open this dialog on start.</li>
             </ul>
         </li>
+        <li><b>Releases</b>: Installs any release of the current channel, including older ones, in case an update breaks something.</li>
+        <li><b>Restore Backup</b>: The files replaced by the last update are kept as backup. This restores them, returning to the version you had before the update.</li>
         <li><b>Schema Updates</b>: These files are required for some of the launch options. If you don't have them, some of the options may not work.</li>
     </ul>
 
@@ -285,3 +297,717 @@ enable_debug_terminal = Enable Debug Terminal
 remove_trait_limit = Remove Trait Limit
 updates_locked = Updates Locked
 universal_rebalancer = Universal Rebalancer
+
+shortcuts_title = Shortcuts
+shortcut_launch_game = Launch Game
+shortcut_reload = Reload Mod List
+shortcut_next_game = Select Next Game
+shortcut_previous_game = Select Previous Game
+shortcut_toggle_selected_mods = Toggle Selected Mods
+shortcut_focus_filter = Focus Filter
+shortcut_move_pack_up = Move Selected Packs Up
+shortcut_move_pack_down = Move Selected Packs Down
+
+show_vanilla_packs_tooltip = Show Vanilla Packs
+
+    If this is enabled, the vanilla and DLC packs of the game are also shown (greyed out) in the pack list, so you can see where your mods end up in the full list of packs the game loads.
+
+backup_selected_mods = Backup Selected Mods
+restore_mods_backup = Restore Mods Backup
+backup_mods_select_folder = Select Backup Folder
+restore_mods_backup_select_file = Select Backup File
+backup_mods_success = Backup created at: {"{"}{"}"}
+restore_mods_backup_success = <p>The following mods have been restored to the secondary folder:</p>
+    <ul>
+    {"{"}{"}"}
+    </ul>
+
+open_game_in_new_window = Open in New Window
+game_loaded_in_this_window = This game is already loaded in this window.
+game_loaded_in_other_window = This game is already loaded in another Runcher window (process id {"{"}{"}"}). Switch that window to another game, or close it, first. Otherwise both windows would overwrite each other's configs.
+
+lint_title = Problematic Packs Detected
+lint_description = <p>The following enabled packs usually cause problems if left enabled:</p>
+    <ul>
+    {"{"}{"}"}
+    </ul>
+    <p>Do you want to disable them before launching the game?</p>
+lint_disable_and_launch = Disable and Launch
+lint_launch_anyway = Launch Anyway
+
+pack_move_to_top = Move to Top
+pack_move_to_bottom = Move to Bottom
+pack_move_to_position = Move to Position...
+pack_move_to_position_label = Position in the load order:
+pack_import_order = Import Order From Text...
+pack_import_order_info = Paste a list of pack names, one per line, in the order you want them to load. Names don't need to be exact, and lines in the "mod "name.pack";" format are also supported. Packs not in the list are moved after the listed ones.
+pack_import_order_unmatched = <p>The following lines didn't match any enabled pack:</p>
+    <ul>
+    {"{"}{"}"}
+    </ul>
+
+known_issue = Known Issue
+log_analysis_report_break = Report Selected Break
+log_analysis_report_break_no_signature = This break doesn't contain an error line Runcher can identify, so it cannot be reported.
+
+settings_sync_folder = Sync Folder
+settings_sync_folder_ph = Folder synced between computers (Dropbox, OneDrive, Syncthing,...). Game configs and profiles are stored here if set.
+settings_generated_files_folder = Generated Files Folder
+settings_generated_files_folder_ph = Folder where the packs Runcher generates (reserved pack, merged pack, masks) are written. If empty, they're written to the config folder, and masks to the secondary folder.
+sync_conflict_title = Config Changed Outside Runcher
+sync_conflict_description = <p>The mod list or load order of {"{"}{"}"} has been changed outside Runcher, probably by another computer through the sync folder.</p>
+    <p>Do you want to reload them, or keep the current ones, overwriting the changes?</p>
+sync_conflict_reload = Reload
+sync_conflict_keep_current = Keep Current
+
+launch_elevated = Run as Admin
+launch_elevated_tt = Launch this game as administrator. Some older games need it to load mods.
+compatibility_mode_tt = Windows compatibility mode used to launch this game, so you don't need to edit the properties of the exe.
+
+force_redownload = Force Re-download
+force_redownload_are_you_sure = This will unsubscribe from the selected mods, delete their files, then resubscribe and download them again. Are you sure?
+mods_redownloaded = Mods re-downloaded and verified.
+force_redownload_failed = <p>The following mods failed to re-download or don't match their workshop data:</p>
+    <ul>
+    {"{"}{"}"}
+    </ul>
+    <p>Try again later, or check Steam's download queue.</p>
+pack_group_lock = Lock Order as Group...
+pack_group_unlock = Unlock Group
+pack_group_name = Name of the group (leave empty to use the name of the last pack). Packs in a group always load together, in their current order.
+pack_in_group = Locked in group: {"{"}{"}"}
+requirements_title = Missing Requirements Detected
+requirements_description = <p>The following enabled mods need things that are not enabled or not installed:</p>
+    <ul>
+    {"{"}{"}"}
+    </ul>
+    <p>These mods will probably not work, or crash the game. What do you want to do?</p>
+requirements_enable_and_launch = Enable Parents and Launch
+requirement_disabled_parent = needs the pack "{"{"}{"}"}", which is disabled.
+requirement_missing_parent = needs the pack "{"{"}{"}"}", which is not installed.
+requirement_missing_dlc = needs the DLC "{"{"}{"}"}", which is not installed.
+requirement_link = (Link)
+theme_system = Follow System
+theme_light = Light
+theme_dark = Dark
+theme_tt = <p>Theme used by Runcher. Changes are previewed live.</p><p>You can add your own QSS themes by putting them in this folder: <i>{"{"}{"}"}</i>. They can use <b>{"{"}accent_color{"}"}</b> and <b>{"{"}assets_path{"}"}</b> as placeholders.</p>
+accent_color_tt = Accent Color
+treat_as_mod_pack = Treat as Mod Pack
+treat_as_mod_pack_failed = <p>The following mods couldn't be changed:</p><ul>{"{"}{"}"}</ul><p>Movie packs in /data are always loaded by the game, so move them out of /data before treating them as mod packs. Treating movie packs as mod packs also requires a Secondary Mods Path.</p>
+add_from_github = Add Mod from GitHub
+add_from_github_label = GitHub repo or release asset url (for example, https://github.com/owner/repo):
+add_from_github_not_found = {"{"}{"}"} was downloaded, but it was not found in the mod list after the reload. Check that the folder it was downloaded to is one the game loads mods from.
+update_from_github = Update from GitHub
+mods_updated_from_github = Mods updated from GitHub.
+update_from_github_failed = <p>The following mods failed to update:</p><ul>{"{"}{"}"}</ul>
+logs_title = Logs
+log_level = Log Level
+log_level_default = Default
+open_runcher_log_viewer = View Runcher's Logs
+log_viewer_title = Runcher's Logs
+log_viewer_filter = Filter...
+copy_diagnostic_bundle = Copy Diagnostic Bundle
+copy_diagnostic_bundle_tt = Generates a zip with Runcher's logs and configs, and the latest script logs of the game (without your Steam API Key, and with your user folder hidden), and copies it to the clipboard so you can attach it to bug reports.
+copy_diagnostic_bundle_success = Diagnostic bundle copied to the clipboard. It's also saved here: {"{"}{"}"}
+updater_skip_version = Skip this Version
+updater_releases = Releases:
+updater_release_current = {"{"}{"}"} (current)
+updater_install_release = Install
+updater_restore_backup = Restore Backup:
+updater_restore_backup_available = Restore {"{"}{"}"}
+updater_restore_backup_none = No backup available.
+updater_restore_backup_restored = Backup restored! Click here to restart Runcher.
+mod_list_changed_title = Mod List Changed
+mod_list_changed_description = <p>The mod list file <i>{"{"}{"}"}</i> of {"{"}{"}"} has been changed by another launcher or mod manager, and it no longer matches Runcher's load order.</p><p>Do you want to import its load order, or overwrite it with Runcher's one?</p>
+mod_list_changed_import = Import its Load Order
+mod_list_changed_overwrite = Overwrite it
+mod_list_changed_ignore = Ignore
+mod_list_grouping = Group Mods By
+mod_list_grouping_categories = Category
+mod_list_grouping_author = Workshop Author
+mod_list_grouping_source = Source
+mod_list_grouping_update_month = Last Update Month
+mod_list_grouping_known_issues = Known Issues
+mod_list_group_unknown_author = Unknown Author
+mod_list_group_unknown_date = Unknown Date
+mod_list_group_known_issues = With Known Issues
+mod_list_group_no_known_issues = Without Known Issues
+mod_list_group_workshop = Workshop
+mod_list_group_secondary = Secondary
+mod_list_group_local = Local
+profile_export = Export Profile
+profile_export_read_only = Do you want to export this profile as read-only? Read-only profiles cannot be overwritten once imported, which is useful to share a setup with your multiplayer group.
+profile_export_as_read_only = Export as Read-Only
+profile_export_as_editable = Export as Editable
+profile_export_select_file = Select where to export the profile
+profile_export_success = Profile exported. Its signature fingerprint is <b>{"{"}{"}"}</b>. Share it with your group, so everyone can check they're using the same unmodified profile.
+profile_import = Import Profile
+profile_import_select_file = Select the profile to import
+profile_import_name_in_use = There's already a profile called {"{"}{"}"}. Rename or delete it before importing this one.
+profile_import_unsigned = None (the profile is not signed)
+profile_import_success = Profile {"{"}{"}"} imported. Its signature fingerprint is <b>{"{"}{"}"}</b>.
+profile_read_only_error = The profile {"{"}{"}"} is read-only and cannot be overwritten. Save it with another name instead.
+pack_size = Size
+pack_move_not_sorted_by_load_order = Packs can only be moved by dragging them while the list is sorted by load order. Click the Load Order column's header to sort it again.
+log_analysis_report_author = Report to the Mod's Author
+log_analysis_report_author_tt = Copies a report of the selected error (with the error, your game version and load order) to the clipboard, and opens the mod's comments in the Workshop so you can paste it there.
+log_analysis_report_author_copied = The report has been copied to the clipboard. This mod is not from the Workshop, so you'll have to send it to its author manually.
+log_analysis_report_author_copied_workshop = The report has been copied to the clipboard. Paste it in the mod's comments, which have just been opened in your browser.
+show_generated_packs = Show Generated Packs:
+show_generated_packs_tt = Show in the Mod List packs generated from other packs, like RPFM's reserved packs, Kaedrin Mod Manager's merged packs, or packs left behind by older versions of Runcher. These are hidden by default because loading them alongside the packs they were generated from loads the same content twice.
+launch_progress_title = Launching Game
+launch_progress_cancel = Cancel
+launch_progress_preparing = Preparing the launch…
+launch_progress_merging = Merging the enabled mods into a single pack…
+launch_progress_load_order = Building the load order…
+launch_progress_masks = Masking the disabled movie packs…
+launch_progress_waiting = Waiting for the game to close to check its logs. Cancel to skip the log check.
+launch_progress_logs = Checking the game's logs for script errors…
+profile_from_save = Save Profile from Save
+profile_from_save_default_name = from save - {"{"}{"}"}
+profile_name_invalid = {"{"}{"}"} is not a valid profile name. Profile names can only contain letters, numbers, spaces, dashes, underscores and dots, and cannot start with a dot.
+profile_from_save_no_save = Select a save in the save list first.
+profile_from_save_overwrite = There's already a profile with this name. Are you sure you want to overwrite it?
+profile_from_save_success = Profile {"{"}{"}"} created with the mods of the selected save.
+profile_from_save_missing_mods = Profile {"{"}{"}"} created, but the following mods of the save are no longer installed, so they're not in it:<br/><br/>{"{"}{"}"}
+launch_cancelled = Launch cancelled.
+apply_conflict_suggestions = Sort conflicting packs in automatic mode
+apply_conflict_suggestions_tt = If enabled, automatic load orders put packs that overwrite files of other packs above them when they're more specific (patches, submods,...), instead of only sorting them alphabetically.
+pack_conflict_suggestions = Suggest Order from Conflicts
+pack_conflict_suggestions_none = No changes to suggest. In all conflicts between enabled packs, the more specific pack already wins.
+pack_conflict_suggestions_description = <p>Some enabled packs overwrite the same files, and the more specific pack is not winning. These are the suggested changes:</p><ul>{"{"}{"}"}</ul><p>Do you want to apply them?</p>
+pack_conflict_suggestions_automatic = <p>Some enabled packs overwrite the same files, and the more specific pack is not winning. These are the suggested changes:</p><ul>{"{"}{"}"}</ul><p>The load order is in automatic mode. Enable "Sort conflicting packs in automatic mode" in the settings to apply them automatically, or switch to manual mode to apply them once.</p>
+pack_conflict_suggestions_apply = Apply Suggestions
+pack_conflict_suggestion = Move <b>{"{"}{"}"}</b> above <b>{"{"}{"}"}</b>: {"{"}{"}"}
+pack_conflict_reason_dependency = {"{"}{"}"} depends on {"{"}{"}"}, so it's probably a patch or submod of it.
+pack_conflict_reason_overlap = {"{"}{"}"} of the {"{"}{"}"} files of {"{"}{"}"} are also in {"{"}{"}"}, so it's probably a patch of it.
+search_workshop = Search the Workshop by Tags
+workshop_search_title = Search the Workshop
+workshop_search_tags = Tags (comma-separated):
+workshop_search_page = Page:{" "}
+workshop_search_search = Search
+workshop_search_category = Add to Category:
+workshop_search_column_name = Name
+workshop_search_column_tags = Tags
+workshop_search_column_file_name = File Name
+workshop_search_no_results = No mods found in the Workshop with those tags.
+workshop_search_subscribed = Subscribed to {"{"}{"}"} mods. The ones downloaded successfully have been added to the chosen category.
+shortcut_undo = Undo Last Operation
+undo_last_operation = Undo Last Operation: {"{"}{"}"}
+undo_last_operation_none = Undo Last Operation (Nothing to Undo)
+undo_last_operation_are_you_sure = This will restore the mod list and load order to how they were before the last operation, discarding any change done since then. Are you sure?
+undo_operation_import_load_order = Import Load Order
+undo_operation_load_profile = Load Profile
+undo_operation_delete_category = Delete Category
+undo_operation_conflict_suggestions = Apply Conflict Suggestions
+export_to_ca_launcher = Export Load Order to CA's Launcher
+export_to_ca_launcher_success = Load order exported to CA's launcher.
+export_to_ca_launcher_missing = <p>Load order exported to CA's launcher, but the launcher doesn't know about these mods yet, so they were not exported:</p><ul>{"{"}{"}"}</ul><p>Open the official launcher once so it detects them, then export again.</p>
+import_from_ca_launcher = Import Load Order from CA's Launcher
+check_script_collisions = Check for script collisions before launching
+check_script_collisions_tt = If enabled, the scripts of the enabled mods are checked before launching the game for collisions that make the game silently skip some of them.
+script_collisions_title = Script Collisions
+script_collisions_description = <p>Some of the enabled mods have scripts that collide with each other. When this happens, the game silently loads only one of them, which usually breaks the other mods:</p><ul>{"{"}{"}"}</ul><p>If these mods are not meant to replace each other's scripts, disable one of them or ask their authors to rename their scripts.</p>
+script_collision_path = <b>{"{"}{"}"}</b> is in: {"{"}{"}"}. Only the first one is loaded.
+script_collision_module = Scripts named <b>{"{"}{"}"}</b> are in: {"{"}{"}"}. Only the first one loaded by the game is used.
+category_style = Color and Icon
+category_style_color = Color:
+category_style_color_placeholder = Color of the category, like #3daee9. Leave empty for no color.
+category_style_color_pick = Pick a color.
+category_style_icon = Icon:
+category_style_icon_placeholder = Name of an icon from the icon theme, like folder-red. Leave empty for no icon.
+mod_version = Version
+mod_version_description = Dates of the copies of this mod on disk. The first one is the one the game loads:
+mod_version_copy = <li>{"{"}{"}"}: {"{"}{"}"}</li>
+mod_version_workshop = <li>Latest Workshop update: {"{"}{"}"}</li>
+mod_version_workshop_stale = <li><b>Offline: the Workshop data may be outdated.</b></li>
+workshop_data_stale = Runcher is offline, so this is the Workshop data from the last time it was downloaded. It may be outdated.
+mod_version_secondary_stale = <li><b>The Secondary copy was made before the latest Workshop update and was never refreshed.</b> Use "Refresh copy from Content" to update it.</li>
+refresh_secondary_copy = Refresh copy from Content
+refresh_secondary_copy_failed = <p>The following mods failed to be refreshed:</p>
+    <ul>
+    {"{"}{"}"}
+    </ul>
+    <p>This can happen either because of an IO error, or because the mod doesn't have a copy both in /secondary and in /content.</p>
+reveal_in_mod_list = Reveal in Mod List
+reveal_in_pack_list = Reveal in Pack List
+reveal_mod_not_found = The mod {"{"}{"}"} is not in the Mod List, or it's hidden by the active filters.
+reveal_pack_not_loaded = The pack of the mod {"{"}{"}"} is not in the Pack List. Only enabled mods are in it.
+reveal_pack_not_from_mod = The pack {"{"}{"}"} is not from a mod.
+open_runcher_locale_folder = Open Runcher Locale Folder
+language_completeness = {"{"}{"}"}% translated. Untranslated texts are shown in English.
+check_shadowed_packs = Check for shadowed packs before launching:
+check_shadowed_packs_tt = If enabled, before launching the game Runcher will check if any enabled mod has different copies in /data, the secondary mods folder and /content. Only the first of them is loaded, and the others are usually old copies that make updates look like they're not applying. As every copy needs to be hashed, this can slow down launching with many mods.
+shadowed_packs_title = Shadowed Packs
+shadowed_packs_description = <p>Some mods have different copies in more than one folder. Only the first copy is loaded, and the other ones are ignored:</p><ul>{"{"}{"}"}</ul><p>You can delete the ignored copies, or rename them so they're ignored but can be recovered later. Copies downloaded from the Workshop are left alone, as Steam downloads them again: unsubscribe from the mod to get rid of them.</p>
+shadowed_pack = <b>{"{"}{"}"}</b><br/>Loaded: {"{"}{"}"}<br/>Ignored: {"{"}{"}"}
+shadowed_pack_copy = {"{"}{"}"} (hash {"{"}{"}"}, modified {"{"}{"}"})
+shadowed_pack_copy_workshop = {"{"}{"}"} (hash {"{"}{"}"}, modified {"{"}{"}"}, from the Workshop)
+shadowed_packs_delete = Delete Ignored Copies
+shadowed_packs_rename = Rename Ignored Copies
+shadowed_packs_none = No shadowed packs found.
+shadowed_packs_failed = <p>The ignored copies of the following mods couldn't be removed:</p><ul>{"{"}{"}"}</ul>
+find_shadowed_packs = Find Shadowed Packs
+launch_progress_pre_launch_hooks = Running the pre-launch hooks…
+launch_progress_post_exit_hooks = Running the post-exit hooks…
+hooks_title = Launch Hooks
+hooks_title_tt = Commands to run before launching a game or after it's closed. Right-click the table to add or remove hooks.
+hooks_column_name = Name
+hooks_column_stage = Run
+hooks_column_command = Command
+hooks_column_command_tt = Command to run. It's run through the system's shell, and the following placeholders are replaced before running it: {"{"}game_key{"}"}, {"{"}game_path{"}"}, {"{"}data_path{"}"}, {"{"}profile{"}"} and {"{"}config_path{"}"}. They're replaced already quoted, so don't put them between quotes. Their values are also available as the environment variables RUNCHER_GAME_KEY, RUNCHER_GAME_PATH, RUNCHER_DATA_PATH, RUNCHER_PROFILE and RUNCHER_CONFIG_PATH.
+hooks_column_games = Games
+hooks_column_profiles = Profiles (Empty For All)
+hooks_column_timeout = Timeout (s)
+hooks_column_timeout_tt = Seconds to wait for the command to finish before killing it. Use 0 for commands that keep running with the game, like injectors.
+hooks_column_failure_policy = On Failure
+hooks_stage_pre_launch = Before Launch
+hooks_stage_post_exit = After Exit
+hooks_failure_policy_ignore = Ignore
+hooks_failure_policy_warn = Warn
+hooks_failure_policy_abort = Abort
+hooks_add = Add
+hooks_remove = Remove
+hooks_failed = <p>The following hooks failed:</p><ul>{"{"}{"}"}</ul>
+hooks_aborted = A hook set to abort on failure failed, so the rest of the launch has been stopped: {"{"}{"}"}
+import_string_names_mode = List of Mod Names
+import_string_names_mode_tt = A list of mod names, one per line, like the ones in videos and forum posts. The names are matched against your installed mods, and you can fix the matches before importing them.
+name_matches_title = Check Mod Matches
+name_matches_info = These are the installed mods that look the most like each name in the list. Names with a warning icon have no clear match, so check them before importing. Mods set to skip will not be imported.
+name_matches_column_name = Name In The List
+name_matches_column_mod = Installed Mod
+name_matches_column_workshop = Workshop
+name_matches_skip = (Skip)
+name_matches_ambiguous = This name has no clear match. Check the selected mod before importing it.
+name_matches_search_workshop = Search in the Workshop
+name_matches_empty = The list doesn't contain any mod names.
+game_updated_title = Game Updated
+game_updated_description = <p>The game has been updated since the last launch (build {"{"}{"}"} to build {"{"}{"}"}).</p><p>Game updates usually break script mods, and the following enabled mods have scripts and haven't been updated since the game was, so they may be incompatible with this version:</p><ul>{"{"}{"}"}</ul><p>This is just a guess based on dates: some of them may work fine, and mods without scripts can break too. If the game crashes or misbehaves, check these first.</p>
+game_updated_disable_and_launch = Disable Them and Launch
+
+load_order_slot_name = Load Order Slot
+load_order_slot_tooltip = Named load orders for quickly switching between sets of mods. Unlike profiles, they only change which mods are enabled and their order, keeping categories and launch options.
+load_order_slot_load = Load this slot
+load_order_slot_save = Save the current load order to this slot
+load_order_slot_delete = Delete this slot
+load_order_slot_not_found = No load order slot named {"{"}{"}"} found for the game selected.
+load_order_slot_name_empty = Load order slot name is empty.
+undo_operation_load_load_order_slot = Load Load Order Slot
+
+rebuild_masks = Rebuild Masks
+rebuild_masks_removed = {"{"}{"}"}: mask removed, as {"{"}{"}"}.
+rebuild_masks_added = {"{"}{"}"}: mask added, as it was missing.
+rebuild_masks_nothing_fixed = <p>The masks folder was already correct.</p>
+rebuild_masks_nothing_masked = <p>No packs are masked.</p>
+rebuild_masks_report = <p>The masks folder has been rebuilt. Fixed:</p>{"{"}{"}"}<p>Masked packs:</p>{"{"}{"}"}
+rebuild_masks_failed = <p>The masks folder couldn't be rebuilt correctly. Fixed:</p><ul>{"{"}{"}"}</ul><p>Currently masked:</p><ul>{"{"}{"}"}</ul>
+mask_reason_disabled_movie = it's a disabled movie pack in the secondary folder
+mask_reason_enabled = the pack is enabled, so the mask was hiding it from the game
+mask_reason_orphaned = the pack is no longer a movie pack in the secondary folder
+
+open_table_in_rpfm = Open Table in RPFM
+open_table_no_table = The selected file is not a DB table.
+open_table_pack_not_found = Pack {"{"}{"}"} not found on disk.
+rpfm_not_found = RPFM not found. Add it to the tools in the settings to use this feature.
+launch_countdown = Countdown Before Launching:
+launch_countdown_tt = If enabled, Runcher waits a few seconds before launching the game, so you can cancel the launch if you clicked it by mistake.
+post_launch_behavior = After Launching:
+post_launch_behavior_tt = What Runcher should do after launching a game. If Runcher needs to wait for the game to close (to check the logs or run post-exit hooks), closing it minimizes it to the tray instead.
+post_launch_keep_open = Keep Runcher Open
+post_launch_minimize_to_tray = Minimize to Tray
+post_launch_close = Close Runcher
+launch_countdown_title = Launching Game
+launch_countdown_label = Launching the game in {"{"}{"}"} seconds...
+tray_restore = Show Runcher
+tray_quit = Quit
+tray_mode = Keep Runcher in the Tray:
+tray_mode_tt = If enabled, Runcher keeps running in the system tray when its window is closed. From there it can launch your games, and it notifies you of new Workshop subscriptions and mod updates.
+tray_launch = Launch
+tray_launch_tt = Launch this game with the last profile you loaded for it. If you never loaded one, its current load order is used.
+tray_launch_busy = Runcher is busy with another operation, like a launch. Try again once it finishes.
+workshop_monitor_title = Workshop Changes
+workshop_monitor_changes = {"{"}{"}"}: {"{"}{"}"} new subscriptions, {"{"}{"}"} updated mods.
+export_map_bin = Export Map to Bin
+export_map_bin_select_file = Select where to save the map bin
+export_map_bin_select_map = The pack contains more than one map. Select the one to export:
+export_map_bin_no_maps = The pack of the mod {"{"}{"}"} doesn't contain any battle map.
+export_map_bin_pack_not_found = The pack of the mod {"{"}{"}"} was not found.
+export_map_bin_success = Map exported to {"{"}{"}"}. You can now upload it with the legacy Workshop uploader.
+export_mod_report = Export Mod Report
+export_mod_report_select_file = Select where to save the mod report
+export_mod_report_success = Mod report exported to {"{"}{"}"}.
+launch_option_enabled = Enabled
+mod_version_workshop_state = <li>Steam state: {"{"}{"}"}</li>
+mod_version_updates_deferred = <li><b>Workshop updates deferred.</b> The game loads the frozen copy in the Secondary folder until updates are resumed.</li>
+workshop_state_unknown = Unknown
+workshop_state_up_to_date = Up-to-date
+workshop_state_update_queued = Update queued
+workshop_state_downloading = Downloading
+workshop_state_download_pending = Download pending
+workshop_state_not_installed = Not installed
+update_workshop_items_now = Update Now from Workshop
+workshop_items_updated = The selected mods have been updated from the Workshop.
+defer_workshop_updates = Defer Workshop Updates
+defer_workshop_updates_failed = The following mods couldn't have their updates deferred or resumed. Only Workshop mods in Content or in Content and Secondary can be deferred: <ul>{"{"}{"}"}</ul>
+category_rules = Category Rules
+category_rules_title = Category Rules
+category_rules_info = Mods not yet assigned to a category are sent to the category of the first rule they match. Patterns are case-insensitive regexes, matched against the pack name, any of the Workshop tags, or the author's name or id. Categories that don't exist are created.
+category_rules_add = Add Rule
+category_rules_remove = Remove Rules
+category_rules_preview = Preview
+category_rules_preview_info = These {"{"}{"}"} mods would be moved with the current rules:
+category_rules_column_category = Category
+category_rules_column_field = Match On
+category_rules_column_pattern = Pattern
+category_rules_column_mod = Mod
+category_rules_category_tt = Existing categories: {"{"}{"}"}
+category_rule_field_pack_name = Pack Name
+category_rule_field_tag = Workshop Tag
+category_rule_field_author = Author
+fingerprint_not_computed = Multiplayer Fingerprint: click to compute
+fingerprint_code = Multiplayer Fingerprint: {"{"}{"}"}
+fingerprint_tt = <p>Short code made from the loaded packs in load order, their contents, the game build and the launch options.</p><p>All players in a multiplayer game should have the same code. Click to compute it and copy it to the clipboard.</p>
+copy_fingerprint_manifest = Copy Fingerprint Manifest
+copy_fingerprint_manifest_success = Fingerprint manifest copied to the clipboard. Share it with the other players to compare your setups.
+compare_fingerprint_manifests = Compare Fingerprint Manifests
+fingerprint_compare_info = Paste the fingerprint manifest of other player to find out why your fingerprints don't match.
+fingerprint_compare_first = First Manifest (yours)
+fingerprint_compare_second = Second Manifest
+fingerprint_compare_compare = Compare
+fingerprint_compare_equal = <p>Both manifests are identical (<b>{"{"}{"}"}</b>).</p>
+fingerprint_compare_different = <p>The fingerprints <b>{"{"}{"}"}</b> and <b>{"{"}{"}"}</b> don't match because:</p><ul>{"{"}{"}"}</ul>
+fingerprint_compare_invalid = <p>One of the manifests is not valid: {"{"}{"}"}</p>
+fingerprint_difference_game = The manifests are for different games: {"{"}{"}"} and {"{"}{"}"}.
+fingerprint_difference_build = The game builds are different: {"{"}{"}"} and {"{"}{"}"}.
+fingerprint_difference_only_first = <b>{"{"}{"}"}</b> is only loaded in the first setup.
+fingerprint_difference_only_second = <b>{"{"}{"}"}</b> is only loaded in the second setup.
+fingerprint_difference_hash = <b>{"{"}{"}"}</b> has different contents. One of you has a different version of it.
+fingerprint_difference_position = <b>{"{"}{"}"}</b> is in a different position: {"{"}{"}"} in the first setup, {"{"}{"}"} in the second.
+fingerprint_difference_launch_option = The launch option "{"{"}{"}"}" is different: "{"{"}{"}"}" and "{"{"}{"}"}".
+
+show_ignored_mods = Show Ignored Mods
+ignore_mods = Ignore
+mod_ignored = Ignored
+
+config_recovered = <p>The config file <b>{"{"}{"}"}</b> failed to load, and has been rebuilt from the parts of it that could still be read.</p>
+    <p>It's broken in <b>{"{"}{"}"}</b> (line {"{"}{"}"}, column {"{"}{"}"}): <i>{"{"}{"}"}</i></p>
+    <p>The broken file has been backed up to <b>{"{"}{"}"}</b>, in case you want to fix it by hand.</p>
+config_recovered_no_field = the root of the file
+config_recovered_newer_version = <p>The file was saved by a newer version of Runcher, which may be why it cannot be read. Update Runcher before using it again.</p>
+config_recovered_unreadable = <p>The file is not valid JSON, so nothing could be recovered from it.</p>
+config_recovered_lost = <p>These parts of the file could not be recovered:</p><ul>{"{"}{"}"}</ul>
+
+launch_mode_tt = <p>How to launch the game:</p>
+    <ul>
+        <li><b>Direct</b>: Runcher starts the game itself. This is the default.</li>
+        <li><b>Steam API</b>: Runcher starts the game's exe from the process that talks with Steam. Use it if the Steam overlay, playtime or controller config don't work with the direct launch.</li>
+        <li><b>Steam</b>: the Steam client launches the game, like from the library. Runcher cannot know when the game is closed with this mode, so the log analysis and post-exit hooks are skipped. Some games open their own launcher when launched this way.</li>
+    </ul>
+    <p>Steam cannot launch games elevated, so if the game is set to launch elevated it's launched directly, whatever mode is selected here. Same if packs from a secondary folder on another drive need to be linked or copied into /data, so they can be removed when the game is closed. The compatibility mode works with all modes.</p>
+launch_mode_direct = Direct
+launch_mode_steam_api = Steam API
+launch_mode_steam = Steam
+profile_lock = Lock the selected profile and load it. While a locked profile is loaded, its mods and load order cannot be changed until it's unlocked, so a campaign cannot be broken by an accidental change.
+profile_unlock = The profile {"{"}{"}"} is locked. Click to unlock it.
+profile_lock_no_profile = Select an existing profile to lock it.
+profile_locked_error = The profile {"{"}{"}"} is locked. Unlock it before changing your mods or your load order.
+profile_unlock_are_you_sure = Are you sure you want to unlock the profile? Changing the mods or the load order of an ongoing campaign may break its saves.
+check_other_managers = Check for other mod managers
+check_other_managers_tt = If enabled, when loading a game and before launching it, Runcher checks if CA's launcher, Vortex or another Runcher are working on the same game, as they'd change its mods behind your back.
+manager_conflicts_title = Other mod managers detected
+manager_conflicts_description = <p>Other mod managers are working on this game. Every mod manager rewrites the list of mods the game loads, so while more than one is in use, the last one to write it wins and your mods may get enabled or disabled seemingly at random:</p>
+    <ul>{"{"}{"}"}</ul>
+manager_conflict_ca_launcher = <b>CA's launcher is running</b>: close it before launching the game from Runcher, and don't launch the game from it while using Runcher, or it'll overwrite your load order with its own.
+manager_conflict_vortex = <b>Vortex is running</b>: close it, or at least don't deploy mods with it while using Runcher.
+manager_conflict_vortex_deployment = <b>Vortex has deployed mods to the data folder</b> (found its deployment file at <i>{"{"}{"}"}</i>): purge its mods from Vortex before using Runcher, or Vortex will restore its own setup on its next deployment.
+manager_conflict_runcher = <b>Another Runcher has this game loaded</b> (process id {"{"}{"}"}): close it, or switch it to another game, so only one of them changes the mods of this game.
+skip_intro_videos_ph = Extra intro videos to skip
+skip_intro_videos_tt = <p>Paths of extra videos to skip when "Skip Intro Videos" is enabled, separated by ";". For example: <i>movies/startup_movie_06.ca_vp8</i>.</p>
+    <p>New numbered startup movies added by DLCs are found in the video tables of the game and skipped automatically. This is only needed for the ones that are not.</p>
+
+data_view_delta = Highlight the files that change packs when the load order changes. Files are compared against the last time the Data View was loaded.
+data_view_delta_previous_owner = Previously loaded from: {"{"}{"}"}
+data_list_pack_error = {"{"}{"}"} could not be loaded: {"{"}{"}"}
+data_list_pack_errors_tt = These packs could not be loaded, so their files are not in the Data View. They are usually corrupted or incomplete downloads: re-download or remove them.
+
+set_working_folder = Set Working Folder…
+set_working_folder_select_folder = Select the folder to load instead of the pack
+use_working_folder = Load Working Folder instead of Pack
+mod_version_working_folder = <li><b>Loading the working folder.</b> The game loads the files in <i>{"{"}{"}"}</i> instead of the pack.</li>
+
+enable_sentry = Send error reports
+enable_sentry_tt = If enabled, crashes and errors are reported to Runcher's developer through Sentry, so they can be fixed. Disabling it stops sending reports right away and deletes the error reports stored on disk. Enabling it requires restarting Runcher.
+sentry_consent_title = Error reporting
+sentry_consent_description = <p>Runcher can send reports of crashes and errors to its developer through Sentry, so they can be fixed.</p><p>Reports contain the error, the version of Runcher and your operating system. They never contain your mods, saves or settings.</p><p>Do you want to send error reports? You can change this at any time in the settings.</p>
+sentry_consent_enable = Send Error Reports
+sentry_consent_disable = Don't Send
+about_error_reporting_enabled = Error reporting through Sentry is <b>enabled</b>. You can disable it in the settings.
+about_error_reporting_disabled = Error reporting through Sentry is <b>disabled</b>. You can enable it in the settings.
+
+config_path_override_ph = Config folder (default)
+config_path_override_tt = <p>Folder where the game keeps its config, scripts and saves, if it's not the default one. For example, if your roaming folder is redirected to OneDrive or symlinked somewhere else. Leave it empty to use the default one.</p>
+
+hash_verification_title = Pack Verification
+hash_verification_progress = Verifying the packs of the imported load order…
+hash_verification_info = Some of the packs of the imported load order are missing, or are not the ones it was shared with. Ask the person who shared it which version of these mods they're using, or get them again from the Workshop.
+hash_verification_column_mod = Mod
+hash_verification_column_result = Result
+hash_verification_column_workshop = Workshop
+hash_verification_ok = Ok
+hash_verification_mismatch = Different pack
+hash_verification_missing = Missing
+hash_verification_error = Cannot be read: {"{"}{"}"}
+hash_verification_open_workshop = Open in the Workshop
+
+pack_export_launch_manifest = Export Launch Manifest…
+export_launch_manifest_select_file = Export the launch manifest to…
+export_launch_manifest_success = Launch manifest exported to {"{"}{"}"}. It contains everything the game was told to load in the last launch, in order and with full paths, so attach it to your bug reports.
+export_launch_manifest_missing = There's no launch manifest for this game yet. Launch the game from Runcher at least once to generate it.
+recently_added_days = Days mods show as recently added
+recently_added_days_tt = Mods that appeared in the mod list in this amount of days are shown in a "Recently added" category at the top of the mod list. Set it to 0 to hide that category.
+mod_list_recently_added = Recently added
+mod_version_first_seen = <li>First seen: {"{"}{"}"}</li>
+mod_version_last_enabled = <li>Last enabled: {"{"}{"}"}</li>
+freeze_campaign_integrity = Freeze Campaign Integrity
+freeze_campaign_integrity_success = Campaign integrity frozen: the hashes of {"{"}{"}"} enabled packs have been saved. Use "Verify Campaign Integrity" later to find out if any of them changed mid-campaign.
+verify_campaign_integrity = Verify Campaign Integrity
+verify_campaign_integrity_not_frozen = The campaign integrity of this game has not been frozen yet. Use "Freeze Campaign Integrity" when starting a campaign.
+verify_campaign_integrity_ok = <p>None of the packs changed since the campaign integrity was frozen ({"{"}{"}"}).</p>
+verify_campaign_integrity_changed = <p>The following changes happened since the campaign integrity was frozen ({"{"}{"}"}). They may explain desyncs or broken saves:</p><ul>{"{"}{"}"}</ul>
+campaign_integrity_difference_build = The game has been updated: {"{"}{"}"} to {"{"}{"}"}.
+campaign_integrity_difference_removed = <b>{"{"}{"}"}</b> is no longer enabled.
+campaign_integrity_difference_added = <b>{"{"}{"}"}</b> has been enabled.
+campaign_integrity_difference_hash = <b>{"{"}{"}"}</b> has been updated or replaced.
+campaign_integrity_difference_position = <b>{"{"}{"}"}</b> has been moved in the load order: from {"{"}{"}"} to {"{"}{"}"}.
+launch_progress_linking_secondary = The game cannot load the Secondary folder from a different drive. Linking its enabled packs into /data until the game is closed…
+launch_progress_copying_secondary = The game cannot load the Secondary folder from a different drive, and linking is not allowed. Copying its enabled packs into /data until the game is closed…
+mod_last_launched = Last Launched
+show_last_launched_column = Show Last Launched Column
+open_sessions = Launch Sessions
+sessions_title = Launch Sessions
+sessions_info = Past launches of the game, newest first. Select one to see the load order it was launched with, and apply it to re-enable the same mods in the same order.
+sessions_column_date = Date
+sessions_column_profile = Profile
+sessions_column_packs = Packs
+sessions_apply = Apply Load Order
+sessions_empty = The game has not been launched with Runcher yet, so there are no sessions to show.
+undo_operation_apply_session = Apply Launch Session
+update_translations = Update Translations
+update_translations_success = Translations updated to the latest ones from the translation hub.
+update_translations_no_update = Translations are already up to date.
+translation_coverage = Translation Coverage
+translation_coverage_title = Translation Coverage
+translation_coverage_info = How much of each enabled mod is translated to <b>{"{"}{"}"}</b>. If a mod has a translation made by you in RPFM and one from the translation hub, the newest one is used at launch. Outdated texts are texts the mod changed after they were translated: those are not used.
+translation_coverage_no_language = There is no language selected in the "Enable Translations" launch option.
+translation_coverage_column_mod = Mod
+translation_coverage_column_source = Source
+translation_coverage_column_coverage = Coverage
+translation_coverage_column_outdated = Outdated
+translation_coverage_value = {"{"}{"}"}% ({"{"}{"}"}/{"{"}{"}"})
+translation_coverage_none = Not translated
+translation_source_local = Local (RPFM)
+translation_source_remote = Translation Hub
+offline_mode = Offline Mode
+offline_mode_tt = If enabled, Runcher makes no network requests: the Workshop data of your mods is the one from the last time it was downloaded, and update checks, translation updates and GitHub mod checks are skipped. Offline mode is also enabled automatically if Steam's servers cannot be reached. Workshop dates shown in italics may be outdated.
+offline_mode_label = Offline: Workshop data may be outdated
+offline_mode_enabled = Offline mode is enabled in the settings. The Workshop data shown is the one from the last time it was downloaded.
+offline_mode_detected = Steam's servers could not be reached, so Runcher is working offline. The Workshop data shown is the one from the last time it was downloaded. Runcher checks the connection every minute, and goes back online once Steam's servers can be reached.
+export_tweaks_as_pack = Export Tweaks as Pack
+export_tweaks_as_pack_select_file = Select where to save the Pack with the tweaks
+export_tweaks_as_pack_none = None of the launch options that change the game is enabled, so there are no tweaks to export.
+export_tweaks_as_pack_reserved_name = That name is used by Runcher for the launch options Pack, which is replaced on each launch. Please, choose another name.
+export_tweaks_as_pack_data_folder = The game would load a Pack saved in /data on top of the launch options, applying the tweaks twice. Please, choose another folder.
+export_tweaks_as_pack_success = The tweaks of the enabled launch options have been saved to {"{"}{"}"} as a mod Pack. Install it like any other mod to use the tweaks without Runcher, and disable the launch options while it's enabled.
+
+mod_double_click_action = Double-Click on a Mod:
+mod_double_click_action_tt = What double-clicking a mod in the Mod List does. Double-clicking a category always expands or collapses it.
+mod_double_click_toggle = Enable/Disable It
+mod_double_click_open_workshop = Open its Workshop Page
+mod_double_click_open_folder = Open its Folder
+mod_double_click_open_rpfm = Open it in RPFM
+
+install_packs_title = Install Mods
+install_packs_info = The following files are going to be installed. Zips are extracted, and only the packs inside them (and their images) are installed:<ul>{"{"}{"}"}</ul>
+install_packs_destination = Install To:
+install_packs_destination_data = Data Folder
+install_packs_destination_secondary = Secondary Folder
+install_packs_category = Category:
+install_packs_success = The following mods have been installed:<ul>{"{"}{"}"}</ul>
+
+share_restricted_title = Mods Others Cannot Subscribe To
+share_restricted_description = The following mods are not public in the Workshop, so the people you share this load order with may not be able to subscribe to them:<ul>{"{"}{"}"}</ul>You can leave them out of the load order, or export their packs to send them alongside it.
+share_restricted_exclude = Leave Them Out
+share_restricted_attach = Export Their Packs
+share_restricted_share_anyway = Share Anyway
+share_restricted_select_folder = Select the folder to export the packs to
+share_restricted_attached = The packs have been exported to <b>{"{"}{"}"}</b>. Send them alongside the load order, so they can be installed by dropping them on Runcher's window.
+
+startup_game = Game on Start:
+startup_game_tt = Game to load when Runcher starts without a game passed through the command line.
+startup_game_default = Default Game
+startup_game_last_used = Last Game Used
+startup_game_ask = Ask Me
+startup_game_ask_title = Select Game
+startup_game_ask_label = Select the game to load:
+no_game_loaded = No game loaded. Select a game in the game bar to load it.
+
+extract_pack = Extract Pack…
+extract_pack_filter = Only extract files whose path contains (separate multiple filters with ';', leave empty to extract everything):
+extract_pack_select_folder = Select the folder to extract the pack to
+extract_pack_not_found = No pack found for the mod {"{"}{"}"}.
+extract_pack_no_files = No file in the pack matches the filter "{"{"}{"}"}".
+extract_pack_success = {"{"}{"}"} files extracted to {"{"}{"}"}.
+
+backup_saves_before_launch = Backup Saves before Launching:
+backup_saves_before_launch_tt = If enabled, the latest saves of the game are zipped before each launch, so they can be recovered if a mod update breaks them.
+backup_saves_count = Saves to Backup:
+backup_saves_count_tt = Amount of saves, starting from the newest one, included in each backup.
+backup_saves_retention = Save Backups to Keep:
+backup_saves_retention_tt = Amount of save backups kept per game. Once over it, the oldest ones are deleted.
+settings_save_backups_folder = Save Backups Folder:
+settings_save_backups_folder_ph = Folder where the saves are backed up before launching. Leave it empty to use Runcher's config folder.
+launch_progress_backing_up_saves = Backing up saves…
+backup_saves_failed = The saves could not be backed up before launching: {"{"}{"}"}
+restore_save_backup = Restore Save Backup…
+restore_save_backup_select = Select the backup to restore:
+restore_save_backup_item = {"{"}{"}"}: {"{"}{"}"}
+restore_save_backup_none = There are no save backups for this game.
+restore_save_backup_no_saves_folder = The saves folder of this game could not be found.
+restore_save_backup_overwrite = <p>The following saves already exist and will be overwritten:</p><p>{"{"}{"}"}</p><p>Are you sure?</p>
+restore_save_backup_success = The following saves have been restored: {"{"}{"}"}
+
+open_bughunt = Bughunt Mode…
+bughunt_title = Bughunt Mode
+bughunt_info = Uncheck the mods you suspect of causing the crash and launch the game. Once you're done testing, tell Runcher if the game crashed, and repeat with other mods until the culprit stands out. Runs recorded so far: {"{"}{"}"}.<br/><br/>Closing this window keeps the bughunt, so you can continue it later. Finishing it or discarding it restores the load order it started with.
+bughunt_empty = There are no mods enabled to hunt bugs in.
+bughunt_launch = Launch with Checked Mods
+bughunt_finish = Finish
+bughunt_discard = Discard
+bughunt_outcome = Did the game crash?
+bughunt_outcome_crashed = It Crashed
+bughunt_outcome_clean = It Didn't Crash
+bughunt_column_mod = Mod
+bughunt_column_crashes_enabled = Crashes with It
+bughunt_column_clean_enabled = Clean Runs with It
+bughunt_column_crashes_disabled = Crashes without It
+bughunt_column_clean_disabled = Clean Runs without It
+bughunt_column_verdict = Verdict
+bughunt_verdict_likely = Likely Culprit
+bughunt_verdict_cleared = Cleared
+bughunt_verdict_unknown = Unknown
+bughunt_summary = Bughunt finished after {"{"}{"}"} runs. The original load order has been restored.<br/><br/>{"{"}{"}"}
+bughunt_summary_empty = Bughunt finished. No mod was disabled in any recorded run, so there's nothing to summarize. The original load order has been restored.
+undo_operation_bughunt = Finish Bughunt
+
+mod_version_upload_status = <li>Upload status: {"{"}{"}"}</li>
+upload_status_uploaded = the local copy matches the last version uploaded to the Workshop.
+upload_status_modified = <b>the local copy changed since the last upload.</b>
+upload_status_unknown = unknown. Subscribe to your own mod to compare the local copy with the uploaded one.
+upload_check_title = Problems found in the pack
+upload_check_description = <p>The pack you're about to upload has the following problems:</p>{"{"}{"}"}<p>Do you want to upload it anyway?</p>
+upload_check_wrong_game = <p><b>Wrong game:</b> the pack is a {"{"}{"}"} pack, but {"{"}{"}"} is expected by {"{"}{"}"}. It was probably saved with the wrong game selected in RPFM.</p>
+upload_check_foreign_tables = <p><b>Tables from another game:</b> {"{"}{"}"} doesn't have these tables in these versions. The pack was probably made for another game using the same pack format:</p><ul>{"{"}{"}"}</ul>
+upload_check_leftover_files = <p><b>Leftover files:</b> these files look like backups or leftovers from editors and merge tools:</p><ul>{"{"}{"}"}</ul>
+upload_check_upload_anyway = Upload anyway
+
+open_game_masks_folder = Open Game Masks Folder
+open_game_temp_packs_folder = Open Game Temp Packs Folder
+
+find_duplicate_mods = Find Duplicate Mods
+duplicate_mods_title = Duplicate Mods
+duplicate_mods_description = <p>The following mods are duplicates of each other, usually re-uploads of abandoned mods:</p><ul>{"{"}{"}"}</ul><p>Keep only one of each group enabled. Usually, the one to keep is the most recently updated one, marked in bold, as the others are likely not maintained anymore. If one of them is a dependency of other mods, keep that one instead.</p>
+duplicate_mods_none = No duplicate mods found.
+duplicate_mods_progress = Comparing the packs of the mods…
+duplicate_kind_identical = Identical packs:
+duplicate_kind_same_files = Packs with the same files, but different contents (probably different versions of the same mod):
+duplicate_mod = {"{"}{"}"} ({"{"}{"}"}, updated {"{"}{"}"})
+duplicate_mod_keep = <b>{"{"}{"}"} ({"{"}{"}"}, updated {"{"}{"}"})</b>
+
+refresh_workshop_data = Refresh Workshop Data
+rescan_data_folder = Rescan Data Folder
+rescan_secondary_folder = Rescan Secondary Folder
+rescan_mod = Rescan Mod
+refresh_workshop_data_offline = Workshop data cannot be refreshed while in offline mode.
+enable_api_server = Enable local API for external tools:
+enable_api_server_tt = If enabled, Runcher listens on a local socket called "runcher_api.sock", so external tools (overlays, stream deck plugins, scripts,...) can query the load order, switch profiles and launch the game using JSON-RPC. Requests must include the token in the "api_token" file of Runcher's config folder, which changes every time Runcher starts. Requires restarting Runcher.
+mod_game_version = Game Version
+mod_game_version_current = Current
+mod_game_version_older = Older
+mod_game_version_description = Version of the game this mod was made for, if the mod says it, or guessed from the versions of its tables:
+mod_game_version_embedded = <li>Made for version: {"{"}{"}"}</li>
+mod_game_version_installed = <li>Installed version: {"{"}{"}"}</li>
+mod_game_version_tables = <li>Tables checked: {"{"}{"}"}. Outdated: {"{"}{"}"}</li>
+mod_game_version_outdated_table = <li><b>{"{"}{"}"}</b>: version {"{"}{"}"} in the mod, {"{"}{"}"} in the game.</li>
+mod_game_version_outdated_table_more = <li>...and {"{"}{"}"} more.</li>
+campaign_safety_safe = Safe to add to an ongoing campaign.
+campaign_safety_unsafe = <b>May need a new campaign.</b> Adding this mod to an ongoing campaign may break it, because:<ul>{"{"}{"}"}</ul>
+campaign_safety_reason_startpos = It changes the starting position of a campaign, which is only read when a campaign starts.
+campaign_safety_reason_startpos_tables = It changes tables used to generate the starting position of the campaigns, which is only read when a campaign starts.
+campaign_safety_reason_campaign_scripts = It has campaign scripts that set up the campaign when it starts, which don't run on an ongoing campaign.
+campaign_safety_title = Mods that may need a new campaign
+campaign_safety_description = <p>The save <b>{"{"}{"}"}</b> was made without these enabled mods, which may break an ongoing campaign:</p><ul>{"{"}{"}"}</ul><p>This is only a guess from the contents of the mods. Do you want to disable them before launching?</p>
+campaign_safety_disable_and_launch = Disable and Launch
+customize_actions = Customize Actions Panel
+customize_actions_info = Choose which controls are shown in the Actions panel, and drag the toolbar buttons to reorder them. Hidden launch options keep their current value. This is saved for the game selected.
+customize_actions_toolbar = Toolbar Buttons
+customize_actions_options = Launch Options and Other Controls
+actions_item_undo = Undo Last Operation
+actions_item_fingerprint = Multiplayer Fingerprint
+pin_profile = Pin Selected Profile
+pin_save = Pin Selected Save
+launch_env_vars_ph = Environment variables
+launch_env_vars_tt = <p>Environment variables to pass to the game when launching it, like in the launch options of Steam: <i>KEY=VALUE</i> pairs separated by spaces, with values containing spaces between double quotes. For example: <i>DXVK_HUD=fps VK_ICD_FILENAMES="C:/Program Files/vk/icd.json"</i>.</p><p>Profiles can have their own variables, which take priority over these ones. They're not passed to the game when launching it through the Steam client.</p>
+profile_env_vars = Environment Variables
+profile_env_vars_label = Environment variables to pass to the game when launching it with this profile, on top of the ones of the game.<br/>Written as <i>KEY=VALUE</i> pairs separated by spaces, with values containing spaces between double quotes.
+unit_multiplier_decimals = Unit Multiplier Decimals
+unit_multiplier_decimals_tt = <p>Amount of decimals of the Unit Multiplier launch option.</p>
+unit_multiplier_step = Unit Multiplier Step
+unit_multiplier_step_tt = <p>How much the Unit Multiplier launch option changes when using the arrows or the mouse wheel. You can always type the exact value you want, using the decimal separator of your system.</p>
+mod_known_issues = Known Issues
+mod_known_issues_description = <p><b>Known issues:</b></p><ul>{"{"}{"}"}</ul>
+known_issues_title = Mods With Known Issues Detected
+known_issues_description = <p>The following enabled mods are known to be broken with the current version of the game, or incompatible with other enabled mods:</p>
+    <ul>
+    {"{"}{"}"}
+    </ul>
+    <p>Do you want to disable them before launching the game? If you know they work for you, you can silence these warnings.</p>
+known_issues_silence_and_launch = Silence and Launch
+add_known_issue = Add Known Issue
+add_known_issue_reason = <p>What is the issue? If you selected two mods, they'll be marked as incompatible with each other. Otherwise, each selected mod will be marked as broken.</p><p>Your issues are kept in mod_blacklist_user.json, in the config folder.</p>
+migrate_from_sibling_game = Migrate Config to the Game Selected
+game_family_no_config = There is no config for {"{"}{"}"} to migrate. Select it at least once, so its mods are found.
+game_family_migration_title = Migrate Config from {"{"}{"}"} to {"{"}{"}"}
+game_family_migration_info = <p>Choose what to migrate from {"{"}{"}"} to {"{"}{"}"}, and check which mod of {"{"}{"}"} corresponds to each mod of {"{"}{"}"}. Mods set to skip are left out of the migrated categories, load order and profiles.</p><p>Categories and profiles already in the game selected are kept. Profiles with a name already in use are not migrated.</p>
+game_family_migration_categories = Categories
+game_family_migration_mods = Enabled Mods and Load Order
+game_family_migration_profiles = Profiles
+game_family_migration_column_source = Mod in {"{"}{"}"}
+game_family_migration_column_target = Mod in {"{"}{"}"}
+game_family_migration_unmapped = No installed mod looks like this one. Select it manually, or it'll be skipped.
+undo_operation_migrate_from_sibling_game = Migrate Config from Sibling Game
+import_categories_from_game = Import Category Layout to the Game Selected
+import_categories_title = Import Category Layout from {"{"}{"}"} to {"{"}{"}"}
+import_categories_info = <p>The categories of {"{"}{"}"} will be added to {"{"}{"}"}. Check which mod of {"{"}{"}"} corresponds to each mod of {"{"}{"}"}. Mods set to skip are left where they are.</p><p>Categories already in the game selected are kept.</p>
+undo_operation_import_categories = Import Category Layout
+inspect_diagnostic_bundle = Inspect Diagnostic Bundle
+inspect_diagnostic_bundle_select_file = Select the diagnostic bundle to inspect
+inspect_diagnostic_bundle_progress = Reading the packs of the mods in the bundle installed here…
+inspect_diagnostic_bundle_no_game = The bundle doesn't contain the config of any game.
+inspect_diagnostic_bundle_title = Diagnostic Bundle: {"{"}{"}"}
+inspect_diagnostic_bundle_info = <p>Game: <b>{"{"}{"}"}</b>. Enabled mods: <b>{"{"}{"}"}</b>.</p><p>This is a read-only view of the bundle. Nothing in it is applied to your configs. Conflicts and the mods causing script errors are checked using your installed copies of the same mods.</p>
+inspect_diagnostic_bundle_missing = <p>These enabled mods are not installed here, so their conflicts and script errors cannot be checked:</p><ul>{"{"}{"}"}</ul>
+inspect_diagnostic_bundle_mods = Mods
+inspect_diagnostic_bundle_conflicts = Conflicts
+inspect_diagnostic_bundle_log_errors = Script Errors
+inspect_diagnostic_bundle_runcher_log = Runcher Log
+inspect_diagnostic_bundle_column_order = Load Order
+inspect_diagnostic_bundle_column_mod = Mod
+inspect_diagnostic_bundle_column_category = Category
+inspect_diagnostic_bundle_column_installed_here = Installed Here
+inspect_diagnostic_bundle_column_paths = Paths
+inspect_diagnostic_bundle_column_winner = Winner
+inspect_diagnostic_bundle_column_loser = Overwritten
+inspect_diagnostic_bundle_column_files = Files
+inspect_diagnostic_bundle_column_log = Log
+inspect_diagnostic_bundle_yes = Yes
+inspect_diagnostic_bundle_no = No
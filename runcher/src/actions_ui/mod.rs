@@ -8,12 +8,14 @@
 // https://github.com/Frodo45127/runcher/blob/master/LICENSE.
 //---------------------------------------------------------------------------//
 
+use qt_widgets::q_abstract_spin_box::CorrectionMode;
 use qt_widgets::QAction;
 use qt_widgets::QCheckBox;
-use qt_widgets::QComboBox;
+use qt_widgets::{QComboBox, q_combo_box::InsertPolicy};
 use qt_widgets::QDoubleSpinBox;
 use qt_widgets::QGridLayout;
 use qt_widgets::QLabel;
+use qt_widgets::{QListWidget, QListWidgetItem};
 use qt_widgets::QMenu;
 use qt_widgets::{QToolButton, q_tool_button::ToolButtonPopupMode};
 use qt_widgets::QWidget;
@@ -22,21 +24,61 @@ use qt_widgets::QWidgetAction;
 use qt_gui::QIcon;
 use qt_gui::QStandardItemModel;
 
+use qt_core::CheckState;
+use qt_core::ContextMenuPolicy;
 use qt_core::QBox;
+use qt_core::QLocale;
 use qt_core::QPtr;
 use qt_core::QString;
+use qt_core::QVariant;
 
 use anyhow::Result;
 use getset::*;
 
 use std::rc::Rc;
 
-use rpfm_ui_common::locale::qtr;
+use rpfm_ui_common::locale::{qtr, tr};
+use rpfm_ui_common::settings::setting_int;
 use rpfm_ui_common::utils::*;
 
+use crate::settings_ui::unit_multiplier_step;
+
 const VIEW_DEBUG: &str = "ui_templates/actions_groupbox.ui";
 const VIEW_RELEASE: &str = "ui/actions_groupbox.ui";
 
+/// Buttons of the toolbar that can be reordered and hidden, as key and locale key, in their default order.
+pub const TOOLBAR_ACTIONS: [(&str, &str); 6] = [
+    ("copy_load_order", "copy_load_order"),
+    ("paste_load_order", "paste_load_order"),
+    ("undo", "actions_item_undo"),
+    ("open_folders", "open_folders"),
+    ("reload", "reload"),
+    ("download_subscribed_mods", "download_subscribed_mods"),
+];
+
+/// Launch options that can be hidden from the Play menu, in the order they're in the menu. Their keys are also their locale keys.
+pub const LAUNCH_OPTIONS: [&str; 7] = [
+    "enable_logging",
+    "enable_skip_intro",
+    "remove_trait_limit",
+    "enable_translations",
+    "merge_all_mods",
+    "unit_multiplier",
+    "universal_rebalancer",
+];
+
+/// Other controls of the panel that can be hidden, as key and locale key.
+pub const PANEL_ACTIONS: [(&str, &str); 2] = [
+    ("load_order_slots", "load_order_slot_name"),
+    ("fingerprint", "actions_item_fingerprint"),
+];
+
+/// Column of the layout where the first button of the toolbar goes.
+const TOOLBAR_FIRST_COLUMN: i32 = 5;
+
+/// Role of the items of the customization lists where their key is stored.
+const VALUE_ACTION_KEY: i32 = 21;
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -52,6 +94,10 @@ pub struct ActionsUI {
     merge_all_mods_checkbox: QBox<QCheckBox>,
     unit_multiplier_spinbox: QBox<QDoubleSpinBox>,
     universal_rebalancer_combobox: QBox<QComboBox>,
+    update_translations: QPtr<QAction>,
+    translation_coverage: QPtr<QAction>,
+    export_tweaks_as_pack: QPtr<QAction>,
+    open_bughunt: QPtr<QAction>,
 
     settings_button: QPtr<QToolButton>,
     folders_button: QPtr<QToolButton>,
@@ -59,23 +105,56 @@ pub struct ActionsUI {
     open_game_data_folder: QPtr<QAction>,
     open_game_content_folder: QPtr<QAction>,
     open_game_secondary_folder: QPtr<QAction>,
+    open_game_masks_folder: QPtr<QAction>,
+    open_game_temp_packs_folder: QPtr<QAction>,
     open_game_config_folder: QPtr<QAction>,
     open_runcher_config_folder: QPtr<QAction>,
     open_runcher_error_folder: QPtr<QAction>,
+    open_runcher_locale_folder: QPtr<QAction>,
+    open_runcher_log_viewer: QPtr<QAction>,
+    inspect_diagnostic_bundle: QPtr<QAction>,
 
     copy_load_order_button: QPtr<QToolButton>,
     paste_load_order_button: QPtr<QToolButton>,
+    export_to_ca_launcher: QPtr<QAction>,
+    import_from_ca_launcher: QPtr<QAction>,
+    open_sessions: QPtr<QAction>,
+    undo_button: QPtr<QToolButton>,
     reload_button: QPtr<QToolButton>,
+    refresh_workshop_data: QPtr<QAction>,
+    rescan_data_folder: QPtr<QAction>,
+    rescan_secondary_folder: QPtr<QAction>,
     download_subscribed_mods_button: QPtr<QToolButton>,
 
     profile_load_button: QPtr<QToolButton>,
     profile_save_button: QPtr<QToolButton>,
     profile_manager_button: QPtr<QToolButton>,
+    profile_from_save_button: QPtr<QToolButton>,
+    profile_lock_button: QPtr<QToolButton>,
     profile_combobox: QPtr<QComboBox>,
     profile_model: QBox<QStandardItemModel>,
 
+    load_order_slot_combobox: QPtr<QComboBox>,
+    load_order_slot_model: QBox<QStandardItemModel>,
+    load_order_slot_load_button: QPtr<QToolButton>,
+    load_order_slot_save_button: QPtr<QToolButton>,
+    load_order_slot_delete_button: QPtr<QToolButton>,
+
     save_combobox: QPtr<QComboBox>,
     save_model: QBox<QStandardItemModel>,
+
+    fingerprint_button: QPtr<QToolButton>,
+    copy_fingerprint_manifest: QPtr<QAction>,
+    compare_fingerprint_manifests: QPtr<QAction>,
+    freeze_campaign_integrity: QPtr<QAction>,
+    verify_campaign_integrity: QPtr<QAction>,
+
+    main_widget: QBox<QWidget>,
+    context_menu: QBox<QMenu>,
+    customize_actions: QPtr<QAction>,
+    pin_profile: QPtr<QAction>,
+    pin_save: QPtr<QAction>,
+    restore_save_backup: QPtr<QAction>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -106,6 +185,53 @@ impl ActionsUI {
         menu.add_action(&action);
     }
 
+    /// This function returns the launch options that are enabled for the current game and changed from their defaults, as name and value.
+    pub unsafe fn active_launch_options(&self) -> Vec<(String, String)> {
+        self.active_launch_options_raw()
+            .into_iter()
+            .map(|(key, value)| (tr(key), value.unwrap_or_else(|| tr("launch_option_enabled"))))
+            .collect()
+    }
+
+    /// This function returns the launch options that are enabled for the current game and changed from their defaults, as locale key and value.
+    ///
+    /// Options that are just enabled have no value. Unlike [ActionsUI::active_launch_options], this doesn't depend on the language of the UI.
+    pub unsafe fn active_launch_options_raw(&self) -> Vec<(&'static str, Option<String>)> {
+        let mut options = vec![];
+
+        for (key, checkbox) in [
+            ("enable_logging", &self.enable_logging_checkbox),
+            ("enable_skip_intro", &self.enable_skip_intro_checkbox),
+            ("remove_trait_limit", &self.remove_trait_limit_checkbox),
+            ("merge_all_mods", &self.merge_all_mods_checkbox),
+        ] {
+            if checkbox.is_enabled() && checkbox.is_checked() {
+                options.push((key, None));
+            }
+        }
+
+        for (key, combobox) in [
+            ("enable_translations", &self.enable_translations_combobox),
+            ("universal_rebalancer", &self.universal_rebalancer_combobox),
+        ] {
+            if combobox.is_enabled() && combobox.current_index() > 0 {
+                options.push((key, Some(combobox.current_text().to_std_string())));
+            }
+        }
+
+        if self.unit_multiplier_spinbox.is_enabled() && self.unit_multiplier_spinbox.value() != 1.00 {
+            options.push(("unit_multiplier", Some(self.unit_multiplier_spinbox.value().to_string())));
+        }
+
+        options
+    }
+
+    /// This function applies the precision and step of the unit multiplier configured in the settings.
+    pub unsafe fn update_unit_multiplier_format(&self) {
+        self.unit_multiplier_spinbox.set_decimals(setting_int("unit_multiplier_decimals").clamp(1, 4));
+        self.unit_multiplier_spinbox.set_single_step(unit_multiplier_step());
+    }
+
     pub unsafe fn update_icons(&self) {
         let enable_logging_icon = QIcon::from_theme_1a(&QString::from_std_str("verb"));
         let enable_skip_intro_icon = QIcon::from_theme_1a(&QString::from_std_str("kdenlive-hide-video"));
@@ -115,8 +241,9 @@ impl ActionsUI {
         let unit_multiplier_icon = QIcon::from_theme_1a(&QString::from_std_str("view-time-schedule-calculus"));
         let universal_rebalancer_icon = QIcon::from_theme_1a(&QString::from_std_str("autocorrection"));
 
+        // Only the launch options are widget actions. The actions after them are plain ones.
         let menu = self.play_button().menu();
-        for index in 0..menu.actions().count_0a() {
+        for index in 0..(LAUNCH_OPTIONS.len() as i32).min(menu.actions().count_0a()) {
             let action = menu.actions().value_1a(index);
             let widget_action = action.static_downcast::<QWidgetAction>();
             let widget = widget_action.default_widget();
@@ -176,8 +303,19 @@ impl ActionsUI {
         let universal_rebalancer_combobox = Self::new_launch_option_combobox(&play_menu, "universal_rebalancer", "view-time-schedule-calculus");
         enable_translations_combobox.set_current_index(0);
         unit_multiplier_spinbox.set_value(1.00);
+
+        // Typed values use the decimal separator of the system, and are only applied once the user is done typing.
+        unit_multiplier_spinbox.set_locale(&QLocale::system());
+        unit_multiplier_spinbox.set_keyboard_tracking(false);
+        unit_multiplier_spinbox.set_correction_mode(CorrectionMode::CorrectToNearestValue);
         universal_rebalancer_combobox.set_current_index(0);
 
+        play_menu.add_separator();
+        let update_translations = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("download")), &qtr("update_translations"));
+        let translation_coverage = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("language-chooser")), &qtr("translation_coverage"));
+        let export_tweaks_as_pack = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("document-export")), &qtr("export_tweaks_as_pack"));
+        let open_bughunt = play_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("tools-report-bug")), &qtr("open_bughunt"));
+
         play_button.set_menu(play_menu.into_raw_ptr());
         play_button.set_popup_mode(ToolButtonPopupMode::MenuButtonPopup);
 
@@ -192,24 +330,52 @@ impl ActionsUI {
         let open_game_data_folder = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_game_data_folder"));
         let open_game_content_folder = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_game_content_folder"));
         let open_game_secondary_folder = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_game_secondary_folder"));
+        let open_game_masks_folder = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_game_masks_folder"));
+        let open_game_temp_packs_folder = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_game_temp_packs_folder"));
         let open_game_config_folder = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_game_config_folder"));
         let open_runcher_config_folder = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_runcher_config_folder"));
         let open_runcher_error_folder = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_runcher_error_folder"));
+        let open_runcher_locale_folder = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder")), &qtr("open_runcher_locale_folder"));
+        let open_runcher_log_viewer = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("text-x-log")), &qtr("open_runcher_log_viewer"));
+        let inspect_diagnostic_bundle = folders_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("document-preview")), &qtr("inspect_diagnostic_bundle"));
         folders_button.set_menu(folders_menu.into_raw_ptr());
         folders_button.set_popup_mode(ToolButtonPopupMode::MenuButtonPopup);
 
         let copy_load_order_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "copy_load_order_button")?;
         let paste_load_order_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "paste_load_order_button")?;
+        let undo_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "undo_button")?;
         let reload_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "reload_button")?;
         let download_subscribed_mods_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "download_subscribed_mods_button")?;
         copy_load_order_button.set_tool_tip(&qtr("copy_load_order"));
         paste_load_order_button.set_tool_tip(&qtr("paste_load_order"));
+        undo_button.set_tool_tip(&qtr("undo_last_operation_none"));
+
+        let copy_load_order_menu = QMenu::from_q_widget(&copy_load_order_button);
+        let export_to_ca_launcher = copy_load_order_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("document-export")), &qtr("export_to_ca_launcher"));
+        copy_load_order_button.set_menu(copy_load_order_menu.into_raw_ptr());
+        copy_load_order_button.set_popup_mode(ToolButtonPopupMode::MenuButtonPopup);
+
+        let paste_load_order_menu = QMenu::from_q_widget(&paste_load_order_button);
+        let import_from_ca_launcher = paste_load_order_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("document-import")), &qtr("import_from_ca_launcher"));
+        let open_sessions = paste_load_order_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("view-history")), &qtr("open_sessions"));
+        paste_load_order_button.set_menu(paste_load_order_menu.into_raw_ptr());
+        paste_load_order_button.set_popup_mode(ToolButtonPopupMode::MenuButtonPopup);
         reload_button.set_tool_tip(&qtr("reload"));
+
+        let reload_menu = QMenu::from_q_widget(&reload_button);
+        let refresh_workshop_data = reload_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("internet-services")), &qtr("refresh_workshop_data"));
+        let rescan_data_folder = reload_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder-sync")), &qtr("rescan_data_folder"));
+        let rescan_secondary_folder = reload_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("folder-sync")), &qtr("rescan_secondary_folder"));
+        reload_button.set_menu(reload_menu.into_raw_ptr());
+        reload_button.set_popup_mode(ToolButtonPopupMode::MenuButtonPopup);
+
         download_subscribed_mods_button.set_tool_tip(&qtr("download_subscribed_mods"));
 
         let profile_load_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "profile_load_button")?;
         let profile_save_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "profile_save_button")?;
         let profile_manager_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "profile_manager_button")?;
+        let profile_from_save_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "profile_from_save_button")?;
+        let profile_lock_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "profile_lock_button")?;
         let profile_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "profile_combobox")?;
         let profile_model: QBox<QStandardItemModel> = QStandardItemModel::new_1a(&profile_combobox);
         profile_combobox.set_model(&profile_model);
@@ -217,11 +383,51 @@ impl ActionsUI {
         profile_load_button.set_tool_tip(&qtr("load_profile"));
         profile_save_button.set_tool_tip(&qtr("save_profile"));
         profile_manager_button.set_tool_tip(&qtr("profile_manager"));
+        profile_from_save_button.set_tool_tip(&qtr("profile_from_save"));
+        profile_lock_button.set_tool_tip(&qtr("profile_lock"));
+
+        let load_order_slot_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "load_order_slot_combobox")?;
+        let load_order_slot_load_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "load_order_slot_load_button")?;
+        let load_order_slot_save_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "load_order_slot_save_button")?;
+        let load_order_slot_delete_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "load_order_slot_delete_button")?;
+        let load_order_slot_model: QBox<QStandardItemModel> = QStandardItemModel::new_1a(&load_order_slot_combobox);
+        load_order_slot_combobox.set_model(&load_order_slot_model);
+        load_order_slot_combobox.set_insert_policy(InsertPolicy::NoInsert);
+        load_order_slot_combobox.line_edit().set_placeholder_text(&qtr("load_order_slot_name"));
+        load_order_slot_combobox.set_tool_tip(&qtr("load_order_slot_tooltip"));
+        load_order_slot_load_button.set_tool_tip(&qtr("load_order_slot_load"));
+        load_order_slot_save_button.set_tool_tip(&qtr("load_order_slot_save"));
+        load_order_slot_delete_button.set_tool_tip(&qtr("load_order_slot_delete"));
 
         let save_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "save_combobox")?;
         let save_model: QBox<QStandardItemModel> = QStandardItemModel::new_1a(&save_combobox);
         save_combobox.set_model(&save_model);
 
+        let fingerprint_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "fingerprint_button")?;
+        fingerprint_button.set_text(&qtr("fingerprint_not_computed"));
+        fingerprint_button.set_tool_tip(&qtr("fingerprint_tt"));
+
+        let fingerprint_menu = QMenu::from_q_widget(&fingerprint_button);
+        let copy_fingerprint_manifest = fingerprint_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("edit-copy")), &qtr("copy_fingerprint_manifest"));
+        let compare_fingerprint_manifests = fingerprint_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("kompare")), &qtr("compare_fingerprint_manifests"));
+        fingerprint_menu.add_separator();
+        let freeze_campaign_integrity = fingerprint_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("object-locked")), &qtr("freeze_campaign_integrity"));
+        let verify_campaign_integrity = fingerprint_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("security-high")), &qtr("verify_campaign_integrity"));
+        fingerprint_button.set_menu(fingerprint_menu.into_raw_ptr());
+        fingerprint_button.set_popup_mode(ToolButtonPopupMode::MenuButtonPopup);
+
+        // Right-clicking the panel allows to customize it, to pin the selected profile and save, and to restore backed up saves.
+        main_widget.set_context_menu_policy(ContextMenuPolicy::CustomContextMenu);
+        let context_menu = QMenu::from_q_widget(&main_widget);
+        let customize_actions = context_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("configure-toolbars")), &qtr("customize_actions"));
+        context_menu.add_separator();
+        let pin_profile = context_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("pin")), &qtr("pin_profile"));
+        let pin_save = context_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("pin")), &qtr("pin_save"));
+        pin_profile.set_checkable(true);
+        pin_save.set_checkable(true);
+        context_menu.add_separator();
+        let restore_save_backup = context_menu.add_action_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("document-revert")), &qtr("restore_save_backup"));
+
         layout.add_widget_5a(&main_widget, 0, 0, 1, 1);
 
         let ui = Rc::new(Self {
@@ -233,6 +439,10 @@ impl ActionsUI {
             merge_all_mods_checkbox,
             unit_multiplier_spinbox,
             universal_rebalancer_combobox,
+            update_translations,
+            translation_coverage,
+            export_tweaks_as_pack,
+            open_bughunt,
             //universal_balancer_ignored: QToolButton::new_0a();
 
             settings_button,
@@ -241,25 +451,165 @@ impl ActionsUI {
             open_game_data_folder,
             open_game_content_folder,
             open_game_secondary_folder,
+            open_game_masks_folder,
+            open_game_temp_packs_folder,
             open_game_config_folder,
             open_runcher_config_folder,
             open_runcher_error_folder,
+            open_runcher_locale_folder,
+            open_runcher_log_viewer,
+            inspect_diagnostic_bundle,
 
             copy_load_order_button,
             paste_load_order_button,
+            export_to_ca_launcher,
+            import_from_ca_launcher,
+            open_sessions,
+            undo_button,
             reload_button,
+            refresh_workshop_data,
+            rescan_data_folder,
+            rescan_secondary_folder,
             download_subscribed_mods_button,
 
             profile_load_button,
             profile_save_button,
             profile_manager_button,
+            profile_from_save_button,
+            profile_lock_button,
             profile_combobox,
             profile_model,
 
+            load_order_slot_combobox,
+            load_order_slot_model,
+            load_order_slot_load_button,
+            load_order_slot_save_button,
+            load_order_slot_delete_button,
+
             save_combobox,
-            save_model
+            save_model,
+
+            fingerprint_button,
+            copy_fingerprint_manifest,
+            compare_fingerprint_manifests,
+            freeze_campaign_integrity,
+            verify_campaign_integrity,
+
+            main_widget,
+            context_menu,
+            customize_actions,
+            pin_profile,
+            pin_save,
+            restore_save_backup,
         });
 
         Ok(ui)
     }
+
+    /// This function returns the keys of the toolbar buttons in the provided order.
+    ///
+    /// Unknown keys are ignored, and buttons missing from the order (like ones added in newer versions) go after the rest, in their default order.
+    pub fn toolbar_order(actions_order: &[String]) -> Vec<&'static str> {
+        let mut order = vec![];
+        for key in actions_order {
+            if let Some((action, _)) = TOOLBAR_ACTIONS.iter().find(|(action, _)| action == key) {
+                if !order.contains(action) {
+                    order.push(*action);
+                }
+            }
+        }
+
+        for (action, _) in TOOLBAR_ACTIONS {
+            if !order.contains(&action) {
+                order.push(action);
+            }
+        }
+
+        order
+    }
+
+    fn toolbar_button(&self, key: &str) -> Option<&QPtr<QToolButton>> {
+        match key {
+            "copy_load_order" => Some(&self.copy_load_order_button),
+            "paste_load_order" => Some(&self.paste_load_order_button),
+            "undo" => Some(&self.undo_button),
+            "open_folders" => Some(&self.folders_button),
+            "reload" => Some(&self.reload_button),
+            "download_subscribed_mods" => Some(&self.download_subscribed_mods_button),
+            _ => None,
+        }
+    }
+
+    /// This function hides the controls of the panel the user doesn't want, and puts the toolbar buttons in the order the user wants.
+    pub unsafe fn apply_layout(&self, hidden_actions: &[String], actions_order: &[String]) {
+        let is_visible = |key: &str| !hidden_actions.iter().any(|hidden| hidden == key);
+
+        let layout = self.main_widget().layout().static_downcast::<QGridLayout>();
+        for (index, key) in Self::toolbar_order(actions_order).iter().enumerate() {
+            if let Some(button) = self.toolbar_button(key) {
+                layout.remove_widget(button);
+                layout.add_widget_5a(button, 0, TOOLBAR_FIRST_COLUMN + index as i32, 1, 1);
+                button.set_visible(is_visible(key));
+            }
+        }
+
+        let menu_actions = self.play_button().menu().actions();
+        for (index, key) in LAUNCH_OPTIONS.iter().enumerate() {
+            menu_actions.value_1a(index as i32).set_visible(is_visible(key));
+        }
+
+        let show_load_order_slots = is_visible("load_order_slots");
+        self.load_order_slot_combobox().set_visible(show_load_order_slots);
+        self.load_order_slot_load_button().set_visible(show_load_order_slots);
+        self.load_order_slot_save_button().set_visible(show_load_order_slots);
+        self.load_order_slot_delete_button().set_visible(show_load_order_slots);
+        self.fingerprint_button().set_visible(is_visible("fingerprint"));
+    }
+
+    /// This function fills the lists of the customization dialog: the toolbar buttons in their order, and the rest of the controls.
+    ///
+    /// Checked items are the visible ones.
+    pub unsafe fn fill_customization_lists(toolbar_list: &QPtr<QListWidget>, options_list: &QPtr<QListWidget>, hidden_actions: &[String], actions_order: &[String]) {
+        toolbar_list.clear();
+        options_list.clear();
+
+        let toolbar_actions = Self::toolbar_order(actions_order)
+            .into_iter()
+            .filter_map(|key| TOOLBAR_ACTIONS.iter().find(|(action, _)| *action == key).copied());
+
+        let options_actions = LAUNCH_OPTIONS.iter()
+            .map(|key| (*key, *key))
+            .chain(PANEL_ACTIONS.iter().copied());
+
+        for (list, actions) in [(toolbar_list, toolbar_actions.collect::<Vec<_>>()), (options_list, options_actions.collect::<Vec<_>>())] {
+            for (key, locale_key) in actions {
+                let item = QListWidgetItem::from_q_string(&qtr(locale_key));
+                item.set_data(VALUE_ACTION_KEY, &QVariant::from_q_string(&QString::from_std_str(key)));
+                item.set_check_state(if hidden_actions.iter().any(|hidden| hidden == key) { CheckState::Unchecked } else { CheckState::Checked });
+                list.add_item_q_list_widget_item(item.into_ptr());
+            }
+        }
+    }
+
+    /// This function returns the hidden controls and the order of the toolbar buttons from the lists of the customization dialog.
+    pub unsafe fn customization_from_lists(toolbar_list: &QPtr<QListWidget>, options_list: &QPtr<QListWidget>) -> (Vec<String>, Vec<String>) {
+        let mut hidden_actions = vec![];
+        let mut actions_order = vec![];
+
+        for (list, is_toolbar) in [(toolbar_list, true), (options_list, false)] {
+            for row in 0..list.count() {
+                let item = list.item(row);
+                let key = item.data(VALUE_ACTION_KEY).to_string().to_std_string();
+                if item.check_state() == CheckState::Unchecked {
+                    hidden_actions.push(key.to_owned());
+                }
+
+                if is_toolbar {
+                    actions_order.push(key);
+                }
+            }
+        }
+
+        (hidden_actions, actions_order)
+    }
 }
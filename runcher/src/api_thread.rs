@@ -0,0 +1,245 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the local API server, so external tools (overlays, stream deck plugins, scripts,...) can control Runcher.
+//!
+//! The server listens on a local socket (a named pipe on Windows) called `runcher_api.sock`, and talks JSON-RPC 2.0:
+//! one request per line, one response per line. The supported methods are:
+//!
+//! - `get_game_selected`: returns the key of the game selected, and the profile in the profile selector.
+//! - `get_load_order`: returns the load order of the game selected.
+//! - `get_profiles`: returns the names of the profiles of the game selected.
+//! - `load_profile`: loads the profile passed in the `name` param.
+//! - `launch_game`: launches the game selected, as if the Play button was clicked. It returns before the game is launched.
+//!
+//! Requests are passed to the UI thread as normal commands, so they're handled exactly like the actions in the UI.
+//!
+//! The socket itself can be reached by any local user, so every request must include a `token` field with the token Runcher writes
+//! to the `api_token` file in its config folder when the server starts. Only the user running Runcher can read that file.
+
+use anyhow::Result;
+use interprocess::local_socket::{prelude::*, GenericNamespaced, ListenerOptions, Stream};
+use serde::{Deserialize, Serialize};
+use serde_json::{from_str, to_string, Value};
+
+use std::collections::hash_map::RandomState;
+use std::fs::OpenOptions;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{BufRead, BufReader, Write};
+#[cfg(unix)] use std::os::unix::fs::OpenOptionsExt;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rpfm_lib::integrations::log::*;
+
+use rpfm_ui_common::settings::config_path;
+
+use crate::CENTRAL_COMMAND;
+use crate::communications::*;
+
+/// Name of the socket the server listens on.
+const API_SOCKET_NAME: &str = "runcher_api.sock";
+
+/// Name of the file in the config folder with the token clients must send with each request.
+const API_TOKEN_FILE_NAME: &str = "api_token";
+
+/// JSON-RPC error codes.
+const ERROR_PARSE: i64 = -32700;
+const ERROR_METHOD_NOT_FOUND: i64 = -32601;
+const ERROR_INVALID_PARAMS: i64 = -32602;
+const ERROR_SERVER: i64 = -32000;
+const ERROR_UNAUTHORIZED: i64 = -32001;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Debug, Deserialize)]
+struct ApiRequest {
+    #[serde(default)]
+    id: Value,
+    #[serde(default)]
+    token: String,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Debug, Serialize)]
+struct ApiResponse {
+    jsonrpc: &'static str,
+    id: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<ApiError>,
+}
+
+#[derive(Debug, Serialize)]
+struct ApiError {
+    code: i64,
+    message: String,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This is the API loop that's going to be executed in a parallel thread to the UI, if the API is enabled. No UI or "Unsafe" stuff here.
+///
+/// Each connection is handled in its own thread, so a client that doesn't close its connection doesn't block the rest.
+pub fn api_loop() {
+
+    // Only write the token once we own the socket. Otherwise a second instance of Runcher would replace the token of the first one.
+    let listener = match API_SOCKET_NAME.to_ns_name::<GenericNamespaced>().and_then(|name| ListenerOptions::new().name(name).create_sync()) {
+        Ok(listener) => listener,
+        Err(error) => {
+            error!("Error starting the API server: {}", error);
+            return;
+        }
+    };
+
+    let token = match write_token() {
+        Ok(token) => token,
+        Err(error) => {
+            error!("Error writing the API token, so the API server will not start: {}", error);
+            return;
+        }
+    };
+
+    info!("API Thread listening on {}…", API_SOCKET_NAME);
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                let token = token.to_owned();
+                thread::spawn(move || handle_connection(stream, &token));
+            },
+            Err(error) => warn!("Error accepting an API connection: {}", error),
+        }
+    }
+}
+
+/// This function answers the requests of a client until it closes the connection.
+fn handle_connection(stream: Stream, token: &str) {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    loop {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match from_str::<ApiRequest>(&line) {
+            Ok(request) if !tokens_match(&request.token, token) => ApiResponse::error(request.id, ERROR_UNAUTHORIZED, "Invalid or missing token.".to_owned()),
+            Ok(request) => handle_request(request),
+            Err(error) => ApiResponse::error(Value::Null, ERROR_PARSE, error.to_string()),
+        };
+
+        let mut response = match to_string(&response) {
+            Ok(response) => response,
+            Err(error) => {
+                error!("Error serializing an API response: {}", error);
+                break;
+            }
+        };
+
+        response.push('\n');
+        let stream = reader.get_mut();
+        if stream.write_all(response.as_bytes()).and_then(|_| stream.flush()).is_err() {
+            break;
+        }
+    }
+}
+
+/// This function passes a request to the UI thread, and waits for its answer.
+fn handle_request(request: ApiRequest) -> ApiResponse {
+    let command = match request.method.as_str() {
+        "get_game_selected" => Command::GetGameSelected,
+        "get_load_order" => Command::GetLoadOrder,
+        "get_profiles" => Command::GetProfiles,
+        "load_profile" => match request.params.get("name").and_then(|name| name.as_str()) {
+            Some(name) => Command::LoadProfile(name.to_owned()),
+            None => return ApiResponse::error(request.id, ERROR_INVALID_PARAMS, "Missing profile name.".to_owned()),
+        },
+        "launch_game" => Command::LaunchGameSelected,
+        _ => return ApiResponse::error(request.id, ERROR_METHOD_NOT_FOUND, format!("Unknown method: {}.", request.method)),
+    };
+
+    let receiver = CENTRAL_COMMAND.send_api(command);
+    let response = CentralCommand::recv(&receiver);
+    match response {
+        Response::Success => ApiResponse::result(request.id, Value::Null),
+        Response::JsonValue(value) => ApiResponse::result(request.id, value),
+        Response::Error(error) => ApiResponse::error(request.id, ERROR_SERVER, error.to_string()),
+        _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+    }
+}
+
+/// This function generates a new token for this session and writes it to the token file, readable only by the current user where the OS allows it.
+fn write_token() -> Result<String> {
+    let path = token_path()?;
+
+    // RandomState is seeded with random keys from the OS, so we use it to get the random part of the token without extra dependencies.
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_nanos()).unwrap_or_default();
+    let random = (0..4).map(|_| RandomState::new().build_hasher().finish().to_string()).collect::<String>();
+    let token = sha256::digest(format!("{nanos}{}{random}", std::process::id()));
+
+    // Remove the old one first, as the permissions of an existing file are not changed when opening it.
+    let _ = std::fs::remove_file(&path);
+
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)] options.mode(0o600);
+
+    let mut file = options.open(path)?;
+    file.write_all(token.as_bytes())?;
+    Ok(token)
+}
+
+/// This function compares the provided token with ours in constant time, so the time it takes doesn't tell how much of the token is right.
+fn tokens_match(provided: &str, token: &str) -> bool {
+    let provided = provided.as_bytes();
+    let token = token.as_bytes();
+    provided.len() == token.len() && provided.iter().zip(token).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+/// This function returns the path of the API token file.
+fn token_path() -> Result<PathBuf> {
+    Ok(config_path()?.join(API_TOKEN_FILE_NAME))
+}
+
+impl ApiResponse {
+    fn result(id: Value, result: Value) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    fn error(id: Value, code: i64, message: String) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result: None,
+            error: Some(ApiError {
+                code,
+                message,
+            }),
+        }
+    }
+}
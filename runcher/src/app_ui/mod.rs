@@ -12,42 +12,66 @@ use qt_widgets::QAction;
 use qt_widgets::QActionGroup;
 use qt_widgets::QApplication;
 use qt_widgets::QButtonGroup;
+use qt_widgets::QCheckBox;
 use qt_widgets::QComboBox;
 use qt_widgets::QGroupBox;
+use qt_widgets::{QInputDialog, q_input_dialog::InputMode};
 use qt_widgets::QLineEdit;
+use qt_widgets::QListWidget;
 use qt_widgets::QRadioButton;
 use qt_widgets::QTabWidget;
 use qt_widgets::QToolBar;
-use qt_widgets::{QDialog, QDialogButtonBox, q_dialog_button_box::StandardButton};
+use qt_widgets::{QDialog, q_dialog::DialogCode, QDialogButtonBox, q_dialog_button_box::StandardButton};
+use qt_widgets::{QFileDialog, q_file_dialog::{AcceptMode, FileMode, Option as QFileDialogOption}};
 use qt_widgets::QLabel;
 use qt_widgets::QMainWindow;
+use qt_widgets::QMenu;
 use qt_widgets::QMessageBox;
 use qt_widgets::q_message_box;
+use qt_widgets::QPlainTextEdit;
+use qt_widgets::QProgressDialog;
 use qt_widgets::QPushButton;
+use qt_widgets::QSpinBox;
 use qt_widgets::QSplitter;
 use qt_widgets::QTableView;
 use qt_widgets::QTextEdit;
 use qt_widgets::QTreeView;
 use qt_widgets::QWidget;
 
+use qt_gui::QDesktopServices;
 use qt_gui::QFont;
+use qt_gui::QGuiApplication;
 use qt_gui::QIcon;
+use qt_gui::QKeySequence;
 use qt_gui::QListOfQStandardItem;
 use qt_gui::QStandardItem;
 use qt_gui::QStandardItemModel;
 
 use qt_core::CheckState;
+use qt_core::ContextMenuPolicy;
 use qt_core::Orientation;
+use qt_core::q_item_selection_model::SelectionFlag;
 use qt_core::QBox;
-use qt_core::QCoreApplication;
+use qt_core::QFileSystemWatcher;
+use qt_core::QFlags;
+use qt_core::QListOfInt;
+use qt_core::QListOfQUrl;
+use qt_core::QMimeData;
 use qt_core::QModelIndex;
 use qt_core::QObject;
+use qt_core::QPoint;
 use qt_core::QPtr;
 use qt_core::QSize;
 use qt_core::QSortFilterProxyModel;
 use qt_core::QString;
+use qt_core::QStringList;
+use qt_core::QTimer;
+use qt_core::QUrl;
 use qt_core::QVariant;
+use qt_core::ShortcutContext;
 use qt_core::SlotNoArgs;
+use qt_core::WindowModality;
+use qt_core::WindowState;
 
 use cpp_core::CppBox;
 use cpp_core::Ref;
@@ -61,17 +85,19 @@ use itertools::Itertools;
 use rayon::prelude::*;
 use sha256::try_digest;
 
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
 use std::fs::{DirBuilder, File};
-use std::io::{BufReader, BufWriter, Cursor, Read, Write};
+use std::io::{BufReader, Cursor, Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 use std::process::exit;
 use std::rc::Rc;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, RwLock, atomic::Ordering};
 use std::time::SystemTime;
+use time::OffsetDateTime;
 
-use rpfm_lib::binary::{ReadBytes, WriteBytes};
-use rpfm_lib::files::{Container, db::DB, EncodeableExtraData, FileType, loc::Loc, pack::Pack, RFile, RFileDecoded, table::DecodedData};
+use rpfm_lib::binary::ReadBytes;
+use rpfm_lib::files::{Container, db::DB, FileType, loc::Loc, pack::Pack, RFile, RFileDecoded, table::DecodedData};
 use rpfm_lib::games::{GameInfo, pfh_file_type::PFHFileType, supported_games::*};
 use rpfm_lib::integrations::log::*;
 use rpfm_lib::schema::Schema;
@@ -88,16 +114,14 @@ use crate::actions_ui::ActionsUI;
 use crate::CENTRAL_COMMAND;
 use crate::cli::Cli;
 use crate::communications::*;
-use crate::DARK_PALETTE;
 use crate::data_ui::DataListUI;
 use crate::data_ui::pack_tree::PackTree;
 use crate::ffi::*;
 use crate::games::*;
-use crate::mod_manager::{*, game_config::{GameConfig, DEFAULT_CATEGORY}, integrations::*, load_order::{ImportedLoadOrderMode, LoadOrder}, mods::{Mod, ShareableMod}, profiles::Profile, saves::Save};
-use crate::LIGHT_PALETTE;
-use crate::LIGHT_STYLE_SHEET;
+use crate::logging::*;
+use crate::mod_manager::{*, authored_packs::upload_check, backups::*, campaign_safety::unsafe_mods_for_save, bughunt::BugHunt, category_rules::{category_rule_moves, CategoryRule, CategoryRuleField}, config_recovery::ConfigRecovery, conflicts::*, diagnostic_bundle::DiagnosticBundle, env_vars::launch_env_vars, game_config::{GameConfig, RescanScope, DEFAULT_CATEGORY}, game_definitions::{active_new_game, base_game_key, executable_path, schema_file_name, set_active_new_game, steam_id, GameDefinitions}, game_families::{migrate_game_config, migrate_profiles, mod_mappings, sibling_games, FamilyMigration}, game_quirks::game_quirks, fingerprint::{Fingerprint, FingerprintDifference, HashCheck}, game_updates::*, hooks::{Hooks, HookStage}, install::{install_packs, vanilla_pack_names}, integrations::*, launch::*, lint::LintPatterns, load_order::{ImportedLoadOrderMode, LoadOrder}, log_patterns::LogPatterns, masks::*, mct::*, misfiled_packs::set_treat_as_mod_pack, mod_blacklist::{BlacklistEntry, ModBlacklist}, mods::{Mod, ShareableMod}, name_matching::{match_mod_names, workshop_search_url}, offline::{offline_mode, set_offline_mode_enabled, OfflineReason}, other_managers::{lock_game, ManagerConflict}, pack_extract::extract_pack, profiles::{active_locked_profile, is_valid_id, last_profile, sanitize_id, set_active_locked_profile, set_last_profile, Profile}, report::{escape_html, mod_report, ReportFormat}, requirements::*, save_backups::{existing_saves, save_backups}, saves::Save, script_breaks::*, secondary_staging::{clean_staged_packs, needs_secondary_staging}, sessions::SessionHistory, shadowed_packs::PackCopy, translations::translation_coverage, undo::UndoSnapshot, workshop_monitor::WorkshopSnapshot};
 use crate::mod_list_ui::*;
-use crate::pack_list_ui::PackListUI;
+use crate::pack_list_ui::{PackListUI, VALUE_IS_VANILLA};
 use crate::{
     REGEX_MAP_INFO_DISPLAY_NAME,
     REGEX_MAP_INFO_DESCRIPTION,
@@ -110,27 +134,91 @@ use crate::{
 use crate::SCHEMA;
 use crate::settings_ui::*;
 use crate::SUPPORTED_GAMES;
+use crate::themes::*;
+use crate::tray_ui::TrayUI;
 use crate::updater_ui::*;
+use crate::VERSION;
 
 use self::slots::AppUISlots;
+use self::ui_state::{toggle_pinned, UIState, WindowGeometry};
 
 pub mod slots;
+mod ui_state;
 
 const LOAD_ORDER_STRING_VIEW_DEBUG: &str = "ui_templates/load_order_string_dialog.ui";
 const LOAD_ORDER_STRING_VIEW_RELEASE: &str = "ui/load_order_string_dialog.ui";
 
+const CATEGORY_RULES_VIEW_DEBUG: &str = "ui_templates/category_rules_dialog.ui";
+const CATEGORY_RULES_VIEW_RELEASE: &str = "ui/category_rules_dialog.ui";
+
+const FINGERPRINT_COMPARE_VIEW_DEBUG: &str = "ui_templates/fingerprint_compare_dialog.ui";
+const FINGERPRINT_COMPARE_VIEW_RELEASE: &str = "ui/fingerprint_compare_dialog.ui";
+
+const NAME_MATCHES_VIEW_DEBUG: &str = "ui_templates/name_matches_dialog.ui";
+const NAME_MATCHES_VIEW_RELEASE: &str = "ui/name_matches_dialog.ui";
+const GAME_FAMILY_MIGRATION_VIEW_DEBUG: &str = "ui_templates/game_family_migration_dialog.ui";
+const GAME_FAMILY_MIGRATION_VIEW_RELEASE: &str = "ui/game_family_migration_dialog.ui";
+const HASH_VERIFICATION_VIEW_DEBUG: &str = "ui_templates/hash_verification_dialog.ui";
+const HASH_VERIFICATION_VIEW_RELEASE: &str = "ui/hash_verification_dialog.ui";
+
+const ACTIONS_CUSTOMIZATION_VIEW_DEBUG: &str = "ui_templates/actions_customization_dialog.ui";
+const ACTIONS_CUSTOMIZATION_VIEW_RELEASE: &str = "ui/actions_customization_dialog.ui";
+
+const SESSIONS_VIEW_DEBUG: &str = "ui_templates/sessions_dialog.ui";
+const SESSIONS_VIEW_RELEASE: &str = "ui/sessions_dialog.ui";
+
+const BUGHUNT_VIEW_DEBUG: &str = "ui_templates/bughunt_dialog.ui";
+const BUGHUNT_VIEW_RELEASE: &str = "ui/bughunt_dialog.ui";
+
+/// Results of the bughunt dialog, on top of the accepted and rejected ones.
+const BUGHUNT_RESULT_LAUNCH: i32 = 2;
+const BUGHUNT_RESULT_DISCARD: i32 = 3;
+
+const TRANSLATION_COVERAGE_VIEW_DEBUG: &str = "ui_templates/translation_coverage_dialog.ui";
+const TRANSLATION_COVERAGE_VIEW_RELEASE: &str = "ui/translation_coverage_dialog.ui";
+
+const INSTALL_PACKS_VIEW_DEBUG: &str = "ui_templates/install_packs_dialog.ui";
+const INSTALL_PACKS_VIEW_RELEASE: &str = "ui/install_packs_dialog.ui";
+
 const WORKSHOP_UPLOAD_VIEW_DEBUG: &str = "ui_templates/workshop_upload_dialog.ui";
 const WORKSHOP_UPLOAD_VIEW_RELEASE: &str = "ui/workshop_upload_dialog.ui";
 
+const WORKSHOP_SEARCH_VIEW_DEBUG: &str = "ui_templates/workshop_search_dialog.ui";
+const WORKSHOP_SEARCH_VIEW_RELEASE: &str = "ui/workshop_search_dialog.ui";
+
 const LOG_ANALYSIS_VIEW_DEBUG: &str = "ui_templates/log_analysis_dialog.ui";
 const LOG_ANALYSIS_VIEW_RELEASE: &str = "ui/log_analysis_dialog.ui";
 
-const MERGE_ALL_PACKS_PACK_NAME: &str = "merge_me_sideways_honey";
+const LOG_VIEWER_VIEW_DEBUG: &str = "ui_templates/log_viewer_dialog.ui";
+const LOG_VIEWER_VIEW_RELEASE: &str = "ui/log_viewer_dialog.ui";
+
+const DIAGNOSTIC_BUNDLE_VIEW_DEBUG: &str = "ui_templates/diagnostic_bundle_dialog.ui";
+const DIAGNOSTIC_BUNDLE_VIEW_RELEASE: &str = "ui/diagnostic_bundle_dialog.ui";
+
+/// Interval, in ms, between checks for new lines in the log viewer.
+const LOG_VIEWER_REFRESH_INTERVAL: i32 = 1000;
+
+/// Seconds to wait before launching the game when the launch countdown is enabled.
+const LAUNCH_COUNTDOWN_SECONDS: i32 = 5;
+
+/// Interval between checks of the Workshop content folders while in tray mode, in milliseconds.
+const WORKSHOP_MONITOR_INTERVAL: i32 = 300_000;
+
+/// Interval between checks for the results of a Workshop scan, in milliseconds.
+const WORKSHOP_MONITOR_POLL_INTERVAL: i32 = 500;
+
+/// Interval between checks for requests from the local API, in milliseconds.
+const API_POLL_INTERVAL: i32 = 100;
 
-#[allow(dead_code)] const VANILLA_MOD_LIST_FILE_NAME: &str = "used_mods.txt";
-#[allow(dead_code)] const CUSTOM_MOD_LIST_FILE_NAME: &str = "mod_list.txt";
-#[allow(dead_code)] const USER_SCRIPT_FILE_NAME: &str = "user.script.txt";
-#[allow(dead_code)] const USER_SCRIPT_EMPIRE_FILE_NAME: &str = "user.empire_script.txt";
+/// Interval between checks for the network coming back while offline mode is detected, in milliseconds.
+const OFFLINE_MODE_CHECK_INTERVAL: i32 = 60_000;
+
+/// Interval between checks for the results of an offline mode detection, in milliseconds.
+const OFFLINE_MODE_POLL_INTERVAL: i32 = 250;
+
+const VANILLA_MOD_LIST_FILE_NAME: &str = "used_mods.txt";
+
+const RPFM_EXECUTABLE: &str = "rpfm_ui.exe";
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -147,6 +235,7 @@ pub struct AppUI {
     // Main Window.
     //-------------------------------------------------------------------------------//
     main_window: QBox<QMainWindow>,
+    main_splitter: QPtr<QSplitter>,
     right_tabbar: QBox<QTabWidget>,
 
     github_button: QBox<QPushButton>,
@@ -154,6 +243,7 @@ pub struct AppUI {
     patreon_button: QBox<QPushButton>,
     about_runcher_button: QBox<QPushButton>,
     check_updates_button: QBox<QPushButton>,
+    offline_label: QBox<QLabel>,
 
     //-------------------------------------------------------------------------------//
     // `Game Selected` menu.
@@ -171,9 +261,16 @@ pub struct AppUI {
     game_selected_shogun_2: QPtr<QAction>,
     game_selected_napoleon: QPtr<QAction>,
     game_selected_empire: QPtr<QAction>,
+    game_selected_new_games: Vec<QPtr<QAction>>,
 
     game_selected_group: QBox<QActionGroup>,
 
+    game_selected_bar: QPtr<QToolBar>,
+    game_selected_context_menu: QBox<QMenu>,
+    game_selected_open_in_new_window: QPtr<QAction>,
+    game_selected_migrate_from_sibling: QPtr<QAction>,
+    game_selected_import_categories: QPtr<QAction>,
+
     //-------------------------------------------------------------------------------//
     // `Actions` section.
     //-------------------------------------------------------------------------------//
@@ -194,6 +291,64 @@ pub struct AppUI {
     //-------------------------------------------------------------------------------//
     pack_list_ui: Rc<PackListUI>,
 
+    //-------------------------------------------------------------------------------//
+    // Tray icon.
+    //-------------------------------------------------------------------------------//
+    tray_ui: Rc<TrayUI>,
+
+    //-------------------------------------------------------------------------------//
+    // Shortcuts.
+    //-------------------------------------------------------------------------------//
+    shortcuts: BTreeMap<String, QBox<QAction>>,
+
+    //-------------------------------------------------------------------------------//
+    // Sync folder watcher.
+    //-------------------------------------------------------------------------------//
+    config_watcher: QBox<QFileSystemWatcher>,
+    config_watcher_timer: QBox<QTimer>,
+    theme_timer: QBox<QTimer>,
+
+    //-------------------------------------------------------------------------------//
+    // Mod list files watcher.
+    //-------------------------------------------------------------------------------//
+    mod_list_watcher: QBox<QFileSystemWatcher>,
+    mod_list_watcher_timer: QBox<QTimer>,
+    mod_list_changed_dialog: QBox<QMessageBox>,
+    mod_list_import_button: QPtr<QPushButton>,
+    mod_list_overwrite_button: QPtr<QPushButton>,
+
+    /// Last known list of packs of each watched mod list file.
+    mod_list_known_contents: Rc<RwLock<HashMap<PathBuf, Vec<String>>>>,
+
+    /// Mod list file changed outside Runcher, pending of the user deciding what to do with it.
+    mod_list_changed_path: Rc<RwLock<Option<PathBuf>>>,
+
+    //-------------------------------------------------------------------------------//
+    // Workshop monitor.
+    //-------------------------------------------------------------------------------//
+    workshop_monitor_timer: QBox<QTimer>,
+    workshop_monitor_poll_timer: QBox<QTimer>,
+
+    /// Receiver of the scan in progress, if any.
+    workshop_monitor_receiver: Rc<RwLock<Option<Receiver<Response>>>>,
+
+    /// Last known state of the content folder of each game, to find what changed on each check.
+    workshop_snapshots: Rc<RwLock<HashMap<String, WorkshopSnapshot>>>,
+
+    //-------------------------------------------------------------------------------//
+    // Local API.
+    //-------------------------------------------------------------------------------//
+    api_timer: QBox<QTimer>,
+
+    //-------------------------------------------------------------------------------//
+    // Offline mode.
+    //-------------------------------------------------------------------------------//
+    offline_mode_timer: QBox<QTimer>,
+    offline_mode_poll_timer: QBox<QTimer>,
+
+    /// Receiver of the offline mode detection in progress, if any.
+    offline_mode_receiver: Rc<RwLock<Option<Receiver<Response>>>>,
+
     //-------------------------------------------------------------------------------//
     // Extra stuff
     //-------------------------------------------------------------------------------//
@@ -206,19 +361,13 @@ pub struct AppUI {
     game_profiles: Arc<RwLock<HashMap<String, Profile>>>,
     game_saves: Arc<RwLock<Vec<Save>>>,
 
+    /// Copy of the config from before the last operation that overwrote it, for undoing it.
+    undo_snapshot: Rc<RwLock<Option<UndoSnapshot>>>,
+
     // Game selected. Unlike RPFM, here it's not a global.
     game_selected: Rc<RwLock<GameInfo>>,
 }
 
-#[derive(Debug, Default, Getters)]
-#[getset(get = "pub")]
-pub struct ScriptBreak {
-    posible_pack: String,
-    posible_pack_mod: String,
-    posible_pack_link: Option<String>,
-    full_log: String,
-}
-
 //-------------------------------------------------------------------------------//
 //                             Implementations
 //-------------------------------------------------------------------------------//
@@ -229,7 +378,7 @@ impl AppUI {
     pub unsafe fn new() -> Result<Rc<Self>> {
 
         // Initialize and configure the main window.
-        let main_window = launcher_window_safe(setting_bool("dark_mode"));
+        let main_window = launcher_window_safe(theme_is_dark(&setting_string("theme")));
         let central_widget = QWidget::new_1a(&main_window);
         let central_layout = create_grid_layout(central_widget.static_upcast());
         main_window.set_central_widget(&central_widget);
@@ -249,7 +398,8 @@ impl AppUI {
         let right_tabbar = QTabWidget::new_1a(&right_widget);
         right_layout.add_widget_5a(&right_tabbar, 1, 0, 1, 1);
 
-        central_layout.add_widget_5a(splitter.into_raw_ptr(), 0, 1, 1, 1);
+        central_layout.add_widget_5a(&splitter, 0, 1, 1, 1);
+        let main_splitter = splitter.into_q_ptr();
 
         // Get the Status bar.
         let status_bar = main_window.status_bar();
@@ -285,6 +435,11 @@ impl AppUI {
         check_updates_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("svn-update")));
         status_bar.add_permanent_widget_1a(&check_updates_button);
 
+        // Only shown in offline mode, to make clear the Workshop data may be outdated.
+        let offline_label = QLabel::from_q_string_q_widget(&qtr("offline_mode_label"), &status_bar);
+        offline_label.set_visible(false);
+        status_bar.add_widget_1a(&offline_label);
+
         //-----------------------------------------------//
         // `Game Selected` Menu.
         //-----------------------------------------------//
@@ -311,6 +466,50 @@ impl AppUI {
         let game_selected_napoleon = game_selected_bar.add_action_2a(&QIcon::from_q_string(&QString::from_std_str(icon_folder.clone() + SUPPORTED_GAMES.game(KEY_NAPOLEON).unwrap().icon_small())), &QString::from_std_str(DISPLAY_NAME_NAPOLEON));
         let game_selected_empire = game_selected_bar.add_action_2a(&QIcon::from_q_string(&QString::from_std_str(icon_folder.clone() + SUPPORTED_GAMES.game(KEY_EMPIRE).unwrap().icon_small())), &QString::from_std_str(DISPLAY_NAME_EMPIRE));
 
+        // The key of each game goes in the action's data, so the user can rename the games without breaking their identification.
+        let game_definitions = GameDefinitions::cached();
+        for (action, game_key) in [
+            (&game_selected_pharaoh_dynasties, KEY_PHARAOH_DYNASTIES),
+            (&game_selected_pharaoh, KEY_PHARAOH),
+            (&game_selected_warhammer_3, KEY_WARHAMMER_3),
+            (&game_selected_troy, KEY_TROY),
+            (&game_selected_three_kingdoms, KEY_THREE_KINGDOMS),
+            (&game_selected_warhammer_2, KEY_WARHAMMER_2),
+            (&game_selected_warhammer, KEY_WARHAMMER),
+            (&game_selected_thrones_of_britannia, KEY_THRONES_OF_BRITANNIA),
+            (&game_selected_attila, KEY_ATTILA),
+            (&game_selected_rome_2, KEY_ROME_2),
+            (&game_selected_shogun_2, KEY_SHOGUN_2),
+            (&game_selected_napoleon, KEY_NAPOLEON),
+            (&game_selected_empire, KEY_EMPIRE),
+        ] {
+            action.set_data(&QVariant::from_q_string(&QString::from_std_str(game_key)));
+
+            if let Some(definition) = game_definitions.games().get(game_key) {
+                if let Some(icon) = definition.icon() {
+                    action.set_icon(&QIcon::from_q_string(&QString::from_std_str(icon.to_string_lossy())));
+                }
+
+                if let Some(display_name) = definition.display_name() {
+                    action.set_text(&QString::from_std_str(display_name));
+                }
+            }
+        }
+
+        // New games defined by the user go after the supported ones, with the icon of their base game if they don't have their own.
+        let mut game_selected_new_games = vec![];
+        for (game_key, definition) in game_definitions.new_games() {
+            let icon = match definition.icon() {
+                Some(icon) => icon.to_string_lossy().to_string(),
+                None => SUPPORTED_GAMES.game(&definition.base_game().clone().unwrap_or_default()).map(|game| icon_folder.clone() + game.icon_small()).unwrap_or_default(),
+            };
+
+            let display_name = definition.display_name().clone().unwrap_or_else(|| game_key.to_owned());
+            let action = game_selected_bar.add_action_2a(&QIcon::from_q_string(&QString::from_std_str(icon)), &QString::from_std_str(display_name));
+            action.set_data(&QVariant::from_q_string(&QString::from_std_str(game_key)));
+            game_selected_new_games.push(action);
+        }
+
         let game_selected_group = QActionGroup::new(&game_selected_bar);
 
         // Configure the `Game Selected` Menu.
@@ -327,6 +526,11 @@ impl AppUI {
         game_selected_group.add_action_q_action(&game_selected_shogun_2);
         game_selected_group.add_action_q_action(&game_selected_napoleon);
         game_selected_group.add_action_q_action(&game_selected_empire);
+        for action in &game_selected_new_games {
+            game_selected_group.add_action_q_action(action);
+            action.set_checkable(true);
+        }
+
         game_selected_pharaoh_dynasties.set_checkable(true);
         game_selected_pharaoh.set_checkable(true);
         game_selected_warhammer_3.set_checkable(true);
@@ -341,7 +545,15 @@ impl AppUI {
         game_selected_napoleon.set_checkable(true);
         game_selected_empire.set_checkable(true);
 
-        central_layout.add_widget_5a(game_selected_bar.into_raw_ptr(), 0, 0, 1, 1);
+        // Context menu, to open games in a new window, so they can be managed side by side.
+        game_selected_bar.set_context_menu_policy(ContextMenuPolicy::CustomContextMenu);
+        let game_selected_context_menu = QMenu::from_q_widget(&game_selected_bar);
+        let game_selected_open_in_new_window = game_selected_context_menu.add_action_q_string(&qtr("open_game_in_new_window"));
+        let game_selected_migrate_from_sibling = game_selected_context_menu.add_action_q_string(&qtr("migrate_from_sibling_game"));
+        let game_selected_import_categories = game_selected_context_menu.add_action_q_string(&qtr("import_categories_from_game"));
+
+        let game_selected_bar = game_selected_bar.into_q_ptr();
+        central_layout.add_widget_5a(&game_selected_bar, 0, 0, 1, 1);
 
         //-------------------------------------------------------------------------------//
         // `Actions` section.
@@ -363,12 +575,83 @@ impl AppUI {
         //-------------------------------------------------------------------------------//
         let pack_list_ui = PackListUI::new(&right_tabbar)?;
 
+        //-------------------------------------------------------------------------------//
+        // Tray icon.
+        //-------------------------------------------------------------------------------//
+        let tray_ui = TrayUI::new(&main_window);
+
+        //-------------------------------------------------------------------------------//
+        // Shortcuts.
+        //-------------------------------------------------------------------------------//
+
+        // These are invisible actions attached to the main window. Their key sequences are set from the settings.
+        //
+        // Undo is the exception: it's attached to the mod list, so Ctrl+Z in text fields still undoes the text instead of the last operation.
+        let mut shortcuts = BTreeMap::new();
+        for (key, _) in SHORTCUTS {
+            let action = QAction::from_q_object(&main_window);
+            if key == "shortcut_undo" {
+                action.set_shortcut_context(ShortcutContext::WidgetWithChildrenShortcut);
+                mod_list_ui.tree_view().add_action(&action);
+            } else {
+                main_window.add_action(&action);
+            }
+
+            shortcuts.insert(key.to_owned(), action);
+        }
+
+        // Sync programs usually write files more than once in a row, so we wait a bit before checking the changes.
+        let config_watcher = QFileSystemWatcher::new_1a(&main_window);
+        let config_watcher_timer = QTimer::new_1a(&main_window);
+        config_watcher_timer.set_single_shot(true);
+        config_watcher_timer.set_interval(1000);
+
+        // Other launchers may rewrite the mod list files while we're open. As with the sync folder, we wait a bit before checking the changes.
+        let mod_list_watcher = QFileSystemWatcher::new_1a(&main_window);
+        let mod_list_watcher_timer = QTimer::new_1a(&main_window);
+        mod_list_watcher_timer.set_single_shot(true);
+        mod_list_watcher_timer.set_interval(1000);
+
+        // This one is non-modal, so the user can check the mod list before deciding what to do.
+        let mod_list_changed_dialog = QMessageBox::from_q_widget(&main_window);
+        mod_list_changed_dialog.set_window_modality(WindowModality::NonModal);
+        mod_list_changed_dialog.set_window_title(&qtr("mod_list_changed_title"));
+        mod_list_changed_dialog.set_icon(q_message_box::Icon::Warning);
+        let mod_list_import_button = mod_list_changed_dialog.add_button_q_string_button_role(&qtr("mod_list_changed_import"), q_message_box::ButtonRole::AcceptRole);
+        let mod_list_overwrite_button = mod_list_changed_dialog.add_button_q_string_button_role(&qtr("mod_list_changed_overwrite"), q_message_box::ButtonRole::DestructiveRole);
+        mod_list_changed_dialog.add_button_q_string_button_role(&qtr("mod_list_changed_ignore"), q_message_box::ButtonRole::RejectRole);
+
+        // Timer to check for changes in the OS theme, so we can follow it.
+        let theme_timer = QTimer::new_1a(&main_window);
+        theme_timer.set_interval(10000);
+
+        // Timer to check for Workshop changes while in tray mode.
+        let workshop_monitor_timer = QTimer::new_1a(&main_window);
+        workshop_monitor_timer.set_interval(WORKSHOP_MONITOR_INTERVAL);
+
+        // Timer to check if the Workshop scan in progress has finished.
+        let workshop_monitor_poll_timer = QTimer::new_1a(&main_window);
+        workshop_monitor_poll_timer.set_interval(WORKSHOP_MONITOR_POLL_INTERVAL);
+
+        // Timer to answer the requests of the local API from the UI thread.
+        let api_timer = QTimer::new_1a(&main_window);
+        api_timer.set_interval(API_POLL_INTERVAL);
+
+        // Timer to check if the network is back while offline mode is detected.
+        let offline_mode_timer = QTimer::new_1a(&main_window);
+        offline_mode_timer.set_interval(OFFLINE_MODE_CHECK_INTERVAL);
+
+        // Timer to check if the offline mode detection in progress has finished.
+        let offline_mode_poll_timer = QTimer::new_1a(&main_window);
+        offline_mode_poll_timer.set_interval(OFFLINE_MODE_POLL_INTERVAL);
+
         let app_ui = Rc::new(Self {
 
             //-------------------------------------------------------------------------------//
             // Main Window.
             //-------------------------------------------------------------------------------//
             main_window,
+            main_splitter,
             right_tabbar,
 
             github_button,
@@ -376,6 +659,7 @@ impl AppUI {
             patreon_button,
             about_runcher_button,
             check_updates_button,
+            offline_label,
 
             //-------------------------------------------------------------------------------//
             // "Game Selected" menu.
@@ -393,9 +677,16 @@ impl AppUI {
             game_selected_shogun_2,
             game_selected_napoleon,
             game_selected_empire,
+            game_selected_new_games,
 
             game_selected_group,
 
+            game_selected_bar,
+            game_selected_context_menu,
+            game_selected_open_in_new_window,
+            game_selected_migrate_from_sibling,
+            game_selected_import_categories,
+
             //-------------------------------------------------------------------------------//
             // `Actions` section.
             //-------------------------------------------------------------------------------//
@@ -416,6 +707,41 @@ impl AppUI {
             //-------------------------------------------------------------------------------//
             pack_list_ui,
 
+            //-------------------------------------------------------------------------------//
+            // Tray icon.
+            //-------------------------------------------------------------------------------//
+            tray_ui,
+
+            //-------------------------------------------------------------------------------//
+            // Shortcuts.
+            //-------------------------------------------------------------------------------//
+            shortcuts,
+
+            //-------------------------------------------------------------------------------//
+            // Sync folder watcher.
+            //-------------------------------------------------------------------------------//
+            config_watcher,
+            config_watcher_timer,
+            mod_list_watcher,
+            mod_list_watcher_timer,
+            mod_list_changed_dialog,
+            mod_list_import_button,
+            mod_list_overwrite_button,
+            mod_list_known_contents: Rc::new(RwLock::new(HashMap::new())),
+            mod_list_changed_path: Rc::new(RwLock::new(None)),
+            theme_timer,
+
+            workshop_monitor_timer,
+            workshop_monitor_poll_timer,
+            workshop_monitor_receiver: Rc::new(RwLock::new(None)),
+            workshop_snapshots: Rc::new(RwLock::new(HashMap::new())),
+
+            api_timer,
+
+            offline_mode_timer,
+            offline_mode_poll_timer,
+            offline_mode_receiver: Rc::new(RwLock::new(None)),
+
             //-------------------------------------------------------------------------------//
             // "Extra stuff" menu.
             //-------------------------------------------------------------------------------//
@@ -427,6 +753,7 @@ impl AppUI {
             game_load_order: Arc::new(RwLock::new(LoadOrder::default())),
             game_profiles: Arc::new(RwLock::new(HashMap::new())),
             game_saves: Arc::new(RwLock::new(vec![])),
+            undo_snapshot: Rc::new(RwLock::new(None)),
 
             // NOTE: This loads arena on purpose, so ANY game selected triggers a game change properly.
             game_selected: Rc::new(RwLock::new(SUPPORTED_GAMES.game("arena").unwrap().clone())),
@@ -437,10 +764,11 @@ impl AppUI {
 
         // Initialize settings.
         init_settings(&app_ui.main_window().static_upcast());
+        app_ui.reload_shortcuts();
 
         // Disable the games we don't have a path for (uninstalled) and Shogun 2, as it's not supported yet.
         for game in SUPPORTED_GAMES.games_sorted().iter() {
-            let has_exe = game.executable_path(&setting_path(game.key())).filter(|path| path.is_file()).is_some();
+            let has_exe = executable_path(game, &setting_path(game.key())).filter(|path| path.is_file()).is_some();
             match game.key() {
                 KEY_PHARAOH_DYNASTIES => {
                     app_ui.game_selected_pharaoh_dynasties().set_enabled(has_exe);
@@ -498,13 +826,36 @@ impl AppUI {
             }
         }
 
+        app_ui.update_new_games_availability(true);
+
         // Load the correct theme.
         app_ui.reload_theme();
+        app_ui.theme_timer().start_0a();
+
+        // The API server is only started on boot, so there's no need to check this again later.
+        if setting_bool("enable_api_server") {
+            app_ui.api_timer().start_0a();
+        }
+
+        // Setup the tray icon and the Workshop monitor, if enabled.
+        app_ui.reload_tray_mode();
+
+        // Check if we need to go offline. The check is sent before the first game load, so its network requests wait for it.
+        app_ui.reload_offline_mode();
+        app_ui.offline_mode_timer().start_0a();
+
+        // Lists downloaded from our repo are updated in the background. Until then, the ones we already have are used.
+        let _ = CENTRAL_COMMAND.send_network(Command::UpdateRemoteLists);
 
         // Apply last ui state.
         app_ui.main_window().restore_geometry(&setting_byte_array("geometry"));
         app_ui.main_window().restore_state_1a(&setting_byte_array("windowState"));
 
+        // Monitors may have been unplugged since the last run, so make sure the window is still on one.
+        if QGuiApplication::screen_at(&app_ui.main_window().geometry().center()).is_null() {
+            app_ui.main_window().restore_geometry(&setting_byte_array("originalGeometry"));
+        }
+
         // Default the right tabs to the pack list.
         app_ui.right_tabbar().set_current_index(1);
 
@@ -527,8 +878,8 @@ impl AppUI {
         // NOTE: This exits if autostart param is passed, or if you pass invalid params,
         // so we don't need to load anything regarthing the UI.
         match Cli::parse_args(&app_ui) {
-            Ok((autostart, network_receiver)) => if autostart {
-                exit(0);
+            Ok((exit_code, network_receiver)) => if let Some(exit_code) = exit_code {
+                exit(exit_code);
             } else {
 
                 // Ignore network errors.
@@ -539,6 +890,11 @@ impl AppUI {
             Err(error) => show_dialog(app_ui.main_window(), error, false),
         }
 
+        // Ask for consent to send error reports before anything gets sent.
+        if !setting_bool("sentry_consent_asked") {
+            app_ui.ask_sentry_consent()?;
+        }
+
         // Check for updates.
         UpdaterUI::new_with_precheck(&app_ui)?;
 
@@ -547,6 +903,10 @@ impl AppUI {
 
     pub unsafe fn set_connections(&self, slots: &AppUISlots) {
         self.actions_ui().play_button().released().connect(slots.launch_game());
+        self.tray_ui().restore().triggered().connect(slots.restore_from_tray());
+        self.tray_ui().quit().triggered().connect(slots.quit_from_tray());
+        self.tray_ui().tray_icon().activated().connect(slots.tray_activated());
+        self.tray_ui().launch_menu().triggered().connect(slots.launch_from_tray());
         self.actions_ui().enable_logging_checkbox().toggled().connect(slots.toggle_logging());
         self.actions_ui().enable_skip_intro_checkbox().toggled().connect(slots.toggle_skip_intros());
         self.actions_ui().remove_trait_limit_checkbox().toggled().connect(slots.toggle_remove_trait_limit());
@@ -559,17 +919,40 @@ impl AppUI {
         self.actions_ui().open_game_root_folder().triggered().connect(slots.open_game_root_folder());
         self.actions_ui().open_game_data_folder().triggered().connect(slots.open_game_data_folder());
         self.actions_ui().open_game_content_folder().triggered().connect(slots.open_game_content_folder());
+        self.actions_ui().folders_button().menu().about_to_show().connect(slots.update_folders_menu());
         self.actions_ui().open_game_secondary_folder().triggered().connect(slots.open_game_secondary_folder());
+        self.actions_ui().open_game_masks_folder().triggered().connect(slots.open_game_masks_folder());
+        self.actions_ui().open_game_temp_packs_folder().triggered().connect(slots.open_game_temp_packs_folder());
         self.actions_ui().open_game_config_folder().triggered().connect(slots.open_game_config_folder());
         self.actions_ui().open_runcher_config_folder().triggered().connect(slots.open_runcher_config_folder());
         self.actions_ui().open_runcher_error_folder().triggered().connect(slots.open_runcher_error_folder());
+        self.actions_ui().open_runcher_locale_folder().triggered().connect(slots.open_runcher_locale_folder());
+        self.actions_ui().open_runcher_log_viewer().triggered().connect(slots.open_runcher_log_viewer());
+        self.actions_ui().inspect_diagnostic_bundle().triggered().connect(slots.inspect_diagnostic_bundle());
         self.actions_ui().copy_load_order_button().released().connect(slots.copy_load_order());
         self.actions_ui().paste_load_order_button().released().connect(slots.paste_load_order());
         self.actions_ui().reload_button().released().connect(slots.reload());
+        self.actions_ui().refresh_workshop_data().triggered().connect(slots.refresh_workshop_data());
+        self.actions_ui().rescan_data_folder().triggered().connect(slots.rescan_data_folder());
+        self.actions_ui().rescan_secondary_folder().triggered().connect(slots.rescan_secondary_folder());
         self.actions_ui().download_subscribed_mods_button().released().connect(slots.download_subscribed_mods());
         self.actions_ui().profile_load_button().released().connect(slots.load_profile());
         self.actions_ui().profile_save_button().released().connect(slots.save_profile());
+        self.actions_ui().profile_from_save_button().released().connect(slots.save_profile_from_save());
+        self.actions_ui().profile_lock_button().toggled().connect(slots.toggle_profile_lock());
         self.actions_ui().profile_manager_button().released().connect(slots.open_profile_manager());
+        self.actions_ui().load_order_slot_load_button().released().connect(slots.load_load_order_slot());
+        self.actions_ui().load_order_slot_save_button().released().connect(slots.save_load_order_slot());
+        self.actions_ui().load_order_slot_delete_button().released().connect(slots.delete_load_order_slot());
+        self.game_selected_bar().custom_context_menu_requested().connect(slots.game_selected_context_menu());
+        self.pack_list_ui().move_to_top().triggered().connect(slots.pack_move_to_top());
+        self.pack_list_ui().move_to_bottom().triggered().connect(slots.pack_move_to_bottom());
+        self.pack_list_ui().move_to_position().triggered().connect(slots.pack_move_to_position());
+        self.pack_list_ui().import_order().triggered().connect(slots.pack_import_order());
+        self.pack_list_ui().export_launch_manifest().triggered().connect(slots.pack_export_launch_manifest());
+        self.pack_list_ui().group_lock().triggered().connect(slots.pack_group_lock());
+        self.pack_list_ui().conflict_suggestions().triggered().connect(slots.pack_conflict_suggestions());
+        self.pack_list_ui().group_unlock().triggered().connect(slots.pack_group_unlock());
 
         self.game_selected_pharaoh_dynasties().triggered().connect(slots.change_game_selected());
         self.game_selected_pharaoh().triggered().connect(slots.change_game_selected());
@@ -584,6 +967,9 @@ impl AppUI {
         self.game_selected_shogun_2().triggered().connect(slots.change_game_selected());
         self.game_selected_napoleon().triggered().connect(slots.change_game_selected());
         self.game_selected_empire().triggered().connect(slots.change_game_selected());
+        for action in self.game_selected_new_games() {
+            action.triggered().connect(slots.change_game_selected());
+        }
 
         self.about_runcher_button().released().connect(slots.about_runcher());
         self.check_updates_button().released().connect(slots.check_updates());
@@ -595,23 +981,106 @@ impl AppUI {
         self.mod_list_ui().model().item_changed().connect(slots.update_pack_list());
         self.mod_list_ui().upload_to_workshop().triggered().connect(slots.upload_to_workshop());
         self.mod_list_ui().download_from_workshop().triggered().connect(slots.download_from_workshop());
+        self.mod_list_ui().search_workshop().triggered().connect(slots.search_workshop());
+        self.mod_list_ui().force_redownload().triggered().connect(slots.force_redownload());
+        self.mod_list_ui().update_workshop_items_now().triggered().connect(slots.update_workshop_items_now());
+        self.mod_list_ui().defer_workshop_updates().triggered().connect(slots.defer_workshop_updates());
+        self.mod_list_ui().add_from_github().triggered().connect(slots.add_from_github());
+        self.mod_list_ui().update_from_github().triggered().connect(slots.update_from_github());
         self.mod_list_ui().context_menu().about_to_show().connect(slots.mod_list_context_menu_open());
         self.mod_list_ui().enable_selected().triggered().connect(slots.enable_selected());
         self.mod_list_ui().disable_selected().triggered().connect(slots.disable_selected());
+        self.mod_list_ui().ignore_mods().triggered().connect(slots.ignore_mods());
         self.mod_list_ui().category_new().triggered().connect(slots.category_create());
         self.mod_list_ui().category_delete().triggered().connect(slots.category_delete());
         self.mod_list_ui().category_rename().triggered().connect(slots.category_rename());
         self.mod_list_ui().category_sort().triggered().connect(slots.category_sort());
+        self.mod_list_ui().category_style().triggered().connect(slots.category_style());
+        self.mod_list_ui().category_rules().triggered().connect(slots.category_rules());
+        self.mod_list_ui().grouping_group().triggered().connect(slots.change_mod_list_grouping());
+        self.mod_list_ui().show_ignored_mods().triggered().connect(slots.toggle_show_ignored_mods());
         draggable_tree_view_drop_signal(self.mod_list_ui().tree_view().static_upcast()).connect(slots.category_move());
 
         self.mod_list_ui().copy_to_secondary().triggered().connect(slots.copy_to_secondary());
+        self.mod_list_ui().refresh_secondary_copy().triggered().connect(slots.refresh_secondary_copy());
+        self.mod_list_ui().find_shadowed_packs().triggered().connect(slots.find_shadowed_packs());
+        self.mod_list_ui().find_duplicate_mods().triggered().connect(slots.find_duplicate_mods());
+        self.mod_list_ui().add_known_issue().triggered().connect(slots.add_known_issue());
+        self.mod_list_ui().rebuild_masks().triggered().connect(slots.rebuild_masks());
         self.mod_list_ui().move_to_secondary().triggered().connect(slots.move_to_secondary());
+        self.mod_list_ui().treat_as_mod_pack().triggered().connect(slots.treat_as_mod_pack());
+        self.mod_list_ui().export_map_bin().triggered().connect(slots.export_map_bin());
+        self.mod_list_ui().extract_pack().triggered().connect(slots.extract_mod_pack());
+        self.mod_list_ui().rescan_mod().triggered().connect(slots.rescan_mod());
+        self.mod_list_ui().set_working_folder().triggered().connect(slots.set_working_folder());
+        self.mod_list_ui().use_working_folder().triggered().connect(slots.use_working_folder());
+        self.mod_list_ui().backup_selected_mods().triggered().connect(slots.backup_selected_mods());
+        self.mod_list_ui().restore_mods_backup().triggered().connect(slots.restore_mods_backup());
+        self.mod_list_ui().export_mod_report().triggered().connect(slots.export_mod_report());
 
         self.pack_list_ui().automatic_order_button().toggled().connect(slots.pack_toggle_auto_sorting());
+        self.pack_list_ui().show_vanilla_packs_button().toggled().connect(slots.pack_toggle_show_vanilla_packs());
         draggable_tree_view_drop_signal(self.pack_list_ui().tree_view().static_upcast()).connect(slots.pack_move());
 
         self.data_list_ui().reload_button().released().connect(slots.data_view_reload());
+        self.data_list_ui().delta_button().toggled().connect(slots.data_view_reload());
         self.data_list_ui().tree_view().double_clicked().connect(slots.open_file_with_rpfm());
+        self.data_list_ui().reveal_in_mod_list().triggered().connect(slots.reveal_data_file_in_mod_list());
+        self.data_list_ui().open_table_in_rpfm().triggered().connect(slots.open_table_with_rpfm());
+        self.pack_list_ui().reveal_in_mod_list().triggered().connect(slots.reveal_pack_in_mod_list());
+        self.pack_list_ui().extract_pack().triggered().connect(slots.extract_pack_list_pack());
+        self.mod_list_ui().reveal_in_pack_list().triggered().connect(slots.reveal_mod_in_pack_list());
+        self.mod_list_ui().tree_view().double_clicked().connect(slots.mod_list_double_clicked());
+        launcher_window_files_dropped_signal(self.main_window().static_upcast()).connect(slots.install_dropped_packs());
+
+        self.shortcuts()["shortcut_launch_game"].triggered().connect(slots.launch_game());
+        self.shortcuts()["shortcut_reload"].triggered().connect(slots.reload());
+        self.shortcuts()["shortcut_next_game"].triggered().connect(slots.next_game());
+        self.shortcuts()["shortcut_previous_game"].triggered().connect(slots.previous_game());
+        self.shortcuts()["shortcut_toggle_selected_mods"].triggered().connect(slots.toggle_selected_mods());
+        self.shortcuts()["shortcut_focus_filter"].triggered().connect(slots.focus_filter());
+        self.shortcuts()["shortcut_move_pack_up"].triggered().connect(slots.move_pack_up());
+        self.shortcuts()["shortcut_move_pack_down"].triggered().connect(slots.move_pack_down());
+        self.shortcuts()["shortcut_undo"].triggered().connect(slots.undo_last_operation());
+        self.actions_ui().undo_button().released().connect(slots.undo_last_operation());
+        self.actions_ui().export_to_ca_launcher().triggered().connect(slots.export_to_ca_launcher());
+        self.actions_ui().import_from_ca_launcher().triggered().connect(slots.import_from_ca_launcher());
+        self.actions_ui().open_sessions().triggered().connect(slots.open_sessions());
+        self.actions_ui().update_translations().triggered().connect(slots.update_translations());
+        self.actions_ui().translation_coverage().triggered().connect(slots.translation_coverage());
+        self.actions_ui().export_tweaks_as_pack().triggered().connect(slots.export_tweaks_as_pack());
+        self.actions_ui().open_bughunt().triggered().connect(slots.open_bughunt());
+        self.actions_ui().main_widget().custom_context_menu_requested().connect(slots.actions_context_menu());
+        self.actions_ui().customize_actions().triggered().connect(slots.customize_actions());
+        self.actions_ui().pin_profile().triggered().connect(slots.pin_profile());
+        self.actions_ui().pin_save().triggered().connect(slots.pin_save());
+        self.actions_ui().restore_save_backup().triggered().connect(slots.restore_save_backup());
+        self.actions_ui().fingerprint_button().released().connect(slots.copy_fingerprint());
+        self.actions_ui().copy_fingerprint_manifest().triggered().connect(slots.copy_fingerprint_manifest());
+        self.actions_ui().compare_fingerprint_manifests().triggered().connect(slots.compare_fingerprint_manifests());
+        self.actions_ui().freeze_campaign_integrity().triggered().connect(slots.freeze_campaign_integrity());
+        self.actions_ui().verify_campaign_integrity().triggered().connect(slots.verify_campaign_integrity());
+
+        self.config_watcher().file_changed().connect(slots.config_files_changed());
+        self.config_watcher().directory_changed().connect(slots.config_files_changed());
+        self.config_watcher_timer().timeout().connect(slots.check_config_files_changes());
+        self.mod_list_watcher().file_changed().connect(slots.mod_list_files_changed());
+        self.mod_list_watcher_timer().timeout().connect(slots.check_mod_list_files_changes());
+        self.mod_list_import_button().released().connect(slots.import_mod_list_file_order());
+        self.mod_list_overwrite_button().released().connect(slots.overwrite_mod_list_file());
+        self.theme_timer().timeout().connect(slots.check_system_theme());
+        self.workshop_monitor_timer().timeout().connect(slots.check_workshop_changes());
+        self.workshop_monitor_poll_timer().timeout().connect(slots.receive_workshop_changes());
+        self.api_timer().timeout().connect(slots.handle_api_requests());
+        self.offline_mode_timer().timeout().connect(slots.check_offline_mode());
+        self.offline_mode_poll_timer().timeout().connect(slots.receive_offline_mode());
+    }
+
+    /// This function reloads the key sequences of all the shortcuts from the settings.
+    pub unsafe fn reload_shortcuts(&self) {
+        for (key, action) in self.shortcuts() {
+            action.set_shortcut(&QKeySequence::from_q_string(&QString::from_std_str(setting_string(key))));
+        }
     }
 
     /// Function to toggle the main window on and off, while keeping the stupid focus from breaking.
@@ -654,20 +1123,46 @@ impl AppUI {
     pub unsafe fn change_game_selected(&self, reload_same_game: bool, skip_network_update: bool) -> Result<Option<Receiver<Response>>> {
 
         // Get the new `Game Selected` and clean his name up, so it ends up like "x_y".
-        let mut new_game_selected = self.game_selected_group.checked_action().text().to_std_string();
-        if let Some(index) = new_game_selected.find('&') { new_game_selected.remove(index); }
-        let new_game_selected = new_game_selected.replace(' ', "_").to_lowercase();
+        //
+        // New games defined by the user load their base game, with their definition on top.
+        let action_game_key = Self::game_key_from_action(&self.game_selected_group.checked_action());
+        let new_game_selected = base_game_key(&action_game_key);
+        let new_game = if action_game_key != new_game_selected { Some(action_game_key.to_owned()) } else { None };
+        let new_game_changed = active_new_game(&new_game_selected) != new_game;
 
         // If the game changed or we're initializing the program, change the game selected.
         //
         // This works because by default, the initially stored game selected is arena, and that one can never set manually.
-        if reload_same_game || new_game_selected != self.game_selected().read().unwrap().key() {
+        if reload_same_game || new_game_changed || new_game_selected != self.game_selected().read().unwrap().key() {
+            let old_game_selected = self.game_selected().read().unwrap().key().to_owned();
+            let is_startup = old_game_selected == KEY_ARENA;
+
+            // Two windows with the same game loaded overwrite each other's configs, so don't switch to a game loaded in another window.
+            if !is_startup && new_game_selected != old_game_selected {
+                if let Some(pid) = self.game_loaded_elsewhere(&new_game_selected) {
+                    let old_action_game_key = active_new_game(&old_game_selected).unwrap_or_else(|| old_game_selected.to_owned());
+                    if let Some(action) = self.available_game_selected_actions().into_iter().find(|action| Self::game_key_from_action(action) == old_action_game_key) {
+                        action.set_checked(true);
+                    }
+
+                    return Err(anyhow!(tre("game_loaded_in_other_window", &[&pid.to_string()])));
+                }
+            }
+
+            self.save_ui_state();
             self.toggle_main_window(false);
 
             let event_loop = qt_core::QEventLoop::new_0a();
             event_loop.process_events_0a();
 
+            set_active_new_game(&new_game_selected, new_game.as_deref());
             let result = self.load_data(&new_game_selected, skip_network_update);
+            self.restore_ui_state(&new_game_selected, is_startup);
+
+            // Remember the game, so it can be loaded on the next start.
+            if result.is_ok() {
+                set_setting_string("last_game_selected", &action_game_key);
+            }
 
             self.toggle_main_window(true);
             result
@@ -676,6 +1171,289 @@ impl AppUI {
         }
     }
 
+    /// This function saves the state of the UI for the game selected, so it can be restored the next time the game is selected.
+    pub unsafe fn save_ui_state(&self) {
+        let game_key = self.game_selected().read().unwrap().key().to_owned();
+
+        // The initial game is only a placeholder until the real one is loaded, so it has no state.
+        if game_key == KEY_ARENA {
+            return;
+        }
+
+        // The customization of the Actions panel is not part of the current state of the UI, so we keep the saved one.
+        let mut state = UIState::load(&game_key).unwrap_or_default();
+        let geometry = self.main_window().normal_geometry();
+        state.set_window_geometry(Some(WindowGeometry::new(geometry.x(), geometry.y(), geometry.width(), geometry.height(), self.main_window().is_maximized())));
+
+        let sizes = self.main_splitter().sizes();
+        state.set_splitter_sizes((0..sizes.count_0a()).map(|index| *sizes.at(index)).collect());
+        state.set_right_tab(self.right_tabbar().current_index());
+
+        state.set_mod_list_column_widths(column_widths(self.mod_list_ui().tree_view()));
+        state.set_pack_list_column_widths(column_widths(self.pack_list_ui().tree_view()));
+        state.set_data_list_column_widths(column_widths(self.data_list_ui().tree_view()));
+
+        let model = self.mod_list_ui().model();
+        state.set_expanded_categories((0..model.row_count_0a())
+            .map(|row| model.item_1a(row))
+            .filter(|item| self.mod_list_ui().tree_view().is_expanded(&self.mod_list_ui().filter().map_from_source(&item.index())))
+            .map(|item| item.text().to_std_string())
+            .collect());
+
+        if let Err(error) = state.save(&game_key) {
+            error!("Error saving the UI state of {}: {}", game_key, error);
+        }
+    }
+
+    /// This function restores the state of the UI saved for the provided game, if any.
+    ///
+    /// The window is only moved on start, and only if the saved position is still on a screen, as monitors may have been unplugged since.
+    pub unsafe fn restore_ui_state(&self, game_key: &str, restore_window: bool) {
+        let state = match UIState::load(game_key) {
+            Ok(state) => state,
+            Err(error) => {
+                error!("Error loading the UI state of {}: {}", game_key, error);
+                return;
+            }
+        };
+
+        self.actions_ui().apply_layout(state.hidden_actions(), state.actions_order());
+
+        // Nothing has been saved for this game yet.
+        let geometry = match state.window_geometry() {
+            Some(geometry) => geometry,
+            None => return,
+        };
+
+        if restore_window {
+            let center = QPoint::new_2a(geometry.x() + geometry.width() / 2, geometry.y() + geometry.height() / 2);
+            if !QGuiApplication::screen_at(&center).is_null() {
+                self.main_window().set_geometry_4a(*geometry.x(), *geometry.y(), *geometry.width(), *geometry.height());
+                if *geometry.maximized() {
+                    self.main_window().set_window_state(QFlags::from(WindowState::WindowMaximized));
+                }
+            }
+        }
+
+        if !state.splitter_sizes().is_empty() && state.splitter_sizes().iter().all(|size| *size > 0) {
+            let sizes = QListOfInt::new();
+            for size in state.splitter_sizes() {
+                sizes.append_int(size);
+            }
+
+            self.main_splitter().set_sizes(&sizes);
+        }
+
+        if *state.right_tab() >= 0 && *state.right_tab() < self.right_tabbar().count() {
+            self.right_tabbar().set_current_index(*state.right_tab());
+        }
+
+        set_column_widths(self.mod_list_ui().tree_view(), state.mod_list_column_widths());
+        set_column_widths(self.pack_list_ui().tree_view(), state.pack_list_column_widths());
+        set_column_widths(self.data_list_ui().tree_view(), state.data_list_column_widths());
+
+        let model = self.mod_list_ui().model();
+        for row in 0..model.row_count_0a() {
+            let item = model.item_1a(row);
+            let index = self.mod_list_ui().filter().map_from_source(&item.index());
+            let expanded = state.expanded_categories().contains(&item.text().to_std_string());
+            self.mod_list_ui().tree_view().set_expanded(&index, expanded);
+        }
+    }
+
+    /// This function returns the key of the game corresponding to the provided `Game Selected` action.
+    /// This function enables the new games defined by the user we have an executable for. On start, it also hides the ones we don't.
+    pub unsafe fn update_new_games_availability(&self, update_visibility: bool) {
+        for action in self.game_selected_new_games() {
+            let game_key = Self::game_key_from_action(action);
+            let has_exe = GameDefinitions::cached().games().get(&game_key)
+                .zip(SUPPORTED_GAMES.game(&base_game_key(&game_key)))
+                .and_then(|(definition, game)| definition.executable_path(game, &setting_path(game.key())))
+                .is_some_and(|path| path.is_file());
+
+            action.set_enabled(has_exe);
+            if update_visibility {
+                action.set_visible(has_exe);
+            }
+        }
+    }
+
+    pub unsafe fn game_key_from_action(action: &QPtr<QAction>) -> String {
+        let game_key = action.data().to_string().to_std_string();
+        if !game_key.is_empty() {
+            return game_key;
+        }
+
+        let mut game_key = action.text().to_std_string();
+        if let Some(index) = game_key.find('&') { game_key.remove(index); }
+        game_key.replace(' ', "_").to_lowercase()
+    }
+
+    /// This function opens a new instance of Runcher with the provided game selected, so more than one game can be managed at the same time.
+    ///
+    /// Games already loaded in a window cannot be opened again, as both windows would overwrite each other's configs.
+    pub unsafe fn open_game_in_new_window(&self, game_key: &str) -> Result<()> {
+        if game_key == self.game_selected().read().unwrap().key() {
+            return Err(anyhow!(tr("game_loaded_in_this_window")));
+        }
+
+        if let Some(pid) = self.game_loaded_elsewhere(game_key) {
+            return Err(anyhow!(tre("game_loaded_in_other_window", &[&pid.to_string()])));
+        }
+
+        std::process::Command::new(std::env::current_exe()?)
+            .arg("--game")
+            .arg(game_key)
+            .spawn()?;
+
+        Ok(())
+    }
+
+    /// This function migrates the config of the provided game to the game selected.
+    ///
+    /// Each mod used in the provided game is mapped to an installed mod of the game selected, and the user can fix the mapping before migrating.
+    /// Only games of the same family can migrate everything. Other games, like the one open in another window, can only share their category layout.
+    pub unsafe fn migrate_from_game(&self, source_key: &str, categories_only: bool) -> Result<()> {
+        self.check_profile_lock()?;
+
+        let game = self.game_selected().read().unwrap().clone();
+        if source_key == game.key() || (!categories_only && !sibling_games(game.key()).contains(&source_key)) {
+            return Ok(());
+        }
+
+        let source = SUPPORTED_GAMES.game(source_key).ok_or_else(|| anyhow!("Game {} not supported.", source_key))?;
+        let source_config = GameConfig::load(source, false).map_err(|_| anyhow!(tre("game_family_no_config", &[source.display_name()])))?;
+        let source_load_order = LoadOrder::load(source).unwrap_or_default();
+        let source_profiles = if categories_only { HashMap::new() } else { Profile::profiles_for_game(source)?.0 };
+
+        let (mappings, mod_names) = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => {
+                let mappings = mod_mappings(&source_config, &source_profiles, game_config);
+                let mod_names = mappings.iter()
+                    .flat_map(|mapping| mapping.candidates().iter())
+                    .filter_map(|(mod_id, _)| game_config.mods().get(mod_id))
+                    .map(|modd| (modd.id().to_owned(), modd.name().to_owned()))
+                    .collect::<HashMap<_, _>>();
+
+                (mappings, mod_names)
+            }
+            None => return Err(anyhow!(tr("game_config_error"))),
+        };
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { GAME_FAMILY_MIGRATION_VIEW_DEBUG } else { GAME_FAMILY_MIGRATION_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+        dialog.set_window_title(&qtre("game_family_migration_title", &[source.display_name(), game.display_name()]));
+
+        let info_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "info_label")?;
+        let categories_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "categories_checkbox")?;
+        let mods_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "mods_checkbox")?;
+        let profiles_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "profiles_checkbox")?;
+        let mappings_table_view: QPtr<QTableView> = find_widget(&main_widget.static_upcast(), "mappings_table_view")?;
+        info_label.set_text(&qtre("game_family_migration_info", &[source.display_name(), game.display_name(), game.display_name(), source.display_name()]));
+        categories_checkbox.set_text(&qtr("game_family_migration_categories"));
+        mods_checkbox.set_text(&qtr("game_family_migration_mods"));
+        profiles_checkbox.set_text(&qtr("game_family_migration_profiles"));
+        profiles_checkbox.set_enabled(!source_profiles.is_empty());
+        profiles_checkbox.set_checked(!source_profiles.is_empty());
+
+        if categories_only {
+            dialog.set_window_title(&qtre("import_categories_title", &[source.display_name(), game.display_name()]));
+            info_label.set_text(&qtre("import_categories_info", &[source.display_name(), game.display_name(), game.display_name(), source.display_name()]));
+            categories_checkbox.set_checked(true);
+            categories_checkbox.set_enabled(false);
+            mods_checkbox.set_checked(false);
+            mods_checkbox.set_visible(false);
+            profiles_checkbox.set_checked(false);
+            profiles_checkbox.set_visible(false);
+        }
+
+        let model = QStandardItemModel::new_1a(&dialog);
+        mappings_table_view.set_model(&model);
+        model.set_column_count(2);
+        model.set_horizontal_header_item(0, QStandardItem::from_q_string(&qtre("game_family_migration_column_source", &[source.display_name()])).into_ptr());
+        model.set_horizontal_header_item(1, QStandardItem::from_q_string(&qtre("game_family_migration_column_target", &[game.display_name()])).into_ptr());
+
+        for (row, mapping) in mappings.iter().enumerate() {
+            let items = QListOfQStandardItem::new();
+            let item_name = QStandardItem::from_q_string(&QString::from_std_str(format!("{} ({})", mapping.source_name(), mapping.source_id())));
+            if mapping.best().is_none() {
+                item_name.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("data-warning")));
+                item_name.set_tool_tip(&qtr("game_family_migration_unmapped"));
+            }
+
+            items.append_q_standard_item(&item_name.into_ptr().as_mut_raw_ptr());
+            items.append_q_standard_item(&QStandardItem::new().into_ptr().as_mut_raw_ptr());
+            model.append_row_q_list_of_q_standard_item(items.into_ptr().as_ref().unwrap());
+
+            let mod_combobox = QComboBox::new_1a(&mappings_table_view);
+            mod_combobox.add_item_q_string(&qtr("name_matches_skip"));
+            for (mod_id, score) in mapping.candidates() {
+                let text = match mod_names.get(mod_id) {
+                    Some(name) if !name.is_empty() => format!("{} ({}) - {:.0}%", name, mod_id, score * 100.0),
+                    _ => format!("{} - {:.0}%", mod_id, score * 100.0),
+                };
+
+                mod_combobox.add_item_q_string_q_variant(&QString::from_std_str(text), &QVariant::from_q_string(&QString::from_std_str(mod_id)));
+            }
+
+            let best = mapping.best().as_ref().and_then(|best| mapping.candidates().iter().position(|(mod_id, _)| mod_id == best));
+            mod_combobox.set_current_index(best.map(|index| index as i32 + 1).unwrap_or_default());
+            mappings_table_view.set_index_widget(&model.index_2a(row as i32, 1), mod_combobox.into_ptr());
+        }
+
+        mappings_table_view.resize_columns_to_contents();
+
+        if dialog.exec() != DialogCode::Accepted.to_int() {
+            return Ok(());
+        }
+
+        let migration = FamilyMigration {
+            categories: categories_checkbox.is_checked(),
+            mods: mods_checkbox.is_checked() && !categories_only,
+            profiles: profiles_checkbox.is_checked() && profiles_checkbox.is_enabled() && !categories_only,
+        };
+
+        if !migration.categories && !migration.mods && !migration.profiles {
+            return Ok(());
+        }
+
+        let mut mapping = HashMap::new();
+        for (row, mod_mapping) in mappings.iter().enumerate() {
+            let mod_combobox: QPtr<QComboBox> = mappings_table_view.index_widget(&model.index_2a(row as i32, 1)).static_downcast();
+            let mod_id = mod_combobox.current_data_0a().to_string().to_std_string();
+            if !mod_id.is_empty() {
+                mapping.insert(mod_mapping.source_id().to_owned(), mod_id);
+            }
+        }
+
+        if migration.categories || migration.mods {
+            self.take_undo_snapshot(if categories_only { "undo_operation_import_categories" } else { "undo_operation_migrate_from_sibling_game" });
+
+            if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+                let mut load_order = self.game_load_order().write().unwrap();
+                migrate_game_config(&source_config, &source_load_order, game_config, &mut load_order, &mapping, &migration);
+
+                game_config.save(&game)?;
+                load_order.save(&game)?;
+            }
+        }
+
+        if migration.profiles {
+            let (target_profiles, _) = Profile::profiles_for_game(&game)?;
+            for mut profile in migrate_profiles(&source_profiles, &target_profiles, game.key(), &mapping) {
+                let profile_name = profile.id().to_owned();
+                profile.save(&game, &profile_name)?;
+            }
+        }
+
+        // The reload loads the config from disk, so it picks up the migrated one.
+        self.actions_ui().reload_button().click();
+
+        Ok(())
+    }
+
     pub unsafe fn load_data(&self, game: &str, skip_network_update: bool) -> Result<Option<Receiver<Response>>> {
 
         // We may receive invalid games here, so rule out the invalid ones.
@@ -683,34 +1461,48 @@ impl AppUI {
             Some(game) => {
 
                 // Schemas are optional, so don't interrupt loading due to they not being present.
-                let schema_path = schemas_path().unwrap().join(game.schema_file_name());
+                let schema_path = schemas_path().unwrap().join(schema_file_name(game));
                 *SCHEMA.write().unwrap() = Schema::load(&schema_path, None).ok();
                 *self.game_selected().write().unwrap() = game.clone();
 
-                // Trigger an update of all game configs, just in case one needs update.
-                let _ = GameConfig::update(game.key());
-
                 // Load the game's config and last known load order.
                 *self.game_load_order().write().unwrap() = LoadOrder::load(game).unwrap_or_else(|_| Default::default());
-                *self.game_config().write().unwrap() = Some(GameConfig::load(game, true)?);
+                let (game_config, recovery) = GameConfig::load_or_recover(game)?;
+                *self.game_config().write().unwrap() = Some(game_config);
+
+                if let Some(recovery) = recovery {
+                    self.show_config_recovery(&recovery);
+                }
 
                 // Trigger an update of all game profiles, just in case one needs update.
                 let _ = Profile::update(&self.game_config().read().unwrap().clone().unwrap(), game);
 
-                // Load the profile's list.
-                match Profile::profiles_for_game(game) {
-                    Ok(profiles) => *self.game_profiles().write().unwrap() = profiles,
-                    Err(error) => show_dialog(self.main_window(), format!("Error loading profiles: {}", error), false),
-                }
+                // Load the profile's list, and the load order slots.
+                self.reload_profiles_list(game);
+                self.reload_load_order_slots_list();
 
-                self.actions_ui().profile_model().clear();
-                for profile in self.game_profiles().read().unwrap().keys().sorted() {
-                    self.actions_ui().profile_combobox().add_item_q_string(&QString::from_std_str(profile));
-                }
+                // Watch the config files of the game, in case they're changed from the sync folder.
+                self.update_config_watcher();
 
                 // Load the launch options for the game selected.
                 let game_path_str = setting_string(game.key());
                 let game_path = PathBuf::from(&game_path_str);
+
+                // Check for other managers before claiming the game, or we'd miss other instances of Runcher with it loaded.
+                // Autostarts have no UI to show the warning in, and the main window is only shown when not autostarting.
+                let conflicts = self.manager_conflicts(game, &game_path);
+                if let Err(error) = lock_game(game.key()) {
+                    info!("Failed to lock the game {}: {}", game.key(), error);
+                }
+
+                if !conflicts.is_empty() && setting_bool("check_other_managers") && self.main_window().is_visible() {
+                    self.show_manager_conflicts(&conflicts, false);
+                }
+
+                // Watch the mod list files of the game, in case they're changed by other launchers.
+                self.mod_list_changed_dialog().hide();
+                self.mod_list_known_contents().write().unwrap().clear();
+                self.update_mod_list_watcher(game, &game_path);
                 setup_actions(self, game, &game_path);
 
                 // Load the saves list for the selected game.
@@ -730,45 +1522,381 @@ impl AppUI {
         }
     }
 
-    pub unsafe fn load_saves_to_ui(&self, game: &GameInfo, game_path: &Path) -> Result<()> {
-        self.actions_ui().save_model().clear();
-        let item = QStandardItem::from_q_string(&QString::from_std_str("No saves"));
-        self.actions_ui().save_model().append_row_q_standard_item(item.into_ptr());
+    /// This function reloads the list of profiles of the provided game.
+    pub unsafe fn reload_profiles_list(&self, game: &GameInfo) {
+        match Profile::profiles_for_game(game) {
+            Ok((profiles, recoveries)) => {
+                *self.game_profiles().write().unwrap() = profiles;
+                recoveries.iter().for_each(|recovery| self.show_config_recovery(recovery));
+            }
+            Err(error) => show_dialog(self.main_window(), format!("Error loading profiles: {}", error), false),
+        }
 
-        // If we have a save folder for the game, read its saves and load them to the save combo.
-        if let Some(ref config_path) = game.config_path(game_path) {
-            let mut game_saves = self.game_saves.write().unwrap();
-            game_saves.clear();
+        self.update_profile_combobox(game.key());
+        self.update_profile_lock_ui();
+    }
 
-            let save_path = config_path.join("save_games");
-            if let Ok(mut saves_paths) = files_from_subdir(&save_path, false) {
+    /// This function fills the profile selector with the profiles loaded for the provided game, with the pinned ones first.
+    pub unsafe fn update_profile_combobox(&self, game_key: &str) {
+        let pinned_profiles = UIState::load(game_key).map(|state| state.pinned_profiles().to_vec()).unwrap_or_default();
+        let profiles = self.game_profiles().read().unwrap();
 
-                // Sort them by date, then reverse, so the most recent one is first.
-                saves_paths.sort_by_key(|x| x.metadata().unwrap().modified().unwrap());
-                saves_paths.reverse();
+        self.actions_ui().profile_model().clear();
+        for profile in pinned_profiles.iter().filter(|profile| profiles.contains_key(*profile)) {
+            self.actions_ui().profile_combobox().add_item_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("pin")), &QString::from_std_str(profile));
+        }
 
-                for save_path in &saves_paths {
-                    let mut save = RFile::new_from_file_path(save_path)?;
-                    save.guess_file_type()?;
+        for profile in profiles.keys().sorted().filter(|profile| !pinned_profiles.contains(profile)) {
+            self.actions_ui().profile_combobox().add_item_q_string(&QString::from_std_str(profile));
+        }
+    }
 
-                    let mut save = Save::default();
-                    save.set_path(save_path.to_path_buf());
-                    save.set_name(save_path.file_name().unwrap().to_string_lossy().to_string());
+    /// This function tells the user that a config file failed to load, where it's broken, and what could not be recovered from it.
+    pub unsafe fn show_config_recovery(&self, recovery: &ConfigRecovery) {
+        let field = if recovery.field().is_empty() {
+            tr("config_recovered_no_field")
+        } else {
+            recovery.field().to_owned()
+        };
 
-                    /*
-                    if let Some(RFileDecoded::ESF(file)) = save.decode(&None, false, true)? {
-                        let mut save = Save::default();
-                        save.set_path(save_path.to_path_buf());
-                        save.set_name(save_path.file_name().unwrap().to_string_lossy().to_string());
-                        let mut mods = vec![];
+        let mut message = tre("config_recovered", &[
+            &recovery.path().to_string_lossy(),
+            &field,
+            &recovery.position().0.to_string(),
+            &recovery.position().1.to_string(),
+            recovery.error(),
+            &recovery.backup_path().to_string_lossy(),
+        ]);
+
+        if *recovery.newer_version() {
+            message.push_str(&tr("config_recovered_newer_version"));
+        }
 
-                        let root_node = file.root_node();
-                        if let NodeType::Record(node) = root_node {
-                            if node.name() == "CAMPAIGN_SAVE_GAME" {
-                                for children in node.children() {
-                                    for child in children {
-                                        if let NodeType::Record(node) = child {
-                                            if node.name() == "SAVE_GAME_HEADER" {
+        if !*recovery.readable() {
+            message.push_str(&tr("config_recovered_unreadable"));
+        } else if !recovery.lost().is_empty() {
+            let lost = recovery.lost().iter().map(|lost| format!("<li>{}</li>", lost)).join("");
+            message.push_str(&tre("config_recovered_lost", &[&lost]));
+        }
+
+        show_dialog(self.main_window(), message, false);
+    }
+
+    /// This function updates the list of files watched for changes done outside Runcher.
+    ///
+    /// Only the files in the sync folder are watched. If there's no sync folder configured, nothing is watched.
+    pub unsafe fn update_config_watcher(&self) {
+        let files = self.config_watcher().files();
+        if !files.is_empty() {
+            self.config_watcher().remove_paths(&files);
+        }
+
+        let directories = self.config_watcher().directories();
+        if !directories.is_empty() {
+            self.config_watcher().remove_paths(&directories);
+        }
+
+        let sync_folder_path = setting_path("sync_folder_path");
+        if sync_folder_path.as_os_str().is_empty() || !sync_folder_path.is_dir() {
+            return;
+        }
+
+        for folder in [game_config_path(), profiles_path()].into_iter().flatten() {
+            if folder.is_dir() {
+                self.config_watcher().add_path(&QString::from_std_str(folder.to_string_lossy()));
+
+                // Sync programs usually replace files instead of editing them, so we need to watch both, the folder and the files.
+                if let Ok(files) = files_from_subdir(&folder, false) {
+                    for file in files {
+                        self.config_watcher().add_path(&QString::from_std_str(file.to_string_lossy()));
+                    }
+                }
+            }
+        }
+    }
+
+    /// This function checks if the config files of the current game have been changed outside Runcher, and reloads them if needed.
+    ///
+    /// If the game config or load order changed, the user is asked if they want to reload them, or keep the current ones, overwriting the changes.
+    pub unsafe fn check_config_files_changes(&self) -> Result<()> {
+
+        // If we're in the middle of another operation, like a rescan, the config in memory may be about to change. Check again later.
+        if !self.main_window().is_enabled() {
+            self.config_watcher_timer().start_0a();
+            return Ok(());
+        }
+
+        // Files replaced by the sync program are no longer watched, so re-add them.
+        self.update_config_watcher();
+
+        let game = self.game_selected().read().unwrap().clone();
+        self.reload_profiles_list(&game);
+
+        // Compare the files with what we have in memory. If they're the same, the change was done by us.
+        let game_config_changed = match GameConfig::load(&game, false) {
+            Ok(game_config_disk) => match *self.game_config().read().unwrap() {
+                Some(ref game_config) => serde_json::to_value(&game_config_disk)? != serde_json::to_value(game_config)?,
+                None => false,
+            },
+            Err(_) => false,
+        };
+
+        let load_order_changed = match LoadOrder::load(&game) {
+            Ok(load_order_disk) => serde_json::to_value(&load_order_disk)? != serde_json::to_value(&*self.game_load_order().read().unwrap())?,
+            Err(_) => false,
+        };
+
+        if !game_config_changed && !load_order_changed {
+            return Ok(());
+        }
+
+        let dialog = QMessageBox::from_q_widget(self.main_window());
+        dialog.set_window_title(&qtr("sync_conflict_title"));
+        dialog.set_icon(q_message_box::Icon::Question);
+        dialog.set_text(&qtre("sync_conflict_description", &[game.display_name()]));
+
+        let reload_button = dialog.add_button_q_string_button_role(&qtr("sync_conflict_reload"), q_message_box::ButtonRole::AcceptRole);
+        dialog.add_button_q_string_button_role(&qtr("sync_conflict_keep_current"), q_message_box::ButtonRole::RejectRole);
+        dialog.set_default_button_q_push_button(&reload_button);
+
+        // Ignore further changes while the user decides, so we don't stack dialogs.
+        self.config_watcher().block_signals(true);
+        dialog.exec();
+        self.config_watcher().block_signals(false);
+
+        if dialog.button_role(&dialog.clicked_button()) == q_message_box::ButtonRole::AcceptRole {
+            let network_receiver = self.change_game_selected(true, true)?;
+            self.update_mod_list_with_online_data(&network_receiver)?;
+        } else {
+            if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+                game_config.save(&game)?;
+            }
+
+            self.game_load_order().write().unwrap().save(&game)?;
+        }
+
+        Ok(())
+    }
+
+    /// This function returns the mod list files of the provided game that other launchers may change while we're open.
+    ///
+    /// These are our own mod list file (or user script, for older games), and the one used by the official launcher, if the game has one.
+    fn mod_list_files(game: &GameInfo, game_path: &Path) -> Vec<PathBuf> {
+        let scripts_path = match game_appdata_path(game, game_path) {
+            Some(config_path) => config_path.join("scripts"),
+            None => PathBuf::new(),
+        };
+
+        let mut paths = vec![mod_list_file_path(game, game_path, &scripts_path)];
+        if *game_quirks(game).needs_launcher_bypass() {
+            paths.push(game_path.join(VANILLA_MOD_LIST_FILE_NAME));
+        }
+
+        paths
+    }
+
+    /// This function updates the list of mod list files watched for changes done by other launchers.
+    ///
+    /// Files we didn't know about are read, so only changes done after this are reported.
+    pub unsafe fn update_mod_list_watcher(&self, game: &GameInfo, game_path: &Path) {
+        let files = self.mod_list_watcher().files();
+        if !files.is_empty() {
+            self.mod_list_watcher().remove_paths(&files);
+        }
+
+        if game_path.as_os_str().is_empty() {
+            return;
+        }
+
+        let mut known_contents = self.mod_list_known_contents().write().unwrap();
+        for path in Self::mod_list_files(game, game_path) {
+            if path.is_file() {
+                self.mod_list_watcher().add_path(&QString::from_std_str(path.to_string_lossy()));
+
+                if !known_contents.contains_key(&path) {
+                    if let Ok(packs) = read_mod_list_file(&path) {
+                        known_contents.insert(path, packs);
+                    }
+                }
+            }
+        }
+    }
+
+    /// This function checks if the mod list files of the current game have been changed by another launcher, and warns the user if so.
+    ///
+    /// Changes that match our current load order are ignored, as they don't change what the game loads.
+    pub unsafe fn check_mod_list_files_changes(&self) -> Result<()> {
+
+        // Same as with the config files, the load order may be about to change if we're in the middle of another operation.
+        if !self.main_window().is_enabled() {
+            self.mod_list_watcher_timer().start_0a();
+            return Ok(());
+        }
+
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+
+        // Files replaced by other launchers are no longer watched, so re-add them.
+        self.update_mod_list_watcher(&game, &game_path);
+
+        let current_packs = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => self.game_load_order().read().unwrap()
+                .mods()
+                .iter()
+                .filter_map(|mod_id| game_config.mods().get(mod_id)?.paths().first()?.file_name())
+                .map(|file_name| file_name.to_string_lossy().to_string())
+                .collect::<Vec<_>>(),
+            None => return Ok(()),
+        };
+
+        for path in Self::mod_list_files(&game, &game_path) {
+            let packs = match read_mod_list_file(&path) {
+                Ok(packs) => packs,
+                Err(_) => continue,
+            };
+
+            let known_packs = self.mod_list_known_contents().write().unwrap().insert(path.to_path_buf(), packs.to_vec());
+            if known_packs.as_ref() == Some(&packs) || packs == current_packs {
+                continue;
+            }
+
+            let file_name = path.file_name().map(|file_name| file_name.to_string_lossy().to_string()).unwrap_or_default();
+            self.mod_list_changed_dialog().set_text(&qtre("mod_list_changed_description", &[&file_name, game.display_name()]));
+            *self.mod_list_changed_path().write().unwrap() = Some(path);
+
+            self.mod_list_changed_dialog().show();
+            self.mod_list_changed_dialog().raise();
+        }
+
+        Ok(())
+    }
+
+    /// This function replaces the current load order with the one in the mod list file changed by another launcher.
+    pub unsafe fn import_mod_list_file_order(&self) -> Result<()> {
+        let path = match self.mod_list_changed_path().write().unwrap().take() {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        let packs = self.mod_list_known_contents().read().unwrap().get(&path).cloned().unwrap_or_default();
+        let mods = self.shareable_mods_from_packs(packs.into_iter().map(|pack| (pack, None)).collect());
+
+        self.load_order_from_shareable_mod_list(&mods)
+    }
+
+    /// This function replaces the current load order with the enabled mods of CA's launcher.
+    pub unsafe fn import_from_ca_launcher(&self) -> Result<()> {
+        let packs = ca_launcher::import_load_order(&self.game_selected().read().unwrap())?;
+        let mods = self.shareable_mods_from_packs(packs);
+
+        self.load_order_from_shareable_mod_list(&mods)
+    }
+
+    /// This function turns a list of pack names from another launcher into shareable mods.
+    ///
+    /// Mods we know about get their name and workshop id from the game config, so missing ones can still be found in the workshop.
+    /// Hashes are left empty, as other launchers don't share them.
+    pub unsafe fn shareable_mods_from_packs(&self, packs: Vec<(String, Option<String>)>) -> Vec<ShareableMod> {
+        let game_config = self.game_config().read().unwrap();
+        packs.into_iter()
+            .map(|(pack, steam_id)| {
+                let mut modd = ShareableMod::default();
+                match game_config.as_ref().and_then(|game_config| game_config.mods().get(&pack)) {
+                    Some(modd_local) => {
+                        modd.set_name(modd_local.name().to_owned());
+                        modd.set_steam_id(modd_local.steam_id().clone().or(steam_id));
+                    }
+                    None => {
+                        modd.set_name(pack.to_owned());
+                        modd.set_steam_id(steam_id);
+                    }
+                }
+
+                modd.set_id(pack);
+                modd
+            })
+            .collect()
+    }
+
+    /// This function writes the current load order to CA's launcher.
+    ///
+    /// Returns the enabled mods the launcher doesn't know about, and therefore were not written.
+    pub unsafe fn export_to_ca_launcher(&self) -> Result<Vec<String>> {
+        ca_launcher::export_load_order(&self.game_selected().read().unwrap(), &self.game_load_order().read().unwrap())
+    }
+
+    /// This function overwrites the mod list file changed by another launcher with our current load order.
+    pub unsafe fn overwrite_mod_list_file(&self) -> Result<()> {
+        let path = match self.mod_list_changed_path().write().unwrap().take() {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let data_path = game.data_path(&game_path)?;
+
+        let mut folder_list = String::new();
+        let mut pack_list = String::new();
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            self.game_load_order().read().unwrap().build_load_order_string(game_config, &game, &data_path, &mut pack_list, &mut folder_list);
+        }
+
+        write_mod_list_file(&game, &path, &folder_list, &pack_list)?;
+
+        if let Ok(packs) = read_mod_list_file(&path) {
+            self.mod_list_known_contents().write().unwrap().insert(path, packs);
+        }
+
+        Ok(())
+    }
+
+    pub unsafe fn load_saves_to_ui(&self, game: &GameInfo, game_path: &Path) -> Result<()> {
+        self.actions_ui().save_model().clear();
+        let item = QStandardItem::from_q_string(&QString::from_std_str("No saves"));
+        self.actions_ui().save_model().append_row_q_standard_item(item.into_ptr());
+
+        // If we have a save folder for the game, read its saves and load them to the save combo.
+        if let Some(ref config_path) = game_appdata_path(game, game_path) {
+            let mut game_saves = self.game_saves.write().unwrap();
+            game_saves.clear();
+
+            let save_path = config_path.join("save_games");
+            if let Ok(mut saves_paths) = files_from_subdir(&save_path, false) {
+
+                // Sort them by date, then reverse, so the most recent one is first.
+                saves_paths.sort_by_key(|x| x.metadata().unwrap().modified().unwrap());
+                saves_paths.reverse();
+
+                // Pinned saves go before the rest, in the order they were pinned.
+                let pinned_saves = UIState::load(game.key()).map(|state| state.pinned_saves().to_vec()).unwrap_or_default();
+                saves_paths.sort_by_key(|path| pinned_saves.iter()
+                    .position(|name| path.file_name().is_some_and(|file_name| file_name.to_string_lossy() == *name))
+                    .unwrap_or(usize::MAX)
+                );
+
+                for save_path in &saves_paths {
+                    let mut save = RFile::new_from_file_path(save_path)?;
+                    save.guess_file_type()?;
+
+                    let mut save = Save::default();
+                    save.set_path(save_path.to_path_buf());
+                    save.set_name(save_path.file_name().unwrap().to_string_lossy().to_string());
+
+                    /*
+                    if let Some(RFileDecoded::ESF(file)) = save.decode(&None, false, true)? {
+                        let mut save = Save::default();
+                        save.set_path(save_path.to_path_buf());
+                        save.set_name(save_path.file_name().unwrap().to_string_lossy().to_string());
+                        let mut mods = vec![];
+
+                        let root_node = file.root_node();
+                        if let NodeType::Record(node) = root_node {
+                            if node.name() == "CAMPAIGN_SAVE_GAME" {
+                                for children in node.children() {
+                                    for child in children {
+                                        if let NodeType::Record(node) = child {
+                                            if node.name() == "SAVE_GAME_HEADER" {
                                                 for children in node.children() {
                                                     for child in children {
                                                         if let NodeType::Record(node) = child {
@@ -795,7 +1923,11 @@ impl AppUI {
 
 
                     }*/
-                    let item = QStandardItem::from_q_string(&QString::from_std_str(save.name()));
+                    let item = if pinned_saves.contains(save.name()) {
+                        QStandardItem::from_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("pin")), &QString::from_std_str(save.name()))
+                    } else {
+                        QStandardItem::from_q_string(&QString::from_std_str(save.name()))
+                    };
                     self.actions_ui().save_model().append_row_q_standard_item(item.into_ptr());
 
                     game_saves.push(save);
@@ -807,24 +1939,120 @@ impl AppUI {
     }
 
     pub unsafe fn load_mods_to_ui(&self, game: &GameInfo, game_path: &Path, skip_network_update: bool) -> Result<Option<Receiver<Response>>> {
-        let mut mods = self.game_config().write().unwrap();
-        if let Some(ref mut mods) = *mods {
-            let mut load_order = self.game_load_order().write().unwrap();
-            let network_receiver = mods.update_mod_list(game, game_path, &mut load_order, skip_network_update)?;
+        let (game_config, load_order) = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => (game_config.clone(), self.game_load_order().read().unwrap().clone()),
+            None => return Ok(None),
+        };
 
-            self.mod_list_ui().load(game, mods)?;
-            self.pack_list_ui().load(mods, game, game_path, &load_order)?;
+        // The game config contains the mod list from the last time we loaded the game, so we can render it
+        // while we rescan the mod folders. Any mod that changed since then is updated once the rescan finishes.
+        if game_config.mods().values().any(|modd| !modd.paths().is_empty()) {
+            self.mod_list_ui().load(game, &game_config)?;
+            self.pack_list_ui().load(&game_config, game, game_path, &load_order)?;
+        }
 
-            Ok(network_receiver)
-        } else {
-            Ok(None)
+        // The result of the rescan replaces the config and load order, so nothing can be edited until it lands, or the edits would be lost.
+        let receiver = CENTRAL_COMMAND.send_background(Command::UpdateModList(Box::new(game.clone()), game_path.to_path_buf(), game_config, load_order, skip_network_update));
+        self.toggle_main_window(false);
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        self.toggle_main_window(true);
+        match response {
+            Response::GameConfigLoadOrderOptionReceiver(game_config, load_order, network_receiver) => {
+                self.mod_list_ui().load(game, &game_config)?;
+                self.pack_list_ui().load(&game_config, game, game_path, &load_order)?;
+
+                *self.game_load_order().write().unwrap() = load_order;
+                *self.game_config().write().unwrap() = Some(game_config);
+
+                Ok(network_receiver)
+            }
+            Response::Error(error) => Err(error),
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        }
+    }
+
+    /// This function rescans only part of the mods of the selected game, then reloads the mod and pack lists.
+    ///
+    /// Nothing else is reloaded and no Workshop data is requested, so it's way faster than a full reload.
+    pub unsafe fn rescan_mods(&self, scope: RescanScope) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let (game_config, load_order) = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => (game_config.clone(), self.game_load_order().read().unwrap().clone()),
+            None => return Ok(()),
+        };
+
+        self.toggle_main_window(false);
+        let receiver = CENTRAL_COMMAND.send_background(Command::RescanMods(Box::new(game.clone()), game_path.to_path_buf(), game_config, load_order, scope));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        self.toggle_main_window(true);
+
+        match response {
+            Response::GameConfigLoadOrderOptionReceiver(game_config, load_order, _) => {
+                self.mod_list_ui().load(&game, &game_config)?;
+                self.pack_list_ui().load(&game_config, &game, &game_path, &load_order)?;
+
+                *self.game_load_order().write().unwrap() = load_order;
+                *self.game_config().write().unwrap() = Some(game_config);
+
+                Ok(())
+            }
+            Response::Error(error) => Err(error),
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        }
+    }
+
+    /// This function requests again the Workshop data of the mods of the selected game, without rescanning them.
+    pub unsafe fn refresh_workshop_data(&self) -> Result<()> {
+        if offline_mode().is_some() {
+            return Err(anyhow!(tr("refresh_workshop_data_offline")));
+        }
+
+        let game = self.game_selected().read().unwrap().clone();
+        let steam_ids = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => game_config.mods()
+                .values()
+                .filter(|modd| !modd.paths().is_empty())
+                .filter_map(|modd| modd.steam_id().clone())
+                .collect::<Vec<_>>(),
+            None => return Ok(()),
+        };
+
+        let receiver = CENTRAL_COMMAND.send_network(Command::RequestModsData(Box::new(game), steam_ids));
+        self.update_mod_list_with_online_data(&Some(receiver))
+    }
+
+    /// This function rescans the packs of the selected mod, and requests again its Workshop data if it's from there.
+    pub unsafe fn rescan_selected_mod(&self) -> Result<()> {
+        let selection = self.mod_list_selection();
+        if selection.len() != 1 || selection[0].data_1a(VALUE_IS_CATEGORY).to_bool() {
+            return Ok(());
+        }
+
+        let mod_id = selection[0].data_1a(VALUE_MOD_ID).to_string().to_std_string();
+        self.rescan_mods(RescanScope::Mod(mod_id.to_owned()))?;
+
+        let steam_id = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => game_config.mods().get(&mod_id).and_then(|modd| modd.steam_id().clone()),
+            None => None,
+        };
+
+        if let Some(steam_id) = steam_id {
+            if offline_mode().is_none() {
+                let game = self.game_selected().read().unwrap().clone();
+                let receiver = CENTRAL_COMMAND.send_network(Command::RequestModsData(Box::new(game), vec![steam_id]));
+                self.update_mod_list_with_online_data(&Some(receiver))?;
+            }
         }
+
+        Ok(())
     }
 
     pub unsafe fn open_settings(&self) {
         let game_key = self.game_selected().read().unwrap().key().to_owned();
         let game_path_old = setting_path(&game_key);
-        let dark_theme_old = setting_bool("dark_mode");
+        let synced_config_path_old = synced_config_path();
+        let generated_files_folders_old = generated_files_folders();
         let font_name_old = setting_string("font_name");
         let font_size_old = setting_int("font_size");
 
@@ -839,12 +2067,58 @@ impl AppUI {
                         QAction::trigger(&self.game_selected_group.checked_action());
                     }
 
+                    // If we changed the sync folder, move the current configs to it (without overwriting the synced ones) and reload the game from it.
+                    if let (Ok(synced_config_path_old), Ok(synced_config_path_new)) = (synced_config_path_old, synced_config_path()) {
+                        if synced_config_path_old != synced_config_path_new {
+                            match copy_synced_config(&synced_config_path_old, &synced_config_path_new) {
+                                Ok(_) => match self.change_game_selected(true, true) {
+                                    Ok(network_receiver) => if let Err(error) = self.update_mod_list_with_online_data(&network_receiver) {
+                                        show_dialog(self.main_window(), error, false);
+                                    }
+                                    Err(error) => show_dialog(self.main_window(), error, false),
+                                }
+                                Err(error) => show_dialog(self.main_window(), error, false),
+                            }
+                        }
+                    }
+
+                    // If we changed where generated files are written, move the existing ones so they're not left behind.
+                    let generated_files_folders_new = generated_files_folders();
+                    if generated_files_folders_old != generated_files_folders_new {
+                        if let Err(error) = move_generated_files(&generated_files_folders_old, &generated_files_folders_new) {
+                            show_dialog(self.main_window(), error, false);
+                        }
+                    }
+
+                    // Apply the format of the unit multiplier, in case it changed.
+                    self.actions_ui().update_unit_multiplier_format();
+
                     // Reload the tools, just in case they changed.
                     *self.tools().write().unwrap() = Tools::load(&None).unwrap_or_else(|_| Tools::default());
 
+                    // Same with the shortcuts.
+                    self.reload_shortcuts();
+
+                    // And the log levels.
+                    reload_log_levels();
+
+                    // And the error reporting, as sending error reports can be disabled without restarting.
+                    let enable_sentry = setting_bool("enable_sentry");
+                    if enable_sentry != sentry_requested() {
+                        if let Err(error) = set_sentry_enabled(enable_sentry) {
+                            show_dialog(self.main_window(), error, false);
+                        }
+                    }
+
+                    // And the tray mode, as both the setting and the games with a path may have changed.
+                    self.reload_tray_mode();
+
+                    // And the offline mode.
+                    self.reload_offline_mode();
+
                     // Disable the games we don't have a path for (uninstalled).
                     for game in SUPPORTED_GAMES.games_sorted().iter() {
-                        let has_exe = game.executable_path(&setting_path(game.key())).filter(|path| path.is_file()).is_some();
+                        let has_exe = executable_path(game, &setting_path(game.key())).filter(|path| path.is_file()).is_some();
                         match game.key() {
                             KEY_PHARAOH_DYNASTIES => self.game_selected_pharaoh_dynasties().set_enabled(has_exe),
                             KEY_PHARAOH => self.game_selected_pharaoh().set_enabled(has_exe),
@@ -863,11 +2137,7 @@ impl AppUI {
                         }
                     }
 
-                    // If we detect a change in theme, reload it.
-                    let dark_theme_new = setting_bool("dark_mode");
-                    if dark_theme_old != dark_theme_new {
-                        self.reload_theme();
-                    }
+                    self.update_new_games_availability(false);
 
                     // If we detect a change in the saved font, trigger a font change.
                     let font_name = setting_string("font_name");
@@ -888,87 +2158,463 @@ impl AppUI {
             Err(error) => show_dialog(&self.main_window, error, false),
         }
 
+        // Always reload the theme, as the settings preview it live even if they're cancelled.
+        self.reload_theme();
+
         // Make sure we don't drag the factory reset setting, no matter if the user saved or not.
         set_setting_bool("factoryReset", false);
     }
 
     pub unsafe fn launch_game(&self) -> Result<()> {
-        let mut folder_list = String::new();
-        let mut pack_list = String::new();
-        let game = self.game_selected().read().unwrap();
-        let game_path = setting_path(game.key());
-        let data_path = game.data_path(&game_path)?;
-
-        // Setup the launch options stuff.
-        prepare_launch_options(self, &game, &game_path, &data_path, &mut folder_list)?;
-
-        // If we have "merge all mods" checked, we need to load the entire load order into a single pack, and load that pack instead of the entire load order.
-        //
-        // TODO: Review this before re-enabling merged mods. This pretty sure breaks on older games.
-        if self.actions_ui().merge_all_mods_checkbox().is_enabled() && self.actions_ui().merge_all_mods_checkbox().is_checked() {
-            let temp_path_file_name = format!("{}_{}.pack", MERGE_ALL_PACKS_PACK_NAME, self.game_selected().read().unwrap().key());
-            let temp_path = data_path.join(&temp_path_file_name);
-            pack_list.push_str(&format!("mod \"{}\";", temp_path_file_name));
+        let check_logs = setting_bool("check_logs");
+        match self.launch_game_with_options(check_logs, check_logs, true) {
+            Ok(_) => Ok(()),
 
-            // Generate the merged pack.
-            let load_order = self.game_load_order().read().unwrap();
-            if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            // Cancelling is not an error, so we don't report it.
+            Err(_) if launch_cancelled() => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
 
-                let pack_paths = load_order.mods().iter()
-                    .filter_map(|mod_id| {
-                        let modd = game_config.mods().get(mod_id)?;
-                        std::fs::canonicalize(modd.paths().first()?).ok()
-                    })
-                .collect::<Vec<_>>();
+    /// This function shows a countdown before launching the game, if enabled, so a misclick on Play can be cancelled before the game starts loading.
+    ///
+    /// Returns false if the countdown was cancelled.
+    pub unsafe fn launch_countdown(&self) -> bool {
+        if !setting_bool("launch_countdown") {
+            return true;
+        }
 
-                if !pack_paths.is_empty() {
-                    let mut reserved_pack = Pack::read_and_merge(&pack_paths, true, false, true)?;
-                    let pack_version = game.pfh_version_by_file_type(PFHFileType::Mod);
-                    reserved_pack.set_pfh_version(pack_version);
+        let dialog = QProgressDialog::from_q_string2_int2_q_widget(&QString::new(), &qtr("launch_progress_cancel"), 0, LAUNCH_COUNTDOWN_SECONDS, self.main_window());
+        dialog.set_window_title(&qtr("launch_countdown_title"));
+        dialog.set_window_modality(WindowModality::WindowModal);
+        dialog.set_minimum_duration(0);
+        dialog.set_auto_close(false);
+
+        let event_loop = qt_core::QEventLoop::new_0a();
+        let timer = QTimer::new_1a(&dialog);
+        timer.set_single_shot(true);
+        timer.timeout().connect(event_loop.slot_quit());
+        dialog.canceled().connect(event_loop.slot_quit());
+        dialog.show();
+
+        for elapsed in 0..LAUNCH_COUNTDOWN_SECONDS {
+            dialog.set_value(elapsed);
+            dialog.set_label_text(&qtre("launch_countdown_label", &[&(LAUNCH_COUNTDOWN_SECONDS - elapsed).to_string()]));
+            timer.start_1a(1000);
+            event_loop.exec_0a();
+
+            if dialog.was_canceled() {
+                break;
+            }
+        }
 
-                    let mut encode_data = EncodeableExtraData::default();
-                    encode_data.set_nullify_dates(true);
+        let cancelled = dialog.was_canceled();
+        dialog.close();
+        dialog.delete_later();
+        !cancelled
+    }
 
-                    reserved_pack.save(Some(&temp_path), &game, &Some(encode_data))?;
-                }
-            } else {
-                return Err(anyhow!(tr("game_config_error")));
-            }
+    /// This function hides the main window, leaving only the tray icon to bring it back.
+    ///
+    /// If the system has no tray, the window is just minimized.
+    pub unsafe fn minimize_to_tray(&self) {
+        if TrayUI::is_available() {
+            self.tray_ui().tray_icon().show();
+            self.main_window().hide();
+        } else {
+            self.main_window().show_minimized();
         }
+    }
 
-        // Otherwise, just add the packs from the load order to the text file.
-        else if let Some(ref game_config) = *self.game_config().read().unwrap() {
-            let load_order = self.game_load_order().read().unwrap();
-            load_order.build_load_order_string(game_config, &game, &data_path, &mut pack_list, &mut folder_list);
+    /// This function brings the main window back from the tray. In tray mode, the icon stays.
+    pub unsafe fn restore_from_tray(&self) {
+        if !setting_bool("tray_mode") {
+            self.tray_ui().tray_icon().hide();
         }
 
-        // If our folder list contains the secondary folder, we need to make sure we create the masks folder in it,
-        // and mask in there all non-enabled movie files.
-        let secondary_mods_path = secondary_mods_path(game.key()).unwrap_or_else(|_| PathBuf::new());
-        if secondary_mods_path.is_dir() && folder_list.contains(&secondary_mods_path.to_string_lossy().to_string()) {
-            let masks_path = secondary_mods_path.join(SECONDARY_FOLDER_NAME);
+        self.main_window().show_normal();
+        self.main_window().raise();
+        self.main_window().activate_window();
+    }
 
-            // Remove all files in it so previous maskings do not interfere.
-            if masks_path.is_dir() {
-                std::fs::remove_dir_all(&masks_path)?;
-            }
+    /// This function applies the offline mode setting.
+    ///
+    /// In offline mode no network request is made, so this also checks if we have network if the user didn't enable it.
+    /// The check is done in the network thread, as it may have to wait for a timeout. Its result is picked up by `receive_offline_mode`.
+    /// Network requests sent after it wait for it, as the network thread handles them in order.
+    pub unsafe fn reload_offline_mode(&self) {
+        set_offline_mode_enabled(setting_bool("offline_mode"));
+        self.update_offline_mode_label();
+
+        if self.offline_mode_receiver().read().unwrap().is_some() {
+            return;
+        }
+
+        let receiver = CENTRAL_COMMAND.send_network(Command::DetectOfflineMode);
+        *self.offline_mode_receiver().write().unwrap() = Some(receiver);
+        self.offline_mode_poll_timer().start_0a();
+    }
 
-            DirBuilder::new().recursive(true).create(&masks_path)?;
+    /// This function checks if the offline mode detection in progress has finished, and shows its result.
+    pub unsafe fn receive_offline_mode(&self) {
+        let response = match *self.offline_mode_receiver().read().unwrap() {
+            Some(ref receiver) => match receiver.try_recv() {
+                Ok(response) => response,
+                Err(_) => return,
+            },
+            None => return,
+        };
 
-            let mut mask_pack = Pack::new_with_version(game.pfh_version_by_file_type(PFHFileType::Movie));
-            mask_pack.set_pfh_file_type(PFHFileType::Movie);
+        self.offline_mode_poll_timer().stop();
+        *self.offline_mode_receiver().write().unwrap() = None;
 
-            if let Some(ref game_config) = *self.game_config().read().unwrap() {
-                for path in std::fs::read_dir(secondary_mods_path)? {
-                    let file_name = path?.file_name().to_string_lossy().to_string();
+        match response {
+            Response::Success => self.update_offline_mode_label(),
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        }
+    }
 
-                    if let Some(modd) = game_config.mods().get(&file_name) {
-                        if modd.pack_type() == &PFHFileType::Movie && !modd.enabled(&data_path) {
-                            mask_pack.save(Some(&masks_path.join(file_name)), &game, &None)?;
-                        }
-                    }
-                }
+    /// This function shows or hides the offline mode label of the status bar, depending on the current offline mode.
+    unsafe fn update_offline_mode_label(&self) {
+        match offline_mode() {
+            Some(reason) => {
+                info!("Offline mode active. Reason: {:?}.", reason);
+                self.offline_label().set_tool_tip(&qtr(reason.locale_key()));
+                self.offline_label().set_visible(true);
             }
+            None => self.offline_label().set_visible(false),
+        }
+    }
+
+    /// This function checks again if we have network, if offline mode was detected instead of enabled by the user.
+    pub unsafe fn check_offline_mode(&self) {
+        if offline_mode() == Some(OfflineReason::Detected) {
+            self.reload_offline_mode();
+        }
+    }
+
+    /// This function applies the tray mode setting.
+    ///
+    /// In tray mode the tray icon is always shown, closing the main window leaves Runcher running in the tray,
+    /// and the content folders of all games are periodically checked for new subscriptions and updates.
+    pub unsafe fn reload_tray_mode(&self) {
+        let enabled = setting_bool("tray_mode") && TrayUI::is_available();
+        QGuiApplication::set_quit_on_last_window_closed(!enabled);
+        self.reload_tray_launch_menu();
+
+        if enabled {
+            self.tray_ui().tray_icon().show();
+
+            // The first check only takes note of what's already there, so we don't report every installed mod as new.
+            if !self.workshop_monitor_timer().is_active() {
+                self.workshop_snapshots().write().unwrap().clear();
+                self.check_workshop_changes();
+                self.workshop_monitor_timer().start_0a();
+            }
+        } else {
+            self.workshop_monitor_timer().stop();
+            if self.main_window().is_visible() {
+                self.tray_ui().tray_icon().hide();
+            }
+        }
+    }
+
+    /// This function fills the tray's launch menu with the games we have a path for.
+    pub unsafe fn reload_tray_launch_menu(&self) {
+        self.tray_ui().launch_menu().clear();
+
+        for game in SUPPORTED_GAMES.games_sorted().iter() {
+            if executable_path(game, &setting_path(game.key())).filter(|path| path.is_file()).is_some() {
+                let action = self.tray_ui().launch_menu().add_action_q_string(&QString::from_std_str(game.display_name()));
+                action.set_data(&QVariant::from_q_string(&QString::from_std_str(game.key())));
+                action.set_tool_tip(&qtr("tray_launch_tt"));
+            }
+        }
+
+        self.tray_ui().launch_menu().set_enabled(!self.tray_ui().launch_menu().is_empty());
+    }
+
+    /// This function launches the provided game with the last profile loaded for it, selecting it first if needed.
+    ///
+    /// If no profile was loaded for the game yet, or it no longer exists, the game is launched with its current load order.
+    ///
+    /// Like with the API, nothing is done while the window is disabled, as that means we're already in the middle of another operation, like a launch.
+    pub unsafe fn launch_from_tray(&self, game_key: &str) {
+        if !self.main_window().is_enabled() {
+            self.tray_ui().tray_icon().show_message_2a(&qtr("tray_launch"), &qtr("tray_launch_busy"));
+            return;
+        }
+
+        if game_key != self.game_selected().read().unwrap().key() {
+            let actions = [
+                self.game_selected_pharaoh_dynasties(),
+                self.game_selected_pharaoh(),
+                self.game_selected_warhammer_3(),
+                self.game_selected_troy(),
+                self.game_selected_three_kingdoms(),
+                self.game_selected_warhammer_2(),
+                self.game_selected_warhammer(),
+                self.game_selected_thrones_of_britannia(),
+                self.game_selected_attila(),
+                self.game_selected_rome_2(),
+                self.game_selected_shogun_2(),
+                self.game_selected_napoleon(),
+                self.game_selected_empire(),
+            ];
+
+            if let Some(action) = actions.iter().find(|action| Self::game_key_from_action(action) == game_key) {
+                action.trigger();
+            }
+
+            // If the game failed to load, don't launch the one that was selected before.
+            if game_key != self.game_selected().read().unwrap().key() {
+                return;
+            }
+        }
+
+        let profile_name = last_profile(game_key).filter(|name| self.game_profiles().read().unwrap().contains_key(name));
+        if let Some(profile_name) = profile_name {
+            if let Err(error) = self.load_profile(Some(profile_name), false) {
+                self.tray_ui().tray_icon().show_message_2a(&qtr("tray_launch"), &QString::from_std_str(error.to_string()));
+                return;
+            }
+        }
+
+        // Clicking Play runs the same checks as launching from the main window.
+        self.actions_ui().play_button().click();
+    }
+
+    /// This function answers the requests received through the local API.
+    ///
+    /// Requests received while the window is disabled, a dialog is open or we're waiting for another thread are rejected,
+    /// as that means we're already in the middle of another operation.
+    pub unsafe fn handle_api_requests(&self) {
+        while let Some((sender, command)) = CENTRAL_COMMAND.try_recv_api() {
+
+            // Dialogs and waits for other threads keep processing events, so we could get here in the middle of another operation.
+            if !self.main_window().is_enabled() || !QApplication::active_modal_widget().is_null() || CENTRAL_COMMAND.is_waiting() {
+                CentralCommand::send_back(&sender, Response::Error(anyhow!("Runcher is busy. Try again later.")));
+                continue;
+            }
+
+            match command {
+                Command::GetGameSelected => {
+                    let value = serde_json::json!({
+                        "game": self.game_selected().read().unwrap().key(),
+                        "profile": self.actions_ui().profile_combobox().current_text().to_std_string(),
+                    });
+
+                    CentralCommand::send_back(&sender, Response::JsonValue(value));
+                }
+
+                Command::GetLoadOrder => match serde_json::to_value(&*self.game_load_order().read().unwrap()) {
+                    Ok(value) => CentralCommand::send_back(&sender, Response::JsonValue(value)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(From::from(error))),
+                }
+
+                Command::GetProfiles => {
+                    let profiles = self.game_profiles().read().unwrap().keys().cloned().sorted().collect::<Vec<_>>();
+                    CentralCommand::send_back(&sender, Response::JsonValue(serde_json::json!(profiles)));
+                }
+
+                Command::LoadProfile(name) => match self.load_profile(Some(name), false) {
+                    Ok(_) => CentralCommand::send_back(&sender, Response::Success),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+
+                // Answer first, as launching may open dialogs that block this thread until they're closed.
+                Command::LaunchGameSelected => {
+                    CentralCommand::send_back(&sender, Response::Success);
+                    self.actions_ui().play_button().click();
+                }
+
+                _ => panic!("{THREADS_COMMUNICATION_ERROR}{command:?}"),
+            }
+        }
+    }
+
+    /// This function starts a scan of the content folders of all games with a path, looking for Workshop changes.
+    ///
+    /// The scan runs in the background. Its results are picked up by `receive_workshop_changes`.
+    pub unsafe fn check_workshop_changes(&self) {
+        if self.workshop_monitor_receiver().read().unwrap().is_some() {
+            return;
+        }
+
+        let games = SUPPORTED_GAMES.games_sorted()
+            .iter()
+            .map(|game| ((*game).clone(), setting_path(game.key())))
+            .filter(|(_, game_path)| !game_path.as_os_str().is_empty())
+            .collect::<Vec<_>>();
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::ScanWorkshopFolders(games));
+        *self.workshop_monitor_receiver().write().unwrap() = Some(receiver);
+        self.workshop_monitor_poll_timer().start_0a();
+    }
+
+    /// This function checks if the Workshop scan in progress has finished, and notifies the changes it found from the tray.
+    pub unsafe fn receive_workshop_changes(&self) {
+        let response = match *self.workshop_monitor_receiver().read().unwrap() {
+            Some(ref receiver) => match receiver.try_recv() {
+                Ok(response) => response,
+                Err(_) => return,
+            },
+            None => return,
+        };
+
+        self.workshop_monitor_poll_timer().stop();
+        *self.workshop_monitor_receiver().write().unwrap() = None;
+
+        let scanned = match response {
+            Response::VecStringWorkshopSnapshot(scanned) => scanned,
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        };
+
+        let mut snapshots = self.workshop_snapshots().write().unwrap();
+        let mut messages = vec![];
+
+        for (game_key, snapshot) in scanned {
+            if let Some(previous) = snapshots.get(&game_key) {
+                let changes = snapshot.changes_since(previous);
+                if !changes.is_empty() {
+                    if let Some(game) = SUPPORTED_GAMES.game(&game_key) {
+                        messages.push(tre("workshop_monitor_changes", &[game.display_name(), &changes.subscribed().len().to_string(), &changes.updated().len().to_string()]));
+                    }
+                }
+            }
+
+            snapshots.insert(game_key, snapshot);
+        }
+
+        if !messages.is_empty() {
+            self.tray_ui().tray_icon().show_message_2a(&qtr("workshop_monitor_title"), &QString::from_std_str(messages.join("\n")));
+        }
+    }
+
+    /// This function launches the selected game, optionally waiting until it's closed and checking its logs for script errors.
+    ///
+    /// Returns the amount of script errors found in the logs. If the launch is cancelled before the game is started, this returns an error.
+    ///
+    /// Non-interactive launches, like the ones started from the cli, show no dialogs, and print the script errors found to stdout instead.
+    pub unsafe fn launch_game_with_options(&self, wait_for_exit: bool, check_logs: bool, interactive: bool) -> Result<usize> {
+        let mut folder_list = String::new();
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let data_path = game.data_path(&game_path)?;
+
+        // Setup the launch options stuff.
+        prepare_launch_options(self, &game, &game_path, &data_path, &mut folder_list)?;
+
+        // Take a snapshot of the config and load order, so changes done while we launch do not affect the launch.
+        let game_config = self.game_config().read().unwrap().clone().ok_or_else(|| anyhow!(tr("game_config_error")))?;
+        let load_order = self.game_load_order().read().unwrap().clone();
+        let merge_all_mods = self.actions_ui().merge_all_mods_checkbox().is_enabled() && self.actions_ui().merge_all_mods_checkbox().is_checked();
+
+        // Preparing the launch, launching and checking the logs is done in the background thread, so the window is not hung while we do it.
+        // Until we finish, the main window is disabled, and we show a dialog with the current step that can be used to cancel the launch.
+        LAUNCH_CANCELLED.store(false, Ordering::SeqCst);
+
+        let progress_dialog = QProgressDialog::from_q_string2_int2_q_widget(&qtr("launch_progress_preparing"), &qtr("launch_progress_cancel"), 0, 0, self.main_window());
+        progress_dialog.set_window_title(&qtr("launch_progress_title"));
+        progress_dialog.set_window_modality(WindowModality::WindowModal);
+        progress_dialog.set_minimum_duration(0);
+
+        let cancel_slot = SlotNoArgs::new(&progress_dialog, || LAUNCH_CANCELLED.store(true, Ordering::SeqCst));
+        progress_dialog.canceled().connect(&cancel_slot);
+
+        self.toggle_main_window(false);
+        if interactive {
+            progress_dialog.show();
+        }
+
+        let result = self.launch_game_in_background(&game, &game_path, &game_config, &load_order, merge_all_mods, folder_list, wait_for_exit || check_logs, check_logs, interactive, &progress_dialog);
+        let cancelled = launch_cancelled();
+
+        // Closing the dialog triggers its cancel signal, so we need to restore the cancelled state after closing it.
+        progress_dialog.close();
+        progress_dialog.delete_later();
+        LAUNCH_CANCELLED.store(cancelled, Ordering::SeqCst);
+        self.toggle_main_window(true);
+
+        result
+    }
+
+    /// This function does the actual launch of the provided game, reporting each step in the provided dialog.
+    ///
+    /// The heavy work is done in the background thread. Only the stuff that needs the UI is done here.
+    unsafe fn launch_game_in_background(&self, game: &GameInfo, game_path: &Path, game_config: &GameConfig, load_order: &LoadOrder, merge_all_mods: bool, folder_list: String, wait_for_exit: bool, check_logs: bool, interactive: bool, progress_dialog: &QBox<QProgressDialog>) -> Result<usize> {
+
+        // Post-exit hooks and packs staged from the secondary folder need us to wait for the game, even if we're not going to check its logs.
+        //
+        // Launches through the Steam client are handed over to Steam, so we cannot wait for those. Nor check their logs.
+        // Steam cannot launch a game elevated, so if the game needs it we launch it ourselves. Same if we staged packs, so we can remove them on exit.
+        let elevated = setting_bool(&format!("launch_elevated_{}", game.key()));
+        let launch_mode = setting_string(&format!("launch_mode_{}", game.key()));
+        let staging = needs_secondary_staging(game, game_path);
+        let through_steam = launch_mode == LAUNCH_MODE_STEAM && !elevated && !staging;
+        if elevated && launch_mode != LAUNCH_MODE_DIRECT {
+            warn!("{} is set to launch elevated, which is not possible through Steam. Launching it directly.", game.key());
+        } else if staging && launch_mode == LAUNCH_MODE_STEAM {
+            warn!("{} needs packs staged in /data, which cannot be removed after a launch through the Steam client. Launching it directly.", game.key());
+        }
+        let profile = self.actions_ui().profile_combobox().current_text().to_std_string();
+        let hooks = Hooks::load().unwrap_or_default();
+        let wait_for_exit = !through_steam && (wait_for_exit || staging || !hooks.hooks_for(HookStage::PostExit, game, &profile).is_empty());
+        let check_logs = check_logs && !through_steam;
+
+        // Read the environment variables first, so a mistake in them stops the launch before anything is done.
+        let env_vars = launch_env_vars(game, self.game_profiles().read().unwrap().get(&profile))?;
+        if through_steam && !env_vars.is_empty() {
+            warn!("Environment variables cannot be passed to games launched through the Steam client. Ignoring them.");
+        }
+
+        if !hooks.hooks_for(HookStage::PreLaunch, game, &profile).is_empty() {
+            self.run_hooks(HookStage::PreLaunch, game, game_path, &profile, interactive, progress_dialog)?;
+
+            if launch_cancelled() {
+                return Err(anyhow!(tr("launch_cancelled")));
+            }
+        }
+
+        // Backup the saves before the mods get a chance to break them. A failed backup is reported, but doesn't stop the launch.
+        if setting_bool("backup_saves_before_launch") {
+            if let Some(config_path) = game_appdata_path(game, game_path) {
+                progress_dialog.set_label_text(&qtr(LaunchStep::BackingUpSaves.locale_key()));
+
+                let count = setting_int("backup_saves_count").max(1) as usize;
+                let retention = setting_int("backup_saves_retention").max(1) as usize;
+                let receiver = CENTRAL_COMMAND.send_background(Command::BackupSaves(game.key().to_owned(), config_path.join("save_games"), save_backups_path()?, count, retention));
+                let response = CENTRAL_COMMAND.recv_try(&receiver);
+                match response {
+                    Response::Success => {},
+                    Response::Error(error) => if interactive {
+                        show_dialog(self.main_window(), tre("backup_saves_failed", &[&error.to_string()]), false);
+                    } else {
+                        error!("Error backing up the saves: {}", error);
+                    },
+                    _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+                }
+            }
+
+            if launch_cancelled() {
+                return Err(anyhow!(tr("launch_cancelled")));
+            }
+        }
+
+        // Build the merged pack/load order and mask the disabled movie packs.
+        let receiver = CENTRAL_COMMAND.send_background(Command::PrepareLaunch(Box::new(game.clone()), game_path.to_path_buf(), game_config.clone(), load_order.clone(), merge_all_mods, folder_list));
+        let (pack_list, folder_list) = loop {
+            let response = CENTRAL_COMMAND.recv_try(&receiver);
+            match response {
+                Response::LaunchStep(step) => progress_dialog.set_label_text(&qtr(step.locale_key())),
+                Response::StringString(pack_list, folder_list) => break (pack_list, folder_list),
+                Response::Error(error) => return Err(error),
+                _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+            }
+        };
+
+        if launch_cancelled() {
+            return Err(anyhow!(tr("launch_cancelled")));
         }
 
         // Check if we are loading a save. First option is no save load. Any index above that is a save.
@@ -982,82 +2628,146 @@ impl AppUI {
             }
         }
 
-        // NOTE: On Empire and Napoleon we need to use the user_script, not the custom file, as it doesn't seem to work.
-        // Older versions of shogun 2 also used the user_script, but the latest update enabled use of custom mod lists.
-        let file_path = if *game.raw_db_version() >= 1 {
-            game_path.join(CUSTOM_MOD_LIST_FILE_NAME)
+        // Older games read the mod list from a user script in their config folder.
+        let scripts_path = if game_quirks(game).user_script().is_some() {
+            scripts_path(game, game_path)?
         } else {
-
-            // Games may fail to launch if we don't have this path created, which is done the first time we start the game.
-            let config_path = game.config_path(&game_path).ok_or(anyhow!("Error getting the game's config path."))?;
-            let scripts_path = config_path.join("scripts");
-            DirBuilder::new().recursive(true).create(&scripts_path)?;
-
-            // Empire has its own user script.
-            if game.key() == KEY_EMPIRE {
-                scripts_path.join(USER_SCRIPT_EMPIRE_FILE_NAME)
-            } else {
-                scripts_path.join(USER_SCRIPT_FILE_NAME)
-            }
+            PathBuf::new()
         };
 
-        let mut file = BufWriter::new(File::create(file_path)?);
+        let file_path = mod_list_file_path(game, game_path, &scripts_path);
+        write_mod_list_file(game, &file_path, &folder_list, &pack_list)?;
 
-        // Napoleon, Empire and Shogun 2 require the user.script.txt or mod list file (for Shogun's latest update) to be in UTF-16 LE. What the actual fuck.
-        if *game.raw_db_version() < 2 {
-            file.write_string_u16(&folder_list)?;
-            file.write_string_u16(&pack_list)?;
-        } else {
-            file.write_all(folder_list.as_bytes())?;
-            file.write_all(pack_list.as_bytes())?;
+        // Keep what we told the game to load, so it can be exported for bug reports.
+        if let Err(error) = launch_manifest(game, game_path, game_config, load_order, &folder_list, &pack_list).and_then(|manifest| save_launch_manifest(game.key(), &manifest)) {
+            error!("Error saving the launch manifest: {}", error);
+        }
+
+        // Keep the session, so users know when they last played with each mod.
+        match SessionHistory::add_session(game.key(), &profile, load_order) {
+            Ok(_) => {
+                if let Ok(date_format) = time::format_description::parse(&setting_string("date_format")) {
+                    if let Err(error) = self.mod_list_ui().update_last_launched(game.key(), &date_format) {
+                        error!("Error updating the last launched dates: {}", error);
+                    }
+                }
+            }
+            Err(error) => error!("Error saving the launch session: {}", error),
         }
 
-        file.flush()?;
+        // Remember what we wrote, so the mod list watcher doesn't report our own changes.
+        self.update_mod_list_watcher(game, game_path);
+        if let Ok(packs) = read_mod_list_file(&file_path) {
+            self.mod_list_known_contents().write().unwrap().insert(file_path.to_path_buf(), packs);
+        }
 
         // Launch is done through workshopper to getup the Steam Api.
         //
         // Here we just build the commands and pass them to workshopper.
-        match game.executable_path(&game_path) {
+        match executable_path(game, game_path) {
             Some(exec_game) => {
                 if cfg!(target_os = "windows") {
 
                     // For post-shogun 2 games, we use the same command to bypass the launcher.
-                    let command = if *game.raw_db_version() >= 1 {
+                    // Empire and Napoleon do not have a launcher. We can make our lives easier calling steam instead of launching the game manually.
+                    let args = launch_args(game, &extra_args);
 
-                        let mut command = format!("cmd /C start /W /d \"{}\" \"{}\" {};", game_path.to_string_lossy().replace('\\', "/"), exec_game.file_name().unwrap().to_string_lossy(), CUSTOM_MOD_LIST_FILE_NAME);
+                    let compatibility_mode = setting_string(&format!("compatibility_mode_{}", game.key()));
+                    let compatibility_layer = COMPATIBILITY_MODES.iter()
+                        .find(|(name, _)| *name == compatibility_mode)
+                        .map(|(_, layer)| *layer)
+                        .unwrap_or_default();
 
-                        for arg in &extra_args {
-                            command.push(' ');
-                            command.push_str(arg);
-                        }
+                    let known_layers = COMPATIBILITY_MODES.iter().map(|(_, layer)| *layer).filter(|layer| !layer.is_empty()).collect::<Vec<_>>();
+                    if let Err(error) = set_compatibility_layer(&exec_game, compatibility_layer, &known_layers) {
+                        error!("Error setting the compatibility mode of the game: {}", error);
+                    }
+
+                    let command = Self::launch_command(game, game_path, &exec_game, &args);
+
+                    let start_date = SystemTime::now();
+                    let command = BASE64_STANDARD.encode(command);
 
-                        command
+                    // If we wait for the game, we do it until the game is closed. The launch cannot be stopped at this point,
+                    // so cancelling here only skips the log analysis.
+                    if wait_for_exit {
+                        progress_dialog.set_label_text(&qtr(LaunchStep::WaitingForGame.locale_key()));
                     }
 
-                    // Empire and Napoleon do not have a launcher. We can make our lives easier calling steam instead of launching the game manually.
-                    else {
-                        format!("cmd /C start /W /d \"{}\" \"{}\"", game_path.to_string_lossy().replace('\\', "/"), exec_game.file_name().unwrap().to_string_lossy())
+                    // If we need to do stuff once the game is closed, we cannot close Runcher, so we go to the tray instead.
+                    // Non-interactive launches have no window to hide, and restoring it from the tray would show it.
+                    let post_launch_behavior = setting_string("post_launch_behavior");
+                    let to_tray = interactive && (post_launch_behavior == POST_LAUNCH_MINIMIZE_TO_TRAY || (post_launch_behavior == POST_LAUNCH_CLOSE && wait_for_exit));
+                    if to_tray {
+                        progress_dialog.hide();
+                        self.minimize_to_tray();
+                    }
+
+                    let response = if through_steam {
+                        match launch_game_through_steam(game, &args) {
+                            Ok(_) => Response::Success,
+                            Err(error) => Response::Error(error),
+                        }
+                    } else {
+                        let receiver = CENTRAL_COMMAND.send_background(Command::LaunchGame(Box::new(game.clone()), command, env_vars, wait_for_exit));
+                        CENTRAL_COMMAND.recv_try(&receiver)
                     };
 
-                    self.toggle_main_window(false);
+                    if let Response::Error(error) = response {
+                        if to_tray {
+                            self.restore_from_tray();
+                        }
 
-                    let event_loop = qt_core::QEventLoop::new_0a();
-                    event_loop.process_events_0a();
+                        return Err(error);
+                    } else if !matches!(response, Response::Success) {
+                        panic!("{THREADS_COMMUNICATION_ERROR}{response:?}");
+                    }
 
-                    let start_date = SystemTime::now();
-                    let command = BASE64_STANDARD.encode(command);
+                    // Once the game is closed, come back from the tray so the results of the post-exit steps can be seen.
+                    if wait_for_exit {
+                        if to_tray {
+                            self.restore_from_tray();
+                            progress_dialog.show();
+                        }
+                    } else if post_launch_behavior == POST_LAUNCH_CLOSE {
+                        self.main_window().close();
+                    }
 
-                    let wait_for_finish = setting_bool("check_logs");
-                    let result = crate::mod_manager::integrations::launch_game(&game, &command, wait_for_finish);
+                    // Staged packs are only needed while the game runs. If they cannot be removed now, they'll be removed on the next launch.
+                    if wait_for_exit && staging {
+                        if let Err(error) = clean_staged_packs(game.key()) {
+                            error!("Error removing the packs staged from the secondary folder: {}", error);
+                        }
+                    }
 
-                    // Check the logs post-launch, if there's any log to check.
-                    if setting_bool("check_logs") {
-                        self.check_logs(&game, &game_path, &start_date)?;
+                    // Post-exit hooks run even if the log check was cancelled, as they usually clean up after the pre-launch ones.
+                    if wait_for_exit && !hooks.hooks_for(HookStage::PostExit, game, &profile).is_empty() {
+                        self.run_hooks(HookStage::PostExit, game, game_path, &profile, interactive, progress_dialog)?;
                     }
 
-                    self.toggle_main_window(true);
+                    // Check the logs post-launch, if there's any log to check.
+                    if check_logs && !launch_cancelled() {
+                        progress_dialog.set_label_text(&qtr(LaunchStep::AnalyzingLogs.locale_key()));
+
+                        let receiver = CENTRAL_COMMAND.send_background(Command::AnalyzeLogs(Box::new(game.clone()), game_path.to_path_buf(), game_config.clone(), load_order.clone(), start_date));
+                        let response = CENTRAL_COMMAND.recv_try(&receiver);
+                        match response {
+                            Response::VecScriptBreak(breaks) => if !launch_cancelled() {
+                                if interactive {
+                                    progress_dialog.hide();
+                                    self.show_log_analysis(game, game_path, game_config, load_order, &breaks)?;
+                                } else {
+                                    Cli::print_script_breaks(&breaks)?;
+                                }
+
+                                return Ok(breaks.len());
+                            },
+                            Response::Error(error) => return Err(error),
+                            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+                        }
+                    }
 
-                    result
+                    Ok(0)
                 } else if cfg!(target_os = "linux") {
                     Err(anyhow!("Unsupported OS."))
                 } else {
@@ -1068,6 +2778,80 @@ impl AppUI {
         }
     }
 
+    /// This function runs the hooks of the provided stage in the background thread, reporting the failures of the ones that should be reported.
+    ///
+    /// If a hook with the abort policy fails, this returns an error.
+    unsafe fn run_hooks(&self, stage: HookStage, game: &GameInfo, game_path: &Path, profile: &str, interactive: bool, progress_dialog: &QBox<QProgressDialog>) -> Result<()> {
+        let step = match stage {
+            HookStage::PreLaunch => LaunchStep::RunningPreLaunchHooks,
+            HookStage::PostExit => LaunchStep::RunningPostExitHooks,
+        };
+
+        progress_dialog.set_label_text(&qtr(step.locale_key()));
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::RunHooks(stage, Box::new(game.clone()), game_path.to_path_buf(), profile.to_owned()));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        match response {
+            Response::VecString(failures) => {
+                if !failures.is_empty() {
+                    if interactive {
+                        let list = failures.iter().map(|failure| format!("<li>{}</li>", failure)).join("");
+                        show_dialog(self.main_window(), tre("hooks_failed", &[&list]), false);
+                    } else {
+                        error!("Hooks failed: {}", failures.join(", "));
+                    }
+                }
+
+                Ok(())
+            }
+            Response::Error(error) => Err(anyhow!(tre("hooks_aborted", &[&error.to_string()]))),
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        }
+    }
+
+    /// This function builds the command used to launch the provided game, applying the elevation option of the game.
+    ///
+    /// Normal launches go through cmd. If the game needs to run elevated, we go through PowerShell instead,
+    /// as Start-Process is the easiest way to call ShellExecute with runas. The compatibility mode is not part of the command,
+    /// as it's set in the registry before launching. See [set_compatibility_layer].
+    ///
+    /// In the Steam API launch mode, workshopper runs the game's exe itself, so the game stays a child of the process with the Steam API
+    /// initialized. That keeps the overlay, playtime and controller config working in setups where cmd loses them.
+    pub fn launch_command(game: &GameInfo, game_path: &Path, exec_game: &Path, args: &str) -> String {
+        let game_path = game_path.to_string_lossy().replace('\\', "/");
+        let exec_name = exec_game.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+
+        let elevated = setting_bool(&format!("launch_elevated_{}", game.key()));
+        if !elevated {
+            let mut command = if setting_string(&format!("launch_mode_{}", game.key())) == LAUNCH_MODE_STEAM_API {
+                format!("\"{}/{}\"", game_path, exec_name)
+            } else {
+                format!("cmd /C start /W /d \"{}\" \"{}\"", game_path, exec_name)
+            };
+
+            if !args.is_empty() {
+                command.push(' ');
+                command.push_str(args);
+            }
+
+            return command;
+        }
+
+        // Single quotes in PowerShell strings are escaped by doubling them.
+        let escape = |string: &str| string.replace('\'', "''");
+        let mut script = format!("Start-Process -FilePath '{}' -WorkingDirectory '{}'", escape(&format!("{}/{}", game_path, exec_name)), escape(&game_path));
+
+        if !args.is_empty() {
+            script.push_str(&format!(" -ArgumentList '{}'", escape(args)));
+        }
+
+        script.push_str(" -Verb RunAs -Wait");
+
+        // The script is passed encoded, so double quotes in the arguments cannot break the command line.
+        let script = script.encode_utf16().flat_map(|character| character.to_le_bytes()).collect::<Vec<_>>();
+        format!("powershell -NoProfile -ExecutionPolicy Bypass -EncodedCommand {}", BASE64_STANDARD.encode(script))
+    }
+
     pub unsafe fn load_profile(&self, profile_name: Option<String>, is_autostart: bool) -> Result<()> {
         let profile_name = if let Some(profile_name) = profile_name {
             profile_name
@@ -1079,67 +2863,44 @@ impl AppUI {
             return Err(anyhow!("Profile name is empty."));
         }
 
+        // Only the locked profile itself can be loaded while it's active. Autostarts are never saved to disk, so they don't break it.
+        if !is_autostart {
+            if let Some(locked_profile) = active_locked_profile(self.game_selected().read().unwrap().key()) {
+                if locked_profile != profile_name {
+                    return Err(anyhow!(tre("profile_locked_error", &[&locked_profile])));
+                }
+            }
+        }
+
         match self.game_profiles().read().unwrap().get(&profile_name) {
             Some(profile) => {
-
-                // First, disable all mods, so we return to a neutral state.
-                self.mod_list_ui().model().block_signals(true);
-
-                for cat in 0..self.mod_list_ui().model().row_count_0a() {
-                    let category = self.mod_list_ui().model().item_1a(cat);
-                    for row in 0..category.row_count() {
-                        let item = category.child_1a(row);
-                        item.set_check_state(CheckState::Unchecked);
-                    }
+                if !is_autostart {
+                    self.take_undo_snapshot("undo_operation_load_profile");
                 }
 
-
-                // Then, enable the mods from the profile in the UI.
-                for mod_id in profile.load_order().mods() {
-                    let mod_id = QString::from_std_str(mod_id);
-                    for cat in 0..self.mod_list_ui().model().row_count_0a() {
-                        let category = self.mod_list_ui().model().item_1a(cat);
-                        for row in 0..category.row_count() {
-                            let item = category.child_1a(row);
-                            if !item.is_null() && item.data_1a(VALUE_MOD_ID).to_string().compare_q_string(&mod_id) == 0 {
-                                item.set_check_state(CheckState::Checked);
-                            }
-                        }
-                    }
+                // If the profile has a MCT preset, swap it in so it's used on the next launch.
+                if let Some(mct_preset) = profile.mct_preset() {
+                    let game_info = self.game_selected().read().unwrap();
+                    let game_path = setting_path(game_info.key());
+                    apply_mct_preset(&game_info, &game_path, mct_preset)?;
                 }
 
-                self.mod_list_ui().model().block_signals(false);
+                self.apply_load_order(profile.load_order(), is_autostart)?;
 
-                let game_info = self.game_selected().read().unwrap();
-                let game_path = setting_path(game_info.key());
-                let game_data_path = game_info.data_path(&game_path)?;
+                if !is_autostart {
+                    set_last_profile(self.game_selected().read().unwrap().key(), &profile_name);
+                }
 
-                // Then do the same for the backend. Keep in mind that if it's an autostart we have to avoid saving these changes to disk.
-                if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
-                    game_config.mods_mut().values_mut().for_each(|modd| { modd.set_enabled(false); });
+                // Loading a locked profile makes it the active one, so the mod list becomes read-only until it's unlocked.
+                if *profile.locked() && !is_autostart {
+                    let game = self.game_selected().read().unwrap().clone();
+                    set_active_locked_profile(game.key(), Some(&profile_name));
 
-                    for mod_id in profile.load_order().mods() {
-                        if let Some(ref mut modd) = game_config.mods_mut().get_mut(mod_id) {
-                            modd.set_enabled(true);
-                        }
+                    if let Some(ref game_config) = *self.game_config().read().unwrap() {
+                        self.mod_list_ui().load(&game, game_config)?;
                     }
 
-                    // Replace the current load order with the one from the profile, and update it.
-                    *self.game_load_order().write().unwrap() = profile.load_order().clone();
-                    let mut load_order = self.game_load_order().write().unwrap();
-                    load_order.update(game_config, &game_data_path);
-
-                    // Reload the pack list.
-
-                    // No need to do the expensive stuff on autostart, as it'll never get shown.
-                    if !is_autostart {
-                        load_order.save(&game_info)?;
-
-                        let game_path = setting_path(game_info.key());
-                        self.pack_list_ui().load(game_config, &game_info, &game_path, &load_order)?;
-                        self.data_list_ui().set_enabled(false);
-                        game_config.save(&game_info)?;
-                    }
+                    self.update_profile_lock_ui();
                 }
 
                 Ok(())
@@ -1148,122 +2909,453 @@ impl AppUI {
         }
     }
 
-    pub unsafe fn save_profile(&self) -> Result<()> {
-        let profile_name = self.actions_ui().profile_combobox().current_text().to_std_string();
-        if profile_name.is_empty() {
-            return Err(anyhow!("Profile name is empty."));
-        }
+    /// This function replaces the current load order and enabled mods with the ones from the provided load order.
+    ///
+    /// If it's an autostart we have to avoid saving these changes to disk.
+    pub unsafe fn apply_load_order(&self, load_order: &LoadOrder, is_autostart: bool) -> Result<()> {
 
-        let mut profile = Profile::default();
-        profile.set_id(profile_name.to_owned());
-        profile.set_game(self.game_selected().read().unwrap().key().to_string());
-        profile.set_load_order(self.game_load_order().read().unwrap().clone());
+        // First, disable all mods, so we return to a neutral state.
+        self.mod_list_ui().model().block_signals(true);
 
-        self.game_profiles().write().unwrap().insert(profile_name.to_owned(), profile.clone());
+        for cat in 0..self.mod_list_ui().model().row_count_0a() {
+            let category = self.mod_list_ui().model().item_1a(cat);
+            for row in 0..category.row_count() {
+                let item = category.child_1a(row);
+                item.set_check_state(CheckState::Unchecked);
+            }
+        }
 
-        self.actions_ui().profile_model().clear();
-        for profile in self.game_profiles().read().unwrap().keys() {
-            self.actions_ui().profile_combobox().add_item_q_string(&QString::from_std_str(profile));
+        // Then, enable the mods from the load order in the UI.
+        for mod_id in load_order.mods() {
+            let mod_id = QString::from_std_str(mod_id);
+            for cat in 0..self.mod_list_ui().model().row_count_0a() {
+                let category = self.mod_list_ui().model().item_1a(cat);
+                for row in 0..category.row_count() {
+                    let item = category.child_1a(row);
+                    if !item.is_null() && item.data_1a(VALUE_MOD_ID).to_string().compare_q_string(&mod_id) == 0 {
+                        item.set_check_state(CheckState::Checked);
+                    }
+                }
+            }
         }
 
-        // Make sure the one we saved stays selected!!!
-        self.actions_ui().profile_combobox().set_current_text(&QString::from_std_str(&profile_name));
+        self.mod_list_ui().model().block_signals(false);
 
-        profile.save(&self.game_selected().read().unwrap(), &profile_name)
-    }
+        let game_info = self.game_selected().read().unwrap();
+        let game_path = setting_path(game_info.key());
+        let game_data_path = game_info.data_path(&game_path)?;
 
-    /// This returns the selection REVERSED!!!
-    pub unsafe fn mod_list_selection(&self) -> Vec<CppBox<QModelIndex>> {
-        self.mod_list_ui().mod_list_selection()
-    }
+        // Then do the same for the backend.
+        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+            game_config.enable_only_load_order_mods(load_order);
 
-    /// This returns the selection REVERSED!!!
-    pub unsafe fn pack_list_selection(&self) -> Vec<CppBox<QModelIndex>> {
-        self.pack_list_ui().pack_list_selection()
-    }
+            // Replace the current load order with the provided one, and update it.
+            *self.game_load_order().write().unwrap() = load_order.clone();
+            let mut game_load_order = self.game_load_order().write().unwrap();
+            game_load_order.update(game_config, &game_data_path);
 
-    /// This returns the selection REVERSED!!!
-    pub unsafe fn data_list_selection(&self) -> Vec<CppBox<QModelIndex>> {
-        self.data_list_ui().data_list_selection()
-    }
+            // Reload the pack list. No need to do the expensive stuff on autostart, as it'll never get shown.
+            if !is_autostart {
+                game_load_order.save(&game_info)?;
 
-    /// This function pops up a modal asking you if you're sure you want to do an action that may result in loss of data.
-    pub unsafe fn are_you_sure(&self, message: &str) -> bool {
+                self.pack_list_ui().load(game_config, &game_info, &game_path, &game_load_order)?;
+                self.data_list_ui().set_enabled(false);
+                game_config.save(&game_info)?;
+            }
+        }
 
-        // Create the dialog and run it (Yes => 3, No => 4).
-        QMessageBox::from_2_q_string_icon3_int_q_widget(
-            &qtr("are_you_sure_title"),
-            &qtr(message),
-            q_message_box::Icon::Warning,
-            65536, // No
-            16384, // Yes
-            1, // By default, select yes.
-            self.main_window(),
-        ).exec() == 3
+        Ok(())
     }
 
-    /// This function creates the stylesheet used for the dark theme in windows.
-    pub fn dark_stylesheet() -> Result<String> {
-        let mut file = File::open(ASSETS_PATH.join("dark-theme.qss"))?;
-        let mut string = String::new();
-        file.read_to_string(&mut string)?;
-        Ok(string.replace("{assets_path}", &ASSETS_PATH.to_string_lossy().replace('\\', "/")))
+    /// This function reloads the list of load order slots of the current game.
+    pub unsafe fn reload_load_order_slots_list(&self) {
+        self.actions_ui().load_order_slot_model().clear();
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            for slot in game_config.load_order_slots().keys() {
+                self.actions_ui().load_order_slot_combobox().add_item_q_string(&QString::from_std_str(slot));
+            }
+        }
     }
 
-    /// This function is used to load/reload a theme live.
-    pub unsafe fn reload_theme(&self) {
-        let app = QCoreApplication::instance();
-        let qapp = app.static_downcast::<QApplication>();
-        let use_dark_theme = setting_bool("dark_mode");
+    /// This function switches to the load order slot selected in the actions bar.
+    ///
+    /// Unlike profiles, slots only change which mods are enabled and their order. Categories and launch options are kept.
+    pub unsafe fn load_load_order_slot(&self) -> Result<()> {
+        self.check_profile_lock()?;
 
-        // Initialize the globals before applying anything.
-        let light_style_sheet = ref_from_atomic(&*LIGHT_STYLE_SHEET);
-        let light_palette = ref_from_atomic(&*LIGHT_PALETTE);
-        let dark_palette = ref_from_atomic(&*DARK_PALETTE);
+        let slot_name = self.actions_ui().load_order_slot_combobox().current_text().to_std_string();
+        let load_order = self.game_config().read().unwrap()
+            .as_ref()
+            .and_then(|game_config| game_config.load_order_slots().get(&slot_name).cloned())
+            .ok_or_else(|| anyhow!(tre("load_order_slot_not_found", &[&slot_name])))?;
 
-        // On Windows, we use the dark theme switch to control the Style, StyleSheet and Palette.
-        if cfg!(target_os = "windows") {
-            if use_dark_theme {
-                QApplication::set_style_q_string(&QString::from_std_str("fusion"));
-                QApplication::set_palette_1a(dark_palette);
-                if let Ok(dark_stylesheet) = Self::dark_stylesheet() {
-                    qapp.set_style_sheet(&QString::from_std_str(dark_stylesheet));
-                }
+        self.take_undo_snapshot("undo_operation_load_load_order_slot");
+        self.apply_load_order(&load_order, false)
+    }
 
-                self.github_button().set_icon(&QIcon::from_q_string(&QString::from_std_str(format!("{}/icons/github.svg", ASSETS_PATH.to_string_lossy()))));
-                self.actions_ui().update_icons();
-            } else {
-                QApplication::set_style_q_string(&QString::from_std_str("windowsvista"));
-                QApplication::set_palette_1a(light_palette);
-                qapp.set_style_sheet(light_style_sheet);
+    /// This function stores the current load order in the slot with the name written in the actions bar, replacing it if it already exists.
+    pub unsafe fn save_load_order_slot(&self) -> Result<()> {
+        let slot_name = self.actions_ui().load_order_slot_combobox().current_text().to_std_string().trim().to_owned();
+        if slot_name.is_empty() {
+            return Err(anyhow!(tr("load_order_slot_name_empty")));
+        }
 
-                self.github_button().set_icon(&QIcon::from_q_string(&QString::from_std_str(format!("{}/icons/github-dark.svg", ASSETS_PATH.to_string_lossy()))));
-                self.actions_ui().update_icons();
+        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+            let load_order = self.game_load_order().read().unwrap().clone();
+            game_config.load_order_slots_mut().insert(slot_name.to_owned(), load_order);
+            game_config.save(&self.game_selected().read().unwrap())?;
+        }
+
+        self.reload_load_order_slots_list();
+
+        // Make sure the one we saved stays selected!!!
+        self.actions_ui().load_order_slot_combobox().set_current_text(&QString::from_std_str(&slot_name));
+        Ok(())
+    }
+
+    /// This function deletes the load order slot selected in the actions bar. The current load order is not changed.
+    pub unsafe fn delete_load_order_slot(&self) -> Result<()> {
+        let slot_name = self.actions_ui().load_order_slot_combobox().current_text().to_std_string();
+        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+            if game_config.load_order_slots_mut().remove(&slot_name).is_none() {
+                return Err(anyhow!(tre("load_order_slot_not_found", &[&slot_name])));
+            }
+
+            game_config.save(&self.game_selected().read().unwrap())?;
+        }
+
+        self.reload_load_order_slots_list();
+        Ok(())
+    }
+
+    /// This function enables and disables the provided mods on top of the current load order.
+    ///
+    /// Like with profiles, if it's an autostart we have to avoid saving these changes to disk.
+    pub unsafe fn apply_mod_overrides(&self, enable: &[String], disable: &[String], is_autostart: bool) -> Result<()> {
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            if let Some(mod_id) = enable.iter().chain(disable.iter()).find(|mod_id| !game_config.mods().contains_key(*mod_id)) {
+                return Err(anyhow!("Mod {} not found.", mod_id));
             }
         }
 
-        // On MacOS, we use the dark theme switch to control the StyleSheet and Palette.
-        else if cfg!(target_os = "macos") {
-            if use_dark_theme {
-                QApplication::set_palette_1a(dark_palette);
-                if let Ok(dark_stylesheet) = Self::dark_stylesheet() {
-                    qapp.set_style_sheet(&QString::from_std_str(dark_stylesheet));
+        if !is_autostart {
+            self.enable_mods(enable)?;
+            return self.disable_mods(disable);
+        }
+
+        // On autostart the UI is never shown, so we only need to update the backend.
+        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+            for (mod_id, enabled) in enable.iter().map(|mod_id| (mod_id, true)).chain(disable.iter().map(|mod_id| (mod_id, false))) {
+                if let Some(ref mut modd) = game_config.mods_mut().get_mut(mod_id) {
+                    modd.set_enabled(enabled);
                 }
-            } else {
-                QApplication::set_palette_1a(light_palette);
-                qapp.set_style_sheet(light_style_sheet);
             }
+
+            let game_info = self.game_selected().read().unwrap();
+            let game_path = setting_path(game_info.key());
+            let game_data_path = game_info.data_path(&game_path)?;
+            self.game_load_order().write().unwrap().update(game_config, &game_data_path);
+        }
+
+        Ok(())
+    }
+
+    pub unsafe fn save_profile(&self) -> Result<()> {
+        let profile_name = self.actions_ui().profile_combobox().current_text().to_std_string();
+        if profile_name.is_empty() {
+            return Err(anyhow!("Profile name is empty."));
+        }
+
+        // Read-only profiles are usually shared between players, so they must not be overwritten.
+        if self.game_profiles().read().unwrap().get(&profile_name).is_some_and(|profile| *profile.read_only()) {
+            return Err(anyhow!(tre("profile_read_only_error", &[&profile_name])));
+        }
+
+        // Locked profiles hold the mods of a campaign, so they cannot be overwritten until they're unlocked.
+        if self.game_profiles().read().unwrap().get(&profile_name).is_some_and(|profile| *profile.locked()) {
+            return Err(anyhow!(tre("profile_locked_error", &[&profile_name])));
+        }
+
+        let mut profile = Profile::default();
+        profile.set_id(profile_name.to_owned());
+        profile.set_game(self.game_selected().read().unwrap().key().to_string());
+        profile.set_load_order(self.game_load_order().read().unwrap().clone());
+
+        // If the game has MCT settings, store them as a preset with the same name as the profile.
+        {
+            let game = self.game_selected().read().unwrap();
+            let game_path = setting_path(game.key());
+            if supports_mct_presets(&game) && !mct_settings_files(&game, &game_path).is_empty() {
+                save_mct_preset(&game, &game_path, &profile_name)?;
+                profile.set_mct_preset(Some(profile_name.to_owned()));
+            }
+        }
+
+        self.game_profiles().write().unwrap().insert(profile_name.to_owned(), profile.clone());
+
+        self.update_profile_combobox(self.game_selected().read().unwrap().key());
+
+        // Make sure the one we saved stays selected!!!
+        self.actions_ui().profile_combobox().set_current_text(&QString::from_std_str(&profile_name));
+
+        profile.save(&self.game_selected().read().unwrap(), &profile_name)
+    }
+
+    /// This function saves the mods the selected save was made with as a new profile, so the modset of a campaign can be recovered later.
+    pub unsafe fn save_profile_from_save(&self) -> Result<()> {
+        let save_index = self.actions_ui().save_combobox().current_index();
+        let save = if save_index > 0 {
+            self.game_saves().read().unwrap().get(save_index as usize - 1).cloned()
+        } else {
+            None
+        };
+
+        let save = save.ok_or_else(|| anyhow!(tr("profile_from_save_no_save")))?;
+
+        // Saves can be big, so we read them in the background.
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetModsFromSave(save.path().to_path_buf()));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        let pack_names = match response {
+            Response::VecString(pack_names) => pack_names,
+            Response::Error(error) => return Err(error),
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        };
+
+        // Save names can contain characters not allowed in profile names, so we replace them in the suggested name.
+        let save_name = save.path().file_stem().map(|name| name.to_string_lossy().to_string()).unwrap_or_else(|| save.name().to_owned());
+        let save_name = sanitize_id(&save_name);
+        let dialog = QInputDialog::new_1a(self.main_window());
+        dialog.set_window_title(&qtr("profile_from_save"));
+        dialog.set_label_text(&qtr("profile_name"));
+        dialog.set_input_mode(InputMode::TextInput);
+        dialog.set_text_value(&QString::from_std_str(tre("profile_from_save_default_name", &[&save_name])));
+
+        if dialog.exec() != 1 {
+            return Ok(());
+        }
+
+        let profile_name = dialog.text_value().to_std_string().trim().to_owned();
+        if profile_name.is_empty() {
+            return Err(anyhow!("Profile name is empty."));
+        }
+
+        if !is_valid_id(&profile_name) {
+            return Err(anyhow!(tre("profile_name_invalid", &[&profile_name])));
         }
 
-        // Linux and company.
-        else if use_dark_theme {
-            qt_widgets::QApplication::set_palette_1a(dark_palette);
-            if let Ok(dark_stylesheet) = Self::dark_stylesheet() {
-                qapp.set_style_sheet(&QString::from_std_str(dark_stylesheet));
+        if let Some(profile) = self.game_profiles().read().unwrap().get(&profile_name) {
+            if *profile.read_only() {
+                return Err(anyhow!(tre("profile_read_only_error", &[&profile_name])));
+            } else if *profile.locked() {
+                return Err(anyhow!(tre("profile_locked_error", &[&profile_name])));
+            } else if !self.are_you_sure("profile_from_save_overwrite") {
+                return Ok(());
             }
+        }
+
+        // The save contains the packs in the order they were loaded, so we keep that order by making the load order manual.
+        let mut load_order = LoadOrder::default();
+        load_order.set_automatic(false);
+
+        let mut missing = vec![];
+        match *self.game_config().read().unwrap() {
+            Some(ref game_config) => for pack_name in &pack_names {
+                match game_config.mods().get(pack_name) {
+                    Some(modd) => if modd.pack_type() == &PFHFileType::Movie {
+                        load_order.movies_mut().push(pack_name.to_owned());
+                    } else {
+                        load_order.mods_mut().push(pack_name.to_owned());
+                    },
+                    None => missing.push(pack_name.to_owned()),
+                }
+            },
+            None => return Err(anyhow!(tr("game_config_error"))),
+        }
+
+        let mut profile = Profile::default();
+        profile.set_id(profile_name.to_owned());
+        profile.set_game(self.game_selected().read().unwrap().key().to_string());
+        profile.set_load_order(load_order);
+        profile.save(&self.game_selected().read().unwrap(), &profile_name)?;
+
+        self.game_profiles().write().unwrap().insert(profile_name.to_owned(), profile);
+
+        self.update_profile_combobox(self.game_selected().read().unwrap().key());
+
+        self.actions_ui().profile_combobox().set_current_text(&QString::from_std_str(&profile_name));
+
+        // Mods no longer installed cannot be part of the profile, so we tell the user which ones they need to get back.
+        if missing.is_empty() {
+            show_dialog(self.main_window(), tre("profile_from_save_success", &[&profile_name]), true);
+        } else {
+            show_dialog(self.main_window(), tre("profile_from_save_missing_mods", &[&profile_name, &missing.join("<br/>")]), false);
+        }
+
+        Ok(())
+    }
+
+    /// This function locks and loads the profile selected in the actions bar, or unlocks the active locked profile.
+    ///
+    /// While a locked profile is active its mods and load order cannot be changed, so a campaign cannot be broken by an accidental toggle.
+    pub unsafe fn toggle_profile_lock(&self, lock: bool) -> Result<()> {
+        let result = if lock {
+            self.lock_profile()
         } else {
-            qt_widgets::QApplication::set_palette_1a(light_palette);
-            qapp.set_style_sheet(light_style_sheet);
+            self.unlock_profile()
+        };
+
+        // The button must reflect the real state, even if the change was cancelled or failed.
+        self.update_profile_lock_ui();
+        result
+    }
+
+    unsafe fn lock_profile(&self) -> Result<()> {
+        let profile_name = self.actions_ui().profile_combobox().current_text().to_std_string();
+        if !self.game_profiles().read().unwrap().contains_key(&profile_name) {
+            return Err(anyhow!(tr("profile_lock_no_profile")));
+        }
+
+        // Loading a locked profile is what activates the lock, so we just need to load it after marking it as locked.
+        self.set_profile_locked(&profile_name, true)?;
+        if let Err(error) = self.load_profile(Some(profile_name.to_owned()), false) {
+            self.set_profile_locked(&profile_name, false)?;
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    unsafe fn unlock_profile(&self) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        if let Some(profile_name) = active_locked_profile(game.key()) {
+            if !self.are_you_sure("profile_unlock_are_you_sure") {
+                return Ok(());
+            }
+
+            self.set_profile_locked(&profile_name, false)?;
+            set_active_locked_profile(game.key(), None);
+
+            if let Some(ref game_config) = *self.game_config().read().unwrap() {
+                self.mod_list_ui().load(&game, game_config)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This function marks the provided profile as locked or not, and saves it. Profiles deleted since they were locked are ignored.
+    unsafe fn set_profile_locked(&self, profile_name: &str, locked: bool) -> Result<()> {
+        let game = self.game_selected().read().unwrap();
+        if let Some(profile) = self.game_profiles().write().unwrap().get_mut(profile_name) {
+            profile.set_locked(locked);
+            profile.save(&game, profile_name)?;
+        }
+
+        Ok(())
+    }
+
+    /// This function returns an error if a locked profile is active for the selected game.
+    pub unsafe fn check_profile_lock(&self) -> Result<()> {
+        match active_locked_profile(self.game_selected().read().unwrap().key()) {
+            Some(profile_name) => Err(anyhow!(tre("profile_locked_error", &[&profile_name]))),
+            None => Ok(()),
+        }
+    }
+
+    /// This function updates the lock button, and the widgets that cannot be used with a locked profile, with the lock state of the selected game.
+    pub unsafe fn update_profile_lock_ui(&self) {
+        let locked_profile = active_locked_profile(self.game_selected().read().unwrap().key());
+
+        self.actions_ui().profile_lock_button().block_signals(true);
+        self.actions_ui().profile_lock_button().set_checked(locked_profile.is_some());
+        self.actions_ui().profile_lock_button().block_signals(false);
+
+        match locked_profile {
+            Some(ref profile_name) => self.actions_ui().profile_lock_button().set_tool_tip(&qtre("profile_unlock", &[profile_name])),
+            None => self.actions_ui().profile_lock_button().set_tool_tip(&qtr("profile_lock")),
+        }
+
+        self.pack_list_ui().automatic_order_button().set_enabled(locked_profile.is_none());
+    }
+
+    /// This returns the selection REVERSED!!!
+    pub unsafe fn mod_list_selection(&self) -> Vec<CppBox<QModelIndex>> {
+        self.mod_list_ui().mod_list_selection()
+    }
+
+    /// This returns the selection REVERSED!!!
+    pub unsafe fn pack_list_selection(&self) -> Vec<CppBox<QModelIndex>> {
+        self.pack_list_ui().pack_list_selection()
+    }
+
+    /// This returns the selection REVERSED!!!
+    pub unsafe fn data_list_selection(&self) -> Vec<CppBox<QModelIndex>> {
+        self.data_list_ui().data_list_selection()
+    }
+
+    /// This function keeps a copy of the current game config and load order, so the operation about to be done can be undone.
+    ///
+    /// Only one snapshot is kept. Taking a new one replaces the previous one.
+    pub unsafe fn take_undo_snapshot(&self, operation: &str) {
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            let game = self.game_selected().read().unwrap();
+            let snapshot = UndoSnapshot::new(operation, &game, game_config, &self.game_load_order().read().unwrap());
+            *self.undo_snapshot().write().unwrap() = Some(snapshot);
+
+            self.actions_ui().undo_button().set_enabled(true);
+            self.actions_ui().undo_button().set_tool_tip(&qtre("undo_last_operation", &[&tr(operation)]));
+        }
+    }
+
+    /// This function restores the game config and load order from before the last risky operation, then reloads the mod list.
+    pub unsafe fn undo_last_operation(&self) -> Result<()> {
+        let snapshot = match *self.undo_snapshot().read().unwrap() {
+            Some(ref snapshot) => snapshot.clone(),
+            None => return Ok(()),
+        };
+
+        self.check_profile_lock()?;
+
+        if !self.are_you_sure("undo_last_operation_are_you_sure") {
+            return Ok(());
+        }
+
+        snapshot.restore(&self.game_selected().read().unwrap())?;
+
+        *self.undo_snapshot().write().unwrap() = None;
+        self.actions_ui().undo_button().set_enabled(false);
+        self.actions_ui().undo_button().set_tool_tip(&qtr("undo_last_operation_none"));
+
+        // The reload loads the config from disk, so it picks up the restored one.
+        self.actions_ui().reload_button().click();
+
+        Ok(())
+    }
+
+    /// This function pops up a modal asking you if you're sure you want to do an action that may result in loss of data.
+    pub unsafe fn are_you_sure(&self, message: &str) -> bool {
+
+        // Create the dialog and run it (Yes => 3, No => 4).
+        QMessageBox::from_2_q_string_icon3_int_q_widget(
+            &qtr("are_you_sure_title"),
+            &qtr(message),
+            q_message_box::Icon::Warning,
+            65536, // No
+            16384, // Yes
+            1, // By default, select yes.
+            self.main_window(),
+        ).exec() == 3
+    }
+
+    /// This function is used to load/reload a theme live.
+    pub unsafe fn reload_theme(&self) {
+        let use_dark_theme = apply_theme(&setting_string("theme"), &setting_string("theme_accent_color"));
+
+        // On Windows, the icons depend on the theme being light or dark.
+        if cfg!(target_os = "windows") {
+            let github_icon = if use_dark_theme { "github.svg" } else { "github-dark.svg" };
+            self.github_button().set_icon(&QIcon::from_q_string(&QString::from_std_str(format!("{}/icons/{}", ASSETS_PATH.to_string_lossy(), github_icon))));
+            self.actions_ui().update_icons();
         }
     }
 
@@ -1279,11 +3371,14 @@ impl AppUI {
         let string_text_edit: QPtr<QTextEdit> = find_widget(&main_widget.static_upcast(), "string_text_edit")?;
         let modlist_mode_radio_button: QPtr<QRadioButton> = find_widget(&main_widget.static_upcast(), "modlist_mode_radio_button")?;
         let runcher_mode_radio_button: QPtr<QRadioButton> = find_widget(&main_widget.static_upcast(), "runcher_mode_radio_button")?;
+        let names_mode_radio_button: QPtr<QRadioButton> = find_widget(&main_widget.static_upcast(), "names_mode_radio_button")?;
         let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
         button_box.button(StandardButton::Ok).released().connect(dialog.slot_accept());
 
         modlist_mode_radio_button.set_text(&qtr("import_string_modlist_mode"));
         runcher_mode_radio_button.set_text(&qtr("import_string_runcher_mode"));
+        names_mode_radio_button.set_text(&qtr("import_string_names_mode"));
+        names_mode_radio_button.set_tool_tip(&qtr("import_string_names_mode_tt"));
         runcher_mode_radio_button.set_checked(true);
 
         let mode_group = QButtonGroup::new_1a(&dialog);
@@ -1291,6 +3386,7 @@ impl AppUI {
         // Configure the `Game Selected` Menu.
         mode_group.add_button_1a(&modlist_mode_radio_button);
         mode_group.add_button_1a(&runcher_mode_radio_button);
+        mode_group.add_button_1a(&names_mode_radio_button);
 
         if let Some(ref string) = string {
             dialog.set_window_title(&qtr("load_order_string_title_copy"));
@@ -1299,6 +3395,7 @@ impl AppUI {
 
             modlist_mode_radio_button.set_visible(false);
             runcher_mode_radio_button.set_visible(false);
+            names_mode_radio_button.set_visible(false);
         } else {
             dialog.set_window_title(&qtr("load_order_string_title_paste"));
             info_label.set_text(&qtr("load_order_string_info_paste"));
@@ -1312,6 +3409,8 @@ impl AppUI {
         if dialog.exec() == 1 && string.is_none() {
             let mode = if runcher_mode_radio_button.is_checked() {
                 ImportedLoadOrderMode::Runcher(string_text_edit.to_plain_text().to_std_string())
+            } else if names_mode_radio_button.is_checked() {
+                ImportedLoadOrderMode::Names(string_text_edit.to_plain_text().to_std_string())
             } else {
                 ImportedLoadOrderMode::Modlist(string_text_edit.to_plain_text().to_std_string())
             };
@@ -1322,14 +3421,116 @@ impl AppUI {
         }
     }
 
+    /// This function matches the provided list of mod names against the installed mods, and lets the user fix the matches before importing them.
+    ///
+    /// Returns the matched mods in the order of the list, or None if the user cancelled the import.
+    pub unsafe fn name_matches_dialog(&self, text: &str) -> Result<Option<Vec<ShareableMod>>> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let steam_id = steam_id(&game, &game_path).ok();
+
+        let (matches, mod_names) = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => {
+                let matches = match_mod_names(game_config, text);
+                let mod_names = matches.iter()
+                    .flat_map(|name_match| name_match.candidates().iter())
+                    .filter_map(|(mod_id, _)| game_config.mods().get(mod_id))
+                    .map(|modd| (modd.id().to_owned(), modd.name().to_owned()))
+                    .collect::<HashMap<_, _>>();
+
+                (matches, mod_names)
+            }
+            None => return Err(anyhow!(tr("game_config_error"))),
+        };
+
+        if matches.is_empty() {
+            return Err(anyhow!(tr("name_matches_empty")));
+        }
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { NAME_MATCHES_VIEW_DEBUG } else { NAME_MATCHES_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+        dialog.set_window_title(&qtr("name_matches_title"));
+
+        let matches_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "matches_label")?;
+        let matches_table_view: QPtr<QTableView> = find_widget(&main_widget.static_upcast(), "matches_table_view")?;
+        matches_label.set_text(&qtr("name_matches_info"));
+
+        let model = QStandardItemModel::new_1a(&dialog);
+        matches_table_view.set_model(&model);
+        model.set_column_count(3);
+        model.set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("name_matches_column_name")).into_ptr());
+        model.set_horizontal_header_item(1, QStandardItem::from_q_string(&qtr("name_matches_column_mod")).into_ptr());
+        model.set_horizontal_header_item(2, QStandardItem::from_q_string(&qtr("name_matches_column_workshop")).into_ptr());
+
+        for (row, name_match) in matches.iter().enumerate() {
+            let items = QListOfQStandardItem::new();
+            let item_name = QStandardItem::from_q_string(&QString::from_std_str(name_match.name()));
+            if name_match.is_ambiguous() {
+                item_name.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("data-warning")));
+                item_name.set_tool_tip(&qtr("name_matches_ambiguous"));
+            }
+
+            items.append_q_standard_item(&item_name.into_ptr().as_mut_raw_ptr());
+            items.append_q_standard_item(&QStandardItem::new().into_ptr().as_mut_raw_ptr());
+            items.append_q_standard_item(&QStandardItem::new().into_ptr().as_mut_raw_ptr());
+            model.append_row_q_list_of_q_standard_item(items.into_ptr().as_ref().unwrap());
+
+            let mod_combobox = QComboBox::new_1a(&matches_table_view);
+            mod_combobox.add_item_q_string(&qtr("name_matches_skip"));
+            for (mod_id, score) in name_match.candidates() {
+                let text = match mod_names.get(mod_id) {
+                    Some(name) if !name.is_empty() => format!("{} ({}) - {:.0}%", name, mod_id, score * 100.0),
+                    _ => format!("{} - {:.0}%", mod_id, score * 100.0),
+                };
+
+                mod_combobox.add_item_q_string_q_variant(&QString::from_std_str(text), &QVariant::from_q_string(&QString::from_std_str(mod_id)));
+            }
+
+            let best = name_match.best().and_then(|best| name_match.candidates().iter().position(|(mod_id, _)| mod_id == best));
+            mod_combobox.set_current_index(best.map(|index| index as i32 + 1).unwrap_or_default());
+            matches_table_view.set_index_widget(&model.index_2a(row as i32, 1), mod_combobox.into_ptr());
+
+            // The workshop search of the Steam API only supports tags, so we can only send the user to the Workshop page for names we don't have.
+            if let Some(steam_id) = steam_id {
+                let link = format!("<a href=\"{}\">{}</a>", workshop_search_url(steam_id, name_match.name()), tr("name_matches_search_workshop"));
+                let link_label = QLabel::from_q_string(&QString::from_std_str(link));
+                link_label.set_open_external_links(true);
+                matches_table_view.set_index_widget(&model.index_2a(row as i32, 2), link_label.into_ptr());
+            }
+        }
+
+        matches_table_view.resize_columns_to_contents();
+
+        if dialog.exec() != 1 {
+            return Ok(None);
+        }
+
+        let mut mod_ids: Vec<(String, Option<String>)> = vec![];
+        for row in 0..model.row_count_0a() {
+            let mod_combobox: QPtr<QComboBox> = matches_table_view.index_widget(&model.index_2a(row, 1)).static_downcast();
+            let mod_id = mod_combobox.current_data_0a().to_string().to_std_string();
+            if !mod_id.is_empty() && mod_ids.iter().all(|(id, _)| id != &mod_id) {
+                mod_ids.push((mod_id, None));
+            }
+        }
+
+        Ok(Some(self.shareable_mods_from_packs(mod_ids)))
+    }
+
     pub unsafe fn load_order_from_shareable_mod_list(&self, shareable_mod_list: &[ShareableMod]) -> Result<()> {
+        self.check_profile_lock()?;
+        self.take_undo_snapshot("undo_operation_import_load_order");
+
+        let mut missing = vec![];
+        let mut to_verify = vec![];
+
         if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
 
             // Before we begin, we need to set all mods to disable. Otherwise, new load orders would get mods mixed up.
             game_config.mods_mut().iter_mut().for_each(|(_, modd)| { modd.set_enabled(false); });
 
-            let mut missing = vec![];
-            let mut wrong_hash = vec![];
             let mut ids = vec![];
 
             for modd in shareable_mod_list {
@@ -1337,10 +3538,7 @@ impl AppUI {
                     Some(modd_local) => {
                         if let Some(path) = modd_local.paths().first() {
                             if !modd.hash().is_empty() {
-                                let current_hash = try_digest(path.as_path())?;
-                                if &current_hash != modd.hash() {
-                                    wrong_hash.push(modd.clone());
-                                }
+                                to_verify.push((modd.clone(), path.to_path_buf()));
                             }
 
                             modd_local.set_enabled(true);
@@ -1369,37 +3567,111 @@ impl AppUI {
             self.data_list_ui().set_enabled(false);
 
             game_config.save(&game)?;
+        }
+
+        // Verify the packs once the load order is imported, as the UI is kept responsive while we do it.
+        if !missing.is_empty() || !to_verify.is_empty() {
+            self.verify_shared_pack_hashes(missing, to_verify)?;
+        }
 
-            // Report any missing mods.
-            if !missing.is_empty() || !wrong_hash.is_empty() {
-                let mut message = String::new();
+        Ok(())
+    }
 
-                if !missing.is_empty() {
-                    message.push_str(&format!("<p>The following mods have not been found in the mod list:<p> <ul>{}</ul>",
-                        missing.iter().map(|modd| match modd.steam_id() {
-                            Some(steam_id) => format!("<li>{}: <a src=\"https://steamcommunity.com/sharedfiles/filedetails/?id={}\">{}</a></li>", modd.id(), steam_id, modd.name()),
-                            None => format!("<li>{}</li>", modd.id())
-                        }).collect::<Vec<_>>().join("\n")
-                    ));
-                }
+    /// This function checks the packs of an imported load order against the hashes they were shared with, in the background.
+    ///
+    /// If any of them is missing or different, the result of each check is shown in a table.
+    pub unsafe fn verify_shared_pack_hashes(&self, missing: Vec<ShareableMod>, to_verify: Vec<(ShareableMod, PathBuf)>) -> Result<()> {
+        let mut checks = missing.into_iter()
+            .map(|modd| (modd, HashCheck::Missing))
+            .collect::<Vec<_>>();
+
+        if !to_verify.is_empty() {
+            let packs = to_verify.iter()
+                .map(|(modd, path)| (path.to_path_buf(), modd.hash().to_owned()))
+                .collect::<Vec<_>>();
 
-                if !wrong_hash.is_empty() {
-                    message.push_str(&format!("<p>The following mods have been found, but their packs are different from the ones expected:<p> <ul>{}</ul>",
-                        wrong_hash.iter().map(|modd| match modd.steam_id() {
-                            Some(steam_id) => format!("<li>{}: <a src=\"https://steamcommunity.com/sharedfiles/filedetails/?id={}\">{}</a></li>", modd.id(), steam_id, modd.name()),
-                            None => format!("<li>{}</li>", modd.id())
-                        }).collect::<Vec<_>>().join("\n")
-                    ));
+            let progress_dialog = QProgressDialog::from_q_string2_int2_q_widget(&qtr("hash_verification_progress"), &QString::new(), 0, packs.len() as i32, self.main_window());
+            progress_dialog.set_window_title(&qtr("hash_verification_title"));
+            progress_dialog.set_window_modality(WindowModality::WindowModal);
+            progress_dialog.set_minimum_duration(0);
+            progress_dialog.show();
+
+            let receiver = CENTRAL_COMMAND.send_background(Command::VerifyPackHashes(packs));
+            let results = loop {
+                let response = CENTRAL_COMMAND.recv_try(&receiver);
+                match response {
+                    Response::Usize(checked) => progress_dialog.set_value(checked as i32),
+                    Response::VecHashCheck(results) => break results,
+                    _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
                 }
+            };
 
-                show_dialog(self.main_window(), message, false);
+            progress_dialog.close();
+            progress_dialog.delete_later();
+
+            checks.extend(to_verify.into_iter().map(|(modd, _)| modd).zip(results));
+        }
+
+        if checks.iter().all(|(_, check)| check == &HashCheck::Ok) {
+            return Ok(());
+        }
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { HASH_VERIFICATION_VIEW_DEBUG } else { HASH_VERIFICATION_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+        dialog.set_window_title(&qtr("hash_verification_title"));
+
+        let results_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "results_label")?;
+        let results_table_view: QPtr<QTableView> = find_widget(&main_widget.static_upcast(), "results_table_view")?;
+        results_label.set_text(&qtr("hash_verification_info"));
+
+        let model = QStandardItemModel::new_1a(&dialog);
+        results_table_view.set_model(&model);
+        model.set_column_count(3);
+        model.set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("hash_verification_column_mod")).into_ptr());
+        model.set_horizontal_header_item(1, QStandardItem::from_q_string(&qtr("hash_verification_column_result")).into_ptr());
+        model.set_horizontal_header_item(2, QStandardItem::from_q_string(&qtr("hash_verification_column_workshop")).into_ptr());
+
+        for (row, (modd, check)) in checks.iter().enumerate() {
+            let items = QListOfQStandardItem::new();
+            let item_mod = QStandardItem::from_q_string(&QString::from_std_str(if modd.name().is_empty() { modd.id() } else { modd.name() }));
+            item_mod.set_tool_tip(&QString::from_std_str(modd.id()));
+
+            let (icon, text) = match check {
+                HashCheck::Ok => ("checkmark", tr("hash_verification_ok")),
+                HashCheck::Mismatch => ("data-warning", tr("hash_verification_mismatch")),
+                HashCheck::Missing => ("data-error", tr("hash_verification_missing")),
+                HashCheck::Error(error) => ("data-error", tre("hash_verification_error", &[error.as_str()])),
+            };
+
+            let item_result = QStandardItem::from_q_string(&QString::from_std_str(text));
+            item_result.set_icon(&QIcon::from_theme_1a(&QString::from_std_str(icon)));
+
+            items.append_q_standard_item(&item_mod.into_ptr().as_mut_raw_ptr());
+            items.append_q_standard_item(&item_result.into_ptr().as_mut_raw_ptr());
+            items.append_q_standard_item(&QStandardItem::new().into_ptr().as_mut_raw_ptr());
+            model.append_row_q_list_of_q_standard_item(items.into_ptr().as_ref().unwrap());
+
+            // Only mods that are not what we expected need a way to get the right version.
+            if check != &HashCheck::Ok {
+                if let Some(steam_id) = modd.steam_id() {
+                    let link = format!("<a href=\"https://steamcommunity.com/sharedfiles/filedetails/?id={}\">{}</a>", steam_id, tr("hash_verification_open_workshop"));
+                    let link_label = QLabel::from_q_string(&QString::from_std_str(link));
+                    link_label.set_open_external_links(true);
+                    results_table_view.set_index_widget(&model.index_2a(row as i32, 2), link_label.into_ptr());
+                }
             }
         }
 
+        results_table_view.resize_columns_to_contents();
+        dialog.exec();
+
         Ok(())
     }
 
     pub unsafe fn batch_toggle_selected_mods(&self, toggle: bool) -> Result<()> {
+        self.check_profile_lock()?;
 
         // Lock the signals for the model, until the last item, so we avoid repeating full updates of the load order.
         self.mod_list_ui().model().block_signals(true);
@@ -1421,6 +3693,42 @@ impl AppUI {
         // Unlock the signals, then manually trigger a full load order rebuild.
         self.mod_list_ui().model().block_signals(false);
 
+        self.update_enabled_mods_from_mod_list()
+    }
+
+    /// This function disables the mods with the provided ids, updating the load order accordingly.
+    pub unsafe fn disable_mods(&self, mod_ids: &[String]) -> Result<()> {
+        self.set_mods_check_state(mod_ids, CheckState::Unchecked)
+    }
+
+    /// This function enables the provided mods in the mod list, and updates the load order with them.
+    pub unsafe fn enable_mods(&self, mod_ids: &[String]) -> Result<()> {
+        self.set_mods_check_state(mod_ids, CheckState::Checked)
+    }
+
+    unsafe fn set_mods_check_state(&self, mod_ids: &[String], state: CheckState) -> Result<()> {
+        self.check_profile_lock()?;
+
+        // Lock the signals for the model, until the last item, so we avoid repeating full updates of the load order.
+        self.mod_list_ui().model().block_signals(true);
+
+        for category in 0..self.mod_list_ui().model().row_count_0a() {
+            let cat_item = self.mod_list_ui().model().item_2a(category, 0);
+            for mod_row in 0..cat_item.row_count() {
+                let mod_item = cat_item.child_2a(mod_row, 0);
+                if !mod_item.is_null() && mod_item.is_checkable() && mod_ids.contains(&mod_item.data_1a(VALUE_MOD_ID).to_string().to_std_string()) {
+                    mod_item.set_check_state(state);
+                }
+            }
+        }
+
+        self.mod_list_ui().model().block_signals(false);
+
+        self.update_enabled_mods_from_mod_list()
+    }
+
+    /// This function updates the enabled state of the mods in the game config from the mod list, and rebuilds the load order with it.
+    pub unsafe fn update_enabled_mods_from_mod_list(&self) -> Result<()> {
         if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
             for category in 0..self.mod_list_ui().model().row_count_0a() {
                 let cat_item = self.mod_list_ui().model().item_2a(category, 0);
@@ -1485,6 +3793,8 @@ impl AppUI {
             return Err(anyhow!("Dude, did you just tried to delete the {} category?!! You monster!!!", DEFAULT_CATEGORY));
         }
 
+        self.take_undo_snapshot("undo_operation_delete_category");
+
         for cat_to_delete in &selection {
 
             // Update the backend.
@@ -1553,6 +3863,10 @@ impl AppUI {
                     if let Some(pos) = game_config.categories_order_mut().iter().position(|x| x == &old_cat_name) {
                         game_config.categories_order_mut()[pos] = new_cat_name.to_owned();
                     }
+
+                    if let Some(style) = game_config.category_styles_mut().remove(&old_cat_name) {
+                        game_config.category_styles_mut().insert(new_cat_name.to_owned(), style);
+                    }
                 }
             }
 
@@ -1616,9 +3930,323 @@ impl AppUI {
         Ok(())
     }
 
+    /// This function lets the user change the color and icon of the selected category.
+    pub unsafe fn style_category(&self) -> Result<()> {
+        let selection = self.mod_list_selection();
+
+        // NOTE: We assume there is only one selection. This breaks with more.
+        let cat_index = &selection[0];
+        let cat_name = cat_index.data_1a(2).to_string().to_std_string();
+
+        let style = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => game_config.category_styles().get(&cat_name).cloned().unwrap_or_default(),
+            None => return Ok(()),
+        };
+
+        if let Some(style) = self.mod_list_ui().category_style_dialog(&style)? {
+            let cat_item = self.mod_list_ui().model().item_from_index(cat_index);
+            ModListUI::apply_category_style(cat_item, Some(&style));
+
+            if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+                game_config.set_category_style(&cat_name, style);
+
+                let game_info = self.game_selected().read().unwrap();
+                game_config.save(&game_info)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This function lets the user edit the rules to automatically assign categories to new mods, previewing what they'd move.
+    ///
+    /// Accepted rules are applied right away to the mods not yet assigned to a category.
+    pub unsafe fn edit_category_rules(&self) -> Result<()> {
+        let (rules, categories) = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => (game_config.category_rules().to_vec(), game_config.categories_order().to_vec()),
+            None => return Err(anyhow!(tr("game_config_error"))),
+        };
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { CATEGORY_RULES_VIEW_DEBUG } else { CATEGORY_RULES_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+        dialog.set_window_title(&qtr("category_rules_title"));
+
+        let rules_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "rules_label")?;
+        let rules_table_view: QPtr<QTableView> = find_widget(&main_widget.static_upcast(), "rules_table_view")?;
+        let add_rule_button: QPtr<QPushButton> = find_widget(&main_widget.static_upcast(), "add_rule_button")?;
+        let remove_rule_button: QPtr<QPushButton> = find_widget(&main_widget.static_upcast(), "remove_rule_button")?;
+        let preview_button: QPtr<QPushButton> = find_widget(&main_widget.static_upcast(), "preview_button")?;
+        let preview_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "preview_label")?;
+        let preview_table_view: QPtr<QTableView> = find_widget(&main_widget.static_upcast(), "preview_table_view")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+        button_box.button(StandardButton::Ok).released().connect(dialog.slot_accept());
+
+        rules_label.set_text(&qtr("category_rules_info"));
+        add_rule_button.set_text(&qtr("category_rules_add"));
+        remove_rule_button.set_text(&qtr("category_rules_remove"));
+        preview_button.set_text(&qtr("category_rules_preview"));
+
+        let rules_model = QStandardItemModel::new_1a(&rules_table_view).into_q_ptr();
+        rules_table_view.set_model(&rules_model);
+        rules_model.set_column_count(3);
+        rules_model.set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("category_rules_column_category")).into_ptr());
+        rules_model.set_horizontal_header_item(1, QStandardItem::from_q_string(&qtr("category_rules_column_field")).into_ptr());
+        rules_model.set_horizontal_header_item(2, QStandardItem::from_q_string(&qtr("category_rules_column_pattern")).into_ptr());
+
+        let preview_model = QStandardItemModel::new_1a(&preview_table_view).into_q_ptr();
+        preview_table_view.set_model(&preview_model);
+
+        for rule in &rules {
+            Self::add_category_rule_row(&rules_model, &rules_table_view, rule, &categories);
+        }
+
+        let add_rule = SlotNoArgs::new(&dialog, clone!(
+            categories,
+            rules_model,
+            rules_table_view => move || {
+                let mut rule = CategoryRule::default();
+                if let Some(category) = categories.first() {
+                    rule.set_category(category.to_owned());
+                }
+
+                Self::add_category_rule_row(&rules_model, &rules_table_view, &rule, &categories);
+            }
+        ));
+
+        let remove_rule = SlotNoArgs::new(&dialog, clone!(
+            rules_model,
+            rules_table_view => move || {
+                let selection = rules_table_view.selection_model().selected_rows_1a(0);
+                let mut rows = (0..selection.count_0a()).map(|index| selection.at(index).row()).collect::<Vec<_>>();
+                rows.sort();
+                rows.reverse();
+
+                for row in rows {
+                    rules_model.remove_row_1a(row);
+                }
+            }
+        ));
+
+        let game_config = self.game_config.clone();
+        let preview = SlotNoArgs::new(&dialog, clone!(
+            game_config,
+            rules_model,
+            rules_table_view,
+            preview_label,
+            preview_model => move || {
+                let rules = Self::category_rules_from_table(&rules_model, &rules_table_view);
+                let moves = match *game_config.read().unwrap() {
+                    Some(ref game_config) => category_rule_moves(&rules, game_config)
+                        .into_iter()
+                        .map(|(mod_id, category)| {
+                            let name = game_config.mods().get(&mod_id).map(|modd| modd.name().to_owned()).unwrap_or_default();
+                            (mod_id, name, category)
+                        })
+                        .collect::<Vec<_>>(),
+                    None => vec![],
+                };
+
+                preview_model.clear();
+                preview_model.set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("category_rules_column_mod")).into_ptr());
+                preview_model.set_horizontal_header_item(1, QStandardItem::from_q_string(&qtr("category_rules_column_category")).into_ptr());
+
+                for (mod_id, name, category) in &moves {
+                    let row = QListOfQStandardItem::new();
+                    let text = if name.is_empty() || name == mod_id { mod_id.to_owned() } else { format!("{} ({})", name, mod_id) };
+                    row.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(text)).into_ptr().as_mut_raw_ptr());
+                    row.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(category)).into_ptr().as_mut_raw_ptr());
+                    preview_model.append_row_q_list_of_q_standard_item(row.into_ptr().as_ref().unwrap());
+                }
+
+                preview_label.set_text(&qtre("category_rules_preview_info", &[&moves.len().to_string()]));
+            }
+        ));
+
+        add_rule_button.released().connect(&add_rule);
+        remove_rule_button.released().connect(&remove_rule);
+        preview_button.released().connect(&preview);
+
+        // Show what the current rules would move from the start.
+        preview_button.click();
+        rules_table_view.resize_columns_to_contents();
+
+        if dialog.exec() != 1 {
+            return Ok(());
+        }
+
+        let rules = Self::category_rules_from_table(&rules_model, &rules_table_view);
+        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+            game_config.set_category_rules(rules);
+            game_config.apply_category_rules();
+
+            let game = self.game_selected().read().unwrap();
+            game_config.save(&game)?;
+            self.mod_list_ui().load(&game, game_config)?;
+        }
+
+        Ok(())
+    }
+
+    /// This function adds a rule to the table of the category rules editor.
+    unsafe fn add_category_rule_row(model: &QPtr<QStandardItemModel>, table_view: &QPtr<QTableView>, rule: &CategoryRule, categories: &[String]) {
+        let row = QListOfQStandardItem::new();
+        row.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(rule.category())).into_ptr().as_mut_raw_ptr());
+        row.append_q_standard_item(&QStandardItem::new().into_ptr().as_mut_raw_ptr());
+        row.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(rule.pattern())).into_ptr().as_mut_raw_ptr());
+        model.append_row_q_list_of_q_standard_item(row.into_ptr().as_ref().unwrap());
+
+        let row = model.row_count_0a() - 1;
+        let item_category = model.item_2a(row, 0);
+        item_category.set_tool_tip(&QString::from_std_str(tre("category_rules_category_tt", &[&categories.join(", ")])));
+
+        let field_combobox = QComboBox::new_1a(table_view);
+        for field in CategoryRuleField::all() {
+            field_combobox.add_item_q_string(&qtr(field.locale_key()));
+        }
+
+        let current = CategoryRuleField::all().iter().position(|field| field == rule.field()).unwrap_or_default();
+        field_combobox.set_current_index(current as i32);
+        table_view.set_index_widget(&model.index_2a(row, 1), field_combobox.into_ptr());
+    }
+
+    /// This function returns the rules in the table of the category rules editor, in order. Empty rows are ignored.
+    unsafe fn category_rules_from_table(model: &QPtr<QStandardItemModel>, table_view: &QPtr<QTableView>) -> Vec<CategoryRule> {
+        let mut rules = vec![];
+        for row in 0..model.row_count_0a() {
+            let category = model.index_2a(row, 0).data_0a().to_string().to_std_string().trim().to_owned();
+            let pattern = model.index_2a(row, 2).data_0a().to_string().to_std_string();
+            if category.is_empty() || pattern.is_empty() {
+                continue;
+            }
+
+            let field_combobox: QPtr<QComboBox> = table_view.index_widget(&model.index_2a(row, 1)).static_downcast();
+            let field = CategoryRuleField::all().get(field_combobox.current_index() as usize).copied().unwrap_or_default();
+
+            let mut rule = CategoryRule::default();
+            rule.set_category(category);
+            rule.set_field(field);
+            rule.set_pattern(pattern);
+            rules.push(rule);
+        }
+
+        rules
+    }
+
+    /// This function saves the grouping selected in the mod list and reloads the list with it.
+    pub unsafe fn change_mod_list_grouping(&self) -> Result<()> {
+        self.mod_list_ui().save_grouping();
+
+        let game = self.game_selected().read().unwrap().clone();
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            self.mod_list_ui().load(&game, game_config)?;
+        }
+
+        Ok(())
+    }
+
+    /// This function saves if ignored mods should be shown in the mod list and reloads the list with it.
+    pub unsafe fn toggle_show_ignored_mods(&self) -> Result<()> {
+        set_setting_bool("show_ignored_mods", self.mod_list_ui().show_ignored_mods().is_checked());
+
+        let game = self.game_selected().read().unwrap().clone();
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            self.mod_list_ui().load(&game, game_config)?;
+        }
+
+        Ok(())
+    }
+
+    /// This function marks the selected mods as ignored or not, and reloads the mod list with them.
+    ///
+    /// Ignored mods are disabled first, so they don't stay in the load order while hidden.
+    pub unsafe fn ignore_selected_mods(&self, ignore: bool) -> Result<()> {
+        let mod_ids = self.mod_list_selection()
+            .iter()
+            .filter(|index| !index.data_1a(VALUE_IS_CATEGORY).to_bool())
+            .map(|index| index.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+            .collect::<Vec<_>>();
+
+        if mod_ids.is_empty() {
+            return Ok(());
+        }
+
+        if ignore {
+            self.disable_mods(&mod_ids)?;
+        }
+
+        let game = self.game_selected().read().unwrap().clone();
+        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+            for mod_id in &mod_ids {
+                if let Some(modd) = game_config.mods_mut().get_mut(mod_id) {
+                    modd.set_ignored(ignore);
+                }
+            }
+
+            game_config.save(&game)?;
+            self.mod_list_ui().load(&game, game_config)?;
+        }
+
+        Ok(())
+    }
+
+    /// This function asks the user for the loose folder to load instead of the pack of the selected mod, and starts using it.
+    pub unsafe fn set_working_folder(&self) -> Result<()> {
+        let mod_id = match self.mod_list_selection().first() {
+            Some(index) if !index.data_1a(VALUE_IS_CATEGORY).to_bool() => index.data_1a(VALUE_MOD_ID).to_string().to_std_string(),
+            _ => return Ok(()),
+        };
+
+        let file_dialog = QFileDialog::from_q_widget_q_string(&self.main_window, &qtr("set_working_folder_select_folder"));
+        file_dialog.set_file_mode(FileMode::Directory);
+        file_dialog.set_options(QFlags::from(QFileDialogOption::ShowDirsOnly));
+
+        if file_dialog.exec() == 1 {
+            let folder = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            self.update_working_folder(&mod_id, Some(folder), true)?;
+        }
+
+        Ok(())
+    }
+
+    /// This function toggles between loading the pack or the working folder of the selected mod.
+    pub unsafe fn toggle_working_folder(&self, enable: bool) -> Result<()> {
+        let mod_id = match self.mod_list_selection().first() {
+            Some(index) if !index.data_1a(VALUE_IS_CATEGORY).to_bool() => index.data_1a(VALUE_MOD_ID).to_string().to_std_string(),
+            _ => return Ok(()),
+        };
+
+        self.update_working_folder(&mod_id, None, enable)
+    }
+
+    /// This function changes the working folder of a mod, keeping the current one if none is provided.
+    unsafe fn update_working_folder(&self, mod_id: &str, folder: Option<PathBuf>, enable: bool) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+            if let Some(modd) = game_config.mods_mut().get_mut(mod_id) {
+                if folder.is_some() {
+                    modd.set_working_folder(folder);
+                }
+
+                modd.set_use_working_folder(enable);
+            }
+
+            game_config.save(&game)?;
+            self.mod_list_ui().load(&game, game_config)?;
+        }
+
+        Ok(())
+    }
+
     /// Parent is model means dest_parent is a modelindex FROM THE MODEL, NOT FROM THE VIEW.
     pub unsafe fn move_category(&self, dest_parent: Ref<QModelIndex>, dest_row: i32, parent_is_model: bool) -> Result<()> {
 
+        // Computed groups are read-only.
+        if self.mod_list_ui().grouping() != ModListGrouping::Categories {
+            return Ok(());
+        }
+
         // Rare case, but possible due to selection weirdness.
         let selection = self.mod_list_selection();
         if selection.is_empty() {
@@ -1645,6 +4273,11 @@ impl AppUI {
             return Err(anyhow!("Cannot move the default category {}.", DEFAULT_CATEGORY));
         }
 
+        // Virtual categories are not part of the config, so they cannot be moved, nor can mods be moved into them.
+        if cats && selection.iter().any(|selection| selection.data_1a(VALUE_IS_VIRTUAL_CATEGORY).to_bool()) {
+            return Err(anyhow!("Cannot move the {} category.", tr("mod_list_recently_added")));
+        }
+
         // dest_parent may be invalid if we're dropping between categories.
         if cats && dest_parent.is_valid() {
             return Ok(());
@@ -1655,12 +4288,17 @@ impl AppUI {
             return Ok(());
         }
 
+        // The virtual category, if any, is always the first row, so it has to be skipped when calculating the position of categories.
+        let first_item = self.mod_list_ui().model().item_1a(0);
+        let virtual_rows = if !first_item.is_null() && first_item.data_1a(VALUE_IS_VIRTUAL_CATEGORY).to_bool() { 1 } else { 0 };
+
         if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
 
             // Categories move.
             //
             // The offset is so we get the correct destination after we remove the categories that may be before the destination.
             if cats {
+                let dest_row = (dest_row - virtual_rows).max(0);
                 let cats_to_move = selection.iter().rev().map(|x| x.data_0a().to_string().to_std_string()).collect::<Vec<_>>();
                 let offset = cats_to_move.iter()
                     .filter_map(|cat| game_config.categories_order().iter().position(|cat2| cat == cat2))
@@ -1679,7 +4317,7 @@ impl AppUI {
                 rows.reverse();
 
                 for (index, row) in rows.iter().enumerate() {
-                    let pos = dest_row as usize + index - offset;
+                    let pos = dest_row as usize + index - offset + virtual_rows as usize;
                     self.mod_list_ui().model().insert_row_int_q_list_of_q_standard_item(pos as i32, row);
                 }
             }
@@ -1709,6 +4347,10 @@ impl AppUI {
                     self.mod_list_ui().filter().map_to_source(category_index_visual)
                 };
 
+                if category_index_logical.data_1a(VALUE_IS_VIRTUAL_CATEGORY).to_bool() {
+                    return Err(anyhow!("Mods cannot be moved into the {} category.", tr("mod_list_recently_added")));
+                }
+
                 let dest_category = category_index_logical.data_0a().to_string().to_std_string();
                 let mut offset = 0;
                 if let Some(dest_mods) = game_config.categories().get(&dest_category) {
@@ -1776,6 +4418,7 @@ impl AppUI {
     }
 
     pub unsafe fn move_pack(&self, new_position: i32) -> Result<()> {
+        self.check_profile_lock()?;
 
         // Rare case, but possible due to selection weirdness.
         let selection = self.pack_list_selection();
@@ -1788,14 +4431,16 @@ impl AppUI {
             return Ok(());
         }
 
-        // Do NOT allow placing a mod pack under a movie pack.
+        // Do NOT allow placing a mod pack under a movie pack, or under the vanilla packs.
         let mut load_order = self.game_load_order().write().unwrap();
-        if !load_order.movies().is_empty() && new_position as usize > load_order.mods().len() {
+        if new_position as usize > load_order.mods().len() {
             return Ok(());
         }
 
         // This one is easier than with categories: we just calculate the offset, take the items at selected positions, then re-add them in their new position.
-        let packs_to_move = selection.iter().rev().map(|x| x.data_1a(VALUE_MOD_ID).to_string().to_std_string()).collect::<Vec<_>>();
+        // Packs in groups drag the rest of their group with them, so groups are always moved as a unit.
+        let selected = selection.iter().rev().map(|x| x.data_1a(VALUE_MOD_ID).to_string().to_std_string()).collect::<Vec<_>>();
+        let packs_to_move = load_order.expand_to_groups(&selected);
         let offset = load_order.mods().iter()
             .enumerate()
             .filter(|(index, mod_id)| (index < &(new_position as usize) && packs_to_move.contains(mod_id)))
@@ -1806,9 +4451,24 @@ impl AppUI {
             let pos: i32 = new_position + index as i32 - offset as i32;
             load_order.mods_mut().insert(pos as usize, mod_id.to_owned());
         }
+
+        // If groups are involved the final order may differ from a plain move, so just reload the list.
+        let has_groups = !load_order.groups().is_empty();
+        if has_groups {
+            load_order.apply_groups();
+        }
+
         let game_info = self.game_selected().read().unwrap();
         load_order.save(&game_info)?;
 
+        if has_groups {
+            drop(load_order);
+            drop(game_info);
+            self.reload_pack_list()?;
+            self.select_packs(&packs_to_move);
+            return Ok(());
+        }
+
         // Visual move.
         let mut rows = selection.iter().map(|x| self.pack_list_ui().model().take_row(x.row()).into_ptr()).collect::<Vec<_>>();
         rows.reverse();
@@ -1820,116 +4480,601 @@ impl AppUI {
 
         for row in 0..self.pack_list_ui().model().row_count_0a() {
             let item = self.pack_list_ui().model().item_2a(row, 3);
-            if !item.is_null() {
+            if !item.is_null() && !self.pack_list_ui().model().item_2a(row, 0).data_1a(VALUE_IS_VANILLA).to_bool() {
                 item.set_data_2a(&QVariant::from_int(row), 2);
             }
         }
 
+        self.pack_list_ui().update_sort_values();
+
         Ok(())
     }
 
-    pub unsafe fn generate_open_in_tools_submenu(app_ui: &Rc<AppUI>) {
-        let menu = app_ui.mod_list_ui().open_in_tool_menu();
-        menu.clear();
+    /// This function moves the selected packs one position up or down in the load order, keeping them selected.
+    pub unsafe fn move_selected_packs(&self, up: bool) -> Result<()> {
+        self.check_profile_lock()?;
 
-        let mut tools = app_ui.tools().read().unwrap().clone();
-        tools.tools_mut().sort_by(|tool_a, tool_b| tool_a.name().cmp(tool_b.name()));
+        if self.pack_list_ui().automatic_order_button().is_checked() {
+            return Err(anyhow!(tr("edit_load_order_with_auto_on")));
+        }
 
-        let game = app_ui.game_selected().read().unwrap();
-        for tool in tools.tools() {
-            if tool.games().iter().any(|x| x == game.key()) {
-                let action = menu.add_action_q_string(&QString::from_std_str(tool.name()));
-                let slot = SlotNoArgs::new(menu, clone!(
-                    tool,
-                    app_ui => move || {
-                        if let Some(ref game_config) = *app_ui.game_config().read().unwrap() {
-                            let selection = app_ui.mod_list_selection();
-                            let mod_index = &selection[0];
-                            let mod_id = mod_index.data_1a(VALUE_MOD_ID).to_string().to_std_string();
+        // Mod rows are in load order, so we can get the rows of the groups of the selected packs from their positions.
+        let selected = self.pack_list_selection().iter().map(|index| index.data_1a(VALUE_MOD_ID).to_string().to_std_string()).collect::<Vec<_>>();
+        let packs_to_move = self.game_load_order().read().unwrap().expand_to_groups(&selected);
+        let rows = {
+            let load_order = self.game_load_order().read().unwrap();
+            let mut rows = self.pack_list_selection().iter().map(|index| index.row()).collect::<Vec<_>>();
+            rows.extend(packs_to_move.iter().filter_map(|mod_id| load_order.mods().iter().position(|x| x == mod_id)).map(|row| row as i32));
+            rows.sort();
+            rows.dedup();
+            rows
+        };
 
-                            if let Some(modd) = game_config.mods().get(&mod_id) {
-                                if let Some(path) = modd.paths().first() {
-                                    if let Err(error) = std::process::Command::new(tool.path().to_string_lossy().to_string())
-                                        .arg(path.to_string_lossy().to_string())
-                                        .spawn() {
-                                        show_dialog(app_ui.main_window(), error, false);
-                                    }
-                                }
-                            }
-                        }
-                    }
-                ));
+        let (first, last) = match (rows.iter().min(), rows.iter().max()) {
+            (Some(first), Some(last)) => (*first, *last),
+            _ => return Ok(()),
+        };
 
-                action.triggered().connect(&slot);
+        // move_pack expects the position the packs are dropped into, so to go down we need to skip one more row.
+        // If the neighbour is in a group, we skip the whole group so we don't end up in the middle of it.
+        let new_position = {
+            let load_order = self.game_load_order().read().unwrap();
+            let neighbour_group_len = |row: i32| load_order.mods()
+                .get(row as usize)
+                .and_then(|mod_id| load_order.group(mod_id))
+                .map(|group| group.mods().iter().filter(|mod_id| load_order.mods().contains(mod_id)).count() as i32)
+                .unwrap_or(1);
+
+            if up {
+                if first == 0 {
+                    return Ok(());
+                }
+
+                (first - neighbour_group_len(first - 1)).max(0)
+            } else {
+                if last + 1 >= self.pack_list_ui().model().row_count_0a() {
+                    return Ok(());
+                }
+
+                last + 1 + neighbour_group_len(last + 1)
             }
+        };
+
+        // Select the full groups, so move_pack moves them too.
+        self.select_packs(&packs_to_move);
+        self.move_pack(new_position)?;
+
+        // If groups are involved, move_pack already reloaded the list and reselected the packs.
+        if !self.game_load_order().read().unwrap().groups().is_empty() {
+            return Ok(());
+        }
+
+        // Re-select the moved packs, so the shortcut can be used repeatedly.
+        let start = new_position - rows.iter().filter(|row| **row < new_position).count() as i32;
+        let selection_model = self.pack_list_ui().tree_view().selection_model();
+        selection_model.clear_selection();
+
+        for row in start..start + rows.len() as i32 {
+            let index = self.pack_list_ui().filter().map_from_source(&self.pack_list_ui().model().index_2a(row, 0));
+            selection_model.select_q_model_index_q_flags_selection_flag(&index, SelectionFlag::Select | SelectionFlag::Rows);
         }
+
+        Ok(())
     }
 
-    /// Function to move files from /content to /secondary, or /data.
-    fn move_to_destination(&self, data_path: &Path, secondary_path: &Option<PathBuf>, steam_user_id: &str, game: &GameInfo, modd: &mut Mod, mod_name: &str, pack: &mut Pack, new_pack_type: bool) -> Result<()> {
+    /// This function moves the selected packs to the provided position in the load order, keeping them selected.
+    ///
+    /// Positions past the end of the load order move the packs to the bottom.
+    pub unsafe fn move_selected_packs_to(&self, position: usize) -> Result<()> {
+        self.check_profile_lock()?;
 
-        // Sometimes they come canonicalized, sometimes dont. This kinda fixes it.
-        let new_path_in_data = data_path.join(&mod_name);
-        let new_path_in_data = std::fs::canonicalize(new_path_in_data.clone()).unwrap_or(new_path_in_data);
-        let mut in_secondary = false;
+        if self.pack_list_ui().automatic_order_button().is_checked() {
+            return Err(anyhow!(tr("edit_load_order_with_auto_on")));
+        }
 
-        // First try to move it to secondary if it's not in /data. Only if it's not in /data already.
-        if let Some(ref secondary_path) = &secondary_path {
-            if !new_path_in_data.is_file() {
-                let new_path_in_secondary = secondary_path.join(&mod_name);
+        // Selection is reversed, and movie packs cannot be moved.
+        let selected = self.pack_list_selection()
+            .iter()
+            .rev()
+            .filter(|index| self.pack_list_ui().model().index_2a(index.row(), 1).data_0a().to_string().to_std_string() == PFHFileType::Mod.to_string())
+            .map(|index| index.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+            .collect::<Vec<_>>();
 
-                // Copy the files unless it exists and its ours.
-                if (!new_path_in_secondary.is_file() || (new_path_in_secondary.is_file() && &steam_user_id != modd.creator())) && pack.save(Some(&new_path_in_secondary), &game, &None).is_ok() {
-                    if !modd.paths().contains(&new_path_in_secondary) {
-                        modd.paths_mut().insert(0, new_path_in_secondary);
-                    }
+        if selected.is_empty() {
+            return Ok(());
+        }
 
-                    if new_pack_type {
-                        modd.set_pack_type(pack.pfh_file_type());
-                    }
+        // Packs in groups drag the rest of their group with them.
+        let packs_to_move = {
+            let mut load_order = self.game_load_order().write().unwrap();
+            let packs_to_move = load_order.expand_to_groups(&selected);
+            load_order.mods_mut().retain(|mod_id| !packs_to_move.contains(mod_id));
 
-                    in_secondary = true;
-                }
+            let position = position.min(load_order.mods().len());
+            for (index, mod_id) in packs_to_move.iter().enumerate() {
+                load_order.mods_mut().insert(position + index, mod_id.to_owned());
             }
-        }
 
-        // If the move to secondary failed, try to do the same with /data.
-        if !in_secondary {
+            load_order.apply_groups();
 
-            // Copy the files unless it exists and its ours.
-            if (!new_path_in_data.is_file() || (new_path_in_data.is_file() && &steam_user_id != modd.creator())) && pack.save(Some(&new_path_in_data), &game, &None).is_ok() {
-                if !modd.paths().contains(&new_path_in_data) {
-                    modd.paths_mut().insert(0, new_path_in_data);
-                }
+            let game_info = self.game_selected().read().unwrap();
+            load_order.save(&game_info)?;
+            packs_to_move
+        };
 
-                if new_pack_type {
-                    modd.set_pack_type(pack.pfh_file_type());
-                }
-            }
-        }
+        self.reload_pack_list()?;
+
+        // Re-select the moved packs, so they can be moved again.
+        self.select_packs(&packs_to_move);
 
         Ok(())
     }
 
-    /// Function to generate a pack from a Shogun 2 map bin data.
-    fn generate_map_pack(&self, game: &GameInfo, data_dec: &[u8], pack_name: &str, map_name: &str) -> Result<Pack> {
-
-        // Get all the files into memory to generate its pack.
-        let mut files = HashMap::new();
-        let mut data_dec = Cursor::new(data_dec);
-        loop {
+    /// This function selects the packs with the provided ids in the pack list.
+    pub unsafe fn select_packs(&self, mod_ids: &[String]) {
+        let selection_model = self.pack_list_ui().tree_view().selection_model();
+        selection_model.clear_selection();
 
-            // At the end of the last file there's a 0A 00 00 00 that doesn't seem to be part of a file.
-            let len = data_dec.len()?;
-            if len < 4 || data_dec.position() >= len - 4 {
-                break;
+        for row in 0..self.pack_list_ui().model().row_count_0a() {
+            let item = self.pack_list_ui().model().item_2a(row, 0);
+            if !item.is_null() && mod_ids.contains(&item.data_1a(VALUE_MOD_ID).to_string().to_std_string()) {
+                let index = self.pack_list_ui().filter().map_from_source(&item.index());
+                selection_model.select_q_model_index_q_flags_selection_flag(&index, SelectionFlag::Select | SelectionFlag::Rows);
             }
-
-            let file_name = data_dec.read_string_u16_0terminated()?;
-            let size = data_dec.read_u64()?;
-            let data = data_dec.read_slice(size as usize, false)?;
-
-            files.insert(file_name, data);
+        }
+    }
+
+    /// This function locks the relative order of the selected packs, creating a group with them.
+    pub unsafe fn group_selected_packs(&self) -> Result<()> {
+        self.check_profile_lock()?;
+
+        let selected = self.pack_list_selection()
+            .iter()
+            .filter(|index| self.pack_list_ui().model().index_2a(index.row(), 1).data_0a().to_string().to_std_string() == PFHFileType::Mod.to_string())
+            .map(|index| index.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+            .collect::<Vec<_>>();
+
+        if selected.len() < 2 {
+            return Ok(());
+        }
+
+        let dialog = QInputDialog::new_1a(self.main_window());
+        dialog.set_window_title(&qtr("pack_group_lock"));
+        dialog.set_label_text(&qtr("pack_group_name"));
+        dialog.set_input_mode(InputMode::TextInput);
+
+        if dialog.exec() == 1 {
+            let name = dialog.text_value().to_std_string();
+            let name = if name.trim().is_empty() { selected.last().cloned().unwrap_or_default() } else { name.trim().to_owned() };
+
+            {
+                let mut load_order = self.game_load_order().write().unwrap();
+                load_order.create_group(&name, &selected);
+
+                let game_info = self.game_selected().read().unwrap();
+                load_order.save(&game_info)?;
+            }
+
+            self.reload_pack_list()?;
+            self.select_packs(&selected);
+        }
+
+        Ok(())
+    }
+
+    /// This function removes the groups the selected packs are in, unlocking their relative order.
+    pub unsafe fn ungroup_selected_packs(&self) -> Result<()> {
+        self.check_profile_lock()?;
+
+        let selected = self.pack_list_selection()
+            .iter()
+            .map(|index| index.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+            .collect::<Vec<_>>();
+
+        {
+            let mut load_order = self.game_load_order().write().unwrap();
+            load_order.remove_groups(&selected);
+
+            let game_info = self.game_selected().read().unwrap();
+            load_order.save(&game_info)?;
+        }
+
+        self.reload_pack_list()?;
+        self.select_packs(&selected);
+
+        Ok(())
+    }
+
+    /// This function asks the user for a position in the load order, then moves the selected packs to it.
+    pub unsafe fn move_selected_packs_to_position(&self) -> Result<()> {
+        let max = self.game_load_order().read().unwrap().mods().len() as i32;
+
+        let dialog = QInputDialog::new_1a(self.main_window());
+        dialog.set_window_title(&qtr("pack_move_to_position"));
+        dialog.set_label_text(&qtr("pack_move_to_position_label"));
+        dialog.set_input_mode(InputMode::IntInput);
+        dialog.set_int_range(1, max.max(1));
+        dialog.set_int_value(1);
+
+        if dialog.exec() == 1 {
+            self.move_selected_packs_to(dialog.int_value() as usize - 1)?;
+        }
+
+        Ok(())
+    }
+
+    /// This function asks the user for a list of pack names, and reorders the load order following it.
+    pub unsafe fn import_pack_order(&self) -> Result<()> {
+        self.check_profile_lock()?;
+
+        if self.pack_list_ui().automatic_order_button().is_checked() {
+            return Err(anyhow!(tr("edit_load_order_with_auto_on")));
+        }
+
+        // Reuse the load order string dialog, as we just need a place to paste the text.
+        let template_path = if cfg!(debug_assertions) { LOAD_ORDER_STRING_VIEW_DEBUG } else { LOAD_ORDER_STRING_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+
+        let info_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "string_label")?;
+        let string_text_edit: QPtr<QTextEdit> = find_widget(&main_widget.static_upcast(), "string_text_edit")?;
+        let modlist_mode_radio_button: QPtr<QRadioButton> = find_widget(&main_widget.static_upcast(), "modlist_mode_radio_button")?;
+        let runcher_mode_radio_button: QPtr<QRadioButton> = find_widget(&main_widget.static_upcast(), "runcher_mode_radio_button")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+        button_box.button(StandardButton::Ok).released().connect(dialog.slot_accept());
+        button_box.add_button_standard_button(StandardButton::Cancel);
+
+        dialog.set_window_title(&qtr("pack_import_order"));
+        info_label.set_text(&qtr("pack_import_order_info"));
+        modlist_mode_radio_button.set_visible(false);
+        runcher_mode_radio_button.set_visible(false);
+
+        if dialog.exec() == 1 {
+            let text = string_text_edit.to_plain_text().to_std_string();
+            let unmatched = {
+                let mut load_order = self.game_load_order().write().unwrap();
+                let unmatched = load_order.reorder_from_names(&text);
+
+                let game_info = self.game_selected().read().unwrap();
+                load_order.save(&game_info)?;
+                unmatched
+            };
+
+            self.reload_pack_list()?;
+
+            if !unmatched.is_empty() {
+                let unmatched = unmatched.iter().map(|name| format!("<li>{}</li>", name)).join("");
+                show_dialog(self.main_window(), tre("pack_import_order_unmatched", &[&unmatched]), false);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This function shows the changes we suggest to the load order so the more specific packs win their conflicts, and applies them if the user wants.
+    pub unsafe fn show_order_suggestions(&self) -> Result<()> {
+        let suggestions = order_suggestions(&self.game_load_order().read().unwrap());
+        if suggestions.is_empty() {
+            show_dialog(self.main_window(), tr("pack_conflict_suggestions_none"), true);
+            return Ok(());
+        }
+
+        let list = suggestions.iter()
+            .map(|suggestion| {
+                let reason = match suggestion.reason() {
+                    SuggestionReason::Dependency => tre("pack_conflict_reason_dependency", &[suggestion.mod_id(), suggestion.above()]),
+                    SuggestionReason::Overlap(shared, total) => tre("pack_conflict_reason_overlap", &[&shared.to_string(), &total.to_string(), suggestion.mod_id(), suggestion.above()]),
+                };
+
+                format!("<li>{}</li>", tre("pack_conflict_suggestion", &[suggestion.mod_id(), suggestion.above(), &reason]))
+            })
+            .join("");
+
+        // Automatic load orders are rebuilt on each change, so in those we can only apply the suggestions through the setting.
+        let automatic = *self.game_load_order().read().unwrap().automatic();
+        let dialog = QMessageBox::from_q_widget(self.main_window());
+        dialog.set_window_title(&qtr("pack_conflict_suggestions"));
+        dialog.set_icon(q_message_box::Icon::Information);
+
+        if automatic {
+            dialog.set_text(&qtre("pack_conflict_suggestions_automatic", &[&list]));
+            dialog.add_button_standard_button(q_message_box::StandardButton::Close);
+            dialog.exec();
+            return Ok(());
+        }
+
+        dialog.set_text(&qtre("pack_conflict_suggestions_description", &[&list]));
+        let apply_button = dialog.add_button_q_string_button_role(&qtr("pack_conflict_suggestions_apply"), q_message_box::ButtonRole::AcceptRole);
+        dialog.add_button_standard_button(q_message_box::StandardButton::Cancel);
+        dialog.set_default_button_q_push_button(&apply_button);
+        dialog.exec();
+
+        if dialog.button_role(&dialog.clicked_button()) == q_message_box::ButtonRole::AcceptRole {
+            self.take_undo_snapshot("undo_operation_conflict_suggestions");
+
+            {
+                let mut load_order = self.game_load_order().write().unwrap();
+                apply_order_suggestions(&mut load_order, &suggestions);
+
+                let game_info = self.game_selected().read().unwrap();
+                load_order.save(&game_info)?;
+            }
+
+            self.reload_pack_list()?;
+        }
+
+        Ok(())
+    }
+
+    /// This function reloads the pack list from the current game config and load order.
+    pub unsafe fn reload_pack_list(&self) -> Result<()> {
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            let game_info = self.game_selected().read().unwrap();
+            let game_path = setting_path(game_info.key());
+            let load_order = self.game_load_order().read().unwrap();
+
+            self.pack_list_ui().load(game_config, &game_info, &game_path, &load_order)?;
+        }
+
+        Ok(())
+    }
+
+    /// This function reloads the data list after a change in the load order, if it's set to highlight the files that change packs.
+    pub unsafe fn update_data_list_delta(&self) -> Result<()> {
+        if !self.data_list_ui().delta_button().is_checked() {
+            return Ok(());
+        }
+
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            let game_info = self.game_selected().read().unwrap();
+            let game_path = setting_path(game_info.key());
+            let load_order = self.game_load_order().read().unwrap();
+
+            self.data_list_ui().load(game_config, &game_info, &game_path, &load_order)?;
+        }
+
+        Ok(())
+    }
+
+    /// This function asks the user if they want to send error reports to Sentry, and applies their choice.
+    ///
+    /// Nothing is sent until the user says yes, and saying no deletes the error reports already stored on disk.
+    pub unsafe fn ask_sentry_consent(&self) -> Result<()> {
+        let dialog = QMessageBox::from_q_widget(self.main_window());
+        dialog.set_window_title(&qtr("sentry_consent_title"));
+        dialog.set_icon(q_message_box::Icon::Question);
+        dialog.set_text(&qtr("sentry_consent_description"));
+
+        dialog.add_button_q_string_button_role(&qtr("sentry_consent_enable"), q_message_box::ButtonRole::AcceptRole);
+        let disable_button = dialog.add_button_q_string_button_role(&qtr("sentry_consent_disable"), q_message_box::ButtonRole::RejectRole);
+        dialog.set_default_button_q_push_button(&disable_button);
+        dialog.exec();
+
+        let enabled = dialog.button_role(&dialog.clicked_button()) == q_message_box::ButtonRole::AcceptRole;
+        set_setting_bool("enable_sentry", enabled);
+        set_setting_bool("sentry_consent_asked", true);
+
+        set_sentry_enabled(enabled)
+    }
+
+    /// This function toggles the selected mods. If any of them is disabled, it enables all of them. Otherwise, it disables them.
+    pub unsafe fn toggle_selected_mods(&self) -> Result<()> {
+        let enable = self.mod_list_selection()
+            .iter()
+            .filter(|index| !index.data_1a(VALUE_IS_CATEGORY).to_bool())
+            .map(|index| self.mod_list_ui().model().item_from_index(index))
+            .filter(|item| !item.is_null() && item.is_checkable())
+            .any(|item| item.check_state() != CheckState::Checked);
+
+        self.batch_toggle_selected_mods(enable)
+    }
+
+    /// This function performs the action configured in the settings for double-clicking a mod on the selected mods.
+    ///
+    /// Categories are ignored, as double-clicking them expands or collapses them.
+    pub unsafe fn mod_list_double_clicked(&self) -> Result<()> {
+        let selection = self.mod_list_selection();
+        if selection.is_empty() || selection.iter().any(|index| index.data_1a(VALUE_IS_CATEGORY).to_bool()) {
+            return Ok(());
+        }
+
+        match &*setting_string("mod_double_click_action") {
+            MOD_DOUBLE_CLICK_OPEN_WORKSHOP => self.mod_list_ui().open_in_steam().trigger(),
+            MOD_DOUBLE_CLICK_OPEN_FOLDER => self.mod_list_ui().open_in_explorer().trigger(),
+            MOD_DOUBLE_CLICK_OPEN_RPFM => {
+                let tool_path = self.tools().read().unwrap().tools().iter()
+                    .find(|tool| tool.path().ends_with(RPFM_EXECUTABLE))
+                    .map(|tool| tool.path().to_path_buf())
+                    .ok_or_else(|| anyhow!(tr("rpfm_not_found")))?;
+
+                let mut command = std::process::Command::new(tool_path.to_string_lossy().to_string());
+                if let Some(ref game_config) = *self.game_config().read().unwrap() {
+                    for index in &selection {
+                        let mod_id = index.data_1a(VALUE_MOD_ID).to_string().to_std_string();
+                        if let Some(path) = game_config.mods().get(&mod_id).and_then(|modd| modd.paths().first()) {
+                            command.arg(path.to_string_lossy().to_string());
+                        }
+                    }
+                }
+
+                command.spawn()?;
+            },
+            _ => self.toggle_selected_mods()?,
+        }
+
+        Ok(())
+    }
+
+    /// This function selects the next/previous available game, in the order they're in the game selected bar.
+    pub unsafe fn switch_game_selected(&self, next: bool) {
+        let actions = self.available_game_selected_actions();
+
+        if let Some(pos) = actions.iter().position(|action| action.is_checked()) {
+            let new_pos = if next {
+                (pos + 1) % actions.len()
+            } else {
+                (pos + actions.len() - 1) % actions.len()
+            };
+
+            if new_pos != pos {
+                actions[new_pos].trigger();
+            }
+        }
+    }
+
+    /// This function returns the actions of the games that can be selected, in the order they appear in the game bar.
+    unsafe fn available_game_selected_actions(&self) -> Vec<&QPtr<QAction>> {
+        [
+            self.game_selected_pharaoh_dynasties(),
+            self.game_selected_pharaoh(),
+            self.game_selected_warhammer_3(),
+            self.game_selected_troy(),
+            self.game_selected_three_kingdoms(),
+            self.game_selected_warhammer_2(),
+            self.game_selected_warhammer(),
+            self.game_selected_thrones_of_britannia(),
+            self.game_selected_attila(),
+            self.game_selected_rome_2(),
+            self.game_selected_shogun_2(),
+            self.game_selected_napoleon(),
+            self.game_selected_empire(),
+        ].into_iter()
+            .chain(self.game_selected_new_games().iter())
+            .filter(|action| action.is_enabled() && action.is_visible())
+            .collect()
+    }
+
+    /// This function asks the user which game to load. Returns the key of the chosen game, or None if the user cancelled the dialog.
+    pub unsafe fn ask_game_selected(&self) -> Option<String> {
+        let actions = self.available_game_selected_actions();
+        if actions.is_empty() {
+            return None;
+        }
+
+        let items = QStringList::new();
+        for action in &actions {
+            items.append_q_string(&action.text());
+        }
+
+        let dialog = QInputDialog::new_1a(self.main_window());
+        dialog.set_window_title(&qtr("startup_game_ask_title"));
+        dialog.set_label_text(&qtr("startup_game_ask_label"));
+        dialog.set_input_mode(InputMode::TextInput);
+        dialog.set_combo_box_items(&items);
+        dialog.set_combo_box_editable(false);
+
+        if dialog.exec() != 1 {
+            return None;
+        }
+
+        let selected = dialog.text_value().to_std_string();
+        actions.iter()
+            .find(|action| action.text().to_std_string() == selected)
+            .map(|action| Self::game_key_from_action(action))
+    }
+
+    pub unsafe fn generate_open_in_tools_submenu(app_ui: &Rc<AppUI>) {
+        let menu = app_ui.mod_list_ui().open_in_tool_menu();
+        menu.clear();
+
+        let mut tools = app_ui.tools().read().unwrap().clone();
+        tools.tools_mut().sort_by(|tool_a, tool_b| tool_a.name().cmp(tool_b.name()));
+
+        let game = app_ui.game_selected().read().unwrap();
+        for tool in tools.tools() {
+            if tool.games().iter().any(|x| x == game.key()) {
+                let action = menu.add_action_q_string(&QString::from_std_str(tool.name()));
+                let slot = SlotNoArgs::new(menu, clone!(
+                    tool,
+                    app_ui => move || {
+                        if let Some(ref game_config) = *app_ui.game_config().read().unwrap() {
+                            let selection = app_ui.mod_list_selection();
+                            let mod_index = &selection[0];
+                            let mod_id = mod_index.data_1a(VALUE_MOD_ID).to_string().to_std_string();
+
+                            if let Some(modd) = game_config.mods().get(&mod_id) {
+                                if let Some(path) = modd.paths().first() {
+                                    if let Err(error) = std::process::Command::new(tool.path().to_string_lossy().to_string())
+                                        .arg(path.to_string_lossy().to_string())
+                                        .spawn() {
+                                        show_dialog(app_ui.main_window(), error, false);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                ));
+
+                action.triggered().connect(&slot);
+            }
+        }
+    }
+
+    /// Function to move files from /content to /secondary, or /data.
+    fn move_to_destination(&self, data_path: &Path, secondary_path: &Option<PathBuf>, steam_user_id: &str, game: &GameInfo, modd: &mut Mod, mod_name: &str, pack: &mut Pack, new_pack_type: bool) -> Result<()> {
+
+        // Sometimes they come canonicalized, sometimes dont. This kinda fixes it.
+        let new_path_in_data = data_path.join(&mod_name);
+        let new_path_in_data = std::fs::canonicalize(new_path_in_data.clone()).unwrap_or(new_path_in_data);
+        let mut in_secondary = false;
+
+        // First try to move it to secondary if it's not in /data. Only if it's not in /data already.
+        if let Some(ref secondary_path) = &secondary_path {
+            if !new_path_in_data.is_file() {
+                let new_path_in_secondary = secondary_path.join(&mod_name);
+
+                // Copy the files unless it exists and its ours.
+                if (!new_path_in_secondary.is_file() || (new_path_in_secondary.is_file() && &steam_user_id != modd.creator())) && pack.save(Some(&new_path_in_secondary), &game, &None).is_ok() {
+                    if !modd.paths().contains(&new_path_in_secondary) {
+                        modd.paths_mut().insert(0, new_path_in_secondary);
+                    }
+
+                    if new_pack_type {
+                        modd.set_pack_type(pack.pfh_file_type());
+                    }
+
+                    in_secondary = true;
+                }
+            }
+        }
+
+        // If the move to secondary failed, try to do the same with /data.
+        if !in_secondary {
+
+            // Copy the files unless it exists and its ours.
+            if (!new_path_in_data.is_file() || (new_path_in_data.is_file() && &steam_user_id != modd.creator())) && pack.save(Some(&new_path_in_data), &game, &None).is_ok() {
+                if !modd.paths().contains(&new_path_in_data) {
+                    modd.paths_mut().insert(0, new_path_in_data);
+                }
+
+                if new_pack_type {
+                    modd.set_pack_type(pack.pfh_file_type());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Function to generate a pack from a Shogun 2 map bin data.
+    fn generate_map_pack(&self, game: &GameInfo, data_dec: &[u8], pack_name: &str, map_name: &str) -> Result<Pack> {
+
+        // Get all the files into memory to generate its pack.
+        let mut files = HashMap::new();
+        let mut data_dec = Cursor::new(data_dec);
+        loop {
+
+            // At the end of the last file there's a 0A 00 00 00 that doesn't seem to be part of a file.
+            let len = data_dec.len()?;
+            if len < 4 || data_dec.position() >= len - 4 {
+                break;
+            }
+
+            let file_name = data_dec.read_string_u16_0terminated()?;
+            let size = data_dec.read_u64()?;
+            let data = data_dec.read_slice(size as usize, false)?;
+
+            files.insert(file_name, data);
         }
 
         let mut pack = Pack::new_with_name_and_version(&pack_name, game.pfh_version_by_file_type(PFHFileType::Mod));
@@ -2133,6 +5278,9 @@ impl AppUI {
                             // So, once population is done, we need to do some post-processing. Our mods need to be moved to either /data or /secondary if we don't have them there.
                             // Shogun 2 mods need to be turned into packs and moved to either /data or /secondary.
                             let steam_user_id = crate::mod_manager::integrations::store_user_id(&game)?.to_string();
+
+                            // Keep the user id around, so we can tell which mods are from the user without asking Steam again.
+                            set_setting_string("steam_user_id", &steam_user_id);
                             let secondary_path = secondary_mods_path(game.key()).ok();
                             let game_data_path = game.data_path(&game_path);
 
@@ -2210,11 +5358,18 @@ impl AppUI {
                                 });
                             }
 
+                            // New mods may only match the category rules by tag or author, which we didn't know until now.
+                            let moved_by_rules = game_config.apply_category_rules();
+
                             game_config.save(&game)?;
 
                             // If we got a successfull network update, then proceed to update the UI with the new data.
-                            // It's faster than a full rebuild, and looks more modern and async.
-                            self.mod_list_ui().update(&game, game_config.mods(), &alt_names)?;
+                            // It's faster than a full rebuild, and looks more modern and async. If mods changed categories, we need the rebuild.
+                            if moved_by_rules.is_empty() {
+                                self.mod_list_ui().update(&game, game_config.mods(), &alt_names)?;
+                            } else {
+                                self.mod_list_ui().load(&game, game_config)?;
+                            }
 
                             // Reload the pack list, as it may have changed in some cases (Shogun 2).
                             let load_order = self.game_load_order().read().unwrap();
@@ -2230,7 +5385,8 @@ impl AppUI {
             }
         }
 
-        Ok(())
+        // GitHub mods are not covered by the workshop request, so we check them separately.
+        self.check_github_mods_updates()
     }
 
     pub unsafe fn upload_mod_to_workshop(&self) -> Result<Option<()>> {
@@ -2254,8 +5410,15 @@ impl AppUI {
                         PreUploadInfo::default()
                     };
 
-                    // If no errors were found, load the UI Template.
-                    let template_path = if cfg!(debug_assertions) { WORKSHOP_UPLOAD_VIEW_DEBUG } else { WORKSHOP_UPLOAD_VIEW_RELEASE };
+                    // Warn about packs we know will cause problems once uploaded. This is the pack the upload uses.
+                    if let Some(pack_path) = modd.paths().first() {
+                        if !self.check_pack_before_upload(&game, pack_path)? {
+                            return Ok(None);
+                        }
+                    }
+
+                    // If no errors were found, load the UI Template.
+                    let template_path = if cfg!(debug_assertions) { WORKSHOP_UPLOAD_VIEW_DEBUG } else { WORKSHOP_UPLOAD_VIEW_RELEASE };
                     let main_widget = load_template(self.main_window(), template_path)?;
                     let dialog = main_widget.static_downcast::<QDialog>();
 
@@ -2341,260 +5504,2124 @@ impl AppUI {
             } else {
                 Ok(None)
             }
-        } else {
-            Ok(None)
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub unsafe fn download_subscribed_mods(&self, published_file_ids: &Option<Vec<String>>) -> Result<()> {
+        self.toggle_main_window(false);
+
+        crate::mod_manager::integrations::download_subscribed_mods(&self.game_selected().read().unwrap(), published_file_ids)?;
+
+        self.toggle_main_window(true);
+
+        // Once done, do a reload of the mod list.
+        self.actions_ui().reload_button().click();
+
+        Ok(())
+    }
+
+    /// This function opens a dialog to search the Workshop by tags, then subscribes to the selected mods and adds them to a category.
+    ///
+    /// Returns the amount of mods subscribed, or None if the user cancelled the operation.
+    pub unsafe fn search_workshop(&self) -> Result<Option<usize>> {
+        let game = self.game_selected().read().unwrap().clone();
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { WORKSHOP_SEARCH_VIEW_DEBUG } else { WORKSHOP_SEARCH_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+
+        let tags_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "tags_label")?;
+        let tags_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "tags_line_edit")?;
+        let page_spinbox: QPtr<QSpinBox> = find_widget(&main_widget.static_upcast(), "page_spinbox")?;
+        let search_button: QPtr<QPushButton> = find_widget(&main_widget.static_upcast(), "search_button")?;
+        let results_tree_view: QPtr<QTreeView> = find_widget(&main_widget.static_upcast(), "results_tree_view")?;
+        let category_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "category_label")?;
+        let category_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "category_combobox")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+        button_box.button(StandardButton::Ok).released().connect(dialog.slot_accept());
+
+        dialog.set_window_title(&qtr("workshop_search_title"));
+        tags_label.set_text(&qtr("workshop_search_tags"));
+        page_spinbox.set_prefix(&qtr("workshop_search_page"));
+        search_button.set_text(&qtr("workshop_search_search"));
+        category_label.set_text(&qtr("workshop_search_category"));
+
+        // Hint the valid tags of the game, as searching by any other tag returns nothing.
+        if let Ok(tags) = game.steam_workshop_tags() {
+            tags_line_edit.set_placeholder_text(&QString::from_std_str(tags.join(",")));
+        }
+
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            for category in game_config.categories_order() {
+                category_combobox.add_item_q_string(&QString::from_std_str(category));
+            }
+
+            category_combobox.set_current_text(&QString::from_std_str(DEFAULT_CATEGORY));
+        }
+
+        let model = QStandardItemModel::new_1a(&results_tree_view).into_q_ptr();
+        results_tree_view.set_model(&model);
+
+        let search = SlotNoArgs::new(&dialog, clone!(
+            game,
+            dialog,
+            tags_line_edit,
+            page_spinbox,
+            model => move || {
+                let tags = tags_line_edit.text().to_std_string()
+                    .split(',')
+                    .map(|tag| tag.trim().to_owned())
+                    .filter(|tag| !tag.is_empty())
+                    .collect::<Vec<_>>();
+
+                dialog.set_enabled(false);
+                let receiver = CENTRAL_COMMAND.send_network(Command::SearchWorkshopMods(Box::new(game.clone()), tags, page_spinbox.value() as u32));
+                let response = CENTRAL_COMMAND.recv_try(&receiver);
+                dialog.set_enabled(true);
+
+                match response {
+                    Response::VecMod(mods) => {
+                        model.clear();
+                        model.set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("workshop_search_column_name")).into_ptr());
+                        model.set_horizontal_header_item(1, QStandardItem::from_q_string(&qtr("workshop_search_column_tags")).into_ptr());
+                        model.set_horizontal_header_item(2, QStandardItem::from_q_string(&qtr("workshop_search_column_file_name")).into_ptr());
+
+                        for modd in &mods {
+                            let row = QListOfQStandardItem::new();
+                            let item_name = QStandardItem::from_q_string(&QString::from_std_str(modd.name()));
+                            let item_tags = QStandardItem::from_q_string(&QString::from_std_str(modd.tags().join(", ")));
+                            let item_file_name = QStandardItem::from_q_string(&QString::from_std_str(modd.file_name()));
+
+                            item_name.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(modd.steam_id().clone().unwrap_or_default())), VALUE_MOD_STEAM_ID);
+                            item_name.set_tool_tip(&QString::from_std_str(modd.description()));
+
+                            row.append_q_standard_item(&item_name.into_ptr().as_mut_raw_ptr());
+                            row.append_q_standard_item(&item_tags.into_ptr().as_mut_raw_ptr());
+                            row.append_q_standard_item(&item_file_name.into_ptr().as_mut_raw_ptr());
+                            model.append_row_q_list_of_q_standard_item(row.into_ptr().as_ref().unwrap());
+                        }
+
+                        if mods.is_empty() {
+                            show_dialog(&dialog, tr("workshop_search_no_results"), false);
+                        }
+                    }
+                    Response::Error(error) => show_dialog(&dialog, error, false),
+                    _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+                }
+            }
+        ));
+
+        search_button.released().connect(&search);
+        tags_line_edit.return_pressed().connect(&search);
+
+        if dialog.exec() != 1 {
+            return Ok(None);
+        }
+
+        let selection = results_tree_view.selection_model().selected_rows_1a(0);
+        let mut published_file_ids = vec![];
+        let mut mod_ids = vec![];
+        for index in 0..selection.count_0a() {
+            let index = selection.at(index);
+            published_file_ids.push(index.data_1a(VALUE_MOD_STEAM_ID).to_string().to_std_string());
+            mod_ids.push(model.index_2a(index.row(), 2).data_0a().to_string().to_std_string());
+        }
+
+        if published_file_ids.is_empty() {
+            return Ok(None);
+        }
+
+        // Assign the mods to the category before downloading them, so the reload keeps them there.
+        // Mods that fail to download have no path, so the reload will remove them from the category.
+        let category = category_combobox.current_text().to_std_string();
+        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+            if game_config.categories().contains_key(&category) {
+                for mods in game_config.categories_mut().values_mut() {
+                    mods.retain(|mod_id| !mod_ids.contains(mod_id));
+                }
+
+                if let Some(mods) = game_config.categories_mut().get_mut(&category) {
+                    mods.extend_from_slice(&mod_ids);
+                }
+
+                game_config.save(&game)?;
+            }
+        }
+
+        self.toggle_main_window(false);
+        let result = subscribe_mods(&game, &published_file_ids);
+        self.toggle_main_window(true);
+        result?;
+
+        // Once done, do a reload of the mod list.
+        self.actions_ui().reload_button().click();
+
+        Ok(Some(published_file_ids.len()))
+    }
+
+    /// This function forces a clean re-download of the selected mods, then checks the downloaded files against the workshop data.
+    ///
+    /// Returns the names of the mods that failed the check, or None if the user cancelled the operation.
+    pub unsafe fn force_redownload_selected_mods(&self) -> Result<Option<Vec<String>>> {
+        let mod_ids = self.mod_list_selection()
+            .iter()
+            .map(|x| x.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+            .collect::<Vec<_>>();
+
+        // Keep the hashes of the current files, so we can tell if the files actually changed.
+        let old_hashes = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => mod_ids.iter()
+                .filter_map(|mod_id| game_config.mods().get(mod_id))
+                .filter(|modd| modd.steam_id().is_some())
+                .map(|modd| (modd.id().to_owned(), modd.paths().last().and_then(|path| try_digest(path.as_path()).ok())))
+                .collect::<HashMap<_, _>>(),
+            None => return Ok(Some(vec![])),
+        };
+
+        let published_file_ids = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => old_hashes.keys()
+                .filter_map(|mod_id| game_config.mods().get(mod_id))
+                .filter_map(|modd| modd.steam_id().clone())
+                .collect::<Vec<_>>(),
+            None => vec![],
+        };
+
+        if published_file_ids.is_empty() {
+            return Err(anyhow!("None of the selected mods is from the workshop."));
+        }
+
+        if !self.are_you_sure("force_redownload_are_you_sure") {
+            return Ok(None);
+        }
+
+        self.toggle_main_window(false);
+        let result = crate::mod_manager::integrations::force_redownload_mods(&self.game_selected().read().unwrap(), &published_file_ids);
+        self.toggle_main_window(true);
+        result?;
+
+        // Reload the mod list, so the mods point to the new files and have updated workshop data.
+        self.actions_ui().reload_button().click();
+
+        let mut failed = vec![];
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            for (mod_id, old_hash) in &old_hashes {
+                if let Some(modd) = game_config.mods().get(mod_id) {
+                    let steam_id = modd.steam_id().clone().unwrap_or_default();
+
+                    // Content paths are always in a folder named after the mod's steam id.
+                    let content_file = modd.paths()
+                        .iter()
+                        .find(|path| path.parent().and_then(|parent| parent.file_name()).map(|name| name.to_string_lossy() == steam_id).unwrap_or(false));
+
+                    // The workshop doesn't give us a hash to compare against, so we check the size it reports and that the pack can be fully read.
+                    let valid = match content_file {
+                        Some(path) => {
+                            let size_matches = *modd.file_size() == 0 || path.metadata().map(|metadata| metadata.len() == *modd.file_size()).unwrap_or(false);
+                            let readable = Pack::read_and_merge(&[path.to_path_buf()], false, false, false).is_ok();
+                            match try_digest(path.as_path()) {
+                                Ok(new_hash) => {
+                                    if Some(&new_hash) == old_hash.as_ref() {
+                                        info!("Mod {} re-downloaded, but its contents didn't change.", mod_id);
+                                    }
+
+                                    size_matches && readable
+                                },
+                                Err(_) => false,
+                            }
+                        }
+                        None => false,
+                    };
+
+                    if !valid {
+                        failed.push(modd.name().to_owned());
+                    }
+                } else {
+                    failed.push(mod_id.to_owned());
+                }
+            }
+        }
+
+        Ok(Some(failed))
+    }
+
+    /// This function asks the user for a GitHub url, and downloads the latest release of the mod in it.
+    ///
+    /// Mods are downloaded to the secondary folder, or to /data if there's no secondary folder.
+    pub unsafe fn add_mod_from_github(&self) -> Result<()> {
+        let dialog = QInputDialog::new_1a(self.main_window());
+        dialog.set_window_title(&qtr("add_from_github"));
+        dialog.set_label_text(&qtr("add_from_github_label"));
+        dialog.set_input_mode(InputMode::TextInput);
+
+        if dialog.exec() != 1 {
+            return Ok(());
+        }
+
+        let (repo, asset) = parse_github_url(&dialog.text_value().to_std_string())?;
+        let game = self.game_selected().read().unwrap().clone();
+        let folder = match secondary_mods_path(game.key()) {
+            Ok(path) => path,
+            Err(_) => game.data_path(&setting_path(game.key()))?,
+        };
+
+        let source = self.download_github_mod(repo, asset, folder)?;
+
+        // Reload first, so the mod gets all its data from the scan, then mark where it came from.
+        self.actions_ui().reload_button().click();
+
+        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+            match game_config.mods_mut().get_mut(source.asset()) {
+                Some(modd) => modd.set_github_source(Some(source)),
+                None => return Err(anyhow!(tre("add_from_github_not_found", &[source.asset()]))),
+            }
+
+            game_config.save(&game)?;
+            self.mod_list_ui().update(&game, game_config.mods(), &[])?;
+        }
+
+        Ok(())
+    }
+
+    /// This function installs the packs, or the packs inside the zips, dropped on the window, asking where to install them and in which category.
+    pub unsafe fn install_dropped_packs(&self, paths: &[PathBuf]) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let data_path = game.data_path(&game_path)?;
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { INSTALL_PACKS_VIEW_DEBUG } else { INSTALL_PACKS_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+        dialog.set_window_title(&qtr("install_packs_title"));
+
+        let info_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "info_label")?;
+        let destination_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "destination_label")?;
+        let destination_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "destination_combobox")?;
+        let category_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "category_label")?;
+        let category_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "category_combobox")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+        button_box.button(StandardButton::Ok).released().connect(dialog.slot_accept());
+
+        let file_names = paths.iter()
+            .filter_map(|path| path.file_name())
+            .map(|name| format!("<li>{}</li>", name.to_string_lossy()))
+            .join("");
+        info_label.set_text(&qtre("install_packs_info", &[&file_names]));
+        destination_label.set_text(&qtr("install_packs_destination"));
+        category_label.set_text(&qtr("install_packs_category"));
+
+        destination_combobox.add_item_q_string_q_variant(&qtr("install_packs_destination_data"), &QVariant::from_q_string(&QString::from_std_str(data_path.to_string_lossy())));
+
+        // If we have a secondary folder, default to it, so manually installed mods don't get mixed with the game's files.
+        if let Ok(secondary_path) = secondary_mods_path(game.key()) {
+            destination_combobox.add_item_q_string_q_variant(&qtr("install_packs_destination_secondary"), &QVariant::from_q_string(&QString::from_std_str(secondary_path.to_string_lossy())));
+            destination_combobox.set_current_index(1);
+        }
+
+        // The default category is always the last one.
+        if let Some(ref game_config) = *self.game_config().read().unwrap() {
+            for category in game_config.categories_order() {
+                category_combobox.add_item_q_string(&QString::from_std_str(category));
+            }
+        }
+        category_combobox.set_current_index(category_combobox.count() - 1);
+
+        if dialog.exec() != 1 {
+            return Ok(());
+        }
+
+        let folder = PathBuf::from(destination_combobox.current_data_0a().to_string().to_std_string());
+        let category = category_combobox.current_text().to_std_string();
+        let installed = if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+            install_packs(&game, &game_path, game_config, paths, &folder, &category)?
+        } else {
+            return Err(anyhow!(tr("game_config_error")));
+        };
+
+        // Once done, do a reload of the mod list so the installed mods are picked up.
+        self.actions_ui().reload_button().click();
+
+        let installed = installed.iter().map(|name| format!("<li>{}</li>", name)).join("");
+        show_dialog(self.main_window(), tre("install_packs_success", &[&installed]), true);
+
+        Ok(())
+    }
+
+    /// This function downloads the latest release of the selected GitHub mods, replacing the files we have.
+    ///
+    /// Returns the names of the mods that failed to update.
+    pub unsafe fn update_selected_mods_from_github(&self) -> Result<Vec<String>> {
+        let mod_ids = self.mod_list_selection()
+            .iter()
+            .map(|x| x.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+            .collect::<Vec<_>>();
+
+        let game = self.game_selected().read().unwrap().clone();
+        let mods = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => mod_ids.iter()
+                .filter_map(|mod_id| game_config.mods().get(mod_id))
+                .filter_map(|modd| Some((modd.id().to_owned(), modd.github_source().clone()?, modd.paths().first()?.parent()?.to_path_buf())))
+                .collect::<Vec<_>>(),
+            None => vec![],
+        };
+
+        let mut failed = vec![];
+        let mut updated = vec![];
+        for (mod_id, source, folder) in mods {
+            match self.download_github_mod(source.repo().to_owned(), Some(source.asset().to_owned()), folder) {
+                Ok(source) => updated.push((mod_id, source)),
+                Err(error) => failed.push(format!("{}: {}", mod_id, error)),
+            }
+        }
+
+        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+            for (mod_id, source) in updated {
+                if let Some(modd) = game_config.mods_mut().get_mut(&mod_id) {
+                    modd.set_github_source(Some(source));
+                }
+            }
+
+            game_config.save(&game)?;
+        }
+
+        self.actions_ui().reload_button().click();
+
+        Ok(failed)
+    }
+
+    /// This function checks if there are new releases of the GitHub mods of the current game, updating the mod list with the result.
+    pub unsafe fn check_github_mods_updates(&self) -> Result<()> {
+        let sources = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => game_config.mods()
+                .values()
+                .filter_map(|modd| modd.github_source().clone())
+                .collect::<Vec<_>>(),
+            None => vec![],
+        };
+
+        if sources.is_empty() {
+            return Ok(());
+        }
+
+        // Offline, we keep the last versions we know of.
+        if offline_mode().is_some() {
+            return Ok(());
+        }
+
+        let receiver = CENTRAL_COMMAND.send_network(Command::CheckGitHubModsUpdates(sources));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        match response {
+            Response::VecGitHubSource(sources) => {
+                if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+                    for modd in game_config.mods_mut().values_mut() {
+                        if let Some(current) = modd.github_source_mut() {
+                            if let Some(source) = sources.iter().find(|source| source.repo() == current.repo() && source.asset() == current.asset()) {
+                                current.set_latest_version(source.latest_version().to_owned());
+                            }
+                        }
+                    }
+
+                    let game = self.game_selected().read().unwrap();
+                    game_config.save(&game)?;
+                    self.mod_list_ui().update(&game, game_config.mods(), &[])?;
+                }
+            }
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        }
+
+        Ok(())
+    }
+
+    /// This function downloads a mod from GitHub in the network thread, disabling the UI while it's downloading.
+    unsafe fn download_github_mod(&self, repo: String, asset: Option<String>, folder: PathBuf) -> Result<GitHubSource> {
+        let game = self.game_selected().read().unwrap().clone();
+        let vanilla_names = vanilla_pack_names(&game, &setting_path(game.key()))?;
+
+        self.toggle_main_window(false);
+        let receiver = CENTRAL_COMMAND.send_network(Command::DownloadGitHubMod(repo, asset, folder, vanilla_names));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        self.toggle_main_window(true);
+
+        match response {
+            Response::GitHubSource(source) => Ok(source),
+            Response::Error(error) => Err(error),
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        }
+    }
+
+    pub unsafe fn backup_selected_mods(&self) -> Result<()> {
+        let mod_ids = self.mod_list_selection()
+            .iter()
+            .map(|x| x.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+            .collect::<Vec<_>>();
+
+        let file_dialog = QFileDialog::from_q_widget_q_string(&self.main_window, &qtr("backup_mods_select_folder"));
+        file_dialog.set_file_mode(FileMode::Directory);
+        file_dialog.set_options(QFlags::from(QFileDialogOption::ShowDirsOnly));
+
+        if file_dialog.exec() == 1 {
+            let folder = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            let game = self.game_selected().read().unwrap();
+            if let Some(ref game_config) = *self.game_config().read().unwrap() {
+                let backup_path = backup_mods(&game, game_config, &mod_ids, &folder)?;
+                let backup_path = backup_path.to_string_lossy().to_string();
+                show_dialog(&self.main_window, tre("backup_mods_success", &[&backup_path]), true);
+            } else {
+                return Err(anyhow!(tr("game_config_error")));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub unsafe fn restore_mods_backup(&self) -> Result<()> {
+        let file_dialog = QFileDialog::from_q_widget_q_string(&self.main_window, &qtr("restore_mods_backup_select_file"));
+        file_dialog.set_file_mode(FileMode::ExistingFile);
+        file_dialog.set_name_filter(&QString::from_std_str("Runcher Backup (*.zip)"));
+
+        if file_dialog.exec() == 1 {
+            let backup_path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            let restored = {
+                let game = self.game_selected().read().unwrap();
+                if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+                    restore_mods_backup(&game, game_config, &backup_path)?
+                } else {
+                    return Err(anyhow!(tr("game_config_error")));
+                }
+            };
+
+            // Once done, do a reload of the mod list so the restored mods are picked up.
+            self.actions_ui().reload_button().click();
+
+            let restored = restored.iter().map(|string| format!("<li>{}</li>", string)).join("");
+            show_dialog(&self.main_window, tre("restore_mods_backup_success", &[&restored]), true);
+        }
+
+        Ok(())
+    }
+
+    /// This function exports the manifest of the last launch of the game selected, with everything the game was told to load.
+    pub unsafe fn export_launch_manifest(&self) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        let manifest = last_launch_manifest(game.key()).map_err(|_| anyhow!(tr("export_launch_manifest_missing")))?;
+
+        let file_dialog = QFileDialog::from_q_widget_q_string(&self.main_window, &qtr("export_launch_manifest_select_file"));
+        file_dialog.set_accept_mode(AcceptMode::AcceptSave);
+        file_dialog.set_name_filter(&QString::from_std_str("Text (*.txt)"));
+        file_dialog.set_default_suffix(&QString::from_std_str("txt"));
+        file_dialog.select_file(&QString::from_std_str(format!("{}_launch_manifest.txt", game.key())));
+
+        if file_dialog.exec() == 1 {
+            let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            std::fs::write(&path, manifest)?;
+            show_dialog(&self.main_window, tre("export_launch_manifest_success", &[&path.to_string_lossy()]), true);
+        }
+
+        Ok(())
+    }
+
+    /// This function exports the tweaks of the enabled launch options as a mod pack, so they can be used without Runcher.
+    ///
+    /// It cannot be saved in /data, as the game would load it on top of the tweaks of the launch options.
+    pub unsafe fn export_tweaks_as_pack(&self) -> Result<()> {
+        if !launch_options_enabled(self) {
+            return Err(anyhow!(tr("export_tweaks_as_pack_none")));
+        }
+
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let data_path = game.data_path(&game_path)?;
+
+        let file_dialog = QFileDialog::from_q_widget_q_string(&self.main_window, &qtr("export_tweaks_as_pack_select_file"));
+        file_dialog.set_accept_mode(AcceptMode::AcceptSave);
+        file_dialog.set_name_filter(&QString::from_std_str("Pack (*.pack)"));
+        file_dialog.set_default_suffix(&QString::from_std_str("pack"));
+        if let Some(document_dir) = directories::UserDirs::new().and_then(|user_dirs| user_dirs.document_dir().map(|path| path.to_path_buf())) {
+            file_dialog.set_directory_q_string(&QString::from_std_str(document_dir.to_string_lossy()));
+        }
+        file_dialog.select_file(&QString::from_std_str(format!("runcher_tweaks_{}.pack", game.key())));
+
+        if file_dialog.exec() == DialogCode::Accepted.to_int() {
+            let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            let data_path = data_path.canonicalize().unwrap_or(data_path);
+            if path.parent().and_then(|parent| parent.canonicalize().ok()).is_some_and(|parent| parent == data_path) {
+                return Err(anyhow!(tr("export_tweaks_as_pack_data_folder")));
+            }
+
+            // Reusing the name of the reserved pack would get the exported pack deleted or overwritten on the next launch.
+            let file_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            if file_name == RESERVED_PACK_NAME || file_name == RESERVED_PACK_NAME_ALTERNATIVE {
+                return Err(anyhow!(tr("export_tweaks_as_pack_reserved_name")));
+            }
+
+            save_tweaks_pack(self, &game, &game_path, &path, PFHFileType::Mod)?;
+            show_dialog(&self.main_window, tre("export_tweaks_as_pack_success", &[&path.to_string_lossy()]), true);
+
+            // Reload, so the pack shows up in the mod list if it was saved in one of the folders we load mods from.
+            self.actions_ui().reload_button().click();
+        }
+
+        Ok(())
+    }
+
+    /// This function updates the pin actions of the context menu of the Actions panel with the profile and save selected.
+    pub unsafe fn update_actions_context_menu(&self) {
+        let state = UIState::load(self.game_selected().read().unwrap().key()).unwrap_or_default();
+
+        let profile_name = self.actions_ui().profile_combobox().current_text().to_std_string();
+        let profile_exists = self.game_profiles().read().unwrap().contains_key(&profile_name);
+        self.actions_ui().pin_profile().set_enabled(profile_exists);
+        self.actions_ui().pin_profile().set_checked(profile_exists && state.pinned_profiles().contains(&profile_name));
+
+        let save_name = self.selected_save_name();
+        self.actions_ui().pin_save().set_enabled(save_name.is_some());
+        self.actions_ui().pin_save().set_checked(save_name.is_some_and(|save_name| state.pinned_saves().contains(&save_name)));
+    }
+
+    /// This function returns the name of the save selected in the save selector, if any.
+    unsafe fn selected_save_name(&self) -> Option<String> {
+        let save_index = self.actions_ui().save_combobox().current_index();
+        if save_index > 0 {
+            self.game_saves().read().unwrap().get(save_index as usize - 1).map(|save| save.name().to_owned())
+        } else {
+            None
+        }
+    }
+
+    /// This function pins the profile selected to the top of the profile selector, or unpins it if it was already pinned.
+    pub unsafe fn toggle_pinned_profile(&self) -> Result<()> {
+        let game_key = self.game_selected().read().unwrap().key().to_owned();
+        let profile_name = self.actions_ui().profile_combobox().current_text().to_std_string();
+        if !self.game_profiles().read().unwrap().contains_key(&profile_name) {
+            return Err(anyhow!("No profile with said name found for the game selected."));
+        }
+
+        let mut state = UIState::load(&game_key)?;
+        toggle_pinned(state.pinned_profiles_mut(), &profile_name);
+        state.save(&game_key)?;
+
+        self.update_profile_combobox(&game_key);
+        self.actions_ui().profile_combobox().set_current_text(&QString::from_std_str(&profile_name));
+
+        Ok(())
+    }
+
+    /// This function pins the save selected to the top of the save selector, or unpins it if it was already pinned.
+    pub unsafe fn toggle_pinned_save(&self) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        let save_name = self.selected_save_name().ok_or_else(|| anyhow!(tr("profile_from_save_no_save")))?;
+
+        let mut state = UIState::load(game.key())?;
+        toggle_pinned(state.pinned_saves_mut(), &save_name);
+        state.save(game.key())?;
+
+        self.load_saves_to_ui(&game, &setting_path(game.key()))?;
+
+        // The save may have moved, so we need to find it to keep it selected.
+        let index = self.game_saves().read().unwrap().iter().position(|save| save.name() == &save_name);
+        if let Some(index) = index {
+            self.actions_ui().save_combobox().set_current_index(index as i32 + 1);
+        }
+
+        Ok(())
+    }
+
+    /// This function lets the user pick one of the backups of the saves of the game selected, and restores its saves.
+    ///
+    /// Saves with the same name as a backed up one are overwritten, but only after asking the user.
+    pub unsafe fn restore_save_backup(&self) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let saves_path = game_appdata_path(&game, &game_path)
+            .map(|path| path.join("save_games"))
+            .ok_or_else(|| anyhow!(tr("restore_save_backup_no_saves_folder")))?;
+
+        let backups = save_backups(game.key(), &save_backups_path()?);
+        if backups.is_empty() {
+            return Err(anyhow!(tr("restore_save_backup_none")));
+        }
+
+        let date_format = time::format_description::parse(&setting_string("date_format"))?;
+        let mut labels = vec![];
+        for backup in &backups {
+            let date = OffsetDateTime::from_unix_timestamp(*backup.date() as i64)?.format(&date_format)?;
+            labels.push(tre("restore_save_backup_item", &[&date, &backup.saves().join(", ")]));
+        }
+
+        let items = QStringList::new();
+        for label in &labels {
+            items.append_q_string(&QString::from_std_str(label));
+        }
+
+        let dialog = QInputDialog::new_1a(self.main_window());
+        dialog.set_window_title(&qtr("restore_save_backup"));
+        dialog.set_label_text(&qtr("restore_save_backup_select"));
+        dialog.set_input_mode(InputMode::TextInput);
+        dialog.set_combo_box_items(&items);
+        dialog.set_combo_box_editable(false);
+
+        if dialog.exec() != DialogCode::Accepted.to_int() {
+            return Ok(());
+        }
+
+        let label = dialog.text_value().to_std_string();
+        let backup = match labels.iter().position(|item| *item == label) {
+            Some(index) => &backups[index],
+            None => return Ok(()),
+        };
+
+        let existing = existing_saves(backup, &saves_path);
+        if !existing.is_empty() {
+            let question = QMessageBox::from_q_widget(self.main_window());
+            question.set_window_title(&qtr("are_you_sure_title"));
+            question.set_icon(q_message_box::Icon::Warning);
+            question.set_text(&qtre("restore_save_backup_overwrite", &[&existing.join("<br/>")]));
+            question.set_standard_buttons(q_message_box::StandardButton::Yes | q_message_box::StandardButton::No);
+            question.set_default_button_standard_button(q_message_box::StandardButton::No);
+
+            if question.exec() != q_message_box::StandardButton::Yes.to_int() {
+                return Ok(());
+            }
+        }
+
+        // Late campaign saves are big, so extracting them takes a while.
+        self.toggle_main_window(false);
+        let receiver = CENTRAL_COMMAND.send_background(Command::RestoreSaveBackup(backup.path().to_path_buf(), saves_path));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        self.toggle_main_window(true);
+
+        let restored = match response {
+            Response::VecString(restored) => restored,
+            Response::Error(error) => return Err(error),
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        };
+
+        self.load_saves_to_ui(&game, &game_path)?;
+        show_dialog(self.main_window(), tre("restore_save_backup_success", &[&restored.join(", ")]), true);
+
+        Ok(())
+    }
+
+    /// This function opens a dialog to choose which controls of the Actions panel are visible, and the order of the toolbar buttons.
+    ///
+    /// The customization is saved for the game selected.
+    pub unsafe fn open_actions_customization_dialog(&self) -> Result<()> {
+        let game_key = self.game_selected().read().unwrap().key().to_owned();
+        let mut state = UIState::load(&game_key)?;
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { ACTIONS_CUSTOMIZATION_VIEW_DEBUG } else { ACTIONS_CUSTOMIZATION_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+        dialog.set_window_title(&qtr("customize_actions"));
+
+        let info_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "info_label")?;
+        let toolbar_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "toolbar_label")?;
+        let options_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "options_label")?;
+        let toolbar_list_widget: QPtr<QListWidget> = find_widget(&main_widget.static_upcast(), "toolbar_list_widget")?;
+        let options_list_widget: QPtr<QListWidget> = find_widget(&main_widget.static_upcast(), "options_list_widget")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+        info_label.set_text(&qtr("customize_actions_info"));
+        toolbar_label.set_text(&qtr("customize_actions_toolbar"));
+        options_label.set_text(&qtr("customize_actions_options"));
+
+        ActionsUI::fill_customization_lists(&toolbar_list_widget, &options_list_widget, state.hidden_actions(), state.actions_order());
+
+        let restore_defaults = SlotNoArgs::new(&dialog, clone!(
+            toolbar_list_widget,
+            options_list_widget => move || {
+                ActionsUI::fill_customization_lists(&toolbar_list_widget, &options_list_widget, &[], &[]);
+            }
+        ));
+        button_box.button(StandardButton::RestoreDefaults).released().connect(&restore_defaults);
+
+        if dialog.exec() == 1 {
+            let (hidden_actions, actions_order) = ActionsUI::customization_from_lists(&toolbar_list_widget, &options_list_widget);
+            self.actions_ui().apply_layout(&hidden_actions, &actions_order);
+
+            state.set_hidden_actions(hidden_actions);
+            state.set_actions_order(actions_order);
+            state.save(&game_key)?;
+        }
+
+        Ok(())
+    }
+
+    /// This function opens a dialog with the past launch sessions of the game selected, allowing to re-apply the load order of one of them.
+    pub unsafe fn open_sessions_dialog(&self) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        let mut sessions = SessionHistory::load(game.key()).sessions().to_vec();
+        if sessions.is_empty() {
+            return Err(anyhow!(tr("sessions_empty")));
+        }
+
+        // Newest sessions first.
+        sessions.reverse();
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { SESSIONS_VIEW_DEBUG } else { SESSIONS_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+        dialog.set_window_title(&qtr("sessions_title"));
+
+        let sessions_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "sessions_label")?;
+        let sessions_table_view: QPtr<QTableView> = find_widget(&main_widget.static_upcast(), "sessions_table_view")?;
+        let load_order_text_edit: QPtr<QTextEdit> = find_widget(&main_widget.static_upcast(), "load_order_text_edit")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+        button_box.button(StandardButton::Ok).set_text(&qtr("sessions_apply"));
+        button_box.button(StandardButton::Ok).released().connect(dialog.slot_accept());
+        sessions_label.set_text(&qtr("sessions_info"));
+
+        let model = QStandardItemModel::new_1a(&dialog);
+        sessions_table_view.set_model(&model);
+        model.set_column_count(3);
+        model.set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("sessions_column_date")).into_ptr());
+        model.set_horizontal_header_item(1, QStandardItem::from_q_string(&qtr("sessions_column_profile")).into_ptr());
+        model.set_horizontal_header_item(2, QStandardItem::from_q_string(&qtr("sessions_column_packs")).into_ptr());
+
+        let date_format = time::format_description::parse(&setting_string("date_format"))?;
+        for session in &sessions {
+            let items = QListOfQStandardItem::new();
+            let date = OffsetDateTime::from_unix_timestamp(*session.timestamp() as i64)?.format(&date_format)?;
+            let packs = session.load_order().mods().len() + session.load_order().movies().len();
+
+            items.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(date)).into_ptr().as_mut_raw_ptr());
+            items.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(session.profile())).into_ptr().as_mut_raw_ptr());
+            items.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(packs.to_string())).into_ptr().as_mut_raw_ptr());
+            model.append_row_q_list_of_q_standard_item(items.into_ptr().as_ref().unwrap());
+        }
+
+        let game_config = self.game_config.clone();
+        let show_load_order = SlotNoArgs::new(&dialog, clone!(
+            game_config,
+            sessions,
+            sessions_table_view,
+            load_order_text_edit => move || {
+                let selection = sessions_table_view.selection_model().selected_rows_1a(0);
+                let session = if selection.count_0a() == 1 { sessions.get(selection.at(0).row() as usize) } else { None };
+                let text = match session {
+                    Some(session) => {
+                        let game_config = game_config.read().unwrap();
+                        session.load_order().mods().iter()
+                            .chain(session.load_order().movies().iter())
+                            .enumerate()
+                            .map(|(index, mod_id)| {
+                                let name = game_config.as_ref()
+                                    .and_then(|game_config| game_config.mods().get(mod_id))
+                                    .map(|modd| modd.name().to_owned())
+                                    .filter(|name| !name.is_empty() && name != mod_id);
+
+                                match name {
+                                    Some(name) => format!("{}. {} ({})", index + 1, name, mod_id),
+                                    None => format!("{}. {}", index + 1, mod_id),
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    },
+                    None => String::new(),
+                };
+
+                load_order_text_edit.set_plain_text(&QString::from_std_str(text));
+            }
+        ));
+
+        sessions_table_view.selection_model().selection_changed().connect(&show_load_order);
+        sessions_table_view.resize_columns_to_contents();
+        sessions_table_view.select_row(0);
+
+        if dialog.exec() == 1 {
+            let selection = sessions_table_view.selection_model().selected_rows_1a(0);
+            if selection.count_0a() == 1 {
+                if let Some(session) = sessions.get(selection.at(0).row() as usize) {
+                    self.check_profile_lock()?;
+                    self.take_undo_snapshot("undo_operation_apply_session");
+                    self.apply_load_order(session.load_order(), false)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This function opens the bughunt mode, to find the mod causing a crash by launching with different mods disabled.
+    ///
+    /// The first time it's opened, the current load order becomes the baseline. Each launch is done with the baseline minus the mods
+    /// unchecked in the dialog, and the user is asked if the game crashed afterwards. Finishing the bughunt shows a summary of the runs
+    /// and restores the baseline.
+    pub unsafe fn open_bughunt_dialog(&self) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+
+        // Every run rewrites the load order, so a locked profile blocks resuming a bughunt too.
+        self.check_profile_lock()?;
+        let mut bughunt = match BugHunt::load(game.key()) {
+            Some(bughunt) => bughunt,
+            None => BugHunt::new(&self.game_load_order().read().unwrap()),
+        };
+
+        if bughunt.baseline().mods().is_empty() {
+            return Err(anyhow!(tr("bughunt_empty")));
+        }
+
+        // Start with the mods disabled in the last run, so consecutive runs only need small changes.
+        let mut disabled = bughunt.runs().last().map(|run| run.disabled().to_vec()).unwrap_or_default();
+
+        loop {
+            let template_path = if cfg!(debug_assertions) { BUGHUNT_VIEW_DEBUG } else { BUGHUNT_VIEW_RELEASE };
+            let main_widget = load_template(self.main_window(), template_path)?;
+            let dialog = main_widget.static_downcast::<QDialog>();
+            dialog.set_window_title(&qtr("bughunt_title"));
+
+            let bughunt_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "bughunt_label")?;
+            let bughunt_table_view: QPtr<QTableView> = find_widget(&main_widget.static_upcast(), "bughunt_table_view")?;
+            let launch_button: QPtr<QPushButton> = find_widget(&main_widget.static_upcast(), "launch_button")?;
+            let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+            bughunt_label.set_text(&qtre("bughunt_info", &[&bughunt.runs().len().to_string()]));
+            launch_button.set_text(&qtr("bughunt_launch"));
+            button_box.button(StandardButton::Ok).set_text(&qtr("bughunt_finish"));
+            button_box.button(StandardButton::Discard).set_text(&qtr("bughunt_discard"));
+            button_box.button(StandardButton::Ok).released().connect(dialog.slot_accept());
+
+            let launch_slot = SlotNoArgs::new(&dialog, clone!(
+                dialog => move || {
+                dialog.done(BUGHUNT_RESULT_LAUNCH);
+            }));
+
+            let discard_slot = SlotNoArgs::new(&dialog, clone!(
+                dialog => move || {
+                dialog.done(BUGHUNT_RESULT_DISCARD);
+            }));
+            launch_button.released().connect(&launch_slot);
+            button_box.button(StandardButton::Discard).released().connect(&discard_slot);
+
+            let model = QStandardItemModel::new_1a(&dialog);
+            bughunt_table_view.set_model(&model);
+            model.set_column_count(6);
+            model.set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("bughunt_column_mod")).into_ptr());
+            model.set_horizontal_header_item(1, QStandardItem::from_q_string(&qtr("bughunt_column_crashes_enabled")).into_ptr());
+            model.set_horizontal_header_item(2, QStandardItem::from_q_string(&qtr("bughunt_column_clean_enabled")).into_ptr());
+            model.set_horizontal_header_item(3, QStandardItem::from_q_string(&qtr("bughunt_column_crashes_disabled")).into_ptr());
+            model.set_horizontal_header_item(4, QStandardItem::from_q_string(&qtr("bughunt_column_clean_disabled")).into_ptr());
+            model.set_horizontal_header_item(5, QStandardItem::from_q_string(&qtr("bughunt_column_verdict")).into_ptr());
+
+            let summaries = bughunt.summary();
+            for summary in &summaries {
+                let items = QListOfQStandardItem::new();
+                let item_mod = QStandardItem::from_q_string(&QString::from_std_str(self.bughunt_mod_name(summary.mod_id())));
+                item_mod.set_checkable(true);
+                item_mod.set_check_state(if disabled.contains(summary.mod_id()) { CheckState::Unchecked } else { CheckState::Checked });
+
+                items.append_q_standard_item(&item_mod.into_ptr().as_mut_raw_ptr());
+                for count in [summary.crashes_enabled(), summary.clean_enabled(), summary.crashes_disabled(), summary.clean_disabled()] {
+                    items.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(count.to_string())).into_ptr().as_mut_raw_ptr());
+                }
+
+                items.append_q_standard_item(&QStandardItem::from_q_string(&qtr(summary.verdict().locale_key())).into_ptr().as_mut_raw_ptr());
+                model.append_row_q_list_of_q_standard_item(items.into_ptr().as_ref().unwrap());
+            }
+
+            bughunt_table_view.resize_columns_to_contents();
+
+            let result = dialog.exec();
+            disabled = summaries.iter()
+                .enumerate()
+                .filter(|(row, _)| model.item_1a(*row as i32).check_state() == CheckState::Unchecked)
+                .map(|(_, summary)| summary.mod_id().to_owned())
+                .collect();
+
+            match result {
+                BUGHUNT_RESULT_LAUNCH => {
+                    self.apply_load_order(&bughunt.load_order_without(&disabled), false)?;
+                    bughunt.save(game.key())?;
+
+                    if let Err(error) = self.launch_game() {
+                        show_dialog(self.main_window(), error, false);
+                        continue;
+                    }
+
+                    if launch_cancelled() {
+                        continue;
+                    }
+
+                    let question = QMessageBox::from_q_widget(self.main_window());
+                    question.set_window_title(&qtr("bughunt_title"));
+                    question.set_icon(q_message_box::Icon::Question);
+                    question.set_text(&qtr("bughunt_outcome"));
+
+                    question.add_button_q_string_button_role(&qtr("bughunt_outcome_crashed"), q_message_box::ButtonRole::YesRole);
+                    question.add_button_q_string_button_role(&qtr("bughunt_outcome_clean"), q_message_box::ButtonRole::NoRole);
+                    question.add_button_standard_button(q_message_box::StandardButton::Cancel);
+                    question.exec();
+
+                    let role = question.button_role(&question.clicked_button());
+                    if role == q_message_box::ButtonRole::YesRole || role == q_message_box::ButtonRole::NoRole {
+                        bughunt.add_run(&disabled, role == q_message_box::ButtonRole::YesRole)?;
+                        bughunt.save(game.key())?;
+                    }
+                }
+
+                // Finishing shows the summary of the mods disabled at least once, as the ones always enabled tell us nothing.
+                result if result == DialogCode::Accepted.to_int() => {
+                    let rows = bughunt.summary()
+                        .iter()
+                        .filter(|summary| summary.crashes_disabled() + summary.clean_disabled() > 0)
+                        .map(|summary| format!("<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                            self.bughunt_mod_name(summary.mod_id()),
+                            summary.crashes_enabled(),
+                            summary.clean_enabled(),
+                            summary.crashes_disabled(),
+                            summary.clean_disabled(),
+                            tr(summary.verdict().locale_key())
+                        ))
+                        .join("");
+
+                    self.take_undo_snapshot("undo_operation_bughunt");
+                    self.apply_load_order(bughunt.baseline(), false)?;
+                    BugHunt::remove(game.key())?;
+
+                    if rows.is_empty() {
+                        show_dialog(self.main_window(), tr("bughunt_summary_empty"), true);
+                    } else {
+                        let header = format!("<tr><th>{}</th><th>{}</th><th>{}</th><th>{}</th><th>{}</th><th>{}</th></tr>",
+                            tr("bughunt_column_mod"),
+                            tr("bughunt_column_crashes_enabled"),
+                            tr("bughunt_column_clean_enabled"),
+                            tr("bughunt_column_crashes_disabled"),
+                            tr("bughunt_column_clean_disabled"),
+                            tr("bughunt_column_verdict")
+                        );
+
+                        show_dialog(self.main_window(), tre("bughunt_summary", &[&bughunt.runs().len().to_string(), &format!("<table>{header}{rows}</table>")]), true);
+                    }
+
+                    break;
+                }
+
+                BUGHUNT_RESULT_DISCARD => {
+                    self.take_undo_snapshot("undo_operation_bughunt");
+                    self.apply_load_order(bughunt.baseline(), false)?;
+                    BugHunt::remove(game.key())?;
+                    break;
+                }
+
+                // Closing keeps the bughunt, so it can be continued later.
+                _ => {
+                    if !bughunt.runs().is_empty() {
+                        bughunt.save(game.key())?;
+                    }
+
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This function returns the name of the provided mod to show in the bughunt mode, falling back to its id.
+    fn bughunt_mod_name(&self, mod_id: &str) -> String {
+        self.game_config().read().unwrap()
+            .as_ref()
+            .and_then(|game_config| game_config.mods().get(mod_id))
+            .map(|modd| modd.name().to_owned())
+            .filter(|name| !name.is_empty() && name != mod_id)
+            .map(|name| format!("{} ({})", name, mod_id))
+            .unwrap_or_else(|| mod_id.to_owned())
+    }
+
+    /// This function updates the translations downloaded from the translation hub.
+    pub unsafe fn update_translations(&self) -> Result<()> {
+        if update_translations_repo()? {
+            show_dialog(self.main_window(), tr("update_translations_success"), true);
+        } else {
+            show_dialog(self.main_window(), tr("update_translations_no_update"), true);
+        }
+
+        Ok(())
+    }
+
+    /// This function opens a dialog with how much of each enabled pack is translated to the language selected in the launch options.
+    pub unsafe fn open_translation_coverage_dialog(&self) -> Result<()> {
+        if !self.actions_ui().enable_translations_combobox().is_enabled() || self.actions_ui().enable_translations_combobox().current_index() == 0 {
+            return Err(anyhow!(tr("translation_coverage_no_language")));
+        }
+
+        let game = self.game_selected().read().unwrap().clone();
+        let language = self.actions_ui().enable_translations_combobox().current_text().to_std_string();
+        let mut coverages = self.pack_list_ui().mod_pack_paths()
+            .iter()
+            .filter_map(|path| path.file_name().map(|name| name.to_string_lossy().to_string()))
+            .map(|pack_name| {
+                let coverage = translation_coverage(game.key(), &pack_name, &language);
+                (pack_name, coverage)
+            })
+            .collect::<Vec<_>>();
+
+        // Least translated packs first, as those are the ones users want to know about.
+        coverages.sort_by(|(name_a, coverage_a), (name_b, coverage_b)| coverage_a.source().is_some().cmp(&coverage_b.source().is_some())
+            .then(coverage_a.percentage().cmp(&coverage_b.percentage()))
+            .then(name_a.cmp(name_b))
+        );
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { TRANSLATION_COVERAGE_VIEW_DEBUG } else { TRANSLATION_COVERAGE_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+        dialog.set_window_title(&qtr("translation_coverage_title"));
+
+        let coverage_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "coverage_label")?;
+        let coverage_table_view: QPtr<QTableView> = find_widget(&main_widget.static_upcast(), "coverage_table_view")?;
+        coverage_label.set_text(&qtre("translation_coverage_info", &[&language]));
+
+        let model = QStandardItemModel::new_1a(&dialog);
+        coverage_table_view.set_model(&model);
+        model.set_column_count(4);
+        model.set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("translation_coverage_column_mod")).into_ptr());
+        model.set_horizontal_header_item(1, QStandardItem::from_q_string(&qtr("translation_coverage_column_source")).into_ptr());
+        model.set_horizontal_header_item(2, QStandardItem::from_q_string(&qtr("translation_coverage_column_coverage")).into_ptr());
+        model.set_horizontal_header_item(3, QStandardItem::from_q_string(&qtr("translation_coverage_column_outdated")).into_ptr());
+
+        let game_config = self.game_config().read().unwrap();
+        for (pack_name, coverage) in &coverages {
+            let items = QListOfQStandardItem::new();
+            let name = game_config.as_ref()
+                .and_then(|game_config| game_config.mods().get(pack_name))
+                .map(|modd| modd.name().to_owned())
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| pack_name.to_owned());
+
+            let item_mod = QStandardItem::from_q_string(&QString::from_std_str(name));
+            item_mod.set_tool_tip(&QString::from_std_str(pack_name));
+
+            let (item_source, item_coverage, item_outdated) = match coverage.source() {
+                Some(source) => {
+                    let icon = if coverage.percentage() == 100 { "checkmark" } else { "data-warning" };
+                    let item_coverage = QStandardItem::from_q_string(&qtre("translation_coverage_value", &[
+                        &coverage.percentage().to_string(),
+                        &coverage.translated().to_string(),
+                        &coverage.total().to_string()
+                    ]));
+                    item_coverage.set_icon(&QIcon::from_theme_1a(&QString::from_std_str(icon)));
+
+                    (
+                        QStandardItem::from_q_string(&qtr(source.locale_key())),
+                        item_coverage,
+                        QStandardItem::from_q_string(&QString::from_std_str(coverage.outdated().to_string())),
+                    )
+                }
+                None => {
+                    let item_coverage = QStandardItem::from_q_string(&qtr("translation_coverage_none"));
+                    item_coverage.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("data-error")));
+                    (QStandardItem::new(), item_coverage, QStandardItem::new())
+                }
+            };
+
+            items.append_q_standard_item(&item_mod.into_ptr().as_mut_raw_ptr());
+            items.append_q_standard_item(&item_source.into_ptr().as_mut_raw_ptr());
+            items.append_q_standard_item(&item_coverage.into_ptr().as_mut_raw_ptr());
+            items.append_q_standard_item(&item_outdated.into_ptr().as_mut_raw_ptr());
+            model.append_row_q_list_of_q_standard_item(items.into_ptr().as_ref().unwrap());
+        }
+
+        coverage_table_view.resize_columns_to_contents();
+        dialog.exec();
+
+        Ok(())
+    }
+
+    /// This function exports a report of the current setup of the game selected, in HTML or Markdown depending on the extension chosen.
+    pub unsafe fn export_mod_report(&self) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        let file_dialog = QFileDialog::from_q_widget_q_string(&self.main_window, &qtr("export_mod_report_select_file"));
+        file_dialog.set_accept_mode(AcceptMode::AcceptSave);
+        file_dialog.set_name_filter(&QString::from_std_str("HTML (*.html);;Markdown (*.md)"));
+        file_dialog.set_default_suffix(&QString::from_std_str("html"));
+        file_dialog.select_file(&QString::from_std_str(format!("{}_mod_report.html", game.key())));
+
+        if file_dialog.exec() == 1 {
+            let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            let data_path = game.data_path(&setting_path(game.key()))?;
+            let report = match *self.game_config().read().unwrap() {
+                Some(ref game_config) => mod_report(ReportFormat::from_path(&path), &game, game_config, &self.game_load_order().read().unwrap(), &data_path, &self.actions_ui().active_launch_options(), VERSION)?,
+                None => return Err(anyhow!(tr("game_config_error"))),
+            };
+
+            std::fs::write(&path, report)?;
+            show_dialog(&self.main_window, tre("export_mod_report_success", &[&path.to_string_lossy()]), true);
+        }
+
+        Ok(())
+    }
+
+    /// This function computes the fingerprint of the current setup and shows its code in the Actions panel.
+    pub unsafe fn fingerprint(&self) -> Result<Fingerprint> {
+        let launch_options = self.actions_ui().active_launch_options_raw()
+            .into_iter()
+            .map(|(key, value)| (key.to_owned(), value.unwrap_or_else(|| true.to_string())))
+            .collect::<Vec<_>>();
+
+        let game_config = self.game_config().read().unwrap().clone().ok_or_else(|| anyhow!(tr("game_config_error")))?;
+        let load_order = self.game_load_order().read().unwrap().clone();
+
+        // Packs without a cached hash need to be hashed, so this is done in the background.
+        self.toggle_main_window(false);
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetFingerprint(game_config, load_order, launch_options));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        self.toggle_main_window(true);
+
+        let fingerprint = match response {
+            Response::Fingerprint(fingerprint) => fingerprint,
+            Response::Error(error) => return Err(error),
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        };
+
+        self.actions_ui().fingerprint_button().set_text(&qtre("fingerprint_code", &[&fingerprint.code()]));
+        Ok(fingerprint)
+    }
+
+    /// This function copies the code of the fingerprint of the current setup to the clipboard.
+    pub unsafe fn copy_fingerprint(&self) -> Result<()> {
+        let fingerprint = self.fingerprint()?;
+        QGuiApplication::clipboard().set_text_1a(&QString::from_std_str(fingerprint.code()));
+        Ok(())
+    }
+
+    /// This function copies the manifest of the fingerprint of the current setup to the clipboard, so it can be compared with the one of other player.
+    pub unsafe fn copy_fingerprint_manifest(&self) -> Result<()> {
+        let fingerprint = self.fingerprint()?;
+        QGuiApplication::clipboard().set_text_1a(&QString::from_std_str(fingerprint.manifest()));
+        Ok(())
+    }
+
+    /// This function opens a dialog to find out why two fingerprints don't match, comparing their manifests.
+    ///
+    /// The first manifest is prefilled with the one of the current setup.
+    pub unsafe fn compare_fingerprint_manifests(&self) -> Result<()> {
+        let fingerprint = self.fingerprint()?;
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { FINGERPRINT_COMPARE_VIEW_DEBUG } else { FINGERPRINT_COMPARE_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+        dialog.set_window_title(&qtr("compare_fingerprint_manifests"));
+
+        let info_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "info_label")?;
+        let first_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "first_label")?;
+        let second_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "second_label")?;
+        let first_text_edit: QPtr<QPlainTextEdit> = find_widget(&main_widget.static_upcast(), "first_text_edit")?;
+        let second_text_edit: QPtr<QPlainTextEdit> = find_widget(&main_widget.static_upcast(), "second_text_edit")?;
+        let compare_button: QPtr<QPushButton> = find_widget(&main_widget.static_upcast(), "compare_button")?;
+        let result_text_edit: QPtr<QTextEdit> = find_widget(&main_widget.static_upcast(), "result_text_edit")?;
+
+        info_label.set_text(&qtr("fingerprint_compare_info"));
+        first_label.set_text(&qtr("fingerprint_compare_first"));
+        second_label.set_text(&qtr("fingerprint_compare_second"));
+        compare_button.set_text(&qtr("fingerprint_compare_compare"));
+        first_text_edit.set_plain_text(&QString::from_std_str(fingerprint.manifest()));
+
+        let compare = SlotNoArgs::new(&dialog, clone!(
+            first_text_edit,
+            second_text_edit,
+            result_text_edit => move || {
+                let first = Fingerprint::from_manifest(&first_text_edit.to_plain_text().to_std_string());
+                let second = Fingerprint::from_manifest(&second_text_edit.to_plain_text().to_std_string());
+                let result = match (first, second) {
+                    (Ok(first), Ok(second)) => {
+                        let differences = first.differences(&second);
+                        if differences.is_empty() {
+                            tre("fingerprint_compare_equal", &[&first.code()])
+                        } else {
+                            let list = differences.iter()
+                                .map(|difference| format!("<li>{}</li>", Self::fingerprint_difference_text(difference)))
+                                .join("");
+
+                            tre("fingerprint_compare_different", &[&first.code(), &second.code(), &list])
+                        }
+                    }
+                    (Err(error), _) | (_, Err(error)) => tre("fingerprint_compare_invalid", &[&error.to_string()]),
+                };
+
+                result_text_edit.set_html(&QString::from_std_str(result));
+            }
+        ));
+
+        compare_button.released().connect(&compare);
+        dialog.exec();
+
+        Ok(())
+    }
+
+    fn fingerprint_difference_text(difference: &FingerprintDifference) -> String {
+        match difference {
+            FingerprintDifference::Game(first, second) => tre("fingerprint_difference_game", &[first, second]),
+            FingerprintDifference::Build(first, second) => tre("fingerprint_difference_build", &[first, second]),
+            FingerprintDifference::PackOnlyInFirst(pack_name) => tre("fingerprint_difference_only_first", &[pack_name]),
+            FingerprintDifference::PackOnlyInSecond(pack_name) => tre("fingerprint_difference_only_second", &[pack_name]),
+            FingerprintDifference::PackHash(pack_name) => tre("fingerprint_difference_hash", &[pack_name]),
+            FingerprintDifference::PackPosition(pack_name, first, second) => tre("fingerprint_difference_position", &[pack_name, &first.to_string(), &second.to_string()]),
+            FingerprintDifference::LaunchOption(key, first, second) => tre("fingerprint_difference_launch_option", &[&tr(key), first, second]),
+        }
+    }
+
+    /// This function saves the hashes of the enabled packs, so we can later check if any of them changed mid-campaign.
+    pub unsafe fn freeze_campaign_integrity(&self) -> Result<()> {
+        let game_config = self.game_config().read().unwrap().clone().ok_or_else(|| anyhow!(tr("game_config_error")))?;
+        let load_order = self.game_load_order().read().unwrap().clone();
+
+        self.toggle_main_window(false);
+        let receiver = CENTRAL_COMMAND.send_background(Command::FreezeCampaignIntegrity(game_config, load_order));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        self.toggle_main_window(true);
+
+        let fingerprint = match response {
+            Response::Fingerprint(fingerprint) => fingerprint,
+            Response::Error(error) => return Err(error),
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        };
+
+        show_dialog(self.main_window(), tre("freeze_campaign_integrity_success", &[&fingerprint.packs().len().to_string()]), true);
+        Ok(())
+    }
+
+    /// This function reports which of the packs frozen at the start of the campaign changed since then.
+    pub unsafe fn verify_campaign_integrity(&self) -> Result<()> {
+        let game_config = self.game_config().read().unwrap().clone().ok_or_else(|| anyhow!(tr("game_config_error")))?;
+        let load_order = self.game_load_order().read().unwrap().clone();
+
+        self.toggle_main_window(false);
+        let receiver = CENTRAL_COMMAND.send_background(Command::VerifyCampaignIntegrity(game_config, load_order));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        self.toggle_main_window(true);
+
+        let result = match response {
+            Response::OptionVecFingerprintDifferenceU64(result) => result,
+            Response::Error(error) => return Err(error),
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        };
+
+        let (differences, frozen_at) = match result {
+            Some(result) => result,
+            None => return Err(anyhow!(tr("verify_campaign_integrity_not_frozen"))),
+        };
+
+        let date_format = time::format_description::parse(&setting_string("date_format"))?;
+        let frozen_at = OffsetDateTime::from_unix_timestamp(frozen_at as i64)?.format(&date_format)?;
+
+        if differences.is_empty() {
+            show_dialog(self.main_window(), tre("verify_campaign_integrity_ok", &[&frozen_at]), true);
+        } else {
+            let list = differences.iter()
+                .map(|difference| format!("<li>{}</li>", Self::campaign_integrity_difference_text(difference)))
+                .join("");
+
+            show_dialog(self.main_window(), tre("verify_campaign_integrity_changed", &[&frozen_at, &list]), false);
+        }
+
+        Ok(())
+    }
+
+    fn campaign_integrity_difference_text(difference: &FingerprintDifference) -> String {
+        match difference {
+            FingerprintDifference::Game(first, second) |
+            FingerprintDifference::Build(first, second) => tre("campaign_integrity_difference_build", &[first, second]),
+            FingerprintDifference::PackOnlyInFirst(pack_name) => tre("campaign_integrity_difference_removed", &[pack_name]),
+            FingerprintDifference::PackOnlyInSecond(pack_name) => tre("campaign_integrity_difference_added", &[pack_name]),
+            FingerprintDifference::PackHash(pack_name) => tre("campaign_integrity_difference_hash", &[pack_name]),
+            FingerprintDifference::PackPosition(pack_name, first, second) => tre("campaign_integrity_difference_position", &[pack_name, &first.to_string(), &second.to_string()]),
+            FingerprintDifference::LaunchOption(key, first, second) => tre("fingerprint_difference_launch_option", &[&tr(key), first, second]),
+        }
+    }
+
+    /// This function checks the load order for packs that usually cause problems if left enabled, and asks the user what to do with them.
+    ///
+    /// Returns false if the user cancelled the launch.
+    pub unsafe fn check_load_order_lint(&self) -> Result<bool> {
+
+        let lints = {
+            let game = self.game_selected().read().unwrap();
+            let game_path = setting_path(game.key());
+            let game_data_path = game.data_path(&game_path)?;
+
+            match *self.game_config().read().unwrap() {
+                Some(ref game_config) => LintPatterns::load().lint(game_config, &self.game_load_order().read().unwrap(), &game_data_path),
+                None => return Ok(true),
+            }
+        };
+
+        if lints.is_empty() {
+            return Ok(true);
+        }
+
+        let list = lints.iter().map(|(mod_id, reason)| format!("<li><b>{}</b>: {}</li>", mod_id, reason)).join("");
+
+        let dialog = QMessageBox::from_q_widget(self.main_window());
+        dialog.set_window_title(&qtr("lint_title"));
+        dialog.set_icon(q_message_box::Icon::Warning);
+        dialog.set_text(&qtre("lint_description", &[&list]));
+
+        let disable_button = dialog.add_button_q_string_button_role(&qtr("lint_disable_and_launch"), q_message_box::ButtonRole::AcceptRole);
+        dialog.add_button_q_string_button_role(&qtr("lint_launch_anyway"), q_message_box::ButtonRole::YesRole);
+        dialog.add_button_standard_button(q_message_box::StandardButton::Cancel);
+        dialog.set_default_button_q_push_button(&disable_button);
+        dialog.exec();
+
+        let role = dialog.button_role(&dialog.clicked_button());
+        if role == q_message_box::ButtonRole::AcceptRole {
+            let mod_ids = lints.into_iter().map(|(mod_id, _)| mod_id).collect::<Vec<_>>();
+            self.disable_mods(&mod_ids)?;
+            Ok(true)
+        } else {
+            Ok(role == q_message_box::ButtonRole::YesRole)
+        }
+    }
+
+    /// This function checks the enabled mods against the blacklist of mods with known issues, and asks the user what to do with the ones found.
+    ///
+    /// Returns false if the user cancelled the launch.
+    pub unsafe fn check_known_issues(&self) -> Result<bool> {
+
+        let issues = {
+            let game = self.game_selected().read().unwrap();
+            let game_path = setting_path(game.key());
+            let game_data_path = game.data_path(&game_path)?;
+
+            match *self.game_config().read().unwrap() {
+                Some(ref game_config) => {
+                    let load_order = self.game_load_order().read().unwrap();
+                    let enabled = load_order.mods()
+                        .iter()
+                        .chain(load_order.movies().iter())
+                        .filter(|mod_id| game_config.mods().get(*mod_id).is_some_and(|modd| modd.enabled(&game_data_path)))
+                        .cloned()
+                        .collect::<Vec<_>>();
+
+                    ModBlacklist::load_merged().issues(game.key(), &enabled).into_iter().cloned().collect::<Vec<_>>()
+                }
+                None => return Ok(true),
+            }
+        };
+
+        if issues.is_empty() {
+            return Ok(true);
+        }
+
+        let list = issues.iter().map(|entry| format!("<li><b>{}</b>: {}</li>", escape_html(entry.mod_id()), entry.description(entry.mod_id()))).join("");
+
+        let dialog = QMessageBox::from_q_widget(self.main_window());
+        dialog.set_window_title(&qtr("known_issues_title"));
+        dialog.set_icon(q_message_box::Icon::Warning);
+        dialog.set_text(&qtre("known_issues_description", &[&list]));
+
+        let disable_button = dialog.add_button_q_string_button_role(&qtr("lint_disable_and_launch"), q_message_box::ButtonRole::AcceptRole);
+        dialog.add_button_q_string_button_role(&qtr("known_issues_silence_and_launch"), q_message_box::ButtonRole::ApplyRole);
+        dialog.add_button_q_string_button_role(&qtr("lint_launch_anyway"), q_message_box::ButtonRole::YesRole);
+        dialog.add_button_standard_button(q_message_box::StandardButton::Cancel);
+        dialog.set_default_button_q_push_button(&disable_button);
+        dialog.exec();
+
+        let role = dialog.button_role(&dialog.clicked_button());
+        if role == q_message_box::ButtonRole::AcceptRole {
+
+            // For incompatibilities, disabling one of the mods is enough. We disable the one the entry is about.
+            let mod_ids = issues.iter().map(|entry| entry.mod_id().to_owned()).sorted().dedup().collect::<Vec<_>>();
+            self.disable_mods(&mod_ids)?;
+            Ok(true)
+        } else if role == q_message_box::ButtonRole::ApplyRole {
+            let mut blacklist = ModBlacklist::load_user();
+            for entry in &issues {
+                let key = entry.key();
+                if !blacklist.silenced().contains(&key) {
+                    blacklist.silenced_mut().push(key);
+                }
+            }
+
+            blacklist.save_user()?;
+            Ok(true)
+        } else {
+            Ok(role == q_message_box::ButtonRole::YesRole)
+        }
+    }
+
+    /// This function adds the selected mods to the user's blacklist of mods with known issues, with the reason provided by the user.
+    ///
+    /// If two mods are selected, they're added as incompatible with each other. Otherwise, each one is added as broken on its own.
+    pub unsafe fn add_known_issue(&self) -> Result<()> {
+        let mod_ids = self.mod_list_selection()
+            .iter()
+            .filter(|index| !index.data_1a(VALUE_IS_CATEGORY).to_bool())
+            .map(|index| index.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+            .collect::<Vec<_>>();
+
+        if mod_ids.is_empty() {
+            return Ok(());
+        }
+
+        let dialog = QInputDialog::new_1a(self.main_window());
+        dialog.set_window_title(&qtr("add_known_issue"));
+        dialog.set_label_text(&qtr("add_known_issue_reason"));
+        dialog.set_input_mode(InputMode::TextInput);
+
+        if dialog.exec() == DialogCode::Accepted.to_int() {
+            let reason = dialog.text_value().to_std_string().trim().to_owned();
+            if reason.is_empty() {
+                return Ok(());
+            }
+
+            let game = self.game_selected().read().unwrap().clone();
+            let mut blacklist = ModBlacklist::load_user();
+            if mod_ids.len() == 2 {
+                blacklist.entries_mut().push(BlacklistEntry::new(game.key(), &mod_ids[0], Some(mod_ids[1].to_owned()), &reason, None));
+            } else {
+                for mod_id in &mod_ids {
+                    blacklist.entries_mut().push(BlacklistEntry::new(game.key(), mod_id, None, &reason, None));
+                }
+            }
+
+            blacklist.save_user()?;
+
+            // Reload the mod list, so the new entries are shown in it.
+            if let Some(ref game_config) = *self.game_config().read().unwrap() {
+                self.mod_list_ui().load(&game, game_config)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This function warns the user about problems in a pack they're about to upload: being saved for another game, or containing leftover files.
+    ///
+    /// Returns false if the user cancelled the upload.
+    pub unsafe fn check_pack_before_upload(&self, game: &GameInfo, pack_path: &Path) -> Result<bool> {
+        let check = upload_check(game, SCHEMA.read().unwrap().as_ref(), pack_path)?;
+        if !check.has_problems() {
+            return Ok(true);
+        }
+
+        let mut problems = String::new();
+        if check.pack_version() != check.game_version() {
+            problems.push_str(&tre("upload_check_wrong_game", &[&format!("{:?}", check.pack_version()), &format!("{:?}", check.game_version()), game.display_name()]));
+        }
+
+        if !check.foreign_tables().is_empty() {
+            let list = check.foreign_tables().iter().map(|(table_name, version)| format!("<li>{} (v{})</li>", table_name, version)).join("");
+            problems.push_str(&tre("upload_check_foreign_tables", &[game.display_name(), &list]));
+        }
+
+        if !check.leftover_files().is_empty() {
+            let list = check.leftover_files().iter().map(|path| format!("<li>{}</li>", path)).join("");
+            problems.push_str(&tre("upload_check_leftover_files", &[&list]));
+        }
+
+        let dialog = QMessageBox::from_q_widget(self.main_window());
+        dialog.set_window_title(&qtr("upload_check_title"));
+        dialog.set_icon(q_message_box::Icon::Warning);
+        dialog.set_text(&qtre("upload_check_description", &[&problems]));
+
+        dialog.add_button_q_string_button_role(&qtr("upload_check_upload_anyway"), q_message_box::ButtonRole::YesRole);
+        let cancel_button = dialog.add_button_standard_button(q_message_box::StandardButton::Cancel);
+        dialog.set_default_button_q_push_button(&cancel_button);
+        dialog.exec();
+
+        Ok(dialog.button_role(&dialog.clicked_button()) == q_message_box::ButtonRole::YesRole)
+    }
+
+    /// This function warns the user about mods in the load order to share that are not public in the Workshop, as others cannot subscribe to them.
+    ///
+    /// The user can exclude them from the load order, export their packs to send them alongside it, or share them anyway.
+    /// Returns false if the user cancelled the sharing.
+    pub unsafe fn check_shared_mods_visibility(&self, game_config: &GameConfig, load_order: &mut LoadOrder) -> Result<bool> {
+        let restricted = load_order.mods()
+            .iter()
+            .filter_map(|mod_id| game_config.mods().get(mod_id))
+            .filter(|modd| modd.visibility().is_some_and(|visibility| visibility != PublishedFileVisibilityDerive::Public))
+            .collect::<Vec<_>>();
+
+        if restricted.is_empty() {
+            return Ok(true);
+        }
+
+        let list = restricted.iter()
+            .map(|modd| format!("<li><b>{}</b> ({}): {}</li>", modd.name(), modd.id(), tr(modd.visibility().unwrap_or_default().locale_key())))
+            .join("");
+
+        let dialog = QMessageBox::from_q_widget(self.main_window());
+        dialog.set_window_title(&qtr("share_restricted_title"));
+        dialog.set_icon(q_message_box::Icon::Warning);
+        dialog.set_text(&qtre("share_restricted_description", &[&list]));
+
+        let exclude_button = dialog.add_button_q_string_button_role(&qtr("share_restricted_exclude"), q_message_box::ButtonRole::AcceptRole);
+        dialog.add_button_q_string_button_role(&qtr("share_restricted_attach"), q_message_box::ButtonRole::ActionRole);
+        dialog.add_button_q_string_button_role(&qtr("share_restricted_share_anyway"), q_message_box::ButtonRole::YesRole);
+        dialog.add_button_standard_button(q_message_box::StandardButton::Cancel);
+        dialog.set_default_button_q_push_button(&exclude_button);
+        dialog.exec();
+
+        let role = dialog.button_role(&dialog.clicked_button());
+        if role == q_message_box::ButtonRole::AcceptRole {
+            let mod_ids = restricted.iter().map(|modd| modd.id().to_owned()).collect::<Vec<_>>();
+            load_order.mods_mut().retain(|mod_id| !mod_ids.contains(mod_id));
+            Ok(true)
+        } else if role == q_message_box::ButtonRole::ActionRole {
+            let file_dialog = QFileDialog::from_q_widget_q_string(self.main_window(), &qtr("share_restricted_select_folder"));
+            file_dialog.set_file_mode(FileMode::Directory);
+            file_dialog.set_options(QFlags::from(QFileDialogOption::ShowDirsOnly));
+
+            if file_dialog.exec() != 1 {
+                return Ok(false);
+            }
+
+            let folder = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            for modd in &restricted {
+                if let Some(path) = modd.paths().first() {
+                    if let Some(file_name) = path.file_name() {
+                        std::fs::copy(path, folder.join(file_name))?;
+                    }
+                }
+            }
+
+            show_dialog(self.main_window(), tre("share_restricted_attached", &[&folder.to_string_lossy()]), true);
+            Ok(true)
+        } else {
+            Ok(role == q_message_box::ButtonRole::YesRole)
+        }
+    }
+
+    /// This function checks the scripts of the enabled mods for collisions, and warns the user about them.
+    ///
+    /// Returns false if the user cancelled the launch.
+    pub unsafe fn check_script_collisions(&self) -> Result<bool> {
+        if !setting_bool("check_script_collisions") {
+            return Ok(true);
+        }
+
+        let collisions = script_collisions(&self.game_load_order().read().unwrap());
+        if collisions.is_empty() {
+            return Ok(true);
+        }
+
+        let list = collisions.iter()
+            .map(|collision| {
+                let key = match collision.kind() {
+                    ScriptCollisionKind::Path => "script_collision_path",
+                    ScriptCollisionKind::ModuleName => "script_collision_module",
+                };
+
+                format!("<li>{}</li>", tre(key, &[collision.name(), &collision.mods().join(", ")]))
+            })
+            .join("");
+
+        let dialog = QMessageBox::from_q_widget(self.main_window());
+        dialog.set_window_title(&qtr("script_collisions_title"));
+        dialog.set_icon(q_message_box::Icon::Warning);
+        dialog.set_text(&qtre("script_collisions_description", &[&list]));
+
+        let launch_button = dialog.add_button_q_string_button_role(&qtr("lint_launch_anyway"), q_message_box::ButtonRole::YesRole);
+        dialog.add_button_standard_button(q_message_box::StandardButton::Cancel);
+        dialog.set_default_button_q_push_button(&launch_button);
+        dialog.exec();
+
+        Ok(dialog.button_role(&dialog.clicked_button()) == q_message_box::ButtonRole::YesRole)
+    }
+
+    /// This function checks for packs with copies in multiple folders that differ from the copy the game loads,
+    /// and offers to delete or rename the ignored copies.
+    ///
+    /// When launching, only the enabled mods are checked. Returns false if the user cancelled the launch.
+    pub unsafe fn check_shadowed_packs(&self, launching: bool) -> Result<bool> {
+        if launching && !setting_bool("check_shadowed_packs") {
+            return Ok(true);
+        }
+
+        let (game_config, mod_ids) = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => {
+                let mod_ids = if launching {
+                    let load_order = self.game_load_order().read().unwrap();
+                    load_order.mods().iter().chain(load_order.movies().iter()).cloned().collect::<Vec<_>>()
+                } else {
+                    game_config.mods().values().filter(|modd| !*modd.ignored()).map(|modd| modd.id().to_owned()).collect::<Vec<_>>()
+                };
+
+                (game_config.clone(), mod_ids)
+            }
+            None => return Ok(true),
+        };
+
+        // Hashing every copy takes a while, so it's done in the background.
+        let game = self.game_selected().read().unwrap().clone();
+        let content_path = game.content_path(&setting_path(game.key())).unwrap_or_default();
+
+        self.toggle_main_window(false);
+        let receiver = CENTRAL_COMMAND.send_background(Command::FindShadowedPacks(game_config, mod_ids, content_path));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        self.toggle_main_window(true);
+
+        let shadowed = match response {
+            Response::VecShadowedPack(shadowed) => shadowed,
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        };
+
+        if shadowed.is_empty() {
+            if !launching {
+                show_dialog(self.main_window(), tr("shadowed_packs_none"), true);
+            }
+
+            return Ok(true);
+        }
+
+        let date_format = time::format_description::parse(&setting_string("date_format"))?;
+        let describe = |copy: &PackCopy| -> Result<String> {
+            let date = OffsetDateTime::from_unix_timestamp(*copy.modified() as i64)?.format(&date_format)?;
+            let key = if *copy.workshop() { "shadowed_pack_copy_workshop" } else { "shadowed_pack_copy" };
+            Ok(tre(key, &[&copy.path().to_string_lossy(), copy.hash().get(..8).unwrap_or(copy.hash()), &date]))
+        };
+
+        let mut list = String::new();
+        for pack in &shadowed {
+            let winner = describe(pack.winner())?;
+            let losers = pack.losers().iter()
+                .map(&describe)
+                .collect::<Result<Vec<_>>>()?
+                .join("<br/>");
+
+            list.push_str(&format!("<li>{}</li>", tre("shadowed_pack", &[pack.mod_id(), &winner, &losers])));
+        }
+
+        let dialog = QMessageBox::from_q_widget(self.main_window());
+        dialog.set_window_title(&qtr("shadowed_packs_title"));
+        dialog.set_icon(q_message_box::Icon::Warning);
+        dialog.set_text(&qtre("shadowed_packs_description", &[&list]));
+
+        dialog.add_button_q_string_button_role(&qtr("shadowed_packs_delete"), q_message_box::ButtonRole::DestructiveRole);
+        dialog.add_button_q_string_button_role(&qtr("shadowed_packs_rename"), q_message_box::ButtonRole::ActionRole);
+        if launching {
+            let launch_button = dialog.add_button_q_string_button_role(&qtr("lint_launch_anyway"), q_message_box::ButtonRole::YesRole);
+            dialog.add_button_standard_button(q_message_box::StandardButton::Cancel);
+            dialog.set_default_button_q_push_button(&launch_button);
+        } else {
+            dialog.add_button_standard_button(q_message_box::StandardButton::Close);
+        }
+        dialog.exec();
+
+        let role = dialog.button_role(&dialog.clicked_button());
+        if role == q_message_box::ButtonRole::DestructiveRole || role == q_message_box::ButtonRole::ActionRole {
+            let errors = shadowed.iter()
+                .filter_map(|pack| {
+                    let result = if role == q_message_box::ButtonRole::DestructiveRole {
+                        pack.delete_losers()
+                    } else {
+                        pack.rename_losers()
+                    };
+
+                    result.err().map(|error| format!("<li>{}: {}</li>", pack.mod_id(), error))
+                })
+                .join("");
+
+            // Reload so the mods lose the paths we just removed.
+            self.actions_ui().reload_button().click();
+
+            if !errors.is_empty() {
+                return Err(anyhow!(tre("shadowed_packs_failed", &[&errors])));
+            }
+
+            // The launch goes on once the copies are fixed, as that's what the user asked for.
+            return Ok(true);
+        }
+
+        Ok(role == q_message_box::ButtonRole::YesRole)
+    }
+
+    /// This function looks for mods with identical packs, or packs with the same files, and shows them grouped in a report.
+    ///
+    /// The most recently updated mod of each group is suggested as the one to keep, as the others are usually abandoned copies.
+    pub unsafe fn find_duplicate_mods(&self) -> Result<()> {
+        let game_config = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => game_config.clone(),
+            None => return Ok(()),
+        };
+
+        let mod_ids = game_config.mods().values().filter(|modd| !*modd.ignored()).map(|modd| modd.id().to_owned()).collect::<Vec<_>>();
+
+        // Every pack is hashed and read, so this takes a while with big mod lists.
+        let progress_dialog = QProgressDialog::from_q_string2_int2_q_widget(&qtr("duplicate_mods_progress"), &QString::new(), 0, mod_ids.len() as i32, self.main_window());
+        progress_dialog.set_window_title(&qtr("duplicate_mods_title"));
+        progress_dialog.set_window_modality(WindowModality::WindowModal);
+        progress_dialog.set_minimum_duration(0);
+        progress_dialog.show();
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::FindDuplicateMods(game_config.clone(), mod_ids));
+        let groups = loop {
+            let response = CENTRAL_COMMAND.recv_try(&receiver);
+            match response {
+                Response::Usize(checked) => progress_dialog.set_value(checked as i32),
+                Response::VecDuplicateGroup(groups) => break groups,
+                _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+            }
+        };
+
+        progress_dialog.close();
+        progress_dialog.delete_later();
+
+        if groups.is_empty() {
+            show_dialog(self.main_window(), tr("duplicate_mods_none"), true);
+            return Ok(());
+        }
+
+        let date_format = time::format_description::parse(&setting_string("date_format"))?;
+        let mut list = String::new();
+        for group in &groups {
+            let mut mods = String::new();
+            for (index, modd) in group.mods().iter().enumerate() {
+                let name = game_config.mods().get(modd.mod_id()).map(|modd| modd.name().to_owned()).unwrap_or_else(|| modd.mod_id().to_owned());
+                let date = OffsetDateTime::from_unix_timestamp(*modd.updated() as i64)?.format(&date_format)?;
+                let key = if index == 0 { "duplicate_mod_keep" } else { "duplicate_mod" };
+                mods.push_str(&format!("<li>{}</li>", tre(key, &[&name, modd.mod_id(), &date])));
+            }
+
+            list.push_str(&format!("<li>{}<ul>{}</ul></li>", tr(group.kind().locale_key()), mods));
+        }
+
+        let dialog = QMessageBox::from_q_widget(self.main_window());
+        dialog.set_window_title(&qtr("duplicate_mods_title"));
+        dialog.set_icon(q_message_box::Icon::Information);
+        dialog.set_text(&qtre("duplicate_mods_description", &[&list]));
+        dialog.add_button_standard_button(q_message_box::StandardButton::Close);
+        dialog.exec();
+
+        Ok(())
+    }
+
+    /// This function checks if other mod managers are working on the selected game, and warns the user about them.
+    ///
+    /// Returns false if the user cancelled the launch.
+    pub unsafe fn check_other_managers(&self) -> Result<bool> {
+        if !setting_bool("check_other_managers") {
+            return Ok(true);
+        }
+
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let conflicts = self.manager_conflicts(&game, &game_path);
+        Ok(conflicts.is_empty() || self.show_manager_conflicts(&conflicts, true))
+    }
+
+    /// This function returns the other mod managers that may be working on the provided game.
+    ///
+    /// Listing the running processes takes a while, so it's done in the background.
+    unsafe fn manager_conflicts(&self, game: &GameInfo, game_path: &Path) -> Vec<ManagerConflict> {
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetManagerConflicts(Box::new(game.clone()), game_path.to_path_buf()));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        match response {
+            Response::VecManagerConflict(conflicts) => conflicts,
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        }
+    }
+
+    /// This function returns the process id of the other instance of Runcher that has the provided game loaded, if any.
+    unsafe fn game_loaded_elsewhere(&self, game_key: &str) -> Option<u32> {
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetGameLoadedElsewhere(game_key.to_owned()));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        match response {
+            Response::OptionU32(pid) => pid,
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        }
+    }
+
+    /// This function tells the user which other mod managers are working on the selected game, and how to stop them from changing its mods.
+    ///
+    /// Returns false if the user cancelled the launch. If we're not launching, there's nothing to cancel.
+    pub unsafe fn show_manager_conflicts(&self, conflicts: &[ManagerConflict], launching: bool) -> bool {
+        let list = conflicts.iter()
+            .map(|conflict| {
+                let message = match conflict {
+                    ManagerConflict::VortexDeployment(path) => tre(conflict.locale_key(), &[&path.to_string_lossy()]),
+                    ManagerConflict::Runcher(pid) => tre(conflict.locale_key(), &[&pid.to_string()]),
+                    _ => tr(conflict.locale_key()),
+                };
+
+                format!("<li>{}</li>", message)
+            })
+            .join("");
+
+        let dialog = QMessageBox::from_q_widget(self.main_window());
+        dialog.set_window_title(&qtr("manager_conflicts_title"));
+        dialog.set_icon(q_message_box::Icon::Warning);
+        dialog.set_text(&qtre("manager_conflicts_description", &[&list]));
+
+        if launching {
+            let launch_button = dialog.add_button_q_string_button_role(&qtr("lint_launch_anyway"), q_message_box::ButtonRole::YesRole);
+            dialog.add_button_standard_button(q_message_box::StandardButton::Cancel);
+            dialog.set_default_button_q_push_button(&launch_button);
+        } else {
+            dialog.add_button_standard_button(q_message_box::StandardButton::Close);
+        }
+        dialog.exec();
+
+        !launching || dialog.button_role(&dialog.clicked_button()) == q_message_box::ButtonRole::YesRole
+    }
+
+    /// This function regenerates the masks folder of the selected game, and reports what was fixed and what is masked now.
+    pub unsafe fn rebuild_masks(&self) -> Result<()> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let data_path = game.data_path(&game_path)?;
+
+        // Fail early if the game has no secondary folder, as it has no masks.
+        masks_path(&game)?;
+
+        let (before, after) = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => {
+                let before = verify_masks(&game, game_config, &data_path)?;
+                rebuild_masks(&game, game_config, &data_path)?;
+                (before, verify_masks(&game, game_config, &data_path)?)
+            }
+            None => return Ok(()),
+        };
+
+        let fixed = before.stale().iter()
+            .map(|(file_name, reason)| format!("<li>{}</li>", tre("rebuild_masks_removed", &[file_name, &tr(reason.locale_key())])))
+            .chain(before.missing().iter().map(|file_name| format!("<li>{}</li>", tre("rebuild_masks_added", &[file_name]))))
+            .join("");
+
+        let masked = after.masks().iter()
+            .map(|(file_name, reason)| format!("<li>{}: {}</li>", file_name, tr(reason.locale_key())))
+            .join("");
+
+        if !after.is_consistent() {
+            return Err(anyhow!(tre("rebuild_masks_failed", &[&fixed, &masked])));
         }
+
+        let fixed = if fixed.is_empty() { tr("rebuild_masks_nothing_fixed") } else { format!("<ul>{fixed}</ul>") };
+        let masked = if masked.is_empty() { tr("rebuild_masks_nothing_masked") } else { format!("<ul>{masked}</ul>") };
+        show_dialog(self.main_window(), tre("rebuild_masks_report", &[&fixed, &masked]), true);
+        Ok(())
     }
 
-    pub unsafe fn download_subscribed_mods(&self, published_file_ids: &Option<Vec<String>>) -> Result<()> {
-        self.toggle_main_window(false);
+    /// This function checks if the game has been updated since the last launch and, if so, warns about the enabled script mods
+    /// not updated since then, as they're the ones more likely to break with the update.
+    ///
+    /// Returns false if the user cancelled the launch.
+    pub unsafe fn check_game_update(&self) -> Result<bool> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let build = match game_build(&game, &game_path) {
+            Some(build) => build,
+            None => return Ok(true),
+        };
 
-        crate::mod_manager::integrations::download_subscribed_mods(&self.game_selected().read().unwrap(), published_file_ids)?;
+        let (previous_build, outdated) = match *self.game_config().write().unwrap() {
+            Some(ref mut game_config) => {
+                let previous_build = game_config.game_build().clone();
+                if previous_build.as_ref() == Some(&build) {
+                    return Ok(true);
+                }
 
-        self.toggle_main_window(true);
+                // On the first launch we have nothing to compare against, and without the date of the update we cannot know what's outdated.
+                let outdated = match (&previous_build, game_patch_date(&game, &game_path)) {
+                    (Some(_), Some(patch_date)) => outdated_script_mods(game_config, &self.game_load_order().read().unwrap(), patch_date),
+                    _ => vec![],
+                };
 
-        // Once done, do a reload of the mod list.
-        self.actions_ui().reload_button().click();
+                if outdated.is_empty() {
+                    game_config.set_game_build(Some(build.to_owned()));
+                    game_config.save(&game)?;
+                    return Ok(true);
+                }
 
-        Ok(())
-    }
+                (previous_build.unwrap_or_default(), outdated)
+            }
+            None => return Ok(true),
+        };
 
-    pub unsafe fn check_logs(&self, game: &GameInfo, game_path: &Path, start_date: &SystemTime) -> Result<()> {
+        let list = outdated.iter().map(|mod_id| format!("<li>{}</li>", mod_id)).join("");
 
-        // NOTE: THIS IS A HACK. WE NEED TO USE SOME KIND OF CACHED DATA, NOT REMAKE IT HERE!!!!
-        let game_config = self.game_config().read().unwrap().clone().unwrap();
-        let load_order = self.game_load_order().read().unwrap();
-        let pack = self.data_list_ui().generate_data(&game_config, game, game_path, &load_order)?;
+        let dialog = QMessageBox::from_q_widget(self.main_window());
+        dialog.set_window_title(&qtr("game_updated_title"));
+        dialog.set_icon(q_message_box::Icon::Warning);
+        dialog.set_text(&qtre("game_updated_description", &[&previous_build, &build, &list]));
 
-        let vanilla_paths = game.ca_packs_paths(game_path)?;
-        let files = files_from_subdir(&game_path, false)?;
-        let paths = files.iter()
-            .filter(|path| {
-                let modified = path.metadata().unwrap().modified().unwrap();
-                //let start_date = &SystemTime::from(std::time::UNIX_EPOCH);
-                modified > *start_date && path.extension().is_some() && path.extension().unwrap() == "txt"
-            })
-            .collect::<Vec<_>>();
+        let disable_button = dialog.add_button_q_string_button_role(&qtr("game_updated_disable_and_launch"), q_message_box::ButtonRole::AcceptRole);
+        dialog.add_button_q_string_button_role(&qtr("lint_launch_anyway"), q_message_box::ButtonRole::YesRole);
+        dialog.add_button_standard_button(q_message_box::StandardButton::Cancel);
+        dialog.set_default_button_q_push_button(&disable_button);
+        dialog.exec();
 
-        let mut breaks = vec![];
-        for path in &paths {
-            let mut data = String::new();
-            let mut file = BufReader::new(File::open(path)?);
-
-            // This fails in the clockwork one due to being windows-1252
-            if file.read_to_string(&mut data).is_ok() {
-
-                // Normal error.
-                /*
-                ********************
-                SCRIPT ERROR, timestamp <375.0s>
-                ERROR - SCRIPT HAS FAILED - event callback was called after receiving event [WorldStartRound] but the script failed with this error message:
-                [string "script\campaign\dynamic_disasters\disaster_the_great_bastion_improved.lua"]:609: attempt to get length of field '?' (a nil value)
-
-                The callstack of the failed script is:
-
-                stack traceback:
-                    [string "script\campaign\dynamic_disasters\disaster_the_great_bastion_improved.lua"]:609: in function 'trigger_pre_invasion_1'
-                    [string "script\campaign\dynamic_disasters\disaster_the_great_bastion_improved.lua"]:313: in function 'callback'
-                    [string "script\_lib\lib_core.lua"]:1930: in function <[string "script\_lib\lib_core.lua"]:1930>
-                    [C]: in function 'xpcall'
-                    [string "script\_lib\lib_core.lua"]:1930: in function 'event_protected_callback'
-                    [string "script\_lib\lib_core.lua"]:1991: in function 'event_callback'
-                    [string "script\_lib\lib_core.lua"]:2051: in function <[string "script\_lib\lib_core.lua"]:2051>
-
-                The callstack of the script which established the failed listener is:
-                stack traceback:
-                    [string "script\_lib\lib_core.lua"]:1908: in function 'add_listener'
-                    [string "script\campaign\dynamic_disasters\disaster_the_great_bastion_improved.lua"]:260: in function 'set_status'
-                    [string "script\campaign\dynamic_disasters\disaster_the_great_bastion_improved.lua"]:565: in function 'trigger_the_great_bastion_improved'
-                    [string "script\campaign\dynamic_disasters\disaster_the_great_bastion_improved.lua"]:486: in function 'start'
-                    [string "script\campaign\mod\dynamic_disasters.lua"]:606: in function <[string "script\campaign\mod\dynamic_disasters.lua"]:536>
-                    (tail call): ?
-                    [string "script\_lib\lib_core.lua"]:1930: in function <[string "script\_lib\lib_core.lua"]:1930>
-                    [C]: in function 'xpcall'
-                    [string "script\_lib\lib_core.lua"]:1930: in function 'event_protected_callback'
-                    [string "script\_lib\lib_core.lua"]:1991: in function 'event_callback'
-                    [string "script\_lib\lib_core.lua"]:2051: in function <[string "script\_lib\lib_core.lua"]:2051>
-                ********************
-                 */
-                let normal_errors = data.match_indices("SCRIPT ERROR, timestamp").collect::<Vec<_>>();
-                for (start_error, _) in normal_errors {
-                    if let Some(end_error) = data[start_error..].find("********************") {
-                        let message = data[start_error..start_error + end_error].to_owned();
-                        let mut script_break = ScriptBreak::default();
-                        script_break.full_log = message.to_owned();
-
-                        let start_path = "[string \"";
-                        let end_path = "\"]:";
-                        let mut paths = vec![];
-                        for (start_path_pos, _) in message.match_indices(start_path) {
-                            if let Some(end_path_pos) = message[start_path_pos + 9..].find(&end_path) {
-                                let path = message[start_path_pos + 9..start_path_pos + 9 + end_path_pos].replace("\\", "/");
-                                paths.push(path);
-                            }
-                        }
+        let role = dialog.button_role(&dialog.clicked_button());
+        if role != q_message_box::ButtonRole::AcceptRole && role != q_message_box::ButtonRole::YesRole {
+            return Ok(false);
+        }
 
-                        // NOTE: pack finding only works if the pack that caused it is in the current run. Take that into account for tests.
-                        for path in &paths {
-                            if let Some(file) = pack.file(&path, true) {
-                                if let Some(pack_name) = file.container_name() {
-                                    if !pack_name.is_empty() && vanilla_paths.iter().all(|x| &x.file_name().unwrap().to_string_lossy().to_string() != pack_name) {
-                                        script_break.posible_pack = pack_name.to_owned();
-
-                                        // This is only valid in newer games!!!
-                                        let modd = game_config.mods().get(pack_name);
-                                        script_break.posible_pack_mod = modd
-                                            .map(|modd| modd.name().to_string())
-                                            .unwrap_or_else(|| String::new());
-                                        script_break.posible_pack_link = modd
-                                            .map(|modd| modd.steam_id()
-                                                .clone()
-                                                .map(|id| format!("https://steamcommunity.com/sharedfiles/filedetails/?id={}", id)))
-                                            .flatten();
-                                        break;
-                                    }
-                                }
-                            }
-                        }
+        // Only remember the new build once the user decided, so we warn once per update, but cancelling keeps the warning for the next launch.
+        if let Some(ref mut game_config) = *self.game_config().write().unwrap() {
+            game_config.set_game_build(Some(build));
+            game_config.save(&game)?;
+        }
 
-                        breaks.push(script_break);
-                    }
-                }
+        if role == q_message_box::ButtonRole::AcceptRole {
+            self.disable_mods(&outdated)?;
+        }
 
-                // Big Fat error.
-                /*
-                [out] <1593.9s>  BIG FAT SCRIPT ERROR
-                [out] <1593.9s>  [string "script\campaign\mod\meh_blightwing_duchy_campaign_features.lua"]:63: attempt to call method 'character_subtype_key' (a nil value)
-                [out] <1593.9s>  stack traceback:
-                    [string "script\_lib\mod\pj_error_wrapping.lua"]:50: in function 'condition'
-                    [string "script\_lib\lib_core.lua"]:1928: in function <[string "script\_lib\lib_core.lua"]:1928>
-                    [C]: in function 'xpcall'
-                    [string "script\_lib\lib_core.lua"]:1928: in function 'event_protected_callback'
-                    [string "script\_lib\lib_core.lua"]:1965: in function 'event_callback'
-                    [string "script\_lib\lib_core.lua"]:2051: in function <[string "script\_lib\lib_core.lua"]:2051>
-                [out] <1594.1s>   & Removing effect bundle [wh3_main_bundle_force_crackdown_corruption] from military force with cqi [80]
-                [out] <1594.1s>   & Removing effect bundle [ovn_fimir_fog_diktat_empty] from the force of character with cqi [159]
-                [out] <1594.1s>  DrunkFlamingo: Checking faction ally outposts for faction: wh2_dlc17_bst_malagor (temp tomb king ally fix)
-
-                 */
-                let big_fat_errors = data.match_indices("BIG FAT SCRIPT ERROR").collect::<Vec<_>>();
-                for (start_error, _) in big_fat_errors {
-
-                    // For end we use the third out.
-                    if let Some(first) = data[start_error..].find("[out]") {
-                        if let Some(second) = data[start_error + first + 3 ..].find("[out]") {
-                            if let Some(end_error) = data[start_error + first + 3 + second + 3..].find("[out]") {
-                                let message = data[start_error..start_error + first + 3 + second + 3 + end_error].to_owned();
-                                let mut script_break = ScriptBreak::default();
-                                script_break.full_log = message.to_owned();
-
-                                let start_path = "[string \"";
-                                let end_path = "\"]:";
-                                let mut paths = vec![];
-                                for (start_path_pos, _) in message.match_indices(start_path) {
-                                    if let Some(end_path_pos) = message[start_path_pos + 9..].find(&end_path) {
-                                        let path = message[start_path_pos + 9..start_path_pos + 9 + end_path_pos].replace("\\", "/");
-                                        paths.push(path);
-                                    }
-                                }
+        Ok(true)
+    }
 
-                                // NOTE: pack finding only works if the pack that caused it is in the current run. Take that into account for tests.
-                                for path in &paths {
-                                    if let Some(file) = pack.file(&path, true) {
-                                        if let Some(pack_name) = file.container_name() {
-                                            if !pack_name.is_empty() && vanilla_paths.iter().all(|x| &x.file_name().unwrap().to_string_lossy().to_string() != pack_name) {
-                                                script_break.posible_pack = pack_name.to_owned();
-
-                                                // This is only valid in newer games!!!
-                                                let modd = game_config.mods().get(pack_name);
-                                                script_break.posible_pack_mod = modd
-                                                    .map(|modd| modd.name().to_string())
-                                                    .unwrap_or_else(|| String::new());
-                                                script_break.posible_pack_link = modd
-                                                    .map(|modd| modd.steam_id()
-                                                        .clone()
-                                                        .map(|id| format!("https://steamcommunity.com/sharedfiles/filedetails/?id={}", id)))
-                                                    .flatten();
-                                                break;
-                                            }
-                                        }
-                                    }
-                                }
+    /// This function checks if the save selected to be loaded was made without any of the enabled mods that may need a new campaign, and warns the user about them.
+    ///
+    /// Returns false if the user cancelled the launch.
+    pub unsafe fn check_campaign_safety(&self) -> Result<bool> {
+        let save_index = self.actions_ui().save_combobox().current_index();
+        let save = if save_index > 0 && self.actions_ui().save_combobox().is_enabled() {
+            self.game_saves().read().unwrap().get(save_index as usize - 1).cloned()
+        } else {
+            None
+        };
 
-                                breaks.push(script_break);
-                            }
-                        }
-                    }
-                }
+        let save = match save {
+            Some(save) => save,
+            None => return Ok(true),
+        };
 
-                // File-loading errors.
-                /*
-                [out] <2.8s>            Failed to load mod file [script\campaign\mod\test_errors_1.lua], error is: cannot open test_errors_1: No such file or directory. Will attempt to require() this file to generate a more meaningful error message:
-                [out] <2.8s>                error loading module test_errors_1 from file test_errors_1:[string "script\campaign\mod\test_errors_1.lua"]:2: 'then' expected near 'aaaaa'
-                [out] <2.8s>        Failed to load mod: [script\campaign\mod\test_errors_1.lua]
+        // Reading the save is slow, so we only do it if any of the enabled mods may need a new campaign.
+        let load_order = self.game_load_order().read().unwrap().clone();
+        match *self.game_config().read().unwrap() {
+            Some(ref game_config) => if unsafe_mods_for_save(game_config, load_order.mods(), &[]).is_empty() {
+                return Ok(true);
+            },
+            None => return Ok(true),
+        }
 
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetModsFromSave(save.path().to_path_buf()));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        let save_mods = match response {
+            Response::VecString(pack_names) => pack_names,
 
-                [out] <2.8s>            Failed to execute loaded mod file [script\campaign\mod\test_error_3.lua], error is: [string "script\campaign\mod\test_error_3.lua"]:1: attempt to call global 'test_func' (a nil value)
-                [out] <2.8s>        Failed to load mod: [script\campaign\mod\test_error_3.lua]
+            // Saves we cannot read are not worth stopping the launch for.
+            Response::Error(error) => {
+                info!("Failed to read the mods of the save {}: {}", save.name(), error);
+                return Ok(true);
+            }
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        };
 
-                 */
-                let fail_load_errors = data.match_indices("Failed to load mod file").collect::<Vec<_>>();
-                let fail_execute_errors = data.match_indices("Failed to execute loaded mod file").collect::<Vec<_>>();
-                for (start_error, _) in fail_load_errors.into_iter().chain(fail_execute_errors.into_iter()) {
+        let (unsafe_mods, list) = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => {
+                let unsafe_mods = unsafe_mods_for_save(game_config, load_order.mods(), &save_mods);
+                let list = unsafe_mods.iter()
+                    .filter_map(|mod_id| game_config.mods().get(mod_id))
+                    .map(|modd| {
+                        let reasons = modd.campaign_safety().reasons().iter().map(|reason| tr(reason.locale_key())).join(" ");
+                        format!("<li><b>{}</b>: {}</li>", modd.id(), reasons)
+                    })
+                    .join("");
 
-                    // For end we use the third out.
-                    if let Some(end_error) = data[start_error..].find("Failed to load mod:") {
-                        let message = data[start_error..start_error + end_error].to_owned();
-                        let mut script_break = ScriptBreak::default();
-                        script_break.full_log = message.to_owned();
+                (unsafe_mods, list)
+            }
+            None => return Ok(true),
+        };
 
-                        // PJ for some reason uses requires that fail when the CA loader does its thing. We need to ignore his mod.
-                        if message.contains("Failed to load mod file [script\\campaign\\mod\\pj_") {
-                            continue;
-                        }
+        if unsafe_mods.is_empty() {
+            return Ok(true);
+        }
 
-                        let start_path = "[string \"";
-                        let end_path = "\"]:";
-                        let mut paths = vec![];
-                        for (start_path_pos, _) in message.match_indices(start_path) {
-                            if let Some(end_path_pos) = message[start_path_pos + 9..].find(&end_path) {
-                                let path = message[start_path_pos + 9..start_path_pos + 9 + end_path_pos].replace("\\", "/");
-                                paths.push(path);
-                            }
-                        }
+        let dialog = QMessageBox::from_q_widget(self.main_window());
+        dialog.set_window_title(&qtr("campaign_safety_title"));
+        dialog.set_icon(q_message_box::Icon::Warning);
+        dialog.set_text(&qtre("campaign_safety_description", &[save.name(), &list]));
+
+        let disable_button = dialog.add_button_q_string_button_role(&qtr("campaign_safety_disable_and_launch"), q_message_box::ButtonRole::AcceptRole);
+        dialog.add_button_q_string_button_role(&qtr("lint_launch_anyway"), q_message_box::ButtonRole::YesRole);
+        dialog.add_button_standard_button(q_message_box::StandardButton::Cancel);
+        dialog.set_default_button_q_push_button(&disable_button);
+        dialog.exec();
+
+        let role = dialog.button_role(&dialog.clicked_button());
+        if role == q_message_box::ButtonRole::AcceptRole {
+            self.disable_mods(&unsafe_mods)?;
+            Ok(true)
+        } else {
+            Ok(role == q_message_box::ButtonRole::YesRole)
+        }
+    }
 
-                        // NOTE: pack finding only works if the pack that caused it is in the current run. Take that into account for tests.
-                        for path in &paths {
-                            if let Some(file) = pack.file(&path, true) {
-                                if let Some(pack_name) = file.container_name() {
-                                    if !pack_name.is_empty() && vanilla_paths.iter().all(|x| &x.file_name().unwrap().to_string_lossy().to_string() != pack_name) {
-                                        script_break.posible_pack = pack_name.to_owned();
-
-                                        // This is only valid in newer games!!!
-                                        let modd = game_config.mods().get(pack_name);
-                                        script_break.posible_pack_mod = modd
-                                            .map(|modd| modd.name().to_string())
-                                            .unwrap_or_else(|| String::new());
-                                        script_break.posible_pack_link = modd
-                                            .map(|modd| modd.steam_id()
-                                                .clone()
-                                                .map(|id| format!("https://steamcommunity.com/sharedfiles/filedetails/?id={}", id)))
-                                            .flatten();
-                                        break;
-                                    }
-                                }
-                            }
-                        }
+    /// This function checks the enabled mods for disabled or missing parent packs and not installed DLCs, and warns the user about them.
+    ///
+    /// Returns false if the user cancelled the launch.
+    pub unsafe fn check_missing_requirements(&self) -> Result<bool> {
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let game_data_path = game.data_path(&game_path)?;
 
-                        breaks.push(script_break);
-                    }
+        let (list, parents) = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => {
+                let missing = missing_requirements(&game, &game_path, game_config, &self.game_load_order().read().unwrap(), &game_data_path);
+                if missing.is_empty() {
+                    return Ok(true);
                 }
+
+                let list = missing.iter()
+                    .map(|(mod_id, requirement)| {
+                        let text = match requirement {
+                            MissingRequirement::DisabledParent(parent) => tre("requirement_disabled_parent", &[parent]),
+                            MissingRequirement::MissingParent(parent) => tre("requirement_missing_parent", &[parent]),
+                            MissingRequirement::MissingDlc(dlc) => tre("requirement_missing_dlc", &[dlc]),
+                        };
+
+                        match requirement.link(&game, game_config, &game_path) {
+                            Some(link) => format!("<li><b>{}</b>: {} <a href=\"{}\">{}</a></li>", mod_id, text, link, tr("requirement_link")),
+                            None => format!("<li><b>{}</b>: {}</li>", mod_id, text),
+                        }
+                    })
+                    .join("");
+
+                let parents = missing.iter()
+                    .filter_map(|(_, requirement)| match requirement {
+                        MissingRequirement::DisabledParent(parent) => Some(parent.to_owned()),
+                        _ => None,
+                    })
+                    .sorted()
+                    .dedup()
+                    .collect::<Vec<_>>();
+
+                (list, parents)
             }
+            None => return Ok(true),
+        };
+
+        let dialog = QMessageBox::from_q_widget(self.main_window());
+        dialog.set_window_title(&qtr("requirements_title"));
+        dialog.set_icon(q_message_box::Icon::Warning);
+        dialog.set_text(&qtre("requirements_description", &[&list]));
+
+        // Only offer enabling the parents if there's any we can enable.
+        let enable_button = if !parents.is_empty() {
+            Some(dialog.add_button_q_string_button_role(&qtr("requirements_enable_and_launch"), q_message_box::ButtonRole::AcceptRole))
+        } else {
+            None
+        };
+
+        let launch_button = dialog.add_button_q_string_button_role(&qtr("lint_launch_anyway"), q_message_box::ButtonRole::YesRole);
+        dialog.add_button_standard_button(q_message_box::StandardButton::Cancel);
+        dialog.set_default_button_q_push_button(enable_button.as_ref().unwrap_or(&launch_button));
+        dialog.exec();
+
+        let role = dialog.button_role(&dialog.clicked_button());
+        if role == q_message_box::ButtonRole::AcceptRole {
+            self.enable_mods(&parents)?;
+            Ok(true)
+        } else {
+            Ok(role == q_message_box::ButtonRole::YesRole)
         }
+    }
+
+    /// This function shows the script breaks found in the game's logs after a launch, if any.
+    pub unsafe fn show_log_analysis(&self, game: &GameInfo, game_path: &Path, game_config: &GameConfig, load_order: &LoadOrder, breaks: &[ScriptBreak]) -> Result<()> {
 
         // If breaks are detected, show the dialog with them.
         if !breaks.is_empty() {
 
+            let known_breaks = KnownScriptBreaks::load();
+
             // If breaks were found, load the UI Template.
             let template_path = if cfg!(debug_assertions) { LOG_ANALYSIS_VIEW_DEBUG } else { LOG_ANALYSIS_VIEW_RELEASE };
             let main_widget = load_template(self.main_window(), template_path)?;
@@ -2603,6 +7630,11 @@ impl AppUI {
             let explanation_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "explanation_label")?;
             let explanation_groupbox: QPtr<QGroupBox> = find_widget(&main_widget.static_upcast(), "explanation_groupbox")?;
             let breaks_table_view: QPtr<QTableView> = find_widget(&main_widget.static_upcast(), "breaks_table_view")?;
+            let report_button: QPtr<QPushButton> = find_widget(&main_widget.static_upcast(), "report_button")?;
+            report_button.set_text(&qtr("log_analysis_report_break"));
+            let report_author_button: QPtr<QPushButton> = find_widget(&main_widget.static_upcast(), "report_author_button")?;
+            report_author_button.set_text(&qtr("log_analysis_report_author"));
+            report_author_button.set_tool_tip(&qtr("log_analysis_report_author_tt"));
             explanation_label.set_text(&qtr("log_anaylis_explanation"));
             explanation_groupbox.set_title(&qtr("log_anaylis_explanation_title"));
             dialog.set_window_title(&qtr("log_anaylis_title"));
@@ -2613,24 +7645,37 @@ impl AppUI {
             breaks_table_filter.set_source_model(&breaks_table_model);
 
             // Setup the table.
-            breaks_table_model.set_column_count(2);
+            breaks_table_model.set_column_count(3);
 
             let item_posible_pack = QStandardItem::from_q_string(&qtr("posible_pack"));
+            let item_known_issue = QStandardItem::from_q_string(&qtr("known_issue"));
             let item_full_log = QStandardItem::from_q_string(&qtr("full_log"));
 
             breaks_table_view.horizontal_header().set_default_section_size(600);
             breaks_table_view.horizontal_header().set_stretch_last_section(true);
 
             breaks_table_model.set_horizontal_header_item(0, item_posible_pack.into_ptr());
-            breaks_table_model.set_horizontal_header_item(1, item_full_log.into_ptr());
+            breaks_table_model.set_horizontal_header_item(1, item_known_issue.into_ptr());
+            breaks_table_model.set_horizontal_header_item(2, item_full_log.into_ptr());
 
             html_item_delegate_safe(&breaks_table_view.static_upcast::<QObject>().as_ptr(), 0);
 
+            // Data for the reports for mod authors.
+            let game_version = game.game_version_number(game_path);
+            let report_load_order = load_order.mods()
+                .iter()
+                .chain(load_order.movies().iter())
+                .cloned()
+                .collect::<Vec<_>>();
+
             // Load the data to the table.
-            for script_break in &breaks {
+            let mut signatures = vec![];
+            let mut author_reports = vec![];
+            for script_break in breaks {
                 let row = QListOfQStandardItem::new();
 
                 let item_pack = QStandardItem::new();
+                let item_known = QStandardItem::new();
                 let item_log = QStandardItem::new();
 
                 item_pack.set_text(&QString::from_std_str(
@@ -2640,17 +7685,71 @@ impl AppUI {
                     }
                 ));
 
-                item_log.set_text(&QString::from_std_str(&script_break.full_log));
+                let signature = script_break_signature(&script_break.full_log());
+                if let Some(known_break) = signature.as_ref().and_then(|signature| known_breaks.find(signature)) {
+                    item_known.set_text(&QString::from_std_str(known_break.description()));
+                }
+
+                item_log.set_text(&QString::from_std_str(&script_break.full_log()));
 
                 row.append_q_standard_item(&item_pack.into_ptr().as_mut_raw_ptr());
+                row.append_q_standard_item(&item_known.into_ptr().as_mut_raw_ptr());
                 row.append_q_standard_item(&item_log.into_ptr().as_mut_raw_ptr());
 
                 breaks_table_model.append_row_q_list_of_q_standard_item(row.into_ptr().as_ref().unwrap());
+                signatures.push((signature, script_break.posible_pack().to_owned()));
+
+                let report = mod_break_report(&script_break.full_log(), script_break.posible_pack(), game.display_name(), game_version, &report_load_order, VERSION);
+                let comments_url = game_config.mods()
+                    .get(script_break.posible_pack())
+                    .and_then(|modd| modd.steam_id().as_ref())
+                    .map(|steam_id| workshop_comments_url(steam_id));
+                author_reports.push((report, comments_url));
             }
 
             //breaks_table_view.resize_columns_to_contents();
             breaks_table_view.resize_rows_to_contents();
 
+            // Reports only contain the anonymized signature and the pack name, so no user data is sent.
+            let breaks_table_filter_ptr = breaks_table_filter.as_ptr();
+            let report_break = SlotNoArgs::new(&dialog, clone!(
+                breaks_table_view,
+                dialog => move || {
+                let indexes = breaks_table_view.selection_model().selected_indexes();
+                if indexes.count_0a() > 0 {
+                    let index = breaks_table_filter_ptr.map_to_source(indexes.at(0));
+                    if let Some((signature, pack_name)) = signatures.get(index.row() as usize) {
+                        match signature {
+                            Some(signature) => { QDesktopServices::open_url(&QUrl::new_1a(&QString::from_std_str(report_script_break_url(signature, pack_name)))); },
+                            None => show_dialog(&dialog, tr("log_analysis_report_break_no_signature"), false),
+                        }
+                    }
+                }
+            }));
+            report_button.released().connect(&report_break);
+
+            // Steam doesn't allow pre-filling comments, so the report goes to the clipboard and the user just has to paste it.
+            let report_author = SlotNoArgs::new(&dialog, clone!(
+                breaks_table_view,
+                dialog => move || {
+                let indexes = breaks_table_view.selection_model().selected_indexes();
+                if indexes.count_0a() > 0 {
+                    let index = breaks_table_filter_ptr.map_to_source(indexes.at(0));
+                    if let Some((report, comments_url)) = author_reports.get(index.row() as usize) {
+                        QGuiApplication::clipboard().set_text_1a(&QString::from_std_str(report));
+
+                        match comments_url {
+                            Some(comments_url) => {
+                                QDesktopServices::open_url(&QUrl::new_1a(&QString::from_std_str(comments_url)));
+                                show_dialog(&dialog, tr("log_analysis_report_author_copied_workshop"), true);
+                            },
+                            None => show_dialog(&dialog, tr("log_analysis_report_author_copied"), true),
+                        }
+                    }
+                }
+            }));
+            report_author_button.released().connect(&report_author);
+
             dialog.set_modal(true);
             dialog.exec();
         }
@@ -2658,9 +7757,503 @@ impl AppUI {
         Ok(())
     }
 
+    /// This function enables the entries of the folders menu pointing to folders the selected game can use, and disables the rest.
+    ///
+    /// Folders are not created here, so opening the menu doesn't leave empty folders around.
+    pub unsafe fn update_folders_menu(&self) {
+        let game = self.game_selected().read().unwrap();
+        let quirks = game_quirks(&game);
+        let has_secondary = *quirks.supports_working_directories() && !setting_string("secondary_mods_path").is_empty();
+
+        self.actions_ui().open_game_secondary_folder().set_enabled(has_secondary);
+        self.actions_ui().open_game_masks_folder().set_enabled(has_secondary && *quirks.supports_masks());
+        self.actions_ui().open_game_temp_packs_folder().set_enabled(game.key() != KEY_ARENA);
+    }
+
+    /// This function opens the folder where the packs generated at launch for the selected game are stored, creating it if needed.
+    pub unsafe fn open_temp_packs_folder(&self) -> Result<()> {
+        let path = temp_packs_folder(&self.game_selected().read().unwrap())?;
+        DirBuilder::new().recursive(true).create(&path)?;
+        open::that(path)?;
+        Ok(())
+    }
+
+    /// This function opens the masks folder of the selected game, creating it if needed.
+    pub unsafe fn open_masks_folder(&self) -> Result<()> {
+        let path = masks_path(&self.game_selected().read().unwrap())?;
+        DirBuilder::new().recursive(true).create(&path)?;
+        open::that(path)?;
+        Ok(())
+    }
+
+    /// This function opens a dialog with Runcher's log, which gets updated as new lines are written to it.
+    pub unsafe fn open_log_viewer(&self) -> Result<()> {
+        let template_path = if cfg!(debug_assertions) { LOG_VIEWER_VIEW_DEBUG } else { LOG_VIEWER_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+
+        let filter_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "filter_line_edit")?;
+        let level_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "level_combobox")?;
+        let log_text_edit: QPtr<QPlainTextEdit> = find_widget(&main_widget.static_upcast(), "log_text_edit")?;
+        let bundle_button: QPtr<QPushButton> = find_widget(&main_widget.static_upcast(), "bundle_button")?;
+        dialog.set_window_title(&qtr("log_viewer_title"));
+        filter_line_edit.set_placeholder_text(&qtr("log_viewer_filter"));
+        bundle_button.set_text(&qtr("copy_diagnostic_bundle"));
+        bundle_button.set_tool_tip(&qtr("copy_diagnostic_bundle_tt"));
+
+        // Off makes no sense here, so we skip it.
+        for level in &LOG_LEVELS[1..] {
+            level_combobox.add_item_q_string(&QString::from_std_str(level));
+        }
+        level_combobox.set_current_index(level_combobox.count() - 1);
+
+        // We keep the full log in memory, so changing the filters doesn't require reading the file again.
+        let log_path = log_file_path()?;
+        let log = Rc::new(RefCell::new(String::new()));
+        let log_position = Rc::new(RefCell::new(0u64));
+
+        let refresh = Rc::new(clone!(
+            log,
+            log_position,
+            log_text_edit,
+            filter_line_edit,
+            level_combobox => move || {
+            if let Ok(mut file) = File::open(&log_path) {
+
+                // If the file got smaller, it's a new one. Start again.
+                let len = file.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+                if len < *log_position.borrow() {
+                    *log_position.borrow_mut() = 0;
+                    log.borrow_mut().clear();
+                    log_text_edit.clear();
+                }
+
+                let mut data = vec![];
+                if file.seek(SeekFrom::Start(*log_position.borrow())).is_ok() && file.read_to_end(&mut data).is_ok() {
+
+                    // Only read full lines, so we don't filter half-written ones.
+                    if let Some(end) = data.iter().rposition(|byte| *byte == b'\n') {
+                        let new_lines = String::from_utf8_lossy(&data[..=end]).to_string();
+                        *log_position.borrow_mut() += end as u64 + 1;
+
+                        let filtered = filter_log(&new_lines, &filter_line_edit.text().to_std_string(), &level_combobox.current_text().to_std_string());
+                        if !filtered.is_empty() {
+                            log_text_edit.append_plain_text(&QString::from_std_str(filtered));
+                        }
+
+                        log.borrow_mut().push_str(&new_lines);
+                    }
+                }
+            }
+        }));
+
+        let refresh_slot = SlotNoArgs::new(&dialog, clone!(
+            refresh => move || {
+            refresh();
+        }));
+
+        let filter = SlotNoArgs::new(&dialog, clone!(
+            log,
+            log_text_edit,
+            filter_line_edit,
+            level_combobox => move || {
+            let filtered = filter_log(&log.borrow(), &filter_line_edit.text().to_std_string(), &level_combobox.current_text().to_std_string());
+            log_text_edit.set_plain_text(&QString::from_std_str(filtered));
+            log_text_edit.vertical_scroll_bar().set_value(log_text_edit.vertical_scroll_bar().maximum());
+        }));
+
+        // The bundle is copied as a file, so it can be pasted directly in the bug report.
+        let copy_bundle = SlotNoArgs::new(&dialog, clone!(
+            dialog => move || {
+            match diagnostic_bundle() {
+                Ok(path) => {
+                    let path = path.to_string_lossy().to_string();
+                    let urls = QListOfQUrl::new();
+                    urls.append_q_url(&QUrl::from_local_file(&QString::from_std_str(&path)));
+
+                    let mime_data = QMimeData::new();
+                    mime_data.set_urls(&urls);
+                    mime_data.set_text(&QString::from_std_str(&path));
+                    QGuiApplication::clipboard().set_mime_data_1a(mime_data.into_ptr());
+
+                    show_dialog(&dialog, tre("copy_diagnostic_bundle_success", &[&path]), true);
+                }
+                Err(error) => show_dialog(&dialog, error, false),
+            }
+        }));
+
+        filter_line_edit.text_changed().connect(&filter);
+        level_combobox.current_index_changed().connect(&filter);
+        bundle_button.released().connect(&copy_bundle);
+
+        let timer = QTimer::new_1a(&dialog);
+        timer.set_interval(LOG_VIEWER_REFRESH_INTERVAL);
+        timer.timeout().connect(&refresh_slot);
+        timer.start_0a();
+        refresh();
+
+        dialog.set_modal(true);
+        dialog.exec();
+
+        Ok(())
+    }
+
+    /// This function opens a diagnostic bundle exported by another user, and shows its mod list, conflicts and logs.
+    ///
+    /// This is read-only: the configs of the game selected are not touched. Conflicts and the mods behind script errors are checked
+    /// against the mods installed here, as the bundle doesn't include the packs.
+    pub unsafe fn inspect_diagnostic_bundle(&self) -> Result<()> {
+        let file_dialog = QFileDialog::from_q_widget_q_string(&self.main_window, &qtr("inspect_diagnostic_bundle_select_file"));
+        file_dialog.set_file_mode(FileMode::ExistingFile);
+        file_dialog.set_name_filter(&QString::from_std_str("Runcher Diagnostic Bundle (*.zip)"));
+
+        if file_dialog.exec() != DialogCode::Accepted.to_int() {
+            return Ok(());
+        }
+
+        let bundle_path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+        let bundle = DiagnosticBundle::read(&bundle_path)?;
+
+        let game_key = bundle.game_selected()
+            .or_else(|| bundle.game_configs().keys().next().map(|game_key| game_key.as_str()))
+            .ok_or_else(|| anyhow!(tr("inspect_diagnostic_bundle_no_game")))?;
+        let game = SUPPORTED_GAMES.game(game_key).ok_or_else(|| anyhow!("Game {} not supported.", game_key))?;
+        let game_config = &bundle.game_configs()[game_key];
+
+        // Use the mods installed here to check the contents of the mods in the bundle.
+        let local_config = if self.game_selected().read().unwrap().key() == game_key {
+            self.game_config().read().unwrap().clone()
+        } else {
+            GameConfig::load(game, false).ok()
+        };
+
+        // The packs of the mods are read in the background, as this takes a while with big load orders.
+        let mods_count = bundle.load_orders().get(game_key).map(|load_order| load_order.mods().len()).unwrap_or_default();
+        let progress_dialog = QProgressDialog::from_q_string2_int2_q_widget(&qtr("inspect_diagnostic_bundle_progress"), &QString::new(), 0, mods_count as i32, self.main_window());
+        progress_dialog.set_window_title(&qtr("inspect_diagnostic_bundle"));
+        progress_dialog.set_window_modality(WindowModality::WindowModal);
+        progress_dialog.set_minimum_duration(0);
+        progress_dialog.show();
+
+        let receiver = CENTRAL_COMMAND.send_background(Command::GetDiagnosticBundleLoadOrder(Box::new(bundle.clone()), game_key.to_owned(), local_config.clone()));
+        let (load_order, missing) = loop {
+            let response = CENTRAL_COMMAND.recv_try(&receiver);
+            match response {
+                Response::Usize(checked) => progress_dialog.set_value(checked as i32),
+                Response::LoadOrderVecString(load_order, missing) => break (load_order, missing),
+                _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+            }
+        };
+
+        progress_dialog.close();
+        progress_dialog.delete_later();
+
+        let log_patterns = LogPatterns::load();
+        let log_errors = bundle.log_errors(log_patterns.game(game), &load_order);
+        let known_breaks = KnownScriptBreaks::load();
+
+        let mod_name = |mod_id: &str| -> String {
+            match game_config.mods().get(mod_id) {
+                Some(modd) if !modd.name().is_empty() && modd.name() != mod_id => format!("{} ({})", modd.name(), mod_id),
+                _ => mod_id.to_owned(),
+            }
+        };
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { DIAGNOSTIC_BUNDLE_VIEW_DEBUG } else { DIAGNOSTIC_BUNDLE_VIEW_RELEASE };
+        let main_widget = load_template(self.main_window(), template_path)?;
+        let dialog = main_widget.static_downcast::<QDialog>();
+        dialog.set_window_title(&qtre("inspect_diagnostic_bundle_title", &[&bundle_path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default()]));
+
+        let info_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "info_label")?;
+        let tab_widget: QPtr<QTabWidget> = find_widget(&main_widget.static_upcast(), "tab_widget")?;
+        let mods_table_view: QPtr<QTableView> = find_widget(&main_widget.static_upcast(), "mods_table_view")?;
+        let conflicts_table_view: QPtr<QTableView> = find_widget(&main_widget.static_upcast(), "conflicts_table_view")?;
+        let log_errors_table_view: QPtr<QTableView> = find_widget(&main_widget.static_upcast(), "log_errors_table_view")?;
+        let runcher_log_text_edit: QPtr<QPlainTextEdit> = find_widget(&main_widget.static_upcast(), "runcher_log_text_edit")?;
+        tab_widget.set_tab_text(0, &qtr("inspect_diagnostic_bundle_mods"));
+        tab_widget.set_tab_text(1, &qtr("inspect_diagnostic_bundle_conflicts"));
+        tab_widget.set_tab_text(2, &qtr("inspect_diagnostic_bundle_log_errors"));
+        tab_widget.set_tab_text(3, &qtr("inspect_diagnostic_bundle_runcher_log"));
+
+        let mut info = tre("inspect_diagnostic_bundle_info", &[game.display_name(), &load_order.mods().len().to_string()]);
+        if !missing.is_empty() {
+            let missing = missing.iter().map(|mod_id| format!("<li>{}</li>", mod_name(mod_id))).join("");
+            info.push_str(&tre("inspect_diagnostic_bundle_missing", &[&missing]));
+        }
+        info_label.set_text(&QString::from_std_str(info));
+
+        // Mod list. Enabled mods first, in load order, then the rest.
+        let mods_model = QStandardItemModel::new_1a(&dialog);
+        mods_table_view.set_model(&mods_model);
+        mods_model.set_column_count(5);
+        mods_model.set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("inspect_diagnostic_bundle_column_order")).into_ptr());
+        mods_model.set_horizontal_header_item(1, QStandardItem::from_q_string(&qtr("inspect_diagnostic_bundle_column_mod")).into_ptr());
+        mods_model.set_horizontal_header_item(2, QStandardItem::from_q_string(&qtr("inspect_diagnostic_bundle_column_category")).into_ptr());
+        mods_model.set_horizontal_header_item(3, QStandardItem::from_q_string(&qtr("inspect_diagnostic_bundle_column_installed_here")).into_ptr());
+        mods_model.set_horizontal_header_item(4, QStandardItem::from_q_string(&qtr("inspect_diagnostic_bundle_column_paths")).into_ptr());
+
+        let mut mods = game_config.mods().values()
+            .filter(|modd| !modd.paths().is_empty())
+            .map(|modd| (load_order.mods().iter().position(|mod_id| mod_id == modd.id()), modd))
+            .collect::<Vec<_>>();
+        mods.sort_by(|(position_a, mod_a), (position_b, mod_b)| match (position_a, position_b) {
+            (Some(position_a), Some(position_b)) => position_a.cmp(position_b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => mod_a.id().cmp(mod_b.id()),
+        });
+
+        for (position, modd) in &mods {
+            let row = QListOfQStandardItem::new();
+            let item_order = QStandardItem::new();
+            if let Some(position) = position {
+                item_order.set_data_2a(&QVariant::from_int(*position as i32 + 1), 2);
+            }
+
+            let installed_here = local_config.as_ref().is_some_and(|local_config| local_config.mods().get(modd.id()).is_some_and(|local| !local.paths().is_empty()));
+            let paths = modd.paths().iter().map(|path| path.to_string_lossy().to_string()).join("\n");
+
+            row.append_q_standard_item(&item_order.into_ptr().as_mut_raw_ptr());
+            row.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(mod_name(modd.id()))).into_ptr().as_mut_raw_ptr());
+            row.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(game_config.category_for_mod(modd.id()))).into_ptr().as_mut_raw_ptr());
+            row.append_q_standard_item(&QStandardItem::from_q_string(&qtr(if installed_here { "inspect_diagnostic_bundle_yes" } else { "inspect_diagnostic_bundle_no" })).into_ptr().as_mut_raw_ptr());
+            row.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(paths)).into_ptr().as_mut_raw_ptr());
+            mods_model.append_row_q_list_of_q_standard_item(row.into_ptr().as_ref().unwrap());
+        }
+
+        mods_table_view.resize_columns_to_contents();
+
+        // Conflicts between the enabled mods, with the files as tooltip, as they can be a lot.
+        let conflicts_model = QStandardItemModel::new_1a(&dialog);
+        conflicts_table_view.set_model(&conflicts_model);
+        conflicts_model.set_column_count(3);
+        conflicts_model.set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("inspect_diagnostic_bundle_column_winner")).into_ptr());
+        conflicts_model.set_horizontal_header_item(1, QStandardItem::from_q_string(&qtr("inspect_diagnostic_bundle_column_loser")).into_ptr());
+        conflicts_model.set_horizontal_header_item(2, QStandardItem::from_q_string(&qtr("inspect_diagnostic_bundle_column_files")).into_ptr());
+
+        for conflict in pack_conflicts(&load_order) {
+            let row = QListOfQStandardItem::new();
+            let item_files = QStandardItem::new();
+            item_files.set_data_2a(&QVariant::from_int(conflict.files().len() as i32), 2);
+            item_files.set_tool_tip(&QString::from_std_str(conflict.files().join("\n")));
+
+            row.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(mod_name(conflict.winner()))).into_ptr().as_mut_raw_ptr());
+            row.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(mod_name(conflict.loser()))).into_ptr().as_mut_raw_ptr());
+            row.append_q_standard_item(&item_files.into_ptr().as_mut_raw_ptr());
+            conflicts_model.append_row_q_list_of_q_standard_item(row.into_ptr().as_ref().unwrap());
+        }
+
+        conflicts_table_view.resize_columns_to_contents();
+
+        // Script errors in the logs of the game.
+        let log_errors_model = QStandardItemModel::new_1a(&dialog);
+        log_errors_table_view.set_model(&log_errors_model);
+        log_errors_model.set_column_count(4);
+        log_errors_model.set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("inspect_diagnostic_bundle_column_log")).into_ptr());
+        log_errors_model.set_horizontal_header_item(1, QStandardItem::from_q_string(&qtr("posible_pack")).into_ptr());
+        log_errors_model.set_horizontal_header_item(2, QStandardItem::from_q_string(&qtr("known_issue")).into_ptr());
+        log_errors_model.set_horizontal_header_item(3, QStandardItem::from_q_string(&qtr("full_log")).into_ptr());
+
+        for log_error in &log_errors {
+            let row = QListOfQStandardItem::new();
+            let posible_pack = log_error.posible_pack().as_ref().map(|mod_id| mod_name(mod_id)).unwrap_or_default();
+            let known_issue = script_break_signature(log_error.error().message())
+                .and_then(|signature| known_breaks.find(&signature).map(|known_break| known_break.description()))
+                .unwrap_or_default();
+
+            row.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(log_error.log_name())).into_ptr().as_mut_raw_ptr());
+            row.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(posible_pack)).into_ptr().as_mut_raw_ptr());
+            row.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(known_issue)).into_ptr().as_mut_raw_ptr());
+            row.append_q_standard_item(&QStandardItem::from_q_string(&QString::from_std_str(log_error.error().message())).into_ptr().as_mut_raw_ptr());
+            log_errors_model.append_row_q_list_of_q_standard_item(row.into_ptr().as_ref().unwrap());
+        }
+
+        log_errors_table_view.resize_columns_to_contents();
+        log_errors_table_view.resize_rows_to_contents();
+
+        // Runcher's logs, only with the warnings and errors, as the rest is rarely useful for finding problems.
+        let runcher_log = bundle.runcher_logs().iter()
+            .map(|(log_name, log)| format!("==== {} ====\n{}", log_name, filter_log(log, "", "warn")))
+            .join("\n\n");
+        runcher_log_text_edit.set_plain_text(&QString::from_std_str(runcher_log));
+
+        dialog.set_modal(true);
+        dialog.exec();
+
+        Ok(())
+    }
+
+    /// This function reveals in the Mod List the mod of the pack selected in the Pack List.
+    pub unsafe fn reveal_pack_in_mod_list(&self) -> Result<()> {
+        let mod_id = self.pack_list_selection()
+            .iter()
+            .find(|index| !index.data_1a(VALUE_IS_VANILLA).to_bool())
+            .map(|index| index.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+            .unwrap_or_default();
+
+        self.reveal_in_mod_list(&mod_id)
+    }
+
+    /// This function exports the Shogun 2 battle map in the selected mod's pack back into a map bin, so it can be uploaded with the legacy uploader.
+    pub unsafe fn export_map_bin(&self) -> Result<()> {
+        let mod_id = self.mod_list_selection()
+            .first()
+            .map(|index| index.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+            .unwrap_or_default();
+
+        let pack_path = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => game_config.mods()
+                .get(&mod_id)
+                .and_then(|modd| modd.paths().first().cloned())
+                .ok_or_else(|| anyhow!(tre("export_map_bin_pack_not_found", &[&mod_id])))?,
+            None => return Ok(()),
+        };
+
+        // If the pack has more than one map, let the user pick the one to export.
+        let map_names = map_names(&pack_path)?;
+        let map_name = match map_names.len() {
+            0 => return Err(anyhow!(tre("export_map_bin_no_maps", &[&mod_id]))),
+            1 => map_names[0].to_owned(),
+            _ => {
+                let items = QStringList::new();
+                for map_name in &map_names {
+                    items.append_q_string(&QString::from_std_str(map_name));
+                }
+
+                let dialog = QInputDialog::new_1a(self.main_window());
+                dialog.set_window_title(&qtr("export_map_bin"));
+                dialog.set_label_text(&qtr("export_map_bin_select_map"));
+                dialog.set_input_mode(InputMode::TextInput);
+                dialog.set_combo_box_items(&items);
+                dialog.set_combo_box_editable(false);
+
+                if dialog.exec() != DialogCode::Accepted.to_int() {
+                    return Ok(());
+                }
+
+                dialog.text_value().to_std_string()
+            }
+        };
+
+        let file_dialog = QFileDialog::from_q_widget_q_string(&self.main_window, &qtr("export_map_bin_select_file"));
+        file_dialog.set_accept_mode(AcceptMode::AcceptSave);
+        file_dialog.set_name_filter(&QString::from_std_str("Shogun 2 Map (*.bin)"));
+        file_dialog.set_default_suffix(&QString::from_std_str("bin"));
+        file_dialog.select_file(&QString::from_std_str(format!("{map_name}.bin")));
+
+        if file_dialog.exec() == DialogCode::Accepted.to_int() {
+            let output_path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+
+            self.toggle_main_window(false);
+            let receiver = CENTRAL_COMMAND.send_background(Command::ExportMapBin(pack_path, map_name, output_path.to_path_buf()));
+            let response = CENTRAL_COMMAND.recv_try(&receiver);
+            self.toggle_main_window(true);
+
+            match response {
+                Response::Success => show_dialog(self.main_window(), tre("export_map_bin_success", &[&output_path.to_string_lossy()]), true),
+                Response::Error(error) => return Err(error),
+                _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This function extracts the files of the pack of the mod selected in the Mod List, or in the Pack List, to a folder chosen by the user.
+    ///
+    /// The user can provide a filter, so only the scripts or tables they're interested in are extracted.
+    pub unsafe fn extract_selected_pack(&self, from_pack_list: bool) -> Result<()> {
+        let selection = if from_pack_list {
+            self.pack_list_selection()
+        } else {
+            self.mod_list_selection()
+        };
+
+        let mod_id = selection.first()
+            .map(|index| index.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+            .unwrap_or_default();
+
+        let pack_path = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => game_config.mods()
+                .get(&mod_id)
+                .and_then(|modd| modd.paths().first().cloned())
+                .ok_or_else(|| anyhow!(tre("extract_pack_not_found", &[&mod_id])))?,
+            None => return Ok(()),
+        };
+
+        let filter_dialog = QInputDialog::new_1a(self.main_window());
+        filter_dialog.set_window_title(&qtr("extract_pack"));
+        filter_dialog.set_label_text(&qtr("extract_pack_filter"));
+        filter_dialog.set_input_mode(InputMode::TextInput);
+
+        if filter_dialog.exec() != 1 {
+            return Ok(());
+        }
+
+        let filter = filter_dialog.text_value().to_std_string();
+
+        let file_dialog = QFileDialog::from_q_widget_q_string(&self.main_window, &qtr("extract_pack_select_folder"));
+        file_dialog.set_file_mode(FileMode::Directory);
+        file_dialog.set_options(QFlags::from(QFileDialogOption::ShowDirsOnly));
+
+        if file_dialog.exec() == 1 {
+            let folder = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            let extracted = extract_pack(&pack_path, &folder, &filter)?;
+            if extracted == 0 {
+                return Err(anyhow!(tre("extract_pack_no_files", &[&filter])));
+            }
+
+            show_dialog(self.main_window(), tre("extract_pack_success", &[&extracted.to_string(), &folder.to_string_lossy()]), true);
+        }
+
+        Ok(())
+    }
+
+    /// This function reveals in the Mod List the mod owning the file selected in the Data List.
+    pub unsafe fn reveal_data_file_in_mod_list(&self) -> Result<()> {
+        let pack_name = self.data_list_ui().selected_pack_name().unwrap_or_default();
+        let mod_id = match *self.game_config().read().unwrap() {
+            Some(ref game_config) => game_config.mods()
+                .values()
+                .find(|modd| modd.paths().first().map(|path| path.ends_with(&pack_name)).unwrap_or(false))
+                .map(|modd| modd.id().to_owned()),
+            None => None,
+        };
+
+        match mod_id {
+            Some(mod_id) => self.reveal_in_mod_list(&mod_id),
+            None => Err(anyhow!(tre("reveal_pack_not_from_mod", &[&pack_name]))),
+        }
+    }
+
+    /// This function selects the provided mod in the Mod List, expanding its category and scrolling to it.
+    pub unsafe fn reveal_in_mod_list(&self, mod_id: &str) -> Result<()> {
+        if self.mod_list_ui().reveal_mod(mod_id) {
+            Ok(())
+        } else {
+            Err(anyhow!(tre("reveal_mod_not_found", &[mod_id])))
+        }
+    }
+
+    /// This function reveals in the Pack List the pack of the mod selected in the Mod List, switching to its tab.
+    pub unsafe fn reveal_mod_in_pack_list(&self) -> Result<()> {
+        let mod_id = self.mod_list_selection()
+            .first()
+            .map(|index| index.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+            .unwrap_or_default();
+
+        self.right_tabbar().set_current_widget(&self.pack_list_ui().tree_view().parent_widget());
+        if self.pack_list_ui().reveal_pack(&mod_id) {
+            Ok(())
+        } else {
+            Err(anyhow!(tre("reveal_pack_not_loaded", &[&mod_id])))
+        }
+    }
+
     pub unsafe fn open_data_file_with_rpfm(&self) -> Result<()> {
         let tools = self.tools().read().unwrap();
-        if let Some(tool) = tools.tools().iter().find(|tool| tool.path().ends_with("rpfm_ui.exe")) {
+        if let Some(tool) = tools.tools().iter().find(|tool| tool.path().ends_with(RPFM_EXECUTABLE)) {
             if let Some(ref game_config) = *self.game_config().read().unwrap() {
 
                 let game = self.game_selected().read().unwrap();
@@ -2678,20 +8271,10 @@ impl AppUI {
                         }
 
                         if selection.column() == 1 {
-
-                            // About the packs, we search them by path in the
                             let pack = selection.data_0a().to_string().to_std_string();
-                            if let Some(ca_pack) = ca_packs.iter().find(|ca_path| ca_path.file_name().unwrap().to_string_lossy() == pack) {
-                                if !packs.contains(ca_pack) {
-                                    packs.push(ca_pack.to_path_buf());
-                                }
-                            } else if let Some((_, modd)) = game_config.mods().iter()
-                                .filter(|(_, modd)| !modd.paths().is_empty())
-                                .find(|(_, modd)| modd.paths().first().unwrap().ends_with(&pack)) {
-
-                                let path = modd.paths().first().unwrap();
-                                if !packs.contains(path) {
-                                    packs.push(path.to_path_buf());
+                            if let Some(path) = data_pack_path(game_config, &ca_packs, &pack) {
+                                if !packs.contains(&path) {
+                                    packs.push(path);
                                 }
                             }
                         }
@@ -2713,4 +8296,61 @@ impl AppUI {
 
         Ok(())
     }
+
+    /// This function opens the DB table selected in the Data List with RPFM, along with the pack it comes from.
+    pub unsafe fn open_data_table_with_rpfm(&self) -> Result<()> {
+        let tool_path = self.tools().read().unwrap().tools().iter()
+            .find(|tool| tool.path().ends_with(RPFM_EXECUTABLE))
+            .map(|tool| tool.path().to_path_buf())
+            .ok_or_else(|| anyhow!(tr("rpfm_not_found")))?;
+
+        let (file_path, pack_name) = match (self.data_list_ui().selected_file_path(), self.data_list_ui().selected_pack_name()) {
+            (Some(file_path), Some(pack_name)) if self.data_list_ui().is_table_selected() => (file_path, pack_name),
+            _ => return Err(anyhow!(tr("open_table_no_table"))),
+        };
+
+        let game = self.game_selected().read().unwrap().clone();
+        let game_path = setting_path(game.key());
+        let ca_packs = game.ca_packs_paths(&game_path)?;
+        let pack_path = self.game_config().read().unwrap()
+            .as_ref()
+            .and_then(|game_config| data_pack_path(game_config, &ca_packs, &pack_name))
+            .ok_or_else(|| anyhow!(tre("open_table_pack_not_found", &[&pack_name])))?;
+
+        std::process::Command::new(tool_path)
+            .arg(pack_path)
+            .arg(&file_path)
+            .spawn()?;
+
+        Ok(())
+    }
+}
+
+/// This function returns the path on disk of the pack with the provided name, as shown in the Data List.
+fn data_pack_path(game_config: &GameConfig, ca_packs: &[PathBuf], pack_name: &str) -> Option<PathBuf> {
+    if let Some(ca_pack) = ca_packs.iter().find(|ca_path| ca_path.file_name().is_some_and(|name| name.to_string_lossy() == pack_name)) {
+        return Some(ca_pack.to_path_buf());
+    }
+
+    game_config.mods()
+        .values()
+        .filter_map(|modd| modd.paths().first())
+        .find(|path| path.ends_with(pack_name))
+        .cloned()
+}
+
+/// This function returns the widths of the columns of the provided view, in order.
+unsafe fn column_widths(tree_view: &QPtr<QTreeView>) -> Vec<i32> {
+    (0..tree_view.header().count())
+        .map(|column| tree_view.column_width(column))
+        .collect()
+}
+
+/// This function sets the widths of the columns of the provided view. Hidden columns, which have no width, are left as they are.
+unsafe fn set_column_widths(tree_view: &QPtr<QTreeView>, widths: &[i32]) {
+    for (column, width) in widths.iter().enumerate() {
+        if *width > 0 {
+            tree_view.set_column_width(column as i32, *width);
+        }
+    }
 }
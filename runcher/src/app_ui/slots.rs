@@ -9,11 +9,16 @@
 //---------------------------------------------------------------------------//
 
 use qt_widgets::QMessageBox;
+use qt_widgets::SlotOfQAction;
+use qt_widgets::SlotOfQPoint;
+use qt_widgets::{q_system_tray_icon::ActivationReason, SlotOfActivationReason};
 
+use qt_gui::QCursor;
 use qt_gui::QDesktopServices;
 use qt_gui::SlotOfQStandardItem;
 
 use qt_core::QBox;
+use qt_core::QCoreApplication;
 use qt_core::QEventLoop;
 use qt_core::QUrl;
 use qt_core::SlotNoArgs;
@@ -21,6 +26,7 @@ use qt_core::SlotOfBool;
 use qt_core::SlotOfDouble;
 use qt_core::SlotOfQModelIndexInt;
 use qt_core::SlotOfQString;
+use qt_core::SlotOfQStringList;
 
 use std::rc::Rc;
 
@@ -28,10 +34,12 @@ use rpfm_ui_common::clone;
 
 use crate::DISCORD_URL;
 use crate::GITHUB_URL;
+use crate::locale_loader::user_locale_path;
 use crate::mod_list_ui::VALUE_MOD_ID;
 use crate::mod_manager::secondary_mods_path;
 use crate::PATREON_URL;
 use crate::profiles_ui::ProfilesUI;
+use crate::themes::{refresh_system_theme, system_theme_changed};
 use crate::VERSION;
 use crate::VERSION_SUBTITLE;
 
@@ -45,6 +53,16 @@ use super::*;
 #[getset(get = "pub")]
 pub struct AppUISlots {
     launch_game: QBox<SlotNoArgs>,
+    restore_from_tray: QBox<SlotNoArgs>,
+    tray_activated: QBox<SlotOfActivationReason>,
+    quit_from_tray: QBox<SlotNoArgs>,
+    launch_from_tray: QBox<SlotOfQAction>,
+    check_workshop_changes: QBox<SlotNoArgs>,
+    receive_workshop_changes: QBox<SlotNoArgs>,
+    handle_api_requests: QBox<SlotNoArgs>,
+    check_offline_mode: QBox<SlotNoArgs>,
+    receive_offline_mode: QBox<SlotNoArgs>,
+    check_system_theme: QBox<SlotNoArgs>,
     toggle_logging: QBox<SlotOfBool>,
     toggle_skip_intros: QBox<SlotOfBool>,
     toggle_remove_trait_limit: QBox<SlotOfBool>,
@@ -57,11 +75,18 @@ pub struct AppUISlots {
     open_game_root_folder: QBox<SlotNoArgs>,
     open_game_data_folder: QBox<SlotNoArgs>,
     open_game_content_folder: QBox<SlotNoArgs>,
+    update_folders_menu: QBox<SlotNoArgs>,
     open_game_secondary_folder: QBox<SlotNoArgs>,
+    open_game_masks_folder: QBox<SlotNoArgs>,
+    open_game_temp_packs_folder: QBox<SlotNoArgs>,
     open_game_config_folder: QBox<SlotNoArgs>,
     open_runcher_config_folder: QBox<SlotNoArgs>,
     open_runcher_error_folder: QBox<SlotNoArgs>,
+    open_runcher_locale_folder: QBox<SlotNoArgs>,
+    open_runcher_log_viewer: QBox<SlotNoArgs>,
+    inspect_diagnostic_bundle: QBox<SlotNoArgs>,
     change_game_selected: QBox<SlotNoArgs>,
+    game_selected_context_menu: QBox<SlotOfQPoint>,
 
     update_pack_list: QBox<SlotOfQStandardItem>,
 
@@ -75,29 +100,115 @@ pub struct AppUISlots {
     copy_load_order: QBox<SlotNoArgs>,
     paste_load_order: QBox<SlotNoArgs>,
     reload: QBox<SlotNoArgs>,
+    refresh_workshop_data: QBox<SlotNoArgs>,
+    rescan_data_folder: QBox<SlotNoArgs>,
+    rescan_secondary_folder: QBox<SlotNoArgs>,
     download_subscribed_mods: QBox<SlotNoArgs>,
     load_profile: QBox<SlotNoArgs>,
     save_profile: QBox<SlotNoArgs>,
+    save_profile_from_save: QBox<SlotNoArgs>,
+    toggle_profile_lock: QBox<SlotOfBool>,
     open_profile_manager: QBox<SlotNoArgs>,
+    load_load_order_slot: QBox<SlotNoArgs>,
+    save_load_order_slot: QBox<SlotNoArgs>,
+    delete_load_order_slot: QBox<SlotNoArgs>,
 
     enable_selected: QBox<SlotNoArgs>,
     disable_selected: QBox<SlotNoArgs>,
+    ignore_mods: QBox<SlotNoArgs>,
     upload_to_workshop: QBox<SlotNoArgs>,
     download_from_workshop: QBox<SlotNoArgs>,
+    search_workshop: QBox<SlotNoArgs>,
+    force_redownload: QBox<SlotNoArgs>,
+    update_workshop_items_now: QBox<SlotNoArgs>,
+    defer_workshop_updates: QBox<SlotNoArgs>,
+    add_from_github: QBox<SlotNoArgs>,
+    update_from_github: QBox<SlotNoArgs>,
     category_create: QBox<SlotNoArgs>,
     category_delete: QBox<SlotNoArgs>,
     category_rename: QBox<SlotNoArgs>,
     category_move: QBox<SlotOfQModelIndexInt>,
     category_sort: QBox<SlotNoArgs>,
+    category_style: QBox<SlotNoArgs>,
+    category_rules: QBox<SlotNoArgs>,
+    change_mod_list_grouping: QBox<SlotNoArgs>,
+    toggle_show_ignored_mods: QBox<SlotNoArgs>,
     mod_list_context_menu_open: QBox<SlotNoArgs>,
     copy_to_secondary: QBox<SlotNoArgs>,
+    refresh_secondary_copy: QBox<SlotNoArgs>,
+    find_shadowed_packs: QBox<SlotNoArgs>,
+    find_duplicate_mods: QBox<SlotNoArgs>,
+    add_known_issue: QBox<SlotNoArgs>,
+    rebuild_masks: QBox<SlotNoArgs>,
     move_to_secondary: QBox<SlotNoArgs>,
+    treat_as_mod_pack: QBox<SlotNoArgs>,
+    export_map_bin: QBox<SlotNoArgs>,
+    extract_mod_pack: QBox<SlotNoArgs>,
+    rescan_mod: QBox<SlotNoArgs>,
+    set_working_folder: QBox<SlotNoArgs>,
+    use_working_folder: QBox<SlotNoArgs>,
+    backup_selected_mods: QBox<SlotNoArgs>,
+    restore_mods_backup: QBox<SlotNoArgs>,
+    export_mod_report: QBox<SlotNoArgs>,
 
     pack_toggle_auto_sorting: QBox<SlotOfBool>,
+    pack_toggle_show_vanilla_packs: QBox<SlotOfBool>,
     pack_move: QBox<SlotOfQModelIndexInt>,
 
     data_view_reload: QBox<SlotNoArgs>,
     open_file_with_rpfm: QBox<SlotNoArgs>,
+    reveal_data_file_in_mod_list: QBox<SlotNoArgs>,
+    open_table_with_rpfm: QBox<SlotNoArgs>,
+    reveal_pack_in_mod_list: QBox<SlotNoArgs>,
+    extract_pack_list_pack: QBox<SlotNoArgs>,
+    reveal_mod_in_pack_list: QBox<SlotNoArgs>,
+    mod_list_double_clicked: QBox<SlotNoArgs>,
+    install_dropped_packs: QBox<SlotOfQStringList>,
+
+    next_game: QBox<SlotNoArgs>,
+    previous_game: QBox<SlotNoArgs>,
+    toggle_selected_mods: QBox<SlotNoArgs>,
+    focus_filter: QBox<SlotNoArgs>,
+    move_pack_up: QBox<SlotNoArgs>,
+    move_pack_down: QBox<SlotNoArgs>,
+
+    pack_move_to_top: QBox<SlotNoArgs>,
+    pack_move_to_bottom: QBox<SlotNoArgs>,
+    pack_move_to_position: QBox<SlotNoArgs>,
+    pack_import_order: QBox<SlotNoArgs>,
+    pack_export_launch_manifest: QBox<SlotNoArgs>,
+    pack_group_lock: QBox<SlotNoArgs>,
+    pack_conflict_suggestions: QBox<SlotNoArgs>,
+    pack_group_unlock: QBox<SlotNoArgs>,
+
+    config_files_changed: QBox<SlotOfQString>,
+    check_config_files_changes: QBox<SlotNoArgs>,
+    mod_list_files_changed: QBox<SlotOfQString>,
+    check_mod_list_files_changes: QBox<SlotNoArgs>,
+    import_mod_list_file_order: QBox<SlotNoArgs>,
+    overwrite_mod_list_file: QBox<SlotNoArgs>,
+
+    undo_last_operation: QBox<SlotNoArgs>,
+
+    export_to_ca_launcher: QBox<SlotNoArgs>,
+    import_from_ca_launcher: QBox<SlotNoArgs>,
+    open_sessions: QBox<SlotNoArgs>,
+    update_translations: QBox<SlotNoArgs>,
+    translation_coverage: QBox<SlotNoArgs>,
+    export_tweaks_as_pack: QBox<SlotNoArgs>,
+    open_bughunt: QBox<SlotNoArgs>,
+
+    actions_context_menu: QBox<SlotOfQPoint>,
+    customize_actions: QBox<SlotNoArgs>,
+    pin_profile: QBox<SlotNoArgs>,
+    pin_save: QBox<SlotNoArgs>,
+    restore_save_backup: QBox<SlotNoArgs>,
+
+    copy_fingerprint: QBox<SlotNoArgs>,
+    copy_fingerprint_manifest: QBox<SlotNoArgs>,
+    compare_fingerprint_manifests: QBox<SlotNoArgs>,
+    freeze_campaign_integrity: QBox<SlotNoArgs>,
+    verify_campaign_integrity: QBox<SlotNoArgs>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -109,8 +220,91 @@ impl AppUISlots {
 
         let launch_game = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
-                if let Err(error) = view.launch_game() {
-                    show_dialog(view.main_window(), error, false);
+                match view.check_load_order_lint()
+                    .and_then(|launch| if launch { view.check_known_issues() } else { Ok(false) })
+                    .and_then(|launch| if launch { view.check_missing_requirements() } else { Ok(false) })
+                    .and_then(|launch| if launch { view.check_game_update() } else { Ok(false) })
+                    .and_then(|launch| if launch { view.check_campaign_safety() } else { Ok(false) })
+                    .and_then(|launch| if launch { view.check_script_collisions() } else { Ok(false) })
+                    .and_then(|launch| if launch { view.check_shadowed_packs(true) } else { Ok(false) })
+                    .and_then(|launch| if launch { view.check_other_managers() } else { Ok(false) })
+                    .map(|launch| launch && view.launch_countdown()) {
+                    Ok(true) => if let Err(error) = view.launch_game() {
+                        show_dialog(view.main_window(), error, false);
+                    }
+                    Ok(false) => {}
+                    Err(error) => show_dialog(view.main_window(), error, false),
+                }
+            }
+        ));
+
+        let restore_from_tray = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                view.restore_from_tray();
+            }
+        ));
+
+        let tray_activated = SlotOfActivationReason::new(&view.main_window, clone!(
+            view => move |reason| {
+                if reason == ActivationReason::Trigger || reason == ActivationReason::DoubleClick {
+                    view.restore_from_tray();
+                }
+            }
+        ));
+
+        let quit_from_tray = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                view.tray_ui().tray_icon().hide();
+                view.main_window().close();
+
+                // Closing a hidden window doesn't count as closing the last window, so we need to quit manually.
+                QCoreApplication::quit();
+            }
+        ));
+
+        let launch_from_tray = SlotOfQAction::new(&view.main_window, clone!(
+            view => move |action| {
+                let game_key = action.data().to_string().to_std_string();
+                view.launch_from_tray(&game_key);
+            }
+        ));
+
+        let check_workshop_changes = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                view.check_workshop_changes();
+            }
+        ));
+
+        let receive_workshop_changes = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                view.receive_workshop_changes();
+            }
+        ));
+
+        let handle_api_requests = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                view.handle_api_requests();
+            }
+        ));
+
+        let check_offline_mode = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                view.check_offline_mode();
+            }
+        ));
+
+        let receive_offline_mode = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                view.receive_offline_mode();
+            }
+        ));
+
+        // The OS theme is read in the background, so a change is picked up by the check after the one that reads it.
+        let check_system_theme = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                refresh_system_theme();
+                if system_theme_changed() {
+                    view.reload_theme();
                 }
             }
         ));
@@ -158,8 +352,7 @@ impl AppUISlots {
         let change_unit_multiplier = SlotOfDouble::new(view.main_window(), clone!(
             view => move |value| {
                 let game = view.game_selected().read().unwrap();
-                let setting = format!("unit_multiplier_{}", game.key());
-                set_setting_f32(&setting, value as f32);
+                set_unit_multiplier(game.key(), value);
             }
         ));
 
@@ -181,6 +374,11 @@ impl AppUISlots {
             view.actions_ui().folders_button().show_menu();
         }));
 
+        let update_folders_menu = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            view.update_folders_menu();
+        }));
+
         let open_game_root_folder = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
             let game = view.game_selected().read().unwrap();
@@ -222,10 +420,24 @@ impl AppUISlots {
             }
         }));
 
+        let open_game_masks_folder = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            if let Err(error) = view.open_masks_folder() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let open_game_temp_packs_folder = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            if let Err(error) = view.open_temp_packs_folder() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
         let open_game_config_folder = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
             let game = view.game_selected().read().unwrap();
-            if let Some(path) = game.config_path(&setting_path(game.key())) {
+            if let Some(path) = game_appdata_path(&game, &setting_path(game.key())) {
                 let _ = open::that(path);
             } else {
                 show_dialog(view.main_window(), "Runcher cannot open that folder (maybe it doesn't exists/is misconfigured?).", false);
@@ -250,6 +462,29 @@ impl AppUISlots {
             }
         }));
 
+        let open_runcher_locale_folder = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            if let Ok(path) = user_locale_path() {
+                let _ = open::that(path);
+            } else {
+                show_dialog(view.main_window(), "Runcher cannot open that folder (maybe it doesn't exists/is misconfigured?).", false);
+            }
+        }));
+
+        let open_runcher_log_viewer = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            if let Err(error) = view.open_log_viewer() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let inspect_diagnostic_bundle = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+            if let Err(error) = view.inspect_diagnostic_bundle() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
         let change_game_selected = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
                 match view.change_game_selected(true, false) {
@@ -263,6 +498,35 @@ impl AppUISlots {
             }
         ));
 
+        let game_selected_context_menu = SlotOfQPoint::new(&view.main_window, clone!(
+            view => move |point| {
+                let action = view.game_selected_bar().action_at_q_point(point);
+                if !action.is_null() {
+                    let game_key = AppUI::game_key_from_action(&action);
+
+                    // Configs can only be migrated to the game selected, from one of its sibling games.
+                    let is_sibling = sibling_games(view.game_selected().read().unwrap().key()).contains(&game_key.as_str());
+                    view.game_selected_migrate_from_sibling().set_visible(is_sibling);
+                    view.game_selected_import_categories().set_visible(game_key != view.game_selected().read().unwrap().key());
+
+                    let selected = view.game_selected_context_menu().exec_1a_mut(&QCursor::pos_0a());
+                    if !selected.is_null() && selected.as_raw_ptr() == view.game_selected_open_in_new_window().as_raw_ptr() {
+                        if let Err(error) = view.open_game_in_new_window(&game_key) {
+                            show_dialog(view.main_window(), error, false);
+                        }
+                    } else if !selected.is_null() && selected.as_raw_ptr() == view.game_selected_migrate_from_sibling().as_raw_ptr() {
+                        if let Err(error) = view.migrate_from_game(&game_key, false) {
+                            show_dialog(view.main_window(), error, false);
+                        }
+                    } else if !selected.is_null() && selected.as_raw_ptr() == view.game_selected_import_categories().as_raw_ptr() {
+                        if let Err(error) = view.migrate_from_game(&game_key, true) {
+                            show_dialog(view.main_window(), error, false);
+                        }
+                    }
+                }
+            }
+        ));
+
         let update_pack_list = SlotOfQStandardItem::new(&view.main_window, clone!(
             view => move |item| {
             if item.column() == 0 {
@@ -322,12 +586,13 @@ impl AppUISlots {
                         <a href=\"https://github.com/Frodo45127/runcher\">https://github.com/Frodo45127/runcher</a>
                         <p>This program is also <b>free</b> (if you paid for this, sorry, but you got scammed), but if you want to help with money, here is <b>RPFM's Patreon</b>:</p>
                         <a href=\"https://www.patreon.com/RPFM\">https://www.patreon.com/RPFM</a>
+                        <p>{}</p>
 
                         <h3>Credits</h3>
                         <ul style=\"list-style-type: disc\">
                             <li>Created and Programmed by: <b>Frodo45127</b>.</li>
                         </ul>
-                        ", &VERSION, &VERSION_SUBTITLE)
+                        ", &VERSION, &VERSION_SUBTITLE, if sentry_enabled() { tr("about_error_reporting_enabled") } else { tr("about_error_reporting_disabled") })
                     )
                 );
             }
@@ -344,13 +609,20 @@ impl AppUISlots {
         let copy_load_order = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
                 if let Some(ref game_config) = *view.game_config().read().unwrap() {
+
+                    // Mods teammates cannot subscribe to need to be dealt with before generating the string.
+                    let mut load_order = view.game_load_order().read().unwrap().clone();
+                    match view.check_shared_mods_visibility(game_config, &mut load_order) {
+                        Ok(true) => {},
+                        Ok(false) => return,
+                        Err(error) => return show_dialog(view.main_window(), error, false),
+                    }
+
                     view.toggle_main_window(false);
 
                     let game_info = view.game_selected().read().unwrap();
                     let game_path = setting_path(game_info.key());
                     if let Ok(game_data_path) = game_info.data_path(&game_path) {
-
-                        let load_order = view.game_load_order().read().unwrap().clone();
                         let receiver = CENTRAL_COMMAND.send_background(Command::GetStringFromLoadOrder(game_config.clone(), game_data_path, load_order));
                         let response = CENTRAL_COMMAND.recv_try(&receiver);
                         match response {
@@ -372,6 +644,13 @@ impl AppUISlots {
         let paste_load_order = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
                 match view.load_order_string_dialog(None) {
+                    Ok(Some(ImportedLoadOrderMode::Names(text))) => match view.name_matches_dialog(&text) {
+                        Ok(Some(mods)) => if let Err(error) = view.load_order_from_shareable_mod_list(&mods) {
+                            show_dialog(view.main_window(), error, false);
+                        }
+                        Ok(None) => {}
+                        Err(error) => show_dialog(view.main_window(), error, false),
+                    }
                     Ok(mode) => if let Some(mode) = mode {
                         view.toggle_main_window(false);
 
@@ -407,6 +686,30 @@ impl AppUISlots {
             }
         ));
 
+        let refresh_workshop_data = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.refresh_workshop_data() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let rescan_data_folder = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.rescan_mods(RescanScope::Data) {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let rescan_secondary_folder = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.rescan_mods(RescanScope::Secondary) {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
         let download_subscribed_mods = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
                 match view.download_subscribed_mods(&None) {
@@ -439,6 +742,115 @@ impl AppUISlots {
             }
         ));
 
+        let search_workshop = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                match view.search_workshop() {
+                    Ok(Some(count)) => show_dialog(view.main_window(), tre("workshop_search_subscribed", &[&count.to_string()]), true),
+                    Ok(None) => {},
+                    Err(error) => show_dialog(view.main_window(), error, false),
+                }
+            }
+        ));
+
+        let force_redownload = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                match view.force_redownload_selected_mods() {
+                    Ok(Some(failed_mods)) => if failed_mods.is_empty() {
+                        show_dialog(view.main_window(), tr("mods_redownloaded"), true);
+                    } else {
+                        let string = failed_mods.iter().map(|string| format!("<li>{}</li>", string)).join("");
+                        show_dialog(view.main_window(), tre("force_redownload_failed", &[&string]), false);
+                    }
+                    Ok(None) => {},
+                    Err(error) => show_dialog(view.main_window(), error, false),
+                }
+            }
+        ));
+
+        let update_workshop_items_now = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                let mod_ids = view.mod_list_selection()
+                    .iter()
+                    .map(|x| x.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+                    .collect::<Vec<_>>();
+
+                let published_file_ids = if let Some(ref game_config) = *view.game_config().read().unwrap() {
+                    mod_ids.iter()
+                        .filter_map(|x| game_config.mods().get(x))
+                        .filter_map(|x| x.steam_id().clone())
+                        .collect::<Vec<_>>()
+                } else {
+                    vec![]
+                };
+
+                // Reload after downloading so the new Workshop state of the items is shown.
+                match view.download_subscribed_mods(&Some(published_file_ids)) {
+                    Ok(_) => {
+                        view.actions_ui().reload_button().click();
+                        show_dialog(view.main_window(), tr("workshop_items_updated"), true);
+                    }
+                    Err(error) => show_dialog(view.main_window(), error, false),
+                }
+            }
+        ));
+
+        let defer_workshop_updates = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                {
+                    let selection = view.mod_list_selection()
+                        .iter()
+                        .map(|x| x.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+                        .collect::<Vec<_>>();
+
+                    let defer = view.mod_list_ui().defer_workshop_updates().is_checked();
+                    let game = view.game_selected().read().unwrap();
+                    if let Some(ref mut game_config) = *view.game_config().write().unwrap() {
+                        match set_defer_updates(&game, game_config, &selection, defer) {
+                            Ok(failed_mods) => if !failed_mods.is_empty() {
+                                let string = failed_mods.iter().map(|string| format!("<li>{}</li>", string)).join("");
+                                show_dialog(view.main_window(), tre("defer_workshop_updates_failed", &[&string]), false)
+                            }
+                            Err(error) => show_dialog(view.main_window(), error, false),
+                        }
+                    }
+                }
+
+                // Deferring only moves packs between Content and Secondary, so there's no need for a full reload.
+                if let Some(ref game_config) = *view.game_config().read().unwrap() {
+                    let game = view.game_selected().read().unwrap();
+                    if let Err(error) = view.mod_list_ui().load(&game, game_config) {
+                        show_dialog(view.main_window(), error, false);
+                    }
+                }
+
+                if let Err(error) = view.reload_pack_list() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let add_from_github = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.add_mod_from_github() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let update_from_github = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                match view.update_selected_mods_from_github() {
+                    Ok(failed_mods) => if failed_mods.is_empty() {
+                        show_dialog(view.main_window(), tr("mods_updated_from_github"), true);
+                    } else {
+                        let string = failed_mods.iter().map(|string| format!("<li>{}</li>", string)).join("");
+                        show_dialog(view.main_window(), tre("update_from_github_failed", &[&string]), false);
+                    }
+                    Err(error) => show_dialog(view.main_window(), error, false),
+                }
+            }
+        ));
+
         let load_profile = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
                 if let Err(error) = view.load_profile(None, false) {
@@ -455,6 +867,22 @@ impl AppUISlots {
             }
         ));
 
+        let save_profile_from_save = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.save_profile_from_save() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let toggle_profile_lock = SlotOfBool::new(&view.main_window, clone!(
+            view => move |lock| {
+                if let Err(error) = view.toggle_profile_lock(lock) {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
         let open_profile_manager = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
                 if let Err(error) = ProfilesUI::new(&view) {
@@ -469,6 +897,30 @@ impl AppUISlots {
             }
         ));
 
+        let load_load_order_slot = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.load_load_order_slot() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let save_load_order_slot = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.save_load_order_slot() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let delete_load_order_slot = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.delete_load_order_slot() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
         let enable_selected = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
                 if let Err(error) = view.batch_toggle_selected_mods(true) {
@@ -485,6 +937,32 @@ impl AppUISlots {
             }
         ));
 
+        let set_working_folder = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.set_working_folder() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let use_working_folder = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                let enable = view.mod_list_ui().use_working_folder().is_checked();
+                if let Err(error) = view.toggle_working_folder(enable) {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let ignore_mods = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                let ignore = view.mod_list_ui().ignore_mods().is_checked();
+                if let Err(error) = view.ignore_selected_mods(ignore) {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
         let upload_to_workshop = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
                 if let Err(error) = view.upload_mod_to_workshop() {
@@ -525,6 +1003,38 @@ impl AppUISlots {
             }
         ));
 
+        let category_style = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+                if let Err(error) = view.style_category() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let category_rules = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+                if let Err(error) = view.edit_category_rules() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let change_mod_list_grouping = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+                if let Err(error) = view.change_mod_list_grouping() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let toggle_show_ignored_mods = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+                if let Err(error) = view.toggle_show_ignored_mods() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
         let category_move = SlotOfQModelIndexInt::new(view.main_window(), clone!(
             view => move |dest_parent, dest_row| {
                 if let Err(error) = view.move_category(dest_parent, dest_row, false) {
@@ -564,7 +1074,63 @@ impl AppUISlots {
             }
         ));
 
-        let move_to_secondary = SlotNoArgs::new(&view.main_window, clone!(
+        let refresh_secondary_copy = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                {
+                    let selection = view.mod_list_selection()
+                        .iter()
+                        .map(|x| x.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+                        .collect::<Vec<_>>();
+
+                    let game = view.game_selected().read().unwrap();
+                    if let Some(ref game_config) = *view.game_config().read().unwrap() {
+                        match refresh_secondary_copies(&game, game_config, &selection) {
+                            Ok(failed_mods) => if !failed_mods.is_empty() {
+                                let string = failed_mods.iter().map(|string| format!("<li>{}</li>", string)).join("");
+                                show_dialog(view.main_window(), tre("refresh_secondary_copy_failed", &[&string]), false)
+                            }
+                            Err(error) => show_dialog(view.main_window(), error, false),
+                        }
+                    }
+                }
+
+                view.actions_ui().reload_button().click();
+            }
+        ));
+
+        let find_shadowed_packs = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.check_shadowed_packs(false) {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let find_duplicate_mods = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.find_duplicate_mods() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let add_known_issue = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.add_known_issue() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let rebuild_masks = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.rebuild_masks() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let move_to_secondary = SlotNoArgs::new(&view.main_window, clone!(
             view => move || {
                 {
                     let selection = view.mod_list_selection()
@@ -588,6 +1154,79 @@ impl AppUISlots {
             }
         ));
 
+        let treat_as_mod_pack = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                {
+                    let selection = view.mod_list_selection()
+                        .iter()
+                        .map(|x| x.data_1a(VALUE_MOD_ID).to_string().to_std_string())
+                        .collect::<Vec<_>>();
+
+                    let enable = view.mod_list_ui().treat_as_mod_pack().is_checked();
+                    let game = view.game_selected().read().unwrap();
+                    if let Some(ref mut game_config) = *view.game_config().write().unwrap() {
+                        match set_treat_as_mod_pack(&game, game_config, &selection, enable) {
+                            Ok(failed_mods) => if !failed_mods.is_empty() {
+                                let string = failed_mods.iter().map(|string| format!("<li>{}</li>", string)).join("");
+                                show_dialog(view.main_window(), tre("treat_as_mod_pack_failed", &[&string]), false)
+                            }
+                            Err(error) => show_dialog(view.main_window(), error, false),
+                        }
+                    }
+                }
+
+                view.actions_ui().reload_button().click();
+            }
+        ));
+
+        let export_map_bin = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.export_map_bin() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let rescan_mod = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.rescan_selected_mod() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let extract_mod_pack = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.extract_selected_pack(false) {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let backup_selected_mods = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.backup_selected_mods() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let restore_mods_backup = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.restore_mods_backup() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
+        let export_mod_report = SlotNoArgs::new(&view.main_window, clone!(
+            view => move || {
+                if let Err(error) = view.export_mod_report() {
+                    show_dialog(view.main_window(), error, false);
+                }
+            }
+        ));
+
         let github_link = SlotNoArgs::new(view.main_window(), || { QDesktopServices::open_url(&QUrl::new_1a(&QString::from_std_str(GITHUB_URL))); });
         let discord_link = SlotNoArgs::new(view.main_window(), || { QDesktopServices::open_url(&QUrl::new_1a(&QString::from_std_str(DISCORD_URL))); });
         let patreon_link = SlotNoArgs::new(view.main_window(), || { QDesktopServices::open_url(&QUrl::new_1a(&QString::from_std_str(PATREON_URL))); });
@@ -617,13 +1256,34 @@ impl AppUISlots {
             }
         ));
 
+        let pack_toggle_show_vanilla_packs = SlotOfBool::new(&view.main_window, clone!(
+            view => move |toggled| {
+                set_setting_bool("show_vanilla_packs", toggled);
+
+                if let Some(ref game_config) = *view.game_config().read().unwrap() {
+                    let game = view.game_selected().read().unwrap();
+                    let game_path = setting_path(game.key());
+                    let load_order = view.game_load_order().read().unwrap();
+                    if let Err(error) = view.pack_list_ui().load(game_config, &game, &game_path, &load_order) {
+                        show_dialog(view.main_window(), error, false);
+                    }
+                }
+            }
+        ));
+
         let pack_move = SlotOfQModelIndexInt::new(view.main_window(), clone!(
             view => move |_, dest_row| {
                 if view.pack_list_ui().automatic_order_button().is_checked() {
                     return show_dialog(view.main_window(), tr("edit_load_order_with_auto_on"), false);
                 }
 
-                if let Err(error) = view.move_pack(dest_row) {
+                // Drop positions are only meaningful if the list is shown in load order.
+                if !view.pack_list_ui().is_sorted_by_load_order() {
+                    return show_dialog(view.main_window(), tr("pack_move_not_sorted_by_load_order"), false);
+                }
+
+                let dest_row = view.pack_list_ui().source_row(dest_row);
+                if let Err(error) = view.move_pack(dest_row).and_then(|_| view.update_data_list_delta()) {
                     return show_dialog(view.main_window(), error, false);
                 }
             }
@@ -661,8 +1321,342 @@ impl AppUISlots {
             }
         }));
 
+        let reveal_data_file_in_mod_list = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.reveal_data_file_in_mod_list() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let open_table_with_rpfm = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.open_data_table_with_rpfm() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let reveal_pack_in_mod_list = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.reveal_pack_in_mod_list() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let extract_pack_list_pack = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.extract_selected_pack(true) {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let reveal_mod_in_pack_list = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.reveal_mod_in_pack_list() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let mod_list_double_clicked = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.mod_list_double_clicked() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let install_dropped_packs = SlotOfQStringList::new(view.main_window(), clone!(
+            view => move |files| {
+            let paths = (0..files.count_0a())
+                .map(|index| PathBuf::from(files.at(index).to_std_string()))
+                .collect::<Vec<_>>();
+
+            if let Err(error) = view.install_dropped_packs(&paths) {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let next_game = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            view.switch_game_selected(true);
+        }));
+
+        let previous_game = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            view.switch_game_selected(false);
+        }));
+
+        let toggle_selected_mods = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.toggle_selected_mods() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        // Focus the filter of the list we're in. If we're not in the pack list, default to the mod list one.
+        let focus_filter = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            let filter_line_edit = if view.pack_list_ui().tree_view().has_focus() {
+                view.pack_list_ui().filter_line_edit()
+            } else {
+                view.mod_list_ui().filter_line_edit()
+            };
+
+            filter_line_edit.set_focus_0a();
+            filter_line_edit.select_all();
+        }));
+
+        let move_pack_up = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.move_selected_packs(true).and_then(|_| view.update_data_list_delta()) {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let move_pack_down = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.move_selected_packs(false).and_then(|_| view.update_data_list_delta()) {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let pack_move_to_top = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.move_selected_packs_to(0).and_then(|_| view.update_data_list_delta()) {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let pack_move_to_bottom = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.move_selected_packs_to(usize::MAX).and_then(|_| view.update_data_list_delta()) {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let pack_move_to_position = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.move_selected_packs_to_position().and_then(|_| view.update_data_list_delta()) {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let pack_import_order = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.import_pack_order().and_then(|_| view.update_data_list_delta()) {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let pack_export_launch_manifest = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.export_launch_manifest() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let pack_group_lock = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.group_selected_packs() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let pack_conflict_suggestions = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.show_order_suggestions() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let pack_group_unlock = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.ungroup_selected_packs() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        // Each change restarts the timer, so we only check the files once the sync program is done with them.
+        let config_files_changed = SlotOfQString::new(view.main_window(), clone!(
+            view => move |_| {
+            view.config_watcher_timer().start_0a();
+        }));
+
+        let check_config_files_changes = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.check_config_files_changes() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let mod_list_files_changed = SlotOfQString::new(view.main_window(), clone!(
+            view => move |_| {
+            view.mod_list_watcher_timer().start_0a();
+        }));
+
+        let check_mod_list_files_changes = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.check_mod_list_files_changes() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let import_mod_list_file_order = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.import_mod_list_file_order() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let overwrite_mod_list_file = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.overwrite_mod_list_file() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let undo_last_operation = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.undo_last_operation() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let export_to_ca_launcher = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            match view.export_to_ca_launcher() {
+                Ok(missing) => if missing.is_empty() {
+                    show_dialog(view.main_window(), tr("export_to_ca_launcher_success"), true);
+                } else {
+                    let list = missing.iter().map(|mod_id| format!("<li>{mod_id}</li>")).collect::<String>();
+                    show_dialog(view.main_window(), tre("export_to_ca_launcher_missing", &[&list]), false);
+                }
+                Err(error) => show_dialog(view.main_window(), error, false),
+            }
+        }));
+
+        let import_from_ca_launcher = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.import_from_ca_launcher() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let open_sessions = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.open_sessions_dialog() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let update_translations = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.update_translations() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let translation_coverage = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.open_translation_coverage_dialog() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let export_tweaks_as_pack = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.export_tweaks_as_pack() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let open_bughunt = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.open_bughunt_dialog() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let actions_context_menu = SlotOfQPoint::new(&view.main_window, clone!(
+            view => move |_| {
+            view.update_actions_context_menu();
+            view.actions_ui().context_menu().exec_1a_mut(&QCursor::pos_0a());
+        }));
+
+        let customize_actions = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.open_actions_customization_dialog() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let pin_profile = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.toggle_pinned_profile() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let pin_save = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.toggle_pinned_save() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let restore_save_backup = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.restore_save_backup() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let copy_fingerprint = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.copy_fingerprint() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let copy_fingerprint_manifest = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            match view.copy_fingerprint_manifest() {
+                Ok(_) => show_dialog(view.main_window(), tr("copy_fingerprint_manifest_success"), true),
+                Err(error) => show_dialog(view.main_window(), error, false),
+            }
+        }));
+
+        let compare_fingerprint_manifests = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.compare_fingerprint_manifests() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let freeze_campaign_integrity = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.freeze_campaign_integrity() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
+        let verify_campaign_integrity = SlotNoArgs::new(view.main_window(), clone!(
+            view => move || {
+            if let Err(error) = view.verify_campaign_integrity() {
+                show_dialog(view.main_window(), error, false);
+            }
+        }));
+
         Self {
             launch_game,
+            restore_from_tray,
+            tray_activated,
+            quit_from_tray,
+            launch_from_tray,
+            check_workshop_changes,
+            receive_workshop_changes,
+            handle_api_requests,
+            check_offline_mode,
+            receive_offline_mode,
+            check_system_theme,
             toggle_logging,
             toggle_skip_intros,
             toggle_remove_trait_limit,
@@ -675,11 +1669,18 @@ impl AppUISlots {
             open_game_root_folder,
             open_game_data_folder,
             open_game_content_folder,
+            update_folders_menu,
             open_game_secondary_folder,
+            open_game_masks_folder,
+            open_game_temp_packs_folder,
             open_game_config_folder,
             open_runcher_config_folder,
             open_runcher_error_folder,
+            open_runcher_locale_folder,
+            open_runcher_log_viewer,
+            inspect_diagnostic_bundle,
             change_game_selected,
+            game_selected_context_menu,
 
             update_pack_list,
 
@@ -693,29 +1694,115 @@ impl AppUISlots {
             copy_load_order,
             paste_load_order,
             reload,
+            refresh_workshop_data,
+            rescan_data_folder,
+            rescan_secondary_folder,
             download_subscribed_mods,
 
             load_profile,
             save_profile,
+            save_profile_from_save,
+            toggle_profile_lock,
             open_profile_manager,
+            load_load_order_slot,
+            save_load_order_slot,
+            delete_load_order_slot,
 
             enable_selected,
             disable_selected,
+            ignore_mods,
             upload_to_workshop,
             download_from_workshop,
+            search_workshop,
+            force_redownload,
+            update_workshop_items_now,
+            defer_workshop_updates,
+            add_from_github,
+            update_from_github,
             category_create,
             category_delete,
             category_rename,
             category_move,
             category_sort,
+            category_style,
+            category_rules,
+            change_mod_list_grouping,
+            toggle_show_ignored_mods,
             mod_list_context_menu_open,
             copy_to_secondary,
+            refresh_secondary_copy,
+            find_shadowed_packs,
+            find_duplicate_mods,
+            add_known_issue,
+            rebuild_masks,
             move_to_secondary,
+            treat_as_mod_pack,
+            export_map_bin,
+            extract_mod_pack,
+            rescan_mod,
+            set_working_folder,
+            use_working_folder,
+            backup_selected_mods,
+            restore_mods_backup,
+            export_mod_report,
 
             pack_toggle_auto_sorting,
+            pack_toggle_show_vanilla_packs,
             pack_move,
             data_view_reload,
             open_file_with_rpfm,
+            reveal_data_file_in_mod_list,
+            open_table_with_rpfm,
+            reveal_pack_in_mod_list,
+            extract_pack_list_pack,
+            reveal_mod_in_pack_list,
+            mod_list_double_clicked,
+            install_dropped_packs,
+
+            next_game,
+            previous_game,
+            toggle_selected_mods,
+            focus_filter,
+            move_pack_up,
+            move_pack_down,
+
+            pack_move_to_top,
+            pack_move_to_bottom,
+            pack_move_to_position,
+            pack_import_order,
+            pack_export_launch_manifest,
+            pack_group_lock,
+            pack_conflict_suggestions,
+            pack_group_unlock,
+
+            config_files_changed,
+            check_config_files_changes,
+            mod_list_files_changed,
+            check_mod_list_files_changes,
+            import_mod_list_file_order,
+            overwrite_mod_list_file,
+
+            undo_last_operation,
+
+            export_to_ca_launcher,
+            import_from_ca_launcher,
+            open_sessions,
+            update_translations,
+            translation_coverage,
+            export_tweaks_as_pack,
+            open_bughunt,
+
+            actions_context_menu,
+            customize_actions,
+            pin_profile,
+            pin_save,
+            restore_save_backup,
+
+            copy_fingerprint,
+            copy_fingerprint_manifest,
+            compare_fingerprint_manifests,
+            freeze_campaign_integrity,
+            verify_campaign_integrity,
         }
     }
 }
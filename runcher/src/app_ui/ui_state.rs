@@ -0,0 +1,131 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the per-game state of the UI.
+//!
+//! Each game has its own layout, column widths, expanded categories and Actions panel customization, so they're stored per game instead of in the global window state.
+
+use anyhow::Result;
+use getset::*;
+use serde::{Deserialize, Serialize};
+use serde_json::to_string_pretty;
+
+use std::fs::{DirBuilder, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
+
+use rpfm_ui_common::settings::config_path;
+
+const UI_STATE_FOLDER: &str = "ui_state";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct UIState {
+
+    // Position and size of the main window, in virtual desktop coordinates.
+    window_geometry: Option<WindowGeometry>,
+
+    // Sizes of the panels of the main splitter.
+    splitter_sizes: Vec<i32>,
+
+    // Index of the tab selected in the right panel.
+    right_tab: i32,
+
+    // Widths of the columns of each list, in order.
+    mod_list_column_widths: Vec<i32>,
+    pack_list_column_widths: Vec<i32>,
+    data_list_column_widths: Vec<i32>,
+
+    // Names of the categories expanded in the mod list.
+    expanded_categories: Vec<String>,
+
+    // Keys of the controls of the Actions panel hidden by the user.
+    #[serde(default)]
+    hidden_actions: Vec<String>,
+
+    // Keys of the buttons of the Actions panel toolbar, in the order the user wants them.
+    #[serde(default)]
+    actions_order: Vec<String>,
+
+    // Names of the profiles and saves pinned to the top of their selectors.
+    #[serde(default)]
+    pinned_profiles: Vec<String>,
+    #[serde(default)]
+    pinned_saves: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct WindowGeometry {
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    maximized: bool,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl UIState {
+
+    /// This function loads the UI state of the provided game. If there's no state saved for it, this returns the default one.
+    pub fn load(game_key: &str) -> Result<Self> {
+        let path = ui_state_path(game_key)?;
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+
+        let file = BufReader::new(File::open(path)?);
+        serde_json::from_reader(file).map_err(From::from)
+    }
+
+    pub fn save(&self, game_key: &str) -> Result<()> {
+        let path = ui_state_path(game_key)?;
+        if let Some(parent_folder) = path.parent() {
+            DirBuilder::new().recursive(true).create(parent_folder)?;
+        }
+
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(to_string_pretty(&self)?.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl WindowGeometry {
+    pub fn new(x: i32, y: i32, width: i32, height: i32, maximized: bool) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            maximized,
+        }
+    }
+}
+
+/// This function pins the provided name, or unpins it if it was already pinned. Pinned names are kept in the order they were pinned.
+pub fn toggle_pinned(pinned: &mut Vec<String>, name: &str) {
+    match pinned.iter().position(|pinned_name| pinned_name == name) {
+        Some(position) => {
+            pinned.remove(position);
+        }
+        None => pinned.push(name.to_owned()),
+    }
+}
+
+fn ui_state_path(game_key: &str) -> Result<PathBuf> {
+    Ok(config_path()?.join(UI_STATE_FOLDER).join(format!("{game_key}.json")))
+}
@@ -8,23 +8,24 @@
 // https://github.com/Frodo45127/runcher/blob/master/LICENSE.
 //---------------------------------------------------------------------------//
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use base64::{Engine as _, engine::general_purpose};
 use crossbeam::channel::Sender;
 use rayon::prelude::*;
 use zstd::stream::*;
 
-use std::path::{Path, PathBuf};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
 
 use rpfm_lib::integrations::{git::*, log::*};
 use rpfm_lib::schema::*;
 
-use rpfm_ui_common::settings::error_path;
 
 use crate::CENTRAL_COMMAND;
 use crate::communications::*;
 use crate::games::{TRANSLATIONS_REPO, TRANSLATIONS_BRANCH, TRANSLATIONS_REMOTE};
-use crate::mod_manager::{game_config::GameConfig, load_order::{ImportedLoadOrderMode, LoadOrder}, mods::ShareableMod};
+use crate::mod_manager::{duplicates::duplicate_mods, fingerprint::{freeze_campaign_integrity, verify_campaign_integrity, verify_pack_hashes, Fingerprint}, game_config::GameConfig, hooks::Hooks, launch::prepare_mod_list, load_order::{ImportedLoadOrderMode, LoadOrder}, log_patterns::LogPatterns, mods::ShareableMod, other_managers::{game_loaded_elsewhere, manager_conflicts}, save_backups::{backup_saves, restore_save_backup}, saves::Save, script_breaks::analyze_logs, shadowed_packs::shadowed_packs, workshop_monitor::WorkshopSnapshot};
 use crate::settings_ui::{schemas_path, translations_remote_path};
 use crate::SCHEMA;
 
@@ -33,9 +34,6 @@ use crate::SCHEMA;
 /// All communication between this and the UI thread is done use the `CENTRAL_COMMAND` static.
 pub fn background_loop() {
 
-    // Initalize background sentry guard. This should, in theory, register crashes on the background thread.
-    let _sentry_guard = Logger::init(&error_path().unwrap_or_else(|_| PathBuf::from(".")), true, false, release_name!());
-
     //---------------------------------------------------------------------------------------//
     // Looping forever and ever...
     //---------------------------------------------------------------------------------------//
@@ -49,8 +47,15 @@ pub fn background_loop() {
 
             Command::Exit => return,
 
-            Command::UpdateMainProgram => {
-                match crate::updater_ui::update_main_program() {
+            Command::UpdateMainProgram(version) => {
+                match crate::updater_ui::update_main_program(version.as_deref()) {
+                    Ok(_) => CentralCommand::send_back(&sender, Response::Success),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            Command::RestoreUpdateBackup => {
+                match crate::updater_ui::restore_update_backup() {
                     Ok(_) => CentralCommand::send_back(&sender, Response::Success),
                     Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
                 }
@@ -100,7 +105,168 @@ pub fn background_loop() {
                 }
             }
 
-            Command::CheckUpdates | Command::CheckSchemaUpdates | Command::CheckTranslationsUpdates | Command::RequestModsData(_,_) => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+            // Mod rescans take a while with big mod lists, so we do them here to not hang the UI.
+            Command::UpdateModList(game, game_path, mut game_config, mut load_order, skip_network_update) => {
+                match game_config.update_mod_list(&game, &game_path, &mut load_order, skip_network_update) {
+                    Ok(network_receiver) => CentralCommand::send_back(&sender, Response::GameConfigLoadOrderOptionReceiver(game_config, load_order, network_receiver)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            Command::RescanMods(game, game_path, mut game_config, mut load_order, scope) => {
+                match game_config.rescan_mods(&game, &game_path, &mut load_order, &scope) {
+                    Ok(_) => CentralCommand::send_back(&sender, Response::GameConfigLoadOrderOptionReceiver(game_config, load_order, None)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            // Hooks may take a while, depending on what the user runs in them, so they get their own thread to not block other commands.
+            Command::RunHooks(stage, game, game_path, profile) => {
+                thread::spawn(move || {
+                    match Hooks::load().and_then(|hooks| hooks.run(stage, &game, &game_path, &profile)) {
+                        Ok(failures) => CentralCommand::send_back(&sender, Response::VecString(failures)),
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                    }
+                });
+            }
+
+            // Merging packs and masking movies may take a while, so we report each step back while we do them.
+            Command::PrepareLaunch(game, game_path, game_config, load_order, merge_all_mods, folder_list) => {
+                let progress = |step| CentralCommand::send_back(&sender, Response::LaunchStep(step));
+                match prepare_mod_list(&game, &game_path, &game_config, &load_order, merge_all_mods, folder_list, progress) {
+                    Ok((pack_list, folder_list)) => CentralCommand::send_back(&sender, Response::StringString(pack_list, folder_list)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            // If we wait for the game to finish, launching blocks until the game is closed.
+            // We do it in its own thread, so the rest of the commands don't have to wait for the game.
+            Command::LaunchGame(game, command, env_vars, wait_for_finish) => {
+                thread::spawn(move || {
+                    match crate::mod_manager::integrations::launch_game(&game, &command, &env_vars, wait_for_finish) {
+                        Ok(_) => CentralCommand::send_back(&sender, Response::Success),
+                        Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                    }
+                });
+            }
+
+            Command::AnalyzeLogs(game, game_path, game_config, load_order, start_date) => {
+                match analyze_logs(&game, &game_path, &game_config, &load_order, &LogPatterns::load(), &start_date) {
+                    Ok(breaks) => CentralCommand::send_back(&sender, Response::VecScriptBreak(breaks)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            Command::GetModsFromSave(path) => {
+                match Save::read_mods(&path) {
+                    Ok(mods) => CentralCommand::send_back(&sender, Response::VecString(mods)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            // Late campaign saves are big, so zipping a few of them takes a while.
+            Command::BackupSaves(game_key, saves_path, folder, count, retention) => {
+                match backup_saves(&game_key, &saves_path, &folder, count, retention) {
+                    Ok(backup_path) => {
+                        if let Some(backup_path) = backup_path {
+                            info!("Saves backed up to {}.", backup_path.to_string_lossy());
+                        }
+
+                        CentralCommand::send_back(&sender, Response::Success)
+                    }
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            Command::RestoreSaveBackup(backup_path, saves_path) => {
+                match restore_save_backup(&backup_path, &saves_path) {
+                    Ok(restored) => CentralCommand::send_back(&sender, Response::VecString(restored)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            // Hashing big packs takes a while, so we report how many packs we have checked as we go.
+            Command::VerifyPackHashes(packs) => {
+                let checked = AtomicUsize::new(0);
+                let progress = || CentralCommand::send_back(&sender, Response::Usize(checked.fetch_add(1, Ordering::SeqCst) + 1));
+                let checks = verify_pack_hashes(&packs, progress);
+                CentralCommand::send_back(&sender, Response::VecHashCheck(checks));
+            }
+
+            Command::FindShadowedPacks(game_config, mod_ids, content_path) => {
+                CentralCommand::send_back(&sender, Response::VecShadowedPack(shadowed_packs(&game_config, &mod_ids, &content_path)));
+            }
+
+            // Games with big Workshop folders take a while to scan, so this is done here instead of on the UI thread.
+            Command::ScanWorkshopFolders(games) => {
+                let snapshots = games.iter()
+                    .filter_map(|(game, game_path)| match WorkshopSnapshot::scan(game, game_path) {
+                        Ok(snapshot) => Some((game.key().to_owned(), snapshot)),
+                        Err(error) => {
+                            warn!("Error checking the Workshop content folder of {}: {}", game.key(), error);
+                            None
+                        }
+                    })
+                    .collect();
+
+                CentralCommand::send_back(&sender, Response::VecStringWorkshopSnapshot(snapshots));
+            }
+
+            // Packs without a cached hash need to be hashed, and that takes a while with big packs.
+            Command::GetFingerprint(game_config, load_order, launch_options) => {
+                match Fingerprint::new(&game_config, &load_order, &launch_options) {
+                    Ok(fingerprint) => CentralCommand::send_back(&sender, Response::Fingerprint(fingerprint)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            Command::FreezeCampaignIntegrity(game_config, load_order) => {
+                match freeze_campaign_integrity(&game_config, &load_order) {
+                    Ok(fingerprint) => CentralCommand::send_back(&sender, Response::Fingerprint(fingerprint)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            Command::VerifyCampaignIntegrity(game_config, load_order) => {
+                match verify_campaign_integrity(&game_config, &load_order) {
+                    Ok(result) => CentralCommand::send_back(&sender, Response::OptionVecFingerprintDifferenceU64(result)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            // Listing the running processes takes a while, so we do it here.
+            Command::GetManagerConflicts(game, game_path) => {
+                CentralCommand::send_back(&sender, Response::VecManagerConflict(manager_conflicts(&game, &game_path)));
+            }
+
+            Command::GetGameLoadedElsewhere(game_key) => {
+                CentralCommand::send_back(&sender, Response::OptionU32(game_loaded_elsewhere(&game_key)));
+            }
+
+            // Workshopper has to read and recompress the whole map, so this takes a while.
+            Command::ExportMapBin(pack_path, map_name, output_path) => {
+                match crate::mod_manager::integrations::export_map_bin(&pack_path, &map_name, &output_path) {
+                    Ok(_) => CentralCommand::send_back(&sender, Response::Success),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            // Same as with the hash verification, we report how many packs we have read as we go.
+            Command::FindDuplicateMods(game_config, mod_ids) => {
+                let checked = AtomicUsize::new(0);
+                let progress = || CentralCommand::send_back(&sender, Response::Usize(checked.fetch_add(1, Ordering::SeqCst) + 1));
+                CentralCommand::send_back(&sender, Response::VecDuplicateGroup(duplicate_mods(&game_config, &mod_ids, progress)));
+            }
+
+            Command::GetDiagnosticBundleLoadOrder(bundle, game_key, local_config) => {
+                let checked = AtomicUsize::new(0);
+                let progress = || CentralCommand::send_back(&sender, Response::Usize(checked.fetch_add(1, Ordering::SeqCst) + 1));
+                let (load_order, missing) = bundle.load_order_with_local_packs(&game_key, local_config.as_ref(), progress);
+                CentralCommand::send_back(&sender, Response::LoadOrderVecString(load_order, missing));
+            }
+
+            Command::CheckUpdates | Command::GetReleases(_) | Command::CheckSchemaUpdates | Command::CheckTranslationsUpdates | Command::RequestModsData(_,_) | Command::SearchWorkshopMods(_,_,_) | Command::DownloadGitHubMod(_,_,_,_) | Command::CheckGitHubModsUpdates(_) | Command::UpdateRemoteLists | Command::DetectOfflineMode |
+            Command::GetGameSelected | Command::GetLoadOrder | Command::GetProfiles | Command::LoadProfile(_) | Command::LaunchGameSelected => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
         }
     }
 }
@@ -143,5 +309,8 @@ fn get_load_order_from_string(mode: ImportedLoadOrderMode) -> Result<Vec<Shareab
             }
             Ok(mods)
         }
+
+        // Names need to be matched against the mod list, and the matches confirmed by the user, so they're handled in the UI.
+        ImportedLoadOrderMode::Names(_) => Err(anyhow!("Lists of mod names cannot be imported without checking their matches.")),
     }
 }
@@ -13,31 +13,59 @@
 use anyhow::{anyhow, Result};
 use clap::{builder::PossibleValuesParser, Parser};
 use crossbeam::channel::Receiver;
+use serde_json::{json, to_writer_pretty};
 
 #[cfg(target_os = "windows")] use std::fs::{read_dir, remove_dir_all};
+use std::io::{stdout, Write};
+use std::path::{Path, PathBuf};
 
 use rpfm_lib::games::supported_games::*;
 use rpfm_lib::integrations::log::*;
 
 #[cfg(target_os = "windows")] use rpfm_ui_common::PROGRAM_PATH;
+use rpfm_ui_common::locale::tr;
 use rpfm_ui_common::settings::setting_string;
 use rpfm_ui_common::utils::log_to_status_bar;
 
 use crate::app_ui::AppUI;
 use crate::communications::Response;
+use crate::mod_manager::game_definitions::GameDefinitions;
+use crate::mod_manager::script_breaks::ScriptBreak;
+use crate::mod_manager::simulation::SimulationFixture;
+use crate::settings_ui::{STARTUP_GAME_ASK, STARTUP_GAME_LAST_USED};
+
+/// Exit code used when the game was launched correctly.
+pub const EXIT_CODE_SUCCESS: i32 = 0;
+
+/// Exit code used when the game could not be launched.
+pub const EXIT_CODE_LAUNCH_FAILED: i32 = 1;
+
+/// Exit code used when the game was launched, but script errors were found in its logs.
+pub const EXIT_CODE_SCRIPT_ERRORS: i32 = 2;
+
+const AFTER_HELP: &str = "Exit codes when autostarting:
+  0: the game was launched correctly.
+  1: the game could not be launched.
+  2: the game was launched, but script errors were found in its logs (only with --check-logs).
+
+With --check-logs, the script errors found are printed to stdout as json.";
 
 //---------------------------------------------------------------------------//
 //                          Struct/Enum Definitions
 //---------------------------------------------------------------------------//
 
 #[derive(Parser)]
-#[command(author, version, about, long_about = None)]
+#[command(author, version, about, long_about = None, after_help = AFTER_HELP)]
 pub(crate) struct Cli {
 
     /// Game we we want to start with. Overrides default game.
     #[arg(short, long, required = false, value_name = "GAME", value_parser = PossibleValuesParser::new(game_keys()))]
     game: Option<String>,
 
+    /// Opens the UI without loading any game, for quick edits of the settings. A game can be loaded later from the game bar.
+    #[arg(long, required = false, conflicts_with_all = ["game", "profile", "autostart"])]
+    no_game: bool,
+
     /// Profile to start with. Requires a game.
     #[arg(short, long, required = false, value_name = "PROFILE_NAME")]
     profile: Option<String>,
@@ -45,6 +73,26 @@ pub(crate) struct Cli {
     /// If we should autostart the game/profile combo. Skips the UI. Requires a game, profile is optional.
     #[arg(short, long, required = false)]
     autostart: bool,
+
+    /// Waits until the game is closed before exiting. Only used with autostart.
+    #[arg(long, required = false)]
+    wait_for_exit: bool,
+
+    /// Checks the game's logs for script errors once the game is closed, printing them to stdout as json. Implies --wait-for-exit. Only used with autostart.
+    #[arg(long, required = false)]
+    check_logs: bool,
+
+    /// Mod to enable on top of the profile. Can be passed multiple times. Requires a game.
+    #[arg(long, required = false, value_name = "MOD_ID")]
+    enable_mod: Vec<String>,
+
+    /// Mod to disable on top of the profile. Can be passed multiple times. Requires a game.
+    #[arg(long, required = false, value_name = "MOD_ID")]
+    disable_mod: Vec<String>,
+
+    /// Runs the simulation described in the provided fixture file, prints its result as json and exits. Skips the UI.
+    #[arg(long, required = false, value_name = "FIXTURE")]
+    simulate: Option<PathBuf>,
 }
 
 /// Function to get the supported game keys.
@@ -55,7 +103,10 @@ fn game_keys() -> Vec<&'static str> {
 
 impl Cli {
 
-    pub unsafe fn parse_args(app_ui: &AppUI) -> Result<(bool, Option<Receiver<Response>>)> {
+    /// This function parses the cli args and applies them.
+    ///
+    /// If Runcher needs to exit after this, it returns the exit code to use.
+    pub unsafe fn parse_args(app_ui: &AppUI) -> Result<(Option<i32>, Option<Receiver<Response>>)> {
 
         // Clean up folders from previous updates, if they exist. Windows-only.
         //
@@ -77,21 +128,29 @@ impl Cli {
         // Parse the entire cli command.
         let cli = Self::parse();
 
-        // If we're not autostarting, make the main window visible, then trigger an event loop cycle
-        // so the window is shown, then we do the expensive stuff.
-        if !cli.autostart {
-            app_ui.main_window().show();
-            app_ui.toggle_main_window(false);
-
-            log_to_status_bar(app_ui.main_window().status_bar(), "Initializing, please wait...");
-            let event_loop = qt_core::QEventLoop::new_0a();
-            event_loop.process_events_0a();
+        // When autostarting there's no UI to report errors, so scripts calling us only get the exit code.
+        match cli.apply(app_ui) {
+            Ok(result) => Ok(result),
+            Err(error) if cli.autostart => {
+                error!("Error autostarting: {}", error);
+                Ok((Some(EXIT_CODE_LAUNCH_FAILED), None))
+            }
+            Err(error) => Err(error),
         }
+    }
+
+    /// This function applies the parsed cli args to the UI, launching the game if we're autostarting.
+    unsafe fn apply(&self, app_ui: &AppUI) -> Result<(Option<i32>, Option<Receiver<Response>>)> {
 
         // Game override.
         let mut game_passed = false;
-        let mut default_game = setting_string("default_game");
-        match cli.game {
+        let startup_game = setting_string("startup_game");
+        let mut default_game = match &*startup_game {
+            STARTUP_GAME_LAST_USED => setting_string("last_game_selected"),
+            _ => setting_string("default_game"),
+        };
+
+        match self.game {
             Some(ref game) => {
 
                 // Set the game selected based on the default game. If we passed a game through an argument, use that one.
@@ -115,12 +174,46 @@ impl Cli {
                         default_game = game.to_owned();
                         game_passed = true;
                     },
+                    _ if GameDefinitions::cached().new_games().iter().any(|(key, _)| *key == game) => {
+                        info!("Valid user-defined game provided through arg, using {} as default game.", game);
+                        default_game = game.to_owned();
+                        game_passed = true;
+                    },
                     _ => info!("Invalid game provided through arg (\"{}\"), using {} as default game.", game, default_game),
                 }
             }
             None => info!("No default game provided through arg, using {} as default game.", default_game),
         }
 
+        // If the user wants to choose the game on each start, ask for it before showing the main window.
+        // Cancelling the dialog opens Runcher without a game, same as --no-game.
+        let mut no_game = self.no_game;
+        if !no_game && !game_passed && !self.autostart && startup_game == STARTUP_GAME_ASK {
+            match app_ui.ask_game_selected() {
+                Some(game) => default_game = game,
+                None => no_game = true,
+            }
+        }
+
+        // If we're not autostarting, make the main window visible, then trigger an event loop cycle
+        // so the window is shown, then we do the expensive stuff.
+        if !self.autostart {
+            app_ui.main_window().show();
+            app_ui.toggle_main_window(false);
+
+            log_to_status_bar(app_ui.main_window().status_bar(), "Initializing, please wait...");
+            let event_loop = qt_core::QEventLoop::new_0a();
+            event_loop.process_events_0a();
+        }
+
+        // Without a game we only need the UI. The first game selected in the game bar gets loaded as if we were starting up.
+        if no_game {
+            info!("No game to load at startup. Skipping the initial load.");
+            app_ui.toggle_main_window(true);
+            log_to_status_bar(app_ui.main_window().status_bar(), &tr("no_game_loaded"));
+            return Ok((None, None));
+        }
+
         // Set the default game, and set it in the UI too.
         match &*default_game {
             KEY_PHARAOH_DYNASTIES => app_ui.game_selected_pharaoh_dynasties().set_checked(true),
@@ -136,7 +229,10 @@ impl Cli {
             KEY_SHOGUN_2 => app_ui.game_selected_shogun_2().set_checked(true),
             KEY_NAPOLEON => app_ui.game_selected_napoleon().set_checked(true),
             KEY_EMPIRE => app_ui.game_selected_empire().set_checked(true),
-            _ => app_ui.game_selected_warhammer_3().set_checked(true),
+            _ => match app_ui.game_selected_new_games().iter().find(|action| AppUI::game_key_from_action(action) == default_game) {
+                Some(action) => action.set_checked(true),
+                None => app_ui.game_selected_warhammer_3().set_checked(true),
+            }
         }
 
         // This may fail for path problems.
@@ -144,21 +240,21 @@ impl Cli {
         // Also, the game we already have loaded is arena. We don't need to force a manual reload with that one.
         //
         // Note: if we're autostarting, skip the network update to start the game 1-5 seconds faster.
-        let network_receiver = app_ui.change_game_selected(false, cli.autostart)?;
+        let network_receiver = app_ui.change_game_selected(false, self.autostart)?;
 
         // If we're not autostarting, enable the UI here.
-        if !cli.autostart {
+        if !self.autostart {
             app_ui.toggle_main_window(true);
         }
 
         if game_passed {
 
             // Default profile. Only check if we have a valid game, because this needs the game to be set.
-            match cli.profile {
+            match self.profile {
                 Some(ref profile) => {
                     info!("Profile {} provided through args.", profile);
 
-                    match app_ui.load_profile(Some(profile.to_string()), cli.autostart) {
+                    match app_ui.load_profile(Some(profile.to_string()), self.autostart) {
                         Ok(_) => info!("Profile loaded correctly."),
                         Err(error) => {
                             error!("Error loading profile {}: {}.", profile, error);
@@ -169,11 +265,29 @@ impl Cli {
                 None => info!("No profile provided through arg."),
             }
 
+            // Mods to enable/disable on top of the profile.
+            if !self.enable_mod.is_empty() || !self.disable_mod.is_empty() {
+                info!("Mods to enable ({}) and disable ({}) provided through args.", self.enable_mod.join(", "), self.disable_mod.join(", "));
+                app_ui.apply_mod_overrides(&self.enable_mod, &self.disable_mod, self.autostart)?;
+            }
+
             // Autostart skipping ui? Only with game loaded, and last.
-            if cli.autostart {
+            if self.autostart {
                 info!("Autostart provided. Skipping UI and loading the game.");
-                app_ui.launch_game()?;
-                return Ok((true, network_receiver));
+                let wait_for_exit = self.wait_for_exit || self.check_logs;
+                let exit_code = match app_ui.launch_game_with_options(wait_for_exit, self.check_logs, false) {
+                    Ok(0) => EXIT_CODE_SUCCESS,
+                    Ok(breaks) => {
+                        info!("{} script errors found in the game's logs.", breaks);
+                        EXIT_CODE_SCRIPT_ERRORS
+                    }
+                    Err(error) => {
+                        error!("Error launching the game: {}", error);
+                        EXIT_CODE_LAUNCH_FAILED
+                    }
+                };
+
+                return Ok((Some(exit_code), network_receiver));
             } else {
                 info!("Autostart not provided, or provided as false.");
             }
@@ -181,6 +295,69 @@ impl Cli {
             info!("No valid game provided through args. Ignoring subsequent checks.");
         }
 
-        Ok((false, network_receiver))
+        Ok((None, network_receiver))
+    }
+
+    /// This function runs the cli commands that don't need the UI, like simulations.
+    ///
+    /// It's called before the UI is created. If one of these commands was passed, it returns the exit code to use.
+    pub fn run_headless() -> Option<i32> {
+        let cli = Self::parse();
+        let fixture_path = cli.simulate.as_ref()?;
+
+        match Self::simulate(fixture_path) {
+            Ok(_) => Some(EXIT_CODE_SUCCESS),
+            Err(error) => {
+                error!("Error running the simulation: {}", error);
+                Some(EXIT_CODE_LAUNCH_FAILED)
+            }
+        }
+    }
+
+    /// This function runs the simulation described in the provided fixture, and writes its result to stdout as json, so scripts can check it.
+    fn simulate(fixture_path: &Path) -> Result<()> {
+        info!("Running simulation from fixture {}.", fixture_path.to_string_lossy());
+
+        let fixture = SimulationFixture::load(fixture_path)?;
+        let root = tempfile::Builder::new().prefix("runcher_simulation").tempdir()?;
+        let result = fixture.run(root.path())?;
+
+        let mut mods = result.game_config().mods().keys().collect::<Vec<_>>();
+        mods.sort();
+
+        let report = json!({
+            "mods": mods,
+            "load_order": result.load_order().mods(),
+            "movies": result.load_order().movies(),
+            "mod_list_file": result.mod_list_file_path(),
+            "folder_list": result.folder_list(),
+            "pack_list": result.pack_list(),
+            "launch_args": result.launch_args(),
+        });
+
+        let mut stdout = stdout().lock();
+        to_writer_pretty(&mut stdout, &report)?;
+        writeln!(stdout)?;
+
+        info!("Simulation finished.");
+        Ok(())
+    }
+
+    /// This function writes the script breaks found after an autostart to stdout as json, so scripts can check them without a dialog blocking them.
+    pub fn print_script_breaks(breaks: &[ScriptBreak]) -> Result<()> {
+        let report = breaks.iter()
+            .map(|script_break| json!({
+                "pack": script_break.posible_pack(),
+                "mod": script_break.posible_pack_mod(),
+                "link": script_break.posible_pack_link(),
+                "log": script_break.full_log(),
+            }))
+            .collect::<Vec<_>>();
+
+        let mut stdout = stdout().lock();
+        to_writer_pretty(&mut stdout, &report)?;
+        writeln!(stdout)?;
+
+        Ok(())
     }
 }
@@ -12,16 +12,19 @@ use qt_core::QEventLoop;
 
 use anyhow::Error;
 use crossbeam::channel::{Receiver, Sender, unbounded};
+use serde_json::Value;
 
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::path::PathBuf;
+use std::time::SystemTime;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use rpfm_lib::games::GameInfo;
 use rpfm_lib::integrations::{log::info, git::GitResponse};
 
-use crate::mod_manager::{game_config::GameConfig, load_order::{ImportedLoadOrderMode, LoadOrder}, mods::{Mod, ShareableMod}};
-use crate::updater_ui::APIResponse;
+use crate::mod_manager::{diagnostic_bundle::DiagnosticBundle, duplicates::DuplicateGroup, fingerprint::{Fingerprint, FingerprintDifference, HashCheck}, game_config::{GameConfig, RescanScope}, hooks::HookStage, other_managers::ManagerConflict, integrations::GitHubSource, launch::LaunchStep, load_order::{ImportedLoadOrderMode, LoadOrder}, mods::{Mod, ShareableMod}, script_breaks::ScriptBreak, shadowed_packs::ShadowedPack, workshop_monitor::WorkshopSnapshot};
+use crate::updater_ui::{APIResponse, UpdateChannel};
 
 /// This const is the standard message in case of message communication error. If this happens, crash the program.
 pub const THREADS_COMMUNICATION_ERROR: &str = "Error in thread communication system. Response received: ";
@@ -37,9 +40,11 @@ pub const THREADS_SENDER_ERROR: &str = "Error in thread communication system. Se
 pub struct CentralCommand<T: Send + Sync + Debug> {
     sender_background: Sender<(Sender<T>, Command)>,
     sender_network: Sender<(Sender<T>, Command)>,
+    sender_api: Sender<(Sender<T>, Command)>,
 
     receiver_background: Receiver<(Sender<T>, Command)>,
     receiver_network: Receiver<(Sender<T>, Command)>,
+    receiver_api: Receiver<(Sender<T>, Command)>,
 
     try_lock: AtomicBool,
 }
@@ -52,7 +57,9 @@ pub struct CentralCommand<T: Send + Sync + Debug> {
 pub enum Command {
     Exit,
     CheckUpdates,
-    UpdateMainProgram,
+    GetReleases(UpdateChannel),
+    UpdateMainProgram(Option<String>),
+    RestoreUpdateBackup,
     CheckSchemaUpdates,
     UpdateSchemas(String),
     CheckTranslationsUpdates,
@@ -60,6 +67,36 @@ pub enum Command {
     GetStringFromLoadOrder(GameConfig, PathBuf, LoadOrder),
     GetLoadOrderFromString(ImportedLoadOrderMode),
     RequestModsData(Box<GameInfo>, Vec<String>),
+    SearchWorkshopMods(Box<GameInfo>, Vec<String>, u32),
+    DownloadGitHubMod(String, Option<String>, PathBuf, HashSet<String>),
+    CheckGitHubModsUpdates(Vec<GitHubSource>),
+    UpdateModList(Box<GameInfo>, PathBuf, GameConfig, LoadOrder, bool),
+    RescanMods(Box<GameInfo>, PathBuf, GameConfig, LoadOrder, RescanScope),
+    GetGameSelected,
+    GetLoadOrder,
+    GetProfiles,
+    LoadProfile(String),
+    LaunchGameSelected,
+    UpdateRemoteLists,
+    DetectOfflineMode,
+    RunHooks(HookStage, Box<GameInfo>, PathBuf, String),
+    PrepareLaunch(Box<GameInfo>, PathBuf, GameConfig, LoadOrder, bool, String),
+    LaunchGame(Box<GameInfo>, String, Vec<(String, String)>, bool),
+    AnalyzeLogs(Box<GameInfo>, PathBuf, GameConfig, LoadOrder, SystemTime),
+    GetModsFromSave(PathBuf),
+    BackupSaves(String, PathBuf, PathBuf, usize, usize),
+    RestoreSaveBackup(PathBuf, PathBuf),
+    VerifyPackHashes(Vec<(PathBuf, String)>),
+    FindShadowedPacks(GameConfig, Vec<String>, PathBuf),
+    ScanWorkshopFolders(Vec<(GameInfo, PathBuf)>),
+    ExportMapBin(PathBuf, String, PathBuf),
+    FindDuplicateMods(GameConfig, Vec<String>),
+    GetDiagnosticBundleLoadOrder(Box<DiagnosticBundle>, String, Option<GameConfig>),
+    GetFingerprint(GameConfig, LoadOrder, Vec<(String, String)>),
+    FreezeCampaignIntegrity(GameConfig, LoadOrder),
+    VerifyCampaignIntegrity(GameConfig, LoadOrder),
+    GetManagerConflicts(Box<GameInfo>, PathBuf),
+    GetGameLoadedElsewhere(String),
 }
 
 /// This enum defines the responses (messages) you can send to the to the UI thread as result of a command.
@@ -70,10 +107,28 @@ pub enum Response {
     Success,
     Error(Error),
     String(String),
+    VecString(Vec<String>),
     APIResponse(APIResponse),
     APIResponseGit(GitResponse),
     VecShareableMods(Vec<ShareableMod>),
     VecMod(Vec<Mod>),
+    GitHubSource(GitHubSource),
+    VecGitHubSource(Vec<GitHubSource>),
+    GameConfigLoadOrderOptionReceiver(GameConfig, LoadOrder, Option<Receiver<Response>>),
+    LaunchStep(LaunchStep),
+    StringString(String, String),
+    VecScriptBreak(Vec<ScriptBreak>),
+    Usize(usize),
+    VecHashCheck(Vec<HashCheck>),
+    VecShadowedPack(Vec<ShadowedPack>),
+    VecStringWorkshopSnapshot(Vec<(String, WorkshopSnapshot)>),
+    Fingerprint(Fingerprint),
+    OptionVecFingerprintDifferenceU64(Option<(Vec<FingerprintDifference>, u64)>),
+    VecManagerConflict(Vec<ManagerConflict>),
+    OptionU32(Option<u32>),
+    VecDuplicateGroup(Vec<DuplicateGroup>),
+    LoadOrderVecString(LoadOrder, Vec<String>),
+    JsonValue(Value),
 }
 
 //-------------------------------------------------------------------------------//
@@ -85,12 +140,15 @@ impl<T: Send + Sync + Debug> Default for CentralCommand<T> {
     fn default() -> Self {
         let (sender_background, receiver_background) = unbounded();
         let (sender_network, receiver_network) = unbounded();
+        let (sender_api, receiver_api) = unbounded();
         let try_lock = AtomicBool::new(false);
         Self {
             sender_background,
             sender_network,
+            sender_api,
             receiver_background,
             receiver_network,
+            receiver_api,
             try_lock,
         }
     }
@@ -125,6 +183,13 @@ impl<T: Send + Sync + Debug> CentralCommand<T> {
         Self::send(&self.sender_network, data)
     }
 
+    /// This function serves to send a message from the API thread to the main thread.
+    ///
+    /// It returns the receiver which will receive the answers for the command, if any.
+    pub fn send_api(&self, data: Command) -> Receiver<T> {
+        Self::send(&self.sender_api, data)
+    }
+
     /// This function serves to send a message back through a generated channel.
     pub fn send_back(sender: &Sender<T>, data: T) {
         if let Err(error) = sender.send(data) {
@@ -154,6 +219,13 @@ impl<T: Send + Sync + Debug> CentralCommand<T> {
         }
     }
 
+    /// This functions serves to receive messages from the API thread on the main thread.
+    ///
+    /// This function doesn't lock the thread. It returns None if there are no messages waiting.
+    pub fn try_recv_api(&self) -> Option<(Sender<T>, Command)> {
+        self.receiver_api.try_recv().ok()
+    }
+
     /// This functions serves to receive messages from a generated channel.
     ///
     /// This function does only try once, and it locks the thread. Panics if the response fails.
@@ -165,6 +237,11 @@ impl<T: Send + Sync + Debug> CentralCommand<T> {
         }
     }
 
+    /// This function returns if there's a [`recv_try`](Self::recv_try) waiting for a response.
+    pub fn is_waiting(&self) -> bool {
+        self.try_lock.load(Ordering::SeqCst)
+    }
+
     /// This functions serves to receive messages from a generated channel.
     ///
     /// This function will keep asking for a response, keeping the UI responsive. Use it for heavy tasks.
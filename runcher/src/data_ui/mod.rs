@@ -8,38 +8,47 @@
 // https://github.com/Frodo45127/runcher/blob/master/LICENSE.
 //---------------------------------------------------------------------------//
 
+use qt_widgets::QAction;
 use qt_widgets::QGridLayout;
 use qt_widgets::QLineEdit;
+use qt_widgets::QListWidget;
+use qt_widgets::QListWidgetItem;
+use qt_widgets::QMenu;
 use qt_widgets::QTabWidget;
 use qt_widgets::QToolButton;
 use qt_widgets::QTreeView;
 
+use qt_gui::QIcon;
 use qt_gui::QStandardItem;
 use qt_gui::QStandardItemModel;
 
 use qt_core::CaseSensitivity;
+use qt_core::ContextMenuPolicy;
 use qt_core::QBox;
 use qt_core::QModelIndex;
 use qt_core::QPtr;
 use qt_core::QRegExp;
 use qt_core::QSortFilterProxyModel;
+use qt_core::QString;
 use qt_core::QTimer;
 
 use cpp_core::CppBox;
 use cpp_core::CppDeletable;
+use cpp_core::Ptr;
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use getset::*;
 use rayon::prelude::*;
 
-use std::path::Path;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
 use rpfm_ui_common::locale::*;
 use rpfm_ui_common::utils::*;
 
-use rpfm_lib::games::pfh_file_type::PFHFileType;
-use rpfm_lib::files::{FileType, RFile, pack::Pack};
+use rpfm_lib::files::{FileType, RFile};
 use rpfm_lib::games::GameInfo;
 
 use crate::ffi::*;
@@ -54,6 +63,8 @@ mod slots;
 const VIEW_DEBUG: &str = "ui_templates/filterable_reloadable_tree_widget.ui";
 const VIEW_RELEASE: &str = "ui/filterable_reloadable_tree_widget.ui";
 
+const DB_FOLDER: &str = "db/";
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -68,6 +79,17 @@ pub struct DataListUI {
     filter_case_sensitive_button: QPtr<QToolButton>,
     filter_timer: QBox<QTimer>,
     reload_button: QPtr<QToolButton>,
+    delta_button: QPtr<QToolButton>,
+
+    /// List of the packs that failed to load the last time the list was loaded, with their errors. Hidden if there are none.
+    pack_errors_list: QBox<QListWidget>,
+
+    /// Pack each file came from the last time the list was loaded, and the key of the game they belong to.
+    owners: RefCell<(String, HashMap<String, String>)>,
+
+    context_menu: QBox<QMenu>,
+    reveal_in_mod_list: QPtr<QAction>,
+    open_table_in_rpfm: QPtr<QAction>,
 }
 
 #[derive(Clone, Debug, Default, Getters)]
@@ -96,12 +118,21 @@ impl DataListUI {
         let filter_case_sensitive_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "filter_case_sensitive_button")?;
         let reload_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "reload_button")?;
         reload_button.set_tool_tip(&qtr("reload_data_view"));
+        let delta_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "delta_button")?;
+        delta_button.set_tool_tip(&qtr("data_view_delta"));
 
         // Replace the placeholder widget.
         let main_layout: QPtr<QGridLayout> = main_widget.layout().static_downcast();
         main_layout.replace_widget_2a(&tree_view_placeholder, &tree_view);
         tree_view_placeholder.delete();
 
+        // Packs that fail to load are listed below the tree, so they can be found without breaking the rest of the list.
+        let pack_errors_list = QListWidget::new_1a(&main_widget);
+        pack_errors_list.set_tool_tip(&qtr("data_list_pack_errors_tt"));
+        pack_errors_list.set_maximum_height(100);
+        pack_errors_list.set_visible(false);
+        main_layout.add_widget_5a(&pack_errors_list, 2, 0, 1, 4);
+
         let model = new_pack_list_model_safe(tree_view.static_upcast());
         let filter = pack_list_filter_safe(main_widget.static_upcast());
         filter.set_source_model(&model);
@@ -111,6 +142,12 @@ impl DataListUI {
         let filter_timer = QTimer::new_1a(&main_widget);
         filter_timer.set_single_shot(true);
 
+        // Context menu.
+        tree_view.set_context_menu_policy(ContextMenuPolicy::CustomContextMenu);
+        let context_menu = QMenu::from_q_widget(&main_widget);
+        let reveal_in_mod_list = context_menu.add_action_q_string(&qtr("reveal_in_mod_list"));
+        let open_table_in_rpfm = context_menu.add_action_q_string(&qtr("open_table_in_rpfm"));
+
         parent.add_tab_2a(&main_widget, &qtr("data_list_title"));
 
         let list = Rc::new(Self {
@@ -121,6 +158,14 @@ impl DataListUI {
             filter_case_sensitive_button,
             filter_timer,
             reload_button,
+            delta_button,
+            pack_errors_list,
+
+            owners: RefCell::new((String::new(), HashMap::new())),
+
+            context_menu,
+            reveal_in_mod_list,
+            open_table_in_rpfm,
         });
 
         list.set_enabled(false);
@@ -135,6 +180,7 @@ impl DataListUI {
         self.filter_line_edit().text_changed().connect(slots.filter_line_edit());
         self.filter_case_sensitive_button().toggled().connect(slots.filter_case_sensitive_button());
         self.filter_timer().timeout().connect(slots.filter_trigger());
+        self.tree_view().custom_context_menu_requested().connect(slots.context_menu());
     }
 
     pub unsafe fn set_enabled(&self, enable: bool) {
@@ -143,68 +189,35 @@ impl DataListUI {
         self.filter_case_sensitive_button().set_enabled(enable);
     }
 
-    pub fn generate_data(&self, game_config: &GameConfig, game: &GameInfo, game_path: &Path, load_order: &LoadOrder) -> Result<Pack> {
-
-        // Only load this if the game path is actually a path.
-        if game_path.exists() && game_path.is_dir() {
-
-            // Build the full pack list with the vanilla packs.
-            let vanilla_paths = game.ca_packs_paths(game_path)?;
-            let movie_paths = load_order.movies().iter()
-                .filter_map(|mod_id| game_config.mods().get(mod_id))
-                .filter_map(|modd| modd.paths().first())
-                .cloned()
-                .collect::<Vec<_>>();
-
-            let mut base_packs = vanilla_paths.iter().chain(movie_paths.iter())
-                .filter_map(|path| Pack::read_and_merge(&[path.to_path_buf()], true, false, false).ok())
-                .collect::<Vec<_>>();
-
-            base_packs.sort_by(|pack_a, pack_b| if pack_a.pfh_file_type() != pack_b.pfh_file_type() {
-                pack_a.pfh_file_type().cmp(&pack_b.pfh_file_type())
-            } else {
-                pack_a.disk_file_path().cmp(pack_b.disk_file_path())
-            });
-
-            // Generate the "merged pack" from the load order mods, and inject them into the full pack list.
-            let mut mod_packs_sorted = load_order.mods().iter()
-                .filter_map(|mod_id| load_order.packs().get(mod_id))
-                .cloned()
-                .collect::<Vec<_>>();
-
-            // If we have movie packs in the base ones, insert the mods before the movie packs.
-            //
-            // If not, insert them at the end of the list.
-            if let Some(pos) = base_packs.iter().position(|x| x.pfh_file_type() == PFHFileType::Movie) {
-                let mut movie_packs = base_packs.split_off(pos);
-                base_packs.append(&mut mod_packs_sorted);
-                base_packs.append(&mut movie_packs);
-            } else {
-                base_packs.append(&mut mod_packs_sorted);
-            };
-
-            let full_pack = Pack::merge(&base_packs)?;
-
-            Ok(full_pack)
-        } else {
-            Err(anyhow!("Game Path not found."))
-        }
-     }
-
     pub unsafe fn load(&self, game_config: &GameConfig, game: &GameInfo, game_path: &Path, load_order: &LoadOrder) -> Result<()> {
         self.tree_view.update_treeview(true, &mut TreeViewOperation::Clear);
+        self.load_pack_errors(&BTreeMap::new());
 
         self.setup_columns();
 
         // Only load this if the game path is actually a path.
         if game_path.exists() && game_path.is_dir() {
             self.set_enabled(true);
-            let full_pack = self.generate_data(game_config, game, game_path, load_order)?;
+            let (full_pack, pack_errors) = load_order.data_pack(game_config, game, game_path)?;
+            self.load_pack_errors(&pack_errors);
+
+            let owners = full_pack.files()
+                .values()
+                .filter_map(|file| file.container_name().as_ref().map(|container_name| (file.path_in_container_raw().to_owned(), container_name.to_owned())))
+                .collect::<HashMap<_, _>>();
 
             // Then, build the tree.
             let build_data = full_pack.files().par_iter().map(|(_, file)| From::from(file)).collect();
             self.tree_view.update_treeview(true, &mut TreeViewOperation::Build(build_data));
 
+            // Only compare against the last load if it was of the same game, or every file would show up as changed.
+            let mut last_owners = self.owners.borrow_mut();
+            if self.delta_button().is_checked() && last_owners.0 == game.key() && !last_owners.1.is_empty() {
+                self.highlight_owner_changes(&last_owners.1, &owners);
+            }
+
+            *last_owners = (game.key().to_owned(), owners);
+
             // Enlarge the first column if it's too small, and autoexpand the first node.
             if self.tree_view().column_width(0) < 300 {
                 self.tree_view().set_column_width(0, 300);
@@ -218,6 +231,20 @@ impl DataListUI {
         Ok(())
     }
 
+    /// This function fills the list of packs that failed to load, and shows it only if there is any.
+    unsafe fn load_pack_errors(&self, pack_errors: &BTreeMap<PathBuf, String>) {
+        self.pack_errors_list().clear();
+
+        for (path, error) in pack_errors {
+            let pack_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_else(|| path.to_string_lossy().to_string());
+            let item = QListWidgetItem::from_q_icon_q_string(&QIcon::from_theme_1a(&QString::from_std_str("dialog-error")), &qtre("data_list_pack_error", &[&pack_name, error]));
+            item.set_tool_tip(&QString::from_std_str(path.to_string_lossy()));
+            self.pack_errors_list().add_item_q_list_widget_item(item.into_ptr());
+        }
+
+        self.pack_errors_list().set_visible(!pack_errors.is_empty());
+    }
+
     /// This returns the selection REVERSED, FROM BOTTOM TO TOP.
     pub unsafe fn data_list_selection(&self) -> Vec<CppBox<QModelIndex>> {
         let indexes_visual = self.tree_view().selection_model().selection().indexes();
@@ -232,6 +259,83 @@ impl DataListUI {
         indexes_visual.iter().map(|x| self.filter().map_to_source(*x)).collect::<Vec<_>>()
     }
 
+    /// This function returns the path of the selected file or folder, if any.
+    pub unsafe fn selected_file_path(&self) -> Option<String> {
+        self.data_list_selection()
+            .iter()
+            .find(|index| index.column() == 0)
+            .map(|index| <QPtr<QTreeView> as PackTree>::get_path_from_index(index.as_ref(), self.model()))
+    }
+
+    /// This function returns if the selected file is a DB table from a known pack.
+    pub unsafe fn is_table_selected(&self) -> bool {
+        self.selected_pack_name().is_some() && self.selected_file_path().is_some_and(|path| path.starts_with(DB_FOLDER))
+    }
+
+    /// This function returns the name of the pack the selected file comes from, if any.
+    ///
+    /// Folders don't belong to a single pack, so they return none.
+    pub unsafe fn selected_pack_name(&self) -> Option<String> {
+        self.data_list_selection()
+            .iter()
+            .find_map(|index| {
+                let pack_name = index.sibling(index.row(), 1).data_0a().to_string().to_std_string();
+                if pack_name.is_empty() {
+                    None
+                } else {
+                    Some(pack_name)
+                }
+            })
+    }
+
+    /// This function highlights the files that come from a different pack than in the provided list of owners,
+    /// and expands the folders containing them, so the effects of a change in the load order can be seen at a glance.
+    unsafe fn highlight_owner_changes(&self, last_owners: &HashMap<String, String>, owners: &HashMap<String, String>) {
+        let root = self.model().item_1a(0);
+        if !root.is_null() {
+            self.highlight_owner_changes_in_item(root, "", last_owners, owners);
+        }
+    }
+
+    /// This function highlights the changed files under the provided item, returning how many of them are there.
+    unsafe fn highlight_owner_changes_in_item(&self, item: Ptr<QStandardItem>, path: &str, last_owners: &HashMap<String, String>, owners: &HashMap<String, String>) -> usize {
+        let mut changes = 0;
+
+        for row in 0..item.row_count() {
+            let child = item.child_2a(row, 0);
+            let child_path = if path.is_empty() {
+                child.text().to_std_string()
+            } else {
+                format!("{}/{}", path, child.text().to_std_string())
+            };
+
+            if child.has_children() {
+                changes += self.highlight_owner_changes_in_item(child, &child_path, last_owners, owners);
+                continue;
+            }
+
+            if let Some(last_owner) = last_owners.get(&child_path) {
+                if owners.get(&child_path) != Some(last_owner) {
+                    let pack = item.child_2a(row, 1);
+                    for changed in [child, pack] {
+                        let font = changed.font();
+                        font.set_bold(true);
+                        changed.set_font(&font);
+                        changed.set_tool_tip(&qtre("data_view_delta_previous_owner", &[last_owner]));
+                    }
+
+                    changes += 1;
+                }
+            }
+        }
+
+        if changes > 0 {
+            self.tree_view().expand(&self.filter().map_from_source(&item.index()));
+        }
+
+        changes
+    }
+
     pub unsafe fn setup_columns(&self) {
         self.model.set_column_count(2);
 
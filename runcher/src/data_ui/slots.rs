@@ -8,6 +8,10 @@
 // https://github.com/Frodo45127/runcher/blob/master/LICENSE.
 //---------------------------------------------------------------------------//
 
+use qt_widgets::SlotOfQPoint;
+
+use qt_gui::QCursor;
+
 use qt_core::QBox;
 use qt_core::{SlotNoArgs, SlotOfQString};
 
@@ -27,6 +31,7 @@ pub struct DataListUISlots {
     filter_line_edit: QBox<SlotOfQString>,
     filter_case_sensitive_button: QBox<SlotNoArgs>,
     filter_trigger: QBox<SlotNoArgs>,
+    context_menu: QBox<SlotOfQPoint>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -51,10 +56,18 @@ impl DataListUISlots {
             view.filter_list();
         }));
 
+        let context_menu = SlotOfQPoint::new(view.tree_view(), clone!(
+            view => move |_| {
+            view.reveal_in_mod_list.set_enabled(view.selected_pack_name().is_some());
+            view.open_table_in_rpfm.set_enabled(view.is_table_selected());
+            view.context_menu().exec_1a_mut(&QCursor::pos_0a());
+        }));
+
         Self {
             filter_line_edit,
             filter_case_sensitive_button,
             filter_trigger,
+            context_menu,
         }
     }
 }
@@ -124,3 +124,14 @@ pub fn draggable_tree_view_drop_signal(widget: QPtr<QWidget>) -> Signal<(*const
         )
     }
 }
+
+pub fn launcher_window_files_dropped_signal(widget: QPtr<QWidget>) -> Signal<(*const QStringList,)> {
+    unsafe {
+        Signal::new(
+            ::cpp_core::Ref::from_raw(widget.as_raw_ptr()).expect("attempted to construct a null Ref"),
+            ::std::ffi::CStr::from_bytes_with_nul_unchecked(
+                b"2filesDropped(QStringList const &)\0",
+            ),
+        )
+    }
+}
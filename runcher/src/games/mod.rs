@@ -14,15 +14,17 @@ use anyhow::Result;
 use rayon::prelude::*;
 
 use std::collections::{HashMap, HashSet};
+use std::fs::DirBuilder;
 use std::path::{PathBuf, Path};
 
 use rpfm_extensions::dependencies::Dependencies;
 use rpfm_extensions::optimizer::Optimizable;
 use rpfm_extensions::translator::*;
 
-use rpfm_lib::files::{Container, ContainerPath, EncodeableExtraData, FileType, loc::Loc, pack::Pack, RFile, RFileDecoded, table::DecodedData};
+use rpfm_lib::files::{Container, ContainerPath, DecodeableExtraData, EncodeableExtraData, FileType, loc::Loc, pack::Pack, RFile, RFileDecoded, table::DecodedData};
 use rpfm_lib::games::{*, pfh_file_type::PFHFileType, supported_games::*};
-use rpfm_lib::integrations::git::GitResponse;
+use rpfm_lib::integrations::{git::GitResponse, log::info};
+use rpfm_lib::schema::Schema;
 use rpfm_lib::utils::files_from_subdir;
 
 use rpfm_ui_common::locale::tre;
@@ -32,8 +34,13 @@ use rpfm_ui_common::utils::show_dialog;
 use crate::app_ui::AppUI;
 use crate::CENTRAL_COMMAND;
 use crate::communications::*;
+use crate::mod_manager::{game_quirks::game_quirks, offline::offline_mode, translations::newest_translation_paths};
 use crate::SCHEMA;
-use crate::settings_ui::{temp_packs_folder, translations_local_path, translations_remote_path};
+use crate::settings_ui::{temp_packs_folder, translations_local_path, translations_remote_path, unit_multiplier};
+
+const MOVIES_PATH: &str = "movies/";
+const VIDEOS_TABLES_PATH: &str = "db/videos_tables/";
+const VIDEOS_TABLES_NAME_COLUMN: &str = "video_name";
 
 const EMPTY_CA_VP8: [u8; 595] = [
     0x43, 0x41, 0x4d, 0x56, 0x01, 0x00, 0x29, 0x00, 0x56, 0x50, 0x38, 0x30, 0x80, 0x02, 0xe0, 0x01, 0x55, 0x55,
@@ -132,25 +139,23 @@ mod warhammer_3;
 //-------------------------------------------------------------------------------//
 
 pub unsafe fn prepare_launch_options(app_ui: &AppUI, game: &GameInfo, game_path: &Path, data_path: &Path, folder_list: &mut String) -> Result<()> {
-    let actions_ui = app_ui.actions_ui();
 
     // We only use the reserved pack if we need to.
-    if (actions_ui.enable_logging_checkbox().is_enabled() && actions_ui.enable_logging_checkbox().is_checked()) ||
-        (actions_ui.enable_skip_intro_checkbox().is_enabled() && actions_ui.enable_skip_intro_checkbox().is_checked()) ||
-        (actions_ui.enable_translations_combobox().is_enabled() && actions_ui.enable_translations_combobox().current_index() != 0) ||
-        (actions_ui.universal_rebalancer_combobox().is_enabled() && actions_ui.universal_rebalancer_combobox().current_index() != 0) ||
-        (actions_ui.unit_multiplier_spinbox().is_enabled() && actions_ui.unit_multiplier_spinbox().value() != 1.00) {
+    if launch_options_enabled(app_ui) {
 
         // We need to use an alternative name for Shogun 2, Rome 2, Attila and Thrones because their load order logic for movie packs seems... either different or broken.
-        let reserved_pack_name = if game.key() == KEY_SHOGUN_2 || game.key() == KEY_ROME_2 || game.key() == KEY_ATTILA || game.key() == KEY_THRONES_OF_BRITANNIA {
+        let quirks = game_quirks(game);
+        let reserved_pack_name = if *quirks.alternative_reserved_pack_name() {
             RESERVED_PACK_NAME_ALTERNATIVE
         } else {
             RESERVED_PACK_NAME
         };
 
         // If the reserved pack is loaded from a custom folder we need to CLEAR SAID FOLDER before anything else. Otherwise we may end up with old packs messing up stuff.
-        if *game.raw_db_version() >= 1 {
+        if *quirks.supports_working_directories() {
             let temp_packs_folder = temp_packs_folder(&game)?;
+            DirBuilder::new().recursive(true).create(&temp_packs_folder)?;
+
             let files = files_from_subdir(&temp_packs_folder, false)?;
             for file in &files {
                 std::fs::remove_file(file)?;
@@ -158,7 +163,7 @@ pub unsafe fn prepare_launch_options(app_ui: &AppUI, game: &GameInfo, game_path:
         }
 
         // Support for add_working_directory seems to be only present in rome 2 and newer games. For older games, we drop the pack into /data.
-        let temp_path = if *game.raw_db_version() >= 1 {
+        let temp_path = if *quirks.supports_working_directories() {
             let temp_packs_folder = temp_packs_folder(&game)?;
             let temp_path = temp_packs_folder.join(reserved_pack_name);
             folder_list.push_str(&format!("add_working_directory \"{}\";\n", temp_packs_folder.to_string_lossy()));
@@ -167,69 +172,82 @@ pub unsafe fn prepare_launch_options(app_ui: &AppUI, game: &GameInfo, game_path:
             data_path.join(reserved_pack_name)
         };
 
-        // Generate the reserved pack.
-        //
-        // Note: It has to be a movie pack because otherwise we cannot overwrite the intro files in older games.
-        let pack_version = game.pfh_version_by_file_type(PFHFileType::Movie);
-        let mut reserved_pack = Pack::new_with_version(pack_version);
-        reserved_pack.set_pfh_file_type(PFHFileType::Movie);
-
-        // These are often used for editing tables. We cache them here instead of remaking them in every launch option.
-        let mut vanilla_pack = Pack::read_and_merge_ca_packs(game, game_path)?;
-        let paths = (0..app_ui.pack_list_ui().model().row_count_0a())
-            .map(|index| PathBuf::from(app_ui.pack_list_ui().model().item_2a(index, 2).text().to_std_string()))
-            .collect::<Vec<_>>();
-
-        let mut modded_pack = if !paths.is_empty() {
-            Pack::read_and_merge(&paths, true, false, true)?
-        } else {
-            Pack::default()
-        };
+        save_tweaks_pack(app_ui, game, game_path, &temp_path, PFHFileType::Movie)?;
+    }
 
-        // Skip videos.
-        prepare_skip_intro_videos(app_ui, &game, &mut reserved_pack, &mut vanilla_pack, &mut modded_pack)?;
+    Ok(())
+}
 
-        // Logging.
-        prepare_script_logging(app_ui, &game, &mut reserved_pack)?;
+/// This function returns if any of the launch options that need the reserved pack is enabled.
+pub unsafe fn launch_options_enabled(app_ui: &AppUI) -> bool {
+    let actions_ui = app_ui.actions_ui();
+    (actions_ui.enable_logging_checkbox().is_enabled() && actions_ui.enable_logging_checkbox().is_checked()) ||
+        (actions_ui.enable_skip_intro_checkbox().is_enabled() && actions_ui.enable_skip_intro_checkbox().is_checked()) ||
+        (actions_ui.enable_translations_combobox().is_enabled() && actions_ui.enable_translations_combobox().current_index() != 0) ||
+        (actions_ui.universal_rebalancer_combobox().is_enabled() && actions_ui.universal_rebalancer_combobox().current_index() != 0) ||
+        (actions_ui.unit_multiplier_spinbox().is_enabled() && actions_ui.unit_multiplier_spinbox().value() != 1.00)
+}
 
-        // Trait limit removal.
-        prepare_trait_limit_removal(app_ui, &game, &mut reserved_pack, &mut vanilla_pack, &mut modded_pack)?;
+/// This function generates a pack with the tweaks of the enabled launch options, and saves it to the provided path.
+///
+/// This is the reserved pack when launching, but it can also be saved as a mod pack, so it can be used without Runcher.
+///
+/// Note: the reserved pack has to be a movie pack because otherwise we cannot overwrite the intro files in older games.
+pub unsafe fn save_tweaks_pack(app_ui: &AppUI, game: &GameInfo, game_path: &Path, path: &Path, pfh_file_type: PFHFileType) -> Result<()> {
+    let quirks = game_quirks(game);
 
-        // Translations.
-        prepare_translations(app_ui, &game, &mut reserved_pack)?;
+    let pack_version = game.pfh_version_by_file_type(pfh_file_type);
+    let mut reserved_pack = Pack::new_with_version(pack_version);
+    reserved_pack.set_pfh_file_type(pfh_file_type);
 
-        // Unit multiplier.
-        prepare_unit_multiplier(app_ui, &game, &mut reserved_pack, &mut vanilla_pack, &mut modded_pack)?;
+    // These are often used for editing tables. We cache them here instead of remaking them in every launch option.
+    let mut vanilla_pack = Pack::read_and_merge_ca_packs(game, game_path)?;
+    let paths = app_ui.pack_list_ui().mod_pack_paths();
 
-        // Universal rebalancer.
-        prepare_universal_rebalancer(app_ui, &game, &mut reserved_pack, &mut vanilla_pack, &mut modded_pack, &paths)?;
+    let mut modded_pack = if !paths.is_empty() {
+        Pack::read_and_merge(&paths, true, false, true)?
+    } else {
+        Pack::default()
+    };
 
-        let mut encode_data = EncodeableExtraData::default();
-        encode_data.set_nullify_dates(true);
+    // Skip videos.
+    prepare_skip_intro_videos(app_ui, game, &mut reserved_pack, &mut vanilla_pack, &mut modded_pack)?;
 
-        // Set the dependencies to be the entire load order. Fake for older games because it seems to crash for them.
-        //
-        // Real for newer games, as they crash if the dependencies are not set correctly.
-        //
-        // NOTE: Warhammer 1 may need to be here too.
-        if game.key() != KEY_EMPIRE &&
-            game.key() != KEY_NAPOLEON &&
-            game.key() != KEY_SHOGUN_2 &&
-            game.key() != KEY_ROME_2 &&
-            game.key() != KEY_ATTILA &&
-            game.key() != KEY_THRONES_OF_BRITANNIA {
-            let pack_names = paths.iter().map(|path| (true, path.file_name().unwrap().to_string_lossy().to_string())).collect::<Vec<_>>();
-            reserved_pack.set_dependencies(pack_names);
-        } else {
-            let pack_names = paths.iter().map(|path| (false, path.file_name().unwrap().to_string_lossy().to_string())).collect::<Vec<_>>();
-            reserved_pack.set_dependencies(pack_names);
-        }
+    // Logging.
+    prepare_script_logging(app_ui, game, &mut reserved_pack)?;
+
+    // Trait limit removal.
+    prepare_trait_limit_removal(app_ui, game, &mut reserved_pack, &mut vanilla_pack, &mut modded_pack)?;
+
+    // Translations.
+    prepare_translations(app_ui, game, &mut reserved_pack)?;
+
+    // Unit multiplier.
+    prepare_unit_multiplier(app_ui, game, &mut reserved_pack, &mut vanilla_pack, &mut modded_pack)?;
+
+    // Universal rebalancer.
+    prepare_universal_rebalancer(app_ui, game, &mut reserved_pack, &mut vanilla_pack, &mut modded_pack, &paths)?;
 
-        reserved_pack.save(Some(&temp_path), &game, &Some(encode_data))?;
+    let mut encode_data = EncodeableExtraData::default();
+    encode_data.set_nullify_dates(true);
+
+    // Set the dependencies to be the entire load order. Fake for older games because it seems to crash for them.
+    //
+    // Real for newer games, as they crash if the dependencies are not set correctly.
+    //
+    // NOTE: Warhammer 1 may need to be here too.
+    if *quirks.real_pack_dependencies() {
+        let pack_names = paths.iter().map(|path| (true, path.file_name().unwrap().to_string_lossy().to_string())).collect::<Vec<_>>();
+        reserved_pack.set_dependencies(pack_names);
+    } else {
+        let pack_names = paths.iter().map(|path| (false, path.file_name().unwrap().to_string_lossy().to_string())).collect::<Vec<_>>();
+        reserved_pack.set_dependencies(pack_names);
     }
 
+    reserved_pack.save(Some(path), game, &Some(encode_data))?;
     Ok(())
 }
+
 pub unsafe fn setup_actions(app_ui: &AppUI, game: &GameInfo, game_path: &Path) {
 
     // The blockers are needed to avoid issues with game change causing incorrect status to be saved.
@@ -240,6 +258,7 @@ pub unsafe fn setup_actions(app_ui: &AppUI, game: &GameInfo, game_path: &Path) {
     app_ui.actions_ui().profile_save_button().block_signals(true);
     app_ui.actions_ui().profile_manager_button().block_signals(true);
     app_ui.actions_ui().profile_combobox().block_signals(true);
+    app_ui.actions_ui().load_order_slot_combobox().block_signals(true);
 
     app_ui.actions_ui().enable_logging_checkbox().block_signals(true);
     app_ui.actions_ui().enable_skip_intro_checkbox().block_signals(true);
@@ -260,8 +279,15 @@ pub unsafe fn setup_actions(app_ui: &AppUI, game: &GameInfo, game_path: &Path) {
     app_ui.actions_ui().profile_save_button().set_enabled(path_is_valid);
     app_ui.actions_ui().profile_manager_button().set_enabled(path_is_valid);
     app_ui.actions_ui().profile_combobox().set_enabled(path_is_valid);
+    app_ui.actions_ui().load_order_slot_combobox().set_enabled(path_is_valid);
+    app_ui.actions_ui().load_order_slot_load_button().set_enabled(path_is_valid);
+    app_ui.actions_ui().load_order_slot_save_button().set_enabled(path_is_valid);
+    app_ui.actions_ui().load_order_slot_delete_button().set_enabled(path_is_valid);
     app_ui.actions_ui().save_combobox().set_enabled(path_is_valid);
 
+    // Map bins are only used by Shogun 2.
+    app_ui.mod_list_ui().export_map_bin().set_visible(game.key() == KEY_SHOGUN_2);
+
     if path_is_valid {
 
         // Only set enabled the launch options that work for the current game.
@@ -410,14 +436,8 @@ pub unsafe fn setup_actions(app_ui: &AppUI, game: &GameInfo, game_path: &Path) {
         app_ui.actions_ui().enable_skip_intro_checkbox().set_checked(setting_bool(&format!("enable_skip_intros_{}", game.key())));
         app_ui.actions_ui().remove_trait_limit_checkbox().set_checked(setting_bool(&format!("remove_trait_limit_{}", game.key())));
         app_ui.actions_ui().merge_all_mods_checkbox().set_checked(setting_bool(&format!("merge_all_mods_{}", game.key())));
-        app_ui.actions_ui().unit_multiplier_spinbox().set_value({
-            let value = setting_f32(&format!("unit_multiplier_{}", game.key()));
-            if value == 0.00 {
-                1.00
-            } else {
-                value
-            }
-        } as f64);
+        app_ui.actions_ui().update_unit_multiplier_format();
+        app_ui.actions_ui().unit_multiplier_spinbox().set_value(unit_multiplier(game.key()));
 
         // Populate the list of translations depending on what local_XX packs the game has.
         app_ui.actions_ui().enable_translations_combobox().clear();
@@ -477,6 +497,9 @@ pub unsafe fn setup_actions(app_ui: &AppUI, game: &GameInfo, game_path: &Path) {
         }
     }
 
+    // Profiles can only be created from saves in games where we can select saves.
+    app_ui.actions_ui().profile_from_save_button().set_enabled(app_ui.actions_ui().save_combobox().is_enabled());
+
     // Unblock all blocked signals.
     app_ui.actions_ui().play_button().block_signals(false);
     app_ui.actions_ui().copy_load_order_button().block_signals(false);
@@ -485,6 +508,7 @@ pub unsafe fn setup_actions(app_ui: &AppUI, game: &GameInfo, game_path: &Path) {
     app_ui.actions_ui().profile_save_button().block_signals(false);
     app_ui.actions_ui().profile_manager_button().block_signals(false);
     app_ui.actions_ui().profile_combobox().block_signals(false);
+    app_ui.actions_ui().load_order_slot_combobox().block_signals(false);
 
     app_ui.actions_ui().enable_logging_checkbox().block_signals(false);
     app_ui.actions_ui().enable_skip_intro_checkbox().block_signals(false);
@@ -549,7 +573,7 @@ pub unsafe fn prepare_script_logging(app_ui: &AppUI, game: &GameInfo, reserved_p
 
 pub unsafe fn prepare_skip_intro_videos(app_ui: &AppUI, game: &GameInfo, reserved_pack: &mut Pack, vanilla_pack: &mut Pack, modded_pack: &mut Pack) -> Result<()> {
     if app_ui.actions_ui().enable_skip_intro_checkbox().is_enabled() && app_ui.actions_ui().enable_skip_intro_checkbox().is_checked() {
-        match game.key() {
+        let result = match game.key() {
             KEY_PHARAOH | KEY_PHARAOH_DYNASTIES => match *SCHEMA.read().unwrap() {
                 Some(ref schema) => pharaoh::prepare_skip_intro_videos(game, reserved_pack, vanilla_pack, modded_pack, schema),
                 None => Ok(())
@@ -569,12 +593,113 @@ pub unsafe fn prepare_skip_intro_videos(app_ui: &AppUI, game: &GameInfo, reserve
             KEY_NAPOLEON => napoleon::prepare_skip_intro_videos(reserved_pack),
             KEY_EMPIRE => empire::prepare_skip_intro_videos(reserved_pack),
             _ => Ok(())
+        };
+
+        result?;
+
+        // Intros added by DLCs and patches are registered in the frontend video tables, so we can find them in the vanilla packs.
+        // For the ones we cannot find, users can add their own.
+        let mut paths = match *SCHEMA.read().unwrap() {
+            Some(ref schema) => discover_intro_videos(reserved_pack, vanilla_pack, schema),
+            None => vec![],
+        };
+
+        paths.extend(user_intro_videos(game.key()));
+
+        for path in paths {
+            if reserved_pack.files().contains_key(&path) {
+                continue;
+            }
+
+            let data = if path.ends_with(".bik") {
+                &EMPTY_BIK[..]
+            } else if path.ends_with(".ca_vp8") {
+                &EMPTY_CA_VP8[..]
+            } else {
+                continue;
+            };
+
+            let file = RFile::new_from_vec(data, FileType::Video, 0, &path);
+            reserved_pack.files_mut().insert(path, file);
         }
+
+        Ok(())
     } else {
         Ok(())
     }
 }
 
+/// This function returns the vanilla videos registered in the frontend video tables as more parts of the intros already skipped in the provided pack.
+///
+/// Numbered intros, like `startup_movie_05`, get new entries with new numbers when a DLC adds its own, so we read the video names
+/// from the `videos_tables` of the vanilla packs, keep the ones that share name with an intro we skip but with a different number,
+/// and skip the movie files they point to. Intros without a number are not extended, as there's nothing telling us what else belongs to them.
+fn discover_intro_videos(reserved_pack: &Pack, vanilla_pack: &Pack, schema: &Schema) -> Vec<String> {
+    let intro_prefixes = reserved_pack.files()
+        .keys()
+        .filter_map(|path| {
+            let stem = path.rsplit_once('.')?.0.rsplit('/').next()?;
+            let prefix = stem.trim_end_matches(|char: char| char.is_ascii_digit());
+            if prefix.len() == stem.len() {
+                return None;
+            }
+
+            Some(prefix.to_lowercase())
+        })
+        .collect::<HashSet<_>>();
+
+    if intro_prefixes.is_empty() {
+        return vec![];
+    }
+
+    let mut dec_extra_data = DecodeableExtraData::default();
+    dec_extra_data.set_schema(Some(schema));
+    let dec_extra_data = Some(dec_extra_data);
+
+    let mut video_names = HashSet::new();
+    for table in vanilla_pack.files_by_path(&ContainerPath::Folder(VIDEOS_TABLES_PATH.to_owned()), true) {
+        let mut table = table.clone();
+        if let Ok(Some(RFileDecoded::DB(data))) = table.decode(&dec_extra_data, false, true) {
+            if let Some(column) = data.definition().column_position_by_name(VIDEOS_TABLES_NAME_COLUMN) {
+                for row in data.data().iter() {
+                    if let Some(DecodedData::StringU8(name) | DecodedData::StringU16(name)) = row.get(column) {
+                        video_names.insert(name.to_lowercase());
+                    }
+                }
+            }
+        }
+    }
+
+    let intro_names = video_names.into_iter()
+        .filter(|name| intro_prefixes.iter().any(|prefix| name.rsplit('/').next().unwrap_or_default().strip_prefix(prefix.as_str())
+            .is_some_and(|number| !number.is_empty() && number.chars().all(|char| char.is_ascii_digit()))
+        ))
+        .collect::<HashSet<_>>();
+
+    // Video names are the path of the movie inside the movies folder, without extension.
+    vanilla_pack.files()
+        .keys()
+        .filter_map(|path| {
+            let path = path.to_lowercase();
+            let name = path.strip_prefix(MOVIES_PATH)?.rsplit_once('.')?.0;
+            if intro_names.contains(name) {
+                Some(path.to_owned())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// This function returns the videos the user wants to skip on the provided game, in addition to the ones we know about.
+fn user_intro_videos(game_key: &str) -> Vec<String> {
+    setting_string(&format!("skip_intro_videos_{game_key}"))
+        .split(';')
+        .map(|path| path.trim().replace('\\', "/").trim_start_matches('/').to_lowercase())
+        .filter(|path| !path.is_empty())
+        .collect()
+}
+
 pub unsafe fn prepare_trait_limit_removal(app_ui: &AppUI, game: &GameInfo, reserved_pack: &mut Pack, vanilla_pack: &mut Pack, modded_pack: &mut Pack) -> Result<()> {
     match *SCHEMA.read().unwrap() {
         Some(ref schema) => {
@@ -602,6 +727,35 @@ pub unsafe fn prepare_trait_limit_removal(app_ui: &AppUI, game: &GameInfo, reser
     }
 }
 
+/// This function updates our copy of the translation hub, if it has new translations.
+///
+/// Returns true if the translations were updated.
+pub fn update_translations_repo() -> Result<bool> {
+    let receiver = CENTRAL_COMMAND.send_network(Command::CheckTranslationsUpdates);
+    let response_thread = CENTRAL_COMMAND.recv_try(&receiver);
+    match response_thread {
+        Response::APIResponseGit(ref response) => {
+            match response {
+                GitResponse::NewUpdate |
+                GitResponse::NoLocalFiles |
+                GitResponse::Diverged => {
+                    let receiver = CENTRAL_COMMAND.send_background(Command::UpdateTranslations);
+                    let response_thread = CENTRAL_COMMAND.recv_try(&receiver);
+                    match response_thread {
+                        Response::Success => Ok(true),
+                        Response::Error(error) => Err(error),
+                        _ => panic!("{THREADS_COMMUNICATION_ERROR}{response_thread:?}"),
+                    }
+                }
+                GitResponse::NoUpdate => Ok(false),
+            }
+        }
+
+        Response::Error(error) => Err(error),
+        _ => panic!("{THREADS_COMMUNICATION_ERROR}{response_thread:?}"),
+    }
+}
+
 /// All total war games use the same translation system.
 ///
 /// The only particularity is that all games before warhammer 1 need to merge all translations into a localisation.loc file.
@@ -627,31 +781,13 @@ pub unsafe fn prepare_translations(app_ui: &AppUI, game: &GameInfo, reserved_pac
     // TODO: Troy has a weird translation system. Check that it works, and check pharaoh too.
     if app_ui.actions_ui().enable_translations_combobox().is_enabled() && app_ui.actions_ui().enable_translations_combobox().current_index() != 0 {
 
-        // Check if the repo needs updating, and update it if so.
-        let receiver = CENTRAL_COMMAND.send_network(Command::CheckTranslationsUpdates);
-        let response_thread = CENTRAL_COMMAND.recv_try(&receiver);
-        match response_thread {
-            Response::APIResponseGit(ref response) => {
-                match response {
-                    GitResponse::NewUpdate |
-                    GitResponse::NoLocalFiles |
-                    GitResponse::Diverged => {
-                        let receiver = CENTRAL_COMMAND.send_background(Command::UpdateTranslations);
-                        let response_thread = CENTRAL_COMMAND.recv_try(&receiver);
-
-                        // Show the error, but continue anyway.
-                        if let Response::Error(error) = response_thread {
-                            show_dialog(app_ui.main_window(), tre("translation_download_error", &[&error.to_string()]), false);
-                        }
-                    }
-                    GitResponse::NoUpdate => {}
-                }
-            }
-
-            Response::Error(error) => {
-                show_dialog(app_ui.main_window(), tre("translation_download_error", &[&error.to_string()]), false);
-            }
-            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response_thread:?}"),
+        // Check if the repo needs updating, and update it if so. Show the error, but continue anyway.
+        //
+        // Offline, we just use the translations we already have.
+        if offline_mode().is_some() {
+            info!("Offline mode active. Skipping the translations update.");
+        } else if let Err(error) = update_translations_repo() {
+            show_dialog(app_ui.main_window(), tre("translation_download_error", &[&error.to_string()]), false);
         }
 
         // Get the paths. Local has priority over remote, so it goes first.
@@ -666,9 +802,7 @@ pub unsafe fn prepare_translations(app_ui: &AppUI, game: &GameInfo, reserved_pac
 
         if !paths.is_empty() {
             let language = app_ui.actions_ui().enable_translations_combobox().current_text().to_std_string();
-            let mut pack_paths = (0..app_ui.pack_list_ui().model().row_count_0a())
-                .map(|index| PathBuf::from(app_ui.pack_list_ui().model().item_2a(index, 2).text().to_std_string()))
-                .collect::<Vec<_>>();
+            let mut pack_paths = app_ui.pack_list_ui().mod_pack_paths();
 
             // Reversed so we just get the higher priority stuff at the end, overwriting the rest.
             pack_paths.sort();
@@ -691,7 +825,13 @@ pub unsafe fn prepare_translations(app_ui: &AppUI, game: &GameInfo, reserved_pac
                 if let Some(ref pack_name) = pack_path.file_name().map(|name| name.to_string_lossy().to_string()) {
                     let mut translation_found = false;
 
-                    if let Ok(tr) = PackTranslation::load(&paths, pack_name, game.key(), &language) {
+                    // If both folders have a translation for the pack, use the newest one.
+                    let translation_paths = newest_translation_paths(game.key(), pack_name, &language)
+                        .into_iter()
+                        .map(|(_, path)| path)
+                        .collect::<Vec<_>>();
+
+                    if let Ok(tr) = PackTranslation::load(&translation_paths, pack_name, game.key(), &language) {
                         for tr in tr.translations().values() {
 
                             // Only add entries for values we actually have translated and up to date.
@@ -0,0 +1,186 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the loader of community locales.
+//!
+//! Translations of Runcher can be dropped as `.ftl` files into the `locale` folder in Runcher's config folder, so they don't need
+//! a rebuild and survive updates. The bundled locales live in the assets folder, which is read-only in installed builds, so community
+//! locales are loaded from the config folder as the fallback locale, with the English keys they're missing added to them. This means
+//! a community locale with the same name as a bundled one only fills the keys the bundled one is missing.
+
+use anyhow::Result;
+
+use std::collections::HashSet;
+use std::fs::{read_dir, read_to_string, DirBuilder};
+use std::path::PathBuf;
+
+use rpfm_lib::integrations::log::*;
+
+use rpfm_ui_common::ASSETS_PATH;
+use rpfm_ui_common::locale::{Locale, FALLBACK_LOCALE};
+use rpfm_ui_common::settings::{config_path, setting_string};
+
+const LOCALE_FOLDER: &str = "locale";
+const LOCALE_EXTENSION: &str = "ftl";
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function returns the path of the folder where users can drop their own locales. It's created if it doesn't exist.
+pub fn user_locale_path() -> Result<PathBuf> {
+    let path = config_path()?.join(LOCALE_FOLDER);
+    DirBuilder::new().recursive(true).create(&path)?;
+    Ok(path)
+}
+
+/// This function returns the file names (Language_id) of the locales in the user's locale folder.
+///
+/// Locales need to be named like the bundled ones, or they're not detected.
+pub fn user_locales() -> Vec<String> {
+    let path = match config_path() {
+        Ok(path) => path.join(LOCALE_FOLDER),
+        Err(_) => return vec![],
+    };
+
+    let mut locales = match read_dir(path) {
+        Ok(entries) => entries.filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file() && path.extension().map(|extension| extension == LOCALE_EXTENSION).unwrap_or(false))
+            .filter_map(|path| path.file_stem().map(|name| name.to_string_lossy().to_string()))
+            .filter(|name| name.split('_').filter(|part| !part.is_empty()).count() == 2)
+            .collect::<Vec<_>>(),
+        Err(_) => vec![],
+    };
+
+    locales.sort();
+    locales
+}
+
+/// This function returns the visible name and the file name of every locale that can be selected, bundled ones first.
+///
+/// Community locales with the same name as a bundled one are not listed again.
+pub fn available_locales() -> Vec<(String, String)> {
+    let mut locales = Locale::get_available_locales()
+        .map(|locales| locales.iter()
+            .map(|(language, locale)| (language.to_owned(), format!("{}_{}", language, locale.language)))
+            .collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    for file_name in user_locales() {
+        let language = file_name.split('_').next().unwrap_or_default().to_owned();
+        if locales.iter().all(|(name, _)| name != &language) {
+            locales.push((language, file_name));
+        }
+    }
+
+    locales
+}
+
+/// This function loads the selected locale from the user's locale folder, if there's one with its name. Returns its name if it was loaded.
+///
+/// This must be called after the fallback locale is set and before the locale is first used, as the fallback locale is only loaded once.
+pub fn load_user_locale(fallback: &str) -> Result<Option<String>> {
+    let language = setting_string("language");
+    let path = config_path()?.join(LOCALE_FOLDER).join(format!("{language}.{LOCALE_EXTENSION}"));
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    let data = read_to_string(path)?;
+    *FALLBACK_LOCALE.write().unwrap() = merge_with_fallback(&data, fallback);
+
+    Ok(Some(language))
+}
+
+/// This function logs the keys missing from the selected locale, which are shown using the fallback one.
+pub fn report_missing_keys(fallback: &str) {
+    let language = setting_string("language");
+    match read_to_string(locale_path(&language)) {
+        Ok(data) => {
+            let missing = missing_keys(&data, fallback);
+            if !missing.is_empty() {
+                warn!("Locale {} is missing {} keys, which will be shown in English: {}", language, missing.len(), missing.join(", "));
+            }
+        }
+        Err(error) => warn!("Error reading the locale {}: {}", language, error),
+    }
+}
+
+/// This function returns the keys of the fallback locale missing from the provided one, sorted.
+pub fn missing_keys(locale: &str, fallback: &str) -> Vec<String> {
+    let locale_keys = keys(locale);
+    let mut missing = keys(fallback)
+        .into_iter()
+        .filter(|key| !locale_keys.contains(key))
+        .collect::<Vec<_>>();
+
+    missing.sort();
+    missing
+}
+
+/// This function returns the percentage of translated keys of the provided bundled or installed locale, if it can be read.
+pub fn locale_completeness(file_name: &str, fallback: &str) -> Option<f64> {
+    let data = read_to_string(locale_path(file_name)).ok()?;
+    Some(completeness(&data, fallback))
+}
+
+/// This function returns the path of the provided locale, preferring the one in the user's locale folder.
+fn locale_path(file_name: &str) -> PathBuf {
+    let file_name = format!("{file_name}.{LOCALE_EXTENSION}");
+    config_path()
+        .map(|path| path.join(LOCALE_FOLDER).join(&file_name))
+        .ok()
+        .filter(|path| path.is_file())
+        .unwrap_or_else(|| ASSETS_PATH.join(LOCALE_FOLDER).join(&file_name))
+}
+
+/// This function returns the provided locale with the messages of the fallback one it's missing appended to it.
+///
+/// A message spans from its key line to the next line that starts a new entry, so multiline values are copied whole.
+fn merge_with_fallback(locale: &str, fallback: &str) -> String {
+    let missing = missing_keys(locale, fallback).into_iter().collect::<HashSet<_>>();
+    let mut merged = locale.trim_end().to_owned();
+    merged.push('\n');
+
+    let mut copying = false;
+    for line in fallback.lines() {
+        if line.starts_with(|char: char| char.is_ascii_alphabetic() || char == '#' || char == '-') {
+            copying = line.split_once('=').map(|(key, _)| missing.contains(key.trim())).unwrap_or(false);
+        }
+
+        if copying {
+            merged.push_str(line);
+            merged.push('\n');
+        }
+    }
+
+    merged
+}
+
+/// This function returns the percentage of keys of the fallback locale the provided one has.
+fn completeness(locale: &str, fallback: &str) -> f64 {
+    let total = keys(fallback).len();
+    if total == 0 {
+        return 100.0;
+    }
+
+    (total - missing_keys(locale, fallback).len()) as f64 * 100.0 / total as f64
+}
+
+/// Message keys are the only lines of a Fluent file that start with an identifier. Values spanning multiple lines are indented,
+/// attributes start with a dot, terms with a dash and comments with a hash.
+fn keys(data: &str) -> HashSet<String> {
+    data.lines()
+        .filter(|line| line.starts_with(|char: char| char.is_ascii_alphabetic()))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, _)| key.trim().to_owned())
+        .collect()
+}
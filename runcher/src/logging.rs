@@ -0,0 +1,359 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing Runcher's logger.
+//!
+//! Logs are written to the terminal and to `runcher.log` in the error folder, filtered by a global level
+//! and optional per-module levels configured in the settings. Errors are also forwarded to Sentry, if it's enabled.
+
+use qt_core::q_variant::Type;
+use qt_core::QString;
+
+use anyhow::Result;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use sentry_log::SentryLogger;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use zip::{CompressionMethod, write::SimpleFileOptions, ZipWriter};
+
+use std::fs::{File, read_dir, remove_file};
+use std::io::{BufWriter, LineWriter, Write};
+use std::path::PathBuf;
+use std::sync::{Mutex, RwLock};
+
+use rpfm_lib::integrations::log::SENTRY_DSN;
+use rpfm_lib::utils::files_from_subdir;
+
+use rpfm_ui_common::settings::*;
+
+use crate::{SENTRY_DSN_KEY, SENTRY_GUARD, SUPPORTED_GAMES};
+use crate::mod_manager::game_definitions::base_game_key;
+use crate::mod_manager::log_patterns::LogPatterns;
+use crate::settings_ui::game_config_path;
+
+pub const LOG_FILE_NAME: &str = "runcher.log";
+const OLD_LOG_FILE_NAME: &str = "runcher.old.log";
+const DIAGNOSTIC_BUNDLE_FILE_NAME: &str = "runcher_diagnostic_bundle.zip";
+pub const DIAGNOSTIC_BUNDLE_SETTINGS_FILE_NAME: &str = "settings.txt";
+pub const DIAGNOSTIC_BUNDLE_GAME_CONFIG_FOLDER: &str = "game_config/";
+pub const DIAGNOSTIC_BUNDLE_GAME_LOGS_FOLDER: &str = "game_logs/";
+
+/// Max amount of script logs of the game included in diagnostic bundles, newest first.
+const DIAGNOSTIC_BUNDLE_MAX_GAME_LOGS: usize = 3;
+
+/// Value of a per-module log level that uses the global one.
+pub const LOG_LEVEL_DEFAULT: &str = "default";
+
+/// Log levels that can be selected in the settings, from less to more verbose.
+pub const LOG_LEVELS: [&str; 6] = ["off", "error", "warn", "info", "debug", "trace"];
+
+/// Modules whose log level can be configured independently from the global one.
+pub const LOG_MODULES: [&str; 6] = [
+    "runcher",
+    "runcher::app_ui",
+    "runcher::mod_manager",
+    "runcher::network_thread",
+    "runcher::background_thread",
+    "rpfm_lib",
+];
+
+/// Settings that must never leave the user's computer.
+const SENSITIVE_SETTINGS: [&str; 1] = ["steam_api_key"];
+
+/// Levels currently in use by the logger.
+static LOG_FILTERS: RwLock<LogFilters> = RwLock::new(LogFilters {
+    global: LevelFilter::Info,
+    modules: Vec::new(),
+});
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+struct LogFilters {
+    global: LevelFilter,
+
+    /// Per-module levels, sorted from more to less specific.
+    modules: Vec<(String, LevelFilter)>,
+}
+
+struct RuncherLogger {
+    file: Mutex<LineWriter<File>>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl LogFilters {
+
+    /// This function returns the level that applies to the provided target.
+    fn level(&self, target: &str) -> LevelFilter {
+        self.modules.iter()
+            .find(|(module, _)| target == module || (target.starts_with(module.as_str()) && target[module.len()..].starts_with("::")))
+            .map(|(_, level)| *level)
+            .unwrap_or(self.global)
+    }
+
+    /// This function returns the most verbose level in use.
+    fn max_level(&self) -> LevelFilter {
+        self.modules.iter()
+            .map(|(_, level)| *level)
+            .fold(self.global, |max, level| max.max(level))
+    }
+}
+
+impl Log for RuncherLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        LOG_FILTERS.read()
+            .map(|filters| metadata.level() <= filters.level(metadata.target()))
+            .unwrap_or(false)
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let date = OffsetDateTime::now_utc().format(&Rfc3339).unwrap_or_default();
+            let line = format!("{} [{}] {}: {}\n", date, record.level(), record.target(), record.args());
+
+            eprint!("{}", line);
+            if let Ok(mut file) = self.file.lock() {
+                let _ = file.write_all(line.as_bytes());
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.flush();
+        }
+    }
+}
+
+/// This function initializes the logger. It must be called only once, before any thread starts logging.
+///
+/// The log of the previous session is kept as `runcher.old.log`, as it's usually the one users want to report.
+pub unsafe fn init_logger() -> Result<()> {
+    let path = log_file_path()?;
+    if path.is_file() {
+        std::fs::rename(&path, error_path()?.join(OLD_LOG_FILE_NAME))?;
+    }
+
+    let logger = RuncherLogger {
+        file: Mutex::new(LineWriter::new(File::create(&path)?)),
+    };
+
+    reload_log_levels();
+    log::set_boxed_logger(Box::new(SentryLogger::with_dest(logger)))?;
+
+    Ok(())
+}
+
+/// This function reloads the log levels from the settings.
+pub unsafe fn reload_log_levels() {
+    let q_settings = settings();
+    let global = parse_level(&setting_string_from_q_setting(&q_settings, "log_level")).unwrap_or(LevelFilter::Info);
+
+    let mut modules = LOG_MODULES.iter()
+        .filter_map(|module| parse_level(&setting_string_from_q_setting(&q_settings, &log_level_setting_key(module)))
+            .map(|level| (module.to_string(), level))
+        )
+        .collect::<Vec<_>>();
+
+    // Longer names first, so submodules take priority over their parents.
+    modules.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()));
+
+    if let Ok(mut filters) = LOG_FILTERS.write() {
+        filters.global = global;
+        filters.modules = modules;
+        log::set_max_level(filters.max_level());
+    }
+}
+
+/// This function returns the setting key of the log level of the provided module.
+pub fn log_level_setting_key(module: &str) -> String {
+    format!("log_level_{}", module.replace("::", "_"))
+}
+
+/// This function returns the path of the current log file.
+pub fn log_file_path() -> Result<PathBuf> {
+    Ok(error_path()?.join(LOG_FILE_NAME))
+}
+
+/// This function returns if error reports are being sent to Sentry.
+pub fn sentry_enabled() -> bool {
+    SENTRY_GUARD.read().unwrap().is_enabled()
+}
+
+/// This function returns if the user wants error reports to be sent to Sentry, even if the client is not running yet.
+pub fn sentry_requested() -> bool {
+    !SENTRY_DSN.read().unwrap().is_empty()
+}
+
+/// This function enables or disables sending error reports to Sentry.
+///
+/// Disabling it closes the client shared by all threads right away, and deletes the error reports already stored on disk,
+/// so they're not sent after the user said no. Enabling it takes effect on the next start, as the client can only be initialized once.
+pub fn set_sentry_enabled(enabled: bool) -> Result<()> {
+    if enabled {
+        *SENTRY_DSN.write().unwrap() = SENTRY_DSN_KEY.to_owned();
+    } else {
+        SENTRY_GUARD.read().unwrap().close(None);
+        SENTRY_DSN.write().unwrap().clear();
+        delete_error_reports()?;
+    }
+
+    Ok(())
+}
+
+/// This function deletes the error reports stored in the error folder. Logs and diagnostic bundles are kept, as they never leave the computer on their own.
+pub fn delete_error_reports() -> Result<()> {
+    for entry in read_dir(error_path()?)?.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if path.is_file() && ![LOG_FILE_NAME, OLD_LOG_FILE_NAME, DIAGNOSTIC_BUNDLE_FILE_NAME].contains(&file_name.as_str()) {
+            remove_file(path)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// This function returns the lines of the provided log that contain the provided text and have the provided level or a less verbose one.
+///
+/// Lines without level, like the ones of multi-line messages, are shown if the line they continue is shown.
+pub fn filter_log(log: &str, filter: &str, max_level: &str) -> String {
+    let filter = filter.to_lowercase();
+    let max_level = parse_level(max_level).unwrap_or(LevelFilter::Trace);
+    let mut show_line = true;
+
+    log.lines()
+        .filter(|line| {
+            let level = line.split_once(" [")
+                .and_then(|(_, rest)| rest.split_once(']'))
+                .and_then(|(level, _)| level.parse::<Level>().ok());
+
+            if let Some(level) = level {
+                show_line = level <= max_level && (filter.is_empty() || line.to_lowercase().contains(&filter));
+            }
+
+            show_line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// This function generates a zip with the logs and the configs of the user, for bug reports, returning its path.
+///
+/// Configs are sanitized: sensitive settings are removed, and the user's home folder is replaced with `~`.
+pub unsafe fn diagnostic_bundle() -> Result<PathBuf> {
+    let error_path = error_path()?;
+    let bundle_path = error_path.join(DIAGNOSTIC_BUNDLE_FILE_NAME);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+    let mut zip = ZipWriter::new(BufWriter::new(File::create(&bundle_path)?));
+
+    // Logs contain paths, so they need to be sanitized too.
+    for file_name in [LOG_FILE_NAME, OLD_LOG_FILE_NAME] {
+        let path = error_path.join(file_name);
+        if path.is_file() {
+            let data = std::fs::read(&path)?;
+            zip.start_file(file_name, options)?;
+            zip.write_all(sanitize(&String::from_utf8_lossy(&data)).as_bytes())?;
+        }
+    }
+
+    zip.start_file(DIAGNOSTIC_BUNDLE_SETTINGS_FILE_NAME, options)?;
+    zip.write_all(sanitized_settings().as_bytes())?;
+
+    let game_config_path = game_config_path()?;
+    if game_config_path.is_dir() {
+        for path in files_from_subdir(&game_config_path, false)? {
+            if let Some(file_name) = path.file_name() {
+                let data = std::fs::read(&path)?;
+                zip.start_file(format!("{}{}", DIAGNOSTIC_BUNDLE_GAME_CONFIG_FOLDER, file_name.to_string_lossy()), options)?;
+                zip.write_all(sanitize(&String::from_utf8_lossy(&data)).as_bytes())?;
+            }
+        }
+    }
+
+    // Script logs of the game, so whoever reads the bundle can check the script errors without asking for them.
+    for path in latest_game_logs() {
+        if let Some(file_name) = path.file_name() {
+            let data = std::fs::read(&path)?;
+            zip.start_file(format!("{}{}", DIAGNOSTIC_BUNDLE_GAME_LOGS_FOLDER, file_name.to_string_lossy()), options)?;
+            zip.write_all(sanitize(&String::from_utf8_lossy(&data)).as_bytes())?;
+        }
+    }
+
+    zip.finish()?.flush()?;
+
+    Ok(bundle_path)
+}
+
+/// This function returns the newest script logs of the last game selected.
+fn latest_game_logs() -> Vec<PathBuf> {
+    let game = match SUPPORTED_GAMES.game(&base_game_key(&setting_string("last_game_selected"))) {
+        Some(game) => game,
+        None => return vec![],
+    };
+
+    let log_patterns = LogPatterns::load();
+    let log_format = log_patterns.game(game);
+    let mut logs = files_from_subdir(&setting_path(game.key()), false)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|path| path.file_name().is_some_and(|name| log_format.is_log_file(&name.to_string_lossy())))
+        .filter_map(|path| Some((path.metadata().ok()?.modified().ok()?, path)))
+        .collect::<Vec<_>>();
+
+    logs.sort_by(|(modified_a, _), (modified_b, _)| modified_b.cmp(modified_a));
+    logs.into_iter()
+        .take(DIAGNOSTIC_BUNDLE_MAX_GAME_LOGS)
+        .map(|(_, path)| path)
+        .collect()
+}
+
+/// This function returns all the settings in a `key = value` format, without the sensitive and binary ones.
+unsafe fn sanitized_settings() -> String {
+    let q_settings = settings();
+    let keys = q_settings.all_keys();
+
+    let mut settings = String::new();
+    for index in 0..keys.count_0a() {
+        let key = keys.at(index).to_std_string();
+        let value = q_settings.value_1a(&QString::from_std_str(&key));
+        if !SENSITIVE_SETTINGS.contains(&key.as_str()) && value.type_() != Type::ByteArray {
+            settings.push_str(&format!("{} = {}\n", key, sanitize(&value.to_string().to_std_string())));
+        }
+    }
+
+    settings
+}
+
+/// This function replaces the user's home folder in the provided string, so usernames are not leaked in bug reports.
+pub fn sanitize(data: &str) -> String {
+    match directories::UserDirs::new() {
+        Some(user_dirs) => {
+            let home = user_dirs.home_dir().to_string_lossy().to_string();
+            data.replace(&home, "~")
+                .replace(&home.replace('\\', "/"), "~")
+                .replace(&home.replace('\\', "\\\\"), "~")
+        }
+        None => data.to_owned(),
+    }
+}
+
+/// This function parses a log level from the settings. Unknown levels, like the default one, return `None`.
+fn parse_level(level: &str) -> Option<LevelFilter> {
+    if LOG_LEVELS.contains(&level) {
+        level.parse().ok()
+    } else {
+        None
+    }
+}
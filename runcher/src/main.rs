@@ -53,9 +53,11 @@ use rpfm_ui_common::utils::*;
 
 use crate::app_ui::AppUI;
 use crate::communications::*;
+use crate::mod_manager::other_managers::unlock_game;
 use crate::settings_ui::*;
 
 mod actions_ui;
+mod api_thread;
 mod app_ui;
 mod background_thread;
 mod cli;
@@ -63,12 +65,16 @@ mod communications;
 mod data_ui;
 mod ffi;
 mod games;
+mod locale_loader;
+mod logging;
 mod mod_manager;
 mod mod_list_ui;
 mod network_thread;
 mod profiles_ui;
 mod pack_list_ui;
 mod settings_ui;
+mod themes;
+mod tray_ui;
 mod updater_ui;
 
 // Statics, so we don't need to pass them everywhere to use them.
@@ -85,7 +91,7 @@ lazy_static! {
     static ref SENTRY_GUARD: Arc<RwLock<ClientInitGuard>> = Arc::new(RwLock::new(Logger::init(&{
         init_config_path().expect("Error while trying to initialize config path. We're fucked.");
         error_path().unwrap_or_else(|_| PathBuf::from("."))
-    }, true, true, release_name!()).unwrap()));
+    }, true, false, release_name!()).unwrap()));
 
     /// Light stylesheet.
     static ref LIGHT_STYLE_SHEET: AtomicPtr<QString> = unsafe {
@@ -175,16 +181,41 @@ fn main() {
     // Setup the fallback locale before anything else.
     *FALLBACK_LOCALE.write().unwrap() = FALLBACK_LOCALE_EN.to_string();
 
-    // Setup sentry's dsn for error reporting.
-    *SENTRY_DSN.write().unwrap() = SENTRY_DSN_KEY.to_owned();
+    // Community locales need to be loaded before the locale is first used, as the fallback locale is only read once.
+    let user_locale = locale_loader::load_user_locale(FALLBACK_LOCALE_EN);
+
+    // Setup sentry's dsn for error reporting, but only if the user agreed to send error reports.
+    if setting_bool("enable_sentry") {
+        *SENTRY_DSN.write().unwrap() = SENTRY_DSN_KEY.to_owned();
+    }
 
     // Access the guard to make sure it gets initialized.
-    if SENTRY_GUARD.read().unwrap().is_enabled() {
+    let sentry_enabled = SENTRY_GUARD.read().unwrap().is_enabled();
+
+    // Our logger goes after the guard, as it needs the config path the guard creates.
+    if let Err(error) = unsafe { logging::init_logger() } {
+        eprintln!("Error initializing the logger: {}", error);
+    }
+
+    if sentry_enabled {
         info!("Sentry Logging support enabled. Starting...");
     } else {
         info!("Sentry Logging support disabled. Starting...");
     }
 
+    match user_locale {
+        Ok(Some(user_locale)) => info!("Loaded community locale: {}.", user_locale),
+        Ok(None) => {},
+        Err(error) => error!("Error loading the community locale: {}", error),
+    }
+
+    locale_loader::report_missing_keys(FALLBACK_LOCALE_EN);
+
+    // Commands that don't need the UI (like simulations) are run here, before creating the threads or the application, so they work headless.
+    if let Some(exit_code) = cli::Cli::run_headless() {
+        std::process::exit(exit_code);
+    }
+
     // Create the background and network threads, where all the magic will happen.
     info!("Initializing threads...");
     let bac_handle = thread::spawn(|| { background_thread::background_loop(); });
@@ -195,11 +226,24 @@ fn main() {
         match unsafe { AppUI::new() } {
             Ok(app_ui) => {
 
+                // The API thread goes after the UI, as it needs the settings initialized and the UI ready to answer its requests.
+                // It's never joined, as it spends its life waiting for connections. It dies with the program.
+                if setting_bool("enable_api_server") {
+                    thread::spawn(|| { api_thread::api_loop(); });
+                }
+
                 // If we closed the window BEFORE executing, exit the app.
                 let exit_code = if unsafe { app_ui.main_window().is_visible() } {
-                    unsafe { QApplication::exec() }
+                    let exit_code = unsafe { QApplication::exec() };
+                    unsafe { app_ui.save_ui_state() };
+                    exit_code
                 } else { 0 };
 
+                // Release the game, so other instances don't think we still have it loaded.
+                if let Err(error) = unlock_game() {
+                    warn!("Failed to release the game lock: {}", error);
+                }
+
                 // Close and rejoin the threads on exit, so we don't leave a rogue thread running.
                 CENTRAL_COMMAND.send_background(Command::Exit);
                 CENTRAL_COMMAND.send_network(Command::Exit);
@@ -9,6 +9,8 @@
 //---------------------------------------------------------------------------//
 
 use qt_widgets::QAction;
+use qt_widgets::QActionGroup;
+use qt_widgets::QColorDialog;
 use qt_widgets::QDialog;
 use qt_widgets::QDialogButtonBox;
 use qt_widgets::q_dialog_button_box::StandardButton;
@@ -21,6 +23,8 @@ use qt_widgets::QToolButton;
 use qt_widgets::QTreeView;
 use qt_widgets::QWidget;
 
+use qt_gui::QColor;
+use qt_gui::QIcon;
 use qt_gui::QListOfQStandardItem;
 use qt_gui::QStandardItem;
 use qt_gui::QStandardItemModel;
@@ -28,7 +32,9 @@ use qt_gui::QStandardItemModel;
 use qt_core::AlignmentFlag;
 use qt_core::CaseSensitivity;
 use qt_core::CheckState;
+use qt_core::ContextMenuPolicy;
 use qt_core::ItemFlag;
+use qt_core::q_item_selection_model::SelectionFlag;
 use qt_core::QBox;
 use qt_core::QFlags;
 use qt_core::QModelIndex;
@@ -47,14 +53,15 @@ use cpp_core::Ptr;
 use anyhow::Result;
 use base64::prelude::*;
 use getset::*;
-use time::OffsetDateTime;
+use time::{formatting::Formattable, OffsetDateTime};
 
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::time::UNIX_EPOCH;
 
 use rpfm_lib::games::GameInfo;
+use rpfm_lib::schema::Schema;
 use rpfm_lib::utils::path_to_absolute_string;
 
 use rpfm_ui_common::locale::*;
@@ -62,7 +69,8 @@ use rpfm_ui_common::settings::*;
 use rpfm_ui_common::utils::*;
 
 use crate::ffi::*;
-use crate::mod_manager::{game_config::GameConfig, icon_data, mods::Mod, secondary_mods_path};
+use crate::mod_manager::{authored_packs::upload_status, game_config::{CategoryStyle, GameConfig}, icon_data, integrations::WorkshopItemState, mod_blacklist::{BlacklistEntry, ModBlacklist}, mods::Mod, offline::offline_mode, pack_versions::game_version_string, profiles::active_locked_profile, secondary_mods_path, sessions::SessionHistory};
+use crate::SCHEMA;
 use crate::settings_ui::last_game_update_date;
 
 use self::slots::ModListUISlots;
@@ -75,22 +83,59 @@ const VIEW_RELEASE: &str = "ui/filterable_tree_widget.ui";
 const CATEGORY_NEW_VIEW_DEBUG: &str = "ui_templates/category_new_dialog.ui";
 const CATEGORY_NEW_VIEW_RELEASE: &str = "ui/category_new_dialog.ui";
 
+const CATEGORY_STYLE_VIEW_DEBUG: &str = "ui_templates/category_style_dialog.ui";
+const CATEGORY_STYLE_VIEW_RELEASE: &str = "ui/category_style_dialog.ui";
+
 pub const VALUE_MOD_ID: i32 = 21;
 pub const VALUE_PACK_PATH: i32 = 22;
 pub const VALUE_MOD_STEAM_ID: i32 = 23;
 pub const VALUE_PACK_TYPE: i32 = 24;
+pub const VALUE_TREAT_AS_MOD_PACK: i32 = 25;
+pub const VALUE_IS_FROM_GITHUB: i32 = 26;
+pub const VALUE_DEFER_UPDATES: i32 = 27;
+pub const VALUE_IS_IGNORED: i32 = 28;
+pub const VALUE_USE_WORKING_FOLDER: i32 = 29;
 pub const VALUE_TIMESTAMP: i32 = 30;
 pub const VALUE_IS_CATEGORY: i32 = 40;
+pub const VALUE_CATEGORY_COLOR: i32 = 41;
+pub const VALUE_WORKING_FOLDER: i32 = 42;
+pub const VALUE_IS_VIRTUAL_CATEGORY: i32 = 43;
 
 pub const FLAG_MOD_IS_OUTDATED: i32 = 31;
 pub const FLAG_MOD_DATA_IS_OLDER_THAN_SECONDARY: i32 = 32;
 pub const FLAG_MOD_DATA_IS_OLDER_THAN_CONTENT: i32 = 33;
 pub const FLAG_MOD_SECONDARY_IS_OLDER_THAN_CONTENT: i32 = 34;
+pub const FLAG_MOD_IS_MISFILED_MOVIE_PACK: i32 = 35;
+
+/// Maximum amount of outdated tables listed in the tooltip of the Game Version column.
+const OUTDATED_TABLES_SHOWN: usize = 10;
+
+/// Ways the mods can be grouped in the list, with the setting value and the locale key of each one.
+const GROUPINGS: [(ModListGrouping, &str, &str); 5] = [
+    (ModListGrouping::Categories, "categories", "mod_list_grouping_categories"),
+    (ModListGrouping::Author, "author", "mod_list_grouping_author"),
+    (ModListGrouping::Source, "source", "mod_list_grouping_source"),
+    (ModListGrouping::UpdateMonth, "update_month", "mod_list_grouping_update_month"),
+    (ModListGrouping::KnownIssues, "known_issues", "mod_list_grouping_known_issues"),
+];
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
 
+/// How the mods are grouped in the list.
+///
+/// Only categories are real groups. The rest are computed from the mods' data, so they cannot be edited.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ModListGrouping {
+    #[default]
+    Categories,
+    Author,
+    Source,
+    UpdateMonth,
+    KnownIssues,
+}
+
 #[derive(Debug, Getters)]
 #[getset(get = "pub")]
 pub struct ModListUI {
@@ -101,16 +146,25 @@ pub struct ModListUI {
     filter_case_sensitive_button: QPtr<QToolButton>,
     filter_timer: QBox<QTimer>,
 
+    header_context_menu: QBox<QMenu>,
+    grouping_group: QBox<QActionGroup>,
+    show_ignored_mods: QPtr<QAction>,
+    show_last_launched_column: QPtr<QAction>,
+
     context_menu: QBox<QMenu>,
     category_new: QPtr<QAction>,
     category_delete: QPtr<QAction>,
     category_rename: QPtr<QAction>,
     category_sort: QPtr<QAction>,
+    category_style: QPtr<QAction>,
+    category_rules: QPtr<QAction>,
     categories_send_to_menu: QBox<QMenu>,
     enable_selected: QPtr<QAction>,
     disable_selected: QPtr<QAction>,
+    ignore_mods: QPtr<QAction>,
     expand_all: QPtr<QAction>,
     collapse_all: QPtr<QAction>,
+    reveal_in_pack_list: QPtr<QAction>,
 
     open_in_explorer: QPtr<QAction>,
     open_in_steam: QPtr<QAction>,
@@ -118,9 +172,30 @@ pub struct ModListUI {
 
     upload_to_workshop: QPtr<QAction>,
     download_from_workshop: QPtr<QAction>,
+    search_workshop: QPtr<QAction>,
+    force_redownload: QPtr<QAction>,
+    update_workshop_items_now: QPtr<QAction>,
+    defer_workshop_updates: QPtr<QAction>,
+    add_from_github: QPtr<QAction>,
+    update_from_github: QPtr<QAction>,
 
     copy_to_secondary: QPtr<QAction>,
+    refresh_secondary_copy: QPtr<QAction>,
+    find_shadowed_packs: QPtr<QAction>,
+    find_duplicate_mods: QPtr<QAction>,
+    add_known_issue: QPtr<QAction>,
+    rescan_mod: QPtr<QAction>,
+    rebuild_masks: QPtr<QAction>,
     move_to_secondary: QPtr<QAction>,
+    treat_as_mod_pack: QPtr<QAction>,
+    export_map_bin: QPtr<QAction>,
+    extract_pack: QPtr<QAction>,
+    set_working_folder: QPtr<QAction>,
+    use_working_folder: QPtr<QAction>,
+
+    backup_selected_mods: QPtr<QAction>,
+    restore_mods_backup: QPtr<QAction>,
+    export_mod_report: QPtr<QAction>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -157,20 +232,48 @@ impl ModListUI {
 
         layout.add_widget_5a(&main_widget, 0, 0, 1, 1);
 
+        // Header context menu, to change how the mods are grouped.
+        tree_view.header().set_context_menu_policy(ContextMenuPolicy::CustomContextMenu);
+        let header_context_menu = QMenu::from_q_widget(&main_widget);
+        let grouping_menu = header_context_menu.add_menu_q_string(&qtr("mod_list_grouping"));
+        let grouping_group = QActionGroup::new(&grouping_menu);
+        let grouping_setting = setting_string("mod_list_grouping");
+        let grouping_is_valid = GROUPINGS.iter().any(|(_, key, _)| *key == grouping_setting);
+        for (index, (_, key, locale_key)) in GROUPINGS.iter().enumerate() {
+            let action = grouping_menu.add_action_q_string(&qtr(locale_key));
+            action.set_data(&QVariant::from_q_string(&QString::from_std_str(key)));
+            action.set_checkable(true);
+            action.set_checked(*key == grouping_setting || (!grouping_is_valid && index == 0));
+            grouping_group.add_action_q_action(&action);
+        }
+
+        let show_ignored_mods = header_context_menu.add_action_q_string(&qtr("show_ignored_mods"));
+        show_ignored_mods.set_checkable(true);
+        show_ignored_mods.set_checked(setting_bool("show_ignored_mods"));
+
+        let show_last_launched_column = header_context_menu.add_action_q_string(&qtr("show_last_launched_column"));
+        show_last_launched_column.set_checkable(true);
+        show_last_launched_column.set_checked(setting_bool("show_last_launched_column"));
+
         // Context menu.
         let context_menu = QMenu::from_q_widget(&main_widget);
         let enable_selected = context_menu.add_action_q_string(&qtr("enable_selected"));
         let disable_selected = context_menu.add_action_q_string(&qtr("disable_selected"));
+        let ignore_mods = context_menu.add_action_q_string(&qtr("ignore_mods"));
+        ignore_mods.set_checkable(true);
 
         let category_new = context_menu.add_action_q_string(&qtr("category_new"));
         let category_delete = context_menu.add_action_q_string(&qtr("category_delete"));
         let category_rename = context_menu.add_action_q_string(&qtr("category_rename"));
         let category_sort = context_menu.add_action_q_string(&qtr("category_sort"));
+        let category_style = context_menu.add_action_q_string(&qtr("category_style"));
+        let category_rules = context_menu.add_action_q_string(&qtr("category_rules"));
         let categories_send_to_menu = QMenu::from_q_string(&qtr("categories_send_to_menu"));
         context_menu.add_menu_q_menu(&categories_send_to_menu);
 
         let open_in_explorer = context_menu.add_action_q_string(&qtr("open_in_explorer"));
         let open_in_steam = context_menu.add_action_q_string(&qtr("open_in_steam"));
+        let reveal_in_pack_list = context_menu.add_action_q_string(&qtr("reveal_in_pack_list"));
         let open_in_tool_menu = QMenu::from_q_string(&qtr("open_in_tool_menu"));
         open_in_tool_menu.set_enabled(false);
         context_menu.add_menu_q_menu(&open_in_tool_menu);
@@ -179,12 +282,37 @@ impl ModListUI {
 
         let upload_to_workshop = context_menu.add_action_q_string(&qtr("upload_to_workshop"));
         let download_from_workshop = context_menu.add_action_q_string(&qtr("download_from_workshop"));
+        let search_workshop = context_menu.add_action_q_string(&qtr("search_workshop"));
+        let force_redownload = context_menu.add_action_q_string(&qtr("force_redownload"));
+        let update_workshop_items_now = context_menu.add_action_q_string(&qtr("update_workshop_items_now"));
+        let defer_workshop_updates = context_menu.add_action_q_string(&qtr("defer_workshop_updates"));
+        defer_workshop_updates.set_checkable(true);
+        let add_from_github = context_menu.add_action_q_string(&qtr("add_from_github"));
+        let update_from_github = context_menu.add_action_q_string(&qtr("update_from_github"));
         context_menu.insert_separator(&upload_to_workshop);
 
         let copy_to_secondary = context_menu.add_action_q_string(&qtr("copy_to_secondary"));
+        let refresh_secondary_copy = context_menu.add_action_q_string(&qtr("refresh_secondary_copy"));
+        let find_shadowed_packs = context_menu.add_action_q_string(&qtr("find_shadowed_packs"));
+        let find_duplicate_mods = context_menu.add_action_q_string(&qtr("find_duplicate_mods"));
+        let add_known_issue = context_menu.add_action_q_string(&qtr("add_known_issue"));
+        let rescan_mod = context_menu.add_action_q_string(&qtr("rescan_mod"));
+        let rebuild_masks = context_menu.add_action_q_string(&qtr("rebuild_masks"));
         let move_to_secondary = context_menu.add_action_q_string(&qtr("move_to_secondary"));
+        let treat_as_mod_pack = context_menu.add_action_q_string(&qtr("treat_as_mod_pack"));
+        treat_as_mod_pack.set_checkable(true);
+        let export_map_bin = context_menu.add_action_q_string(&qtr("export_map_bin"));
+        let extract_pack = context_menu.add_action_q_string(&qtr("extract_pack"));
+        let set_working_folder = context_menu.add_action_q_string(&qtr("set_working_folder"));
+        let use_working_folder = context_menu.add_action_q_string(&qtr("use_working_folder"));
+        use_working_folder.set_checkable(true);
         context_menu.insert_separator(&copy_to_secondary);
 
+        let backup_selected_mods = context_menu.add_action_q_string(&qtr("backup_selected_mods"));
+        let restore_mods_backup = context_menu.add_action_q_string(&qtr("restore_mods_backup"));
+        let export_mod_report = context_menu.add_action_q_string(&qtr("export_mod_report"));
+        context_menu.insert_separator(&backup_selected_mods);
+
         let expand_all = context_menu.add_action_q_string(&qtr("expand_all"));
         let collapse_all = context_menu.add_action_q_string(&qtr("collapse_all"));
         context_menu.insert_separator(&expand_all);
@@ -197,16 +325,25 @@ impl ModListUI {
             filter_case_sensitive_button,
             filter_timer,
 
+            header_context_menu,
+            grouping_group,
+            show_ignored_mods,
+            show_last_launched_column,
+
             context_menu,
             category_new,
             category_delete,
             category_rename,
             category_sort,
+            category_style,
+            category_rules,
             categories_send_to_menu,
             enable_selected,
             disable_selected,
+            ignore_mods,
             expand_all,
             collapse_all,
+            reveal_in_pack_list,
 
             open_in_explorer,
             open_in_steam,
@@ -214,9 +351,30 @@ impl ModListUI {
 
             upload_to_workshop,
             download_from_workshop,
+            search_workshop,
+            force_redownload,
+            update_workshop_items_now,
+            defer_workshop_updates,
+            add_from_github,
+            update_from_github,
 
             copy_to_secondary,
+            refresh_secondary_copy,
+            find_shadowed_packs,
+            find_duplicate_mods,
+            add_known_issue,
+            rescan_mod,
+            rebuild_masks,
             move_to_secondary,
+            treat_as_mod_pack,
+            export_map_bin,
+            extract_pack,
+            set_working_folder,
+            use_working_folder,
+
+            backup_selected_mods,
+            restore_mods_backup,
+            export_mod_report,
         });
 
         let slots = ModListUISlots::new(&list);
@@ -231,6 +389,8 @@ impl ModListUI {
         self.filter_timer().timeout().connect(slots.filter_trigger());
 
         self.tree_view().custom_context_menu_requested().connect(slots.context_menu());
+        self.tree_view().header().custom_context_menu_requested().connect(slots.header_context_menu());
+        self.show_last_launched_column().toggled().connect(slots.toggle_last_launched_column());
 
         self.tree_view().selection_model().selection_changed().connect(slots.context_menu_enabler());
         self.context_menu().about_to_show().connect(slots.context_menu_enabler());
@@ -255,6 +415,9 @@ impl ModListUI {
         let data_path = path_to_absolute_string(&game_data_path);
         let secondary_path = path_to_absolute_string(&secondary_mods_path(game.key()).unwrap_or_else(|_| PathBuf::default()));
         let content_path = path_to_absolute_string(&game.content_path(&game_path).unwrap_or_else(|_| PathBuf::default()));
+        let steam_user_id = setting_string("steam_user_id");
+        let installed_version = game.game_version_number(&game_path);
+        let schema = SCHEMA.read().unwrap();
 
         // Initialize these here so they can be re-use.
         let outdated_icon = icon_data("outdated.png").unwrap_or_else(|_| vec![]);
@@ -269,32 +432,57 @@ impl ModListUI {
         let secondary_older_than_content_icon = icon_data("secondary_older_than_content.png").unwrap_or_else(|_| vec![]);
         let secondary_older_than_content = tre("mod_secondary_older_than_content", &[&BASE64_STANDARD.encode(secondary_older_than_content_icon)]);
 
-        // This loads mods per category, meaning all installed mod have to be in the categories list!!!!
-        for category in game_config.categories_order() {
+        let misfiled_movie_pack_icon = icon_data("misfiled_movie_pack.png").unwrap_or_else(|_| vec![]);
+        let misfiled_movie_pack = tre("mod_misfiled_movie_pack", &[&BASE64_STANDARD.encode(misfiled_movie_pack_icon)]);
+
+        // Computed groups cannot be edited, so we disable drag and drop on them.
+        let grouping = self.grouping();
+        self.tree_view().set_drag_enabled(grouping == ModListGrouping::Categories);
+
+        let show_ignored = self.show_ignored_mods().is_checked();
+        let locked = active_locked_profile(game.key()).is_some();
+        let ignored_marker = format!(" <i>[{}]</i>", tr("mod_ignored"));
+
+        let known_issues_marker = format!(" <i>[{}]</i>", tr("mod_known_issues"));
+        let known_issues = Self::known_issues(game, game_config.mods(), &game_data_path);
+
+        // This loads mods per group. When grouping by category, this means all installed mod have to be in the categories list!!!!
+        let mut groups = Self::groups(game_config, grouping, &data_path, &secondary_path, &content_path, &known_issues);
+
+        // Recently added mods are pulled out of their groups into a virtual one at the top, so new subscriptions are easy to find.
+        let recently_added = Self::recently_added(game_config, show_ignored);
+        let has_recently_added = !recently_added.is_empty();
+        if has_recently_added {
+            for (_, mods) in groups.iter_mut() {
+                mods.retain(|mod_id| !recently_added.contains(mod_id));
+            }
+
+            groups.insert(0, (tr("mod_list_recently_added"), recently_added));
+        }
+
+        for (index, (category, mods)) in groups.iter().enumerate() {
+            let is_virtual = has_recently_added && index == 0;
             let item = QStandardItem::from_q_string(&QString::from_std_str(category));
             item.set_data_2a(&QVariant::from_bool(true), VALUE_IS_CATEGORY);
+            item.set_data_2a(&QVariant::from_bool(is_virtual), VALUE_IS_VIRTUAL_CATEGORY);
             item.set_editable(false);
+
+            // Only real categories have visuals. Computed groups don't.
+            if grouping == ModListGrouping::Categories && !is_virtual {
+                Self::apply_category_style(item.as_ptr(), game_config.category_styles().get(category));
+            }
+
             self.model().append_row_q_standard_item(item.into_ptr().as_mut_raw_ptr());
 
-            if let Some(mods) = game_config.categories().get(category) {
+            if !mods.is_empty() {
                 for mod_id in mods {
                     if let Some(modd) = game_config.mods().get(mod_id) {
 
-                        // Ignore registered mods with no path.
-                        if !modd.paths().is_empty() {
-                            let category = QString::from_std_str(game_config.category_for_mod(modd.id()));
-                            let mut parent = None;
-
-                            // Find the parent category.
-                            for index in 0..self.model().row_count_0a() {
-                                let item = self.model().item_1a(index);
-                                if !item.is_null() && item.text().compare_q_string(&category) == 0 {
-                                    parent = Some(item);
-                                    break;
-                                }
-                            }
-
-                            if let Some(ref parent) = parent {
+                        // Ignore registered mods with no path, and mods the user ignored, unless asked to show them.
+                        if !modd.paths().is_empty() && (show_ignored || !*modd.ignored()) {
+                            // Groups are added in order, so the parent is the group we just added. Names may be repeated between virtual and real categories.
+                            let parent = self.model().item_1a(index as i32);
+                            if !parent.is_null() {
                                 let row = QListOfQStandardItem::new();
 
                                 let item_mod_name = Self::new_item();
@@ -305,6 +493,9 @@ impl ModListUI {
                                 let item_file_size = Self::new_item();
                                 let item_time_created = Self::new_item();
                                 let item_time_updated = Self::new_item();
+                                let item_version = Self::new_item();
+                                let item_last_launched = Self::new_item();
+                                let item_game_version = Self::new_item();
 
                                 let mod_name = if modd.name() != modd.id() {
                                     if !modd.file_name().is_empty() {
@@ -324,6 +515,12 @@ impl ModListUI {
                                     format!("<i>{}</i>", modd.name())
                                 };
 
+                                let mod_name = if known_issues.contains_key(modd.id()) {
+                                    mod_name + &known_issues_marker
+                                } else {
+                                    mod_name
+                                };
+
                                 // TODO: show discrepancies between steam's reported data and real data.
                                 let mod_size = if *modd.file_size() != 0 {
                                     format!("{:.2} MB", *modd.file_size() as f64 / 1024.0 / 1024.0)
@@ -371,6 +568,11 @@ impl ModListUI {
                                     }
                                 }
 
+                                item_flags.set_data_2a(&QVariant::from_bool(*modd.misfiled_movie_pack()), FLAG_MOD_IS_MISFILED_MOVIE_PACK);
+                                if *modd.misfiled_movie_pack() {
+                                    flags_description.push_str(&misfiled_movie_pack);
+                                }
+
                                 if !flags_description.is_empty() {
                                     flags_description = tr("mod_flags_description") + "<ul>" + &flags_description + "<ul/>";
                                     item_flags.set_tool_tip(&QString::from_std_str(&flags_description));
@@ -391,17 +593,35 @@ impl ModListUI {
                                     locations.push(format!("Content ({})", id));
                                 }
 
+                                if let Some(source) = modd.github_source() {
+                                    if source.update_available() {
+                                        locations.push(format!("GitHub ({} -> {})", source.version(), source.latest_version()));
+                                    } else {
+                                        locations.push(format!("GitHub ({})", source.version()));
+                                    }
+                                }
+
                                 item_location.set_text(&QString::from_std_str(locations.join(",")));
 
                                 item_time_created.set_data_2a(&QVariant::from_i64(*modd.time_created() as i64), VALUE_TIMESTAMP);
                                 item_time_updated.set_data_2a(&QVariant::from_i64(*modd.time_updated() as i64), VALUE_TIMESTAMP);
 
-                                item_mod_name.set_text(&QString::from_std_str(mod_name));
+                                if *modd.ignored() {
+                                    item_mod_name.set_text(&QString::from_std_str(mod_name + &ignored_marker));
+                                } else {
+                                    item_mod_name.set_text(&QString::from_std_str(mod_name));
+                                }
+
                                 item_creator.set_text(&QString::from_std_str(modd.creator_name()));
                                 item_type.set_text(&QString::from_std_str(modd.pack_type().to_string()));
                                 item_file_size.set_text(&QString::from_std_str(&mod_size));
                                 item_time_created.set_text(&QString::from_std_str(&time_created));
                                 item_time_updated.set_text(&QString::from_std_str(&time_updated));
+                                Self::set_workshop_data_stale(item_time_updated.as_ptr(), modd);
+                                Self::set_version_data(item_version.as_ptr(), modd, &date_format, &data_path, &secondary_path, &content_path, &steam_user_id)?;
+                                Self::set_game_version_data(item_game_version.as_ptr(), modd, schema.as_ref(), installed_version);
+                                Self::set_campaign_safety_data(item_mod_name.as_ptr(), modd);
+                                Self::set_known_issues_data(item_mod_name.as_ptr(), modd.id(), known_issues.get(modd.id()));
 
                                 item_mod_name.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(modd.id())), VALUE_MOD_ID);
                                 item_mod_name.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(modd.paths()[0].to_string_lossy())), VALUE_PACK_PATH);
@@ -412,6 +632,12 @@ impl ModListUI {
 
                                 item_mod_name.set_data_2a(&QVariant::from_bool(false), VALUE_IS_CATEGORY);
                                 item_mod_name.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(modd.pack_type().to_string())), VALUE_PACK_TYPE);
+                                item_mod_name.set_data_2a(&QVariant::from_bool(*modd.treat_as_mod_pack()), VALUE_TREAT_AS_MOD_PACK);
+                                item_mod_name.set_data_2a(&QVariant::from_bool(modd.github_source().is_some()), VALUE_IS_FROM_GITHUB);
+                                item_mod_name.set_data_2a(&QVariant::from_bool(*modd.defer_updates()), VALUE_DEFER_UPDATES);
+                                item_mod_name.set_data_2a(&QVariant::from_bool(*modd.ignored()), VALUE_IS_IGNORED);
+                                item_mod_name.set_data_2a(&QVariant::from_bool(*modd.use_working_folder()), VALUE_USE_WORKING_FOLDER);
+                                item_mod_name.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(modd.working_folder().as_ref().map(|path| path.to_string_lossy().to_string()).unwrap_or_default())), VALUE_WORKING_FOLDER);
 
                                 if modd.can_be_toggled(&game_data_path) {
                                     item_mod_name.set_checkable(true);
@@ -419,6 +645,13 @@ impl ModListUI {
                                     if modd.enabled(&game_data_path) {
                                         item_mod_name.set_check_state(CheckState::Checked);
                                     }
+
+                                    // Mods of a locked profile keep their state until it's unlocked.
+                                    if locked {
+                                        let mut flags = item_mod_name.flags().to_int();
+                                        flags &= !ItemFlag::ItemIsUserCheckable.to_int();
+                                        item_mod_name.set_flags(QFlags::from(flags));
+                                    }
                                 }
 
                                 // This is for movie mods in /data.
@@ -441,6 +674,9 @@ impl ModListUI {
                                 row.append_q_standard_item(&item_file_size.into_ptr().as_mut_raw_ptr());
                                 row.append_q_standard_item(&item_time_created.into_ptr().as_mut_raw_ptr());
                                 row.append_q_standard_item(&item_time_updated.into_ptr().as_mut_raw_ptr());
+                                row.append_q_standard_item(&item_version.into_ptr().as_mut_raw_ptr());
+                                row.append_q_standard_item(&item_last_launched.into_ptr().as_mut_raw_ptr());
+                                row.append_q_standard_item(&item_game_version.into_ptr().as_mut_raw_ptr());
                                 parent.append_row_q_list_of_q_standard_item(row.into_ptr().as_ref().unwrap());
                             }
                         }
@@ -449,11 +685,15 @@ impl ModListUI {
             }
         }
 
+        self.update_last_launched(game.key(), &date_format)?;
+
         // If we have no api key, don't show the author column, as we cannot get it without api key.
         if setting_string("steam_api_key").is_empty() {
             self.tree_view().hide_column(3);
         }
 
+        self.tree_view().set_column_hidden(9, !self.show_last_launched_column().is_checked());
+
         self.tree_view().expand_all();
         self.tree_view().header().resize_sections(ResizeMode::ResizeToContents);
 
@@ -463,6 +703,7 @@ impl ModListUI {
         full_desc.push_str(&data_older_than_secondary);
         full_desc.push_str(&data_older_than_content);
         full_desc.push_str(&secondary_older_than_content);
+        full_desc.push_str(&misfiled_movie_pack);
         full_desc.push_str("</ul>");
 
         self.model.horizontal_header_item(1).set_tool_tip(&QString::from_std_str(full_desc));
@@ -483,6 +724,9 @@ impl ModListUI {
         let data_path = path_to_absolute_string(&game_data_path);
         let secondary_path = path_to_absolute_string(&secondary_mods_path(game.key()).unwrap_or_else(|_| PathBuf::default()));
         let content_path = path_to_absolute_string(&game.content_path(&game_path).unwrap_or_else(|_| PathBuf::default()));
+        let steam_user_id = setting_string("steam_user_id");
+        let installed_version = game.game_version_number(&game_path);
+        let schema = SCHEMA.read().unwrap();
 
         // Initialize these here so they can be re-use.
         let outdated_icon = icon_data("outdated.png").unwrap_or_else(|_| vec![]);
@@ -497,6 +741,13 @@ impl ModListUI {
         let secondary_older_than_content_icon = icon_data("secondary_older_than_content.png").unwrap_or_else(|_| vec![]);
         let secondary_older_than_content = tre("mod_secondary_older_than_content", &[&BASE64_STANDARD.encode(secondary_older_than_content_icon)]);
 
+        let misfiled_movie_pack_icon = icon_data("misfiled_movie_pack.png").unwrap_or_else(|_| vec![]);
+        let misfiled_movie_pack = tre("mod_misfiled_movie_pack", &[&BASE64_STANDARD.encode(misfiled_movie_pack_icon)]);
+        let ignored_marker = format!(" <i>[{}]</i>", tr("mod_ignored"));
+
+        let known_issues_marker = format!(" <i>[{}]</i>", tr("mod_known_issues"));
+        let known_issues = Self::known_issues(game, mods, &game_data_path);
+
         for category_index in 0..self.model().row_count_0a() {
             let category = self.model().item_2a(category_index, 0);
             let mut index_to_delete = vec![];
@@ -518,6 +769,8 @@ impl ModListUI {
                         let item_file_size = category.child_2a(mod_index, 5);
                         let item_time_created = category.child_2a(mod_index, 6);
                         let item_time_updated = category.child_2a(mod_index, 7);
+                        let item_version = category.child_2a(mod_index, 8);
+                        let item_game_version = category.child_2a(mod_index, 10);
 
                         let mod_name = if modd.name() != modd.id() {
                             if !modd.file_name().is_empty() {
@@ -537,6 +790,12 @@ impl ModListUI {
                             format!("<i>{}</i>", modd.name())
                         };
 
+                        let mod_name = if known_issues.contains_key(modd.id()) {
+                            mod_name + &known_issues_marker
+                        } else {
+                            mod_name
+                        };
+
                         // TODO: show discrepancies between steam's reported data and real data.
                         let mod_size = if *modd.file_size() != 0 {
                             format!("{:.2} MB", *modd.file_size() as f64 / 1024.0 / 1024.0)
@@ -584,6 +843,11 @@ impl ModListUI {
                             }
                         }
 
+                        item_flags.set_data_2a(&QVariant::from_bool(*modd.misfiled_movie_pack()), FLAG_MOD_IS_MISFILED_MOVIE_PACK);
+                        if *modd.misfiled_movie_pack() {
+                            flags_description.push_str(&misfiled_movie_pack);
+                        }
+
                         if !flags_description.is_empty() {
                             flags_description = tr("mod_flags_description") + "<ul>" + &flags_description + "<ul/>";
                             item_flags.set_tool_tip(&QString::from_std_str(&flags_description));
@@ -604,17 +868,39 @@ impl ModListUI {
                             locations.push(format!("Content ({})", id));
                         }
 
+                        if let Some(source) = modd.github_source() {
+                            if source.update_available() {
+                                locations.push(format!("GitHub ({} -> {})", source.version(), source.latest_version()));
+                            } else {
+                                locations.push(format!("GitHub ({})", source.version()));
+                            }
+                        }
+
                         item_location.set_text(&QString::from_std_str(locations.join(",")));
 
                         item_time_created.set_data_2a(&QVariant::from_i64(*modd.time_created() as i64), VALUE_TIMESTAMP);
                         item_time_updated.set_data_2a(&QVariant::from_i64(*modd.time_updated() as i64), VALUE_TIMESTAMP);
 
-                        item_mod_name.set_text(&QString::from_std_str(mod_name));
+                        if *modd.ignored() {
+                            item_mod_name.set_text(&QString::from_std_str(mod_name + &ignored_marker));
+                        } else {
+                            item_mod_name.set_text(&QString::from_std_str(mod_name));
+                        }
+
+                        item_mod_name.set_data_2a(&QVariant::from_bool(*modd.defer_updates()), VALUE_DEFER_UPDATES);
+                        item_mod_name.set_data_2a(&QVariant::from_bool(*modd.ignored()), VALUE_IS_IGNORED);
+                        item_mod_name.set_data_2a(&QVariant::from_bool(*modd.use_working_folder()), VALUE_USE_WORKING_FOLDER);
+                        item_mod_name.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(modd.working_folder().as_ref().map(|path| path.to_string_lossy().to_string()).unwrap_or_default())), VALUE_WORKING_FOLDER);
                         item_creator.set_text(&QString::from_std_str(modd.creator_name()));
                         item_type.set_text(&QString::from_std_str(modd.pack_type().to_string()));
                         item_file_size.set_text(&QString::from_std_str(&mod_size));
                         item_time_created.set_text(&QString::from_std_str(&time_created));
                         item_time_updated.set_text(&QString::from_std_str(&time_updated));
+                        Self::set_workshop_data_stale(item_time_updated, modd);
+                        Self::set_version_data(item_version, modd, &date_format, &data_path, &secondary_path, &content_path, &steam_user_id)?;
+                        Self::set_game_version_data(item_game_version, modd, schema.as_ref(), installed_version);
+                        Self::set_campaign_safety_data(item_mod_name, modd);
+                        Self::set_known_issues_data(item_mod_name, modd.id(), known_issues.get(modd.id()));
                     }
                 }
             }
@@ -637,8 +923,220 @@ impl ModListUI {
         Ok(())
     }
 
+    /// This function fills the Last Launched column of all mods from the session history of the provided game.
+    pub unsafe fn update_last_launched<F: Formattable + ?Sized>(&self, game_key: &str, date_format: &F) -> Result<()> {
+        let last_launched = SessionHistory::load(game_key).last_launched();
+
+        for category_index in 0..self.model().row_count_0a() {
+            let category = self.model().item_2a(category_index, 0);
+            for mod_index in 0..category.row_count() {
+                let mod_id = category.child_2a(mod_index, 0).data_1a(VALUE_MOD_ID).to_string().to_std_string();
+                let item = category.child_2a(mod_index, 9);
+                if item.is_null() {
+                    continue;
+                }
+
+                let timestamp = last_launched.get(&mod_id).copied().unwrap_or_default();
+                let text = if timestamp != 0 {
+                    OffsetDateTime::from_unix_timestamp(timestamp as i64)?.format(date_format)?
+                } else {
+                    "-".to_string()
+                };
+
+                item.set_text(&QString::from_std_str(&text));
+                item.set_data_2a(&QVariant::from_i64(timestamp as i64), VALUE_TIMESTAMP);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This function fills the Version column of a mod: the date of the copy in use, with the dates of all its copies and of its last Workshop update in the tooltip.
+    ///
+    /// Secondary copies older than the last Workshop update get marked, as they were copied before the update and never refreshed.
+    /// For mods made by the user, the tooltip also tells if the local copy matches the last uploaded one.
+    unsafe fn set_version_data<F: Formattable + ?Sized>(item: Ptr<QStandardItem>, modd: &Mod, date_format: &F, data_path: &str, secondary_path: &str, content_path: &str, steam_user_id: &str) -> Result<()> {
+        let copies = modd.copies_dates();
+
+        let mut description = String::new();
+        for (path, date) in &copies {
+            let path = path_to_absolute_string(path);
+            let location = if path.starts_with(data_path) {
+                "Data"
+            } else if !secondary_path.is_empty() && path.starts_with(secondary_path) {
+                "Secondary"
+            } else {
+                "Content"
+            };
+
+            let date = OffsetDateTime::from_unix_timestamp(*date as i64)?.format(date_format)?;
+            description.push_str(&tre("mod_version_copy", &[location, &date]));
+        }
+
+        if *modd.time_updated() != 0 {
+            let date = OffsetDateTime::from_unix_timestamp(*modd.time_updated() as i64)?.format(date_format)?;
+            description.push_str(&tre("mod_version_workshop", &[&date]));
+        }
+
+        if modd.steam_id().is_some() && offline_mode().is_some() {
+            description.push_str(&tr("mod_version_workshop_stale"));
+        }
+
+        if modd.steam_id().is_some() && *modd.workshop_state() != WorkshopItemState::Unknown {
+            description.push_str(&tre("mod_version_workshop_state", &[&tr(modd.workshop_state().locale_key())]));
+        }
+
+        if *modd.defer_updates() {
+            description.push_str(&tr("mod_version_updates_deferred"));
+        }
+
+        if let Some(working_folder) = modd.active_working_folder() {
+            description.push_str(&tre("mod_version_working_folder", &[&working_folder.to_string_lossy()]));
+        }
+
+        // For mods made by the user, tell them if what they have on disk is what they last uploaded.
+        if !steam_user_id.is_empty() && modd.creator() == steam_user_id {
+            if let Some(status) = upload_status(modd) {
+                description.push_str(&tre("mod_version_upload_status", &[&tr(status.locale_key())]));
+            }
+        }
+
+        if *modd.first_seen() != 0 {
+            let date = OffsetDateTime::from_unix_timestamp(*modd.first_seen() as i64)?.format(date_format)?;
+            description.push_str(&tre("mod_version_first_seen", &[&date]));
+        }
+
+        if *modd.last_enabled() != 0 {
+            let date = OffsetDateTime::from_unix_timestamp(*modd.last_enabled() as i64)?.format(date_format)?;
+            description.push_str(&tre("mod_version_last_enabled", &[&date]));
+        }
+
+        // Deferred mods have a stale secondary copy on purpose, so we don't warn about it.
+        if *modd.defer_updates() {
+            item.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("media-playback-pause")));
+        } else if modd.secondary_is_stale(secondary_path, content_path) {
+            description.push_str(&tr("mod_version_secondary_stale"));
+            item.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("data-warning")));
+        } else if modd.workshop_state().update_pending() {
+            item.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("download")));
+        } else {
+            item.set_icon(&QIcon::new());
+        }
+
+        // The first copy is the one the game loads.
+        let date = copies.first().map(|(_, date)| *date).unwrap_or_default();
+        let date_text = if date != 0 {
+            OffsetDateTime::from_unix_timestamp(date as i64)?.format(date_format)?
+        } else {
+            "-".to_string()
+        };
+
+        item.set_text(&QString::from_std_str(&date_text));
+        item.set_data_2a(&QVariant::from_i64(date as i64), VALUE_TIMESTAMP);
+        item.set_tool_tip(&QString::from_std_str(tr("mod_version_description") + "<ul>" + &description + "</ul>"));
+
+        Ok(())
+    }
+
+    /// This function marks the Workshop data of a mod as possibly outdated while in offline mode, as it's not refreshed until we're back online.
+    unsafe fn set_workshop_data_stale(item: Ptr<QStandardItem>, modd: &Mod) {
+        let stale = modd.steam_id().is_some() && offline_mode().is_some();
+        let font = item.font();
+        font.set_italic(stale);
+        item.set_font(&font);
+
+        if stale {
+            item.set_tool_tip(&qtr("workshop_data_stale"));
+        } else {
+            item.set_tool_tip(&QString::new());
+        }
+    }
+
+    /// This function fills the Game Version column of a mod: the version of the game it was made for, marked if it's not the installed one.
+    ///
+    /// If the mod doesn't say it, we only know if its tables match the installed version of the game or an older one.
+    unsafe fn set_game_version_data(item: Ptr<QStandardItem>, modd: &Mod, schema: Option<&Schema>, installed_version: Option<u32>) {
+        let check = modd.game_version().check(schema, installed_version);
+
+        let mut description = String::new();
+        if let Some(embedded) = check.embedded() {
+            description.push_str(&tre("mod_game_version_embedded", &[embedded]));
+        }
+
+        if let Some(installed_version) = installed_version {
+            description.push_str(&tre("mod_game_version_installed", &[&game_version_string(installed_version)]));
+        }
+
+        if *check.checked_tables() != 0 {
+            description.push_str(&tre("mod_game_version_tables", &[&check.checked_tables().to_string(), &check.outdated_tables().len().to_string()]));
+        }
+
+        for (table_name, version, latest) in check.outdated_tables().iter().take(OUTDATED_TABLES_SHOWN) {
+            description.push_str(&tre("mod_game_version_outdated_table", &[table_name, &version.to_string(), &latest.to_string()]));
+        }
+
+        if check.outdated_tables().len() > OUTDATED_TABLES_SHOWN {
+            description.push_str(&tre("mod_game_version_outdated_table_more", &[&(check.outdated_tables().len() - OUTDATED_TABLES_SHOWN).to_string()]));
+        }
+
+        let text = match check.embedded() {
+            Some(embedded) => embedded.to_owned(),
+            None => if *check.checked_tables() == 0 {
+                "-".to_owned()
+            } else if check.outdated_tables().is_empty() {
+                installed_version.map(game_version_string).unwrap_or_else(|| tr("mod_game_version_current"))
+            } else {
+                tr("mod_game_version_older")
+            },
+        };
+
+        if *check.mismatch() {
+            item.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("data-warning")));
+        } else {
+            item.set_icon(&QIcon::new());
+        }
+
+        item.set_text(&QString::from_std_str(&text));
+        if description.is_empty() {
+            item.set_tool_tip(&QString::new());
+        } else {
+            item.set_tool_tip(&QString::from_std_str(tr("mod_game_version_description") + "<ul>" + &description + "</ul>"));
+        }
+    }
+
+    /// This function sets the details of a mod shown in the tooltip of its name: if it can be added to an ongoing campaign, and why not if it can't.
+    unsafe fn set_campaign_safety_data(item: Ptr<QStandardItem>, modd: &Mod) {
+        let details = if modd.campaign_safety().is_safe() {
+            tr("campaign_safety_safe")
+        } else {
+            let reasons = modd.campaign_safety()
+                .reasons()
+                .iter()
+                .map(|reason| format!("<li>{}</li>", tr(reason.locale_key())))
+                .collect::<String>();
+
+            tre("campaign_safety_unsafe", &[&reasons])
+        };
+
+        item.set_tool_tip(&QString::from_std_str(details));
+    }
+
+    /// This function adds the known issues of a mod to the tooltip of its name, if it has any.
+    ///
+    /// It has to be called after [ModListUI::set_campaign_safety_data], as that one replaces the tooltip.
+    unsafe fn set_known_issues_data(item: Ptr<QStandardItem>, mod_id: &str, issues: Option<&Vec<BlacklistEntry>>) {
+        if let Some(issues) = issues {
+            let list = issues.iter()
+                .map(|issue| format!("<li>{}</li>", issue.description(mod_id)))
+                .collect::<String>();
+
+            let tool_tip = item.tool_tip().to_std_string() + &tre("mod_known_issues_description", &[&list]);
+            item.set_tool_tip(&QString::from_std_str(tool_tip));
+        }
+    }
+
     pub unsafe fn setup_columns(&self) {
-        self.model.set_column_count(7);
+        self.model.set_column_count(11);
 
         let item_mod_name = QStandardItem::from_q_string(&qtr("mod_name"));
         let item_flags = QStandardItem::from_q_string(&qtr("flags"));
@@ -648,6 +1146,9 @@ impl ModListUI {
         let item_file_size = QStandardItem::from_q_string(&qtr("file_size"));
         let item_time_created = QStandardItem::from_q_string(&qtr("time_created"));
         let item_time_updated = QStandardItem::from_q_string(&qtr("time_updated"));
+        let item_version = QStandardItem::from_q_string(&qtr("mod_version"));
+        let item_last_launched = QStandardItem::from_q_string(&qtr("mod_last_launched"));
+        let item_game_version = QStandardItem::from_q_string(&qtr("mod_game_version"));
 
         self.model.set_horizontal_header_item(0, item_mod_name.into_ptr());
         self.model.set_horizontal_header_item(1, item_flags.into_ptr());
@@ -657,6 +1158,9 @@ impl ModListUI {
         self.model.set_horizontal_header_item(5, item_file_size.into_ptr());
         self.model.set_horizontal_header_item(6, item_time_created.into_ptr());
         self.model.set_horizontal_header_item(7, item_time_updated.into_ptr());
+        self.model.set_horizontal_header_item(8, item_version.into_ptr());
+        self.model.set_horizontal_header_item(9, item_last_launched.into_ptr());
+        self.model.set_horizontal_header_item(10, item_game_version.into_ptr());
 
         html_item_delegate_safe(&self.tree_view().static_upcast::<QObject>().as_ptr(), 0);
         flags_item_delegate_safe(&self.tree_view().static_upcast::<QObject>().as_ptr(), 1);
@@ -664,6 +1168,107 @@ impl ModListUI {
         self.tree_view.header().set_minimum_section_size(24 * 4);
     }
 
+    /// This function returns the grouping currently selected in the header's context menu.
+    pub unsafe fn grouping(&self) -> ModListGrouping {
+        let key = self.grouping_group().checked_action().data().to_string().to_std_string();
+        GROUPINGS.iter()
+            .find(|(_, grouping_key, _)| *grouping_key == key)
+            .map(|(grouping, _, _)| *grouping)
+            .unwrap_or_default()
+    }
+
+    /// This function saves the grouping currently selected in the header's context menu to the settings.
+    pub unsafe fn save_grouping(&self) {
+        set_setting_string("mod_list_grouping", &self.grouping_group().checked_action().data().to_string().to_std_string());
+    }
+
+    /// This function returns the groups the installed mods should be shown in with the provided grouping, in order, with their mods.
+    fn groups(game_config: &GameConfig, grouping: ModListGrouping, data_path: &str, secondary_path: &str, content_path: &str, known_issues: &HashMap<String, Vec<BlacklistEntry>>) -> Vec<(String, Vec<String>)> {
+        if grouping == ModListGrouping::Categories {
+            return game_config.categories_order()
+                .iter()
+                .map(|category| (category.to_owned(), game_config.categories().get(category).cloned().unwrap_or_default()))
+                .collect();
+        }
+
+        let unknown_date = tr("mod_list_group_unknown_date");
+        let with_known_issues = tr("mod_list_group_known_issues");
+        let month_format = time::format_description::parse("[year]-[month]").unwrap();
+
+        let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for modd in game_config.mods().values().filter(|modd| !modd.paths().is_empty()) {
+            let group = match grouping {
+
+                // Group by where the mod was installed from, not by where is loaded from.
+                ModListGrouping::Source => match modd.location(data_path, secondary_path, content_path) {
+                    (_, _, Some(_)) => tr("mod_list_group_workshop"),
+                    (_, true, None) => tr("mod_list_group_secondary"),
+                    _ => tr("mod_list_group_local"),
+                },
+                ModListGrouping::UpdateMonth => OffsetDateTime::from_unix_timestamp(*modd.time_updated() as i64)
+                    .ok()
+                    .filter(|_| *modd.time_updated() != 0)
+                    .and_then(|date| date.format(&month_format).ok())
+                    .unwrap_or_else(|| unknown_date.to_owned()),
+                ModListGrouping::KnownIssues => if known_issues.contains_key(modd.id()) {
+                    with_known_issues.to_owned()
+                } else {
+                    tr("mod_list_group_no_known_issues")
+                },
+                _ => if modd.creator_name().is_empty() {
+                    tr("mod_list_group_unknown_author")
+                } else {
+                    modd.creator_name().to_owned()
+                },
+            };
+
+            groups.entry(group).or_default().push(modd.id().to_owned());
+        }
+
+        let mut groups = groups.into_iter()
+            .map(|(group, mut mods)| {
+                mods.sort_by_key(|mod_id| game_config.mods().get(mod_id).map(|modd| modd.name().to_lowercase()));
+                (group, mods)
+            })
+            .collect::<Vec<_>>();
+
+        // Most recent months first, with undated mods at the end.
+        if grouping == ModListGrouping::UpdateMonth {
+            groups.sort_by(|(a, _), (b, _)| (a == &unknown_date).cmp(&(b == &unknown_date)).then_with(|| b.cmp(a)));
+        }
+
+        // Mods with known issues first, as those are the ones the user wants to check.
+        if grouping == ModListGrouping::KnownIssues {
+            groups.sort_by_key(|(group, _)| group != &with_known_issues);
+        }
+
+        groups
+    }
+
+    /// This function returns the known issues of the enabled mods among the provided ones, by mod.
+    ///
+    /// Only enabled mods are checked, so incompatibilities are only reported for mods that are going to be loaded together.
+    fn known_issues(game: &GameInfo, mods: &HashMap<String, Mod>, game_data_path: &Path) -> HashMap<String, Vec<BlacklistEntry>> {
+        let enabled = mods.values()
+            .filter(|modd| modd.enabled(game_data_path))
+            .map(|modd| modd.id().to_owned())
+            .collect::<Vec<_>>();
+
+        ModBlacklist::load_merged().issues_by_mod(game.key(), &enabled)
+    }
+
+    /// This function returns the installed mods first seen in the amount of days set in the settings, newest first.
+    fn recently_added(game_config: &GameConfig, show_ignored: bool) -> Vec<String> {
+        let days = setting_int("recently_added_days").max(0) as u64;
+        let mut mods = game_config.mods()
+            .values()
+            .filter(|modd| !modd.paths().is_empty() && (show_ignored || !*modd.ignored()) && modd.recently_added(days))
+            .collect::<Vec<_>>();
+
+        mods.sort_by(|a, b| b.first_seen().cmp(a.first_seen()));
+        mods.iter().map(|modd| modd.id().to_owned()).collect()
+    }
+
     pub unsafe fn category_new_dialog(&self, rename: bool) -> Result<Option<String>> {
 
         // Load the UI Template.
@@ -711,6 +1316,91 @@ impl ModListUI {
         }
     }
 
+    pub unsafe fn category_style_dialog(&self, style: &CategoryStyle) -> Result<Option<CategoryStyle>> {
+
+        // Load the UI Template.
+        let template_path = if cfg!(debug_assertions) { CATEGORY_STYLE_VIEW_DEBUG } else { CATEGORY_STYLE_VIEW_RELEASE };
+        let main_widget = load_template(self.tree_view(), template_path)?;
+
+        let dialog = main_widget.static_downcast::<QDialog>();
+        dialog.set_window_title(&qtr("category_style"));
+
+        let color_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "color_label")?;
+        let color_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "color_line_edit")?;
+        let color_pick_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "color_pick_button")?;
+        let icon_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "icon_label")?;
+        let icon_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "icon_line_edit")?;
+        let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
+
+        color_label.set_text(&qtr("category_style_color"));
+        color_line_edit.set_placeholder_text(&qtr("category_style_color_placeholder"));
+        color_pick_button.set_tool_tip(&qtr("category_style_color_pick"));
+        icon_label.set_text(&qtr("category_style_icon"));
+        icon_line_edit.set_placeholder_text(&qtr("category_style_icon_placeholder"));
+
+        if let Some(color) = style.color() {
+            color_line_edit.set_text(&QString::from_std_str(color));
+        }
+
+        if let Some(icon) = style.icon() {
+            icon_line_edit.set_text(&QString::from_std_str(icon));
+        }
+
+        button_box.button(StandardButton::Ok).released().connect(dialog.slot_accept());
+
+        let color_line_edit_picker = color_line_edit.clone();
+        color_pick_button.released().connect(&qt_core::SlotNoArgs::new(&color_pick_button, move || {
+            let current = QColor::from_q_string(&color_line_edit_picker.text());
+            let color = QColorDialog::get_color_2a(&current, &color_line_edit_picker);
+            if color.is_valid() {
+                color_line_edit_picker.set_text(&color.name_0a());
+            }
+        }));
+
+        // Do not allow accepting colors Qt cannot parse.
+        let color_line_edit_validator = color_line_edit.clone();
+        color_line_edit.text_changed().connect(&qt_core::SlotNoArgs::new(&color_line_edit, move || {
+            let text = color_line_edit_validator.text().trimmed();
+            button_box.button(StandardButton::Ok).set_enabled(text.is_empty() || QColor::from_q_string(&text).is_valid());
+        }));
+
+        if dialog.exec() == 1 {
+            let color = color_line_edit.text().trimmed();
+            let color = if color.is_empty() {
+                None
+            } else {
+                Some(QColor::from_q_string(&color).name_0a().to_std_string())
+            };
+
+            let icon = icon_line_edit.text().trimmed().to_std_string();
+            let icon = if icon.is_empty() { None } else { Some(icon) };
+
+            let mut style = CategoryStyle::default();
+            style.set_color(color);
+            style.set_icon(icon);
+
+            Ok(Some(style))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// This function applies the color and icon of a category to its item.
+    ///
+    /// The color is only stored in the category item. The mods inside it get painted with it by the delegate,
+    /// so they always show the color of the category they're in, even after being dragged to another one.
+    pub unsafe fn apply_category_style(item: Ptr<QStandardItem>, style: Option<&CategoryStyle>) {
+        match style.and_then(|style| style.color().as_ref()) {
+            Some(color) => item.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(color)), VALUE_CATEGORY_COLOR),
+            None => item.set_data_2a(&QVariant::new(), VALUE_CATEGORY_COLOR),
+        }
+
+        match style.and_then(|style| style.icon().as_ref()) {
+            Some(icon) => item.set_icon(&QIcon::from_theme_1a(&QString::from_std_str(icon))),
+            None => item.set_icon(&QIcon::new()),
+        }
+    }
+
     pub unsafe fn categories(&self) -> Vec<String> {
         let mut categories = Vec::with_capacity(self.model().row_count_0a() as usize);
         for index in 0..self.model().row_count_0a() {
@@ -751,6 +1441,42 @@ impl ModListUI {
         indexes_visual.iter().map(|x| self.filter().map_to_source(*x)).collect::<Vec<_>>()
     }
 
+    /// This function selects the provided mod, expanding its category and scrolling to it. Returns false if the mod is not in the list.
+    ///
+    /// If the mod is hidden by the filter, the filter is cleared so it can be shown.
+    pub unsafe fn reveal_mod(&self, mod_id: &str) -> bool {
+        let mod_id = QString::from_std_str(mod_id);
+        for category_index in 0..self.model().row_count_0a() {
+            let category = self.model().item_2a(category_index, 0);
+            for mod_index in 0..category.row_count() {
+                let item = category.child_2a(mod_index, 0);
+                if item.data_1a(VALUE_MOD_ID).to_string().compare_q_string(&mod_id) != 0 {
+                    continue;
+                }
+
+                let mut index = self.filter().map_from_source(&item.index());
+                if !index.is_valid() {
+                    self.filter_line_edit().clear();
+                    self.filter_list();
+                    index = self.filter().map_from_source(&item.index());
+                }
+
+                // Other filters may still hide it.
+                if !index.is_valid() {
+                    return false;
+                }
+
+                self.tree_view().expand(&index.parent());
+                self.tree_view().selection_model().select_q_model_index_q_flags_selection_flag(&index, SelectionFlag::ClearAndSelect | SelectionFlag::Rows);
+                self.tree_view().scroll_to_1a(&index);
+                self.tree_view().set_focus_0a();
+                return true;
+            }
+        }
+
+        false
+    }
+
     pub unsafe fn filter_list(&self) {
 
         // Set the pattern to search.
@@ -13,11 +13,13 @@ use qt_widgets::SlotOfQPoint;
 use qt_gui::QCursor;
 
 use qt_core::QBox;
-use qt_core::{SlotNoArgs, SlotOfQString};
+use qt_core::{SlotNoArgs, SlotOfBool, SlotOfQString};
 
 use std::path::PathBuf;
 use std::rc::Rc;
 
+use rpfm_lib::games::pfh_file_type::PFHFileType;
+
 use rpfm_ui_common::clone;
 
 use super::*;
@@ -33,6 +35,8 @@ pub struct ModListUISlots {
     filter_case_sensitive_button: QBox<SlotNoArgs>,
     filter_trigger: QBox<SlotNoArgs>,
 
+    header_context_menu: QBox<SlotOfQPoint>,
+    toggle_last_launched_column: QBox<SlotOfBool>,
     context_menu: QBox<SlotOfQPoint>,
     context_menu_enabler: QBox<SlotNoArgs>,
 
@@ -64,6 +68,17 @@ impl ModListUISlots {
             view.filter_list();
         }));
 
+        let header_context_menu = SlotOfQPoint::new(&view.tree_view, clone!(
+            view => move |_| {
+            view.header_context_menu().exec_1a_mut(&QCursor::pos_0a());
+        }));
+
+        let toggle_last_launched_column = SlotOfBool::new(&view.tree_view, clone!(
+            view => move |checked| {
+            set_setting_bool("show_last_launched_column", checked);
+            view.tree_view.set_column_hidden(9, !checked);
+        }));
+
         let context_menu = SlotOfQPoint::new(&view.tree_view, clone!(
             view => move |_| {
             view.context_menu().exec_1a_mut(&QCursor::pos_0a());
@@ -75,20 +90,55 @@ impl ModListUISlots {
             let all_categories = !selection.is_empty() && selection.iter().all(|index| index.data_1a(VALUE_IS_CATEGORY).to_bool());
             let all_mods = !selection.is_empty() && selection.iter().all(|index| !index.data_1a(VALUE_IS_CATEGORY).to_bool());
 
-            view.category_delete.set_enabled(all_categories);
-            view.category_rename.set_enabled(all_categories && selection.len() == 1);
-            view.category_sort.set_enabled(all_categories && selection.len() == 1);
-            view.categories_send_to_menu.set_enabled(all_mods);
+            // Computed groups are read-only, so categories can only be edited when grouping by them. Same goes for virtual categories.
+            let categories_mode = view.grouping() == ModListGrouping::Categories;
+            let real_categories = all_categories && selection.iter().all(|index| !index.data_1a(VALUE_IS_VIRTUAL_CATEGORY).to_bool());
+            view.category_new.set_enabled(categories_mode);
+            view.category_delete.set_enabled(categories_mode && real_categories);
+            view.category_rename.set_enabled(categories_mode && real_categories && selection.len() == 1);
+            view.category_sort.set_enabled(categories_mode && real_categories && selection.len() == 1);
+            view.category_style.set_enabled(categories_mode && real_categories && selection.len() == 1);
+            view.category_rules.set_enabled(categories_mode);
+            view.categories_send_to_menu.set_enabled(categories_mode && all_mods);
+
+            view.ignore_mods.set_enabled(all_mods);
+            view.ignore_mods.set_checked(all_mods && selection.iter().all(|index| index.data_1a(VALUE_IS_IGNORED).to_bool()));
 
             view.open_in_explorer.set_enabled(all_mods);
             view.open_in_steam.set_enabled(all_mods);
+            view.reveal_in_pack_list.set_enabled(all_mods && selection.len() == 1);
             view.open_in_tool_menu.set_enabled(all_mods);
 
             view.upload_to_workshop.set_enabled(all_mods && selection.len() == 1);
             view.download_from_workshop.set_enabled(all_mods);
+            view.force_redownload.set_enabled(all_mods);
+
+            let all_workshop = all_mods && selection.iter().all(|index| !index.data_1a(VALUE_MOD_STEAM_ID).to_string().is_empty());
+            view.update_workshop_items_now.set_enabled(all_workshop);
+            view.defer_workshop_updates.set_enabled(all_workshop);
+            view.defer_workshop_updates.set_checked(all_workshop && selection.iter().all(|index| index.data_1a(VALUE_DEFER_UPDATES).to_bool()));
+            view.update_from_github.set_enabled(all_mods && selection.iter().all(|index| index.data_1a(VALUE_IS_FROM_GITHUB).to_bool()));
 
             view.copy_to_secondary.set_enabled(all_mods);
+            view.refresh_secondary_copy.set_enabled(all_mods);
             view.move_to_secondary.set_enabled(all_mods);
+
+            // Only movie packs can be treated as mod packs. Once treated as such, their type is Mod, so we need to check the override too.
+            let movie_type = PFHFileType::Movie.to_string();
+            let all_overridden = all_mods && selection.iter().all(|index| index.data_1a(VALUE_TREAT_AS_MOD_PACK).to_bool());
+            let all_movies = all_mods && selection.iter().all(|index| index.data_1a(VALUE_TREAT_AS_MOD_PACK).to_bool() || index.data_1a(VALUE_PACK_TYPE).to_string().to_std_string() == movie_type);
+            view.treat_as_mod_pack.set_enabled(all_movies);
+            view.treat_as_mod_pack.set_checked(all_overridden);
+            view.export_map_bin.set_enabled(all_mods && selection.len() == 1);
+            view.extract_pack.set_enabled(all_mods && selection.len() == 1);
+            view.rescan_mod.set_enabled(all_mods && selection.len() == 1);
+
+            let has_working_folder = all_mods && selection.len() == 1 && !selection[0].data_1a(VALUE_WORKING_FOLDER).to_string().is_empty();
+            view.set_working_folder.set_enabled(all_mods && selection.len() == 1);
+            view.use_working_folder.set_enabled(has_working_folder);
+            view.use_working_folder.set_checked(has_working_folder && selection[0].data_1a(VALUE_USE_WORKING_FOLDER).to_bool());
+
+            view.backup_selected_mods.set_enabled(all_mods);
         }));
 
         let open_in_explorer = SlotNoArgs::new(&view.tree_view, clone!(
@@ -136,6 +186,8 @@ impl ModListUISlots {
             filter_case_sensitive_button,
             filter_trigger,
 
+            header_context_menu,
+            toggle_last_launched_column,
             context_menu,
             context_menu_enabler,
             open_in_explorer,
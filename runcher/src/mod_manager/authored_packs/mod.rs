@@ -0,0 +1,190 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the checks for packs authored by the user.
+//!
+//! Modders usually keep their working copy of a mod in /data, while being subscribed to it in the Workshop.
+//! Comparing both copies tells them if what they have on disk is what they last uploaded, and checking the pack before uploading
+//! catches the usual mistakes: packs saved for another game, or debug leftovers shipped to everyone.
+
+use anyhow::Result;
+use getset::*;
+use sha256::try_digest;
+
+use std::collections::HashMap;
+use std::fs::metadata;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::SystemTime;
+
+use rpfm_lib::files::pack::Pack;
+use rpfm_lib::games::{GameInfo, pfh_file_type::PFHFileType, pfh_version::PFHVersion};
+use rpfm_lib::schema::Schema;
+
+use super::mods::Mod;
+use super::pack_versions::PackGameVersion;
+
+/// Endings of files usually left behind by editors and merge tools, which should never be uploaded.
+const LEFTOVER_FILE_ENDINGS: [&str; 7] = [".bak", ".orig", ".old", ".tmp", ".swp", ".rej", "~"];
+
+lazy_static::lazy_static! {
+
+    /// Hashes of the packs compared, with the size and modification date they had when hashed, so we only hash them again when they change.
+    static ref PACK_HASHES: RwLock<HashMap<PathBuf, (u64, SystemTime, String)>> = RwLock::new(HashMap::new());
+}
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// State of the local copy of an authored mod, compared with the last version uploaded to the Workshop.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UploadStatus {
+
+    /// The local copy is the same as the Workshop one.
+    Uploaded,
+
+    /// The local copy changed since the last upload.
+    Modified,
+
+    /// There is no Workshop copy on disk to compare with, usually because the user is not subscribed to their own mod.
+    Unknown,
+}
+
+/// Problems found in a pack before uploading it.
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct UploadCheck {
+
+    /// Version of the pack.
+    pack_version: PFHVersion,
+
+    /// Version the selected game expects its mod packs to have.
+    game_version: PFHVersion,
+
+    /// Tables of the pack with a version the schema of the selected game doesn't have, with their version in the pack.
+    ///
+    /// Games sharing the same pack version can only be told apart by these.
+    foreign_tables: Vec<(String, i32)>,
+
+    /// Paths of the files in the pack that look like leftovers from editors and merge tools.
+    leftover_files: Vec<String>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl UploadStatus {
+
+    /// This function returns the key of the explanation of the status.
+    pub fn locale_key(&self) -> &'static str {
+        match self {
+            Self::Uploaded => "upload_status_uploaded",
+            Self::Modified => "upload_status_modified",
+            Self::Unknown => "upload_status_unknown",
+        }
+    }
+}
+
+impl UploadCheck {
+
+    /// This function returns if the pack was saved for a game different than the selected one.
+    pub fn wrong_game(&self) -> bool {
+        self.pack_version != self.game_version || !self.foreign_tables.is_empty()
+    }
+
+    /// This function returns if the check found any problem.
+    pub fn has_problems(&self) -> bool {
+        self.wrong_game() || !self.leftover_files.is_empty()
+    }
+}
+
+/// This function returns the upload status of the provided mod, or None if it has no local copy besides the Workshop one.
+///
+/// Only meaningful for mods authored by the user: for anyone else, a local copy differing from the Workshop one is just an outdated copy.
+/// Hashes are cached, so rebuilding the mod list only hashes the packs that changed since the last time.
+pub fn upload_status(modd: &Mod) -> Option<UploadStatus> {
+    let steam_id = modd.steam_id().as_ref()?;
+    let content_path = content_copy(modd, steam_id);
+    let local_path = modd.paths().iter().find(|path| Some(*path) != content_path)?;
+
+    let status = match content_path {
+        Some(content_path) => match (cached_digest(local_path), cached_digest(content_path)) {
+            (Some(local_hash), Some(content_hash)) => if local_hash == content_hash {
+                UploadStatus::Uploaded
+            } else {
+                UploadStatus::Modified
+            },
+            _ => UploadStatus::Unknown,
+        },
+        None => UploadStatus::Unknown,
+    };
+
+    Some(status)
+}
+
+/// This function checks the pack at the provided path for problems that should be fixed before uploading it for the provided game.
+///
+/// Without a schema, only the pack version is used to detect packs saved for another game.
+pub fn upload_check(game: &GameInfo, schema: Option<&Schema>, pack_path: &Path) -> Result<UploadCheck> {
+    let mut pack = Pack::read_and_merge(&[pack_path.to_path_buf()], true, false, false)?;
+
+    let mut leftover_files = pack.files()
+        .keys()
+        .filter(|path| {
+            let path = path.to_lowercase();
+            LEFTOVER_FILE_ENDINGS.iter().any(|ending| path.ends_with(ending))
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    leftover_files.sort();
+
+    let foreign_tables = match schema {
+        Some(schema) => PackGameVersion::read(&mut pack)
+            .table_versions()
+            .iter()
+            .filter(|(table_name, version)| schema.definition_by_name_and_version(table_name, **version).is_none())
+            .map(|(table_name, version)| (table_name.to_owned(), *version))
+            .collect(),
+        None => vec![],
+    };
+
+    Ok(UploadCheck {
+        pack_version: pack.pfh_version(),
+        game_version: game.pfh_version_by_file_type(PFHFileType::Mod),
+        foreign_tables,
+        leftover_files,
+    })
+}
+
+/// This function returns the hash of the file at the provided path, reusing the cached one if the file didn't change since it was hashed.
+fn cached_digest(path: &Path) -> Option<String> {
+    let metadata = metadata(path).ok()?;
+    let size = metadata.len();
+    let modified = metadata.modified().ok()?;
+
+    if let Some((cached_size, cached_modified, hash)) = PACK_HASHES.read().unwrap().get(path) {
+        if *cached_size == size && *cached_modified == modified {
+            return Some(hash.to_owned());
+        }
+    }
+
+    let hash = try_digest(path).ok()?;
+    PACK_HASHES.write().unwrap().insert(path.to_path_buf(), (size, modified, hash.clone()));
+    Some(hash)
+}
+
+/// Content paths are always in a folder named after the mod's steam id.
+fn content_copy<'a>(modd: &'a Mod, steam_id: &str) -> Option<&'a PathBuf> {
+    modd.paths()
+        .iter()
+        .find(|path| path.parent().and_then(|parent| parent.file_name()).is_some_and(|name| name.to_string_lossy() == steam_id))
+}
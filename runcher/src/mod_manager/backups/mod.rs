@@ -0,0 +1,227 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for backing up mods to zip files, and restoring them.
+//!
+//! Backups contain the packs of the mods, their images if they have one, and a manifest with the mod's data,
+//! so mods can be restored with their info even if they're no longer available in the workshop.
+
+use anyhow::{anyhow, Result};
+use getset::*;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_reader, to_string_pretty};
+use sha256::try_digest;
+use zip::{CompressionMethod, write::SimpleFileOptions, ZipArchive, ZipWriter};
+
+use std::fs::{rename, DirBuilder, File};
+use std::io::{BufReader, BufWriter, Read, Seek, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rpfm_lib::games::GameInfo;
+
+use super::game_config::GameConfig;
+use super::mods::Mod;
+use super::secondary_mods_path;
+
+const BACKUP_MANIFEST_FILE_NAME: &str = "runcher_backup.json";
+const BACKUP_FILE_NAME_START: &str = "runcher_backup_";
+const BACKUP_FILE_NAME_END: &str = ".zip";
+
+/// Extension added to the files being restored, until the whole backup has been restored.
+const RESTORE_TEMP_EXTENSION: &str = "part";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct ModBackup {
+
+    /// Key of the game the mods in this backup are for.
+    game_key: String,
+
+    /// Mods in the backup.
+    mods: Vec<ModBackupEntry>,
+}
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct ModBackupEntry {
+
+    /// Name of the pack within the backup.
+    file_name: String,
+
+    /// Hash of the pack, to detect corrupted backups.
+    hash: String,
+
+    /// Data of the mod when the backup was made. Paths are not kept, as they're not valid on restore.
+    modd: Mod,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function creates a backup of the provided mods in the provided folder, returning the path of the backup.
+pub fn backup_mods(game: &GameInfo, game_config: &GameConfig, mod_ids: &[String], folder: &Path) -> Result<PathBuf> {
+    let date = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let backup_path = folder.join(format!("{}{}_{}{}", BACKUP_FILE_NAME_START, game.key(), date, BACKUP_FILE_NAME_END));
+
+    let mut manifest = ModBackup::default();
+    manifest.set_game_key(game.key().to_owned());
+
+    let mut zip = ZipWriter::new(BufWriter::new(File::create(&backup_path)?));
+
+    // Packs are big, so this can take a while. But most of their files are not compressed, so the backup ends up a lot smaller.
+    let options = SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Deflated)
+        .large_file(true);
+
+    for mod_id in mod_ids {
+        if let Some(modd) = game_config.mods().get(mod_id) {
+            if let Some(path) = modd.paths().first() {
+                let file_name = path.file_name().ok_or_else(|| anyhow!("Invalid path for mod {}.", mod_id))?.to_string_lossy().to_string();
+
+                zip.start_file(file_name.to_owned(), options)?;
+                std::io::copy(&mut BufReader::new(File::open(path)?), &mut zip)?;
+
+                let mut image_path = path.to_path_buf();
+                image_path.set_extension("png");
+                if image_path.is_file() {
+                    if let Some(image_name) = image_path.file_name() {
+                        zip.start_file(image_name.to_string_lossy().to_string(), options)?;
+                        std::io::copy(&mut BufReader::new(File::open(&image_path)?), &mut zip)?;
+                    }
+                }
+
+                let mut modd = modd.clone();
+                modd.paths_mut().clear();
+
+                let mut entry = ModBackupEntry::default();
+                entry.set_file_name(file_name);
+                entry.set_hash(try_digest(path.as_path())?);
+                entry.set_modd(modd);
+
+                manifest.mods_mut().push(entry);
+            }
+        }
+    }
+
+    if manifest.mods().is_empty() {
+        drop(zip);
+        std::fs::remove_file(&backup_path)?;
+        return Err(anyhow!("None of the selected mods has a pack to backup."));
+    }
+
+    zip.start_file(BACKUP_MANIFEST_FILE_NAME, SimpleFileOptions::default().compression_method(CompressionMethod::Deflated))?;
+    zip.write_all(to_string_pretty(&manifest)?.as_bytes())?;
+    zip.finish()?.flush()?;
+
+    Ok(backup_path)
+}
+
+/// This function restores the mods in the provided backup to the secondary folder, returning the ids of the restored mods.
+///
+/// Mods not in the game config are added to it with the data from the backup, so their info is kept even if they're no longer in the workshop.
+///
+/// Files are first extracted with a temporary extension, and only renamed once the whole backup has been extracted and checked.
+/// If anything fails, the extracted files are removed, so a failed restore doesn't leave partial packs in the secondary folder.
+pub fn restore_mods_backup(game: &GameInfo, game_config: &mut GameConfig, backup_path: &Path) -> Result<Vec<String>> {
+    let secondary_path = secondary_mods_path(game.key())?;
+    DirBuilder::new().recursive(true).create(&secondary_path)?;
+
+    let mut zip = ZipArchive::new(BufReader::new(File::open(backup_path)?))?;
+    let manifest: ModBackup = from_reader(zip.by_name(BACKUP_MANIFEST_FILE_NAME).map_err(|_| anyhow!("This file is not a Runcher backup."))?)?;
+
+    if manifest.game_key() != game.key() {
+        return Err(anyhow!("This backup is for another game ({}).", manifest.game_key()));
+    }
+
+    let mut extracted = vec![];
+    if let Err(error) = extract_backup(&mut zip, &manifest, &secondary_path, &mut extracted) {
+        for (temp_path, _) in &extracted {
+            let _ = std::fs::remove_file(temp_path);
+        }
+
+        return Err(error);
+    }
+
+    for (temp_path, path) in &extracted {
+        rename(temp_path, path)?;
+    }
+
+    let mut restored = vec![];
+    for entry in manifest.mods() {
+        if !game_config.mods().contains_key(entry.modd().id()) {
+            let mut modd = entry.modd().clone();
+            modd.set_paths(vec![secondary_path.join(backup_file_name(entry)?)]);
+            game_config.mods_mut().insert(modd.id().to_owned(), modd);
+        }
+
+        restored.push(entry.modd().id().to_owned());
+    }
+
+    game_config.save(game)?;
+
+    Ok(restored)
+}
+
+/// This function extracts the packs and images of the provided backup to temporary files in the provided folder.
+///
+/// The temporary paths and the paths they should end up in are added to `extracted` as they're created, so they can be removed on error.
+fn extract_backup<R: Read + Seek>(zip: &mut ZipArchive<R>, manifest: &ModBackup, folder: &Path, extracted: &mut Vec<(PathBuf, PathBuf)>) -> Result<()> {
+    for entry in manifest.mods() {
+        let file_name = backup_file_name(entry)?;
+        let pack_path = folder.join(&file_name);
+        let temp_pack_path = temp_path(&pack_path);
+
+        extracted.push((temp_pack_path.clone(), pack_path));
+        {
+            let mut file = BufWriter::new(File::create(&temp_pack_path)?);
+            std::io::copy(&mut zip.by_name(entry.file_name())?, &mut file)?;
+            file.flush()?;
+        }
+
+        if &try_digest(temp_pack_path.as_path())? != entry.hash() {
+            return Err(anyhow!("The pack {} in the backup is corrupted.", file_name));
+        }
+
+        let mut image_path = PathBuf::from(&file_name);
+        image_path.set_extension("png");
+        let image_name = image_path.to_string_lossy().to_string();
+        if let Ok(mut image) = zip.by_name(&image_name) {
+            let image_path = folder.join(&image_name);
+            let temp_image_path = temp_path(&image_path);
+
+            extracted.push((temp_image_path.clone(), image_path));
+            let mut file = BufWriter::new(File::create(&temp_image_path)?);
+            std::io::copy(&mut image, &mut file)?;
+            file.flush()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Only the file name is used, so broken or malicious backups cannot write outside the secondary folder.
+fn backup_file_name(entry: &ModBackupEntry) -> Result<String> {
+    Path::new(entry.file_name()).file_name()
+        .map(|file_name| file_name.to_string_lossy().to_string())
+        .ok_or_else(|| anyhow!("Invalid file name in backup: {}.", entry.file_name()))
+}
+
+fn temp_path(path: &Path) -> PathBuf {
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".");
+    temp_path.push(RESTORE_TEMP_EXTENSION);
+    PathBuf::from(temp_path)
+}
@@ -0,0 +1,199 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the bughunt mode, to find the mod causing a crash by hand.
+//!
+//! A bughunt keeps a baseline load order, and the outcome of each launch done with some of its mods disabled, as reported by the user.
+//! With enough runs, the mods that are enabled in every crash and disabled in every clean run stand out.
+//! The bughunt is saved after each run, so it can be continued after closing Runcher.
+
+use anyhow::Result;
+use getset::*;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_reader, to_writer};
+
+use std::fs::{remove_file, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rpfm_ui_common::settings::config_path;
+
+use super::load_order::LoadOrder;
+
+const BUGHUNT_FILE_NAME_START: &str = "bughunt_";
+const BUGHUNT_FILE_NAME_END: &str = ".json";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct BugHunt {
+
+    /// Load order the bughunt started with. Runs are done with this load order, minus the mods disabled in each run.
+    baseline: LoadOrder,
+
+    /// Runs done so far, oldest first.
+    runs: Vec<BugHuntRun>,
+}
+
+#[derive(Clone, Debug, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct BugHuntRun {
+
+    /// Time the run was recorded, in seconds since the unix epoch.
+    timestamp: u64,
+
+    /// Mods of the baseline disabled in this run.
+    disabled: Vec<String>,
+
+    /// If the game crashed in this run, as reported by the user.
+    crashed: bool,
+}
+
+/// Outcome of the runs of a mod of the baseline.
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct SuspectSummary {
+    mod_id: String,
+    crashes_enabled: usize,
+    crashes_disabled: usize,
+    clean_enabled: usize,
+    clean_disabled: usize,
+}
+
+/// What the runs tell us about a mod.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SuspectVerdict {
+
+    /// The game crashed every time the mod was enabled, and never crashed without it.
+    Likely,
+
+    /// The game worked at least once with the mod enabled.
+    Cleared,
+
+    /// Not enough runs to know.
+    Unknown,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl BugHunt {
+
+    /// This function starts a new bughunt with the provided load order as baseline.
+    pub fn new(baseline: &LoadOrder) -> Self {
+        Self {
+            baseline: baseline.clone(),
+            runs: vec![],
+        }
+    }
+
+    /// This function loads the bughunt in progress of the provided game, if any.
+    pub fn load(game_key: &str) -> Option<Self> {
+        bughunt_path(game_key).ok()
+            .and_then(|path| File::open(path).ok())
+            .and_then(|file| from_reader(BufReader::new(file)).ok())
+    }
+
+    pub fn save(&self, game_key: &str) -> Result<()> {
+        let mut file = BufWriter::new(File::create(bughunt_path(game_key)?)?);
+        to_writer(&mut file, self)?;
+        file.flush()?;
+        Ok(())
+    }
+
+    /// This function removes the bughunt in progress of the provided game, if any.
+    pub fn remove(game_key: &str) -> Result<()> {
+        let path = bughunt_path(game_key)?;
+        if path.is_file() {
+            remove_file(path)?;
+        }
+
+        Ok(())
+    }
+
+    /// This function returns the baseline load order without the provided mods.
+    pub fn load_order_without(&self, disabled: &[String]) -> LoadOrder {
+        let mut load_order = self.baseline.clone();
+        load_order.mods_mut().retain(|mod_id| !disabled.contains(mod_id));
+        load_order
+    }
+
+    /// This function records the outcome of a run done with the provided mods disabled.
+    pub fn add_run(&mut self, disabled: &[String], crashed: bool) -> Result<()> {
+        self.runs.push(BugHuntRun {
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            disabled: disabled.to_vec(),
+            crashed,
+        });
+
+        Ok(())
+    }
+
+    /// This function returns the outcome of the runs for each mod of the baseline, in load order.
+    pub fn summary(&self) -> Vec<SuspectSummary> {
+        self.baseline.mods()
+            .iter()
+            .map(|mod_id| {
+                let mut summary = SuspectSummary {
+                    mod_id: mod_id.to_owned(),
+                    ..Default::default()
+                };
+
+                for run in &self.runs {
+                    match (run.crashed, run.disabled.contains(mod_id)) {
+                        (true, false) => summary.crashes_enabled += 1,
+                        (true, true) => summary.crashes_disabled += 1,
+                        (false, false) => summary.clean_enabled += 1,
+                        (false, true) => summary.clean_disabled += 1,
+                    }
+                }
+
+                summary
+            })
+            .collect()
+    }
+}
+
+impl SuspectSummary {
+
+    /// This function returns what the runs tell us about the mod.
+    ///
+    /// This assumes the crash is reproducible: a single clean run with the mod enabled clears it.
+    pub fn verdict(&self) -> SuspectVerdict {
+        if self.clean_enabled > 0 {
+            SuspectVerdict::Cleared
+        } else if self.crashes_enabled > 0 && self.crashes_disabled == 0 && self.clean_disabled > 0 {
+            SuspectVerdict::Likely
+        } else {
+            SuspectVerdict::Unknown
+        }
+    }
+}
+
+impl SuspectVerdict {
+
+    /// This function returns the key of the name of the verdict.
+    pub fn locale_key(&self) -> &'static str {
+        match self {
+            Self::Likely => "bughunt_verdict_likely",
+            Self::Cleared => "bughunt_verdict_cleared",
+            Self::Unknown => "bughunt_verdict_unknown",
+        }
+    }
+}
+
+fn bughunt_path(game_key: &str) -> Result<PathBuf> {
+    Ok(config_path()?.join(format!("{BUGHUNT_FILE_NAME_START}{game_key}{BUGHUNT_FILE_NAME_END}")))
+}
@@ -0,0 +1,161 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the interoperability with CA's launcher.
+//!
+//! The launcher keeps its own list of mods, with their enabled state and order, in a json file in the user's AppData.
+//! By reading and writing that list, users can switch between Runcher and the official launcher without losing their mod selection.
+
+use anyhow::{anyhow, Result};
+use directories::BaseDirs;
+use serde_json::{to_string, Value};
+
+use std::fs::{read_dir, remove_file, rename, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use rpfm_lib::games::GameInfo;
+
+use super::game_quirks::game_quirks;
+use super::load_order::LoadOrder;
+
+const CA_LAUNCHER_FOLDER: &str = "The Creative Assembly/Launcher";
+const MOD_DATA_FILE_NAME_END: &str = "-moddata.dat";
+
+const KEY_GAME: &str = "game";
+const KEY_PACK_FILE: &str = "packfile";
+const KEY_ACTIVE: &str = "active";
+const KEY_ORDER: &str = "order";
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function returns the path of the mod data file of CA's launcher.
+///
+/// The launcher names the file after the date its format was introduced, so if there's more than one, we use the last modified one.
+pub fn mod_data_path() -> Result<PathBuf> {
+    let base_dirs = BaseDirs::new().ok_or_else(|| anyhow!("Failed to get the AppData path."))?;
+    let folder = base_dirs.data_dir().join(CA_LAUNCHER_FOLDER);
+
+    read_dir(&folder)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.file_name().map(|name| name.to_string_lossy().ends_with(MOD_DATA_FILE_NAME_END)).unwrap_or(false))
+        .max_by_key(|path| path.metadata().and_then(|metadata| metadata.modified()).ok())
+        .ok_or_else(|| anyhow!("CA's launcher mod data not found in {}. Open the official launcher at least once to generate it.", folder.to_string_lossy()))
+}
+
+/// This function returns the enabled packs of the provided game in CA's launcher, in their load order.
+///
+/// Each pack comes with its workshop id, if it's installed from the workshop.
+pub fn import_load_order(game: &GameInfo) -> Result<Vec<(String, Option<String>)>> {
+    let key = launcher_key(game)?;
+    let entries = read_mod_data(&mod_data_path()?)?;
+
+    let mut packs = entries.iter()
+        .filter(|entry| is_entry_for_game(entry, &key))
+        .filter(|entry| entry[KEY_ACTIVE].as_bool().unwrap_or(false))
+        .filter_map(|entry| Some((entry[KEY_ORDER].as_u64().unwrap_or(u64::MAX), pack_name(entry)?, steam_id(entry))))
+        .collect::<Vec<_>>();
+
+    packs.sort_by_key(|(order, _, _)| *order);
+    Ok(packs.into_iter().map(|(_, pack, steam_id)| (pack, steam_id)).collect())
+}
+
+/// This function writes the enabled packs of the provided load order to the mod data of CA's launcher.
+///
+/// The launcher only knows about the mods it detected itself, so we can only update those.
+/// Returns the enabled packs the launcher doesn't know about.
+pub fn export_load_order(game: &GameInfo, load_order: &LoadOrder) -> Result<Vec<String>> {
+    let key = launcher_key(game)?;
+    let path = mod_data_path()?;
+    let mut entries = read_mod_data(&path)?;
+
+    let enabled = load_order.mods().iter()
+        .chain(load_order.movies().iter())
+        .collect::<Vec<_>>();
+
+    // Disabled mods go after the enabled ones, keeping their relative order.
+    let mut disabled = vec![];
+    let mut found = vec![];
+    for (index, entry) in entries.iter_mut().enumerate() {
+        if !is_entry_for_game(entry, &key) {
+            continue;
+        }
+
+        let position = pack_name(entry).and_then(|pack| enabled.iter().position(|mod_id| **mod_id == pack));
+        match position {
+            Some(position) => {
+                entry[KEY_ACTIVE] = Value::Bool(true);
+                entry[KEY_ORDER] = Value::from(position + 1);
+                found.push(position);
+            }
+            None => {
+                entry[KEY_ACTIVE] = Value::Bool(false);
+                disabled.push((entry[KEY_ORDER].as_u64().unwrap_or(u64::MAX), index));
+            }
+        }
+    }
+
+    disabled.sort_by_key(|(order, _)| *order);
+    for (offset, (_, index)) in disabled.iter().enumerate() {
+        entries[*index][KEY_ORDER] = Value::from(enabled.len() + offset + 1);
+    }
+
+    // Write to a temporary file first, so a failed write doesn't leave the launcher with a broken mod list.
+    let temp_path = path.with_extension("dat.part");
+    let result = write_mod_data(&temp_path, &entries).and_then(|_| rename(&temp_path, &path).map_err(From::from));
+    if result.is_err() {
+        let _ = remove_file(&temp_path);
+    }
+    result?;
+
+    Ok(enabled.iter()
+        .enumerate()
+        .filter(|(index, _)| !found.contains(index))
+        .map(|(_, mod_id)| mod_id.to_string())
+        .collect())
+}
+
+/// This function returns the key CA's launcher uses for the provided game, if we know it.
+fn launcher_key(game: &GameInfo) -> Result<String> {
+    game_quirks(game).ca_launcher_key()
+        .clone()
+        .ok_or_else(|| anyhow!("This game doesn't use CA's launcher, or its mod data format is not known."))
+}
+
+fn read_mod_data(path: &Path) -> Result<Vec<Value>> {
+    let file = BufReader::new(File::open(path)?);
+    serde_json::from_reader(file).map_err(From::from)
+}
+
+fn write_mod_data(path: &Path, entries: &[Value]) -> Result<()> {
+    let mut file = BufWriter::new(File::create(path)?);
+    file.write_all(to_string(entries)?.as_bytes())?;
+    file.flush().map_err(From::from)
+}
+
+fn is_entry_for_game(entry: &Value, key: &str) -> bool {
+    entry[KEY_GAME].as_str() == Some(key)
+}
+
+/// The launcher stores the full path of the pack. We only need the pack name.
+fn pack_name(entry: &Value) -> Option<String> {
+    entry[KEY_PACK_FILE].as_str()
+        .and_then(|path| PathBuf::from(path).file_name().map(|name| name.to_string_lossy().to_string()))
+}
+
+/// Workshop packs live in a folder named after their workshop id, so we get the id from there.
+fn steam_id(entry: &Value) -> Option<String> {
+    entry[KEY_PACK_FILE].as_str()
+        .and_then(|path| PathBuf::from(path).parent().and_then(|parent| parent.file_name()).map(|name| name.to_string_lossy().to_string()))
+        .filter(|id| !id.is_empty() && id.chars().all(|char| char.is_ascii_digit()))
+}
@@ -0,0 +1,145 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the classification of mods by whether they can be added to an ongoing campaign.
+//!
+//! Most mods only change data the game reads when it needs it, so they can be added at any point. But the starting position of a campaign
+//! is baked into the save when the campaign starts, and scripts that set things up on the first turn never run on an ongoing campaign.
+//! Adding mods that change these mid-campaign usually breaks the campaign, sometimes many turns later. We can only guess which mods do it
+//! from their contents, so this is a heuristic, not a guarantee.
+
+use getset::*;
+use serde::{Deserialize, Serialize};
+
+use rpfm_lib::files::{Container, pack::Pack};
+
+use super::game_config::GameConfig;
+
+/// Name of the file containing the starting position of a campaign.
+const STARTPOS_FILE_NAME: &str = "startpos.esf";
+
+/// Prefix of the tables used to generate the starting position of the campaigns.
+const STARTPOS_TABLES_PREFIX: &str = "db/start_pos_";
+
+/// Folder of the scripts only loaded in campaign.
+const CAMPAIGN_SCRIPTS_FOLDER: &str = "script/campaign/";
+
+/// Text in campaign scripts that means they set up the campaign when it starts. Listeners alone are left out,
+/// as nearly every campaign script has them and most work fine when added mid-campaign.
+const CAMPAIGN_SCRIPT_MARKERS: [&str; 3] = ["is_new_game", "add_first_tick_callback_new", "first_tick_cb"];
+
+/// Version of the classification. Bump it when the checks change, so cached classifications are done again.
+const CAMPAIGN_SAFETY_VERSION: u32 = 1;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// Reasons why a mod may need a new campaign.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CampaignSafetyReason {
+
+    /// The mod contains the starting position of a campaign.
+    StartPos,
+
+    /// The mod contains tables used to generate the starting position of the campaigns.
+    StartPosTables,
+
+    /// The mod contains campaign scripts that set up the campaign when it starts.
+    CampaignScripts,
+}
+
+/// Classification of a mod by whether it can be added to an ongoing campaign.
+#[derive(Clone, Debug, Default, PartialEq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct CampaignSafety {
+
+    /// Reasons why the mod may need a new campaign. If empty, the mod is considered safe to add mid-campaign.
+    reasons: Vec<CampaignSafetyReason>,
+
+    /// Version of the checks used to classify the mod. 0 in classifications saved before we versioned them.
+    #[serde(default)]
+    #[getset(skip)]
+    version: u32,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl CampaignSafetyReason {
+
+    /// This function returns the key of the explanation of the reason.
+    pub fn locale_key(&self) -> &'static str {
+        match self {
+            Self::StartPos => "campaign_safety_reason_startpos",
+            Self::StartPosTables => "campaign_safety_reason_startpos_tables",
+            Self::CampaignScripts => "campaign_safety_reason_campaign_scripts",
+        }
+    }
+}
+
+impl CampaignSafety {
+
+    /// This function classifies the provided pack. Only campaign scripts are loaded, to look for the setup of new campaigns in them.
+    pub fn read(pack: &mut Pack) -> Self {
+        let mut reasons = vec![];
+
+        let paths = pack.files().keys().map(|path| path.to_lowercase()).collect::<Vec<_>>();
+        if paths.iter().any(|path| path.ends_with(STARTPOS_FILE_NAME)) {
+            reasons.push(CampaignSafetyReason::StartPos);
+        }
+
+        if paths.iter().any(|path| path.starts_with(STARTPOS_TABLES_PREFIX)) {
+            reasons.push(CampaignSafetyReason::StartPosTables);
+        }
+
+        let has_campaign_hooks = pack.files_mut()
+            .iter_mut()
+            .filter(|(path, _)| {
+                let path = path.to_lowercase();
+                path.starts_with(CAMPAIGN_SCRIPTS_FOLDER) && path.ends_with(".lua")
+            })
+            .any(|(_, file)| {
+                file.load().is_ok() && file.cached().is_ok_and(|bytes| {
+                    let script = String::from_utf8_lossy(bytes);
+                    CAMPAIGN_SCRIPT_MARKERS.iter().any(|marker| script.contains(marker))
+                })
+            });
+
+        if has_campaign_hooks {
+            reasons.push(CampaignSafetyReason::CampaignScripts);
+        }
+
+        Self {
+            reasons,
+            version: CAMPAIGN_SAFETY_VERSION,
+        }
+    }
+
+    /// This function returns if the classification was done with older checks, so it needs to be done again.
+    pub fn is_outdated(&self) -> bool {
+        self.version < CAMPAIGN_SAFETY_VERSION
+    }
+
+    /// This function returns if the mod can be added to an ongoing campaign.
+    pub fn is_safe(&self) -> bool {
+        self.reasons.is_empty()
+    }
+}
+
+/// This function returns the provided mods that may need a new campaign and are not in the provided list of mods of a save, in the same order.
+pub fn unsafe_mods_for_save(game_config: &GameConfig, mod_ids: &[String], save_mods: &[String]) -> Vec<String> {
+    mod_ids.iter()
+        .filter(|mod_id| !save_mods.contains(mod_id))
+        .filter(|mod_id| game_config.mods().get(*mod_id).is_some_and(|modd| !modd.campaign_safety().is_safe()))
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,116 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the rules to automatically assign categories to mods.
+//!
+//! Each rule matches a pattern against the pack name, the Workshop tags or the author of a mod, and sends the matching mods to a category.
+//! Rules only apply to mods still in the default category, so they never undo what the user sorted by hand.
+
+use getset::*;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::mod_manager::mods::Mod;
+
+use super::game_config::{GameConfig, DEFAULT_CATEGORY};
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, PartialEq, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct CategoryRule {
+
+    /// Category the matching mods are sent to. It's created if it doesn't exist.
+    category: String,
+
+    /// Data of the mod the pattern is matched against.
+    field: CategoryRuleField,
+
+    /// Case-insensitive regex to match.
+    pattern: String,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CategoryRuleField {
+    #[default]
+    PackName,
+    Tag,
+    Author,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl CategoryRule {
+
+    /// This function returns the regex of the rule, or None if the rule is incomplete or its pattern is invalid.
+    pub fn regex(&self) -> Option<Regex> {
+        if self.category.trim().is_empty() || self.pattern.is_empty() {
+            return None;
+        }
+
+        RegexBuilder::new(&self.pattern).case_insensitive(true).build().ok()
+    }
+}
+
+impl CategoryRuleField {
+
+    pub fn all() -> [Self; 3] {
+        [Self::PackName, Self::Tag, Self::Author]
+    }
+
+    pub fn locale_key(&self) -> &'static str {
+        match self {
+            Self::PackName => "category_rule_field_pack_name",
+            Self::Tag => "category_rule_field_tag",
+            Self::Author => "category_rule_field_author",
+        }
+    }
+
+    fn is_match(&self, regex: &Regex, modd: &Mod) -> bool {
+        match self {
+            Self::PackName => regex.is_match(modd.id()),
+            Self::Tag => modd.tags().iter().any(|tag| regex.is_match(tag)),
+            Self::Author => regex.is_match(modd.creator_name()) || (!modd.creator().is_empty() && regex.is_match(modd.creator())),
+        }
+    }
+}
+
+/// This function returns the mods in the default category matched by the provided rules, alongside the category they'd be moved to.
+///
+/// Rules are checked in order, and the first one matching a mod wins. The result is sorted by mod id.
+pub fn category_rule_moves(rules: &[CategoryRule], game_config: &GameConfig) -> Vec<(String, String)> {
+    let regexes = rules.iter()
+        .filter_map(|rule| rule.regex().map(|regex| (regex, rule)))
+        .collect::<Vec<_>>();
+
+    if regexes.is_empty() {
+        return vec![];
+    }
+
+    let mut moves = game_config.categories()
+        .get(DEFAULT_CATEGORY)
+        .map(|mod_ids| mod_ids.iter()
+            .filter_map(|mod_id| game_config.mods().get(mod_id))
+            .filter_map(|modd| regexes.iter()
+                .find(|(regex, rule)| rule.field.is_match(regex, modd))
+                .map(|(_, rule)| (modd.id().to_owned(), rule.category.trim().to_owned()))
+            )
+            .filter(|(_, category)| category != DEFAULT_CATEGORY)
+            .collect::<Vec<_>>()
+        )
+        .unwrap_or_default();
+
+    moves.sort();
+    moves
+}
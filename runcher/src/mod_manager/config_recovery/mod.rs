@@ -0,0 +1,233 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the recovery of config files that fail to load.
+//!
+//! When a game config or a profile cannot be parsed, we back up the broken file, find out where exactly it's broken,
+//! and rebuild as much as we can from the parts of it that are still readable, instead of silently starting from scratch.
+
+use anyhow::Result;
+use getset::*;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Key of the format version in the config files.
+const VERSION_KEY: &str = "version";
+
+const BACKUP_EXTENSION: &str = "corrupt";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct ConfigRecovery {
+
+    /// Path of the broken file.
+    path: PathBuf,
+
+    /// Path of the untouched copy of the broken file.
+    backup_path: PathBuf,
+
+    /// Field of the file where the parsing failed, like `mods.my_mod.pack.paths[0]`. Empty if it failed outside any field.
+    field: String,
+
+    /// Line and column where the parsing failed.
+    position: (usize, usize),
+
+    /// Error returned by the parser.
+    error: String,
+
+    /// If the file was saved by a newer version of Runcher, which may explain why it cannot be read.
+    newer_version: bool,
+
+    /// If the file is valid json, so parts of it could be recovered.
+    readable: bool,
+
+    /// Parts of the file that couldn't be recovered, with the reason why.
+    lost: Vec<String>,
+}
+
+/// Nesting level of the json text, used to find out in which field an error is.
+enum JsonLevel {
+    Object(Option<String>),
+    Array(usize),
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl ConfigRecovery {
+
+    /// This function backs up the broken file at the provided path, and prepares the report of the recovery.
+    ///
+    /// The backup is made before anything is recovered, so the original file can be fixed by hand if the recovery is not good enough.
+    pub fn new(path: &Path, data: &[u8], error: &serde_json::Error, current_version: u32) -> Result<Self> {
+        let file_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let backup_path = path.with_file_name(format!("{file_name}.{timestamp}.{BACKUP_EXTENSION}"));
+        std::fs::write(&backup_path, data)?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            backup_path,
+            field: field_path(data, error.line(), error.column()),
+            position: (error.line(), error.column()),
+            error: error.to_string(),
+            newer_version: config_version(data) > current_version,
+            readable: !config_value(data).is_null(),
+            lost: vec![],
+        })
+    }
+
+    /// This function reads the field with the provided key from the provided json object, or returns its default value if it cannot be read.
+    pub fn field<T: DeserializeOwned + Default>(&mut self, value: &Value, key: &str) -> T {
+        match value.get(key) {
+            Some(field) => match serde_json::from_value(field.clone()) {
+                Ok(field) => field,
+                Err(error) => {
+                    self.lost.push(format!("{key}: {error}"));
+                    T::default()
+                }
+            },
+            None => T::default(),
+        }
+    }
+
+    /// This function reads the entries of the map with the provided key from the provided json object one by one,
+    /// so a single broken entry doesn't take the rest of the map with it.
+    ///
+    /// Returns the readable entries, and the unreadable ones as json, so they can be salvaged further.
+    pub fn map_entries<T: DeserializeOwned>(&mut self, value: &Value, key: &str) -> (BTreeMap<String, T>, BTreeMap<String, Value>) {
+        let mut entries = BTreeMap::new();
+        let mut broken = BTreeMap::new();
+
+        match value.get(key) {
+            Some(Value::Object(map)) => for (entry_key, entry) in map {
+                match serde_json::from_value(entry.clone()) {
+                    Ok(entry) => { entries.insert(entry_key.to_owned(), entry); },
+                    Err(error) => {
+                        self.lost.push(format!("{key}.{entry_key}: {error}"));
+                        broken.insert(entry_key.to_owned(), entry.clone());
+                    }
+                }
+            },
+            Some(_) => self.lost.push(format!("{key}: not a map")),
+            None => {},
+        }
+
+        (entries, broken)
+    }
+
+    /// This function reads the list of strings with the provided key from the provided json object, skipping the items that are not strings.
+    pub fn strings(&mut self, value: &Value, key: &str) -> Vec<String> {
+        match value.get(key) {
+            Some(Value::Array(items)) => items.iter()
+                .filter_map(|item| item.as_str().map(|item| item.to_owned()))
+                .collect(),
+            Some(_) => {
+                self.lost.push(format!("{key}: not a list"));
+                vec![]
+            },
+            None => vec![],
+        }
+    }
+}
+
+/// This function returns the readable parts of the provided config file as json. If the file is not even valid json, there's nothing to read.
+pub fn config_value(data: &[u8]) -> Value {
+    serde_json::from_slice(data).unwrap_or(Value::Null)
+}
+
+/// This function returns the format version of the provided config file. Files from before the format was versioned, or not readable at all, are version 0.
+pub fn config_version(data: &[u8]) -> u32 {
+    config_value(data).get(VERSION_KEY)
+        .and_then(|version| version.as_u64())
+        .unwrap_or(0) as u32
+}
+
+/// This function returns the path of the field at the provided line and column of a json file, like `mods.my_mod.pack.paths[0]`.
+///
+/// It only tracks the nesting of the text before the position, so it works even if the file is not valid json.
+fn field_path(data: &[u8], line: usize, column: usize) -> String {
+
+    // Lines and columns reported by serde_json start at 1.
+    let line_start = data.split_inclusive(|byte| *byte == b'\n')
+        .take(line.saturating_sub(1))
+        .map(|line| line.len())
+        .sum::<usize>();
+    let offset = (line_start + column.saturating_sub(1)).min(data.len());
+
+    let mut levels: Vec<JsonLevel> = vec![];
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut string = vec![];
+    let mut last_string = None;
+
+    for byte in &data[..offset] {
+        if in_string {
+            if escaped {
+                escaped = false;
+                string.push(*byte);
+            } else if *byte == b'\\' {
+                escaped = true;
+            } else if *byte == b'"' {
+                in_string = false;
+                last_string = Some(String::from_utf8_lossy(&string).to_string());
+            } else {
+                string.push(*byte);
+            }
+
+            continue;
+        }
+
+        match byte {
+            b'"' => {
+                in_string = true;
+                string.clear();
+            }
+            b'{' => levels.push(JsonLevel::Object(None)),
+            b'[' => levels.push(JsonLevel::Array(0)),
+            b'}' | b']' => { levels.pop(); },
+            b':' => if let Some(JsonLevel::Object(key)) = levels.last_mut() {
+                *key = last_string.take();
+            },
+            b',' => match levels.last_mut() {
+                Some(JsonLevel::Object(key)) => *key = None,
+                Some(JsonLevel::Array(index)) => *index += 1,
+                None => {},
+            },
+            _ => {},
+        }
+    }
+
+    let mut path = String::new();
+    for level in &levels {
+        match level {
+            JsonLevel::Object(Some(key)) => {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+
+                path.push_str(key);
+            },
+            JsonLevel::Object(None) => break,
+            JsonLevel::Array(index) => path.push_str(&format!("[{index}]")),
+        }
+    }
+
+    path
+}
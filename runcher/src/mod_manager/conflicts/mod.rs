@@ -0,0 +1,293 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for detecting conflicts between the enabled packs, and suggesting an order for them.
+//!
+//! Two packs conflict when they contain the same files. When that happens, the game uses the files of the pack higher in the load order.
+//! Usually, the pack that should win is the more specific one: a patch or submod of the other pack.
+//!
+//! Scripts are special, as they can also collide without sharing a path: the game loads the scripts in the mod folders by their file name.
+
+use getset::*;
+
+use std::collections::HashMap;
+
+use rpfm_lib::files::{Container, pack::Pack};
+
+use super::load_order::LoadOrder;
+
+/// Extension of files only used by tools, which do not cause conflicts in the game.
+const IGNORED_EXTENSION: &str = ".rpfm_reserved";
+
+const SCRIPT_FOLDER: &str = "script/";
+const SCRIPT_EXTENSION: &str = ".lua";
+
+/// Scripts in folders with this name are loaded automatically by the game, by their file name.
+const AUTOLOADED_SCRIPT_FOLDER: &str = "/mod/";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// Files overwritten between two enabled packs.
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct PackConflict {
+
+    /// Id of the pack higher in the load order, which wins the conflict.
+    winner: String,
+
+    /// Id of the pack lower in the load order, which has its files overwritten.
+    loser: String,
+
+    /// Paths of the files in both packs, lowercased.
+    files: Vec<String>,
+}
+
+/// A suggested change to the load order, to make a pack win its conflicts with another one.
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct OrderSuggestion {
+
+    /// Id of the pack to move.
+    mod_id: String,
+
+    /// Id of the pack it should be above of.
+    above: String,
+
+    /// Why the pack should be above the other one.
+    reason: SuggestionReason,
+}
+
+/// Reasons a pack is considered more specific than another one.
+#[derive(Clone, Debug)]
+pub enum SuggestionReason {
+
+    /// The pack has the other one as a dependency.
+    Dependency,
+
+    /// The pack has fewer files than the other one. Contains the amount of conflicting files and the amount of files in the pack.
+    Overlap(usize, usize),
+}
+
+/// Scripts of different enabled packs that collide when the game loads them.
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct ScriptCollision {
+
+    /// Path or module name the scripts collide on, lowercased.
+    name: String,
+
+    /// How the scripts collide.
+    kind: ScriptCollisionKind,
+
+    /// Ids of the packs with colliding scripts, in load order. The first one is the one the game ends up using.
+    mods: Vec<String>,
+}
+
+/// Ways scripts of different packs can collide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScriptCollisionKind {
+
+    /// The scripts have the same path, so only the one in the pack higher in the load order is loaded.
+    Path,
+
+    /// The scripts have different paths but the same file name in autoloaded folders. Lua only loads the first module with a name.
+    ModuleName,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function returns the conflicts between the mods in the load order.
+///
+/// Only the packs already loaded by the load order are checked, so this doesn't touch the disk.
+pub fn pack_conflicts(load_order: &LoadOrder) -> Vec<PackConflict> {
+    let mut owners: HashMap<String, Vec<usize>> = HashMap::new();
+    for (index, mod_id) in load_order.mods().iter().enumerate() {
+        if let Some(pack) = load_order.packs().get(mod_id) {
+            for path in pack.files().keys() {
+                let path = path.to_lowercase();
+                if !path.ends_with(IGNORED_EXTENSION) {
+                    owners.entry(path).or_default().push(index);
+                }
+            }
+        }
+    }
+
+    // Indexes are in load order, so the first owner of a file is the one winning it.
+    let mut conflicts: HashMap<(usize, usize), Vec<String>> = HashMap::new();
+    for (path, indexes) in owners {
+        for (position, winner) in indexes.iter().enumerate() {
+            for loser in &indexes[position + 1..] {
+                conflicts.entry((*winner, *loser)).or_default().push(path.to_owned());
+            }
+        }
+    }
+
+    let mut conflicts = conflicts.into_iter().collect::<Vec<_>>();
+    conflicts.sort_by_key(|(indexes, _)| *indexes);
+    conflicts.into_iter()
+        .map(|((winner, loser), mut files)| {
+            files.sort();
+            PackConflict {
+                winner: load_order.mods()[winner].to_owned(),
+                loser: load_order.mods()[loser].to_owned(),
+                files,
+            }
+        })
+        .collect()
+}
+
+/// This function returns the scripts of the enabled packs that collide at load time.
+///
+/// Collisions between a pack and one of its dependencies are ignored, as patches and submods usually replace scripts on purpose.
+pub fn script_collisions(load_order: &LoadOrder) -> Vec<ScriptCollision> {
+    let mut paths: HashMap<String, Vec<usize>> = HashMap::new();
+    let mut modules: HashMap<String, Vec<(usize, String)>> = HashMap::new();
+
+    for (index, mod_id) in load_order.mods().iter().enumerate() {
+        if let Some(pack) = load_order.packs().get(mod_id) {
+            for path in pack.files().keys() {
+                let path = path.to_lowercase();
+                if !path.starts_with(SCRIPT_FOLDER) || !path.ends_with(SCRIPT_EXTENSION) {
+                    continue;
+                }
+
+                if path.contains(AUTOLOADED_SCRIPT_FOLDER) {
+                    if let Some(module) = path.rsplit('/').next().and_then(|name| name.strip_suffix(SCRIPT_EXTENSION)) {
+                        modules.entry(module.to_owned()).or_default().push((index, path.to_owned()));
+                    }
+                }
+
+                paths.entry(path).or_default().push(index);
+            }
+        }
+    }
+
+    let related = |a: usize, b: usize| {
+        let (mod_a, mod_b) = (&load_order.mods()[a], &load_order.mods()[b]);
+        match (load_order.packs().get(mod_a), load_order.packs().get(mod_b)) {
+            (Some(pack_a), Some(pack_b)) =>
+                pack_a.dependencies().iter().any(|(_, name)| name.eq_ignore_ascii_case(mod_b)) ||
+                pack_b.dependencies().iter().any(|(_, name)| name.eq_ignore_ascii_case(mod_a)),
+            _ => false,
+        }
+    };
+
+    // Only keep collisions with at least two unrelated packs.
+    let unrelated = |indexes: &[usize]| indexes.iter().enumerate().any(|(position, a)| indexes[position + 1..].iter().any(|b| !related(*a, *b)));
+
+    let mut collisions = vec![];
+    for (path, mut indexes) in paths {
+        indexes.dedup();
+        if indexes.len() > 1 && unrelated(&indexes) {
+            collisions.push((indexes[0], ScriptCollision {
+                name: path,
+                kind: ScriptCollisionKind::Path,
+                mods: indexes.iter().map(|index| load_order.mods()[*index].to_owned()).collect(),
+            }));
+        }
+    }
+
+    for (module, scripts) in modules {
+
+        // Scripts with the same path are already reported as path collisions.
+        let mut module_paths = scripts.iter().map(|(_, path)| path).collect::<Vec<_>>();
+        module_paths.sort();
+        module_paths.dedup();
+        if module_paths.len() < 2 {
+            continue;
+        }
+
+        let mut indexes = scripts.iter().map(|(index, _)| *index).collect::<Vec<_>>();
+        indexes.dedup();
+        if indexes.len() > 1 && unrelated(&indexes) {
+            collisions.push((indexes[0], ScriptCollision {
+                name: module,
+                kind: ScriptCollisionKind::ModuleName,
+                mods: indexes.iter().map(|index| load_order.mods()[*index].to_owned()).collect(),
+            }));
+        }
+    }
+
+    collisions.sort_by(|(index_a, collision_a), (index_b, collision_b)| index_a.cmp(index_b).then_with(|| collision_a.name.cmp(&collision_b.name)));
+    collisions.into_iter().map(|(_, collision)| collision).collect()
+}
+
+/// This function returns if the provided pack contains scripts.
+pub fn has_scripts(pack: &Pack) -> bool {
+    pack.files().keys().any(|path| {
+        let path = path.to_lowercase();
+        path.starts_with(SCRIPT_FOLDER) && path.ends_with(SCRIPT_EXTENSION)
+    })
+}
+
+/// This function returns the changes needed in the load order so, in each conflict, the more specific pack wins.
+///
+/// A pack is more specific than another if it depends on it or, failing that, if it has fewer files.
+/// Conflicts between packs in the same group are ignored, as their order is locked.
+pub fn order_suggestions(load_order: &LoadOrder) -> Vec<OrderSuggestion> {
+    let mut suggestions: Vec<OrderSuggestion> = vec![];
+
+    for conflict in pack_conflicts(load_order) {
+        if let Some(group) = load_order.group(&conflict.winner) {
+            if group.mods().contains(&conflict.loser) {
+                continue;
+            }
+        }
+
+        let (winner, loser) = match (load_order.packs().get(&conflict.winner), load_order.packs().get(&conflict.loser)) {
+            (Some(winner), Some(loser)) => (winner, loser),
+            _ => continue,
+        };
+
+        let depends_on = |pack: &Pack, parent: &str| pack.dependencies().iter().any(|(_, name)| name.eq_ignore_ascii_case(parent));
+
+        // If the pack winning is already the specific one, there's nothing to suggest.
+        let reason = if depends_on(winner, &conflict.loser) {
+            continue;
+        } else if depends_on(loser, &conflict.winner) {
+            SuggestionReason::Dependency
+        } else if loser.files().len() < winner.files().len() {
+            SuggestionReason::Overlap(conflict.files.len(), loser.files().len())
+        } else {
+            continue;
+        };
+
+        // Only suggest each move once, against the highest pack it needs to be above of.
+        if !suggestions.iter().any(|suggestion| suggestion.mod_id == conflict.loser) {
+            suggestions.push(OrderSuggestion {
+                mod_id: conflict.loser,
+                above: conflict.winner,
+                reason,
+            });
+        }
+    }
+
+    suggestions
+}
+
+/// This function applies the provided suggestions to the load order, moving each pack right above the one it should be above of.
+///
+/// Suggestions are applied in order, so if two of them contradict each other, the last one wins.
+pub fn apply_order_suggestions(load_order: &mut LoadOrder, suggestions: &[OrderSuggestion]) {
+    for suggestion in suggestions {
+        let mods = load_order.mods_mut();
+        if let Some(current) = mods.iter().position(|mod_id| mod_id == &suggestion.mod_id) {
+            let mod_id = mods.remove(current);
+            let target = mods.iter().position(|mod_id| mod_id == &suggestion.above).unwrap_or(current);
+            mods.insert(target, mod_id);
+        }
+    }
+
+    load_order.apply_groups();
+}
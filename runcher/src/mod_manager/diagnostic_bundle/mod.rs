@@ -0,0 +1,257 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the reading of diagnostic bundles exported by other users, so they can be inspected when helping them.
+//!
+//! Bundles are only read, never extracted, and nothing in them is written to the configs of whoever opens them.
+//! The bundle doesn't contain the packs of the mods, so conflicts and the packs behind script errors can only be found
+//! for the mods also installed in the computer opening the bundle.
+
+use anyhow::{anyhow, Result};
+use getset::*;
+use rayon::prelude::*;
+use zip::ZipArchive;
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+use rpfm_lib::files::{Container, pack::Pack};
+use rpfm_lib::integrations::log::error;
+
+use crate::logging::{DIAGNOSTIC_BUNDLE_GAME_CONFIG_FOLDER, DIAGNOSTIC_BUNDLE_GAME_LOGS_FOLDER, DIAGNOSTIC_BUNDLE_SETTINGS_FILE_NAME};
+
+use super::game_config::{GameConfig, GAME_CONFIG_FILE_NAME_END, GAME_CONFIG_FILE_NAME_START};
+use super::load_order::{LoadOrder, FILE_NAME_END as LOAD_ORDER_FILE_NAME_END, FILE_NAME_START as LOAD_ORDER_FILE_NAME_START};
+use super::log_patterns::{LogError, LogFormat};
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// Contents of a diagnostic bundle.
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct DiagnosticBundle {
+
+    /// Settings of the user, by key.
+    settings: BTreeMap<String, String>,
+
+    /// Logs of Runcher, by file name.
+    runcher_logs: BTreeMap<String, String>,
+
+    /// Script logs of the game selected when the bundle was made, by file name.
+    game_logs: BTreeMap<String, String>,
+
+    /// Configs of the games, by game key.
+    game_configs: BTreeMap<String, GameConfig>,
+
+    /// Last load order of the games, by game key.
+    load_orders: BTreeMap<String, LoadOrder>,
+}
+
+/// Error found in the script logs of a bundle.
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct BundleLogError {
+
+    /// Name of the log the error is in.
+    log_name: String,
+
+    /// The error itself.
+    error: LogError,
+
+    /// Id of the mod that may have caused the error, if any of the paths in the error is in the packs loaded.
+    posible_pack: Option<String>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl DiagnosticBundle {
+
+    /// This function reads the diagnostic bundle at the provided path.
+    ///
+    /// Game configs from older versions of Runcher are updated as they're read. Broken configs are logged and skipped,
+    /// so a bundle with a broken config can still be inspected. Which is usually why the bundle was sent.
+    pub fn read(path: &Path) -> Result<Self> {
+        let mut zip = ZipArchive::new(BufReader::new(File::open(path)?))?;
+        if zip.by_name(DIAGNOSTIC_BUNDLE_SETTINGS_FILE_NAME).is_err() {
+            return Err(anyhow!("This file is not a Runcher diagnostic bundle."));
+        }
+
+        let mut bundle = Self::default();
+        for index in 0..zip.len() {
+            let mut file = zip.by_index(index)?;
+            if file.is_dir() {
+                continue;
+            }
+
+            let name = file.name().to_owned();
+            let mut data = vec![];
+            file.read_to_end(&mut data)?;
+            let data = String::from_utf8_lossy(&data).to_string();
+
+            if name == DIAGNOSTIC_BUNDLE_SETTINGS_FILE_NAME {
+                bundle.settings = data.lines()
+                    .filter_map(|line| line.split_once(" = "))
+                    .map(|(key, value)| (key.to_owned(), value.to_owned()))
+                    .collect();
+            } else if let Some(file_name) = name.strip_prefix(DIAGNOSTIC_BUNDLE_GAME_LOGS_FOLDER) {
+                bundle.game_logs.insert(file_name.to_owned(), data);
+            } else if let Some(file_name) = name.strip_prefix(DIAGNOSTIC_BUNDLE_GAME_CONFIG_FOLDER) {
+                if let Some(game_key) = file_name.strip_prefix(GAME_CONFIG_FILE_NAME_START).and_then(|name| name.strip_suffix(GAME_CONFIG_FILE_NAME_END)) {
+                    match GameConfig::read_from_slice(data.as_bytes()) {
+                        Ok(game_config) => { bundle.game_configs.insert(game_key.to_owned(), game_config); },
+                        Err(error) => error!("Game config {} of diagnostic bundle {} cannot be read: {}", name, path.to_string_lossy(), error),
+                    }
+                } else if let Some(game_key) = file_name.strip_prefix(LOAD_ORDER_FILE_NAME_START).and_then(|name| name.strip_suffix(LOAD_ORDER_FILE_NAME_END)) {
+                    match serde_json::from_str(&data) {
+                        Ok(load_order) => { bundle.load_orders.insert(game_key.to_owned(), load_order); },
+                        Err(error) => error!("Load order {} of diagnostic bundle {} cannot be read: {}", name, path.to_string_lossy(), error),
+                    }
+                }
+            } else if !name.contains('/') && name.ends_with(".log") {
+                bundle.runcher_logs.insert(name, data);
+            }
+        }
+
+        Ok(bundle)
+    }
+
+    /// This function returns the key of the game selected when the bundle was made, if there's a config for it.
+    pub fn game_selected(&self) -> Option<&str> {
+        self.settings.get("last_game_selected")
+            .filter(|game_key| self.game_configs.contains_key(*game_key))
+            .map(|game_key| game_key.as_str())
+    }
+
+    /// This function returns the load order of the provided game, with the packs of its mods loaded from the installed copies of the same mods.
+    ///
+    /// Mods not installed in this computer are returned separately, as we cannot check their contents.
+    /// The provided progress callback is called after each pack is read, as this takes a while with big load orders.
+    pub fn load_order_with_local_packs<F: Fn() + Sync>(&self, game_key: &str, local_config: Option<&GameConfig>, progress: F) -> (LoadOrder, Vec<String>) {
+        let mut load_order = self.load_orders.get(game_key).cloned().unwrap_or_default();
+        let packs = load_order.mods().par_iter()
+            .map(|mod_id| {
+                let path = local_config.and_then(|game_config| game_config.mods().get(mod_id)).and_then(|modd| modd.paths().first());
+                let pack = path.and_then(|path| Pack::read_and_merge(&[path.to_path_buf()], true, false, false).ok());
+                progress();
+                (mod_id.to_owned(), pack)
+            })
+            .collect::<Vec<_>>();
+
+        let mut missing = vec![];
+        for (mod_id, pack) in packs {
+            match pack {
+                Some(pack) => { load_order.packs_mut().insert(mod_id, pack); }
+                None => missing.push(mod_id),
+            }
+        }
+
+        (load_order, missing)
+    }
+
+    /// This function returns the errors in the script logs of the bundle.
+    ///
+    /// The packs of the provided load order are used to guess the mod that caused each error. Like the game, the first pack with the file wins.
+    pub fn log_errors(&self, log_format: &LogFormat, load_order: &LoadOrder) -> Vec<BundleLogError> {
+        self.game_logs.iter()
+            .flat_map(|(log_name, log)| log_format.errors(log).into_iter().map(move |error| (log_name, error)))
+            .map(|(log_name, error)| {
+                let posible_pack = error.paths().iter()
+                    .find_map(|path| load_order.mods().iter().find(|mod_id| load_order.packs().get(*mod_id).is_some_and(|pack| pack.file(path, true).is_some())))
+                    .cloned();
+
+                BundleLogError {
+                    log_name: log_name.to_owned(),
+                    error,
+                    posible_pack,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::NamedTempFile;
+    use zip::{CompressionMethod, write::SimpleFileOptions, ZipWriter};
+
+    use std::io::Write;
+
+    use crate::mod_manager::mods::versions::v4::ModV4;
+
+    use super::*;
+
+    fn write_bundle(files: &[(String, String)]) -> NamedTempFile {
+        let bundle = NamedTempFile::new().unwrap();
+        let mut zip = ZipWriter::new(File::create(bundle.path()).unwrap());
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+        for (name, data) in files {
+            zip.start_file(name.to_owned(), options).unwrap();
+            zip.write_all(data.as_bytes()).unwrap();
+        }
+
+        zip.finish().unwrap();
+        bundle
+    }
+
+    #[test]
+    fn read_bundle() {
+        let mut game_config = GameConfig::default();
+        game_config.set_game_key("warhammer_3".to_owned());
+
+        let mut load_order = LoadOrder::default();
+        load_order.set_mods(vec!["a.pack".to_owned()]);
+
+        // Config from before the format was versioned.
+        let old_mod = ModV4 {
+            id: "old.pack".to_owned(),
+            enabled: true,
+            ..Default::default()
+        };
+        let old_game_config = serde_json::json!({
+            "game_key": "warhammer",
+            "mods": { "old.pack": old_mod },
+            "categories": {},
+            "categories_order": [],
+        });
+
+        let bundle = write_bundle(&[
+            (DIAGNOSTIC_BUNDLE_SETTINGS_FILE_NAME.to_owned(), "last_game_selected = warhammer_3\nfont_size = 10\n".to_owned()),
+            (format!("{DIAGNOSTIC_BUNDLE_GAME_CONFIG_FOLDER}{GAME_CONFIG_FILE_NAME_START}warhammer_3{GAME_CONFIG_FILE_NAME_END}"), serde_json::to_string(&game_config).unwrap()),
+            (format!("{DIAGNOSTIC_BUNDLE_GAME_CONFIG_FOLDER}{LOAD_ORDER_FILE_NAME_START}warhammer_3{LOAD_ORDER_FILE_NAME_END}"), serde_json::to_string(&load_order).unwrap()),
+            (format!("{DIAGNOSTIC_BUNDLE_GAME_CONFIG_FOLDER}{GAME_CONFIG_FILE_NAME_START}warhammer_2{GAME_CONFIG_FILE_NAME_END}"), "{ broken".to_owned()),
+            (format!("{DIAGNOSTIC_BUNDLE_GAME_CONFIG_FOLDER}{GAME_CONFIG_FILE_NAME_START}warhammer{GAME_CONFIG_FILE_NAME_END}"), old_game_config.to_string()),
+            (format!("{DIAGNOSTIC_BUNDLE_GAME_LOGS_FOLDER}script_log.txt"), "script error".to_owned()),
+            ("runcher.log".to_owned(), "runcher log".to_owned()),
+        ]);
+
+        let bundle = DiagnosticBundle::read(bundle.path()).unwrap();
+        assert_eq!(bundle.settings().get("font_size").map(|value| value.as_str()), Some("10"));
+        assert_eq!(bundle.game_selected(), Some("warhammer_3"));
+
+        // Old configs are updated, and broken ones are skipped instead of failing the whole bundle.
+        assert_eq!(bundle.game_configs().keys().collect::<Vec<_>>(), vec!["warhammer", "warhammer_3"]);
+        assert!(bundle.game_configs()["warhammer"].mods().contains_key("old.pack"));
+        assert_eq!(bundle.load_orders()["warhammer_3"].mods(), &vec!["a.pack".to_owned()]);
+        assert_eq!(bundle.game_logs()["script_log.txt"], "script error");
+        assert_eq!(bundle.runcher_logs()["runcher.log"], "runcher log");
+    }
+
+    #[test]
+    fn read_not_a_bundle() {
+        let bundle = write_bundle(&[("runcher.log".to_owned(), "runcher log".to_owned())]);
+        assert!(DiagnosticBundle::read(bundle.path()).is_err());
+    }
+}
@@ -0,0 +1,177 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the detection of duplicated mods.
+//!
+//! When a mod gets abandoned, it's common for someone else to re-upload it under another name, sometimes without changing a single byte.
+//! Users end up subscribed to both, loading the same files twice. Here we find mods whose packs are identical,
+//! or which contain exactly the same files, no matter their names.
+
+use getset::*;
+use rayon::prelude::*;
+use sha256::{digest, try_digest};
+
+use std::collections::{BTreeMap, HashSet};
+
+use rpfm_lib::files::pack::Pack;
+
+use super::game_config::GameConfig;
+use super::mods::Mod;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// How similar the mods of a group are.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DuplicateKind {
+
+    /// The packs are byte-identical.
+    Identical,
+
+    /// The packs contain the same files, but their contents differ.
+    SameFiles,
+}
+
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct DuplicateGroup {
+
+    /// How similar the mods of the group are.
+    kind: DuplicateKind,
+
+    /// Mods of the group, most recently updated first. The first one is the one we suggest keeping.
+    mods: Vec<DuplicateMod>,
+}
+
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct DuplicateMod {
+
+    /// Id of the mod.
+    mod_id: String,
+
+    /// Last time the mod was updated, in seconds since the unix epoch. The Workshop update if the mod is from there, the date of its pack otherwise.
+    updated: u64,
+}
+
+/// Data we need from each pack to compare it with the rest.
+struct PackSignature {
+    mod_id: String,
+    updated: u64,
+    hash: String,
+    files_hash: Option<String>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl DuplicateKind {
+
+    /// This function returns the key of the explanation of how similar the mods are.
+    pub fn locale_key(&self) -> &'static str {
+        match self {
+            Self::Identical => "duplicate_kind_identical",
+            Self::SameFiles => "duplicate_kind_same_files",
+        }
+    }
+}
+
+/// This function returns the groups of duplicated mods among the provided ones.
+///
+/// Identical packs are grouped first. Then, packs with the same files are grouped, leaving out the ones already grouped as identical to each other.
+/// The provided closure is called each time a mod is read.
+pub fn duplicate_mods<F: Fn() + Sync>(game_config: &GameConfig, mod_ids: &[String], progress: F) -> Vec<DuplicateGroup> {
+    let signatures = mod_ids.par_iter()
+        .filter_map(|mod_id| {
+            let signature = game_config.mods().get(mod_id).and_then(PackSignature::new);
+            progress();
+            signature
+        })
+        .collect::<Vec<_>>();
+
+    let mut by_hash: BTreeMap<&str, Vec<&PackSignature>> = BTreeMap::new();
+    for signature in &signatures {
+        by_hash.entry(&signature.hash).or_default().push(signature);
+    }
+
+    // Only one pack of each identical group is used to look for packs with the same files, so they don't get reported twice.
+    let mut groups = vec![];
+    let mut by_files: BTreeMap<&str, Vec<&PackSignature>> = BTreeMap::new();
+    for group in by_hash.values() {
+        if let Some(files_hash) = &group[0].files_hash {
+            by_files.entry(files_hash).or_default().push(group[0]);
+        }
+
+        if group.len() > 1 {
+            groups.push(DuplicateGroup::new(DuplicateKind::Identical, group));
+        }
+    }
+
+    for group in by_files.values() {
+        let hashes = group.iter().map(|signature| &signature.hash).collect::<HashSet<_>>();
+        if hashes.len() > 1 {
+            groups.push(DuplicateGroup::new(DuplicateKind::SameFiles, group));
+        }
+    }
+
+    groups
+}
+
+impl DuplicateGroup {
+    fn new(kind: DuplicateKind, signatures: &[&PackSignature]) -> Self {
+        let mut mods = signatures.iter()
+            .map(|signature| DuplicateMod {
+                mod_id: signature.mod_id.to_owned(),
+                updated: signature.updated,
+            })
+            .collect::<Vec<_>>();
+
+        mods.sort_by(|a, b| b.updated.cmp(&a.updated).then_with(|| a.mod_id.cmp(&b.mod_id)));
+
+        Self {
+            kind,
+            mods,
+        }
+    }
+}
+
+impl PackSignature {
+
+    /// Mod paths are sorted by priority, so we only check the first one, as it's the one the game loads.
+    fn new(modd: &Mod) -> Option<Self> {
+        let path = modd.paths().first()?;
+        let hash = try_digest(path.as_path()).ok()?;
+
+        // Empty packs all have the same (lack of) files, so they're not compared by files.
+        let files_hash = Pack::read_and_merge(&[path.to_path_buf()], true, false, false).ok()
+            .map(|pack| {
+                let mut files = pack.files().keys().map(|path| path.to_lowercase()).collect::<Vec<_>>();
+                files.sort();
+                files
+            })
+            .filter(|files| !files.is_empty())
+            .map(|files| digest(files.join("\n")));
+
+        let updated = if *modd.time_updated() != 0 {
+            *modd.time_updated() as u64
+        } else {
+            modd.copies_dates().first().map(|(_, date)| *date).unwrap_or_default()
+        };
+
+        Some(Self {
+            mod_id: modd.id().to_owned(),
+            updated,
+            hash,
+            files_hash,
+        })
+    }
+}
@@ -0,0 +1,139 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the environment variables passed to the game when launching it.
+//!
+//! They're written like in the launch options of Steam: `KEY=VALUE` pairs separated by spaces, with values containing spaces between double quotes.
+//! For example: `DXVK_HUD=fps,frametimes VK_ICD_FILENAMES="C:/Program Files/vk/icd.json"`. Variables can be set per game, and per profile.
+//! The ones set in the profile loaded take priority over the ones of the game.
+
+use anyhow::{anyhow, Result};
+
+use rpfm_lib::games::GameInfo;
+
+use rpfm_ui_common::settings::setting_string;
+
+use super::profiles::Profile;
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function parses a list of environment variables, returning them in the order they were written.
+///
+/// Later variables with the same key replace earlier ones.
+pub fn parse_env_vars(text: &str) -> Result<Vec<(String, String)>> {
+    let mut env_vars: Vec<(String, String)> = vec![];
+    for entry in split_entries(text)? {
+        let (key, value) = entry.split_once('=').ok_or_else(|| anyhow!("Invalid environment variable \"{}\": it must be written as KEY=VALUE.", entry))?;
+        if !valid_key(key) {
+            return Err(anyhow!("Invalid environment variable name \"{}\": only letters, numbers and underscores are allowed, and it cannot start with a number.", key));
+        }
+
+        match env_vars.iter_mut().find(|(existing, _)| existing == key) {
+            Some((_, existing)) => *existing = value.to_owned(),
+            None => env_vars.push((key.to_owned(), value.to_owned())),
+        }
+    }
+
+    Ok(env_vars)
+}
+
+/// This function returns the environment variables to pass to the provided game, with the ones of the provided profile, if any, on top.
+///
+/// Invalid lists are reported instead of skipped, as a typo would otherwise go unnoticed until the game behaves differently than expected.
+pub fn launch_env_vars(game: &GameInfo, profile: Option<&Profile>) -> Result<Vec<(String, String)>> {
+    let mut env_vars = parse_env_vars(&setting_string(&format!("launch_env_vars_{}", game.key())))
+        .map_err(|error| anyhow!("Error in the environment variables of {}: {}", game.display_name(), error))?;
+
+    if let Some(profile) = profile {
+        let profile_env_vars = parse_env_vars(profile.env_vars())
+            .map_err(|error| anyhow!("Error in the environment variables of the profile {}: {}", profile.id(), error))?;
+
+        for (key, value) in profile_env_vars {
+            match env_vars.iter_mut().find(|(existing, _)| *existing == key) {
+                Some((_, existing)) => *existing = value,
+                None => env_vars.push((key, value)),
+            }
+        }
+    }
+
+    Ok(env_vars)
+}
+
+/// Entries are separated by whitespace, unless it's between double quotes. Quotes are removed, but backslashes are kept as-is, as they're common in Windows paths.
+fn split_entries(text: &str) -> Result<Vec<String>> {
+    let mut entries = vec![];
+    let mut entry = String::new();
+    let mut in_quotes = false;
+
+    for character in text.chars() {
+        match character {
+            '"' => in_quotes = !in_quotes,
+            _ if character.is_whitespace() && !in_quotes => {
+                if !entry.is_empty() {
+                    entries.push(std::mem::take(&mut entry));
+                }
+            }
+            _ => entry.push(character),
+        }
+    }
+
+    if in_quotes {
+        return Err(anyhow!("Unclosed double quotes in \"{}\".", text.trim()));
+    }
+
+    if !entry.is_empty() {
+        entries.push(entry);
+    }
+
+    Ok(entries)
+}
+
+fn valid_key(key: &str) -> bool {
+    !key.is_empty() &&
+        !key.starts_with(|character: char| character.is_ascii_digit()) &&
+        key.chars().all(|character| character.is_ascii_alphanumeric() || character == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_env_vars_with_quotes() {
+        let env_vars = parse_env_vars(r#"DXVK_HUD=fps,frametimes  VK_ICD_FILENAMES="C:\Program Files\vk\icd.json""#).unwrap();
+        assert_eq!(env_vars, vec![
+            ("DXVK_HUD".to_owned(), "fps,frametimes".to_owned()),
+            ("VK_ICD_FILENAMES".to_owned(), "C:\\Program Files\\vk\\icd.json".to_owned()),
+        ]);
+    }
+
+    #[test]
+    fn parse_env_vars_later_keys_win() {
+        let env_vars = parse_env_vars("A=1\nB=2 A=3").unwrap();
+        assert_eq!(env_vars, vec![("A".to_owned(), "3".to_owned()), ("B".to_owned(), "2".to_owned())]);
+    }
+
+    #[test]
+    fn parse_env_vars_empty() {
+        assert!(parse_env_vars("").unwrap().is_empty());
+        assert!(parse_env_vars("  \n ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_env_vars_invalid() {
+        assert!(parse_env_vars("NO_VALUE").is_err());
+        assert!(parse_env_vars("1ABC=x").is_err());
+        assert!(parse_env_vars("BAD-KEY=x").is_err());
+        assert!(parse_env_vars("=x").is_err());
+        assert!(parse_env_vars("A=\"unclosed").is_err());
+    }
+}
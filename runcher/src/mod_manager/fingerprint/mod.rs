@@ -0,0 +1,380 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the fingerprint of a setup, used to check that all the players of a multiplayer game have the same one.
+//!
+//! The fingerprint is a short code made from the hashes of the loaded packs in load order, the build of the game and the launch options.
+//! Players can compare their codes by voice, and if they don't match, compare their manifests to find out why.
+//!
+//! Hashing big packs is slow, so hashes are cached along with the size and modification date of the pack they belong to.
+//!
+//! Fingerprints are also used to freeze the packs of a campaign when it starts, so we can later find out which packs changed mid-campaign.
+
+use anyhow::{anyhow, Result};
+use getset::*;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_reader, to_writer};
+use sha256::{digest, try_digest};
+
+use std::collections::HashMap;
+use std::fs::{read_to_string, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use rpfm_ui_common::settings::config_path;
+
+use super::game_config::GameConfig;
+use super::load_order::LoadOrder;
+
+const PACK_HASH_CACHE_FILE_NAME: &str = "pack_hash_cache.json";
+
+const CAMPAIGN_INTEGRITY_FILE_NAME_START: &str = "campaign_integrity_";
+const CAMPAIGN_INTEGRITY_FILE_NAME_END: &str = ".txt";
+
+/// Length of the code shown to the user.
+const FINGERPRINT_CODE_LENGTH: usize = 8;
+
+const MANIFEST_HEADER: &str = "# Runcher fingerprint";
+const MANIFEST_GAME: &str = "game";
+const MANIFEST_BUILD: &str = "build";
+const MANIFEST_PACK: &str = "pack";
+const MANIFEST_OPTION: &str = "option";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, PartialEq, Getters)]
+#[getset(get = "pub")]
+pub struct Fingerprint {
+
+    /// Key of the game.
+    game_key: String,
+
+    /// Build of the game, if known.
+    game_build: Option<String>,
+
+    /// Name and hash of the loaded packs, in load order.
+    packs: Vec<(String, String)>,
+
+    /// Key and value of the launch options changed from their defaults.
+    launch_options: Vec<(String, String)>,
+}
+
+/// Reason why two fingerprints don't match.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FingerprintDifference {
+    Game(String, String),
+    Build(String, String),
+    PackOnlyInFirst(String),
+    PackOnlyInSecond(String),
+    PackHash(String),
+    PackPosition(String, usize, usize),
+    LaunchOption(String, String, String),
+}
+
+/// Result of checking a pack against the hash it was shared with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HashCheck {
+
+    /// The pack is the one expected.
+    Ok,
+
+    /// The pack is different from the one expected.
+    Mismatch,
+
+    /// The pack is not in the mod list, or its file doesn't exist.
+    Missing,
+
+    /// The pack couldn't be read. Contains the reason why.
+    Error(String),
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct PackHashCache {
+    entries: HashMap<PathBuf, PackHashCacheEntry>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct PackHashCacheEntry {
+    size: u64,
+    modified: u64,
+    hash: String,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl Fingerprint {
+
+    /// This function builds the fingerprint of the current setup. Packs are hashed in parallel, and only if their cached hash is outdated.
+    pub fn new(game_config: &GameConfig, load_order: &LoadOrder, launch_options: &[(String, String)]) -> Result<Self> {
+        let paths = load_order.mods()
+            .iter()
+            .chain(load_order.movies().iter())
+            .filter_map(|mod_id| game_config.mods().get(mod_id))
+            .filter_map(|modd| modd.paths().first().map(|path| (modd.id().to_owned(), path.to_path_buf())))
+            .collect::<Vec<_>>();
+
+        let mut cache = PackHashCache::load();
+        let hashes = paths.par_iter()
+            .map(|(_, path)| cache.hash(path))
+            .collect::<Vec<_>>();
+
+        let mut packs = Vec::with_capacity(paths.len());
+        for ((pack_name, path), hash) in paths.into_iter().zip(hashes) {
+            let (entry, hash) = hash?;
+            cache.entries.insert(path, entry);
+            packs.push((pack_name, hash));
+        }
+
+        // The cache is only a speedup. Failing to save it is not a reason to not show the fingerprint.
+        let _ = cache.save();
+
+        let mut launch_options = launch_options.to_vec();
+        launch_options.sort();
+
+        Ok(Self {
+            game_key: game_config.game_key().to_owned(),
+            game_build: game_config.game_build().clone(),
+            packs,
+            launch_options,
+        })
+    }
+
+    /// This function returns the short code of the fingerprint.
+    pub fn code(&self) -> String {
+        digest(self.manifest_body())[..FINGERPRINT_CODE_LENGTH].to_uppercase()
+    }
+
+    /// This function returns the full manifest of the fingerprint, to compare it with the one of other player.
+    pub fn manifest(&self) -> String {
+        format!("{MANIFEST_HEADER} {}\n{}", self.code(), self.manifest_body())
+    }
+
+    /// This function parses a manifest generated with [Fingerprint::manifest]. Unknown lines are ignored, so it can be pasted with extra text around it.
+    pub fn from_manifest(manifest: &str) -> Result<Self> {
+        let mut fingerprint = Self::default();
+        let mut found = false;
+
+        for line in manifest.lines().map(|line| line.trim()) {
+            let (kind, value) = match line.split_once(' ') {
+                Some(split) => split,
+                None => continue,
+            };
+
+            match kind {
+                MANIFEST_GAME => fingerprint.game_key = value.to_owned(),
+                MANIFEST_BUILD => fingerprint.game_build = Some(value.to_owned()),
+                MANIFEST_PACK => if let Some((hash, pack_name)) = value.split_once(' ') {
+                    fingerprint.packs.push((pack_name.to_owned(), hash.to_owned()));
+                },
+                MANIFEST_OPTION => if let Some((key, value)) = value.split_once('=') {
+                    fingerprint.launch_options.push((key.to_owned(), value.to_owned()));
+                },
+                _ => continue,
+            }
+
+            found = true;
+        }
+
+        if found {
+            Ok(fingerprint)
+        } else {
+            Err(anyhow!("No fingerprint data found in the manifest."))
+        }
+    }
+
+    /// This function returns the differences between this fingerprint and the provided one.
+    pub fn differences(&self, other: &Self) -> Vec<FingerprintDifference> {
+        let mut differences = vec![];
+
+        if self.game_key != other.game_key {
+            differences.push(FingerprintDifference::Game(self.game_key.to_owned(), other.game_key.to_owned()));
+        }
+
+        if self.game_build != other.game_build {
+            differences.push(FingerprintDifference::Build(self.game_build.clone().unwrap_or_default(), other.game_build.clone().unwrap_or_default()));
+        }
+
+        let other_packs = other.packs.iter()
+            .enumerate()
+            .map(|(position, (pack_name, hash))| (pack_name.as_str(), (position, hash.as_str())))
+            .collect::<HashMap<_, _>>();
+
+        for (position, (pack_name, hash)) in self.packs.iter().enumerate() {
+            match other_packs.get(pack_name.as_str()) {
+                Some((other_position, other_hash)) => {
+                    if hash != other_hash {
+                        differences.push(FingerprintDifference::PackHash(pack_name.to_owned()));
+                    }
+
+                    if position != *other_position {
+                        differences.push(FingerprintDifference::PackPosition(pack_name.to_owned(), position + 1, other_position + 1));
+                    }
+                }
+                None => differences.push(FingerprintDifference::PackOnlyInFirst(pack_name.to_owned())),
+            }
+        }
+
+        for (pack_name, _) in &other.packs {
+            if self.packs.iter().all(|(name, _)| name != pack_name) {
+                differences.push(FingerprintDifference::PackOnlyInSecond(pack_name.to_owned()));
+            }
+        }
+
+        let options = self.launch_options.iter().cloned().collect::<HashMap<_, _>>();
+        let other_options = other.launch_options.iter().cloned().collect::<HashMap<_, _>>();
+        let mut keys = options.keys().chain(other_options.keys()).collect::<Vec<_>>();
+        keys.sort();
+        keys.dedup();
+
+        for key in keys {
+            let value = options.get(key).cloned().unwrap_or_default();
+            let other_value = other_options.get(key).cloned().unwrap_or_default();
+            if value != other_value {
+                differences.push(FingerprintDifference::LaunchOption(key.to_owned(), value, other_value));
+            }
+        }
+
+        differences
+    }
+
+    /// The body of the manifest is what the code is made from, so it must not contain anything that changes between computers, like paths.
+    fn manifest_body(&self) -> String {
+        let mut body = format!("{MANIFEST_GAME} {}\n", self.game_key);
+
+        if let Some(ref build) = self.game_build {
+            body.push_str(&format!("{MANIFEST_BUILD} {}\n", build));
+        }
+
+        for (pack_name, hash) in &self.packs {
+            body.push_str(&format!("{MANIFEST_PACK} {} {}\n", hash, pack_name));
+        }
+
+        for (key, value) in &self.launch_options {
+            body.push_str(&format!("{MANIFEST_OPTION} {}={}\n", key, value));
+        }
+
+        body
+    }
+}
+
+/// This function checks the packs at the provided paths against their expected hashes. Packs are hashed in parallel, and only if their cached hash is outdated.
+///
+/// A pack that cannot be read doesn't stop the rest from being checked. The provided closure is called each time a pack is checked.
+pub fn verify_pack_hashes<F: Fn() + Sync>(packs: &[(PathBuf, String)], progress: F) -> Vec<HashCheck> {
+    let mut cache = PackHashCache::load();
+    let hashes = packs.par_iter()
+        .map(|(path, _)| {
+            let hash = if path.is_file() { Some(cache.hash(path)) } else { None };
+            progress();
+            hash
+        })
+        .collect::<Vec<_>>();
+
+    let mut checks = Vec::with_capacity(packs.len());
+    for ((path, expected_hash), hash) in packs.iter().zip(hashes) {
+        checks.push(match hash {
+            Some(Ok((entry, hash))) => {
+                cache.entries.insert(path.to_path_buf(), entry);
+
+                if &hash == expected_hash {
+                    HashCheck::Ok
+                } else {
+                    HashCheck::Mismatch
+                }
+            }
+            Some(Err(error)) => HashCheck::Error(error.to_string()),
+            None => HashCheck::Missing,
+        });
+    }
+
+    // The cache is only a speedup. Failing to save it is not a reason to not report the checks.
+    let _ = cache.save();
+
+    checks
+}
+
+/// This function freezes the integrity of the current campaign, saving the fingerprint of the enabled packs of the provided game config.
+///
+/// Launch options don't change the packs, so they're not part of it.
+pub fn freeze_campaign_integrity(game_config: &GameConfig, load_order: &LoadOrder) -> Result<Fingerprint> {
+    let fingerprint = Fingerprint::new(game_config, load_order, &[])?;
+    std::fs::write(campaign_integrity_path(game_config.game_key())?, fingerprint.manifest())?;
+    Ok(fingerprint)
+}
+
+/// This function returns the frozen integrity of the campaign of the provided game, and when it was frozen, in seconds since the unix epoch.
+pub fn frozen_campaign_integrity(game_key: &str) -> Result<Option<(Fingerprint, u64)>> {
+    let path = campaign_integrity_path(game_key)?;
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    let frozen_at = path.metadata()?.modified()?.duration_since(UNIX_EPOCH)?.as_secs();
+    let fingerprint = Fingerprint::from_manifest(&read_to_string(&path)?)?;
+    Ok(Some((fingerprint, frozen_at)))
+}
+
+/// This function returns the differences between the frozen integrity of the campaign and the current packs, or None if the campaign was never frozen.
+pub fn verify_campaign_integrity(game_config: &GameConfig, load_order: &LoadOrder) -> Result<Option<(Vec<FingerprintDifference>, u64)>> {
+    match frozen_campaign_integrity(game_config.game_key())? {
+        Some((frozen, frozen_at)) => {
+            let current = Fingerprint::new(game_config, load_order, &[])?;
+            Ok(Some((frozen.differences(&current), frozen_at)))
+        }
+        None => Ok(None),
+    }
+}
+
+fn campaign_integrity_path(game_key: &str) -> Result<PathBuf> {
+    Ok(config_path()?.join(format!("{CAMPAIGN_INTEGRITY_FILE_NAME_START}{game_key}{CAMPAIGN_INTEGRITY_FILE_NAME_END}")))
+}
+
+impl PackHashCache {
+
+    fn load() -> Self {
+        Self::path().ok()
+            .and_then(|path| File::open(path).ok())
+            .and_then(|file| from_reader(BufReader::new(file)).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self) -> Result<()> {
+        let mut file = BufWriter::new(File::create(Self::path()?)?);
+        to_writer(&mut file, self)?;
+        file.flush()?;
+        Ok(())
+    }
+
+    fn path() -> Result<PathBuf> {
+        Ok(config_path()?.join(PACK_HASH_CACHE_FILE_NAME))
+    }
+
+    /// This function returns the hash of the pack at the provided path, alongside the cache entry for it. Packs are only hashed if they changed since they were cached.
+    fn hash(&self, path: &Path) -> Result<(PackHashCacheEntry, String)> {
+        let metadata = path.metadata()?;
+        let size = metadata.len();
+        let modified = metadata.modified()?.duration_since(UNIX_EPOCH)?.as_millis() as u64;
+
+        if let Some(entry) = self.entries.get(path) {
+            if entry.size == size && entry.modified == modified {
+                return Ok((entry.clone(), entry.hash.to_owned()));
+            }
+        }
+
+        let hash = try_digest(path)?;
+        Ok((PackHashCacheEntry { size, modified, hash: hash.to_owned() }, hash))
+    }
+}
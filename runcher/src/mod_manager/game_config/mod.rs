@@ -10,36 +10,44 @@
 
 //! Module containing the centralized code for mod and load order management.
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use crossbeam::channel::Receiver;
 use getset::*;
 use rayon::{iter::Either, prelude::*};
 use serde::{Deserialize, Serialize};
-use serde_json::to_string_pretty;
+use serde_json::{to_string_pretty, Value};
 
-use std::collections::{BTreeMap, HashMap};
-use std::fs::{DirBuilder, File};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::{rename, DirBuilder, File};
 use std::io::{BufReader, BufWriter, Cursor, Read, Write};
-use std::path::Path;
-use std::time::UNIX_EPOCH;
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
-use rpfm_lib::files::pack::Pack;
 use rpfm_lib::games::{GameInfo, pfh_file_type::PFHFileType};
-use rpfm_lib::integrations::log::error;
+use rpfm_lib::integrations::log::{error, info};
+
+use rpfm_ui_common::settings::setting_bool;
 
 use crate::games::{RESERVED_PACK_NAME, RESERVED_PACK_NAME_ALTERNATIVE};
 use crate::communications::{Command, Response};
-use crate::mod_manager::{load_order::LoadOrder, mods::Mod};
+use crate::mod_manager::{load_order::LoadOrder, mods::Mod, offline::offline_mode};
 use crate::{settings_ui::*, CENTRAL_COMMAND};
 
-use super::secondary_mods_packs_paths;
+use super::category_rules::{category_rule_moves, CategoryRule};
+use super::config_recovery::{config_value, config_version, ConfigRecovery};
+use super::misfiled_packs::update_repacked_movie_packs;
+use super::pack_scan_cache::PackScanCache;
+use super::{is_generated_pack, secondary_mods_path, secondary_mods_packs_paths};
 
 mod versions;
 
-const GAME_CONFIG_FILE_NAME_START: &str = "game_config_";
-const GAME_CONFIG_FILE_NAME_END: &str = ".json";
+pub const GAME_CONFIG_FILE_NAME_START: &str = "game_config_";
+pub const GAME_CONFIG_FILE_NAME_END: &str = ".json";
 pub const DEFAULT_CATEGORY: &str = "Unassigned";
 
+/// Version of the format of the game config files. Bump it when adding a new entry to the versions module.
+const GAME_CONFIG_VERSION: u32 = 5;
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -48,6 +56,10 @@ pub const DEFAULT_CATEGORY: &str = "Unassigned";
 #[getset(get = "pub", get_mut = "pub", set = "pub")]
 pub struct GameConfig {
 
+    // Version of the format of the file. Files from before the format was versioned have none.
+    #[serde(default)]
+    version: u32,
+
     // Key of the game.
     game_key: String,
 
@@ -63,6 +75,47 @@ pub struct GameConfig {
 
     // List of categories in order.
     categories_order: Vec<String>,
+
+    // Color and icon of each category, by category name. Categories without custom visuals are not here.
+    #[serde(default)]
+    category_styles: BTreeMap<String, CategoryStyle>,
+
+    // Build of the game on the last launch, used to detect game updates.
+    #[serde(default)]
+    game_build: Option<String>,
+
+    // Named load orders to quickly switch between, by slot name. Unlike profiles, they only store the enabled mods and their order.
+    #[serde(default)]
+    load_order_slots: BTreeMap<String, LoadOrder>,
+
+    // Rules to automatically send new mods to a category, checked in order.
+    #[serde(default)]
+    category_rules: Vec<CategoryRule>,
+}
+
+/// Part of the mods to rescan, for reloads lighter than a full one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RescanScope {
+
+    /// Packs in /data.
+    Data,
+
+    /// Packs in the secondary folder.
+    Secondary,
+
+    /// Packs of a single mod, by id.
+    Mod(String),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct CategoryStyle {
+
+    // Color of the category, in #RRGGBB format.
+    color: Option<String>,
+
+    // Name of the icon of the category, from the icon theme.
+    icon: Option<String>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -90,27 +143,144 @@ impl GameConfig {
         file.read_to_end(&mut data)?;
 
         let mut config: Self = serde_json::from_slice(&data)?;
+        config.add_missing_default_category();
 
-        // Just in case we don't have a default category yet.
-        if config.categories().get(DEFAULT_CATEGORY).is_none() {
-            config.categories_mut().insert(DEFAULT_CATEGORY.to_owned(), vec![]);
-            config.categories_order_mut().retain(|category| category != DEFAULT_CATEGORY);
-            config.categories_order_mut().push(DEFAULT_CATEGORY.to_owned());
-        }
+        Ok(config)
+    }
+
+    /// This function reads a game config from the provided data, updating it in memory if it's from before the format was versioned.
+    ///
+    /// Nothing is written to disk, so this can be used to read configs from other computers, like the ones in diagnostic bundles.
+    pub fn read_from_slice(data: &[u8]) -> Result<Self> {
+        let mut config: Self = match serde_json::from_slice(data) {
+            Ok(config) => config,
+            Err(error) => {
+                if config_version(data) != 0 {
+                    return Err(error.into());
+                }
+
+                // Same as with the update of the files, only the last old version is checked.
+                let config_old: versions::v4::GameConfigV4 = serde_json::from_slice(data).map_err(|_| error)?;
+                Self::from(&config_old)
+            }
+        };
 
+        config.add_missing_default_category();
         Ok(config)
     }
 
+    /// Just in case we don't have a default category yet.
+    fn add_missing_default_category(&mut self) {
+        if self.categories().get(DEFAULT_CATEGORY).is_none() {
+            self.categories_mut().insert(DEFAULT_CATEGORY.to_owned(), vec![]);
+            self.categories_order_mut().retain(|category| category != DEFAULT_CATEGORY);
+            self.categories_order_mut().push(DEFAULT_CATEGORY.to_owned());
+        }
+    }
+
+    /// This function loads the game config, recovering what it can from it if it fails to load.
+    ///
+    /// Configs from before the format was versioned are first updated through the old versions. If that doesn't fix them,
+    /// the broken file is backed up and replaced with whatever could be read from it. The recovery report is returned alongside the config.
+    pub fn load_or_recover(game: &GameInfo) -> Result<(Self, Option<ConfigRecovery>)> {
+        if let Ok(config) = Self::load(game, true) {
+            return Ok((config, None));
+        }
+
+        // Only trigger the update if the current config fails to load, as checking all versions on each load is slow.
+        let path = game_config_path()?.join(format!("{GAME_CONFIG_FILE_NAME_START}{}{GAME_CONFIG_FILE_NAME_END}", game.key()));
+        if config_version(&std::fs::read(&path)?) == 0 {
+            let _ = Self::update(game.key());
+        }
+
+        let data = std::fs::read(&path)?;
+        let error = match serde_json::from_slice::<Self>(&data) {
+            Ok(_) => return Ok((Self::load(game, true)?, None)),
+            Err(error) => error,
+        };
+
+        let mut recovery = ConfigRecovery::new(&path, &data, &error, GAME_CONFIG_VERSION)?;
+        let mut config = Self::recover(game, &config_value(&data), &mut recovery);
+        config.save(game)?;
+
+        error!("Game config {} failed to load and has been recovered: {}", path.to_string_lossy(), error);
+        Ok((config, Some(recovery)))
+    }
+
+    /// This function rebuilds a game config from the readable parts of a broken one.
+    ///
+    /// Mods that cannot be read keep at least their enabled state, and the ones missing from the categories end up in the default category after the next scan.
+    fn recover(game: &GameInfo, value: &Value, recovery: &mut ConfigRecovery) -> Self {
+        let (mut mods, broken_mods) = recovery.map_entries::<Mod>(value, "mods");
+        for (mod_id, broken_mod) in broken_mods {
+            let mut modd = Mod::default();
+            modd.set_id(mod_id.to_owned());
+            modd.set_name(mod_id.to_owned());
+            modd.set_enabled(broken_mod.get("enabled").and_then(|enabled| enabled.as_bool()).unwrap_or(false));
+            mods.insert(mod_id, modd);
+        }
+
+        let (categories, broken_categories) = recovery.map_entries::<Vec<String>>(value, "categories");
+        let mut categories = categories.into_iter()
+            .chain(broken_categories.into_iter().map(|(category, mod_ids)| {
+                let mod_ids = mod_ids.as_array()
+                    .map(|mod_ids| mod_ids.iter().filter_map(|mod_id| mod_id.as_str().map(|mod_id| mod_id.to_owned())).collect())
+                    .unwrap_or_default();
+
+                (category, mod_ids)
+            }))
+            .collect::<BTreeMap<_, Vec<_>>>();
+
+        // Mods can only be in one category, and only known mods can be in categories.
+        let mut categorized = HashSet::new();
+        categories.values_mut().for_each(|mod_ids| mod_ids.retain(|mod_id| mods.contains_key(mod_id) && categorized.insert(mod_id.to_owned())));
+
+        let mut ordered = HashSet::new();
+        let mut categories_order = recovery.strings(value, "categories_order");
+        categories_order.retain(|category| categories.contains_key(category) && ordered.insert(category.to_owned()));
+        for category in categories.keys() {
+            if !categories_order.contains(category) {
+                categories_order.push(category.to_owned());
+            }
+        }
+
+        let mut config = Self {
+            version: GAME_CONFIG_VERSION,
+            game_key: game.key().to_owned(),
+            mods: mods.into_iter().collect(),
+            categories,
+            categories_order,
+            category_styles: recovery.map_entries(value, "category_styles").0,
+            game_build: recovery.field(value, "game_build"),
+            load_order_slots: recovery.map_entries(value, "load_order_slots").0,
+            category_rules: recovery.field(value, "category_rules"),
+        };
+
+        // The default category always goes last.
+        config.categories_mut().entry(DEFAULT_CATEGORY.to_owned()).or_default();
+        config.categories_order_mut().retain(|category| category != DEFAULT_CATEGORY);
+        config.categories_order_mut().push(DEFAULT_CATEGORY.to_owned());
+
+        config
+    }
+
     pub fn save(&mut self, game: &GameInfo) -> Result<()> {
         let path = game_config_path()?.join(format!("{GAME_CONFIG_FILE_NAME_START}{}{GAME_CONFIG_FILE_NAME_END}", game.key()));
+        self.version = GAME_CONFIG_VERSION;
 
         // Make sure the path exists to avoid problems with updating schemas.
         if let Some(parent_folder) = path.parent() {
             DirBuilder::new().recursive(true).create(parent_folder)?;
         }
 
-        let mut file = BufWriter::new(File::create(path)?);
+        // Write to a temporary file first, so sync programs and other instances of Runcher watching this config never see it half-written.
+        let temp_path = path.with_extension("json.part");
+        let mut file = BufWriter::new(File::create(&temp_path)?);
         file.write_all(to_string_pretty(&self)?.as_bytes())?;
+        file.flush()?;
+        drop(file);
+
+        rename(temp_path, path)?;
         Ok(())
     }
 
@@ -172,13 +342,96 @@ impl GameConfig {
 
         self.categories_mut().remove(category);
         self.categories_order_mut().retain(|x| x != category);
+        self.category_styles_mut().remove(category);
+    }
+
+    /// This function sets the visuals of a category. Styles without color nor icon are removed, so the config doesn't fill with empty entries.
+    pub fn set_category_style(&mut self, category: &str, style: CategoryStyle) {
+        if style == CategoryStyle::default() {
+            self.category_styles_mut().remove(category);
+        } else {
+            self.category_styles_mut().insert(category.to_owned(), style);
+        }
+    }
+
+    /// This function moves the mods in the default category matching the category rules to their category, creating it if needed.
+    ///
+    /// Returns the moved mods, alongside the category they were moved to.
+    pub fn apply_category_rules(&mut self) -> Vec<(String, String)> {
+        let moves = category_rule_moves(&self.category_rules, self);
+        for (mod_id, category) in &moves {
+            if !self.categories().contains_key(category) {
+                self.create_category(category);
+            }
+
+            if let Some(mods) = self.categories_mut().get_mut(DEFAULT_CATEGORY) {
+                mods.retain(|id| id != mod_id);
+            }
+
+            if let Some(mods) = self.categories_mut().get_mut(category) {
+                mods.push(mod_id.to_owned());
+            }
+        }
+
+        moves
+    }
+
+    /// This function enables the mods in the provided load order, and disables the rest.
+    pub fn enable_only_load_order_mods(&mut self, load_order: &LoadOrder) {
+        self.mods_mut().values_mut().for_each(|modd| { modd.set_enabled(false); });
+
+        for mod_id in load_order.mods() {
+            if let Some(modd) = self.mods_mut().get_mut(mod_id) {
+                modd.set_enabled(true);
+            }
+        }
     }
 
     /// NOTE: This returns a channel receiver for the workshop/equivalent service data request.
     /// This is done so the request doesn't hang the entire load process, as it usually takes 2 or 3 seconds to complete.
     pub fn update_mod_list(&mut self, game: &GameInfo, game_path: &Path, load_order: &mut LoadOrder, skip_network_update: bool) -> Result<Option<Receiver<Response>>> {
+        // Refresh the copies of the movie packs to load as mod packs before scanning, so the scan picks them up.
+        if let Ok(secondary_path) = secondary_mods_path(game.key()) {
+            for mod_id in update_repacked_movie_packs(game, self, &secondary_path) {
+                error!("Error generating the mod pack copy of {}.", mod_id);
+            }
+        }
+
+        // Only packs changed since the last scan are read, so reloads of big mod folders don't take forever.
+        let start = Instant::now();
+        let mut pack_scan_cache = PackScanCache::load(game);
+        let secondary_mods_paths = secondary_mods_packs_paths(game.key());
+        let receiver = self.scan_mods(game, game_path, &secondary_mods_paths, &mut pack_scan_cache, skip_network_update)?;
+
+        if let Err(error) = pack_scan_cache.save(game) {
+            error!("Error saving the pack scan cache: {}", error);
+        }
+
+        info!("Scanned {} mods for {} in {} ms.", self.mods().values().filter(|modd| !modd.paths().is_empty() && !*modd.ignored()).count(), game.key(), start.elapsed().as_millis());
+
+        // Update the current load order to reflect any change related to mods no longer being installed or being added as new.
+        let game_data_path = game.data_path(game_path)?;
+        load_order.update(self, &game_data_path);
+        load_order.save(game)?;
+
+        // Save the GameConfig or we may lost the population.
+        self.save(game)?;
+
+        Ok(receiver)
+    }
+
+    /// This function scans the game's folders for mods, updating their data and categories. Nothing is saved to disk.
+    ///
+    /// The secondary folder packs are passed as argument so this can be used without touching the settings.
+    pub fn scan_mods(&mut self, game: &GameInfo, game_path: &Path, secondary_mods_paths: &Option<Vec<PathBuf>>, pack_scan_cache: &mut PackScanCache, skip_network_update: bool) -> Result<Option<Receiver<Response>>> {
         let mut receiver = None;
 
+        // Packs generated by us or other tools are skipped by default, so their content is not loaded twice.
+        let show_generated_packs = setting_bool("show_generated_packs");
+
+        // Keep the mods we already knew about, so we can tell which ones are new.
+        let known_mods = self.mods().keys().cloned().collect::<HashSet<_>>();
+
         // Clear the mod paths, just in case a failure while loading them leaves them unclean.
         self.mods_mut().values_mut().for_each(|modd| modd.paths_mut().clear());
 
@@ -189,9 +442,9 @@ impl GameConfig {
             // In that case, we assume there are no packs nor mods to load to avoid further errors.
             if let Ok(vanilla_packs) = game.ca_packs_paths(game_path) {
                 let data_paths = game.data_packs_paths(game_path);
+                let data_path = game.data_path(game_path).map(|path| std::fs::canonicalize(&path).unwrap_or(path)).unwrap_or_default();
                 let content_path = game.content_path(game_path).map(|path| std::fs::canonicalize(path.clone()).unwrap_or_else(|_| path));
                 let content_paths = game.content_packs_paths(game_path);
-                let secondary_mods_paths = secondary_mods_packs_paths(game.key());
 
                 let mut steam_ids = vec![];
 
@@ -200,22 +453,24 @@ impl GameConfig {
                 // These have less priority.
                 if let Ok(ref content_path) = content_path {
                     if let Some(ref paths) = content_paths {
+                        let pack_types = pack_scan_cache.pack_types(paths);
                         let (packs, maps): (Vec<_>, Vec<_>) = paths.par_iter()
-                            .partition_map(|path| match Pack::read_and_merge(&[path.to_path_buf()], true, false, false) {
-                                Ok(pack) => Either::Left((path, pack)),
+                            .zip(pack_types)
+                            .partition_map(|(path, pack_type)| match pack_type {
+                                Ok(pack_type) => Either::Left((path, pack_type)),
                                 Err(_) => Either::Right(path),
                             });
 
-                        for (path, pack) in packs {
+                        for (path, pack_type) in packs {
                             let pack_name = path.file_name().unwrap().to_string_lossy().as_ref().to_owned();
-                            if pack.pfh_file_type() == PFHFileType::Mod || pack.pfh_file_type() == PFHFileType::Movie {
+                            if pack_type == PFHFileType::Mod || pack_type == PFHFileType::Movie {
                                 match self.mods_mut().get_mut(&pack_name) {
                                     Some(modd) => {
                                         if !modd.paths().contains(path) {
                                             modd.paths_mut().push(path.to_path_buf());
                                         }
 
-                                        modd.set_pack_type(pack.pfh_file_type());
+                                        modd.set_pack_type(pack_type);
 
                                         let metadata = modd.paths().last().unwrap().metadata()?;
                                         #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
@@ -235,7 +490,7 @@ impl GameConfig {
                                         modd.set_name(pack_name.to_owned());
                                         modd.set_id(pack_name.to_owned());
                                         modd.set_paths(vec![path.to_path_buf()]);
-                                        modd.set_pack_type(pack.pfh_file_type());
+                                        modd.set_pack_type(pack_type);
 
                                         let metadata = modd.paths()[0].metadata()?;
                                         #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
@@ -323,175 +578,241 @@ impl GameConfig {
                     }
                 }
 
-                // Ignore network population errors for now.
-                if !skip_network_update {
+                // Ignore network population errors for now. Offline, we keep the Workshop data we already have.
+                if !skip_network_update && offline_mode().is_none() {
                     receiver = Some(CENTRAL_COMMAND.send_network(Command::RequestModsData(Box::new(game.clone()), steam_ids)));
                 }
 
                 // Then, if the game supports secondary mod path (only since Shogun 2) we check for mods in there. These have middle priority.
                 //
                 // Non supported games will simply return "None" here.
-                if let Some(ref paths) = secondary_mods_paths {
-                    let paths = paths.iter()
-                        .filter(|path| {
-                            if let Ok(canon_path) = std::fs::canonicalize(path) {
-                                !vanilla_packs.contains(&canon_path) &&
-                                    canon_path.file_name().map(|x| x.to_string_lossy().to_string()).unwrap_or_else(String::new) != RESERVED_PACK_NAME &&
-                                    canon_path.file_name().map(|x| x.to_string_lossy().to_string()).unwrap_or_else(String::new) != RESERVED_PACK_NAME_ALTERNATIVE
-                            } else {
-                                false
+                if let Some(paths) = secondary_mods_paths {
+                    let paths = scannable_packs(paths, &vanilla_packs, show_generated_packs);
+
+                    let pack_types = pack_scan_cache.pack_types(&paths);
+                    for (path, pack_type) in paths.iter().zip(pack_types) {
+                        if let Ok(pack_type) = pack_type {
+                            if pack_type == PFHFileType::Mod || pack_type == PFHFileType::Movie {
+                                self.add_local_pack(path, pack_type, false, &data_path)?;
                             }
-                        })
-                        .collect::<Vec<_>>();
+                        }
+                    }
+                }
 
-                    let packs = paths.par_iter()
-                        .map(|path| (path, Pack::read_and_merge(&[path.to_path_buf()], true, false, false)))
-                        .collect::<Vec<_>>();
+                // Then finally we process /data packs. These have the highest priority.
+                if let Some(ref paths) = data_paths {
+                    let paths = scannable_packs(paths, &vanilla_packs, show_generated_packs);
 
-                    for (path, pack) in packs {
-                        let pack_name = path.file_name().unwrap().to_string_lossy().as_ref().to_owned();
-                        if let Ok(pack) = pack {
-                            if pack.pfh_file_type() == PFHFileType::Mod || pack.pfh_file_type() == PFHFileType::Movie {
+                    let pack_types = pack_scan_cache.pack_types(&paths);
+                    for (path, pack_type) in paths.iter().zip(pack_types) {
+                        if let Ok(pack_type) = pack_type {
+                            if pack_type == PFHFileType::Mod || pack_type == PFHFileType::Movie {
 
-                                match self.mods_mut().get_mut(&pack_name) {
-                                    Some(modd) => {
-                                        if !modd.paths().contains(path) {
-                                            modd.paths_mut().insert(0, path.to_path_buf());
-                                        }
-                                        modd.set_pack_type(pack.pfh_file_type());
+                                // These are not cannonicalized by default.
+                                let path = std::fs::canonicalize(path)?;
+                                self.add_local_pack(&path, pack_type, true, &data_path)?;
+                            }
+                        }
+                    }
+                }
+            }
+        }
 
-                                        let metadata = modd.paths()[0].metadata()?;
-                                        #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
-                                        modd.set_time_updated(metadata.modified()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
-                                    }
-                                    None => {
+        self.mark_new_mods(&known_mods)?;
+        self.mods_mut().par_iter_mut().for_each(|(_, modd)| update_pack_data(modd, pack_scan_cache));
+        self.update_categories();
 
-                                        // If the mod fails to be found, is possible is a legacy mod. Find it by alt name.
-                                        match self.mods_mut().values_mut()
-                                            .filter(|modd| modd.alt_name().is_some())
-                                            .find(|modd| modd.alt_name().unwrap() == pack_name) {
+        Ok(receiver)
+    }
 
-                                            Some(modd) => {
-                                                if !modd.paths().contains(path) {
-                                                    modd.paths_mut().insert(0, path.to_path_buf());
-                                                }
-                                                modd.set_pack_type(pack.pfh_file_type());
+    /// This function adds the local pack at the provided path to the mod it belongs to, creating the mod if there is none. Returns the id of the mod.
+    ///
+    /// Data packs go first. Secondary packs go after the data ones, but before the content ones.
+    fn add_local_pack(&mut self, path: &Path, pack_type: PFHFileType, is_data: bool, data_path: &Path) -> Result<String> {
+        let pack_name = path.file_name().unwrap().to_string_lossy().to_string();
+
+        // Data packs may correspond to a bin, and legacy mods are found by their alt name.
+        let bin_mod_id = if is_data {
+            self.mods().values()
+                .find(|modd| !modd.file_name().is_empty() && modd.file_name().split('/').last() == Some(pack_name.as_str()))
+                .map(|modd| modd.id().to_owned())
+        } else {
+            None
+        };
 
-                                                let metadata = modd.paths()[0].metadata()?;
-                                                #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
-                                                modd.set_time_updated(metadata.modified()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
-                                            }
+        let mod_id = match bin_mod_id {
+            Some(ref mod_id) => mod_id.to_owned(),
+            None => match self.mods().get(&pack_name) {
+                Some(modd) => modd.id().to_owned(),
+                None => match self.mods().values().find(|modd| modd.alt_name().is_some_and(|alt_name| alt_name == pack_name)) {
+                    Some(modd) => modd.id().to_owned(),
+                    None => {
+                        let mut modd = Mod::default();
+                        modd.set_name(pack_name.to_owned());
+                        modd.set_id(pack_name.to_owned());
+                        self.mods_mut().insert(pack_name.to_owned(), modd);
+                        pack_name
+                    }
+                }
+            }
+        };
 
-                                            None => {
+        if let Some(modd) = self.mods_mut().get_mut(&mod_id) {
+            let index = if is_data {
+                0
+            } else {
+                modd.paths().iter().take_while(|path| path.starts_with(data_path)).count()
+            };
 
-                                                let mut modd = Mod::default();
-                                                modd.set_name(pack_name.to_owned());
-                                                modd.set_id(pack_name.to_owned());
-                                                modd.set_paths(vec![path.to_path_buf()]);
-                                                modd.set_pack_type(pack.pfh_file_type());
+            if !modd.paths().iter().any(|mod_path| mod_path == path) {
+                modd.paths_mut().insert(index, path.to_path_buf());
+            }
 
-                                                let metadata = modd.paths()[0].metadata()?;
-                                                #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
-                                                modd.set_time_updated(metadata.modified()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
+            if index == 0 && bin_mod_id.is_none() {
+                modd.set_pack_type(pack_type);
+            }
 
-                                                self.mods_mut().insert(pack_name, modd);
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+            let metadata = modd.paths()[0].metadata()?;
+            #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
+            modd.set_time_updated(metadata.modified()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
+        }
 
-                // Then finally we process /data packs. These have the highest priority.
-                if let Some(ref paths) = data_paths {
-                    let paths = paths.iter()
-                        .filter(|path| {
-                            if let Ok(canon_path) = std::fs::canonicalize(path) {
-                                let file_name = canon_path.file_name().map(|x| x.to_string_lossy().to_string()).unwrap_or_else(String::new);
-                                !vanilla_packs.contains(&canon_path) && file_name != RESERVED_PACK_NAME && file_name != RESERVED_PACK_NAME_ALTERNATIVE
-                            } else {
-                                false
-                            }
-                        })
-                        .collect::<Vec<_>>();
+        Ok(mod_id)
+    }
 
-                    let packs = paths.par_iter()
-                        .map(|path| (path, Pack::read_and_merge(&[path.to_path_buf()], true, false, false)))
-                        .collect::<Vec<_>>();
+    /// This function marks when we first saw each new mod. On the first scan of a game everything is new, so we cannot know when they were installed.
+    fn mark_new_mods(&mut self, known_mods: &HashSet<String>) -> Result<()> {
+        if !known_mods.is_empty() {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+            self.mods_mut().values_mut()
+                .filter(|modd| !modd.paths().is_empty() && !known_mods.contains(modd.id()))
+                .for_each(|modd| modd.set_first_seen(now));
+        }
 
-                    for (path, pack) in packs {
-                        let pack_name = path.file_name().unwrap().to_string_lossy().as_ref().to_owned();
-                        if let Ok(pack) = pack {
-                            if pack.pfh_file_type() == PFHFileType::Mod || pack.pfh_file_type() == PFHFileType::Movie {
+        Ok(())
+    }
 
-                                // These are not cannonicalized by default.
-                                let path = std::fs::canonicalize(path)?;
+    /// This function rescans only part of the mods of the game, updating the load order and saving both to disk.
+    ///
+    /// Unlike [`update_mod_list`](Self::update_mod_list), only the packs in the scope are checked, and no Workshop data is requested,
+    /// so small changes don't need a full reload.
+    pub fn rescan_mods(&mut self, game: &GameInfo, game_path: &Path, load_order: &mut LoadOrder, scope: &RescanScope) -> Result<()> {
+        let game_data_path = game.data_path(game_path)?;
+        let data_path = std::fs::canonicalize(&game_data_path).unwrap_or_else(|_| game_data_path.to_path_buf());
+        let secondary_path = secondary_mods_path(game.key()).ok();
+        let vanilla_packs = game.ca_packs_paths(game_path)?;
+        let show_generated_packs = setting_bool("show_generated_packs");
+
+        // Refresh the copies of the movie packs to load as mod packs, as they live in the secondary folder.
+        if *scope != RescanScope::Data {
+            if let Some(ref secondary_path) = secondary_path {
+                for mod_id in update_repacked_movie_packs(game, self, secondary_path) {
+                    error!("Error generating the mod pack copy of {}.", mod_id);
+                }
+            }
+        }
 
-                                // Check if the pack corresponds to a bin.
-                                if let Some((_, modd)) = self.mods_mut().iter_mut().find(|(_, modd)| !modd.file_name().is_empty() && modd.file_name().split('/').last().unwrap() == pack_name) {
-                                    if !modd.paths().contains(&path) {
-                                        modd.paths_mut().insert(0, path.to_path_buf());
-                                    }
+        // Get the packs to scan from each folder in the scope. Data packs are not canonicalized by default.
+        let mut data_packs = vec![];
+        let mut secondary_packs = vec![];
+        if *scope != RescanScope::Secondary {
+            if let Some(paths) = game.data_packs_paths(game_path) {
+                data_packs = scannable_packs(&paths, &vanilla_packs, show_generated_packs)
+                    .iter()
+                    .filter_map(|path| std::fs::canonicalize(path).ok())
+                    .collect();
+            }
+        }
 
-                                    let metadata = modd.paths()[0].metadata()?;
-                                    #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
-                                    modd.set_time_updated(metadata.modified()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
-                                } else {
-                                    match self.mods_mut().get_mut(&pack_name) {
-                                        Some(modd) => {
-                                            if !modd.paths().contains(&path) {
-                                                modd.paths_mut().insert(0, path.to_path_buf());
-                                            }
-                                            modd.set_pack_type(pack.pfh_file_type());
+        if *scope != RescanScope::Data {
+            if let Some(paths) = secondary_mods_packs_paths(game.key()) {
+                secondary_packs = scannable_packs(&paths, &vanilla_packs, show_generated_packs);
+            }
+        }
 
-                                            let metadata = modd.paths()[0].metadata()?;
-                                            #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
-                                            modd.set_time_updated(metadata.modified()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
-                                        }
+        // For a single mod, we only care about its own packs.
+        if let RescanScope::Mod(mod_id) = scope {
+            let pack_names = match self.mods().get(mod_id) {
+                Some(modd) => {
+                    let mut names = vec![modd.id().to_owned()];
+                    if let Some(alt_name) = modd.alt_name() {
+                        names.push(alt_name);
+                    }
 
-                                        // Same as with secondaries for legacy mods.
-                                        None => {
-                                            match self.mods_mut().values_mut()
-                                                .filter(|modd| modd.alt_name().is_some())
-                                                .find(|modd| modd.alt_name().unwrap() == pack_name) {
-
-                                                Some(modd) => {
-                                                    if !modd.paths().contains(&path) {
-                                                        modd.paths_mut().insert(0, path.to_path_buf());
-                                                    }
-                                                    modd.set_pack_type(pack.pfh_file_type());
-
-                                                    let metadata = modd.paths()[0].metadata()?;
-                                                    #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
-                                                    modd.set_time_updated(metadata.modified()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
-                                                }
+                    if let Some(file_name) = modd.file_name().split('/').last() {
+                        if !file_name.is_empty() {
+                            names.push(file_name.to_owned());
+                        }
+                    }
 
-                                                None => {
-                                                    let mut modd = Mod::default();
-                                                    modd.set_name(pack_name.to_owned());
-                                                    modd.set_id(pack_name.to_owned());
-                                                    modd.set_paths(vec![path.to_path_buf()]);
-                                                    modd.set_pack_type(pack.pfh_file_type());
+                    names
+                }
+                None => return Err(anyhow!("Mod {} not found.", mod_id)),
+            };
 
-                                                    let metadata = modd.paths()[0].metadata()?;
-                                                    #[cfg(target_os = "windows")] modd.set_time_created(metadata.created()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
-                                                    modd.set_time_updated(metadata.modified()?.duration_since(UNIX_EPOCH)?.as_secs() as usize);
+            let is_mod_pack = |path: &PathBuf| path.file_name().is_some_and(|name| pack_names.iter().any(|pack_name| **pack_name == *name.to_string_lossy()));
+            data_packs.retain(is_mod_pack);
+            secondary_packs.retain(is_mod_pack);
+        }
 
-                                                    self.mods_mut().insert(pack_name, modd);
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
+        // Remove the paths we're about to rescan, so mods whose packs were deleted lose them.
+        let in_scope = |path: &Path| match scope {
+            RescanScope::Data => path.starts_with(&data_path),
+            RescanScope::Secondary => secondary_path.as_ref().is_some_and(|secondary_path| path.starts_with(secondary_path)),
+            RescanScope::Mod(_) => path.starts_with(&data_path) || secondary_path.as_ref().is_some_and(|secondary_path| path.starts_with(secondary_path)) || !path.is_file(),
+        };
+
+        let known_mods = self.mods().keys().cloned().collect::<HashSet<_>>();
+        for modd in self.mods_mut().values_mut() {
+            if let RescanScope::Mod(mod_id) = scope {
+                if modd.id() != mod_id {
+                    continue;
+                }
+            }
+
+            modd.paths_mut().retain(|path| !in_scope(path));
+        }
+
+        let mut pack_scan_cache = PackScanCache::load(game);
+        let mut touched_mods = HashSet::new();
+        for (paths, is_data) in [(&secondary_packs, false), (&data_packs, true)] {
+            let pack_types = pack_scan_cache.pack_types(paths);
+            for (path, pack_type) in paths.iter().zip(pack_types) {
+                if let Ok(pack_type) = pack_type {
+                    if pack_type == PFHFileType::Mod || pack_type == PFHFileType::Movie {
+                        touched_mods.insert(self.add_local_pack(path, pack_type, is_data, &data_path)?);
                     }
                 }
             }
         }
 
+        // The mod may have lost all its local packs, but it still needs its flags updated.
+        if let RescanScope::Mod(mod_id) = scope {
+            touched_mods.insert(mod_id.to_owned());
+        }
+
+        self.mark_new_mods(&known_mods)?;
+        for mod_id in &touched_mods {
+            if let Some(modd) = self.mods_mut().get_mut(mod_id) {
+                update_pack_data(modd, &pack_scan_cache);
+            }
+        }
+
+        // Only the packs in the scope were scanned, so keep the cached data of the rest.
+        pack_scan_cache.keep_previous_entries();
+        if let Err(error) = pack_scan_cache.save(game) {
+            error!("Error saving the pack scan cache: {}", error);
+        }
+
+        self.update_categories();
+
+        load_order.update(self, &game_data_path);
+        load_order.save(game)?;
+        self.save(game)
+    }
+
+    /// This function removes mods without paths from the categories, adds the new ones to the default category, and sorts them using the category rules.
+    fn update_categories(&mut self) {
         // Update the categories list to remove any mod that has no path, and add any new mod to the default category.
         for mods in self.categories.values_mut() {
             mods.retain(|mod_id| match self.mods.get(mod_id) {
@@ -512,6 +833,11 @@ impl GameConfig {
             None => { self.categories_mut().insert(DEFAULT_CATEGORY.to_owned(), mods_to_add); },
         }
 
+        // Sort the mods not yet assigned to a category using the user's rules. Mods without Workshop data yet get sorted once it arrives.
+        for (mod_id, category) in self.apply_category_rules() {
+            info!("Mod {} sent to category {} by a category rule.", mod_id, category);
+        }
+
         // If we got a default category, make sure it's always at the end.
         if let Some(cat) = self.categories_order().last() {
             if cat != DEFAULT_CATEGORY && self.categories().get(DEFAULT_CATEGORY).is_some() {
@@ -520,15 +846,35 @@ impl GameConfig {
                 }
             }
         }
+    }
+}
 
-        // Update the current load order to reflect any change related to mods no longer being installed or being added as new.
-        let game_data_path = game.data_path(game_path)?;
-        load_order.update(self, &game_data_path);
-        load_order.save(game)?;
+/// This function returns the provided packs, minus the vanilla ones and the ones generated by us or other tools, unless we want to see them.
+fn scannable_packs(paths: &[PathBuf], vanilla_packs: &[PathBuf], show_generated_packs: bool) -> Vec<PathBuf> {
+    paths.iter()
+        .filter(|path| {
+            if let Ok(canon_path) = std::fs::canonicalize(path) {
+                let file_name = canon_path.file_name().map(|x| x.to_string_lossy().to_string()).unwrap_or_else(String::new);
+                !vanilla_packs.contains(&canon_path) && file_name != RESERVED_PACK_NAME && file_name != RESERVED_PACK_NAME_ALTERNATIVE &&
+                    (show_generated_packs || !is_generated_pack(&file_name))
+            } else {
+                false
+            }
+        })
+        .cloned()
+        .collect()
+}
 
-        // Save the GameConfig or we may lost the population.
-        self.save(game)?;
+/// This function updates the data we get from the first pack of the provided mod.
+///
+/// Movie packs that look like mod packs are flagged, so the user knows why their load order may be broken.
+fn update_pack_data(modd: &mut Mod, pack_scan_cache: &PackScanCache) {
+    let misfiled = *modd.pack_type() == PFHFileType::Movie && !modd.paths().is_empty() && pack_scan_cache.is_misfiled_movie_pack(&modd.paths()[0]);
+    modd.set_misfiled_movie_pack(misfiled);
 
-        Ok(receiver)
-    }
+    let game_version = modd.paths().first().map(|path| pack_scan_cache.game_version(path)).unwrap_or_default();
+    modd.set_game_version(game_version);
+
+    let campaign_safety = modd.paths().first().map(|path| pack_scan_cache.campaign_safety(path)).unwrap_or_default();
+    modd.set_campaign_safety(campaign_safety);
 }
@@ -89,6 +89,7 @@ impl From<&GameConfigV4> for GameConfigV5 {
             mods: value.mods.iter().map(|(key, value)| (key.to_owned(), ModV5::from(value))).collect::<HashMap<_, _>>(),
             categories: value.categories.clone(),
             categories_order: value.categories_order.clone(),
+            ..Default::default()
         }
     }
 }
@@ -0,0 +1,191 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the user-defined game definitions.
+//!
+//! The file formats of each game come from rpfm_lib, so a game cannot be defined from scratch at runtime.
+//! What users can do is override the parts of a supported game that differ in their install (its executable, its Steam app id,
+//! its schema, its name and its icon), or add a new game, like a Steam beta, a standalone saga or a newly-released title,
+//! based on the supported game whose formats it shares.
+//!
+//! New games get their own entry in the game selector. Selecting one loads its base game with the overrides of the new game,
+//! so they share the path, mods and profiles of their base game.
+//!
+//! Definitions are read from a json file in the config folder, so they can be edited without waiting for a release.
+//! They're read once per run, so changes need a restart.
+
+use anyhow::Result;
+use getset::*;
+use log::error;
+use serde::{Deserialize, Serialize};
+use serde_json::from_reader;
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+use rpfm_lib::games::GameInfo;
+
+use rpfm_ui_common::settings::{config_path, setting_string, set_setting_string};
+
+use crate::SUPPORTED_GAMES;
+
+const GAME_DEFINITIONS_FILE_NAME: &str = "game_definitions.json";
+
+lazy_static::lazy_static! {
+
+    /// Definitions in use, so we don't read the file every time we need to check a game.
+    static ref GAME_DEFINITIONS: GameDefinitions = GameDefinitions::load();
+}
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct GameDefinitions {
+
+    /// Definitions of each game, by key. For supported games, the key of the game. For new games, any key not used by a supported game.
+    games: HashMap<String, GameDefinition>,
+}
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+#[serde(default)]
+pub struct GameDefinition {
+
+    /// Key of the supported game whose formats the game uses. Only for new games.
+    base_game: Option<String>,
+
+    /// Name shown for the game in the game selector.
+    display_name: Option<String>,
+
+    /// Path of the executable of the game, relative to the game's folder.
+    executable: Option<String>,
+
+    /// Steam app id of the game. Used to launch it through Steam and to find its Workshop mods.
+    steam_id: Option<u64>,
+
+    /// File name of the schema of the game, in the schemas folder.
+    schema: Option<String>,
+
+    /// Absolute path of the icon shown for the game in the game selector.
+    icon: Option<PathBuf>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl GameDefinitions {
+
+    /// This function loads the user-defined game definitions. A broken file is reported and ignored, so it doesn't stop the program from starting.
+    pub fn load() -> Self {
+        let path = match game_definitions_path() {
+            Ok(path) => path,
+            Err(_) => return Self::default(),
+        };
+
+        match File::open(&path) {
+            Ok(file) => match from_reader(BufReader::new(file)) {
+                Ok(definitions) => definitions,
+                Err(error) => {
+                    error!("Error loading the game definitions at {}: {}", path.to_string_lossy(), error);
+                    Self::default()
+                }
+            },
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// This function returns the definitions in use.
+    pub fn cached() -> &'static Self {
+        &GAME_DEFINITIONS
+    }
+
+    /// This function returns the new games defined by the user, sorted by key. Games with an unknown base game are ignored.
+    pub fn new_games(&self) -> Vec<(&String, &GameDefinition)> {
+        let mut games = self.games.iter()
+            .filter(|(key, definition)| SUPPORTED_GAMES.game(key).is_none() && definition.base_game().as_ref().is_some_and(|base| SUPPORTED_GAMES.game(base).is_some()))
+            .collect::<Vec<_>>();
+
+        games.sort_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b));
+        games
+    }
+}
+
+impl GameDefinition {
+
+    /// This function returns the path of the executable of the provided game with this definition applied.
+    pub fn executable_path(&self, game: &GameInfo, game_path: &Path) -> Option<PathBuf> {
+        match self.executable() {
+            Some(executable) => Some(game_path.join(executable)),
+            None => game.executable_path(game_path),
+        }
+    }
+}
+
+/// This function returns the key of the supported game to load for the provided key. For new games, their base game.
+pub fn base_game_key(key: &str) -> String {
+    match GameDefinitions::cached().new_games().iter().find(|(new_key, _)| *new_key == key) {
+        Some((_, definition)) => definition.base_game().clone().unwrap_or_default(),
+        None => key.to_owned(),
+    }
+}
+
+/// This function returns the key of the new game loaded on top of the provided supported game, if any.
+pub fn active_new_game(game_key: &str) -> Option<String> {
+    let key = setting_string(&format!("active_new_game_{game_key}"));
+    if GameDefinitions::cached().new_games().iter().any(|(new_key, definition)| **new_key == key && definition.base_game().as_deref() == Some(game_key)) {
+        Some(key)
+    } else {
+        None
+    }
+}
+
+/// This function sets the new game loaded on top of the provided supported game. None loads the supported game as is.
+pub fn set_active_new_game(game_key: &str, new_game: Option<&str>) {
+    set_setting_string(&format!("active_new_game_{game_key}"), new_game.unwrap_or_default());
+}
+
+/// This function returns the user definition of the provided game, or an empty one if the user didn't define it.
+///
+/// If a new game based on the provided one is loaded, its definition is the one returned.
+pub fn game_definition(game: &GameInfo) -> GameDefinition {
+    let key = active_new_game(game.key()).unwrap_or_else(|| game.key().to_owned());
+    GameDefinitions::cached().games().get(&key).cloned().unwrap_or_default()
+}
+
+/// This function returns the path of the executable of the provided game, taking into account the user definition of the game.
+pub fn executable_path(game: &GameInfo, game_path: &Path) -> Option<PathBuf> {
+    game_definition(game).executable_path(game, game_path)
+}
+
+/// This function returns the Steam app id of the provided game, taking into account the user definition of the game.
+pub fn steam_id(game: &GameInfo, game_path: &Path) -> Result<u64> {
+    match game_definition(game).steam_id() {
+        Some(steam_id) => Ok(*steam_id),
+        None => Ok(game.steam_id(game_path)?),
+    }
+}
+
+/// This function returns the file name of the schema of the provided game, taking into account the user definition of the game.
+pub fn schema_file_name(game: &GameInfo) -> String {
+    game_definition(game).schema()
+        .clone()
+        .unwrap_or_else(|| game.schema_file_name().to_owned())
+}
+
+/// This function returns the path of the user-defined game definitions.
+pub fn game_definitions_path() -> Result<PathBuf> {
+    Ok(config_path()?.join(GAME_DEFINITIONS_FILE_NAME))
+}
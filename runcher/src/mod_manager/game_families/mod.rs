@@ -0,0 +1,340 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the families of games, and the migration of configs between games of the same family.
+//!
+//! Some games are sibling titles of the same game, like Pharaoh and Pharaoh Dynasties, and players move between them keeping most of their mods.
+//! Each game has its own config, so instead of rebuilding categories and profiles by hand, they can be migrated from a sibling game.
+//! Mods may have different pack names in each game, so each mod is mapped to a mod of the other game before migrating anything.
+
+use getset::*;
+
+use std::collections::{HashMap, HashSet};
+
+use rpfm_lib::games::supported_games::{KEY_PHARAOH, KEY_PHARAOH_DYNASTIES};
+
+use super::game_config::{GameConfig, DEFAULT_CATEGORY};
+use super::load_order::{LoadOrder, LoadOrderGroup};
+use super::name_matching::match_mod_names;
+use super::profiles::Profile;
+
+/// Families of sibling games, by game key.
+const GAME_FAMILIES: [&[&str]; 1] = [
+    &[KEY_PHARAOH_DYNASTIES, KEY_PHARAOH],
+];
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// Mapping of a mod of the source game to the mods of the target game that may be the same mod.
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct ModMapping {
+
+    /// Id of the mod in the source game.
+    source_id: String,
+
+    /// Visual name of the mod in the source game.
+    source_name: String,
+
+    /// Ids of the mods of the target game that may be the same mod, with their score from 0 to 1, best first.
+    candidates: Vec<(String, f64)>,
+
+    /// Id of the mod of the target game used by default, if any is good enough.
+    best: Option<String>,
+}
+
+/// Parts of the config to migrate.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FamilyMigration {
+
+    /// Categories, with their styles and rules.
+    pub categories: bool,
+
+    /// Enabled mods, load order and ignored mods.
+    pub mods: bool,
+
+    /// Profiles.
+    pub profiles: bool,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function returns the keys of the games in the same family as the provided game, not including it.
+pub fn sibling_games(game_key: &str) -> Vec<&'static str> {
+    GAME_FAMILIES.iter()
+        .find(|family| family.contains(&game_key))
+        .map(|family| family.iter().filter(|key| **key != game_key).copied().collect())
+        .unwrap_or_default()
+}
+
+/// This function maps the mods used in the config and profiles of the source game to the installed mods of the target game.
+///
+/// Mods with the same pack name in both games are mapped directly. The rest are matched by name.
+pub fn mod_mappings(source: &GameConfig, source_profiles: &HashMap<String, Profile>, target: &GameConfig) -> Vec<ModMapping> {
+    let mut mod_ids = source.categories_order().iter()
+        .filter_map(|category| source.categories().get(category))
+        .flatten()
+        .cloned()
+        .collect::<Vec<_>>();
+
+    mod_ids.extend(source_profiles.values().flat_map(|profile| profile.load_order().mods().iter().cloned()));
+
+    let mut seen = HashSet::new();
+    mod_ids.retain(|mod_id| seen.insert(mod_id.to_owned()));
+
+    mod_ids.into_iter()
+        .map(|source_id| {
+            let source_name = source.mods().get(&source_id)
+                .map(|modd| modd.name().to_owned())
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| source_id.strip_suffix(".pack").unwrap_or(&source_id).to_owned());
+
+            if target.mods().get(&source_id).is_some_and(|modd| !modd.paths().is_empty()) {
+                return ModMapping {
+                    candidates: vec![(source_id.to_owned(), 1.0)],
+                    best: Some(source_id.to_owned()),
+                    source_id,
+                    source_name,
+                };
+            }
+
+            let (candidates, best) = match match_mod_names(target, &source_name).pop() {
+                Some(name_match) => (name_match.candidates().to_vec(), name_match.best().map(|best| best.to_owned())),
+                None => (vec![], None),
+            };
+
+            ModMapping {
+                source_id,
+                source_name,
+                candidates,
+                best,
+            }
+        })
+        .collect()
+}
+
+/// This function migrates the categories, mods and load order of the source game to the target game, using the provided mapping of mod ids.
+///
+/// Mods without a mapping are skipped. The target's categories are kept: migrated categories are added to them,
+/// and only the mapped mods are moved to the categories they're in in the source game.
+pub fn migrate_game_config(source: &GameConfig, source_load_order: &LoadOrder, target: &mut GameConfig, target_load_order: &mut LoadOrder, mapping: &HashMap<String, String>, migration: &FamilyMigration) {
+    if migration.categories {
+        for category in source.categories_order() {
+            if category != DEFAULT_CATEGORY && !target.categories().contains_key(category) {
+                target.create_category(category);
+            }
+
+            if let Some(style) = source.category_styles().get(category) {
+                if !target.category_styles().contains_key(category) {
+                    target.set_category_style(category, style.clone());
+                }
+            }
+
+            let mod_ids = source.categories().get(category)
+                .map(|mod_ids| mod_ids.iter().filter_map(|mod_id| mapping.get(mod_id)).cloned().collect::<Vec<_>>())
+                .unwrap_or_default();
+
+            for mod_id in mod_ids {
+                if !target.mods().get(&mod_id).is_some_and(|modd| !modd.paths().is_empty()) {
+                    continue;
+                }
+
+                target.categories_mut().values_mut().for_each(|mods| mods.retain(|id| id != &mod_id));
+                if let Some(mods) = target.categories_mut().get_mut(category) {
+                    mods.push(mod_id);
+                }
+            }
+        }
+
+        for rule in source.category_rules() {
+            if !target.category_rules().contains(rule) {
+                target.category_rules_mut().push(rule.clone());
+            }
+        }
+    }
+
+    if migration.mods {
+        let ignored = mapping.iter()
+            .filter(|(source_id, _)| source.mods().get(*source_id).is_some_and(|modd| *modd.ignored()))
+            .map(|(_, target_id)| target_id.to_owned())
+            .collect::<HashSet<_>>();
+
+        let enabled = source_load_order.mods().iter()
+            .filter_map(|mod_id| mapping.get(mod_id))
+            .filter(|mod_id| target.mods().contains_key(*mod_id) && !ignored.contains(*mod_id))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for modd in target.mods_mut().values_mut() {
+            if ignored.contains(modd.id()) {
+                modd.set_ignored(true);
+            }
+
+            modd.set_enabled(enabled.contains(modd.id()));
+        }
+
+        target_load_order.set_automatic(*source_load_order.automatic());
+        target_load_order.set_mods(enabled);
+        target_load_order.set_groups(map_groups(source_load_order.groups(), mapping));
+    }
+}
+
+/// This function returns the provided profiles of the source game, converted to profiles of the target game using the provided mapping of mod ids.
+///
+/// Profiles with a name already used in the target game are skipped, so no profile gets overwritten. Everything else in the profile is kept, except:
+/// - MCT presets, as they're per game.
+/// - Signatures, as the mapped load order is not the one that was signed.
+/// - Campaign locks, as the campaign they were locked for is from the source game.
+pub fn migrate_profiles(source_profiles: &HashMap<String, Profile>, target_profiles: &HashMap<String, Profile>, target_game_key: &str, mapping: &HashMap<String, String>) -> Vec<Profile> {
+    source_profiles.values()
+        .filter(|profile| !target_profiles.contains_key(profile.id()))
+        .map(|profile| {
+            let mut migrated = profile.clone();
+            migrated.set_game(target_game_key.to_owned());
+            migrated.set_mct_preset(None);
+            migrated.set_signature(None);
+            migrated.set_locked(false);
+
+            let load_order = migrated.load_order_mut();
+            let mods = load_order.mods().iter().filter_map(|mod_id| mapping.get(mod_id)).cloned().collect();
+            let movies = load_order.movies().iter().filter_map(|mod_id| mapping.get(mod_id)).cloned().collect();
+            let groups = map_groups(load_order.groups(), mapping);
+            load_order.set_mods(mods);
+            load_order.set_movies(movies);
+            load_order.set_groups(groups);
+
+            migrated
+        })
+        .collect()
+}
+
+/// Groups are kept only if at least two of their mods are mapped, as a group of one mod does nothing.
+fn map_groups(groups: &[LoadOrderGroup], mapping: &HashMap<String, String>) -> Vec<LoadOrderGroup> {
+    groups.iter()
+        .filter_map(|group| {
+            let mods = group.mods().iter().filter_map(|mod_id| mapping.get(mod_id)).cloned().collect::<Vec<_>>();
+            if mods.len() < 2 {
+                return None;
+            }
+
+            let mut mapped = group.clone();
+            mapped.set_mods(mods);
+            Some(mapped)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::mod_manager::mods::Mod;
+
+    use super::*;
+
+    fn game_config(mods: &[(&str, &str)]) -> GameConfig {
+        let mut game_config = GameConfig::default();
+        for (mod_id, name) in mods {
+            let mut modd = Mod::default();
+            modd.set_id(mod_id.to_string());
+            modd.set_name(name.to_string());
+            modd.set_paths(vec![PathBuf::from(mod_id)]);
+            game_config.mods_mut().insert(mod_id.to_string(), modd);
+            game_config.categories_mut().entry(DEFAULT_CATEGORY.to_owned()).or_default().push(mod_id.to_string());
+        }
+
+        game_config.categories_order_mut().push(DEFAULT_CATEGORY.to_owned());
+        game_config
+    }
+
+    fn mapping<'a>(mappings: &'a [ModMapping], source_id: &str) -> &'a ModMapping {
+        mappings.iter().find(|mapping| mapping.source_id() == source_id).unwrap()
+    }
+
+    #[test]
+    fn sibling_games_of_a_family() {
+        assert_eq!(sibling_games(KEY_PHARAOH), vec![KEY_PHARAOH_DYNASTIES]);
+        assert_eq!(sibling_games(KEY_PHARAOH_DYNASTIES), vec![KEY_PHARAOH]);
+        assert!(sibling_games("warhammer_3").is_empty());
+    }
+
+    #[test]
+    fn same_pack_names_map_directly() {
+        let source = game_config(&[("shared.pack", "Shared Mod")]);
+        let target = game_config(&[("shared.pack", "Shared Mod, Dynasties Edition")]);
+
+        let mappings = mod_mappings(&source, &HashMap::new(), &target);
+        assert_eq!(mappings.len(), 1);
+        assert_eq!(mappings[0].best().as_deref(), Some("shared.pack"));
+        assert_eq!(mappings[0].candidates(), &vec![("shared.pack".to_owned(), 1.0)]);
+    }
+
+    #[test]
+    fn other_mods_map_by_name() {
+        let source = game_config(&[("pharaoh_units.pack", "Better Units"), ("unknown.pack", "Something Else Entirely")]);
+        let target = game_config(&[("dynasties_units.pack", "Better Units")]);
+
+        let mappings = mod_mappings(&source, &HashMap::new(), &target);
+        assert_eq!(mapping(&mappings, "pharaoh_units.pack").best().as_deref(), Some("dynasties_units.pack"));
+        assert!(mapping(&mappings, "unknown.pack").best().is_none());
+    }
+
+    #[test]
+    fn profile_mods_are_mapped_once() {
+        let source = game_config(&[("a.pack", "Mod A")]);
+        let target = game_config(&[("a.pack", "Mod A")]);
+
+        let mut load_order = LoadOrder::default();
+        load_order.set_mods(vec!["a.pack".to_owned(), "b.pack".to_owned()]);
+
+        let mut profile = Profile::default();
+        profile.set_load_order(load_order);
+
+        let profiles = HashMap::from([("profile".to_owned(), profile)]);
+        let mappings = mod_mappings(&source, &profiles, &target);
+        assert_eq!(mappings.iter().map(|mapping| mapping.source_id().as_str()).collect::<Vec<_>>(), vec!["a.pack", "b.pack"]);
+
+        // Mods without a config in the source game are matched by their pack name.
+        assert_eq!(mapping(&mappings, "b.pack").source_name(), "b");
+    }
+
+    #[test]
+    fn migrated_profiles_keep_their_settings() {
+        let mut load_order = LoadOrder::default();
+        load_order.set_automatic(true);
+        load_order.set_mods(vec!["a.pack".to_owned(), "unmapped.pack".to_owned()]);
+
+        let mut profile = Profile::default();
+        profile.set_id("profile".to_owned());
+        profile.set_game(KEY_PHARAOH.to_owned());
+        profile.set_load_order(load_order);
+        profile.set_read_only(true);
+        profile.set_env_vars("VAR=1".to_owned());
+        profile.set_mct_preset(Some("preset".to_owned()));
+        profile.set_locked(true);
+
+        let source_profiles = HashMap::from([("profile".to_owned(), profile)]);
+        let mapping = HashMap::from([("a.pack".to_owned(), "b.pack".to_owned())]);
+        let migrated = migrate_profiles(&source_profiles, &HashMap::new(), KEY_PHARAOH_DYNASTIES, &mapping);
+
+        assert_eq!(migrated.len(), 1);
+        assert_eq!(migrated[0].game(), KEY_PHARAOH_DYNASTIES);
+        assert_eq!(migrated[0].load_order().mods(), &vec!["b.pack".to_owned()]);
+        assert!(*migrated[0].load_order().automatic());
+        assert!(*migrated[0].read_only());
+        assert_eq!(migrated[0].env_vars(), "VAR=1");
+        assert!(migrated[0].mct_preset().is_none());
+        assert!(!*migrated[0].locked());
+    }
+}
@@ -0,0 +1,149 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the per-game quirks database.
+//!
+//! Each game has its own way of reading the list of mods to load: where the list goes, its encoding, which commands it supports...
+//! Instead of guessing these from the game's db version, we keep them in a list that can be updated from Runcher's repo.
+
+use anyhow::Result;
+use getset::*;
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use rpfm_lib::games::{GameInfo, supported_games::KEY_EMPIRE};
+
+use super::launch::{USER_SCRIPT_EMPIRE_FILE_NAME, USER_SCRIPT_FILE_NAME};
+use super::remote_lists;
+
+const GAME_QUIRKS_FILE_NAME: &str = "game_quirks.json";
+
+/// Quirks shipped with the program, used if we don't have a downloaded list.
+const GAME_QUIRKS_DEFAULT: &str = include_str!("../../../../game_quirks.json");
+
+lazy_static::lazy_static! {
+
+    /// Quirks in use, so we don't read the list every time we need to check a quirk. Reloaded when the list is updated.
+    static ref GAME_QUIRKS: RwLock<GameQuirks> = RwLock::new(GameQuirks::load());
+}
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct GameQuirks {
+
+    /// Quirks of each game, by game key.
+    games: HashMap<String, GameQuirk>,
+}
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct GameQuirk {
+
+    /// Name of the user script the game reads the mod list from, in its scripts folder. If none, the game reads our custom mod list file.
+    user_script: Option<String>,
+
+    /// If the mod list has to be encoded in UTF-16 LE.
+    utf16_mod_list: bool,
+
+    /// If the game supports loading packs from outside /data through `add_working_directory`.
+    supports_working_directories: bool,
+
+    /// If the game only loads working directories in the same drive as the game. Packs in a secondary folder on other drive need to be staged in /data.
+    #[serde(default)]
+    working_directories_same_drive_only: bool,
+
+    /// If disabled movie packs in the secondary folder can be hidden from the game with empty packs in a masks folder loaded before it.
+    #[serde(default = "default_supports_masks")]
+    supports_masks: bool,
+
+    /// If the game has a launcher we need to bypass by passing it the mod list file on launch.
+    needs_launcher_bypass: bool,
+
+    /// If the game needs the alternative name for the reserved pack, due to its different load order logic for movie packs.
+    alternative_reserved_pack_name: bool,
+
+    /// If the reserved pack needs the real load order as dependencies. Older games crash with them, newer ones crash without them.
+    real_pack_dependencies: bool,
+
+    /// Key of the game in the mod data of CA's launcher, if the game uses it and we know the key.
+    #[serde(default)]
+    ca_launcher_key: Option<String>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl GameQuirks {
+
+    /// This function loads the downloaded list of quirks, falling back to the one shipped with the program if it fails.
+    pub fn load() -> Self {
+        remote_lists::load(GAME_QUIRKS_FILE_NAME, Some(GAME_QUIRKS_DEFAULT))
+    }
+
+    /// This function returns if the downloaded list of quirks is missing or outdated.
+    pub fn needs_update() -> bool {
+        remote_lists::needs_update(GAME_QUIRKS_FILE_NAME)
+    }
+
+    /// This function downloads the latest list of quirks. The list is only saved if it's valid.
+    pub fn update() -> Result<()> {
+        remote_lists::update::<Self>(GAME_QUIRKS_FILE_NAME)?;
+        *GAME_QUIRKS.write().unwrap() = Self::load();
+        Ok(())
+    }
+
+    /// This function returns the quirks of the provided game.
+    ///
+    /// Games missing from the list get their quirks guessed from their db version, which is what we did before having this list.
+    pub fn game(&self, game: &GameInfo) -> GameQuirk {
+        match self.games.get(game.key()) {
+            Some(quirk) => quirk.clone(),
+            None => {
+                let raw_db_version = *game.raw_db_version();
+                let user_script = if raw_db_version >= 1 {
+                    None
+                } else if game.key() == KEY_EMPIRE {
+                    Some(USER_SCRIPT_EMPIRE_FILE_NAME.to_owned())
+                } else {
+                    Some(USER_SCRIPT_FILE_NAME.to_owned())
+                };
+
+                GameQuirk {
+                    user_script,
+                    utf16_mod_list: raw_db_version < 2,
+                    supports_working_directories: raw_db_version >= 1,
+                    working_directories_same_drive_only: raw_db_version < 2,
+                    supports_masks: raw_db_version >= 1,
+                    needs_launcher_bypass: raw_db_version >= 1,
+                    alternative_reserved_pack_name: false,
+                    real_pack_dependencies: raw_db_version >= 2,
+                    ca_launcher_key: None,
+                }
+            }
+        }
+    }
+}
+
+/// This function returns the quirks of the provided game.
+pub fn game_quirks(game: &GameInfo) -> GameQuirk {
+    GAME_QUIRKS.read().unwrap().game(game)
+}
+
+/// Lists downloaded before masks were a quirk were written for games that all used them if they supported working directories.
+fn default_supports_masks() -> bool {
+    true
+}
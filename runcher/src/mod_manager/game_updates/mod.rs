@@ -0,0 +1,53 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the detection of game updates.
+//!
+//! Game updates usually break the script mods that haven't been updated for them. We remember the build of the game on each launch so,
+//! when it changes, we can point out the enabled script mods not updated since the game was.
+
+use std::path::Path;
+
+use rpfm_lib::games::GameInfo;
+
+use crate::settings_ui::last_game_update_date;
+
+use super::conflicts::has_scripts;
+use super::game_config::GameConfig;
+use super::integrations::game_build_id;
+use super::load_order::LoadOrder;
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function returns an identifier of the installed version of the game.
+///
+/// We use the build id from Steam if we can. If not, the version number of the game, and if the game doesn't have one, the date of its executable.
+pub fn game_build(game: &GameInfo, game_path: &Path) -> Option<String> {
+    game_build_id(game, game_path)
+        .or_else(|| game.game_version_number(game_path).map(|version| version.to_string()))
+        .or_else(|| last_game_update_date(game, game_path).ok().filter(|date| *date > 0).map(|date| date.to_string()))
+}
+
+/// This function returns the date of the last update of the game, if we can get it.
+pub fn game_patch_date(game: &GameInfo, game_path: &Path) -> Option<u64> {
+    last_game_update_date(game, game_path).ok()
+        .filter(|date| *date > 0)
+}
+
+/// This function returns the enabled mods with scripts that haven't been updated since the provided date, in load order.
+pub fn outdated_script_mods(game_config: &GameConfig, load_order: &LoadOrder, patch_date: u64) -> Vec<String> {
+    load_order.mods().iter()
+        .filter(|mod_id| game_config.mods().get(*mod_id).map(|modd| modd.outdated(patch_date)).unwrap_or(false))
+        .filter(|mod_id| load_order.packs().get(*mod_id).map(has_scripts).unwrap_or(false))
+        .cloned()
+        .collect()
+}
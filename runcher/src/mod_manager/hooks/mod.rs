@@ -0,0 +1,251 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the launch hooks.
+//!
+//! Hooks are external commands run before launching a game or after it's closed, like starting an injector,
+//! syncing saves or closing overlays. Each hook can be limited to some games and profiles.
+
+use anyhow::{anyhow, Result};
+use getset::*;
+use serde::{Deserialize, Serialize};
+use serde_json::to_string_pretty;
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+#[cfg(target_os = "windows")] use std::os::windows::process::CommandExt;
+use std::thread::{sleep, spawn};
+use std::time::{Duration, Instant};
+
+use rpfm_lib::games::GameInfo;
+use rpfm_lib::integrations::log::*;
+
+use rpfm_ui_common::settings::config_path;
+
+#[cfg(target_os = "windows")] use super::integrations::CREATE_NO_WINDOW;
+use super::launch::launch_cancelled;
+
+const HOOKS_FILE_NAME: &str = "hooks.json";
+
+/// Interval at which we check if a running hook has finished.
+const HOOK_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct Hooks {
+    hooks: Vec<Hook>,
+}
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct Hook {
+
+    /// Name of the hook, for the logs and error messages.
+    name: String,
+
+    /// When to run the hook.
+    stage: HookStage,
+
+    /// Command to run. Placeholders are replaced before running it.
+    command: String,
+
+    /// Keys of the games this hook runs for. Empty means all games.
+    games: Vec<String>,
+
+    /// Profiles this hook runs for. Empty means all profiles, including no profile.
+    profiles: Vec<String>,
+
+    /// Seconds to wait for the hook to finish before killing it. 0 means we don't wait for it, for hooks that keep running with the game.
+    /// These are detached, so they're not killed when the launch is cancelled.
+    timeout: u64,
+
+    /// What to do if the hook fails or times out.
+    failure_policy: HookFailurePolicy,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HookStage {
+    #[default]
+    PreLaunch,
+    PostExit,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HookFailurePolicy {
+
+    /// Failures are only logged.
+    Ignore,
+
+    /// Failures are reported, but the launch continues.
+    #[default]
+    Warn,
+
+    /// Failures stop the launch. For post-exit hooks, this also stops the hooks after the failed one.
+    Abort,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl Hooks {
+
+    /// This function loads the hooks from disk. If there's no hooks file, this returns an empty list.
+    pub fn load() -> Result<Self> {
+        let path = hooks_path()?;
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+
+        let file = BufReader::new(File::open(path)?);
+        serde_json::from_reader(file).map_err(From::from)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let mut file = BufWriter::new(File::create(hooks_path()?)?);
+        file.write_all(to_string_pretty(&self)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function returns the hooks of the provided stage that apply to the provided game and profile, in the order they run.
+    pub fn hooks_for(&self, stage: HookStage, game: &GameInfo, profile: &str) -> Vec<&Hook> {
+        self.hooks.iter()
+            .filter(|hook| hook.stage == stage)
+            .filter(|hook| hook.games.is_empty() || hook.games.iter().any(|key| key == game.key()))
+            .filter(|hook| hook.profiles.is_empty() || hook.profiles.iter().any(|name| name == profile))
+            .collect()
+    }
+
+    /// This function runs the hooks of the provided stage that apply to the provided game and profile, one after another.
+    ///
+    /// Returns the failures of hooks that should be reported. If a hook with the abort policy fails, this returns an error instead.
+    ///
+    /// Placeholder values are passed to the shell as environment variables, and the placeholders are replaced with quoted references to them,
+    /// so values with spaces or shell characters (like a profile called `a; rm -rf ~`) cannot break or inject anything into the command.
+    pub fn run(&self, stage: HookStage, game: &GameInfo, game_path: &Path, profile: &str) -> Result<Vec<String>> {
+        let data_path = game.data_path(game_path)?;
+        let placeholders = [
+            ("{game_key}", "RUNCHER_GAME_KEY", game.key().to_owned()),
+            ("{game_path}", "RUNCHER_GAME_PATH", game_path.to_string_lossy().to_string()),
+            ("{data_path}", "RUNCHER_DATA_PATH", data_path.to_string_lossy().to_string()),
+            ("{profile}", "RUNCHER_PROFILE", profile.to_owned()),
+            ("{config_path}", "RUNCHER_CONFIG_PATH", config_path()?.to_string_lossy().to_string()),
+        ];
+
+        let mut failures = vec![];
+        for hook in self.hooks_for(stage, game, profile) {
+            let command = placeholders.iter().fold(hook.command.to_owned(), |command, (placeholder, var, _)| command.replace(placeholder, &quoted_env_var(var)));
+            let vars = placeholders.iter().map(|(_, var, value)| (*var, value.as_str())).collect::<Vec<_>>();
+
+            info!("Running hook {}: {}", hook.name, command);
+            if let Err(error) = hook.execute(&command, &vars) {
+                let message = format!("{}: {}", hook.name, error);
+                match hook.failure_policy {
+                    HookFailurePolicy::Ignore => warn!("Hook failed: {}", message),
+                    HookFailurePolicy::Warn => failures.push(message),
+                    HookFailurePolicy::Abort => return Err(anyhow!(message)),
+                }
+            }
+        }
+
+        Ok(failures)
+    }
+}
+
+impl Hook {
+
+    /// This function runs the provided command, waiting for it to finish if the hook has a timeout.
+    ///
+    /// Pre-launch hooks still running when the launch is cancelled are killed.
+    fn execute(&self, command_line: &str, vars: &[(&str, &str)]) -> Result<()> {
+
+        // Cmd has no way to escape quotes inside quotes, so values with them would break out of the quoted reference.
+        if cfg!(target_os = "windows") {
+            if let Some((var, _)) = vars.iter().find(|(_, value)| value.contains('"')) {
+                return Err(anyhow!("The value of {} contains quotes, which cannot be passed safely to the command.", var));
+            }
+        }
+
+        let mut child = system_command(command_line).envs(vars.iter().copied()).spawn()?;
+
+        // Hooks without timeout are detached: a thread waits for them so they don't linger as zombies once they finish.
+        if self.timeout == 0 {
+            let name = self.name.to_owned();
+            spawn(move || match child.wait() {
+                Ok(status) => info!("Detached hook {} finished with {}.", name, status),
+                Err(error) => warn!("Error waiting for detached hook {}: {}", name, error),
+            });
+
+            return Ok(());
+        }
+
+        let start = Instant::now();
+        let timeout = Duration::from_secs(self.timeout);
+        loop {
+            if let Some(status) = child.try_wait()? {
+                return if status.success() {
+                    Ok(())
+                } else {
+                    Err(anyhow!("The command failed with {}.", status))
+                };
+            }
+
+            if self.stage == HookStage::PreLaunch && launch_cancelled() {
+                let _ = child.kill();
+                return Err(anyhow!("The launch was cancelled."));
+            }
+
+            if start.elapsed() > timeout {
+                let _ = child.kill();
+                return Err(anyhow!("The command didn't finish in {} seconds.", self.timeout));
+            }
+
+            sleep(HOOK_POLL_INTERVAL);
+        }
+    }
+}
+
+/// This function builds a command that runs the provided command line through the system's shell, so users can use redirections and such.
+fn system_command(command_line: &str) -> Command {
+    #[cfg(target_os = "windows")] {
+        let mut command = Command::new("cmd");
+        command.arg("/C");
+        command.raw_arg(command_line);
+        command.creation_flags(CREATE_NO_WINDOW);
+        command
+    }
+
+    #[cfg(not(target_os = "windows"))] {
+        let mut command = Command::new("sh");
+        command.arg("-c");
+        command.arg(command_line);
+        command
+    }
+}
+
+/// This function returns a reference to the provided environment variable, quoted for the system's shell.
+fn quoted_env_var(var: &str) -> String {
+    if cfg!(target_os = "windows") {
+        format!("\"%{var}%\"")
+    } else {
+        format!("\"${var}\"")
+    }
+}
+
+/// This function returns the path of the hooks file.
+pub fn hooks_path() -> Result<PathBuf> {
+    Ok(config_path()?.join(HOOKS_FILE_NAME))
+}
@@ -0,0 +1,255 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the installation of mods distributed outside the Workshop.
+//!
+//! These mods usually come as loose packs, or as zips with the packs inside, sometimes alongside their image.
+//! Installing them means copying the packs to /data or the secondary folder, and registering them in the game config.
+
+use anyhow::{anyhow, Result};
+use zip::ZipArchive;
+
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+use std::fs::{copy, remove_file, DirBuilder, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use rpfm_lib::games::GameInfo;
+
+use super::game_config::{GameConfig, DEFAULT_CATEGORY};
+use super::mods::Mod;
+
+const PACK_EXTENSION: &str = "pack";
+const ZIP_EXTENSION: &str = "zip";
+const IMAGE_EXTENSION: &str = "png";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// Where the file to install comes from.
+enum InstallSource {
+
+    /// A loose file.
+    File(PathBuf),
+
+    /// An entry of a zip, by zip path and entry name.
+    ZipEntry(PathBuf, String),
+}
+
+/// A pack to install, with its image if it has one.
+struct PlannedPack {
+    source: InstallSource,
+    image: Option<InstallSource>,
+    dest: PathBuf,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl PlannedPack {
+
+    /// This function returns if the pack is already where it would be installed. Copying a file over itself empties it, so these are not copied.
+    fn is_in_place(&self) -> bool {
+        match self.source {
+            InstallSource::File(ref path) => self.dest.canonicalize().ok().is_some_and(|dest| path.canonicalize().ok() == Some(dest)),
+            InstallSource::ZipEntry(..) => false,
+        }
+    }
+}
+
+/// This function installs the provided packs, or the packs inside the provided zips, in the provided folder.
+///
+/// The installed packs are registered in the game config, in the provided category. Returns the ids of the installed mods.
+/// Packs with the same name as a vanilla pack are rejected, so a broken zip cannot overwrite the game's files. So are packs with the same
+/// name as a file already in the folder, as we don't want to replace a mod without the user knowing. If anything fails, the files already copied are removed.
+pub fn install_packs(game: &GameInfo, game_path: &Path, game_config: &mut GameConfig, paths: &[PathBuf], folder: &Path, category: &str) -> Result<Vec<String>> {
+    DirBuilder::new().recursive(true).create(folder)?;
+
+    let vanilla_names = vanilla_pack_names(game, game_path)?;
+    let planned = plan_install(paths, folder, &vanilla_names)?;
+
+    // The game config is only updated if everything went right.
+    let mut written = vec![];
+    let mut new_game_config = game_config.clone();
+    if let Err(error) = copy_planned_packs(&planned, &mut written).and_then(|_| register_packs(game, &mut new_game_config, &planned, category)) {
+        for path in &written {
+            let _ = remove_file(path);
+        }
+
+        return Err(error);
+    }
+
+    *game_config = new_game_config;
+
+    Ok(planned.iter()
+        .filter_map(|pack| pack.dest.file_name())
+        .map(|name| name.to_string_lossy().to_string())
+        .collect())
+}
+
+/// This function returns the names of the vanilla packs of the provided game, in lowercase, so packs being installed can be checked against them.
+pub fn vanilla_pack_names(game: &GameInfo, game_path: &Path) -> Result<HashSet<String>> {
+    Ok(game.ca_packs_paths(game_path)?
+        .iter()
+        .filter_map(|path| path.file_name())
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .collect())
+}
+
+/// This function returns what needs to be copied where to install the provided packs and zips, without touching the disk.
+///
+/// Fails if any of the packs cannot be installed, listing all the name collisions at once.
+fn plan_install(paths: &[PathBuf], folder: &Path, vanilla_names: &HashSet<String>) -> Result<Vec<PlannedPack>> {
+    let mut planned = vec![];
+    for path in paths {
+        let extension = path.extension().map(|extension| extension.to_string_lossy().to_lowercase()).unwrap_or_default();
+        if extension == PACK_EXTENSION {
+            let file_name = path.file_name().ok_or_else(|| anyhow!("Invalid pack path: {}.", path.to_string_lossy()))?.to_string_lossy().to_string();
+            let image_path = path.with_extension(IMAGE_EXTENSION);
+            planned.push(PlannedPack {
+                dest: folder.join(file_name),
+                source: InstallSource::File(path.to_path_buf()),
+                image: if image_path.is_file() { Some(InstallSource::File(image_path)) } else { None },
+            });
+        } else if extension == ZIP_EXTENSION {
+            let zip = ZipArchive::new(BufReader::new(File::open(path)?))?;
+            let entries = zip.file_names()
+                .map(|name| name.to_owned())
+                .collect::<Vec<_>>();
+
+            let pack_entries = entries.iter()
+                .filter(|name| Path::new(name).extension().is_some_and(|extension| extension.to_string_lossy().to_lowercase() == PACK_EXTENSION))
+                .collect::<Vec<_>>();
+
+            if pack_entries.is_empty() {
+                return Err(anyhow!("{} doesn't contain any pack.", path.to_string_lossy()));
+            }
+
+            for entry in pack_entries {
+
+                // Only use the file name, so broken or malicious zips cannot write outside the folder. Folders inside the zip are ignored.
+                let file_name = Path::new(entry).file_name().ok_or_else(|| anyhow!("Invalid file name in zip: {}.", entry))?.to_string_lossy().to_string();
+                let image_entry = Path::new(entry).with_extension(IMAGE_EXTENSION).to_string_lossy().replace('\\', "/");
+                planned.push(PlannedPack {
+                    dest: folder.join(file_name),
+                    source: InstallSource::ZipEntry(path.to_path_buf(), entry.to_owned()),
+                    image: if entries.contains(&image_entry) { Some(InstallSource::ZipEntry(path.to_path_buf(), image_entry)) } else { None },
+                });
+            }
+        } else {
+            return Err(anyhow!("{} is not a pack or a zip.", path.to_string_lossy()));
+        }
+    }
+
+    let mut names = HashSet::new();
+    let mut collisions = vec![];
+    for pack in &planned {
+        let file_name = pack.dest.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        if vanilla_names.contains(&file_name.to_lowercase()) {
+            return Err(anyhow!("{} has the same name as a vanilla pack.", file_name));
+        }
+
+        if !names.insert(file_name.to_lowercase()) {
+            return Err(anyhow!("More than one of the packs to install is called {}.", file_name));
+        }
+
+        if pack.dest.exists() && !pack.is_in_place() {
+            collisions.push(file_name);
+        }
+    }
+
+    if collisions.is_empty() {
+        Ok(planned)
+    } else {
+        Err(anyhow!("The following packs are already installed in {}: {}. Remove them first if you want to replace them.", folder.to_string_lossy(), collisions.join(", ")))
+    }
+}
+
+/// This function copies the planned packs and their images to their destination, keeping track of each file written so they can be removed if something fails.
+fn copy_planned_packs(planned: &[PlannedPack], written: &mut Vec<PathBuf>) -> Result<()> {
+    let mut zips: HashMap<PathBuf, ZipArchive<BufReader<File>>> = HashMap::new();
+    for pack in planned {
+
+        // Packs already in the folder are left as they are.
+        if pack.is_in_place() {
+            continue;
+        }
+
+        let mut files = vec![(&pack.source, pack.dest.to_path_buf())];
+        if let Some(ref image) = pack.image {
+            files.push((image, pack.dest.with_extension(IMAGE_EXTENSION)));
+        }
+
+        for (source, dest) in files {
+
+            // Packs cannot exist already, but images can. Those are overwritten, but not removed if something fails.
+            if !dest.exists() {
+                written.push(dest.to_path_buf());
+            }
+
+            match source {
+                InstallSource::File(path) => { copy(path, &dest)?; },
+                InstallSource::ZipEntry(path, entry) => {
+                    let zip = match zips.entry(path.to_path_buf()) {
+                        Entry::Occupied(zip) => zip.into_mut(),
+                        Entry::Vacant(zip) => zip.insert(ZipArchive::new(BufReader::new(File::open(path)?))?),
+                    };
+
+                    extract_entry(zip, entry, &dest)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// This function registers the installed packs in the game config, moving them to the provided category, and saves it.
+fn register_packs(game: &GameInfo, game_config: &mut GameConfig, planned: &[PlannedPack], category: &str) -> Result<()> {
+    for pack in planned {
+        let pack_path = &pack.dest;
+        let mod_id = pack_path.file_name().unwrap().to_string_lossy().to_string();
+        match game_config.mods_mut().get_mut(&mod_id) {
+            Some(modd) => if !modd.paths().contains(pack_path) {
+                modd.paths_mut().insert(0, pack_path.to_path_buf());
+            },
+            None => {
+                let mut modd = Mod::default();
+                modd.set_id(mod_id.to_owned());
+                modd.set_name(mod_id.to_owned());
+                modd.set_paths(vec![pack_path.to_path_buf()]);
+                game_config.mods_mut().insert(mod_id.to_owned(), modd);
+            }
+        }
+
+        // New mods end up in the default category on the next scan, so we only need to move them if we got another one.
+        if category != DEFAULT_CATEGORY {
+            if !game_config.categories().contains_key(category) {
+                game_config.create_category(category);
+            }
+
+            game_config.categories_mut().values_mut().for_each(|mods| mods.retain(|id| id != &mod_id));
+            if let Some(mods) = game_config.categories_mut().get_mut(category) {
+                mods.push(mod_id.to_owned());
+            }
+        }
+    }
+
+    game_config.save(game)
+}
+
+fn extract_entry(zip: &mut ZipArchive<BufReader<File>>, entry: &str, path: &Path) -> Result<()> {
+    let mut file = BufWriter::new(File::create(path)?);
+    std::io::copy(&mut zip.by_name(entry)?, &mut file)?;
+    file.flush()?;
+    Ok(())
+}
@@ -0,0 +1,211 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Integration with GitHub releases, for mods (usually tools or script frameworks) distributed there instead of the workshop.
+
+use anyhow::{anyhow, Result};
+use reqwest::blocking::Client;
+use reqwest::header::ACCEPT;
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde_json::from_str;
+
+use std::collections::{HashMap, HashSet};
+use std::fs::{remove_file, rename, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use super::GitHubSource;
+
+const GITHUB_URL: &str = "https://github.com/";
+const GITHUB_API_LATEST_RELEASE: &str = "https://api.github.com/repos/{repo}/releases/latest";
+const GITHUB_USER_AGENT: &str = "Runcher";
+
+const PACK_EXTENSION: &str = ".pack";
+const DOWNLOAD_TEMP_EXTENSION: &str = ".part";
+
+/// Unauthenticated requests to GitHub's api are limited to 60 per hour, so we don't ask again for a repo we checked recently.
+const RELEASE_CACHE_DURATION: Duration = Duration::from_secs(60 * 60);
+
+lazy_static::lazy_static! {
+
+    /// Latest release tags we know of, by repo, with the time we checked them.
+    static ref LATEST_RELEASES: RwLock<HashMap<String, (String, Instant)>> = RwLock::new(HashMap::new());
+}
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    assets: Vec<Asset>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct Asset {
+    name: String,
+    browser_download_url: String,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function parses a GitHub url, returning the repo (owner/name) and, if the url points to a release asset, the asset name.
+///
+/// Accepted formats are `owner/name`, the repo url, any url within the repo, and release asset urls.
+pub fn parse_url(url: &str) -> Result<(String, Option<String>)> {
+    let url = url.trim();
+    let path = url.strip_prefix(GITHUB_URL)
+        .or_else(|| url.strip_prefix("http://github.com/"))
+        .or_else(|| url.strip_prefix("github.com/"))
+        .unwrap_or(url);
+
+    let parts = path.split('/').filter(|part| !part.is_empty()).collect::<Vec<_>>();
+    if parts.len() < 2 || parts[0].contains(':') {
+        return Err(anyhow!("Invalid GitHub url: {}.", url));
+    }
+
+    let repo = format!("{}/{}", parts[0], parts[1].trim_end_matches(".git"));
+
+    // Asset urls are in the format owner/name/releases/download/tag/asset.
+    let asset = if parts.len() == 6 && parts[2] == "releases" && parts[3] == "download" {
+        Some(parts[5].to_owned())
+    } else {
+        None
+    };
+
+    Ok((repo, asset))
+}
+
+/// This function downloads the provided asset (or the first pack, if no asset is provided) of the latest release of a repo
+/// into the provided folder, returning the source info of the downloaded mod.
+///
+/// Only packs are downloaded, and packs with the same name as a vanilla pack are rejected, so a release cannot overwrite the game's files.
+pub fn download_mod(repo: &str, asset: Option<&str>, folder: &Path, vanilla_names: &HashSet<String>) -> Result<GitHubSource> {
+    let release = latest_release(repo)?;
+    let asset = match asset {
+        Some(name) => release.assets.iter().find(|x| x.name == name),
+        None => release.assets.iter().find(|x| x.name.to_lowercase().ends_with(PACK_EXTENSION)),
+    }.ok_or_else(|| anyhow!("No pack found in the latest release ({}) of {}.", release.tag_name, repo))?;
+
+    // Asset names come from the server, so make sure they're just a pack name before using them as a path.
+    let file_name = Path::new(&asset.name).file_name().map(|name| name.to_string_lossy().to_string());
+    if file_name.as_deref() != Some(asset.name.as_str()) || !asset.name.to_lowercase().ends_with(PACK_EXTENSION) {
+        return Err(anyhow!("{} is not a pack.", asset.name));
+    }
+
+    if vanilla_names.contains(&asset.name.to_lowercase()) {
+        return Err(anyhow!("{} has the same name as a vanilla pack.", asset.name));
+    }
+
+    let data = client()?
+        .get(&asset.browser_download_url)
+        .header(ACCEPT, "application/octet-stream")
+        .send()?
+        .error_for_status()?
+        .bytes()?;
+
+    // Write to a temp file first, so a failed download doesn't leave a broken pack where the old one was.
+    let path = folder.join(&asset.name);
+    let temp_path = folder.join(format!("{}{}", asset.name, DOWNLOAD_TEMP_EXTENSION));
+    let result = File::create(&temp_path)
+        .map(BufWriter::new)
+        .and_then(|mut file| file.write_all(&data).and_then(|_| file.flush()))
+        .and_then(|_| rename(&temp_path, &path));
+
+    if let Err(error) = result {
+        let _ = remove_file(&temp_path);
+        return Err(error.into());
+    }
+
+    Ok(GitHubSource {
+        repo: repo.to_owned(),
+        asset: asset.name.to_owned(),
+        version: release.tag_name.to_owned(),
+        latest_version: release.tag_name,
+    })
+}
+
+/// This function returns the provided sources with their latest version updated. Sources that fail to be checked are returned unchanged.
+///
+/// Each repo is checked only once, repos checked in the last hour are not checked again, and we stop asking once GitHub rate-limits us.
+pub fn check_updates(sources: &[GitHubSource]) -> Vec<GitHubSource> {
+    let mut rate_limited = false;
+    let mut latest_versions: HashMap<String, Option<String>> = HashMap::new();
+    for source in sources {
+        if latest_versions.contains_key(&source.repo) {
+            continue;
+        }
+
+        let cached = LATEST_RELEASES.read().unwrap()
+            .get(&source.repo)
+            .filter(|(_, checked)| checked.elapsed() < RELEASE_CACHE_DURATION)
+            .map(|(tag, _)| tag.to_owned());
+
+        let latest_version = match cached {
+            Some(tag) => Some(tag),
+            None if rate_limited => None,
+            None => match latest_release(&source.repo) {
+                Ok(release) => Some(release.tag_name),
+                Err(error) => {
+                    rate_limited = is_rate_limit_error(&error);
+                    None
+                }
+            }
+        };
+
+        latest_versions.insert(source.repo.to_owned(), latest_version);
+    }
+
+    sources.iter()
+        .map(|source| {
+            let mut source = source.clone();
+            if let Some(Some(latest_version)) = latest_versions.get(&source.repo) {
+                source.latest_version = latest_version.to_owned();
+            }
+            source
+        })
+        .collect()
+}
+
+/// This function returns the latest release of a repo.
+fn latest_release(repo: &str) -> Result<Release> {
+    let data = client()?
+        .get(GITHUB_API_LATEST_RELEASE.replace("{repo}", repo))
+        .header(ACCEPT, "application/vnd.github+json")
+        .send()?
+        .error_for_status()?
+        .text()?;
+
+    let release: Release = from_str(&data)?;
+    LATEST_RELEASES.write().unwrap().insert(repo.to_owned(), (release.tag_name.to_owned(), Instant::now()));
+    Ok(release)
+}
+
+/// GitHub answers with a 403 or a 429 once we go over the rate limit.
+fn is_rate_limit_error(error: &anyhow::Error) -> bool {
+    error.downcast_ref::<reqwest::Error>()
+        .and_then(|error| error.status())
+        .map(|status| status == StatusCode::FORBIDDEN || status == StatusCode::TOO_MANY_REQUESTS)
+        .unwrap_or(false)
+}
+
+/// GitHub's api rejects requests without user agent.
+fn client() -> Result<Client> {
+    Client::builder()
+        .user_agent(GITHUB_USER_AGENT)
+        .build()
+        .map_err(From::from)
+}
@@ -10,29 +10,36 @@
 
 //! Online integrations. The intention is so this module acts as a common abstraction of specific integrations.
 //!
-//! For now we only support steam workshop, so all calls are redirected to the steam module.
+//! Most calls are redirected to the steam module, as the workshop is where most mods come from.
+//! Mods distributed through GitHub releases are handled by the github module.
 
 use anyhow::Result;
-use serde::Deserialize;
+use getset::*;
+use serde::{Deserialize, Serialize};
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::Path;
 
+use rpfm_lib::files::{Container, pack::Pack};
 use rpfm_lib::games::GameInfo;
 
 use crate::mod_manager::mods::Mod;
 
+mod github;
 mod steam;
 
-#[cfg(target_os = "windows")] const CREATE_NO_WINDOW: u32 = 0x08000000;
+#[cfg(target_os = "windows")] pub(crate) const CREATE_NO_WINDOW: u32 = 0x08000000;
 #[cfg(target_os = "windows")] const DETACHED_PROCESS: u32 = 0x00000008;
 #[cfg(target_os = "windows")] const CREATE_NEW_CONSOLE: u32 = 0x00000010;
 
+/// Folder where Shogun 2 keeps its battle maps.
+const MAP_PRESETS_PATH: &str = "battleterrain/presets/";
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
 
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PublishedFileVisibilityDerive {
     Public,
     FriendsOnly,
@@ -40,6 +47,36 @@ pub enum PublishedFileVisibilityDerive {
     Unlisted,
 }
 
+/// Download/update state of a Workshop item in the user's Steam client.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WorkshopItemState {
+    #[default]
+    Unknown,
+    UpToDate,
+    UpdateQueued,
+    Downloading,
+    DownloadPending,
+    NotInstalled,
+}
+
+/// Source of a mod downloaded from a GitHub release.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct GitHubSource {
+
+    /// Repo the mod is downloaded from, in the owner/name format.
+    repo: String,
+
+    /// Name of the release asset to download.
+    asset: String,
+
+    /// Tag of the release we have downloaded.
+    version: String,
+
+    /// Tag of the latest release we know of.
+    latest_version: String,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct PreUploadInfo {
     pub published_file_id: u64,
@@ -53,6 +90,69 @@ pub struct PreUploadInfo {
 //                             Implementations
 //-------------------------------------------------------------------------------//
 
+impl PublishedFileVisibilityDerive {
+
+    /// This function returns the key of the name of the visibility.
+    pub fn locale_key(&self) -> &'static str {
+        match self {
+            Self::Public => "upload_workshop_visibility_public",
+            Self::FriendsOnly => "upload_workshop_visibility_friends_only",
+            Self::Private => "upload_workshop_visibility_private",
+            Self::Unlisted => "upload_workshop_visibility_unlisted",
+        }
+    }
+}
+
+impl WorkshopItemState {
+
+    // Steam's EItemState flags.
+    const FLAG_INSTALLED: u32 = 4;
+    const FLAG_NEEDS_UPDATE: u32 = 8;
+    const FLAG_DOWNLOADING: u32 = 16;
+    const FLAG_DOWNLOAD_PENDING: u32 = 32;
+
+    /// This function turns Steam's item state flags into the state we show. Flags are checked from the most to the least urgent.
+    pub fn from_flags(flags: u32) -> Self {
+        if flags == 0 {
+            Self::Unknown
+        } else if flags & Self::FLAG_DOWNLOADING != 0 {
+            Self::Downloading
+        } else if flags & Self::FLAG_DOWNLOAD_PENDING != 0 {
+            Self::DownloadPending
+        } else if flags & Self::FLAG_NEEDS_UPDATE != 0 {
+            Self::UpdateQueued
+        } else if flags & Self::FLAG_INSTALLED != 0 {
+            Self::UpToDate
+        } else {
+            Self::NotInstalled
+        }
+    }
+
+    /// Returns if Steam may replace the files of the item soon.
+    pub fn update_pending(&self) -> bool {
+        matches!(self, Self::UpdateQueued | Self::Downloading | Self::DownloadPending)
+    }
+
+    pub fn locale_key(&self) -> &'static str {
+        match self {
+            Self::Unknown => "workshop_state_unknown",
+            Self::UpToDate => "workshop_state_up_to_date",
+            Self::UpdateQueued => "workshop_state_update_queued",
+            Self::Downloading => "workshop_state_downloading",
+            Self::DownloadPending => "workshop_state_download_pending",
+            Self::NotInstalled => "workshop_state_not_installed",
+        }
+    }
+}
+
+impl GitHubSource {
+
+    /// Returns if there is a newer release than the one we have downloaded.
+    pub fn update_available(&self) -> bool {
+        !self.latest_version.is_empty() && self.latest_version != self.version
+    }
+}
+
 pub fn request_mods_data(game: &GameInfo, mod_ids: &[String]) -> Result<Vec<Mod>> {
     steam::request_mods_data(game, mod_ids)
 }
@@ -69,18 +169,65 @@ pub fn upload_mod_to_workshop(game: &GameInfo, modd: &Mod, title: &str, descript
     steam::upload_mod_to_workshop(game, modd, title, description, tags, changelog, visibility, force_update)
 }
 
-pub fn launch_game(game: &GameInfo, command_to_pass: &str, wait_for_finish: bool) -> Result<()> {
-    steam::launch_game(game, command_to_pass, wait_for_finish)
+pub fn launch_game(game: &GameInfo, command_to_pass: &str, env_vars: &[(String, String)], wait_for_finish: bool) -> Result<()> {
+    steam::launch_game(game, command_to_pass, env_vars, wait_for_finish)
+}
+
+pub fn launch_game_through_steam(game: &GameInfo, args: &str) -> Result<()> {
+    steam::launch_game_through_steam(game, args)
 }
 
 pub fn download_subscribed_mods(game: &GameInfo, published_file_ids: &Option<Vec<String>>) -> Result<()> {
     steam::download_subscribed_mods(game, published_file_ids)
 }
 
+pub fn search_mods(game: &GameInfo, tags: &[String], page: u32) -> Result<Vec<Mod>> {
+    steam::search_mods(game, tags, page)
+}
+
+pub fn subscribe_mods(game: &GameInfo, published_file_ids: &[String]) -> Result<()> {
+    steam::subscribe_mods(game, published_file_ids)
+}
+
+pub fn force_redownload_mods(game: &GameInfo, published_file_ids: &[String]) -> Result<()> {
+    steam::force_redownload_mods(game, published_file_ids)
+}
+
+pub fn export_map_bin(pack_path: &Path, map_name: &str, output_path: &Path) -> Result<()> {
+    steam::export_map_bin(pack_path, map_name, output_path)
+}
+
+/// This function returns the names of the Shogun 2 battle maps in the provided pack, sorted.
+pub fn map_names(pack_path: &Path) -> Result<Vec<String>> {
+    let pack = Pack::read_and_merge(&[pack_path.to_path_buf()], true, false, false)?;
+    Ok(pack.paths_raw()
+        .iter()
+        .filter_map(|path| path.to_lowercase().strip_prefix(MAP_PRESETS_PATH)?.split_once('/').map(|(name, _)| name.to_owned()))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect())
+}
+
+pub fn parse_github_url(url: &str) -> Result<(String, Option<String>)> {
+    github::parse_url(url)
+}
+
+pub fn download_github_mod(repo: &str, asset: Option<&str>, folder: &Path, vanilla_names: &HashSet<String>) -> Result<GitHubSource> {
+    github::download_mod(repo, asset, folder, vanilla_names)
+}
+
+pub fn check_github_mods_updates(sources: &[GitHubSource]) -> Vec<GitHubSource> {
+    github::check_updates(sources)
+}
+
 pub fn store_user_id(game: &GameInfo) -> Result<u64> {
     steam::user_id(game)
 }
 
+pub fn game_build_id(game: &GameInfo, game_path: &Path) -> Option<String> {
+    steam::build_id(game, game_path).ok().flatten()
+}
+
 pub fn can_game_locked(game: &GameInfo, game_path: &Path) -> bool {
     match steam::can_game_locked(game, game_path) {
         Ok(result) => result,
@@ -10,16 +10,18 @@
 
 use anyhow::{anyhow, Result};
 use base64::prelude::*;
-use interprocess::local_socket::{prelude::*, GenericNamespaced, ListenerOptions};
+use interprocess::local_socket::{prelude::*, GenericNamespaced, Listener, ListenerNonblockingMode, ListenerOptions, Stream};
 use regex::Regex;
 use serde::Deserialize;
 use steam_workshop_api::{client::Workshop, interfaces::i_steam_user::*};
 
 use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufWriter, Read, Write};
+use std::io::{BufWriter, ErrorKind, Read, Write};
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Child, Command};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
 #[cfg(target_os = "windows")]use std::os::windows::process::CommandExt;
 
 use rpfm_lib::files::{EncodeableExtraData, pack::Pack};
@@ -28,10 +30,10 @@ use rpfm_lib::utils::path_to_absolute_string;
 
 use rpfm_ui_common::settings::{setting_path, setting_string};
 
-use crate::mod_manager::mods::Mod;
+use crate::mod_manager::{game_definitions::steam_id, mods::Mod, offline::offline_mode};
 
 #[cfg(target_os = "windows")]use super::{CREATE_NEW_CONSOLE, CREATE_NO_WINDOW, DETACHED_PROCESS};
-use super::{PreUploadInfo, PublishedFileVisibilityDerive};
+use super::{PreUploadInfo, PublishedFileVisibilityDerive, WorkshopItemState};
 
 lazy_static::lazy_static! {
     pub static ref REGEX_URL: Regex = Regex::new(r"(\[url=)(.*)(\])(.*)(\[/url\])").unwrap();
@@ -48,6 +50,12 @@ const WORKSHOPPER_EXE: &str = "workshopper.exe";
 const BAT_UPLOAD_TO_WORKSHOP: &str = "upload-to-workshop.bat";
 const BAT_GET_PUBLISHED_FILE_DETAILS: &str = "get-published-file-details.bat";
 
+/// Time we wait for workshopper to connect to us before giving up, and how often we check if it did.
+const WORKSHOPPER_IPC_TIMEOUT: Duration = Duration::from_secs(60);
+const WORKSHOPPER_IPC_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+const STEAM_RUN_URL: &str = "steam://run/";
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -64,6 +72,9 @@ pub struct QueryResultDerive {
     pub tags: Vec<String>,
     pub file_name: String,
     pub file_size: u32,
+
+    #[serde(default)]
+    pub item_state: u32,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
@@ -128,24 +139,7 @@ pub fn request_mods_data(game: &GameInfo, mod_ids: &[String]) -> Result<Vec<Mod>
     }
 
     let workshop_items = request_mods_data_raw(game, mod_ids)?;
-
-    let mut mods = vec![];
-    for workshop_item in &workshop_items {
-        let mut modd = Mod::default();
-        modd.set_steam_id(Some(workshop_item.published_file_id.to_string()));
-
-        modd.set_name(workshop_item.title.to_owned());
-        modd.set_creator(workshop_item.owner.to_string());
-        modd.set_file_name(workshop_item.file_name.to_owned());
-        modd.set_file_size(workshop_item.file_size as u64);
-        modd.set_description(workshop_item.description.to_owned());
-        modd.set_time_created(workshop_item.time_created as usize);
-        modd.set_time_updated(workshop_item.time_updated as usize);
-
-        mods.push(modd);
-    }
-
-    Ok(mods)
+    Ok(mods_from_workshop_items(&workshop_items))
 }
 
 pub fn request_mods_data_raw(game: &GameInfo, mod_ids: &[String]) -> Result<Vec<QueryResultDerive>> {
@@ -155,8 +149,9 @@ pub fn request_mods_data_raw(game: &GameInfo, mod_ids: &[String]) -> Result<Vec<
         return Ok(vec![])
     }
 
+    check_online()?;
     let game_path = setting_path(game.key());
-    let steam_id = game.steam_id(&game_path)? as u32;
+    let steam_id = steam_id(game, &game_path)? as u32;
     let published_file_ids = mod_ids.join(",");
     let ipc_channel = rand::random::<u64>().to_string();
 
@@ -192,6 +187,106 @@ pub fn request_mods_data_raw(game: &GameInfo, mod_ids: &[String]) -> Result<Vec<
     }
 }
 
+/// This function searches the Workshop of the provided game for mods with all the provided tags.
+///
+/// Results come in pages of up to 50 mods, starting at page 1.
+pub fn search_mods(game: &GameInfo, tags: &[String], page: u32) -> Result<Vec<Mod>> {
+    check_online()?;
+    let game_path = setting_path(game.key());
+    let steam_id = steam_id(game, &game_path)? as u32;
+    let ipc_channel = rand::random::<u64>().to_string();
+
+    // The server has to exist before workshopper tries to connect to it.
+    let channel = ipc_channel.to_ns_name::<GenericNamespaced>()?;
+    let server = ListenerOptions::new().name(channel).nonblocking(ListenerNonblockingMode::Accept).create_sync()?;
+
+    // Tags are typed by the user, so they're passed to workshopper as args, without going through a batch file or cmd, which would interpret them.
+    let mut command = Command::new(&*WORKSHOPPER_PATH);
+    command.arg("search-items");
+    command.arg("-s");
+    command.arg(steam_id.to_string());
+    command.arg("-p");
+    command.arg(page.to_string());
+    command.arg("-i");
+    command.arg(&ipc_channel);
+
+    if !tags.is_empty() {
+        command.arg("-t");
+        command.arg(tags.join(","));
+    }
+
+    // This is for creating the terminal window. Without it, the entire process runs in the background and there's no feedback on when it's done.
+    #[cfg(target_os = "windows")] if cfg!(debug_assertions) {
+        command.creation_flags(DETACHED_PROCESS);
+    } else {
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let mut child = command.spawn()?;
+    let mut stream = accept_from_child(&server, &mut child)?;
+
+    let mut message = String::new();
+    stream.read_to_string(&mut message)?;
+
+    if message == "{}" {
+        Err(anyhow!("Error searching the Steam Workshop."))
+    } else {
+        let workshop_items: Vec<QueryResultDerive> = serde_json::from_str(&message)?;
+        Ok(mods_from_workshop_items(&workshop_items))
+    }
+}
+
+/// This function waits until the provided workshopper process connects to the provided server, which must be non-blocking on accept.
+///
+/// If workshopper closes without connecting, or doesn't connect in time, this returns an error instead of waiting forever.
+fn accept_from_child(server: &Listener, child: &mut Child) -> Result<Stream> {
+    let start = Instant::now();
+    loop {
+        match server.accept() {
+            Ok(stream) => return Ok(stream),
+            Err(error) if error.kind() == ErrorKind::WouldBlock => {
+
+                // It may have connected right before closing, so check one last time before giving up.
+                if child.try_wait()?.is_some() {
+                    return server.accept().map_err(|_| anyhow!("Workshopper closed without sending any data. Check its log for more info."));
+                }
+
+                if start.elapsed() > WORKSHOPPER_IPC_TIMEOUT {
+                    let _ = child.kill();
+                    return Err(anyhow!("Workshopper took too long to answer."));
+                }
+
+                sleep(WORKSHOPPER_IPC_POLL_INTERVAL);
+            }
+            Err(error) => return Err(error.into()),
+        }
+    }
+}
+
+/// This function turns the raw data of Workshop items into mods.
+fn mods_from_workshop_items(workshop_items: &[QueryResultDerive]) -> Vec<Mod> {
+    let mut mods = vec![];
+    for workshop_item in workshop_items {
+        let mut modd = Mod::default();
+        modd.set_steam_id(Some(workshop_item.published_file_id.to_string()));
+
+        modd.set_name(workshop_item.title.to_owned());
+        modd.set_creator(workshop_item.owner.to_string());
+        modd.set_file_name(workshop_item.file_name.to_owned());
+        modd.set_file_size(workshop_item.file_size as u64);
+        modd.set_description(workshop_item.description.to_owned());
+        modd.set_time_created(workshop_item.time_created as usize);
+        modd.set_time_updated(workshop_item.time_updated as usize);
+        modd.set_tags(workshop_item.tags.to_vec());
+        modd.set_workshop_state(WorkshopItemState::from_flags(workshop_item.item_state));
+        modd.set_visibility(Some(workshop_item.visibility));
+
+        mods.push(modd);
+    }
+
+    mods
+}
+
 pub fn request_user_names(user_ids: &[String]) -> Result<HashMap<String, String>> {
 
     // Do not call the cmd if there are no users.
@@ -199,6 +294,7 @@ pub fn request_user_names(user_ids: &[String]) -> Result<HashMap<String, String>
         return Ok(HashMap::new())
     }
 
+    check_online()?;
     let mut client = Workshop::new(None);
     let api_key = setting_string("steam_api_key");
     if !api_key.is_empty() {
@@ -222,6 +318,9 @@ pub fn populate_mods_with_online_data(mods: &mut HashMap<String, Mod>, workshop_
             modd.set_description(workshop_item.description().to_string());
             modd.set_time_created(*workshop_item.time_created());
             modd.set_time_updated(*workshop_item.time_updated());
+            modd.set_tags(workshop_item.tags().to_vec());
+            modd.set_workshop_state(*workshop_item.workshop_state());
+            modd.set_visibility(*workshop_item.visibility());
         }
     }
 
@@ -254,8 +353,9 @@ pub fn populate_mods_with_author_names(mods: &mut HashMap<String, Mod>, user_nam
 ///
 /// If the mod doesn't yet exists in the workshop, it creates it. If it already exists, it updates it.
 pub fn upload_mod_to_workshop(game: &GameInfo, modd: &Mod, title: &str, description: &str, tags: &[String], changelog: &str, visibility: &Option<u32>, force_update: bool) -> Result<()> {
+    check_online()?;
     let game_path = setting_path(game.key());
-    let steam_id = game.steam_id(&game_path)? as u32;
+    let steam_id = steam_id(game, &game_path)? as u32;
 
     let pack_path = if modd.paths().is_empty() {
         return Err(anyhow!("Mod Path not found."));
@@ -313,9 +413,9 @@ pub fn upload_mod_to_workshop(game: &GameInfo, modd: &Mod, title: &str, descript
 }
 
 /// This function launches a game through workshopper, with access to the Steam Api.
-pub fn launch_game(game: &GameInfo, command_to_pass: &str, wait_for_finish: bool) -> Result<()> {
+pub fn launch_game(game: &GameInfo, command_to_pass: &str, env_vars: &[(String, String)], wait_for_finish: bool) -> Result<()> {
     let game_path = setting_path(game.key());
-    let steam_id = game.steam_id(&game_path)? as u32;
+    let steam_id = steam_id(game, &game_path)? as u32;
 
     let mut command = Command::new("cmd");
     command.arg("/C");
@@ -329,6 +429,9 @@ pub fn launch_game(game: &GameInfo, command_to_pass: &str, wait_for_finish: bool
     command.arg("-c");
     command.arg(command_to_pass);
 
+    // Workshopper passes its environment to the game, so we just need to set the variables here.
+    command.envs(env_vars.iter().cloned());
+
     // This is for creating the terminal window. Without it, the entire process runs in the background and there's no feedback on when it's done.
     #[cfg(target_os = "windows")] if cfg!(debug_assertions) {
         command.creation_flags(DETACHED_PROCESS);
@@ -345,10 +448,55 @@ pub fn launch_game(game: &GameInfo, command_to_pass: &str, wait_for_finish: bool
     Ok(())
 }
 
+/// This function asks the Steam client to launch a game, so it goes through Steam like when launched from the library.
+///
+/// Steam takes care of the game from there, so we cannot know when it's closed.
+pub fn launch_game_through_steam(game: &GameInfo, args: &str) -> Result<()> {
+    let game_path = setting_path(game.key());
+    let steam_id = steam_id(game, &game_path)? as u32;
+
+    // Steam decodes the arguments before passing them to the game, so anything that could break the url has to be encoded.
+    let args = args.bytes()
+        .map(|byte| if byte.is_ascii_alphanumeric() || b"-_.;".contains(&byte) { (byte as char).to_string() } else { format!("%{:02X}", byte) })
+        .collect::<String>();
+
+    open::that(format!("{STEAM_RUN_URL}{steam_id}//{args}/"))?;
+    Ok(())
+}
+
+/// This function asks workshopper to subscribe to the provided mods, then download them.
+pub fn subscribe_mods(game: &GameInfo, published_file_ids: &[String]) -> Result<()> {
+    check_online()?;
+    let game_path = setting_path(game.key());
+    let steam_id = steam_id(game, &game_path)? as u32;
+
+    let mut command = Command::new("cmd");
+    command.arg("/C");
+    command.arg(&*WORKSHOPPER_PATH);
+
+    command.arg("download-subscribed-items");
+    command.arg("-s");
+    command.arg(steam_id.to_string());
+    command.arg("-p");
+    command.arg(published_file_ids.join(","));
+    command.arg("--subscribe");
+
+    // This is for creating the terminal window. Without it, the entire process runs in the background and there's no feedback on when it's done.
+    #[cfg(target_os = "windows")]command.creation_flags(DETACHED_PROCESS);
+
+    let status = command.spawn()?.wait()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(anyhow!("Workshopper failed to subscribe to the mods. Check its log for more info."))
+    }
+}
+
 /// This function asks workshopper to get all subscribed items, check which ones are missing, and tell steam to re-download them.
 pub fn download_subscribed_mods(game: &GameInfo, published_file_ids: &Option<Vec<String>>) -> Result<()> {
+    check_online()?;
     let game_path = setting_path(game.key());
-    let steam_id = game.steam_id(&game_path)? as u32;
+    let steam_id = steam_id(game, &game_path)? as u32;
 
     let mut command = Command::new("cmd");
     command.arg("/C");
@@ -372,9 +520,62 @@ pub fn download_subscribed_mods(game: &GameInfo, published_file_ids: &Option<Vec
     Ok(())
 }
 
+/// This function asks workshopper to unsubscribe from the provided mods, remove their files, then resubscribe and download them again.
+pub fn force_redownload_mods(game: &GameInfo, published_file_ids: &[String]) -> Result<()> {
+    check_online()?;
+    let game_path = setting_path(game.key());
+    let steam_id = steam_id(game, &game_path)? as u32;
+
+    let mut command = Command::new("cmd");
+    command.arg("/C");
+    command.arg(&*WORKSHOPPER_PATH);
+
+    command.arg("force-redownload");
+    command.arg("-s");
+    command.arg(steam_id.to_string());
+    command.arg("-p");
+    command.arg(published_file_ids.join(","));
+
+    // This is for creating the terminal window. Without it, the entire process runs in the background and there's no feedback on when it's done.
+    #[cfg(target_os = "windows")]command.creation_flags(DETACHED_PROCESS);
+
+    let status = command.spawn()?.wait()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(anyhow!("Workshopper failed to re-download some of the mods. Check its log for more info."))
+    }
+}
+
+/// This function asks workshopper to turn the Shogun 2 battle map in the provided pack back into a map bin for the legacy uploader.
+pub fn export_map_bin(pack_path: &Path, map_name: &str, output_path: &Path) -> Result<()> {
+    let mut command = Command::new("cmd");
+    command.arg("/C");
+    command.arg(&*WORKSHOPPER_PATH);
+
+    command.arg("export-map-bin");
+    command.arg("-p");
+    command.arg(pack_path.to_string_lossy().to_string());
+    command.arg("-m");
+    command.arg(map_name);
+    command.arg("-o");
+    command.arg(output_path.to_string_lossy().to_string());
+
+    #[cfg(target_os = "windows")] if cfg!(debug_assertions) {
+        command.creation_flags(DETACHED_PROCESS);
+    }
+
+    let status = command.spawn()?.wait()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(anyhow!("Workshopper failed to export the map. Check its log for more info."))
+    }
+}
+
 pub fn user_id(game: &GameInfo) -> Result<u64> {
     let game_path = setting_path(game.key());
-    let steam_id = game.steam_id(&game_path)? as u32;
+    let steam_id = steam_id(game, &game_path)? as u32;
     let ipc_channel = rand::random::<u64>().to_string();
 
     let mut command = Command::new("cmd");
@@ -409,7 +610,7 @@ pub fn user_id(game: &GameInfo) -> Result<u64> {
 }
 
 fn app_manifest_path(game: &GameInfo, game_path: &Path) -> Result<PathBuf> {
-    let steam_id = game.steam_id(&game_path)? as u32;
+    let steam_id = steam_id(game, &game_path)? as u32;
     let mut app_path = game_path.to_path_buf();
     app_path.pop();
     app_path.pop();
@@ -418,6 +619,18 @@ fn app_manifest_path(game: &GameInfo, game_path: &Path) -> Result<PathBuf> {
     Ok(app_path)
 }
 
+/// This function returns the build id of the installed version of the game, from its Steam manifest.
+pub fn build_id(game: &GameInfo, game_path: &Path) -> Result<Option<String>> {
+    let mut data = String::new();
+    File::open(app_manifest_path(game, game_path)?)?.read_to_string(&mut data)?;
+
+    // The manifest is in Valve's KeyValues format: one quoted key and value per line.
+    Ok(data.lines()
+        .map(|line| line.split('"').filter(|part| !part.trim().is_empty()).collect::<Vec<_>>())
+        .find(|parts| parts.len() == 2 && parts[0].eq_ignore_ascii_case("buildid"))
+        .map(|parts| parts[1].to_owned()))
+}
+
 pub fn can_game_locked(game: &GameInfo, game_path: &Path) -> Result<bool> {
     let app_path = app_manifest_path(game, game_path)?;
     Ok(app_path.is_file())
@@ -449,3 +662,11 @@ pub fn toggle_game_locked(game: &GameInfo, game_path: &Path, toggle: bool) -> Re
 
     Ok(permissions.readonly())
 }
+
+/// This function returns an error if we're in offline mode, so workshopper is not started just to wait for a timeout.
+fn check_online() -> Result<()> {
+    match offline_mode() {
+        Some(reason) => Err(anyhow!("Runcher is in offline mode ({:?}), so it cannot talk with the Steam Workshop.", reason)),
+        None => Ok(()),
+    }
+}
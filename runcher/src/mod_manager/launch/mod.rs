@@ -0,0 +1,434 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the non-UI code used to tell the games which mods to load when launching them.
+
+use anyhow::{anyhow, Result};
+
+use std::collections::HashMap;
+use std::fs::{DirBuilder, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+#[cfg(target_os = "windows")] use std::os::windows::process::CommandExt;
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use rpfm_lib::binary::WriteBytes;
+use rpfm_lib::files::{EncodeableExtraData, pack::Pack};
+use rpfm_lib::games::{GameInfo, pfh_file_type::PFHFileType};
+use rpfm_lib::integrations::log::*;
+
+use rpfm_ui_common::settings::config_path;
+
+use crate::games::{RESERVED_PACK_NAME, RESERVED_PACK_NAME_ALTERNATIVE};
+use crate::settings_ui::{game_appdata_path, temp_packs_folder};
+
+use super::{MERGE_ALL_PACKS_PACK_NAME, secondary_mods_path};
+use super::game_config::GameConfig;
+use super::game_quirks::game_quirks;
+#[cfg(target_os = "windows")] use super::integrations::CREATE_NO_WINDOW;
+use super::load_order::LoadOrder;
+use super::masks::{rebuild_masks, verify_masks};
+use super::secondary_staging::{clean_staged_packs, needs_secondary_staging, stage_secondary_packs, staged_packs};
+use super::user_script::{update_user_script, FOLDER_LINE_START, MOD_LINE_START};
+
+pub const CUSTOM_MOD_LIST_FILE_NAME: &str = "mod_list.txt";
+pub const USER_SCRIPT_FILE_NAME: &str = "user.script.txt";
+pub const USER_SCRIPT_EMPIRE_FILE_NAME: &str = "user.empire_script.txt";
+
+const LAUNCH_MANIFEST_FILE_NAME_START: &str = "launch_manifest_";
+const LAUNCH_MANIFEST_FILE_NAME_END: &str = ".txt";
+
+/// Registry key where Windows keeps the compatibility layers of each executable for the current user.
+const COMPATIBILITY_LAYERS_KEY: &str = r"HKCU\Software\Microsoft\Windows NT\CurrentVersion\AppCompatFlags\Layers";
+
+/// Flag used by the UI to tell the background thread to stop the launch in progress.
+pub static LAUNCH_CANCELLED: AtomicBool = AtomicBool::new(false);
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// Steps of a launch, reported to the UI while they're being done.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LaunchStep {
+    RunningPreLaunchHooks,
+    BackingUpSaves,
+    MergingPacks,
+    BuildingLoadOrder,
+    MaskingMovies,
+    LinkingSecondaryPacks,
+    CopyingSecondaryPacks,
+    WaitingForGame,
+    AnalyzingLogs,
+    RunningPostExitHooks,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl LaunchStep {
+
+    /// This function returns the locale key of the message to show while this step is being done.
+    pub fn locale_key(&self) -> &'static str {
+        match self {
+            Self::RunningPreLaunchHooks => "launch_progress_pre_launch_hooks",
+            Self::BackingUpSaves => "launch_progress_backing_up_saves",
+            Self::MergingPacks => "launch_progress_merging",
+            Self::BuildingLoadOrder => "launch_progress_load_order",
+            Self::MaskingMovies => "launch_progress_masks",
+            Self::LinkingSecondaryPacks => "launch_progress_linking_secondary",
+            Self::CopyingSecondaryPacks => "launch_progress_copying_secondary",
+            Self::WaitingForGame => "launch_progress_waiting",
+            Self::AnalyzingLogs => "launch_progress_logs",
+            Self::RunningPostExitHooks => "launch_progress_post_exit_hooks",
+        }
+    }
+}
+
+/// This function returns if the launch in progress has been cancelled from the UI.
+pub fn launch_cancelled() -> bool {
+    LAUNCH_CANCELLED.load(Ordering::SeqCst)
+}
+
+/// This function builds the pack and folder lists to write to the mod list file of the provided game, and prepares the files they need.
+///
+/// The folder list is expected to already contain the folders added by the launch options. The provided closure is called before each step.
+/// If the launch is cancelled between steps, this returns an error.
+pub fn prepare_mod_list(game: &GameInfo, game_path: &Path, game_config: &GameConfig, load_order: &LoadOrder, merge_all_mods: bool, mut folder_list: String, progress: impl Fn(LaunchStep)) -> Result<(String, String)> {
+    let mut pack_list = String::new();
+    let data_path = game.data_path(game_path)?;
+
+    // Packs staged by a previous launch would be loaded twice if left in /data.
+    clean_staged_packs(game.key())?;
+
+    // If we have "merge all mods" checked, we need to load the entire load order into a single pack, and load that pack instead of the entire load order.
+    //
+    // TODO: Review this before re-enabling merged mods. This pretty sure breaks on older games.
+    if merge_all_mods {
+        progress(LaunchStep::MergingPacks);
+
+        // Like the reserved pack, the merged pack goes to the temp packs folder if the game can load it from there, and to /data otherwise.
+        let temp_path_file_name = format!("{}_{}.pack", MERGE_ALL_PACKS_PACK_NAME, game.key());
+        let temp_path = if *game_quirks(game).supports_working_directories() {
+            let temp_packs_folder = temp_packs_folder(game)?;
+            DirBuilder::new().recursive(true).create(&temp_packs_folder)?;
+
+            let working_directory = format!("add_working_directory \"{}\";\n", temp_packs_folder.to_string_lossy());
+            if !folder_list.contains(&working_directory) {
+                folder_list.push_str(&working_directory);
+            }
+
+            // Merged packs from before the temp packs folder was used would be loaded too, so remove them.
+            let old_temp_path = data_path.join(&temp_path_file_name);
+            if old_temp_path.is_file() {
+                std::fs::remove_file(&old_temp_path)?;
+            }
+
+            temp_packs_folder.join(&temp_path_file_name)
+        } else {
+            data_path.join(&temp_path_file_name)
+        };
+        pack_list.push_str(&format!("mod \"{}\";", temp_path_file_name));
+
+        let pack_paths = load_order.mods().iter()
+            .filter_map(|mod_id| {
+                let modd = game_config.mods().get(mod_id)?;
+                std::fs::canonicalize(modd.paths().first()?).ok()
+            })
+            .collect::<Vec<_>>();
+
+        if !pack_paths.is_empty() {
+            let mut reserved_pack = Pack::read_and_merge(&pack_paths, true, false, true)?;
+            let pack_version = game.pfh_version_by_file_type(PFHFileType::Mod);
+            reserved_pack.set_pfh_version(pack_version);
+
+            let mut encode_data = EncodeableExtraData::default();
+            encode_data.set_nullify_dates(true);
+
+            reserved_pack.save(Some(&temp_path), game, &Some(encode_data))?;
+        }
+    }
+
+    // Otherwise, just add the packs from the load order to the text file.
+    else {
+
+        // If the game cannot load the secondary folder from another drive, its packs are loaded from /data instead.
+        let staged_game_config;
+        let game_config = if needs_secondary_staging(game, game_path) {
+            staged_game_config = stage_secondary_packs(game, &data_path, game_config, load_order, &progress)?;
+            &staged_game_config
+        } else {
+            game_config
+        };
+
+        progress(LaunchStep::BuildingLoadOrder);
+        load_order.build_load_order_string(game_config, game, &data_path, &mut pack_list, &mut folder_list);
+    }
+
+    if launch_cancelled() {
+        return Err(anyhow!("Launch cancelled."));
+    }
+
+    // If our folder list contains the secondary folder, we need to make sure we create the masks folder in it,
+    // and mask in there all non-enabled movie files.
+    let secondary_mods_path = secondary_mods_path(game.key()).unwrap_or_else(|_| PathBuf::new());
+    if *game_quirks(game).supports_masks() && secondary_mods_path.is_dir() && folder_list.contains(&secondary_mods_path.to_string_lossy().to_string()) {
+        progress(LaunchStep::MaskingMovies);
+
+        // Masks left by launches done outside Runcher may be hiding enabled packs, so log them before replacing them.
+        let report = verify_masks(game, game_config, &data_path)?;
+        if !report.is_consistent() {
+            info!("Stale masks found before launch: {:?}. Missing masks: {:?}.", report.stale(), report.missing());
+        }
+
+        rebuild_masks(game, game_config, &data_path)?;
+
+        let report = verify_masks(game, game_config, &data_path)?;
+        if !report.is_consistent() {
+            return Err(anyhow!("The masks folder couldn't be rebuilt. Stale masks: {:?}. Missing masks: {:?}.", report.stale(), report.missing()));
+        }
+    }
+
+    if launch_cancelled() {
+        return Err(anyhow!("Launch cancelled."));
+    }
+
+    Ok((pack_list, folder_list))
+}
+
+/// This function returns the scripts folder of the provided game, creating it if it doesn't exist.
+///
+/// Games may fail to launch if we don't have this path created, which is done the first time we start the game.
+pub fn scripts_path(game: &GameInfo, game_path: &Path) -> Result<PathBuf> {
+    let config_path = game_appdata_path(game, game_path).ok_or(anyhow!("Error getting the game's config path. If the game keeps its config in a non-standard folder, set it in the settings."))?;
+    let scripts_path = config_path.join("scripts");
+    DirBuilder::new().recursive(true).create(&scripts_path)?;
+    Ok(scripts_path)
+}
+
+/// This function returns the path of the file where the mod list has to be written for the provided game.
+///
+/// NOTE: On Empire and Napoleon we need to use the user_script, not the custom file, as it doesn't seem to work.
+/// Older versions of shogun 2 also used the user_script, but the latest update enabled use of custom mod lists.
+pub fn mod_list_file_path(game: &GameInfo, game_path: &Path, scripts_path: &Path) -> PathBuf {
+    match game_quirks(game).user_script() {
+        Some(user_script) => scripts_path.join(user_script),
+        None => game_path.join(CUSTOM_MOD_LIST_FILE_NAME),
+    }
+}
+
+/// This function writes the provided folder and pack lists to the mod list file of the provided game.
+pub fn write_mod_list_file(game: &GameInfo, path: &Path, folder_list: &str, pack_list: &str) -> Result<()> {
+
+    // User scripts may contain lines written by the user, so we only refresh our own block in them.
+    // If there are no mods to load, this restores the user script to what the user had before.
+    let quirks = game_quirks(game);
+    if quirks.user_script().is_some() {
+        update_user_script(path, folder_list, pack_list)?;
+    } else {
+        let mut file = BufWriter::new(File::create(path)?);
+
+        // Napoleon, Empire and Shogun 2 require the user.script.txt or mod list file (for Shogun's latest update) to be in UTF-16 LE. What the actual fuck.
+        if *quirks.utf16_mod_list() {
+            file.write_string_u16(folder_list)?;
+            file.write_string_u16(pack_list)?;
+        } else {
+            file.write_all(folder_list.as_bytes())?;
+            file.write_all(pack_list.as_bytes())?;
+        }
+
+        file.flush()?;
+    }
+
+    Ok(())
+}
+
+/// This function returns the names of the packs listed in the provided mod list file, in the order they're loaded.
+///
+/// It works for any file using the mod list format, like the ones written by the official launcher or older games' user scripts.
+pub fn read_mod_list_file(path: &Path) -> Result<Vec<String>> {
+    let data = std::fs::read(path)?;
+
+    // Older games use UTF-16 LE files. As these files start with either a BOM or ascii text, we can detect them by their first bytes.
+    let text = if data.starts_with(&[0xFF, 0xFE]) || (data.len() >= 2 && data[1] == 0) {
+        let chars = data.chunks_exact(2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+            .collect::<Vec<_>>();
+
+        String::from_utf16_lossy(&chars)
+    } else {
+        String::from_utf8_lossy(&data).to_string()
+    };
+
+    Ok(text.lines()
+        .filter_map(|line| {
+            let start = line.find(MOD_LINE_START)?;
+            let pack_name = line[start + MOD_LINE_START.len()..].strip_prefix('"')?;
+            pack_name.find('"').map(|end| pack_name[..end].to_owned())
+        })
+        .collect())
+}
+
+/// This function returns the arguments to pass to the game's executable when launching it.
+///
+/// Empire and Napoleon do not have a launcher, nor read a mod list file, so they get no arguments.
+pub fn launch_args(game: &GameInfo, extra_args: &[String]) -> String {
+    if *game_quirks(game).needs_launcher_bypass() {
+        let mut args = format!("{};", CUSTOM_MOD_LIST_FILE_NAME);
+
+        for arg in extra_args {
+            args.push(' ');
+            args.push_str(arg);
+        }
+
+        args
+    } else {
+        String::new()
+    }
+}
+
+/// This function sets the compatibility layer Windows uses to run the provided executable. If the layer is empty, it's removed instead.
+///
+/// Windows reads the layers from the registry when starting any process, so this works no matter how the game is started, elevated or not.
+/// Only layers in `known_layers` are removed, so layers the user set from the properties of the executable are kept.
+pub fn set_compatibility_layer(exec_game: &Path, layer: &str, known_layers: &[&str]) -> Result<()> {
+    let exec_game = exec_game.to_string_lossy().replace('/', "\\");
+    let current_layer = current_compatibility_layer(&exec_game)?;
+
+    if !layer.is_empty() {
+        let layer = format!("~ {}", layer);
+        if current_layer.as_deref() != Some(&layer) {
+            run_reg(&["add", COMPATIBILITY_LAYERS_KEY, "/v", &exec_game, "/t", "REG_SZ", "/d", &layer, "/f"])?;
+        }
+    } else if let Some(current_layer) = current_layer {
+        let current_layers = current_layer.split_whitespace().filter(|layer| *layer != "~").collect::<Vec<_>>();
+        if current_layers.len() == 1 && known_layers.contains(&current_layers[0]) {
+            run_reg(&["delete", COMPATIBILITY_LAYERS_KEY, "/v", &exec_game, "/f"])?;
+        }
+    }
+
+    Ok(())
+}
+
+/// This function returns the compatibility layers currently set for the provided executable, if any.
+fn current_compatibility_layer(exec_game: &str) -> Result<Option<String>> {
+    let mut command = Command::new("reg");
+    command.args(["query", COMPATIBILITY_LAYERS_KEY, "/v", exec_game]);
+    #[cfg(target_os = "windows")] command.creation_flags(CREATE_NO_WINDOW);
+
+    // Reg fails if the value doesn't exist, so a failure here just means there's no layer.
+    let output = command.output()?;
+    if !output.status.success() {
+        return Ok(None);
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).lines()
+        .find_map(|line| line.split_once("REG_SZ"))
+        .map(|(_, value)| value.trim().to_owned())
+        .filter(|value| !value.is_empty()))
+}
+
+fn run_reg(args: &[&str]) -> Result<()> {
+    let mut command = Command::new("reg");
+    command.args(args);
+    #[cfg(target_os = "windows")] command.creation_flags(CREATE_NO_WINDOW);
+
+    let output = command.output()?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(anyhow!("Error updating the compatibility layer of the game: {}", String::from_utf8_lossy(&output.stderr).trim()))
+    }
+}
+
+/// This function returns what the game is told to load in a launch, numbered in the order the game reads it and with full paths.
+///
+/// That's the folders the game reads packs from, the packs in the mod list (including the merged one), the movie packs the game loads on its own
+/// (including the reserved one) and any other line of the mod list, like excluded packs. Unlike the list of enabled mods, this is what bug reports need.
+/// Packs staged in /data from the secondary folder are listed with their staged path and how they were staged.
+pub fn launch_manifest(game: &GameInfo, game_path: &Path, game_config: &GameConfig, load_order: &LoadOrder, folder_list: &str, pack_list: &str) -> Result<String> {
+    let data_path = game.data_path(game_path)?;
+    let pack_paths = load_order.mods().iter()
+        .filter_map(|mod_id| game_config.mods().get(mod_id))
+        .filter_map(|modd| modd.paths().first())
+        .filter_map(|path| Some((path.file_name()?.to_string_lossy().to_string(), path.to_path_buf())))
+        .collect::<HashMap<_, _>>();
+
+    let staged = staged_packs(game.key()).into_iter()
+        .filter_map(|pack| Some((pack.dest().file_name()?.to_string_lossy().to_string(), pack)))
+        .collect::<HashMap<_, _>>();
+
+    let mut entries = vec![];
+    let mut folders = vec![];
+    for line in folder_list.lines().chain(pack_list.lines()).map(|line| line.trim()).filter(|line| !line.is_empty()) {
+        if let Some(folder) = quoted_value(line, FOLDER_LINE_START) {
+            entries.push(("folder", folder.to_owned()));
+            folders.push(PathBuf::from(folder));
+        } else if let Some(pack_name) = quoted_value(line, MOD_LINE_START) {
+            if let Some(pack) = staged.get(pack_name) {
+                entries.push(("pack", format!("{} ({} from {})", pack.dest().to_string_lossy(), pack.strategy().manifest_label(), pack.source().to_string_lossy())));
+                continue;
+            }
+
+            // Packs not in the mod list, like the merged one, are looked for in the same places the game looks for them.
+            let path = pack_paths.get(pack_name).cloned()
+                .or_else(|| folders.iter().map(|folder| folder.join(pack_name)).find(|path| path.is_file()))
+                .unwrap_or_else(|| data_path.join(pack_name));
+
+            entries.push(("pack", path.to_string_lossy().to_string()));
+        } else {
+            entries.push(("line", line.to_owned()));
+        }
+    }
+
+    for mod_id in load_order.movies() {
+        if let Some(path) = game_config.mods().get(mod_id).and_then(|modd| modd.paths().first()) {
+            entries.push(("movie", path.to_string_lossy().to_string()));
+        }
+    }
+
+    for folder in folders.iter().chain([&data_path]) {
+        for reserved_pack_name in [RESERVED_PACK_NAME, RESERVED_PACK_NAME_ALTERNATIVE] {
+            let path = folder.join(reserved_pack_name);
+            if path.is_file() {
+                entries.push(("movie", path.to_string_lossy().to_string()));
+            }
+        }
+    }
+
+    let mut manifest = format!("# Runcher launch manifest\n# Game: {}\n", game.key());
+    for (index, (kind, value)) in entries.iter().enumerate() {
+        manifest.push_str(&format!("{:>4}. {} {}\n", index + 1, kind, value));
+    }
+
+    Ok(manifest)
+}
+
+/// This function saves the manifest of the last launch of the provided game, so it can be exported later for bug reports.
+pub fn save_launch_manifest(game_key: &str, manifest: &str) -> Result<()> {
+    std::fs::write(launch_manifest_path(game_key)?, manifest)?;
+    Ok(())
+}
+
+/// This function returns the manifest of the last launch of the provided game.
+pub fn last_launch_manifest(game_key: &str) -> Result<String> {
+    Ok(std::fs::read_to_string(launch_manifest_path(game_key)?)?)
+}
+
+fn launch_manifest_path(game_key: &str) -> Result<PathBuf> {
+    Ok(config_path()?.join(format!("{LAUNCH_MANIFEST_FILE_NAME_START}{game_key}{LAUNCH_MANIFEST_FILE_NAME_END}")))
+}
+
+/// This function returns the value between quotes of a mod list line starting with the provided text.
+fn quoted_value<'a>(line: &'a str, start: &str) -> Option<&'a str> {
+    line.strip_prefix(start)?.strip_prefix('"')?.split('"').next()
+}
@@ -0,0 +1,92 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for linting load orders.
+//!
+//! Linting here means detecting packs that usually cause problems if left enabled, like autosave or debug packs.
+//! The patterns used for it are kept in a list that can be updated from Runcher's repo.
+
+use anyhow::Result;
+use getset::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use std::path::Path;
+
+use super::game_config::GameConfig;
+use super::load_order::LoadOrder;
+use super::remote_lists;
+
+const LINT_PATTERNS_FILE_NAME: &str = "lint_patterns.json";
+
+/// Patterns shipped with the program, used if we don't have a downloaded list.
+const LINT_PATTERNS_DEFAULT: &str = include_str!("../../../../lint_patterns.json");
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct LintPatterns {
+    patterns: Vec<LintPattern>,
+}
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct LintPattern {
+
+    /// Regex to match against the pack names.
+    pattern: String,
+
+    /// Explanation of why packs matching this pattern are problematic.
+    reason: String,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl LintPatterns {
+
+    /// This function loads the downloaded list of patterns, falling back to the one shipped with the program if it fails.
+    pub fn load() -> Self {
+        remote_lists::load(LINT_PATTERNS_FILE_NAME, Some(LINT_PATTERNS_DEFAULT))
+    }
+
+    /// This function returns if the downloaded list of patterns is missing or outdated.
+    pub fn needs_update() -> bool {
+        remote_lists::needs_update(LINT_PATTERNS_FILE_NAME)
+    }
+
+    /// This function downloads the latest list of patterns. The list is only saved if it's valid.
+    pub fn update() -> Result<()> {
+        remote_lists::update::<Self>(LINT_PATTERNS_FILE_NAME)
+    }
+
+    /// This function returns the id of the enabled mods matching any of the patterns, alongside the reason they matched.
+    pub fn lint(&self, game_config: &GameConfig, load_order: &LoadOrder, game_data_path: &Path) -> Vec<(String, String)> {
+        let regexes = self.patterns()
+            .iter()
+            .filter_map(|pattern| Regex::new(pattern.pattern()).ok().map(|regex| (regex, pattern.reason())))
+            .collect::<Vec<_>>();
+
+        load_order.mods()
+            .iter()
+            .chain(load_order.movies().iter())
+            .filter_map(|mod_id| game_config.mods().get(mod_id))
+            .filter(|modd| modd.enabled(game_data_path))
+            .filter_map(|modd| regexes.iter()
+                .find(|(regex, _)| regex.is_match(modd.id()))
+                .map(|(_, reason)| (modd.id().to_owned(), reason.to_string()))
+            )
+            .collect()
+    }
+}
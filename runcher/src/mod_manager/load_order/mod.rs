@@ -8,30 +8,35 @@
 // https://github.com/Frodo45127/runcher/blob/master/LICENSE.
 //---------------------------------------------------------------------------//
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use getset::*;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_json::to_string_pretty;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io::{BufReader, BufWriter, Read, Write};
 use std::fs::{DirBuilder, File};
 use std::path::{Path, PathBuf};
 
-use rpfm_lib::files::pack::Pack;
+use rpfm_lib::files::{Container, pack::Pack};
 use rpfm_lib::games::{GameInfo, pfh_file_type::PFHFileType};
 use rpfm_lib::integrations::log::*;
 use rpfm_lib::utils::{path_to_absolute_path, path_to_absolute_string};
 
-use crate::mod_manager::SECONDARY_FOLDER_NAME;
+use rpfm_ui_common::settings::setting_bool;
+
 use crate::settings_ui::game_config_path;
 
+use super::conflicts::{apply_order_suggestions, order_suggestions};
 use super::game_config::GameConfig;
+use super::game_quirks::game_quirks;
+use super::masks::masks_path;
+use super::name_matching::{is_exact_match, list_names, match_names};
 use super::secondary_mods_path;
 
-const FILE_NAME_START: &str = "last_load_order_";
-const FILE_NAME_END: &str = ".json";
+pub const FILE_NAME_START: &str = "last_load_order_";
+pub const FILE_NAME_END: &str = ".json";
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -50,15 +55,36 @@ pub struct LoadOrder {
     // Movie Packs. These are not reorderable, so we keep them in a separate list.
     movies: Vec<String>,
 
+    // Groups of mods whose relative order is locked. Defaulted so load orders saved before groups existed still load.
+    #[serde(default)]
+    groups: Vec<LoadOrderGroup>,
+
     // List of Packs open for data checking. Not serialized.
     #[serde(skip_deserializing, skip_serializing)]
     packs: HashMap<String, Pack>,
+
+    // Packs that failed to load, with the error they failed with. Not serialized.
+    #[serde(skip_deserializing, skip_serializing)]
+    pack_errors: BTreeMap<PathBuf, String>,
+}
+
+/// A group of mods that always load together, in the order they're in the group.
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct LoadOrderGroup {
+
+    // Name of the group, for the UI.
+    name: String,
+
+    // Id/Pack name of the mods in the group, in their locked order. Mods not enabled are kept, but ignored when ordering.
+    mods: Vec<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ImportedLoadOrderMode {
     Runcher(String),
-    Modlist(String)
+    Modlist(String),
+    Names(String),
 }
 
 //-------------------------------------------------------------------------------//
@@ -71,7 +97,9 @@ impl Default for LoadOrder {
             automatic: true,
             mods: vec![],
             movies: vec![],
+            groups: vec![],
             packs: HashMap::new(),
+            pack_errors: BTreeMap::new(),
         }
     }
 }
@@ -101,6 +129,7 @@ impl LoadOrder {
 
         let mut file = BufWriter::new(File::create(path)?);
         file.write_all(to_string_pretty(&self)?.as_bytes())?;
+
         Ok(())
     }
 
@@ -113,17 +142,40 @@ impl LoadOrder {
             self.build_manual(game_config, game_data_path);
         }
 
+        // Groups apply to both modes, so the mods in them are always together and in their locked order.
+        self.apply_groups();
+
         // After the order is built, reload the enabled packs.
         self.packs.clear();
-        self.packs = self.mods.clone()
+        self.pack_errors.clear();
+        let packs = self.mods.clone()
             .into_par_iter()
             .chain(self.movies.clone())
             .filter_map(|mod_id| {
                 let modd = game_config.mods().get(&mod_id)?;
                 let path = modd.paths().first()?;
-                Some((mod_id.to_owned(), Pack::read_and_merge(&[path.to_path_buf()], true, false, false).ok()?))
+                Some((mod_id, path.to_path_buf(), Pack::read_and_merge(&[path.to_path_buf()], true, false, false)))
             })
-            .collect();
+            .collect::<Vec<_>>();
+
+        // Broken packs are left out instead of failing the whole load, but we keep their errors so the user can find them.
+        for (mod_id, path, pack) in packs {
+            match pack {
+                Ok(pack) => {
+                    self.packs.insert(mod_id, pack);
+                }
+                Err(error) => {
+                    warn!("Error loading pack {}: {}", path.to_string_lossy(), error);
+                    self.pack_errors.insert(path, error.to_string());
+                }
+            }
+        }
+
+        // In automatic mode, packs can be reordered so the more specific ones win their conflicts. This needs the packs loaded.
+        if self.automatic && setting_bool("apply_conflict_suggestions") {
+            let suggestions = order_suggestions(self);
+            apply_order_suggestions(self, &suggestions);
+        }
     }
 
     /// Automatic builds means the user input is ignored, and mods are sorted alphabetically.
@@ -190,6 +242,126 @@ impl LoadOrder {
         })
     }
 
+    /// This function reorders the mods following the list of pack names in the provided text, one per line, returning the names that didn't match any mod.
+    ///
+    /// Exact pack names are matched first, so a similar name can't take the mod another line names exactly. The rest of the names
+    /// are matched loosely, the same way as other name lists, so lists from other mod managers or written by hand can be used.
+    /// Mods not in the list are kept after the listed ones, in their current relative order. Groups are kept together.
+    pub fn reorder_from_names(&mut self, text: &str) -> Vec<String> {
+        let names = list_names(text);
+        let mut matched: Vec<Option<String>> = vec![None; names.len()];
+
+        for (index, name) in names.iter().enumerate() {
+            let found = self.mods.iter().find(|mod_id| is_exact_match(name, mod_id) && !matched.iter().flatten().any(|x| x == *mod_id));
+            matched[index] = found.cloned();
+        }
+
+        // Only the mods nobody named exactly are candidates for the loose matches.
+        let remaining = self.mods.iter()
+            .filter(|mod_id| !matched.iter().flatten().any(|x| x == *mod_id))
+            .map(|mod_id| (mod_id.as_str(), vec![mod_id.as_str()]))
+            .collect::<Vec<_>>();
+
+        let mut unmatched = vec![];
+        for (index, name) in names.iter().enumerate() {
+            if matched[index].is_some() {
+                continue;
+            }
+
+            let found = match_names(&remaining, name)
+                .first()
+                .and_then(|name_match| name_match.good_candidates().find(|mod_id| !matched.iter().flatten().any(|x| x == *mod_id)).map(|mod_id| mod_id.to_owned()));
+
+            match found {
+                Some(mod_id) => matched[index] = Some(mod_id),
+                None => unmatched.push(name.to_string()),
+            }
+        }
+
+        let mut ordered = matched.into_iter().flatten().collect::<Vec<_>>();
+        let rest = self.mods.iter()
+            .filter(|mod_id| !ordered.contains(mod_id))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        ordered.extend(rest);
+        self.mods = ordered;
+
+        // Groups keep their locked order even if the list says otherwise.
+        self.apply_groups();
+
+        unmatched
+    }
+
+    /// This function creates a new group with the provided mods, locking their current relative order.
+    ///
+    /// Mods that were already in another group are removed from it, and groups left with less than two mods are removed.
+    pub fn create_group(&mut self, name: &str, mod_ids: &[String]) {
+        let mut mods = mod_ids.to_vec();
+        mods.sort_by_key(|mod_id| self.mods.iter().position(|x| x == mod_id).unwrap_or(usize::MAX));
+        mods.dedup();
+
+        self.groups.iter_mut().for_each(|group| group.mods.retain(|mod_id| !mods.contains(mod_id)));
+        self.groups.retain(|group| group.mods.len() > 1);
+
+        if mods.len() > 1 {
+            self.groups.push(LoadOrderGroup {
+                name: name.to_owned(),
+                mods,
+            });
+        }
+
+        self.apply_groups();
+    }
+
+    /// This function removes the groups containing any of the provided mods.
+    pub fn remove_groups(&mut self, mod_ids: &[String]) {
+        self.groups.retain(|group| !group.mods.iter().any(|mod_id| mod_ids.contains(mod_id)));
+    }
+
+    /// This function returns the group the provided mod is in, if any.
+    pub fn group(&self, mod_id: &str) -> Option<&LoadOrderGroup> {
+        self.groups.iter().find(|group| group.mods.iter().any(|x| x == mod_id))
+    }
+
+    /// This function returns the provided mods plus the rest of the mods in their groups, sorted by their position in the load order.
+    ///
+    /// Use it before moving mods, so groups are moved as a unit.
+    pub fn expand_to_groups(&self, mod_ids: &[String]) -> Vec<String> {
+        let mut expanded = mod_ids.to_vec();
+        for mod_id in mod_ids {
+            if let Some(group) = self.group(mod_id) {
+                for member in &group.mods {
+                    if self.mods.contains(member) && !expanded.contains(member) {
+                        expanded.push(member.to_owned());
+                    }
+                }
+            }
+        }
+
+        expanded.sort_by_key(|mod_id| self.mods.iter().position(|x| x == mod_id).unwrap_or(usize::MAX));
+        expanded
+    }
+
+    /// This function reorders the mods so the ones in each group are together and in their locked order.
+    ///
+    /// Each group is placed where its first mod in the current load order is.
+    pub fn apply_groups(&mut self) {
+        for group in &self.groups {
+            let members = group.mods.iter()
+                .filter(|mod_id| self.mods.contains(mod_id))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if let Some(anchor) = self.mods.iter().position(|mod_id| members.contains(mod_id)) {
+                self.mods.retain(|mod_id| !members.contains(mod_id));
+                for (index, mod_id) in members.into_iter().enumerate() {
+                    self.mods.insert(anchor + index, mod_id);
+                }
+            }
+        }
+    }
+
     fn build_movies(&mut self, game_config: &GameConfig, game_data_path: &Path) {
 
         // Movies are still automatic, even in manual mode.
@@ -223,9 +395,12 @@ impl LoadOrder {
     pub fn build_load_order_string(&self, game_config: &GameConfig, game: &GameInfo, game_data_path: &Path, pack_string: &mut String, folder_paths: &mut String) {
         let mut added_secondary_folder = false;
         let secondary_mods_path = secondary_mods_path(game.key()).unwrap_or_else(|_| PathBuf::new());
-        let secondary_mods_masks_path = path_to_absolute_path(&secondary_mods_path.join(SECONDARY_FOLDER_NAME), true);
+        let secondary_mods_masks_path = path_to_absolute_path(&masks_path(game).unwrap_or_else(|_| PathBuf::new()), true);
         let game_data_path = game_data_path.canonicalize().unwrap();
         let mut folder_paths_mods = String::new();
+        let quirks = game_quirks(game);
+        let supports_working_directories = *quirks.supports_working_directories();
+        let supports_masks = *quirks.supports_masks();
 
         for mod_id in self.mods() {
             if let Some(modd) = game_config.mods().get(mod_id) {
@@ -235,16 +410,20 @@ impl LoadOrder {
                     continue;
                 }
 
+                // Mods being developed may be loaded from their loose working folder instead of from their pack.
+                if supports_working_directories {
+                    if let Some(working_folder) = modd.active_working_folder() {
+                        folder_paths_mods.push_str(&format!("add_working_directory \"{}\";\n", path_to_absolute_string(working_folder)));
+                        continue;
+                    }
+                }
+
                 // Check if the mod is from /data, /secondary or /content.
                 //
-                // Loading from content is only supported on Rome2 and later games.
-                //
-                // Loading from secondary is only supported on a fully updated Shogun 2 and later games.
-                //
-                // Also, Shogun 2 requires some custom file management to move and convert mods to /data, but that's not done here.
+                // Loading from outside /data is only supported on games with working directories. See the game quirks.
                 let pack_name = modd.paths()[0].file_name().unwrap().to_string_lossy().as_ref().to_owned();
                 let path = &modd.paths()[0];
-                if !path.starts_with(&game_data_path) && *game.raw_db_version() >= 1 {
+                if !path.starts_with(&game_data_path) && supports_working_directories {
                     let mut folder_path = path_to_absolute_path(path, false);
                     folder_path.pop();
 
@@ -255,7 +434,9 @@ impl LoadOrder {
 
                             // We have to add both, the secondary folder and the masking folder, so movie packs in secondary can be toggled by using masks.
                             folder_paths_mods.insert_str(0, &format!("add_working_directory \"{}\";\n", folder_path_str));
-                            folder_paths_mods.insert_str(0, &format!("add_working_directory \"{}\";\n", secondary_mods_masks_path.to_string_lossy()));
+                            if supports_masks {
+                                folder_paths_mods.insert_str(0, &format!("add_working_directory \"{}\";\n", secondary_mods_masks_path.to_string_lossy()));
+                            }
                             added_secondary_folder = true;
                         }
                     } else {
@@ -277,7 +458,7 @@ impl LoadOrder {
                 if modd.can_be_toggled(&game_data_path) {
 
                     // This only works for Rome 2 and later games.
-                    if *game.raw_db_version() >= 1 {
+                    if supports_working_directories {
                         let mut folder_path = path_to_absolute_path(&modd.paths()[0], false);
                         folder_path.pop();
 
@@ -288,7 +469,9 @@ impl LoadOrder {
 
                                 // We have to add both, the secondary folder and the masking folder, so movie packs in secondary can be toggled by using masks.
                                 folder_paths_mods.insert_str(0, &format!("add_working_directory \"{}\";\n", folder_path_str));
-                                folder_paths_mods.insert_str(0, &format!("add_working_directory \"{}\";\n", secondary_mods_masks_path.to_string_lossy()));
+                                if supports_masks {
+                                    folder_paths_mods.insert_str(0, &format!("add_working_directory \"{}\";\n", secondary_mods_masks_path.to_string_lossy()));
+                                }
                                 added_secondary_folder = true;
                             }
                         } else {
@@ -301,4 +484,63 @@ impl LoadOrder {
 
         folder_paths.push_str(&folder_paths_mods);
     }
+
+    /// This function returns a pack with the data the game loads with this load order: the vanilla packs, and the mods and movie packs on top of them.
+    ///
+    /// Packs that fail to load are left out of it, and returned along with the error they failed with.
+    pub fn data_pack(&self, game_config: &GameConfig, game: &GameInfo, game_path: &Path) -> Result<(Pack, BTreeMap<PathBuf, String>)> {
+
+        // Only load this if the game path is actually a path.
+        if game_path.exists() && game_path.is_dir() {
+
+            // Build the full pack list with the vanilla packs.
+            let vanilla_paths = game.ca_packs_paths(game_path)?;
+            let movie_paths = self.movies().iter()
+                .filter_map(|mod_id| game_config.mods().get(mod_id))
+                .filter_map(|modd| modd.paths().first())
+                .cloned()
+                .collect::<Vec<_>>();
+
+            let mut pack_errors = self.pack_errors.clone();
+            let mut base_packs = vec![];
+            for path in vanilla_paths.iter().chain(movie_paths.iter()) {
+                match Pack::read_and_merge(&[path.to_path_buf()], true, false, false) {
+                    Ok(pack) => base_packs.push(pack),
+                    Err(error) => {
+                        warn!("Error loading pack {}: {}", path.to_string_lossy(), error);
+                        pack_errors.insert(path.to_path_buf(), error.to_string());
+                    }
+                }
+            }
+
+            base_packs.sort_by(|pack_a, pack_b| if pack_a.pfh_file_type() != pack_b.pfh_file_type() {
+                pack_a.pfh_file_type().cmp(&pack_b.pfh_file_type())
+            } else {
+                pack_a.disk_file_path().cmp(pack_b.disk_file_path())
+            });
+
+            // Generate the "merged pack" from the load order mods, and inject them into the full pack list.
+            let mut mod_packs_sorted = self.mods().iter()
+                .filter_map(|mod_id| self.packs().get(mod_id))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            // If we have movie packs in the base ones, insert the mods before the movie packs.
+            //
+            // If not, insert them at the end of the list.
+            if let Some(pos) = base_packs.iter().position(|x| x.pfh_file_type() == PFHFileType::Movie) {
+                let mut movie_packs = base_packs.split_off(pos);
+                base_packs.append(&mut mod_packs_sorted);
+                base_packs.append(&mut movie_packs);
+            } else {
+                base_packs.append(&mut mod_packs_sorted);
+            };
+
+            let full_pack = Pack::merge(&base_packs)?;
+
+            Ok((full_pack, pack_errors))
+        } else {
+            Err(anyhow!("Game Path not found."))
+        }
+    }
 }
@@ -0,0 +1,247 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the per-game patterns used to find script errors in the logs of the games.
+//!
+//! Each game writes its script errors in its own way: the newer ones with clear markers at the start and end of each error,
+//! the older ones as bare lua errors. Instead of hardcoding each format, we keep a list of regexes per game that can be updated from Runcher's repo.
+
+use anyhow::Result;
+use getset::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use rpfm_lib::games::GameInfo;
+use rpfm_lib::integrations::log::*;
+
+use super::remote_lists;
+
+const LOG_PATTERNS_FILE_NAME: &str = "log_patterns.json";
+
+/// Patterns shipped with the program, used if we don't have a downloaded list.
+const LOG_PATTERNS_DEFAULT: &str = include_str!("../../../../log_patterns.json");
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct LogPatterns {
+
+    /// Format of the logs of games without their own.
+    default: LogFormat,
+
+    /// Format of the logs of each game, by game key.
+    games: HashMap<String, LogFormat>,
+}
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct LogFormat {
+
+    /// Regex the names of the log files have to match.
+    log_files: String,
+
+    /// Patterns of the errors to look for. Parts of the log already matched by a pattern are ignored by the following ones.
+    errors: Vec<ErrorPattern>,
+
+    /// Compiled regexes, so they're compiled once instead of once per log.
+    #[serde(skip)]
+    #[getset(skip)]
+    compiled: OnceLock<CompiledLogFormat>,
+}
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct ErrorPattern {
+
+    /// Name of the kind of error. Only used in the logs.
+    name: String,
+
+    /// Regex matching the start of the error.
+    start: String,
+
+    /// Regex matching the end of the error, searched from its start. Errors without end are ignored, as they may still be being written.
+    end: String,
+
+    /// Amount of matches of the end regex to skip before the one ending the error. For errors whose end marker also appears inside them.
+    #[serde(default)]
+    end_skip: usize,
+
+    /// Regex matching errors that should be ignored, like the ones from mods known to trigger them on purpose.
+    #[serde(default)]
+    ignore: Option<String>,
+
+    /// Regex capturing the paths of the scripts involved in the error, in its first group.
+    path: String,
+}
+
+/// Compiled regexes of a log format. Patterns with invalid regexes are left out.
+#[derive(Clone, Debug, Default)]
+struct CompiledLogFormat {
+    log_files: Option<Regex>,
+    errors: Vec<CompiledErrorPattern>,
+}
+
+#[derive(Clone, Debug)]
+struct CompiledErrorPattern {
+    start: Regex,
+    end: Regex,
+    end_skip: usize,
+    ignore: Option<Regex>,
+    path: Regex,
+}
+
+/// Error found in a log.
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct LogError {
+
+    /// Text of the error, as written in the log.
+    message: String,
+
+    /// Paths of the scripts involved in the error, in the order they appear in it. Normalized to use forward slashes.
+    paths: Vec<String>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl LogPatterns {
+
+    /// This function loads the downloaded list of patterns, falling back to the one shipped with the program if it fails.
+    pub fn load() -> Self {
+        remote_lists::load(LOG_PATTERNS_FILE_NAME, Some(LOG_PATTERNS_DEFAULT))
+    }
+
+    /// This function returns if the downloaded list of patterns is missing or outdated.
+    pub fn needs_update() -> bool {
+        remote_lists::needs_update(LOG_PATTERNS_FILE_NAME)
+    }
+
+    /// This function downloads the latest list of patterns. The list is only saved if it's valid.
+    pub fn update() -> Result<()> {
+        remote_lists::update::<Self>(LOG_PATTERNS_FILE_NAME)
+    }
+
+    /// This function returns the format of the logs of the provided game.
+    pub fn game(&self, game: &GameInfo) -> &LogFormat {
+        self.games.get(game.key()).unwrap_or(&self.default)
+    }
+}
+
+impl LogFormat {
+
+    /// This function returns the compiled regexes of this format, compiling them on the first call.
+    ///
+    /// Patterns with invalid regexes are reported and skipped, so a broken pattern doesn't stop the rest from working.
+    fn compiled(&self) -> &CompiledLogFormat {
+        self.compiled.get_or_init(|| CompiledLogFormat {
+            log_files: Regex::new(&self.log_files).ok(),
+            errors: self.errors.iter()
+                .filter_map(|pattern| match (Regex::new(&pattern.start), Regex::new(&pattern.end), Regex::new(&pattern.path)) {
+                    (Ok(start), Ok(end), Ok(path)) => Some(CompiledErrorPattern {
+                        start,
+                        end,
+                        end_skip: pattern.end_skip,
+                        ignore: pattern.ignore.as_ref().and_then(|ignore| Regex::new(ignore).ok()),
+                        path,
+                    }),
+                    _ => {
+                        warn!("Invalid regex in log pattern {}. Skipping it.", pattern.name);
+                        None
+                    }
+                })
+                .collect(),
+        })
+    }
+
+    /// This function returns if the provided file name is one of the logs of this format.
+    pub fn is_log_file(&self, file_name: &str) -> bool {
+        self.compiled().log_files.as_ref().is_some_and(|regex| regex.is_match(file_name))
+    }
+
+    /// This function returns the errors found in the provided log.
+    pub fn errors(&self, log: &str) -> Vec<LogError> {
+        let mut errors = vec![];
+        let mut matched_ranges: Vec<(usize, usize)> = vec![];
+
+        for CompiledErrorPattern { start, end, end_skip, ignore, path } in &self.compiled().errors {
+            for start_match in start.find_iter(log) {
+                let start_error = start_match.start();
+                if matched_ranges.iter().any(|(start, end)| start_error >= *start && start_error < *end) {
+                    continue;
+                }
+
+                let end_error = match end.find_iter(&log[start_error..]).nth(*end_skip) {
+                    Some(end_match) => start_error + end_match.start(),
+                    None => continue,
+                };
+
+                let message = &log[start_error..end_error];
+                if ignore.as_ref().is_some_and(|ignore| ignore.is_match(message)) {
+                    continue;
+                }
+
+                matched_ranges.push((start_error, end_error));
+                errors.push(LogError {
+                    message: message.to_owned(),
+                    paths: path.captures_iter(message)
+                        .filter_map(|captures| captures.get(1))
+                        .map(|path| path.as_str().replace('\\', "/"))
+                        .collect(),
+                });
+            }
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_format(game_key: &str) -> LogFormat {
+        let patterns: LogPatterns = serde_json::from_str(LOG_PATTERNS_DEFAULT).unwrap();
+        patterns.games().get(game_key).cloned().unwrap()
+    }
+
+    #[test]
+    fn lua_errors_skip_traceback_lines() {
+        let log = "[string \"script/campaign/mod/a.lua\"]:10: attempt to index a nil value\n\
+            stack traceback:\n\
+            \t[string \"script/campaign/mod/b.lua\"]:20: in function 'f'\n\
+            \n\
+            Loading finished.\n\
+            stack traceback:\n\
+            \t[string \"script/campaign/mod/c.lua\"]:5: in function 'g'\n\
+            \n";
+
+        for game_key in ["rome_2", "attila"] {
+            let errors = default_format(game_key).errors(log);
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].paths(), &vec!["script/campaign/mod/a.lua".to_owned(), "script/campaign/mod/b.lua".to_owned()]);
+        }
+    }
+
+    #[test]
+    fn log_files_match_after_compiling() {
+        let format = default_format("rome_2");
+        assert!(format.is_log_file("script_log.txt"));
+        assert!(format.is_log_file("SCRIPT_LOG.TXT"));
+        assert!(!format.is_log_file("script_log.log"));
+    }
+}
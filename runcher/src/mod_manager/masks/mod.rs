@@ -0,0 +1,191 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the masks of the secondary mods folder.
+//!
+//! Movie packs in the secondary folder are always loaded by the game if the folder is added as working directory.
+//! To disable them, we put an empty pack with the same name in the masks folder, which the game loads instead of the real one.
+//! If that folder goes out of sync with the enabled mods, content can end up silently hidden, so here we check it and rebuild it.
+
+use anyhow::Result;
+use getset::*;
+
+use std::fs::{read_dir, remove_dir_all, DirBuilder};
+use std::path::{Path, PathBuf};
+
+use rpfm_lib::files::pack::Pack;
+use rpfm_lib::games::{GameInfo, pfh_file_type::PFHFileType};
+
+use super::{SECONDARY_FOLDER_NAME, secondary_mods_path};
+use super::game_config::GameConfig;
+
+use crate::settings_ui::generated_masks_folder;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// Status of the masks folder of a game, compared with what it should contain.
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct MasksReport {
+
+    /// Packs masked in the masks folder, with the reason they're masked.
+    masks: Vec<(String, MaskReason)>,
+
+    /// Disabled movie packs in the secondary folder without a mask.
+    missing: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MaskReason {
+
+    /// The pack is a disabled movie pack in the secondary folder. These are the only packs that should be masked.
+    DisabledMovie,
+
+    /// The pack is enabled, so the mask is hiding it from the game.
+    Enabled,
+
+    /// The pack is no longer a movie pack in the secondary folder, so the mask does nothing useful.
+    Orphaned,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl MasksReport {
+
+    /// This function returns if the masks folder contains exactly what it should.
+    pub fn is_consistent(&self) -> bool {
+        self.missing.is_empty() && self.masks.iter().all(|(_, reason)| *reason == MaskReason::DisabledMovie)
+    }
+
+    /// This function returns the masks that shouldn't be in the masks folder, with the reason why.
+    pub fn stale(&self) -> Vec<&(String, MaskReason)> {
+        self.masks.iter()
+            .filter(|(_, reason)| *reason != MaskReason::DisabledMovie)
+            .collect()
+    }
+}
+
+impl MaskReason {
+
+    /// This function returns the locale key of the explanation of this reason.
+    pub fn locale_key(&self) -> &'static str {
+        match self {
+            Self::DisabledMovie => "mask_reason_disabled_movie",
+            Self::Enabled => "mask_reason_enabled",
+            Self::Orphaned => "mask_reason_orphaned",
+        }
+    }
+}
+
+/// This function returns the path of the masks folder of the provided game.
+///
+/// If the user configured a folder for generated files, masks go there. Otherwise, they go in the secondary folder.
+pub fn masks_path(game: &GameInfo) -> Result<PathBuf> {
+    match generated_masks_folder(game) {
+        Some(path) => Ok(path),
+        None => Ok(secondary_mods_path(game.key())?.join(SECONDARY_FOLDER_NAME)),
+    }
+}
+
+/// This function compares the masks folder of the provided game with the movie packs in the secondary folder.
+///
+/// If the game has no secondary folder, the report is empty.
+pub fn verify_masks(game: &GameInfo, game_config: &GameConfig, data_path: &Path) -> Result<MasksReport> {
+    let secondary_mods_path = match secondary_mods_path(game.key()) {
+        Ok(path) if path.is_dir() => path,
+        _ => return Ok(MasksReport::default()),
+    };
+
+    let expected = expected_masks(game_config, data_path, &secondary_mods_path)?;
+    let masks_path = masks_path(game)?;
+    let existing = if masks_path.is_dir() {
+        file_names(&masks_path)?
+    } else {
+        vec![]
+    };
+
+    let masks = existing.iter()
+        .map(|file_name| {
+            let reason = if expected.contains(file_name) {
+                MaskReason::DisabledMovie
+            } else if game_config.mods().get(file_name).is_some_and(|modd| modd.enabled(data_path)) {
+                MaskReason::Enabled
+            } else {
+                MaskReason::Orphaned
+            };
+
+            (file_name.to_owned(), reason)
+        })
+        .collect();
+
+    let missing = expected.into_iter()
+        .filter(|file_name| !existing.contains(file_name))
+        .collect();
+
+    Ok(MasksReport {
+        masks,
+        missing,
+    })
+}
+
+/// This function regenerates the masks folder of the provided game, masking all disabled movie packs in the secondary folder.
+///
+/// If the game has no secondary folder, this does nothing.
+pub fn rebuild_masks(game: &GameInfo, game_config: &GameConfig, data_path: &Path) -> Result<()> {
+    let secondary_mods_path = match secondary_mods_path(game.key()) {
+        Ok(path) if path.is_dir() => path,
+        _ => return Ok(()),
+    };
+
+    let masks_path = masks_path(game)?;
+
+    // Remove all files in it so previous maskings do not interfere.
+    if masks_path.is_dir() {
+        remove_dir_all(&masks_path)?;
+    }
+
+    DirBuilder::new().recursive(true).create(&masks_path)?;
+
+    let mut mask_pack = Pack::new_with_version(game.pfh_version_by_file_type(PFHFileType::Movie));
+    mask_pack.set_pfh_file_type(PFHFileType::Movie);
+
+    for file_name in expected_masks(game_config, data_path, &secondary_mods_path)? {
+        mask_pack.save(Some(&masks_path.join(file_name)), game, &None)?;
+    }
+
+    Ok(())
+}
+
+/// This function returns the names of the packs in the secondary folder that need a mask.
+fn expected_masks(game_config: &GameConfig, data_path: &Path, secondary_mods_path: &Path) -> Result<Vec<String>> {
+    Ok(file_names(secondary_mods_path)?
+        .into_iter()
+        .filter(|file_name| game_config.mods()
+            .get(file_name)
+            .is_some_and(|modd| modd.pack_type() == &PFHFileType::Movie && !modd.enabled(data_path))
+        )
+        .collect())
+}
+
+fn file_names(path: &Path) -> Result<Vec<String>> {
+    let mut file_names = vec![];
+    for entry in read_dir(path)? {
+        let entry = entry?;
+        if entry.path().is_file() {
+            file_names.push(entry.file_name().to_string_lossy().to_string());
+        }
+    }
+
+    Ok(file_names)
+}
@@ -0,0 +1,128 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for managing Mod Configuration Tool (MCT) presets.
+//!
+//! MCT keeps its settings in lua files within the scripts folder of the game's config folder.
+//! A preset is just a copy of those files, stored in Runcher's config folder under a name.
+
+use anyhow::{anyhow, Result};
+
+use std::fs::DirBuilder;
+use std::path::{Path, PathBuf};
+
+use rpfm_lib::games::{GameInfo, supported_games::KEY_WARHAMMER_3};
+use rpfm_lib::utils::files_from_subdir;
+
+use rpfm_ui_common::settings::config_path;
+
+use crate::settings_ui::game_appdata_path;
+
+use super::profiles::is_valid_id;
+
+const MCT_PRESETS_FOLDER: &str = "mct_presets";
+const MCT_SETTINGS_FILE_NAME_START: &str = "mct_";
+const MCT_SETTINGS_FILE_NAME_END: &str = ".lua";
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function returns if the game provided supports MCT presets.
+pub fn supports_mct_presets(game: &GameInfo) -> bool {
+    game.key() == KEY_WARHAMMER_3
+}
+
+/// This function returns the folder where MCT keeps its settings files, if the game supports it.
+pub fn mct_settings_folder(game: &GameInfo, game_path: &Path) -> Option<PathBuf> {
+    if supports_mct_presets(game) {
+        game_appdata_path(game, game_path).map(|path| path.join("scripts"))
+    } else {
+        None
+    }
+}
+
+/// This function returns the paths of the MCT settings files currently in use for the game.
+pub fn mct_settings_files(game: &GameInfo, game_path: &Path) -> Vec<PathBuf> {
+    match mct_settings_folder(game, game_path) {
+        Some(folder) => files_from_subdir(&folder, false)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|path| {
+                let file_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+                file_name.starts_with(MCT_SETTINGS_FILE_NAME_START) && file_name.ends_with(MCT_SETTINGS_FILE_NAME_END)
+            })
+            .collect(),
+        None => vec![],
+    }
+}
+
+/// This function returns the folder where Runcher keeps the MCT presets for the game.
+pub fn mct_presets_path(game: &GameInfo) -> Result<PathBuf> {
+    Ok(config_path()?.join(MCT_PRESETS_FOLDER).join(game.key()))
+}
+
+/// This function saves the MCT settings currently in use for the game as a preset with the provided name.
+///
+/// If a preset with the same name exists, it gets replaced.
+pub fn save_mct_preset(game: &GameInfo, game_path: &Path, name: &str) -> Result<()> {
+    let files = mct_settings_files(game, game_path);
+    if files.is_empty() {
+        return Err(anyhow!("No MCT settings files found for the game selected."));
+    }
+
+    let preset_path = preset_path(game, name)?;
+    if preset_path.is_dir() {
+        std::fs::remove_dir_all(&preset_path)?;
+    }
+
+    DirBuilder::new().recursive(true).create(&preset_path)?;
+
+    for file in &files {
+        if let Some(file_name) = file.file_name() {
+            std::fs::copy(file, preset_path.join(file_name))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// This function replaces the MCT settings currently in use for the game with the ones in the preset with the provided name.
+pub fn apply_mct_preset(game: &GameInfo, game_path: &Path, name: &str) -> Result<()> {
+    let settings_folder = mct_settings_folder(game, game_path).ok_or_else(|| anyhow!("The game selected doesn't support MCT presets."))?;
+    let preset_path = preset_path(game, name)?;
+    if !preset_path.is_dir() {
+        return Err(anyhow!("MCT preset {} not found.", name));
+    }
+
+    // Remove the current settings first, so files not in the preset do not linger around.
+    for file in &mct_settings_files(game, game_path) {
+        std::fs::remove_file(file)?;
+    }
+
+    DirBuilder::new().recursive(true).create(&settings_folder)?;
+
+    for file in &files_from_subdir(&preset_path, false)? {
+        if let Some(file_name) = file.file_name() {
+            std::fs::copy(file, settings_folder.join(file_name))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Presets are named after the profile they belong to, so their names are validated like profile ids, to keep them within the presets folder.
+fn preset_path(game: &GameInfo, name: &str) -> Result<PathBuf> {
+    if !is_valid_id(name) {
+        return Err(anyhow!("Invalid MCT preset name: {}.", name));
+    }
+
+    Ok(mct_presets_path(game)?.join(name))
+}
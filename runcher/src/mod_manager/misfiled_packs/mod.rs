@@ -0,0 +1,133 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code to detect and fix misfiled movie packs.
+//!
+//! Some mods are uploaded with a Movie header instead of a Mod one. The game always loads movie packs, ignoring the load order,
+//! which breaks any mod expected to load after or before them. These can be overridden to be treated as mod packs,
+//! in which case we keep a copy of them with the correct header in the secondary folder, which takes priority over the original.
+
+use anyhow::{anyhow, Result};
+
+use std::path::{Path, PathBuf};
+
+use rpfm_lib::files::pack::Pack;
+use rpfm_lib::games::{GameInfo, pfh_file_type::PFHFileType};
+use rpfm_lib::utils::path_to_absolute_string;
+
+use rpfm_ui_common::settings::setting_path;
+
+use super::game_config::GameConfig;
+use super::mods::Mod;
+use super::secondary_mods_path;
+
+/// Folders that only mod packs should have. Movie packs containing files in these are most likely mods with the wrong header.
+const MOD_ONLY_FOLDERS: [&str; 3] = ["db/", "script/", "text/"];
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function returns if the pack at the provided path is a movie pack that looks like a mod pack.
+pub fn is_misfiled_movie_pack(path: &Path) -> bool {
+    match Pack::read_and_merge(&[path.to_path_buf()], true, false, false) {
+        Ok(pack) => is_misfiled_movie(&pack),
+        Err(_) => false,
+    }
+}
+
+/// This function returns if the provided pack is a movie pack that looks like a mod pack.
+pub fn is_misfiled_movie(pack: &Pack) -> bool {
+    pack.pfh_file_type() == PFHFileType::Movie && pack.files()
+        .keys()
+        .any(|path| {
+            let path = path.to_lowercase();
+            MOD_ONLY_FOLDERS.iter().any(|folder| path.starts_with(folder))
+        })
+}
+
+/// This function sets or removes the "treat as mod pack" override of the provided mods, returning the ones it failed for.
+///
+/// Only mods outside /data can be overridden, as the game always loads movie packs in /data. Removing the override deletes our copy of the pack.
+/// The copies themselves are generated on the next reload of the mod list.
+pub fn set_treat_as_mod_pack(game: &GameInfo, game_config: &mut GameConfig, mod_ids: &[String], treat_as_mod_pack: bool) -> Result<Vec<String>> {
+    let mut mods_failed = vec![];
+
+    let game_path = setting_path(game.key());
+    let data_path = path_to_absolute_string(&game.data_path(&game_path)?);
+    let secondary_path = secondary_mods_path(game.key())?;
+
+    for mod_id in mod_ids {
+        if let Some(modd) = game_config.mods_mut().get_mut(mod_id) {
+            if treat_as_mod_pack {
+                if modd.paths().iter().any(|path| path_to_absolute_string(path).starts_with(&data_path)) {
+                    mods_failed.push(modd.id().to_owned());
+                } else {
+                    modd.set_treat_as_mod_pack(true);
+                }
+            } else if *modd.treat_as_mod_pack() {
+                modd.set_treat_as_mod_pack(false);
+
+                // Only remove our copy if the original is still there.
+                let repacked_path = secondary_path.join(modd.id());
+                if original_path(modd, &secondary_path).is_some() && repacked_path.is_file() && std::fs::remove_file(&repacked_path).is_err() {
+                    mods_failed.push(modd.id().to_owned());
+                }
+            }
+        }
+    }
+
+    game_config.save(game)?;
+
+    Ok(mods_failed)
+}
+
+/// This function makes sure the copies of the mods overridden to be treated as mod packs are up-to-date with their originals.
+///
+/// Mods whose copy fails to be generated are returned.
+pub fn update_repacked_movie_packs(game: &GameInfo, game_config: &GameConfig, secondary_path: &Path) -> Vec<String> {
+    game_config.mods()
+        .values()
+        .filter(|modd| *modd.treat_as_mod_pack())
+        .filter(|modd| repack_as_mod_pack(game, modd, secondary_path).is_err())
+        .map(|modd| modd.id().to_owned())
+        .collect()
+}
+
+/// This function saves a copy of the provided mod in the secondary folder with a Mod header, if it doesn't exist or it's outdated.
+///
+/// Mods only in the secondary folder have their header fixed in place.
+fn repack_as_mod_pack(game: &GameInfo, modd: &Mod, secondary_path: &Path) -> Result<()> {
+    let repacked_path = secondary_path.join(modd.id());
+    let source_path = original_path(modd, secondary_path)
+        .or_else(|| modd.paths().first())
+        .ok_or_else(|| anyhow!("No pack found for mod {}.", modd.id()))?;
+
+    if source_path != &repacked_path && repacked_path.is_file() && repacked_path.metadata()?.modified()? >= source_path.metadata()?.modified()? {
+        return Ok(());
+    }
+
+    let mut pack = Pack::read_and_merge(&[source_path.to_path_buf()], true, false, false)?;
+    if pack.pfh_file_type() != PFHFileType::Mod || source_path != &repacked_path {
+        pack.set_pfh_file_type(PFHFileType::Mod);
+        pack.save(Some(&repacked_path), game, &None)?;
+    }
+
+    Ok(())
+}
+
+/// This function returns the path of the provided mod outside the secondary folder, if any.
+fn original_path<'a>(modd: &'a Mod, secondary_path: &Path) -> Option<&'a PathBuf> {
+    let secondary_path_str = path_to_absolute_string(secondary_path);
+    modd.paths()
+        .iter()
+        .rev()
+        .find(|path| !path_to_absolute_string(path).starts_with(&secondary_path_str))
+}
@@ -28,15 +28,99 @@ use crate::SUPPORTED_GAMES;
 
 use self::game_config::GameConfig;
 
+pub mod authored_packs;
+pub mod backups;
+pub mod bughunt;
+pub mod ca_launcher;
+pub mod campaign_safety;
+pub mod category_rules;
+pub mod config_recovery;
+pub mod conflicts;
+pub mod diagnostic_bundle;
+pub mod duplicates;
+pub mod env_vars;
+pub mod fingerprint;
 pub mod game_config;
+pub mod game_definitions;
+pub mod game_families;
+pub mod game_quirks;
+pub mod game_updates;
+pub mod hooks;
+pub mod install;
 pub mod integrations;
+pub mod launch;
+pub mod lint;
 pub mod load_order;
+pub mod log_patterns;
+pub mod masks;
+pub mod mct;
+pub mod misfiled_packs;
+pub mod mod_blacklist;
 pub mod mods;
+pub mod name_matching;
+pub mod offline;
+pub mod other_managers;
+pub mod pack_extract;
+pub mod pack_scan_cache;
+pub mod pack_versions;
 pub mod profiles;
+pub mod remote_lists;
+pub mod report;
+pub mod requirements;
+pub mod save_backups;
 pub mod saves;
+pub mod shadowed_packs;
+pub mod simulation;
+pub mod script_breaks;
+pub mod secondary_staging;
+pub mod sessions;
+pub mod translations;
+pub mod undo;
+pub mod user_script;
+pub mod workshop_monitor;
 
 pub const SECONDARY_FOLDER_NAME: &str = "masks";
 
+/// Name of the pack generated when merging all mods into one.
+pub const MERGE_ALL_PACKS_PACK_NAME: &str = "merge_me_sideways_honey";
+
+/// Name of the reserved pack without the prefix used to force its position in the load order. Older versions used longer or shorter prefixes.
+const RESERVED_PACK_BASE_NAME: &str = "run_you_fool_thron.pack";
+
+/// Parts of the names of packs generated by other tools: RPFM's reserved packs and Kaedrin Mod Manager's merged packs.
+const FOREIGN_GENERATED_PACK_MARKERS: [&str; 2] = ["rpfm_reserved", "kmm_merge"];
+
+/// This function returns if the provided pack was generated from other packs, either by Runcher or by another tool.
+///
+/// Loading these alongside the packs they were generated from loads the same content twice, so they're hidden from the mod list unless the user wants to see them.
+pub fn is_generated_pack(pack_name: &str) -> bool {
+    let pack_name = pack_name.to_lowercase();
+    pack_name.trim_start_matches(['z', '!']) == RESERVED_PACK_BASE_NAME ||
+        pack_name.starts_with(MERGE_ALL_PACKS_PACK_NAME) ||
+        FOREIGN_GENERATED_PACK_MARKERS.iter().any(|marker| pack_name.contains(marker))
+}
+
+/// This function refreshes the secondary copies of the provided mods from their Workshop copies.
+///
+/// Only mods with a copy in both, secondary and content, can be refreshed. Returns the mods that couldn't be refreshed.
+pub fn refresh_secondary_copies(game: &GameInfo, game_config: &GameConfig, mod_ids: &[String]) -> Result<Vec<String>> {
+    let game_path = setting_path(game.key());
+    let secondary_path_str = path_to_absolute_string(&secondary_mods_path(game.key())?);
+    let content_path_str = path_to_absolute_string(&path_to_absolute_path(&game.content_path(&game_path)?, true));
+
+    let (refreshable, mut mods_failed): (Vec<String>, Vec<String>) = mod_ids.iter()
+        .cloned()
+        .partition(|mod_id| match game_config.mods().get(mod_id) {
+            Some(modd) => modd.paths().len() == 2 &&
+                path_to_absolute_string(&modd.paths()[0]).starts_with(&secondary_path_str) &&
+                path_to_absolute_string(&modd.paths()[1]).starts_with(&content_path_str),
+            None => false,
+        });
+
+    mods_failed.append(&mut copy_to_secondary(game, game_config, &refreshable)?);
+    Ok(mods_failed)
+}
+
 pub fn copy_to_secondary(game: &GameInfo, game_config: &GameConfig, mod_ids: &[String]) -> Result<Vec<String>> {
     let mut mods_failed = vec![];
 
@@ -105,6 +189,40 @@ pub fn copy_to_secondary(game: &GameInfo, game_config: &GameConfig, mod_ids: &[S
     Ok(mods_failed)
 }
 
+/// This function marks the provided Workshop mods to have their updates deferred, or removes the mark.
+///
+/// Deferred mods are loaded from a frozen copy in the secondary folder, so Steam updating them mid-campaign doesn't change what the game loads.
+/// When deferring, the copy is made if it doesn't exist yet. When undeferring, the copy is refreshed with the latest Workshop version.
+/// Returns the mods that couldn't be changed.
+pub fn set_defer_updates(game: &GameInfo, game_config: &mut GameConfig, mod_ids: &[String], defer: bool) -> Result<Vec<String>> {
+    let game_path = setting_path(game.key());
+    let secondary_path_str = path_to_absolute_string(&secondary_mods_path(game.key())?);
+    let content_path_str = path_to_absolute_string(&path_to_absolute_path(&game.content_path(&game_path)?, true));
+
+    let (workshop_mods, mut mods_failed): (Vec<String>, Vec<String>) = mod_ids.iter()
+        .cloned()
+        .partition(|mod_id| game_config.mods().get(mod_id).map(|modd| modd.steam_id().is_some()).unwrap_or(false));
+
+    let mut to_copy = vec![];
+    for mod_id in &workshop_mods {
+        if let Some(modd) = game_config.mods_mut().get_mut(mod_id) {
+            modd.set_defer_updates(defer);
+
+            // Only copy the ones that don't have a frozen copy yet. The rest keep the one they have.
+            let without_copy = modd.paths().len() == 1 && path_to_absolute_string(&modd.paths()[0]).starts_with(&content_path_str);
+            let with_copy = modd.paths().len() == 2 && path_to_absolute_string(&modd.paths()[0]).starts_with(&secondary_path_str);
+            if (defer && without_copy) || (!defer && with_copy) {
+                to_copy.push(mod_id.to_owned());
+            }
+        }
+    }
+
+    mods_failed.append(&mut copy_to_secondary(game, game_config, &to_copy)?);
+    game_config.save(game)?;
+
+    Ok(mods_failed)
+}
+
 pub fn move_to_secondary(game: &GameInfo, game_config: &GameConfig, mod_ids: &[String]) -> Result<Vec<String>> {
     let mut mods_failed = vec![];
 
@@ -157,7 +275,7 @@ pub fn move_to_secondary(game: &GameInfo, game_config: &GameConfig, mod_ids: &[S
 
 pub fn secondary_mods_path(game: &str) -> Result<PathBuf> {
     match SUPPORTED_GAMES.game(game) {
-        Some(game_info) => if game_info.raw_db_version() < &1 {
+        Some(game_info) => if !game_quirks::game_quirks(game_info).supports_working_directories() {
             return Err(anyhow!("This game ({}) doesn't support secondary mod folders.", game))
         }
         None => return Err(anyhow!("What kind of game is {}?", game)),
@@ -0,0 +1,253 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the blacklist of mods with known issues.
+//!
+//! The blacklist lists, per game, mods known to be broken with the current version of the game, or incompatible with other mods.
+//! There are two lists: a community-maintained one that can be updated from Runcher's repo, and one with the user's own entries.
+//! The user's list also keeps the entries the user has silenced, so they're no longer reported.
+
+use anyhow::Result;
+use getset::*;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_reader, to_string_pretty};
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
+
+use rpfm_ui_common::settings::config_path;
+
+use super::remote_lists;
+use super::report::escape_html;
+
+const MOD_BLACKLIST_FILE_NAME: &str = "mod_blacklist.json";
+const MOD_BLACKLIST_USER_FILE_NAME: &str = "mod_blacklist_user.json";
+
+/// Blacklist shipped with the program, used if we don't have a downloaded list.
+const MOD_BLACKLIST_DEFAULT: &str = include_str!("../../../../mod_blacklist.json");
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct ModBlacklist {
+
+    /// Mods with known issues.
+    entries: Vec<BlacklistEntry>,
+
+    /// Keys of the entries the user doesn't want to be warned about. Only used in the user's list.
+    #[serde(default)]
+    silenced: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct BlacklistEntry {
+
+    /// Key of the game the entry is for.
+    game: String,
+
+    /// Id of the mod with the issue.
+    mod_id: String,
+
+    /// Id of the mod it's incompatible with. If none, the mod is broken on its own.
+    #[serde(default)]
+    incompatible_with: Option<String>,
+
+    /// Explanation of the issue.
+    reason: String,
+
+    /// Link to a discussion about the issue, if any.
+    #[serde(default)]
+    link: Option<String>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl ModBlacklist {
+
+    /// This function loads the downloaded blacklist, falling back to the one shipped with the program if it fails.
+    pub fn load() -> Self {
+        remote_lists::load(MOD_BLACKLIST_FILE_NAME, Some(MOD_BLACKLIST_DEFAULT))
+    }
+
+    /// This function loads the user's blacklist. If there's none, it returns an empty one.
+    pub fn load_user() -> Self {
+        mod_blacklist_user_path().ok()
+            .and_then(|path| File::open(path).ok())
+            .and_then(|file| from_reader(BufReader::new(file)).ok())
+            .unwrap_or_default()
+    }
+
+    /// This function loads both blacklists, merged into one. Entries in both lists are only kept once.
+    pub fn load_merged() -> Self {
+        let mut blacklist = Self::load();
+        let user = Self::load_user();
+
+        for entry in user.entries {
+            if !blacklist.entries.contains(&entry) {
+                blacklist.entries.push(entry);
+            }
+        }
+
+        blacklist.silenced = user.silenced;
+        blacklist
+    }
+
+    /// This function saves the blacklist as the user's one.
+    pub fn save_user(&self) -> Result<()> {
+        let mut file = BufWriter::new(File::create(mod_blacklist_user_path()?)?);
+        file.write_all(to_string_pretty(self)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function returns if the downloaded blacklist is missing or outdated.
+    pub fn needs_update() -> bool {
+        remote_lists::needs_update(MOD_BLACKLIST_FILE_NAME)
+    }
+
+    /// This function downloads the latest blacklist. The list is only saved if it's valid.
+    pub fn update() -> Result<()> {
+        remote_lists::update::<Self>(MOD_BLACKLIST_FILE_NAME)
+    }
+
+    /// This function returns the entries of the provided game affecting the provided mods, ignoring the silenced ones.
+    ///
+    /// Incompatibilities are only returned if both mods are in the list.
+    pub fn issues(&self, game_key: &str, mod_ids: &[String]) -> Vec<&BlacklistEntry> {
+        self.entries.iter()
+            .filter(|entry| entry.game == game_key)
+            .filter(|entry| !self.silenced.contains(&entry.key()))
+            .filter(|entry| mod_ids.contains(&entry.mod_id))
+            .filter(|entry| entry.incompatible_with.as_ref().map(|mod_id| mod_ids.contains(mod_id)).unwrap_or(true))
+            .collect()
+    }
+
+    /// This function returns the same as [ModBlacklist::issues], grouped by the mod they affect.
+    ///
+    /// Incompatibilities affect both mods, so they're returned for both.
+    pub fn issues_by_mod(&self, game_key: &str, mod_ids: &[String]) -> HashMap<String, Vec<BlacklistEntry>> {
+        let mut issues: HashMap<String, Vec<BlacklistEntry>> = HashMap::new();
+        for entry in self.issues(game_key, mod_ids) {
+            issues.entry(entry.mod_id.to_owned()).or_default().push(entry.clone());
+
+            if let Some(ref mod_id) = entry.incompatible_with {
+                issues.entry(mod_id.to_owned()).or_default().push(entry.clone());
+            }
+        }
+
+        issues
+    }
+}
+
+impl BlacklistEntry {
+
+    pub fn new(game: &str, mod_id: &str, incompatible_with: Option<String>, reason: &str, link: Option<String>) -> Self {
+        Self {
+            game: game.to_owned(),
+            mod_id: mod_id.to_owned(),
+            incompatible_with,
+            reason: reason.to_owned(),
+            link,
+        }
+    }
+
+    /// This function returns the key used to silence the entry. Entries have no id, so it's built from the game and mods they affect.
+    pub fn key(&self) -> String {
+        match self.incompatible_with {
+            Some(ref mod_id) => format!("{}|{}|{}", self.game, self.mod_id, mod_id),
+            None => format!("{}|{}", self.game, self.mod_id),
+        }
+    }
+
+    /// This function returns a short, human-readable description of the issue, as seen from the provided mod, as rich text.
+    ///
+    /// Entries come from remote lists, so their text is escaped.
+    pub fn description(&self, mod_id: &str) -> String {
+        let mut description = match self.incompatible_with {
+            Some(ref incompatible_with) => {
+                let other = if incompatible_with == mod_id { &self.mod_id } else { incompatible_with };
+                format!("Incompatible with {}: {}", escape_html(other), escape_html(&self.reason))
+            }
+            None => escape_html(&self.reason),
+        };
+
+        if let Some(ref link) = self.link {
+            let link = escape_html(link);
+            description.push_str(&format!(" <a href=\"{}\">{}</a>", link, link));
+        }
+
+        description
+    }
+}
+
+/// This function returns the path of the user's blacklist.
+pub fn mod_blacklist_user_path() -> Result<PathBuf> {
+    Ok(config_path()?.join(MOD_BLACKLIST_USER_FILE_NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blacklist() -> ModBlacklist {
+        let mut blacklist = ModBlacklist::default();
+        blacklist.entries_mut().push(BlacklistEntry::new("warhammer_3", "broken.pack", None, "Crashes on startup.", None));
+        blacklist.entries_mut().push(BlacklistEntry::new("warhammer_3", "a.pack", Some("b.pack".to_owned()), "Both replace the same script.", None));
+        blacklist.entries_mut().push(BlacklistEntry::new("warhammer_2", "other_game.pack", None, "Broken since the last patch.", None));
+        blacklist
+    }
+
+    #[test]
+    fn issues_only_for_mods_of_the_game() {
+        let mod_ids = vec!["broken.pack".to_owned(), "a.pack".to_owned(), "other_game.pack".to_owned()];
+        let issues = blacklist().issues("warhammer_3", &mod_ids).into_iter().cloned().collect::<Vec<_>>();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].mod_id(), "broken.pack");
+    }
+
+    #[test]
+    fn incompatibilities_need_both_mods() {
+        let blacklist = blacklist();
+        assert!(blacklist.issues("warhammer_3", &["a.pack".to_owned()]).is_empty());
+
+        let issues = blacklist.issues_by_mod("warhammer_3", &["a.pack".to_owned(), "b.pack".to_owned()]);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues["a.pack"].len(), 1);
+        assert_eq!(issues["b.pack"].len(), 1);
+    }
+
+    #[test]
+    fn silenced_entries_are_ignored() {
+        let mut blacklist = blacklist();
+        let key = blacklist.entries()[0].key();
+        blacklist.silenced_mut().push(key);
+        assert!(blacklist.issues("warhammer_3", &["broken.pack".to_owned()]).is_empty());
+    }
+
+    #[test]
+    fn description_is_escaped() {
+        let entry = BlacklistEntry::new("warhammer_3", "broken.pack", None, "Breaks <b>everything</b>.", Some("https://example.com/?a=1&b=\"2\"".to_owned()));
+        assert_eq!(entry.description("broken.pack"), "Breaks &lt;b&gt;everything&lt;/b&gt;. <a href=\"https://example.com/?a=1&amp;b=&quot;2&quot;\">https://example.com/?a=1&amp;b=&quot;2&quot;</a>");
+    }
+
+    #[test]
+    fn description_from_both_mods() {
+        let entry = blacklist().entries()[1].clone();
+        assert_eq!(entry.description("a.pack"), "Incompatible with b.pack: Both replace the same script.");
+        assert_eq!(entry.description("b.pack"), "Incompatible with a.pack: Both replace the same script.");
+    }
+}
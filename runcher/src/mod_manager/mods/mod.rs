@@ -14,9 +14,14 @@ use serde::{Deserialize, Serialize};
 use sha256::try_digest;
 
 use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use rpfm_lib::{games::pfh_file_type::PFHFileType, utils::path_to_absolute_string};
 
+use super::campaign_safety::CampaignSafety;
+use super::integrations::{GitHubSource, PublishedFileVisibilityDerive, WorkshopItemState};
+use super::pack_versions::PackGameVersion;
+
 pub mod versions;
 
 //-------------------------------------------------------------------------------//
@@ -71,6 +76,62 @@ pub struct Mod {
 
     /// Time the mod was last updated on the workshop.
     time_updated: usize,
+
+    /// Tags of the mod in the workshop. Defaulted so configs saved before tags were stored still load.
+    #[serde(default)]
+    tags: Vec<String>,
+
+    /// If this mod is a movie pack the user wants loaded as a mod pack, through a copy with a Mod header in the secondary folder.
+    #[serde(default)]
+    treat_as_mod_pack: bool,
+
+    /// If this mod is a movie pack with contents only mod packs should have. Calculated on each scan.
+    #[serde(skip)]
+    misfiled_movie_pack: bool,
+
+    /// Version data of the first pack of the mod, used to tell which version of the game it was made for. Calculated on each scan.
+    #[serde(skip)]
+    game_version: PackGameVersion,
+
+    /// If the first pack of the mod can be added to an ongoing campaign. Calculated on each scan.
+    #[serde(skip)]
+    campaign_safety: CampaignSafety,
+
+    /// GitHub release this mod is downloaded from, if it's not from the workshop.
+    #[serde(default)]
+    github_source: Option<GitHubSource>,
+
+    /// Download/update state of the mod in Steam, as reported on the last Workshop data request.
+    #[serde(skip)]
+    workshop_state: WorkshopItemState,
+
+    /// Visibility of the mod in the workshop, as reported on the last Workshop data request. None if we don't know it.
+    #[serde(default)]
+    visibility: Option<PublishedFileVisibilityDerive>,
+
+    /// If the user wants Workshop updates of this mod kept away from the game, through a frozen copy in the secondary folder.
+    #[serde(default)]
+    defer_updates: bool,
+
+    /// If the user wants this mod hidden from the mod list and kept out of the checks. Mods are disabled when ignored.
+    #[serde(default)]
+    ignored: bool,
+
+    /// Loose folder the game loads instead of the pack, so mod developers can test their files without repacking them.
+    #[serde(default)]
+    working_folder: Option<PathBuf>,
+
+    /// If the working folder is loaded instead of the pack.
+    #[serde(default)]
+    use_working_folder: bool,
+
+    /// Time the mod first appeared in the mod list, in seconds since the unix epoch. 0 if we don't know it.
+    #[serde(default)]
+    first_seen: u64,
+
+    /// Time the mod was last enabled, in seconds since the unix epoch. 0 if it was never enabled since we started tracking it.
+    #[serde(default)]
+    last_enabled: u64,
 }
 
 #[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
@@ -185,6 +246,35 @@ impl Mod {
         Ok((data_older_than_secondary, data_older_than_content, secondary_older_than_content))
     }
 
+    /// Returns the last modification date of each copy of the mod on disk, in seconds since the unix epoch.
+    ///
+    /// Copies which date cannot be read are skipped.
+    pub fn copies_dates(&self) -> Vec<(PathBuf, u64)> {
+        self.paths()
+            .iter()
+            .filter_map(|path| {
+                let modified = path.metadata().ok()?.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+                Some((path.to_path_buf(), modified.as_secs()))
+            })
+            .collect()
+    }
+
+    /// Returns if the secondary copy of the mod is stale, meaning it was copied before the latest update of the mod in the Workshop and never refreshed.
+    pub fn secondary_is_stale(&self, secondary_path: &str, content_path: &str) -> bool {
+        if secondary_path.is_empty() || content_path.is_empty() || self.time_updated == 0 {
+            return false;
+        }
+
+        let copies = self.copies_dates();
+        let has_content = copies.iter().any(|(path, _)| path_to_absolute_string(path).starts_with(content_path));
+        let secondary_date = copies.iter().find(|(path, _)| path_to_absolute_string(path).starts_with(secondary_path)).map(|(_, date)| *date);
+
+        match secondary_date {
+            Some(date) => has_content && date < self.time_updated as u64,
+            None => false,
+        }
+    }
+
     /// Returns if the mod is enabled or not.
     pub fn enabled(&self, data_path: &Path) -> bool {
 
@@ -212,9 +302,19 @@ impl Mod {
     }
 
     pub fn set_enabled(&mut self, enabled: bool) {
+        if enabled && !self.enabled {
+            self.last_enabled = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_secs()).unwrap_or_default();
+        }
+
         self.enabled = enabled;
     }
 
+    /// Returns if the mod first appeared in the mod list less than the provided amount of days ago.
+    pub fn recently_added(&self, days: u64) -> bool {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_secs()).unwrap_or_default();
+        self.first_seen != 0 && days != 0 && now.saturating_sub(self.first_seen) < days * 24 * 60 * 60
+    }
+
     pub fn can_be_toggled(&self, data_path: &Path) -> bool {
         if self.pack_type == PFHFileType::Mod {
             true
@@ -231,6 +331,11 @@ impl Mod {
         }
     }
 
+    /// This function returns the working folder to load instead of the pack, if the user enabled it and it still exists.
+    pub fn active_working_folder(&self) -> Option<&PathBuf> {
+        self.working_folder.as_ref().filter(|path| self.use_working_folder && path.is_dir())
+    }
+
     /// Function to get the alternative name for Shogun 2 map binaries.
     pub fn alt_name(&self) -> Option<String> {
         if !self.file_name().is_empty() && !self.file_name().ends_with(".pack") {
@@ -53,6 +53,7 @@ impl From<&ModV4> for ModV5 {
             time_created: value.time_created,
             time_updated: value.time_updated,
             pack_type: PFHFileType::Mod,
+            ..Default::default()
         }
     }
 }
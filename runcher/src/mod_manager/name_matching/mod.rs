@@ -0,0 +1,283 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the matching of mod names against the installed mods.
+//!
+//! Load orders shared in videos and forum posts are usually lists of the names shown in the Workshop, not of pack names,
+//! and often with typos or shortened names. Here we find the installed mods that look the most like each of those names.
+
+use getset::*;
+
+use std::collections::HashSet;
+
+use super::game_config::GameConfig;
+
+/// Maximum amount of candidates kept for each name.
+const MAX_CANDIDATES: usize = 5;
+
+/// Minimum score for a candidate to be selected by default.
+const MIN_AUTOMATIC_SCORE: f64 = 0.6;
+
+/// If the best two candidates are closer than this, the match is considered ambiguous.
+const AMBIGUOUS_SCORE_MARGIN: f64 = 0.1;
+
+const WORKSHOP_SEARCH_URL: &str = "https://steamcommunity.com/workshop/browse/?appid=";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct NameMatch {
+
+    /// Name as it was in the list.
+    name: String,
+
+    /// Ids of the mods that may be the one with the name, with their score from 0 to 1, best first.
+    candidates: Vec<(String, f64)>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl NameMatch {
+
+    /// This function returns the id of the best candidate, if it's good enough to be used without asking the user.
+    pub fn best(&self) -> Option<&str> {
+        self.good_candidates().next()
+    }
+
+    /// This function returns the ids of the candidates good enough to be used without asking the user, best first.
+    pub fn good_candidates(&self) -> impl Iterator<Item = &str> {
+        self.candidates.iter()
+            .filter(|(_, score)| *score >= MIN_AUTOMATIC_SCORE)
+            .map(|(mod_id, _)| mod_id.as_str())
+    }
+
+    /// This function returns if the user should check this match before using it.
+    pub fn is_ambiguous(&self) -> bool {
+        match (self.candidates.first(), self.candidates.get(1)) {
+            (Some((_, best)), Some((_, second))) => *best < MIN_AUTOMATIC_SCORE || best - second < AMBIGUOUS_SCORE_MARGIN,
+            (Some((_, best)), None) => *best < MIN_AUTOMATIC_SCORE,
+            (None, _) => true,
+        }
+    }
+}
+
+/// This function matches each line of the provided text against the installed mods, by their Workshop name and pack name.
+///
+/// Empty lines, list markers and numbering are ignored, so lists copied from forum posts can be pasted as they are.
+pub fn match_mod_names(game_config: &GameConfig, text: &str) -> Vec<NameMatch> {
+    let mods = game_config.mods().values()
+        .filter(|modd| !modd.paths().is_empty())
+        .map(|modd| (modd.id().as_str(), vec![modd.name().as_str(), modd.id().as_str()]))
+        .collect::<Vec<_>>();
+
+    match_names(&mods, text)
+}
+
+/// This function matches each line of the provided text against the provided mods, each one with the names it can be known by.
+///
+/// Lines can also be in the `mod "name.pack";` format used by the games, and pack extensions are ignored.
+pub fn match_names(mods: &[(&str, Vec<&str>)], text: &str) -> Vec<NameMatch> {
+    let mods = mods.iter()
+        .map(|(mod_id, names)| (*mod_id, names.iter().map(|name| normalize(strip_pack_extension(name))).collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+
+    list_names(text)
+        .into_iter()
+        .map(|name| {
+            let normalized = normalize(strip_pack_extension(name));
+            let mut candidates = mods.iter()
+                .map(|(mod_id, names)| (mod_id.to_string(), names.iter().map(|mod_name| similarity(&normalized, mod_name)).fold(0.0, f64::max)))
+                .filter(|(_, score)| *score > 0.0)
+                .collect::<Vec<_>>();
+
+            candidates.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+            candidates.truncate(MAX_CANDIDATES);
+
+            NameMatch {
+                name: name.to_owned(),
+                candidates,
+            }
+        })
+        .collect()
+}
+
+/// This function returns the names in each line of the provided text, without list markers, numbering or empty lines.
+pub fn list_names(text: &str) -> Vec<&str> {
+    text.lines()
+        .map(clean_line)
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// This function returns if the provided name is exactly the provided pack name, ignoring case and the pack extension.
+pub fn is_exact_match(name: &str, pack_name: &str) -> bool {
+    strip_pack_extension(name).eq_ignore_ascii_case(strip_pack_extension(pack_name))
+}
+
+/// This function returns the url to search the provided name in the Workshop of the game with the provided Steam id.
+pub fn workshop_search_url(steam_id: u64, name: &str) -> String {
+    let encoded = name.bytes()
+        .map(|byte| if byte.is_ascii_alphanumeric() { (byte as char).to_string() } else { format!("%{:02X}", byte) })
+        .collect::<String>();
+
+    format!("{WORKSHOP_SEARCH_URL}{steam_id}&searchtext={encoded}")
+}
+
+/// This function removes the list markers and numbering forum posts usually have, and the `mod "";` wrapping of the game's mod lists.
+fn clean_line(line: &str) -> &str {
+    let line = line.trim();
+    let line = match line.strip_prefix("mod ") {
+        Some(rest) if rest.trim_end().ends_with(';') => rest.trim_end().trim_end_matches(';').trim().trim_matches('"'),
+        _ => line,
+    };
+
+    let line = line.trim_start_matches(['-', '*', '•']).trim_start();
+
+    // Only remove numbers followed by a dot or parenthesis, as some mod names start with numbers.
+    let digits = line.chars().take_while(|char| char.is_ascii_digit()).count();
+    match line[digits..].strip_prefix(['.', ')']) {
+        Some(rest) if digits > 0 => rest.trim(),
+        _ => line.trim(),
+    }
+}
+
+/// Pack names are compared without their extension, as lists written by hand usually don't have it.
+fn strip_pack_extension(name: &str) -> &str {
+    name.get(name.len().saturating_sub(5)..)
+        .filter(|extension| extension.eq_ignore_ascii_case(".pack"))
+        .map(|_| &name[..name.len() - 5])
+        .unwrap_or(name)
+}
+
+/// Names are compared ignoring case, punctuation and extra spaces. Underscores count as spaces, as they're used instead of spaces in pack names.
+fn normalize(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .map(|char| if char.is_alphanumeric() { char } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// This function returns how similar the two provided normalized names are, from 0 to 1.
+///
+/// We use the best of the edit distance, which deals with typos, and the shared words, which deals with shortened or reordered names.
+fn similarity(a: &str, b: &str) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    if a == b {
+        return 1.0;
+    }
+
+    let a_chars = a.chars().collect::<Vec<_>>();
+    let b_chars = b.chars().collect::<Vec<_>>();
+    let edit = 1.0 - levenshtein(&a_chars, &b_chars) as f64 / a_chars.len().max(b_chars.len()) as f64;
+
+    let a_words = a.split(' ').collect::<HashSet<_>>();
+    let b_words = b.split(' ').collect::<HashSet<_>>();
+    let shared = a_words.intersection(&b_words).count() as f64;
+    let words = 2.0 * shared / (a_words.len() + b_words.len()) as f64;
+
+    edit.max(words)
+}
+
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let cost = if a_char == b_char { 0 } else { 1 };
+            current[j + 1] = (previous[j] + cost).min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::mod_manager::mods::Mod;
+
+    use super::*;
+
+    fn game_config(mods: &[(&str, &str)]) -> GameConfig {
+        let mut game_config = GameConfig::default();
+        for (mod_id, name) in mods {
+            let mut modd = Mod::default();
+            modd.set_id(mod_id.to_string());
+            modd.set_name(name.to_string());
+            modd.set_paths(vec![PathBuf::from(mod_id)]);
+            game_config.mods_mut().insert(mod_id.to_string(), modd);
+        }
+
+        game_config
+    }
+
+    #[test]
+    fn clean_line_removes_markers_and_numbering() {
+        assert_eq!(clean_line("  - Radious Total War Mod"), "Radious Total War Mod");
+        assert_eq!(clean_line("* SFO: Grimhammer"), "SFO: Grimhammer");
+        assert_eq!(clean_line("12. Mixu's Legendary Lords"), "Mixu's Legendary Lords");
+        assert_eq!(clean_line("3) Cataph's Kraka Drak"), "Cataph's Kraka Drak");
+
+        // Numbers not followed by a dot or parenthesis are part of the name.
+        assert_eq!(clean_line("300 Spartans"), "300 Spartans");
+    }
+
+    #[test]
+    fn normalize_ignores_case_and_punctuation() {
+        assert_eq!(normalize("Mixu's  Legendary_Lords!"), "mixu s legendary lords");
+    }
+
+    #[test]
+    fn similarity_scores() {
+        assert_eq!(similarity("sfo grimhammer", "sfo grimhammer"), 1.0);
+        assert_eq!(similarity("", "sfo grimhammer"), 0.0);
+
+        // Typos and reordered words still score high.
+        assert!(similarity("mixus legendary lord", "mixu s legendary lords") > 0.8);
+        assert!(similarity("legendary lords mixu", "mixu legendary lords") > 0.9);
+        assert!(similarity("kraka drak", "radious total war") < 0.5);
+    }
+
+    #[test]
+    fn match_mod_names_by_name_and_pack_name() {
+        let game_config = game_config(&[
+            ("mixu_legendary_lords.pack", "Mixu's Legendary Lords"),
+            ("sfo_grimhammer.pack", "SFO: Grimhammer"),
+            ("cataph_kraka_drak.pack", "Cataph's Kraka Drak"),
+        ]);
+
+        let matches = match_mod_names(&game_config, "1. Mixus Legendary Lord\n\n- sfo_grimhammer\n* Some Mod Nobody Has");
+        assert_eq!(matches.len(), 3);
+        assert_eq!(matches[0].name(), "Mixus Legendary Lord");
+        assert_eq!(matches[0].best(), Some("mixu_legendary_lords.pack"));
+        assert_eq!(matches[1].best(), Some("sfo_grimhammer.pack"));
+        assert!(!matches[1].is_ambiguous());
+
+        // Names without a good enough candidate are left for the user to decide.
+        assert_eq!(matches[2].best(), None);
+        assert!(matches[2].is_ambiguous());
+    }
+}
@@ -0,0 +1,97 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the offline mode.
+//!
+//! Without network, each request to Steam or GitHub has to wait for a timeout before failing, and most of those failures are silently ignored.
+//! In offline mode, no network request is made: the Workshop data we already have in the game config is used as is, and the UI marks it as stale.
+//!
+//! Offline mode is either enabled by the user in the settings, or detected when Steam's servers are unreachable.
+//! Detected offline mode is checked again periodically, so it goes away once the network is back.
+//! The state is kept in atomics instead of read from the settings, as it's checked from the background and network threads.
+
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// Server we try to reach to know if we have network.
+const STEAM_API_HOST: &str = "api.steampowered.com:443";
+
+/// Time we wait for Steam's servers to answer before considering them unreachable.
+const DETECTION_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Set if the user enabled offline mode in the settings.
+static OFFLINE_MODE_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Set if Steam's servers were unreachable the last time we checked.
+static OFFLINE_MODE_DETECTED: AtomicBool = AtomicBool::new(false);
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// Reason offline mode is active.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OfflineReason {
+
+    /// The user enabled it in the settings.
+    Enabled,
+
+    /// Steam's servers were unreachable.
+    Detected,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl OfflineReason {
+
+    /// This function returns the key of the explanation of why offline mode is active.
+    pub fn locale_key(&self) -> &'static str {
+        match self {
+            Self::Enabled => "offline_mode_enabled",
+            Self::Detected => "offline_mode_detected",
+        }
+    }
+}
+
+/// This function returns why offline mode is active, or None if it's not active.
+pub fn offline_mode() -> Option<OfflineReason> {
+    if OFFLINE_MODE_ENABLED.load(Ordering::SeqCst) {
+        Some(OfflineReason::Enabled)
+    } else if OFFLINE_MODE_DETECTED.load(Ordering::SeqCst) {
+        Some(OfflineReason::Detected)
+    } else {
+        None
+    }
+}
+
+/// This function enables or disables the offline mode set in the settings.
+pub fn set_offline_mode_enabled(enabled: bool) {
+    OFFLINE_MODE_ENABLED.store(enabled, Ordering::SeqCst);
+}
+
+/// This function checks if Steam's servers are reachable, enabling or disabling the detected offline mode accordingly.
+///
+/// If the user already enabled offline mode, nothing is checked, as that would be a network request.
+///
+/// This blocks for a few seconds if the servers are unreachable, so don't call it from the UI thread.
+pub fn detect_offline_mode() {
+    if OFFLINE_MODE_ENABLED.load(Ordering::SeqCst) {
+        return;
+    }
+
+    let reachable = STEAM_API_HOST.to_socket_addrs()
+        .map(|mut addresses| addresses.any(|address| TcpStream::connect_timeout(&address, DETECTION_TIMEOUT).is_ok()))
+        .unwrap_or(false);
+
+    OFFLINE_MODE_DETECTED.store(!reachable, Ordering::SeqCst);
+}
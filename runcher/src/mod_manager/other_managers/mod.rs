@@ -0,0 +1,168 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the detection of other mod managers working on the same game.
+//!
+//! CA's launcher, Vortex and other instances of Runcher all rewrite the mod list of the game when they launch it or deploy mods,
+//! so with more than one of them working on the same game the last one to write wins, and mods seem to get enabled or disabled at random.
+//!
+//! To know which instance of Runcher is working on a game, each instance leaves a lock file with its process id for the game it has loaded.
+
+use anyhow::{anyhow, Result};
+use sysinfo::{get_current_pid, Pid, ProcessRefreshKind, RefreshKind, System};
+
+use std::fs::{read_dir, read_to_string, remove_file, write};
+use std::path::{Path, PathBuf};
+
+use rpfm_lib::games::GameInfo;
+use rpfm_ui_common::settings::config_path;
+
+const CA_LAUNCHER_EXE: &str = "launcher.exe";
+const VORTEX_EXE: &str = "vortex.exe";
+
+/// Vortex leaves a manifest starting with this in the folders it deploys mods to.
+const VORTEX_DEPLOYMENT_FILE_NAME_START: &str = "vortex.deployment";
+
+const LOCK_FILE_NAME_START: &str = "game_lock_";
+const LOCK_FILE_NAME_END: &str = ".lock";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// Other mod manager that may change the mods of the game behind our back.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ManagerConflict {
+
+    /// CA's launcher of the game is running.
+    CaLauncher,
+
+    /// Vortex is running.
+    Vortex,
+
+    /// Vortex has deployed mods to the data folder of the game. Contains the path of its deployment manifest.
+    VortexDeployment(PathBuf),
+
+    /// Another instance of Runcher has the game loaded. Contains its process id.
+    Runcher(u32),
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl ManagerConflict {
+
+    /// This function returns the key of the message explaining the conflict and how to solve it.
+    pub fn locale_key(&self) -> &'static str {
+        match self {
+            Self::CaLauncher => "manager_conflict_ca_launcher",
+            Self::Vortex => "manager_conflict_vortex",
+            Self::VortexDeployment(_) => "manager_conflict_vortex_deployment",
+            Self::Runcher(_) => "manager_conflict_runcher",
+        }
+    }
+}
+
+/// This function returns the other mod managers that may be working on the provided game.
+pub fn manager_conflicts(game: &GameInfo, game_path: &Path) -> Vec<ManagerConflict> {
+    let mut conflicts = vec![];
+    let system = System::new_with_specifics(RefreshKind::everything().with_processes(ProcessRefreshKind::everything()));
+
+    // CA's launcher is installed with each game, so we only care about the one in this game's folder.
+    let game_path_str = format!("{}/", normalize_path(game_path).trim_end_matches('/'));
+    if !game_path.as_os_str().is_empty() && system.processes().values().any(|process| process.exe().is_some_and(|exe|
+        normalize_path(exe).starts_with(&game_path_str) &&
+        exe.file_name().is_some_and(|name| name.eq_ignore_ascii_case(CA_LAUNCHER_EXE))
+    )) {
+        conflicts.push(ManagerConflict::CaLauncher);
+    }
+
+    if system.processes().values().any(|process| process.name().eq_ignore_ascii_case(VORTEX_EXE)) {
+        conflicts.push(ManagerConflict::Vortex);
+    }
+
+    if let Ok(data_path) = game.data_path(game_path) {
+        if let Ok(entries) = read_dir(data_path) {
+            let manifest = entries.filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .find(|path| path.is_file() && path.file_name().is_some_and(|name| name.to_string_lossy().to_lowercase().starts_with(VORTEX_DEPLOYMENT_FILE_NAME_START)));
+
+            if let Some(manifest) = manifest {
+                conflicts.push(ManagerConflict::VortexDeployment(manifest));
+            }
+        }
+    }
+
+    if let Some(pid) = loaded_by_other_runcher(&system, game.key()) {
+        conflicts.push(ManagerConflict::Runcher(pid));
+    }
+
+    conflicts
+}
+
+/// This function returns the process id of the other instance of Runcher that has the provided game loaded, if any.
+pub fn game_loaded_elsewhere(game_key: &str) -> Option<u32> {
+    let system = System::new_with_specifics(RefreshKind::everything().with_processes(ProcessRefreshKind::everything()));
+    loaded_by_other_runcher(&system, game_key)
+}
+
+/// This function marks the provided game as loaded by this instance of Runcher, releasing any other game it had loaded.
+pub fn lock_game(game_key: &str) -> Result<()> {
+    unlock_game()?;
+
+    let own_pid = get_current_pid().map_err(|error| anyhow!(error))?.as_u32().to_string();
+    write(lock_path(&config_path()?, game_key), own_pid)?;
+    Ok(())
+}
+
+/// This function releases the game loaded by this instance of Runcher, if any. To be called on exit.
+pub fn unlock_game() -> Result<()> {
+    let own_pid = get_current_pid().map_err(|error| anyhow!(error))?.as_u32().to_string();
+    let path = config_path()?;
+
+    for entry in read_dir(&path)?.filter_map(|entry| entry.ok()) {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if file_name.starts_with(LOCK_FILE_NAME_START) && file_name.ends_with(LOCK_FILE_NAME_END) && read_to_string(entry.path()).is_ok_and(|pid| pid.trim() == own_pid) {
+            remove_file(entry.path())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// This function returns the process id of the instance of Runcher that last loaded the provided game, if any.
+fn locked_by(game_key: &str) -> Option<u32> {
+    let path = config_path().ok()?;
+    read_to_string(lock_path(&path, game_key)).ok()?.trim().parse().ok()
+}
+
+/// Process ids get reused, so we only trust the lock if its process is still a Runcher.
+fn loaded_by_other_runcher(system: &System, game_key: &str) -> Option<u32> {
+    let pid = locked_by(game_key)?;
+    let own_pid = get_current_pid().ok()?;
+    if pid == own_pid.as_u32() {
+        return None;
+    }
+
+    let own_name = system.process(own_pid).map(|process| process.name().to_owned());
+    system.process(Pid::from_u32(pid))
+        .filter(|process| Some(process.name()) == own_name.as_deref())
+        .map(|_| pid)
+}
+
+fn lock_path(config_path: &Path, game_key: &str) -> PathBuf {
+    config_path.join(format!("{LOCK_FILE_NAME_START}{game_key}{LOCK_FILE_NAME_END}"))
+}
+
+/// Paths are compared ignoring case and separators, as that's what Windows does.
+fn normalize_path(path: &Path) -> String {
+    path.to_string_lossy().to_lowercase().replace('\\', "/")
+}
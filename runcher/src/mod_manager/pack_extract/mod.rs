@@ -0,0 +1,77 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the extraction of the contents of a pack to disk.
+//!
+//! This is meant for users that just want to check a script or a table of a mod, without having to install RPFM for it.
+
+use anyhow::{anyhow, Result};
+
+use std::fs::{DirBuilder, File};
+use std::io::{BufWriter, Write};
+use std::path::{Component, Path, PathBuf};
+
+use rpfm_lib::files::pack::Pack;
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function extracts the files of the provided pack to the provided folder, keeping the folder structure they have in the pack.
+///
+/// Only files whose path contains one of the provided filters, separated by `;`, are extracted. An empty filter extracts the entire pack.
+/// Filters are case-insensitive. Returns the amount of files extracted.
+pub fn extract_pack(pack_path: &Path, folder: &Path, filter: &str) -> Result<usize> {
+    let filters = filter.split(';')
+        .map(|filter| filter.trim().replace('\\', "/").to_lowercase())
+        .filter(|filter| !filter.is_empty())
+        .collect::<Vec<_>>();
+
+    let mut pack = Pack::read_and_merge(&[pack_path.to_path_buf()], true, false, false)?;
+    let mut extracted = 0;
+
+    for (path, file) in pack.files_mut() {
+        let path_lower = path.to_lowercase();
+        if !filters.is_empty() && !filters.iter().any(|filter| path_lower.contains(filter)) {
+            continue;
+        }
+
+        let file_path = folder.join(sanitized_path(path)?);
+        if let Some(parent) = file_path.parent() {
+            DirBuilder::new().recursive(true).create(parent)?;
+        }
+
+        let data = file.encode(&None, false, false, true)?.ok_or_else(|| anyhow!("Failed to get the data of {}.", path))?;
+        let mut writer = BufWriter::new(File::create(&file_path)?);
+        writer.write_all(&data)?;
+        writer.flush()?;
+
+        extracted += 1;
+    }
+
+    Ok(extracted)
+}
+
+/// This function turns a path inside a pack into a relative path on disk, so broken or malicious packs cannot write outside the folder.
+fn sanitized_path(path: &str) -> Result<PathBuf> {
+    let sanitized = Path::new(path)
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(component) => Some(component),
+            _ => None,
+        })
+        .collect::<PathBuf>();
+
+    if sanitized.as_os_str().is_empty() {
+        Err(anyhow!("Invalid path in pack: {}.", path))
+    } else {
+        Ok(sanitized)
+    }
+}
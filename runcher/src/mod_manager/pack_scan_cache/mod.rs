@@ -0,0 +1,214 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the cache used to speed up mod scanning.
+//!
+//! Opening every pack is the slowest part of a reload, specially with big secondary folders. So we keep the data we need from each pack,
+//! along with its size and modification date, and only read again the packs that changed since the last scan.
+//!
+//! The cache is not synced between computers, as the paths in it are only valid in the computer they were scanned in.
+
+use anyhow::Result;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_reader, to_writer};
+
+use std::collections::HashMap;
+use std::fs::{DirBuilder, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use rpfm_lib::files::pack::Pack;
+use rpfm_lib::games::{GameInfo, pfh_file_type::PFHFileType};
+
+use rpfm_ui_common::settings::config_path;
+
+use super::campaign_safety::CampaignSafety;
+use super::misfiled_packs::{is_misfiled_movie, is_misfiled_movie_pack};
+use super::pack_versions::PackGameVersion;
+
+const PACK_SCAN_CACHE_FOLDER: &str = "pack_scan_cache";
+const PACK_SCAN_CACHE_EXTENSION: &str = ".json";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PackScanCache {
+
+    /// Data of the packs found in the current scan, by path.
+    entries: HashMap<PathBuf, PackScanCacheEntry>,
+
+    /// Data of the packs found in the previous scan. Packs not found again are dropped from the cache when saving it.
+    #[serde(skip)]
+    previous_entries: HashMap<PathBuf, PackScanCacheEntry>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct PackScanCacheEntry {
+
+    /// Size of the pack, in bytes.
+    size: u64,
+
+    /// Last modification date of the pack, in milliseconds since the unix epoch.
+    modified: u64,
+
+    /// Type of the pack.
+    pack_type: PFHFileType,
+
+    /// If the pack is a movie pack that looks like a mod pack.
+    misfiled_movie_pack: bool,
+
+    /// Version data of the pack. None in caches saved before we read it, so those packs are read again.
+    #[serde(default)]
+    game_version: Option<PackGameVersion>,
+
+    /// If the pack can be added to an ongoing campaign. None in caches saved before we checked it, so those packs are read again.
+    #[serde(default)]
+    campaign_safety: Option<CampaignSafety>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl PackScanCache {
+
+    /// This function loads the cache of the provided game. If it doesn't exist or fails to load, an empty cache is returned.
+    pub fn load(game: &GameInfo) -> Self {
+        let previous_entries = Self::path(game)
+            .and_then(|path| Ok(from_reader::<_, Self>(BufReader::new(File::open(path)?))?))
+            .map(|cache| cache.entries)
+            .unwrap_or_default();
+
+        Self {
+            entries: HashMap::new(),
+            previous_entries,
+        }
+    }
+
+    /// This function saves the cache of the provided game, with only the packs found in the current scan.
+    pub fn save(&self, game: &GameInfo) -> Result<()> {
+        let path = Self::path(game)?;
+        if let Some(parent) = path.parent() {
+            DirBuilder::new().recursive(true).create(parent)?;
+        }
+
+        let mut file = BufWriter::new(File::create(path)?);
+        to_writer(&mut file, self)?;
+        file.flush()?;
+
+        Ok(())
+    }
+
+    /// This function keeps the data of the packs of the previous scan that were not scanned again, as long as they still exist.
+    ///
+    /// Used when only part of the packs are scanned, so saving the cache doesn't drop the rest of them.
+    pub fn keep_previous_entries(&mut self) {
+        for (path, entry) in &self.previous_entries {
+            if !self.entries.contains_key(path) && path.is_file() {
+                self.entries.insert(path.to_path_buf(), entry.clone());
+            }
+        }
+    }
+
+    fn path(game: &GameInfo) -> Result<PathBuf> {
+        Ok(config_path()?.join(PACK_SCAN_CACHE_FOLDER).join(format!("{}{}", game.key(), PACK_SCAN_CACHE_EXTENSION)))
+    }
+
+    /// This function returns the types of the packs at the provided paths, in the same order.
+    ///
+    /// Packs are read in parallel, and only if they're not in the cache or they changed since they were cached.
+    pub fn pack_types(&mut self, paths: &[PathBuf]) -> Vec<Result<PFHFileType>> {
+        let entries = paths.par_iter()
+            .map(|path| self.entry(path))
+            .collect::<Vec<_>>();
+
+        paths.iter()
+            .zip(entries)
+            .map(|(path, entry)| entry.map(|entry| {
+                let pack_type = entry.pack_type;
+                self.entries.insert(path.to_path_buf(), entry);
+                pack_type
+            }))
+            .collect()
+    }
+
+    /// This function returns if the pack at the provided path is a movie pack that looks like a mod pack, reading it only if it's not in the cache.
+    pub fn is_misfiled_movie_pack(&self, path: &Path) -> bool {
+        match self.cached_entry(path) {
+            Some(entry) => entry.misfiled_movie_pack,
+            None => is_misfiled_movie_pack(path),
+        }
+    }
+
+    /// This function returns the version data of the pack at the provided path, reading it only if it's not in the cache.
+    pub fn game_version(&self, path: &Path) -> PackGameVersion {
+        match self.cached_entry(path).and_then(|entry| entry.game_version.as_ref()) {
+            Some(game_version) => game_version.clone(),
+            None => PackGameVersion::from_path(path),
+        }
+    }
+
+    /// This function returns if the pack at the provided path can be added to an ongoing campaign, reading it only if it's not in the cache.
+    pub fn campaign_safety(&self, path: &Path) -> CampaignSafety {
+        match self.cached_entry(path).and_then(|entry| entry.campaign_safety.as_ref()).filter(|campaign_safety| !campaign_safety.is_outdated()) {
+            Some(campaign_safety) => campaign_safety.clone(),
+            None => match Pack::read_and_merge(&[path.to_path_buf()], true, false, false) {
+                Ok(mut pack) => CampaignSafety::read(&mut pack),
+                Err(_) => CampaignSafety::default(),
+            },
+        }
+    }
+
+    /// This function returns the cached data of the pack at the provided path, if it's cached and it didn't change since then.
+    ///
+    /// Packs not scanned again, like the ones outside a partial rescan, are looked for in the previous scan.
+    fn cached_entry(&self, path: &Path) -> Option<&PackScanCacheEntry> {
+        if let Some(entry) = self.entries.get(path) {
+            return Some(entry);
+        }
+
+        let entry = self.previous_entries.get(path)?;
+        let metadata = path.metadata().ok()?;
+        let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?.as_millis() as u64;
+        if entry.size == metadata.len() && entry.modified == modified {
+            Some(entry)
+        } else {
+            None
+        }
+    }
+
+    /// This function returns the cached data of the pack at the provided path, or reads it if it's not cached or it's outdated.
+    fn entry(&self, path: &Path) -> Result<PackScanCacheEntry> {
+        let metadata = path.metadata()?;
+        let size = metadata.len();
+        let modified = metadata.modified()?.duration_since(UNIX_EPOCH)?.as_millis() as u64;
+
+        let cached = self.entries.get(path).or_else(|| self.previous_entries.get(path));
+        if let Some(entry) = cached {
+            if entry.size == size && entry.modified == modified && entry.game_version.is_some() && entry.campaign_safety.as_ref().is_some_and(|campaign_safety| !campaign_safety.is_outdated()) {
+                return Ok(entry.clone());
+            }
+        }
+
+        let mut pack = Pack::read_and_merge(&[path.to_path_buf()], true, false, false)?;
+        Ok(PackScanCacheEntry {
+            size,
+            modified,
+            pack_type: pack.pfh_file_type(),
+            misfiled_movie_pack: is_misfiled_movie(&pack),
+            game_version: Some(PackGameVersion::read(&mut pack)),
+            campaign_safety: Some(CampaignSafety::read(&mut pack)),
+        })
+    }
+}
@@ -0,0 +1,170 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the detection of the version of the game packs were made for.
+//!
+//! Packs don't store the version of the game they were made for. Some modders write it in a text file inside the pack, which we use if we find it.
+//! Otherwise, we compare the versions of the tables in the pack with the ones in the schema, which follows the installed game:
+//! tables older than the schema's mean the pack was made for an older version of the game.
+
+use getset::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use std::collections::BTreeMap;
+use std::io::Cursor;
+use std::path::Path;
+
+use rpfm_lib::files::{Container, db::DB, FileType, pack::Pack};
+use rpfm_lib::schema::Schema;
+
+/// Extensions of the files we look for the game version in.
+const VERSION_FILE_EXTENSIONS: [&str; 4] = [".txt", ".md", ".json", ".lua"];
+
+/// Regex matching the game version modders usually write in their packs, like "Game Version: 5.2" or "built_for = v5.2.1".
+const VERSION_REGEX: &str = r"(?i)(?:game[ _-]?version|built[ _-]?for|compatible[ _-]?with)\W{0,3}v?(\d+(?:\.\d+){1,3})";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// Version data found in a pack.
+#[derive(Clone, Debug, Default, PartialEq, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct PackGameVersion {
+
+    /// Version of the game written in the pack, if any.
+    embedded: Option<String>,
+
+    /// Version of each table in the pack, by table name. For tables with multiple files, the lowest version is kept.
+    table_versions: BTreeMap<String, i32>,
+}
+
+/// Result of comparing the version data of a pack with the installed game.
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct GameVersionCheck {
+
+    /// Version of the game the pack says it was made for, if it says it.
+    embedded: Option<String>,
+
+    /// Tables of the pack with an older version than the schema's, with their version in the pack and in the schema.
+    outdated_tables: Vec<(String, i32, i32)>,
+
+    /// Amount of tables of the pack we could compare with the schema.
+    checked_tables: usize,
+
+    /// If the pack doesn't seem to be made for the installed version of the game.
+    mismatch: bool,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl PackGameVersion {
+
+    /// This function reads the version data of the pack at the provided path. If the pack cannot be read, empty data is returned.
+    pub fn from_path(path: &Path) -> Self {
+        match Pack::read_and_merge(&[path.to_path_buf()], true, false, false) {
+            Ok(mut pack) => Self::read(&mut pack),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// This function reads the version data of the provided pack.
+    ///
+    /// Only the files that may contain it are loaded: tables for their header, and text files in the root of the pack or with "version" in their name.
+    pub fn read(pack: &mut Pack) -> Self {
+        let regex = Regex::new(VERSION_REGEX).ok();
+        let mut data = Self::default();
+
+        for (path, file) in pack.files_mut().iter_mut() {
+            let path_lower = path.to_lowercase();
+            if file.file_type() == FileType::DB {
+                let table_name = match path_lower.split('/').nth(1) {
+                    Some(table_name) => table_name.to_owned(),
+                    None => continue,
+                };
+
+                if file.load().is_err() {
+                    continue;
+                }
+
+                if let Ok(version) = file.cached().and_then(|bytes| DB::read_header(&mut Cursor::new(bytes)).map(|header| header.0)) {
+                    data.table_versions.entry(table_name)
+                        .and_modify(|current| *current = (*current).min(version))
+                        .or_insert(version);
+                }
+            } else if data.embedded.is_none() && VERSION_FILE_EXTENSIONS.iter().any(|extension| path_lower.ends_with(extension)) {
+                let file_name = path_lower.rsplit('/').next().unwrap_or_default();
+                if (path_lower.contains('/') && !file_name.contains("version")) || file.load().is_err() {
+                    continue;
+                }
+
+                if let (Some(regex), Ok(bytes)) = (&regex, file.cached()) {
+                    let text = String::from_utf8_lossy(bytes);
+                    data.embedded = regex.captures(&text)
+                        .and_then(|captures| captures.get(1))
+                        .map(|version| version.as_str().to_owned());
+                }
+            }
+        }
+
+        data
+    }
+
+    /// This function compares the version data of the pack with the installed version of the game and the schema.
+    ///
+    /// The version written in the pack takes priority. If there is none, the pack is considered made for another version if any of its tables is outdated.
+    pub fn check(&self, schema: Option<&Schema>, installed_version: Option<u32>) -> GameVersionCheck {
+        let mut outdated_tables = vec![];
+        let mut checked_tables = 0;
+
+        if let Some(schema) = schema {
+            for (table_name, version) in &self.table_versions {
+                if let Some(latest) = schema.definitions_by_table_name(table_name).and_then(|definitions| definitions.iter().map(|definition| *definition.version()).max()) {
+                    checked_tables += 1;
+                    if *version < latest {
+                        outdated_tables.push((table_name.to_owned(), *version, latest));
+                    }
+                }
+            }
+        }
+
+        let mismatch = match (&self.embedded, installed_version) {
+            (Some(embedded), Some(installed_version)) => !version_matches(embedded, installed_version),
+            (Some(_), None) => false,
+            (None, _) => !outdated_tables.is_empty(),
+        };
+
+        GameVersionCheck {
+            embedded: self.embedded.clone(),
+            outdated_tables,
+            checked_tables,
+            mismatch,
+        }
+    }
+}
+
+/// This function turns a version number of a game into its usual text form.
+///
+/// Version numbers store each part of the version in one byte: major, minor, patch and build. The build is left out, as modders don't use it.
+pub fn game_version_string(version: u32) -> String {
+    format!("{}.{}.{}", version >> 24, (version >> 16) & 0xFF, (version >> 8) & 0xFF)
+}
+
+/// This function returns if the provided version text matches the provided version number, only checking the parts present in the text.
+fn version_matches(version: &str, version_number: u32) -> bool {
+    let installed = [version_number >> 24, (version_number >> 16) & 0xFF, (version_number >> 8) & 0xFF];
+    version.split('.')
+        .zip(installed.iter())
+        .all(|(part, installed)| part.parse::<u32>().is_ok_and(|part| part == *installed))
+}
@@ -10,21 +10,28 @@
 
 //! Module containing the centralized code for mod and load order management.
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use getset::*;
+use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use serde_json::to_string_pretty;
 
 use std::collections::HashMap;
 use std::fs::{DirBuilder, File};
 use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
 
 use rpfm_lib::games::GameInfo;
+use rpfm_lib::integrations::log::error;
 use rpfm_lib::utils::*;
 
+use rpfm_ui_common::settings::{set_setting_string, setting_string};
+
 use crate::mod_manager::game_config::GameConfig;
 use crate::settings_ui::*;
+use crate::SUPPORTED_GAMES;
 
+use super::config_recovery::{config_value, ConfigRecovery};
 use super::load_order::LoadOrder;
 
 mod versions;
@@ -32,6 +39,12 @@ mod versions;
 const FILE_NAME_START: &str = "profile_";
 const FILE_NAME_END: &str = ".json";
 
+/// Amount of characters of the signature shown as the profile's fingerprint.
+const FINGERPRINT_LENGTH: usize = 16;
+
+/// Version of the format of the profile files. Bump it when adding a new entry to the versions module.
+const PROFILE_VERSION: u32 = 1;
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -40,6 +53,10 @@ const FILE_NAME_END: &str = ".json";
 #[getset(get = "pub", get_mut = "pub", set = "pub")]
 pub struct Profile {
 
+    // Version of the format of the file. Files from before the format was versioned have none.
+    #[serde(default)]
+    version: u32,
+
     // Id/Name of the profile. Must be unique for the game.
     id: String,
 
@@ -48,6 +65,25 @@ pub struct Profile {
 
     // Load order of this profile.
     load_order: LoadOrder,
+
+    // Name of the MCT preset to apply when loading this profile, if any.
+    mct_preset: Option<String>,
+
+    // If the profile cannot be overwritten. Used for profiles shared between players, so nobody changes them by accident.
+    #[serde(default)]
+    read_only: bool,
+
+    // Signature of the profile's content when it was exported, so players can check they're all using the same unmodified profile.
+    #[serde(default)]
+    signature: Option<String>,
+
+    // If the profile is locked for a campaign. While a locked profile is active, its mods and load order cannot be changed until it's unlocked.
+    #[serde(default)]
+    locked: bool,
+
+    // Environment variables to pass to the game when launching it with this profile, on top of the ones of the game.
+    #[serde(default)]
+    env_vars: String,
 }
 
 //-------------------------------------------------------------------------------//
@@ -56,22 +92,33 @@ pub struct Profile {
 
 impl Profile {
 
-    pub fn profiles_for_game(game: &GameInfo) -> Result<HashMap<String, Self>> {
+    /// This function loads all the profiles of the provided game, recovering the ones that fail to load.
+    ///
+    /// The recovery reports of the recovered profiles are returned alongside the profiles.
+    pub fn profiles_for_game(game: &GameInfo) -> Result<(HashMap<String, Self>, Vec<ConfigRecovery>)> {
         let mut profiles = HashMap::new();
+        let mut recoveries = vec![];
         let path = profiles_path()?;
         let file_name_start = format!("{FILE_NAME_START}{}_", game.key());
 
+        // Games whose key starts with the key of this game (like Pharaoh Dynasties with Pharaoh) have profiles with the same prefix. Skip those.
+        let other_games_starts = SUPPORTED_GAMES.games_sorted().iter()
+            .filter(|other| other.key() != game.key() && other.key().starts_with(&format!("{}_", game.key())))
+            .map(|other| format!("{FILE_NAME_START}{}_", other.key()))
+            .collect::<Vec<_>>();
+
         let files = files_from_subdir(&path, false)?;
         for file in files {
             let file_name = file.file_name().unwrap().to_string_lossy();
-            if file_name.starts_with(&file_name_start) && file_name.ends_with(FILE_NAME_END) {
+            if file_name.starts_with(&file_name_start) && file_name.ends_with(FILE_NAME_END) && !other_games_starts.iter().any(|start| file_name.starts_with(start)) {
                 let file_name_no_end = file.file_stem().unwrap().to_string_lossy().strip_prefix(&file_name_start).unwrap().to_string();
-                let profile = Self::load(game, &file_name_no_end, false)?;
+                let (profile, recovery) = Self::load_or_recover(game, &file_name_no_end)?;
                 profiles.insert(file_name_no_end, profile);
+                recoveries.extend(recovery);
             }
         }
 
-        Ok(profiles)
+        Ok((profiles, recoveries))
     }
 
     pub fn load(game: &GameInfo, profile: &str, new_if_missing: bool) -> Result<Self> {
@@ -91,8 +138,56 @@ impl Profile {
         Ok(profile)
     }
 
+    /// This function loads the provided profile, recovering what it can from it if it fails to load.
+    ///
+    /// The broken file is backed up and replaced with whatever could be read from it. The recovery report is returned alongside the profile.
+    pub fn load_or_recover(game: &GameInfo, profile: &str) -> Result<(Self, Option<ConfigRecovery>)> {
+        if let Ok(loaded) = Self::load(game, profile, false) {
+            return Ok((loaded, None));
+        }
+
+        let path = profiles_path()?.join(format!("{FILE_NAME_START}{}_{}{FILE_NAME_END}", game.key(), profile));
+        let data = std::fs::read(&path)?;
+        let error = match serde_json::from_slice::<Self>(&data) {
+            Ok(loaded) => return Ok((loaded, None)),
+            Err(error) => error,
+        };
+
+        let mut recovery = ConfigRecovery::new(&path, &data, &error, PROFILE_VERSION)?;
+        let value = config_value(&data);
+
+        // If the load order itself is broken, keep at least the mods in it.
+        let load_order = match value.get("load_order") {
+            Some(load_order) if serde_json::from_value::<LoadOrder>(load_order.clone()).is_err() => {
+                let mut recovered = LoadOrder::default();
+                *recovered.mods_mut() = recovery.strings(load_order, "mods");
+                *recovered.movies_mut() = recovery.strings(load_order, "movies");
+                recovered
+            }
+            _ => recovery.field(&value, "load_order"),
+        };
+
+        let mut recovered = Self {
+            version: PROFILE_VERSION,
+            id: profile.to_owned(),
+            game: game.key().to_owned(),
+            load_order,
+            mct_preset: recovery.field(&value, "mct_preset"),
+            read_only: recovery.field(&value, "read_only"),
+            signature: recovery.field(&value, "signature"),
+            locked: recovery.field(&value, "locked"),
+            env_vars: recovery.field(&value, "env_vars"),
+        };
+
+        recovered.save(game, profile)?;
+
+        error!("Profile {} failed to load and has been recovered: {}", path.to_string_lossy(), error);
+        Ok((recovered, Some(recovery)))
+    }
+
     pub fn save(&mut self, game: &GameInfo, profile: &str) -> Result<()> {
         let path = profiles_path()?.join(format!("{FILE_NAME_START}{}_{}{FILE_NAME_END}", game.key(), profile));
+        self.version = PROFILE_VERSION;
 
         // Make sure the path exists to avoid problems with updating schemas.
         if let Some(parent_folder) = path.parent() {
@@ -111,6 +206,80 @@ impl Profile {
         Ok(())
     }
 
+    /// This function exports the profile to the provided path, signing it so its content can be verified after importing it.
+    pub fn export(&self, path: &Path, read_only: bool) -> Result<()> {
+        let mut profile = self.clone();
+        profile.read_only = read_only;
+
+        // Locks are for the campaigns of whoever locked the profile, not for the ones of whoever imports it.
+        profile.locked = false;
+
+        // Environment variables are usually for debugging setups, and point to files in the computer of whoever set them.
+        profile.env_vars = String::new();
+        profile.signature = Some(profile.compute_signature()?);
+
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(to_string_pretty(&profile)?.as_bytes())?;
+        Ok(())
+    }
+
+    /// This function imports a profile exported with [Profile::export]. It's not saved.
+    ///
+    /// Signed profiles whose content doesn't match their signature are rejected, as they have been modified after being exported.
+    /// The id ends up in file names and hook commands, so profiles with ids that are not safe for them are rejected too.
+    pub fn import(game: &GameInfo, path: &Path) -> Result<Self> {
+        let mut file = BufReader::new(File::open(path)?);
+        let mut data = Vec::with_capacity(file.get_ref().metadata()?.len() as usize);
+        file.read_to_end(&mut data)?;
+
+        let mut profile: Self = serde_json::from_slice(&data)?;
+        if profile.game() != game.key() {
+            return Err(anyhow!("The profile is for {}, not for {}.", profile.game(), game.key()));
+        }
+
+        if !is_valid_id(profile.id()) {
+            return Err(anyhow!("The profile's name ({}) contains invalid characters.", profile.id()));
+        }
+
+        if profile.signature().is_some() && !profile.signature_is_valid() {
+            return Err(anyhow!("The profile has been modified after being exported, so its signature is no longer valid."));
+        }
+
+        // Same as when exporting. Hand-made or unsigned files may still have them.
+        profile.locked = false;
+        profile.env_vars = String::new();
+
+        Ok(profile)
+    }
+
+    /// This function returns if the profile is signed, and its content matches its signature.
+    pub fn signature_is_valid(&self) -> bool {
+        match self.signature {
+            Some(ref signature) => self.compute_signature().map(|computed| &computed == signature).unwrap_or(false),
+            None => false,
+        }
+    }
+
+    /// This function returns a short version of the profile's signature, so players can easily compare it.
+    pub fn fingerprint(&self) -> Option<String> {
+        self.signature.as_ref().map(|signature| signature.chars()
+            .take(FINGERPRINT_LENGTH)
+            .chunks(4)
+            .into_iter()
+            .map(|chunk| chunk.collect::<String>())
+            .join("-")
+            .to_uppercase()
+        )
+    }
+
+    /// This function returns the signature of the profile's content.
+    ///
+    /// The id is not part of it, so renaming a profile doesn't invalidate it.
+    fn compute_signature(&self) -> Result<String> {
+        let content = serde_json::to_string(&(&self.game, &self.load_order, &self.mct_preset, self.read_only, self.locked, &self.env_vars))?;
+        Ok(sha256::digest(content))
+    }
+
     pub fn delete(&self, game: &GameInfo) -> Result<()> {
         let path = profiles_path()?.join(format!("{FILE_NAME_START}{}_{}{FILE_NAME_END}", game.key(), self.id()));
         if path.is_file() {
@@ -120,3 +289,54 @@ impl Profile {
         Ok(())
     }
 }
+
+/// This function returns if the provided id can be used as a profile id.
+///
+/// Only letters, numbers, spaces, dashes, underscores and dots are allowed, without dots at the start, so ids cannot be used to escape the profiles folder.
+pub fn is_valid_id(id: &str) -> bool {
+    !id.trim().is_empty() &&
+        !id.starts_with('.') &&
+        !id.contains("..") &&
+        id.chars().all(|character| character.is_alphanumeric() || matches!(character, ' ' | '-' | '_' | '.'))
+}
+
+/// This function returns the provided text with the characters not allowed in profile ids replaced with underscores.
+///
+/// Dots are replaced too, so the result is valid as long as it's not empty.
+pub fn sanitize_id(id: &str) -> String {
+    id.chars()
+        .map(|character| if character.is_alphanumeric() || matches!(character, ' ' | '-' | '_') { character } else { '_' })
+        .collect()
+}
+
+/// This function returns the id of the locked profile active for the provided game, if any.
+///
+/// While a locked profile is active, the mod list and the load order of the game are read-only.
+pub fn active_locked_profile(game_key: &str) -> Option<String> {
+    let profile = setting_string(&format!("locked_profile_{game_key}"));
+    if profile.is_empty() {
+        None
+    } else {
+        Some(profile)
+    }
+}
+
+/// This function sets the locked profile active for the provided game. None unlocks the game.
+pub fn set_active_locked_profile(game_key: &str, profile: Option<&str>) {
+    set_setting_string(&format!("locked_profile_{game_key}"), profile.unwrap_or_default());
+}
+
+/// This function returns the name of the last profile loaded for the provided game, if any.
+pub fn last_profile(game_key: &str) -> Option<String> {
+    let profile = setting_string(&format!("last_profile_{game_key}"));
+    if profile.is_empty() {
+        None
+    } else {
+        Some(profile)
+    }
+}
+
+/// This function remembers the last profile loaded for the provided game.
+pub fn set_last_profile(game_key: &str, profile: &str) {
+    set_setting_string(&format!("last_profile_{game_key}"), profile);
+}
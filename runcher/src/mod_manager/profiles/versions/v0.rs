@@ -92,9 +92,14 @@ impl From<&ProfileV0> for ProfileV1 {
         *load_order.mods_mut() = value.mods().to_vec();
 
         Self {
+            version: 0,                 // Set when saving.
             id: value.id().to_string(),
             game: String::new(),        // To be filled after the from.
             load_order,                 // Movies need to be removed from this later.
+            mct_preset: None,
+            read_only: false,
+            signature: None,
+            locked: false,
         }
     }
 }
@@ -0,0 +1,92 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the shared code of the lists kept in Runcher's repo, like the lint patterns or the game quirks.
+//!
+//! Each list is a json file in the root of the repo. A copy of it may be shipped with the program, and the latest version
+//! is downloaded to the config folder, with the same file name, when the downloaded copy is missing or outdated.
+
+use anyhow::Result;
+use reqwest::blocking::Client;
+use serde::de::DeserializeOwned;
+use serde_json::{from_reader, from_str};
+
+use std::fs::{rename, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use rpfm_ui_common::settings::config_path;
+
+const REMOTE_LISTS_URL: &str = "https://raw.githubusercontent.com/Frodo45127/runcher/master/";
+const REMOTE_LISTS_USER_AGENT: &str = "Runcher";
+
+/// Time after which we consider a downloaded list outdated.
+const REMOTE_LIST_MAX_AGE: Duration = Duration::from_secs(60 * 60 * 24);
+
+/// Timeouts for downloading a list. They're small files, so if it takes longer than this we're better off with the copy we have.
+const REMOTE_LIST_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const REMOTE_LIST_TIMEOUT: Duration = Duration::from_secs(30);
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function loads the downloaded copy of the provided list, falling back to the provided default one if it fails.
+///
+/// Lists without a default fall back to an empty list.
+pub fn load<T: DeserializeOwned + Default>(file_name: &str, default: Option<&str>) -> T {
+    remote_list_path(file_name).ok()
+        .and_then(|path| File::open(path).ok())
+        .and_then(|file| from_reader(BufReader::new(file)).ok())
+        .unwrap_or_else(|| default.and_then(|default| from_str(default).ok()).unwrap_or_default())
+}
+
+/// This function returns if the downloaded copy of the provided list is missing or outdated.
+pub fn needs_update(file_name: &str) -> bool {
+    remote_list_path(file_name).ok()
+        .and_then(|path| path.metadata().ok())
+        .and_then(|metadata| metadata.modified().ok())
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .map(|age| age > REMOTE_LIST_MAX_AGE)
+        .unwrap_or(true)
+}
+
+/// This function downloads the latest version of the provided list.
+///
+/// The list is only saved if it's valid, and it's written to a temporary file first, so a failed write doesn't leave a broken list behind.
+pub fn update<T: DeserializeOwned>(file_name: &str) -> Result<()> {
+    let data = Client::builder()
+        .user_agent(REMOTE_LISTS_USER_AGENT)
+        .connect_timeout(REMOTE_LIST_CONNECT_TIMEOUT)
+        .timeout(REMOTE_LIST_TIMEOUT)
+        .build()?
+        .get(format!("{REMOTE_LISTS_URL}{file_name}"))
+        .send()?
+        .error_for_status()?
+        .text()?;
+
+    let _: T = from_str(&data)?;
+
+    let path = remote_list_path(file_name)?;
+    let temp_path = path.with_extension("json.part");
+    let mut file = BufWriter::new(File::create(&temp_path)?);
+    file.write_all(data.as_bytes())?;
+    file.flush()?;
+    drop(file);
+
+    rename(temp_path, path)?;
+    Ok(())
+}
+
+/// This function returns the path of the downloaded copy of the provided list.
+pub fn remote_list_path(file_name: &str) -> Result<PathBuf> {
+    Ok(config_path()?.join(file_name))
+}
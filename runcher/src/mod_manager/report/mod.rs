@@ -0,0 +1,194 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the generation of mod reports.
+//!
+//! Reports are standalone HTML or Markdown files with the current setup of a game: launch options, load order and mods by category.
+//! They're meant to be shared in communities or attached to bug reports, so their text is in English. Only the names of the launch options are localized.
+
+use anyhow::Result;
+use time::{formatting::Formattable, OffsetDateTime};
+
+use std::path::Path;
+
+use rpfm_lib::games::GameInfo;
+
+use super::game_config::GameConfig;
+use super::load_order::LoadOrder;
+use super::mods::Mod;
+
+const WORKSHOP_ITEM_URL: &str = "https://steamcommunity.com/sharedfiles/filedetails/?id=";
+const GITHUB_URL: &str = "https://github.com/";
+
+/// Dates are always in the same format, as reports are meant to be read by other people.
+const DATE_FORMAT: &str = "[year]-[month]-[day]";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    Html,
+    Markdown,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl ReportFormat {
+
+    /// This function returns the format matching the extension of the provided path. Anything that's not Markdown is HTML.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().map(|extension| extension.to_string_lossy().to_lowercase()).as_deref() {
+            Some("md") | Some("markdown") => Self::Markdown,
+            _ => Self::Html,
+        }
+    }
+
+    fn heading(&self, level: usize, text: &str) -> String {
+        match self {
+            Self::Html => format!("<h{level}>{}</h{level}>\n", escape_html(text)),
+            Self::Markdown => format!("{} {}\n\n", "#".repeat(level), text),
+        }
+    }
+
+    fn paragraph(&self, text: &str) -> String {
+        match self {
+            Self::Html => format!("<p>{}</p>\n", escape_html(text)),
+            Self::Markdown => format!("{}\n\n", text),
+        }
+    }
+
+    /// Items are expected to be already formatted, so they can contain links.
+    fn list(&self, items: &[String], ordered: bool) -> String {
+        match self {
+            Self::Html => {
+                let tag = if ordered { "ol" } else { "ul" };
+                format!("<{tag}>\n{}</{tag}>\n", items.iter().map(|item| format!("<li>{item}</li>\n")).collect::<String>())
+            }
+            Self::Markdown => {
+                let items = items.iter()
+                    .enumerate()
+                    .map(|(index, item)| if ordered { format!("{}. {item}\n", index + 1) } else { format!("- {item}\n") })
+                    .collect::<String>();
+                format!("{items}\n")
+            }
+        }
+    }
+
+    fn text(&self, text: &str) -> String {
+        match self {
+            Self::Html => escape_html(text),
+            Self::Markdown => text.replace('[', "\\[").replace(']', "\\]"),
+        }
+    }
+
+    fn link(&self, text: &str, url: &str) -> String {
+        match self {
+            Self::Html => format!("<a href=\"{}\">{}</a>", escape_html(url), escape_html(text)),
+            Self::Markdown => format!("[{}]({})", self.text(text), url),
+        }
+    }
+
+    fn document(&self, title: &str, body: &str) -> String {
+        match self {
+            Self::Html => format!("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n{}</body>\n</html>\n", escape_html(title), body),
+            Self::Markdown => body.to_owned(),
+        }
+    }
+}
+
+/// This function generates a report of the current setup of the provided game.
+///
+/// Launch options are received already formatted as name and value, as they depend on the game and only the UI knows which ones apply.
+pub fn mod_report(format: ReportFormat, game: &GameInfo, game_config: &GameConfig, load_order: &LoadOrder, data_path: &Path, launch_options: &[(String, String)], runcher_version: &str) -> Result<String> {
+    let date_format = time::format_description::parse(DATE_FORMAT)?;
+    let title = format!("{} mod report", game.display_name());
+    let mut body = format.heading(1, &title);
+    body.push_str(&format.paragraph(&format!("Generated with Runcher {runcher_version}.")));
+
+    body.push_str(&format.heading(2, "Launch options"));
+    if launch_options.is_empty() {
+        body.push_str(&format.paragraph("None."));
+    } else {
+        let options = launch_options.iter()
+            .map(|(name, value)| format!("{}: {}", format.text(name), format.text(value)))
+            .collect::<Vec<_>>();
+        body.push_str(&format.list(&options, false));
+    }
+
+    body.push_str(&format.heading(2, "Load order"));
+    let mods = load_order.mods().iter()
+        .chain(load_order.movies().iter())
+        .map(|mod_id| match game_config.mods().get(mod_id) {
+            Some(modd) => mod_entry(format, modd, &date_format),
+            None => format.text(mod_id),
+        })
+        .collect::<Vec<_>>();
+
+    if mods.is_empty() {
+        body.push_str(&format.paragraph("No mods enabled."));
+    } else {
+        body.push_str(&format.list(&mods, true));
+    }
+
+    body.push_str(&format.heading(2, "Mods by category"));
+    for category in game_config.categories_order() {
+        let mods = game_config.categories().get(category)
+            .map(|mod_ids| mod_ids.iter()
+                .filter_map(|mod_id| game_config.mods().get(mod_id))
+                .filter(|modd| !modd.paths().is_empty())
+                .map(|modd| {
+                    let status = if modd.enabled(data_path) { "enabled" } else { "disabled" };
+                    format!("{} ({})", mod_entry(format, modd, &date_format), status)
+                })
+                .collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        if !mods.is_empty() {
+            body.push_str(&format.heading(3, category));
+            body.push_str(&format.list(&mods, false));
+        }
+    }
+
+    Ok(format.document(&title, &body))
+}
+
+/// This function returns the name of the mod with a link to where it can be downloaded, its pack and its version, if known.
+fn mod_entry<F: Formattable + ?Sized>(format: ReportFormat, modd: &Mod, date_format: &F) -> String {
+    let name = match (modd.steam_id(), modd.github_source()) {
+        (_, Some(source)) => format.link(modd.name(), &format!("{GITHUB_URL}{}", source.repo())),
+        (Some(steam_id), None) => format.link(modd.name(), &format!("{WORKSHOP_ITEM_URL}{steam_id}")),
+        (None, None) => format.text(modd.name()),
+    };
+
+    let version = match modd.github_source() {
+        Some(source) => Some(source.version().to_owned()),
+        None if *modd.time_updated() != 0 => OffsetDateTime::from_unix_timestamp(*modd.time_updated() as i64).ok()
+            .and_then(|date| date.format(date_format).ok())
+            .map(|date| format!("updated {date}")),
+        None => None,
+    };
+
+    match version {
+        Some(version) => format!("{name} - {} - {}", format.text(modd.id()), format.text(&version)),
+        None => format!("{name} - {}", format.text(modd.id())),
+    }
+}
+
+/// This function escapes the provided text, so it can be used in HTML and Qt's rich text.
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
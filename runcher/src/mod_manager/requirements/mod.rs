@@ -0,0 +1,177 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for detecting missing requirements of the enabled mods.
+//!
+//! Requirements are the parent packs in each pack's dependency list, and the DLCs needed by mods,
+//! which are deduced from their workshop tags using a list of tags known to require a DLC. That list can be updated from Runcher's repo.
+
+use anyhow::Result;
+use getset::*;
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use rpfm_lib::games::GameInfo;
+
+use super::game_config::GameConfig;
+use super::game_definitions::steam_id;
+use super::load_order::LoadOrder;
+use super::remote_lists;
+
+const REQUIRED_DLC_FILE_NAME: &str = "required_dlc.json";
+
+/// List of workshop tags requiring a DLC, shipped with the program.
+const REQUIRED_DLC_DEFAULT: &str = include_str!("../../../../required_dlc.json");
+
+const WORKSHOP_ITEM_URL: &str = "https://steamcommunity.com/sharedfiles/filedetails/?id=";
+const WORKSHOP_SEARCH_URL: &str = "https://steamcommunity.com/workshop/browse/?appid=";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct RequiredDlcs {
+    dlcs: Vec<RequiredDlc>,
+}
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct RequiredDlc {
+
+    /// Key of the game this DLC is for.
+    game_key: String,
+
+    /// Workshop tag used by mods requiring this DLC.
+    tag: String,
+
+    /// Visual name of the DLC.
+    name: String,
+
+    /// CA Packs installed with the DLC. If any of them is installed, we consider the DLC installed.
+    packs: Vec<String>,
+
+    /// Steam Store's url of the DLC.
+    url: String,
+}
+
+/// A requirement of an enabled mod that's not met.
+#[derive(Clone, Debug)]
+pub enum MissingRequirement {
+
+    /// A parent pack that's installed, but disabled. Contains the id of the parent.
+    DisabledParent(String),
+
+    /// A parent pack that's not installed at all. Contains the name of the parent.
+    MissingParent(String),
+
+    /// A DLC that's not installed. Contains the name of the DLC.
+    MissingDlc(String),
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl RequiredDlcs {
+
+    /// This function loads the downloaded list of DLCs required by workshop tags, falling back to the one shipped with the program if it fails.
+    pub fn load() -> Self {
+        remote_lists::load(REQUIRED_DLC_FILE_NAME, Some(REQUIRED_DLC_DEFAULT))
+    }
+
+    /// This function returns if the downloaded list of DLCs is missing or outdated.
+    pub fn needs_update() -> bool {
+        remote_lists::needs_update(REQUIRED_DLC_FILE_NAME)
+    }
+
+    /// This function downloads the latest list of DLCs. The list is only saved if it's valid.
+    pub fn update() -> Result<()> {
+        remote_lists::update::<Self>(REQUIRED_DLC_FILE_NAME)
+    }
+}
+
+impl MissingRequirement {
+
+    /// This function returns a link where the missing requirement can be found, if we know one.
+    pub fn link(&self, game: &GameInfo, game_config: &GameConfig, game_path: &Path) -> Option<String> {
+        match self {
+            Self::DisabledParent(mod_id) => game_config.mods()
+                .get(mod_id)
+                .and_then(|modd| modd.steam_id().as_ref())
+                .map(|steam_id| format!("{}{}", WORKSHOP_ITEM_URL, steam_id)),
+            Self::MissingParent(pack_name) => steam_id(game, game_path).ok()
+                .map(|app_id| format!("{}{}&searchtext={}", WORKSHOP_SEARCH_URL, app_id, pack_name.trim_end_matches(".pack"))),
+            Self::MissingDlc(name) => RequiredDlcs::load().dlcs()
+                .iter()
+                .find(|dlc| dlc.game_key() == game.key() && dlc.name() == name)
+                .map(|dlc| dlc.url().to_owned())
+                .filter(|url| !url.is_empty()),
+        }
+    }
+}
+
+/// This function returns the unmet requirements of the enabled mods, alongside the id of the mod requiring them.
+///
+/// The dependency lists are taken from the packs already loaded by the load order, so this doesn't touch the disk for mods.
+pub fn missing_requirements(game: &GameInfo, game_path: &Path, game_config: &GameConfig, load_order: &LoadOrder, game_data_path: &Path) -> Vec<(String, MissingRequirement)> {
+    let ca_packs = game.ca_packs_paths(game_path)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|path| path.file_name())
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .collect::<HashSet<_>>();
+
+    let enabled = load_order.mods()
+        .iter()
+        .chain(load_order.movies().iter())
+        .filter_map(|mod_id| game_config.mods().get(mod_id))
+        .filter(|modd| modd.enabled(game_data_path))
+        .collect::<Vec<_>>();
+
+    let mut missing = vec![];
+
+    // Parent packs. Only hard dependencies are requirements. Soft ones are optional by definition.
+    for modd in &enabled {
+        if let Some(pack) = load_order.packs().get(modd.id()) {
+            for (_, parent) in pack.dependencies().iter().filter(|(hard, _)| *hard) {
+                let parent_lower = parent.to_lowercase();
+                if ca_packs.contains(&parent_lower) || enabled.iter().any(|x| x.id().to_lowercase() == parent_lower) {
+                    continue;
+                }
+
+                let requirement = match game_config.mods().values().find(|x| x.id().to_lowercase() == parent_lower) {
+
+                    // Ignored parents are usually libraries embedded in the mods that depend on them, so they're not reported.
+                    Some(parent_mod) if *parent_mod.ignored() => continue,
+                    Some(parent_mod) => MissingRequirement::DisabledParent(parent_mod.id().to_owned()),
+                    None => MissingRequirement::MissingParent(parent.to_owned()),
+                };
+
+                missing.push((modd.id().to_owned(), requirement));
+            }
+        }
+    }
+
+    // DLCs. A DLC is installed if any of its packs is installed.
+    let dlcs = RequiredDlcs::load();
+    for modd in &enabled {
+        for dlc in dlcs.dlcs().iter().filter(|dlc| dlc.game_key() == game.key()) {
+            if modd.tags().iter().any(|tag| tag.eq_ignore_ascii_case(dlc.tag())) && !dlc.packs().iter().any(|pack| ca_packs.contains(&pack.to_lowercase())) {
+                missing.push((modd.id().to_owned(), MissingRequirement::MissingDlc(dlc.name().to_owned())));
+            }
+        }
+    }
+
+    missing
+}
@@ -0,0 +1,175 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the automatic backups of the saves of a game, done before each launch.
+//!
+//! Mod updates regularly break saves, so before launching we zip the latest saves of the game.
+//! Only the newest backups of each game are kept, so the folder doesn't grow forever. The backups can be listed and restored
+//! from the save selector.
+
+use anyhow::{anyhow, Result};
+use getset::Getters;
+use zip::{CompressionMethod, write::SimpleFileOptions, ZipArchive, ZipWriter};
+
+use std::cmp::Reverse;
+use std::fs::{read_dir, remove_file, rename, DirBuilder, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SAVE_BACKUP_FILE_NAME_START: &str = "runcher_saves_";
+const SAVE_BACKUP_FILE_NAME_END: &str = ".zip";
+
+/// Extension of the temporary files saves are extracted to, so a failed restore never leaves a half-written save behind.
+const RESTORE_TEMP_EXTENSION: &str = "restoring";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// A backup of the saves of a game.
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct SaveBackup {
+
+    /// Path of the backup's zip.
+    path: PathBuf,
+
+    /// Date the backup was made, in seconds since the unix epoch.
+    date: u64,
+
+    /// Names of the saves in the backup.
+    saves: Vec<String>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function zips the newest saves in the provided saves folder into a backup in the provided folder, then removes the oldest backups
+/// of the game over the retention limit.
+///
+/// Returns the path of the backup, or None if the game has no saves to backup.
+pub fn backup_saves(game_key: &str, saves_path: &Path, folder: &Path, count: usize, retention: usize) -> Result<Option<PathBuf>> {
+    let mut saves = files_by_date(saves_path, |_| true);
+    saves.truncate(count);
+
+    if saves.is_empty() {
+        return Ok(None);
+    }
+
+    DirBuilder::new().recursive(true).create(folder)?;
+
+    let date = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let backup_path = folder.join(format!("{SAVE_BACKUP_FILE_NAME_START}{game_key}_{date}{SAVE_BACKUP_FILE_NAME_END}"));
+
+    let mut zip = ZipWriter::new(BufWriter::new(File::create(&backup_path)?));
+    let options = SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Stored)
+        .large_file(true);
+
+    for save in &saves {
+        if let Some(file_name) = save.file_name() {
+            zip.start_file(file_name.to_string_lossy().to_string(), options)?;
+            std::io::copy(&mut BufReader::new(File::open(save)?), &mut zip)?;
+        }
+    }
+
+    zip.finish()?.flush()?;
+
+    // Backups from other games share the folder, so only the ones of this game count towards its limit.
+    let prefix = format!("{SAVE_BACKUP_FILE_NAME_START}{game_key}_");
+    let backups = files_by_date(folder, |name| name.starts_with(&prefix) && name.ends_with(SAVE_BACKUP_FILE_NAME_END));
+    for old_backup in backups.iter().skip(retention) {
+        remove_file(old_backup)?;
+    }
+
+    Ok(Some(backup_path))
+}
+
+/// This function returns the backups of the saves of the provided game in the provided folder, newest first.
+///
+/// Backups that cannot be read are skipped.
+pub fn save_backups(game_key: &str, folder: &Path) -> Vec<SaveBackup> {
+    let prefix = format!("{SAVE_BACKUP_FILE_NAME_START}{game_key}_");
+    files_by_date(folder, |name| name.starts_with(&prefix) && name.ends_with(SAVE_BACKUP_FILE_NAME_END))
+        .into_iter()
+        .filter_map(|path| {
+            let file_name = path.file_name()?.to_string_lossy().to_string();
+            let date = file_name.strip_prefix(&prefix)?.strip_suffix(SAVE_BACKUP_FILE_NAME_END)?.parse().ok()?;
+            let zip = ZipArchive::new(BufReader::new(File::open(&path).ok()?)).ok()?;
+            let saves = zip.file_names().map(|name| name.to_owned()).collect();
+
+            Some(SaveBackup {
+                path,
+                date,
+                saves,
+            })
+        })
+        .collect()
+}
+
+/// This function returns the saves of the provided backup that already exist in the provided saves folder, so the user can be asked before overwriting them.
+pub fn existing_saves(backup: &SaveBackup, saves_path: &Path) -> Vec<String> {
+    backup.saves.iter()
+        .filter(|name| saves_path.join(name).exists())
+        .cloned()
+        .collect()
+}
+
+/// This function restores the saves of the provided backup to the provided saves folder, overwriting the saves with the same name.
+///
+/// Each save is extracted to a temporary file and then renamed, so a failed restore never leaves a broken save. Returns the names of the restored saves.
+pub fn restore_save_backup(backup_path: &Path, saves_path: &Path) -> Result<Vec<String>> {
+    DirBuilder::new().recursive(true).create(saves_path)?;
+
+    let mut zip = ZipArchive::new(BufReader::new(File::open(backup_path)?))?;
+    let mut restored = vec![];
+    for index in 0..zip.len() {
+        let mut file = zip.by_index(index)?;
+
+        // Only use the file name, so broken backups cannot write outside the saves folder.
+        let file_name = Path::new(file.name()).file_name()
+            .ok_or_else(|| anyhow!("Invalid file name in save backup: {}.", file.name()))?
+            .to_string_lossy()
+            .to_string();
+
+        let save_path = saves_path.join(&file_name);
+        let temp_path = save_path.with_extension(RESTORE_TEMP_EXTENSION);
+
+        let result = File::create(&temp_path)
+            .map(BufWriter::new)
+            .and_then(|mut temp_file| std::io::copy(&mut file, &mut temp_file).and_then(|_| temp_file.flush()))
+            .and_then(|_| rename(&temp_path, &save_path));
+
+        if let Err(error) = result {
+            let _ = remove_file(&temp_path);
+            return Err(error.into());
+        }
+
+        restored.push(file_name);
+    }
+
+    Ok(restored)
+}
+
+/// This function returns the files in the provided folder whose name passes the provided filter, newest first.
+fn files_by_date(folder: &Path, filter: impl Fn(&str) -> bool) -> Vec<PathBuf> {
+    let mut files = read_dir(folder)
+        .map(|entries| entries.flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file() && path.file_name().is_some_and(|name| filter(&name.to_string_lossy())))
+            .filter_map(|path| path.metadata().and_then(|metadata| metadata.modified()).ok().map(|modified| (path, modified)))
+            .collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    files.sort_by_key(|(_, modified)| Reverse(*modified));
+    files.into_iter().map(|(path, _)| path).collect()
+}
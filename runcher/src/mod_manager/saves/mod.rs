@@ -8,10 +8,13 @@
 // https://github.com/Frodo45127/runcher/blob/master/LICENSE.
 //---------------------------------------------------------------------------//
 
+use anyhow::{anyhow, Result};
 use getset::*;
 use serde::{Deserialize, Serialize};
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use rpfm_lib::files::{esf::NodeType, RFile, RFileDecoded};
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -28,3 +31,49 @@ pub struct Save {
 //-------------------------------------------------------------------------------//
 //                             Implementations
 //-------------------------------------------------------------------------------//
+
+impl Save {
+
+    /// This function reads the list of mods the save was made with from the save file, in the order they were loaded.
+    ///
+    /// This decodes the entire save, so it may take a while with late campaign saves.
+    pub fn read_mods(path: &Path) -> Result<Vec<String>> {
+        let mut file = RFile::new_from_file_path(path)?;
+        file.guess_file_type()?;
+
+        match file.decode(&None, false, true)? {
+            Some(RFileDecoded::ESF(esf)) => {
+                let mut mods = vec![];
+                Self::mods_from_node(esf.root_node(), &mut mods);
+
+                if mods.is_empty() {
+                    Err(anyhow!("No mod list found in the save."))
+                } else {
+                    Ok(mods)
+                }
+            }
+            _ => Err(anyhow!("The save could not be decoded.")),
+        }
+    }
+
+    /// This function looks for the mod history block in the provided node and its children, and adds the packs in it to the provided list.
+    fn mods_from_node(node: &NodeType, mods: &mut Vec<String>) {
+        if let NodeType::Record(node) = node {
+            if node.name() == "mod_history_block_name" {
+                for children in node.children() {
+                    if let Some(NodeType::Ascii(pack_name)) = children.first() {
+                        if !mods.contains(pack_name) {
+                            mods.push(pack_name.to_owned());
+                        }
+                    }
+                }
+            } else {
+                for children in node.children() {
+                    for child in children {
+                        Self::mods_from_node(child, mods);
+                    }
+                }
+            }
+        }
+    }
+}
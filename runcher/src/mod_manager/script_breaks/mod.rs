@@ -0,0 +1,263 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for the knowledge base of known script breaks.
+//!
+//! The knowledge base is a community-maintained list of script errors caused by mods, downloaded from Runcher's repo.
+//! Script breaks are identified by a signature: the first error line of the break, stripped of anything specific to the user's run.
+
+use anyhow::Result;
+use getset::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use std::path::Path;
+use std::time::SystemTime;
+
+use rpfm_lib::files::Container;
+use rpfm_lib::games::GameInfo;
+use rpfm_lib::utils::files_from_subdir;
+
+use crate::logging::sanitize;
+
+use super::game_config::GameConfig;
+use super::load_order::LoadOrder;
+use super::log_patterns::LogPatterns;
+use super::remote_lists;
+
+const KNOWN_SCRIPT_BREAKS_FILE_NAME: &str = "known_script_breaks.json";
+const REPORT_SCRIPT_BREAK_URL: &str = "https://github.com/Frodo45127/runcher/issues/new";
+const WORKSHOP_COMMENTS_URL: &str = "https://steamcommunity.com/sharedfiles/filedetails/comments/";
+
+/// Max amount of lines of the log included in reports for mod authors. Full logs are too long for a Workshop comment.
+const MOD_BREAK_REPORT_MAX_LOG_LINES: usize = 20;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct KnownScriptBreaks {
+    breaks: Vec<KnownScriptBreak>,
+}
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct KnownScriptBreak {
+
+    /// Regex to match against the signature of the script break.
+    pattern: String,
+
+    /// Name of the mod causing the script break.
+    mod_name: String,
+
+    /// Version of the mod with the issue. Free text, as mods have no standard versioning.
+    affected_version: String,
+
+    /// Version of the mod fixing the issue, if it has been fixed.
+    fixed_version: Option<String>,
+
+    /// Extra info about the issue, like workarounds.
+    notes: String,
+}
+
+/// Script break found in the logs of a game.
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct ScriptBreak {
+    posible_pack: String,
+    posible_pack_mod: String,
+    posible_pack_link: Option<String>,
+    full_log: String,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl KnownScriptBreaks {
+
+    /// This function loads the downloaded knowledge base. If there's none, it returns an empty one.
+    pub fn load() -> Self {
+        remote_lists::load(KNOWN_SCRIPT_BREAKS_FILE_NAME, None)
+    }
+
+    /// This function returns if the downloaded knowledge base is missing or outdated.
+    pub fn needs_update() -> bool {
+        remote_lists::needs_update(KNOWN_SCRIPT_BREAKS_FILE_NAME)
+    }
+
+    /// This function downloads the latest knowledge base. It's only saved if it's valid.
+    pub fn update() -> Result<()> {
+        remote_lists::update::<Self>(KNOWN_SCRIPT_BREAKS_FILE_NAME)
+    }
+
+    /// This function returns the known script break matching the provided signature, if any.
+    pub fn find(&self, signature: &str) -> Option<&KnownScriptBreak> {
+        self.breaks().iter().find(|known| Regex::new(known.pattern()).map(|regex| regex.is_match(signature)).unwrap_or(false))
+    }
+}
+
+impl KnownScriptBreak {
+
+    /// This function returns a short, human-readable description of the issue.
+    pub fn description(&self) -> String {
+        let mut description = format!("Known issue in {} (version {})", self.mod_name, self.affected_version);
+        if let Some(ref fixed_version) = self.fixed_version {
+            description.push_str(&format!(", fixed in {}", fixed_version));
+        }
+
+        description.push('.');
+
+        if !self.notes.is_empty() {
+            description.push(' ');
+            description.push_str(&self.notes);
+        }
+
+        description
+    }
+}
+
+/// This function looks for script breaks in the logs written by the provided game since the provided date.
+///
+/// Which files are logs and how errors look in them depends on the game, so both come from the provided log patterns.
+/// The packs causing them are searched in the data loaded by the game, so this needs the game config and load order used to launch it.
+pub fn analyze_logs(game: &GameInfo, game_path: &Path, game_config: &GameConfig, load_order: &LoadOrder, log_patterns: &LogPatterns, start_date: &SystemTime) -> Result<Vec<ScriptBreak>> {
+
+    // NOTE: THIS IS A HACK. WE NEED TO USE SOME KIND OF CACHED DATA, NOT REMAKE IT HERE!!!!
+    let (pack, _) = load_order.data_pack(game_config, game, game_path)?;
+
+    let log_format = log_patterns.game(game);
+    let vanilla_paths = game.ca_packs_paths(game_path)?;
+    let files = files_from_subdir(game_path, false)?;
+    let mut paths = vec![];
+    for path in &files {
+        let is_log_file = path.file_name().map(|name| log_format.is_log_file(&name.to_string_lossy())).unwrap_or(false);
+        if is_log_file && path.metadata()?.modified()? > *start_date {
+            paths.push(path);
+        }
+    }
+
+    let mut breaks = vec![];
+    for path in &paths {
+
+        // Some logs, like the clockwork one, are windows-1252, so we read them lossy instead of skipping them.
+        let data = String::from_utf8_lossy(&std::fs::read(path)?).to_string();
+        for error in log_format.errors(&data) {
+            let mut script_break = ScriptBreak {
+                full_log: error.message().to_owned(),
+                ..Default::default()
+            };
+
+            // NOTE: pack finding only works if the pack that caused it is in the current run. Take that into account for tests.
+            for path in error.paths() {
+                if let Some(file) = pack.file(path, true) {
+                    if let Some(pack_name) = file.container_name() {
+                        if !pack_name.is_empty() && vanilla_paths.iter().all(|x| &x.file_name().unwrap().to_string_lossy().to_string() != pack_name) {
+                            script_break.posible_pack = pack_name.to_owned();
+
+                            // This is only valid in newer games!!!
+                            let modd = game_config.mods().get(pack_name);
+                            script_break.posible_pack_mod = modd
+                                .map(|modd| modd.name().to_string())
+                                .unwrap_or_default();
+                            script_break.posible_pack_link = modd
+                                .and_then(|modd| modd.steam_id()
+                                    .clone()
+                                    .map(|id| format!("https://steamcommunity.com/sharedfiles/filedetails/?id={}", id)));
+                            break;
+                        }
+                    }
+                }
+            }
+
+            breaks.push(script_break);
+        }
+    }
+
+    Ok(breaks)
+}
+
+/// This function returns the signature of a script break from its log.
+///
+/// The signature is the first error line of the log, without timestamps, absolute paths or object ids,
+/// so it's the same between runs and doesn't contain any info about the user.
+///
+/// The home folder is removed first, like in bug reports. Other absolute paths, like the game's one, are then replaced as a whole.
+pub fn script_break_signature(log: &str) -> Option<String> {
+    let error_line = Regex::new(r#"\[string "([^"]+)"\]:(\d+): ([^\r\n]*)"#).ok()?;
+    let absolute_path = Regex::new(r#"[A-Za-z]:[\\/][^\s"\]]*"#).ok()?;
+    let object_id = Regex::new(r"\[\d+\]").ok()?;
+
+    let captures = error_line.captures(log)?;
+    let path = captures.get(1)?.as_str().replace('\\', "/");
+    let line = captures.get(2)?.as_str();
+    let message = sanitize(captures.get(3)?.as_str());
+    let message = absolute_path.replace_all(&message, "<path>");
+    let message = object_id.replace_all(&message, "[id]");
+
+    Some(format!("{}:{}: {}", path, line, message.trim()))
+}
+
+/// This function returns the url to report a new script break with the provided signature.
+pub fn report_script_break_url(signature: &str, pack_name: &str) -> String {
+    let title = format!("Script break signature: {}", signature);
+    let body = format!("Pack: {}\n\nSignature:\n```\n{}\n```\n\nMod version:\n\nNotes:\n", pack_name, signature);
+
+    format!("{}?title={}&body={}", REPORT_SCRIPT_BREAK_URL, percent_encode(&title), percent_encode(&body))
+}
+
+/// This function returns a report for the author of the mod that caused a script break, ready to be pasted in the mod's comments.
+///
+/// The home folder is removed from the log, so the report doesn't leak the user's name.
+pub fn mod_break_report(log: &str, pack_name: &str, game_name: &str, game_version: Option<u32>, load_order: &[String], runcher_version: &str) -> String {
+    let log = sanitize(log);
+    let lines = log.lines()
+        .map(|line| line.trim_end())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+
+    let mut excerpt = lines.iter()
+        .take(MOD_BREAK_REPORT_MAX_LOG_LINES)
+        .copied()
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if lines.len() > MOD_BREAK_REPORT_MAX_LOG_LINES {
+        excerpt.push_str("\n[...]");
+    }
+
+    let game_version = game_version.map(|version| version.to_string()).unwrap_or_else(|| "Unknown".to_owned());
+
+    format!("[b]Script error caused by {}[/b]\n\nGame: {} (version {})\nLauncher: Runcher {}\n\n[b]Error:[/b]\n[code]\n{}\n[/code]\n\n[b]Load order:[/b]\n[code]\n{}\n[/code]\n",
+        pack_name,
+        game_name,
+        game_version,
+        runcher_version,
+        excerpt,
+        load_order.join("\n")
+    )
+}
+
+/// This function returns the url of the comments page of a Workshop mod.
+pub fn workshop_comments_url(steam_id: &str) -> String {
+    format!("{}{}", WORKSHOP_COMMENTS_URL, steam_id)
+}
+
+/// This function encodes a string so it can be used as part of an url query.
+fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
@@ -0,0 +1,250 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the fallback for secondary folders on a different drive than the game.
+//!
+//! Some older games refuse to load packs through `add_working_directory` if the folder is not in the same drive as the game.
+//! For those, the enabled packs in the secondary folder are staged in /data before launching the game, by linking them if the system allows it
+//! or by copying them if not (Windows only allows links with admin rights or developer mode), and removed once the game is closed.
+//! The staged packs are tracked in a file, so they can be cleaned up on the next launch if Runcher was closed before the game.
+
+use anyhow::{anyhow, Result};
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_reader, to_writer_pretty};
+
+use std::fs::{copy, read_link, remove_file, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Component, Path, PathBuf};
+
+use rpfm_lib::games::GameInfo;
+use rpfm_lib::integrations::log::*;
+
+use rpfm_ui_common::settings::config_path;
+
+use super::game_config::GameConfig;
+use super::game_quirks::game_quirks;
+use super::launch::LaunchStep;
+use super::load_order::LoadOrder;
+use super::secondary_mods_path;
+
+const STAGED_PACKS_FILE_NAME_START: &str = "staged_packs_";
+const STAGED_PACKS_FILE_NAME_END: &str = ".json";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// How a pack from the secondary folder has been staged in /data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StagingStrategy {
+
+    /// The pack has been linked, so it takes no extra space.
+    Link,
+
+    /// The system didn't allow us to link it, so the pack has been copied.
+    Copy,
+}
+
+/// A pack from the secondary folder staged in /data.
+#[derive(Clone, Debug, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct StagedPack {
+
+    /// Path of the pack in the secondary folder.
+    source: PathBuf,
+
+    /// Path of the pack in /data.
+    dest: PathBuf,
+
+    /// How the pack was staged.
+    strategy: StagingStrategy,
+
+    /// Size of the staged file, so we can tell if it's still our copy before removing it.
+    size: u64,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl StagingStrategy {
+
+    /// This function returns the step reported to the UI while packs are being staged with this strategy.
+    pub fn launch_step(&self) -> LaunchStep {
+        match self {
+            Self::Link => LaunchStep::LinkingSecondaryPacks,
+            Self::Copy => LaunchStep::CopyingSecondaryPacks,
+        }
+    }
+
+    /// This function returns how the strategy is shown in the launch manifest.
+    pub fn manifest_label(&self) -> &'static str {
+        match self {
+            Self::Link => "linked",
+            Self::Copy => "copied",
+        }
+    }
+}
+
+impl StagedPack {
+
+    /// This function returns if the file in /data is still the one we staged, so we don't remove files we didn't put there.
+    fn is_ours(&self) -> bool {
+        match self.strategy {
+            StagingStrategy::Link => self.dest.is_symlink() && read_link(&self.dest).is_ok_and(|target| target == self.source),
+            StagingStrategy::Copy => !self.dest.is_symlink() && self.dest.metadata().is_ok_and(|metadata| metadata.is_file() && metadata.len() == self.size),
+        }
+    }
+}
+
+/// This function returns if the packs in the secondary folder of the provided game need to be staged in /data to be loaded.
+pub fn needs_secondary_staging(game: &GameInfo, game_path: &Path) -> bool {
+    if !*game_quirks(game).working_directories_same_drive_only() {
+        return false;
+    }
+
+    match (secondary_mods_path(game.key()), game.data_path(game_path)) {
+        (Ok(secondary_path), Ok(data_path)) => drive(&secondary_path) != drive(&data_path.canonicalize().unwrap_or(data_path)),
+        _ => false,
+    }
+}
+
+/// This function stages in /data the enabled packs of the secondary folder, returning a copy of the game config pointing to the staged packs.
+///
+/// The provided closure is called with the step of the strategy used, each time it changes.
+/// Packs with the same name as a file already in /data are not staged, as we don't want to touch files we didn't put there.
+pub fn stage_secondary_packs(game: &GameInfo, data_path: &Path, game_config: &GameConfig, load_order: &LoadOrder, progress: impl Fn(LaunchStep)) -> Result<GameConfig> {
+    let secondary_path = secondary_mods_path(game.key())?;
+    let data_path = data_path.canonicalize()?;
+    let mut game_config = game_config.clone();
+    let mut staged = staged_packs(game.key());
+    let mut last_strategy = None;
+
+    for mod_id in load_order.mods().iter().chain(load_order.movies().iter()) {
+        if let Some(modd) = game_config.mods_mut().get_mut(mod_id) {
+            let source = match modd.paths().first() {
+                Some(path) if path.starts_with(&secondary_path) => path.to_path_buf(),
+                _ => continue,
+            };
+
+            let file_name = match source.file_name() {
+                Some(file_name) => file_name.to_owned(),
+                None => continue,
+            };
+
+            let dest = data_path.join(file_name);
+            if dest.exists() {
+                warn!("Cannot stage {} in /data: a file with the same name is already there.", source.to_string_lossy());
+                continue;
+            }
+
+            let strategy = match link_pack(&source, &dest) {
+                Ok(_) => StagingStrategy::Link,
+                Err(_) => StagingStrategy::Copy,
+            };
+
+            // Copies can take a while, so the strategy is reported before doing them.
+            if last_strategy != Some(strategy) {
+                progress(strategy.launch_step());
+                last_strategy = Some(strategy);
+            }
+
+            let size = match strategy {
+                StagingStrategy::Link => 0,
+                StagingStrategy::Copy => copy(&source, &dest)?,
+            };
+
+            // Track it before anything else can fail, so it gets cleaned up even if the launch fails.
+            staged.push(StagedPack {
+                source: source.to_path_buf(),
+                dest: dest.to_path_buf(),
+                strategy,
+                size,
+            });
+            save_staged_packs(game.key(), &staged)?;
+
+            info!("Staged {} in /data. Strategy: {:?}.", source.to_string_lossy(), strategy);
+            modd.paths_mut().insert(0, dest);
+        }
+    }
+
+    Ok(game_config)
+}
+
+/// This function removes from /data the packs staged from the secondary folder of the provided game.
+///
+/// Files that are no longer the ones we staged, like packs the user replaced after the launch, are left alone and forgotten.
+pub fn clean_staged_packs(game_key: &str) -> Result<()> {
+    let mut remaining = vec![];
+    for pack in staged_packs(game_key) {
+        if !pack.is_ours() {
+            if pack.dest.exists() {
+                warn!("Not removing {} from /data: it's not the pack we staged there anymore.", pack.dest.to_string_lossy());
+            }
+            continue;
+        }
+
+        if remove_file(&pack.dest).is_err() {
+            remaining.push(pack);
+        }
+    }
+
+    save_staged_packs(game_key, &remaining)?;
+
+    if remaining.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!("The following staged packs couldn't be removed from /data. Is the game still running?: {:?}", remaining.iter().map(|pack| &pack.dest).collect::<Vec<_>>()))
+    }
+}
+
+/// This function returns the packs currently staged in /data for the provided game.
+pub fn staged_packs(game_key: &str) -> Vec<StagedPack> {
+    staged_packs_path(game_key).ok()
+        .and_then(|path| File::open(path).ok())
+        .and_then(|file| from_reader(BufReader::new(file)).ok())
+        .unwrap_or_default()
+}
+
+fn save_staged_packs(game_key: &str, packs: &[StagedPack]) -> Result<()> {
+    let path = staged_packs_path(game_key)?;
+    if packs.is_empty() {
+        if path.is_file() {
+            remove_file(path)?;
+        }
+
+        return Ok(());
+    }
+
+    let mut file = BufWriter::new(File::create(path)?);
+    to_writer_pretty(&mut file, packs)?;
+    file.flush()?;
+    Ok(())
+}
+
+fn staged_packs_path(game_key: &str) -> Result<PathBuf> {
+    Ok(config_path()?.join(format!("{STAGED_PACKS_FILE_NAME_START}{game_key}{STAGED_PACKS_FILE_NAME_END}")))
+}
+
+/// Drives only exist on Windows. Elsewhere, all paths are in the same one.
+fn drive(path: &Path) -> Option<Component<'_>> {
+    path.components().next().filter(|component| matches!(component, Component::Prefix(_)))
+}
+
+#[cfg(target_os = "windows")]
+fn link_pack(source: &Path, dest: &Path) -> std::io::Result<()> {
+    std::os::windows::fs::symlink_file(source, dest)
+}
+
+#[cfg(not(target_os = "windows"))]
+fn link_pack(source: &Path, dest: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(source, dest)
+}
@@ -0,0 +1,119 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the history of launch sessions of each game.
+//!
+//! Each time a game is launched, we keep the load order it was launched with, so users can find out when they last played with a mod,
+//! and go back to the exact setup of a past session.
+
+use anyhow::Result;
+use getset::*;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_reader, to_writer};
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rpfm_ui_common::settings::config_path;
+
+use super::load_order::LoadOrder;
+
+const SESSIONS_FILE_NAME_START: &str = "sessions_";
+const SESSIONS_FILE_NAME_END: &str = ".json";
+
+/// Amount of sessions we keep per game. Older ones are dropped.
+const MAX_SESSIONS: usize = 200;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Default, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct SessionHistory {
+
+    /// Sessions of the game, oldest first.
+    sessions: Vec<Session>,
+}
+
+#[derive(Clone, Debug, Getters, Serialize, Deserialize)]
+#[getset(get = "pub")]
+pub struct Session {
+
+    /// Time the game was launched, in seconds since the unix epoch.
+    timestamp: u64,
+
+    /// Profile selected when the game was launched. May not be the one the load order came from.
+    profile: String,
+
+    /// Load order the game was launched with.
+    load_order: LoadOrder,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl SessionHistory {
+
+    /// This function loads the session history of the provided game. A missing or broken history is treated as empty.
+    pub fn load(game_key: &str) -> Self {
+        sessions_path(game_key).ok()
+            .and_then(|path| File::open(path).ok())
+            .and_then(|file| from_reader(BufReader::new(file)).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, game_key: &str) -> Result<()> {
+        let mut file = BufWriter::new(File::create(sessions_path(game_key)?)?);
+        to_writer(&mut file, self)?;
+        file.flush()?;
+        Ok(())
+    }
+
+    /// This function adds a session with the provided load order to the history of the provided game, dropping the oldest ones if needed.
+    pub fn add_session(game_key: &str, profile: &str, load_order: &LoadOrder) -> Result<()> {
+        let mut history = Self::load(game_key);
+        history.sessions.push(Session {
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            profile: profile.to_owned(),
+            load_order: load_order.clone(),
+        });
+
+        if history.sessions.len() > MAX_SESSIONS {
+            let excess = history.sessions.len() - MAX_SESSIONS;
+            history.sessions.drain(..excess);
+        }
+
+        history.save(game_key)
+    }
+
+    /// This function returns the last time each mod was part of a launched load order, by mod id.
+    pub fn last_launched(&self) -> HashMap<String, u64> {
+        let mut last_launched = HashMap::new();
+        for session in &self.sessions {
+            for mod_id in session.load_order.mods().iter().chain(session.load_order.movies().iter()) {
+                let timestamp = last_launched.entry(mod_id.to_owned()).or_insert(session.timestamp);
+                if *timestamp < session.timestamp {
+                    *timestamp = session.timestamp;
+                }
+            }
+        }
+
+        last_launched
+    }
+}
+
+fn sessions_path(game_key: &str) -> Result<PathBuf> {
+    Ok(config_path()?.join(format!("{SESSIONS_FILE_NAME_START}{game_key}{SESSIONS_FILE_NAME_END}")))
+}
@@ -0,0 +1,141 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the detection of shadowed packs.
+//!
+//! When the same pack is in more than one of the folders we load mods from (/data, the secondary folder and /content), only the first copy is loaded.
+//! If the copies are different, the ignored ones are usually old copies nobody remembers about, and the reason behind "my update isn't applying".
+//!
+//! Copies in /content are never removed, as Steam downloads them again. The only way to get rid of them is unsubscribing from the mod.
+
+use anyhow::Result;
+use getset::*;
+use rayon::prelude::*;
+use sha256::try_digest;
+
+use std::fs::{remove_file, rename};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use super::game_config::GameConfig;
+
+/// Extension added to renamed shadowed copies, so they're no longer detected as packs but can be recovered.
+const SHADOWED_EXTENSION: &str = "shadowed";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct ShadowedPack {
+
+    /// Id of the mod with multiple copies.
+    mod_id: String,
+
+    /// Copy the game loads.
+    winner: PackCopy,
+
+    /// Copies ignored because of the winner. Copies identical to the winner are not here, as ignoring them is harmless.
+    losers: Vec<PackCopy>,
+}
+
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct PackCopy {
+
+    /// Path of the copy.
+    path: PathBuf,
+
+    /// SHA256 hash of the copy.
+    hash: String,
+
+    /// Last modification date of the copy, in seconds since the unix epoch.
+    modified: u64,
+
+    /// If the copy is in /content, so it can't be removed without unsubscribing.
+    workshop: bool,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function returns the provided mods with copies in multiple folders that differ from the copy the game loads.
+pub fn shadowed_packs(game_config: &GameConfig, mod_ids: &[String], content_path: &Path) -> Vec<ShadowedPack> {
+    let mut shadowed = mod_ids.par_iter()
+        .filter_map(|mod_id| game_config.mods().get(mod_id))
+        .filter(|modd| modd.paths().len() > 1)
+        .filter_map(|modd| {
+
+            // Mod paths are sorted by priority, so the first one is the one loaded.
+            let winner = PackCopy::new(&modd.paths()[0], content_path).ok()?;
+            let losers = modd.paths()[1..].iter()
+                .filter_map(|path| PackCopy::new(path, content_path).ok())
+                .filter(|copy| copy.hash != winner.hash)
+                .collect::<Vec<_>>();
+
+            if losers.is_empty() {
+                None
+            } else {
+                Some(ShadowedPack {
+                    mod_id: modd.id().to_owned(),
+                    winner,
+                    losers,
+                })
+            }
+        })
+        .collect::<Vec<_>>();
+
+    shadowed.sort_by(|a, b| a.mod_id.cmp(&b.mod_id));
+    shadowed
+}
+
+impl ShadowedPack {
+
+    /// This function deletes the copies the game doesn't load, along with their images. Copies in /content are skipped.
+    pub fn delete_losers(&self) -> Result<()> {
+        for loser in self.losers.iter().filter(|loser| !loser.workshop) {
+            remove_file(&loser.path)?;
+
+            let mut image_path = loser.path.to_path_buf();
+            image_path.set_extension("png");
+            let _ = remove_file(image_path);
+        }
+
+        Ok(())
+    }
+
+    /// This function renames the copies the game doesn't load, so they're ignored but can be recovered. Copies in /content are skipped.
+    pub fn rename_losers(&self) -> Result<()> {
+        for loser in self.losers.iter().filter(|loser| !loser.workshop) {
+            let mut file_name = loser.path.file_name().unwrap_or_default().to_os_string();
+            file_name.push(".");
+            file_name.push(SHADOWED_EXTENSION);
+
+            rename(&loser.path, loser.path.with_file_name(file_name))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl PackCopy {
+    fn new(path: &Path, content_path: &Path) -> Result<Self> {
+        let modified = path.metadata()?.modified()?.duration_since(UNIX_EPOCH)?.as_secs();
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            hash: try_digest(path)?,
+            modified,
+            workshop: !content_path.as_os_str().is_empty() && path.starts_with(content_path),
+        })
+    }
+}
@@ -0,0 +1,355 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing a headless simulation of the mod management logic.
+//!
+//! A simulation builds a fake game install from a fixture (a json describing the packs and where they are),
+//! then runs the same code the UI uses to scan mods, build the load order and write the mod list file,
+//! without a GUI, an installed game, or touching the user's configs.
+//!
+//! Fixtures live in `runcher/fixtures/simulation`.
+
+use anyhow::{anyhow, Result};
+use getset::*;
+use serde::{Deserialize, Serialize};
+use serde_json::from_reader;
+
+use std::fs::{DirBuilder, File};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use rpfm_lib::files::pack::Pack;
+use rpfm_lib::games::{GameInfo, pfh_file_type::PFHFileType};
+
+use crate::SUPPORTED_GAMES;
+
+use super::category_rules::CategoryRule;
+use super::game_config::GameConfig;
+use super::launch::{launch_args, mod_list_file_path, write_mod_list_file};
+use super::load_order::LoadOrder;
+use super::pack_scan_cache::PackScanCache;
+use super::profiles::Profile;
+
+const GAME_FOLDER: &str = "steamapps/common/game";
+const SECONDARY_FOLDER: &str = "secondary";
+const SCRIPTS_FOLDER: &str = "scripts";
+const VANILLA_PACK_NAME: &str = "data.pack";
+const MANIFEST_FILE_NAME: &str = "manifest.txt";
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// Description of a fake game install, and the state of the mods in it.
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct SimulationFixture {
+
+    /// Key of the game to simulate.
+    game_key: String,
+
+    /// If the load order should be built automatically.
+    automatic_load_order: bool,
+
+    /// Packs in the fake install.
+    packs: Vec<SimulatedPack>,
+
+    /// Manual load order, by pack name. Ignored if the load order is automatic.
+    load_order: Vec<String>,
+
+    /// Extra arguments passed to the game on launch.
+    extra_args: Vec<String>,
+
+    /// Category rules of the game config, applied to the mods when they're scanned.
+    #[serde(default)]
+    category_rules: Vec<CategoryRule>,
+
+    /// Profile loaded after scanning the mods. If any, its load order replaces the one of the fixture, and only its mods are enabled.
+    #[serde(default)]
+    profile: Option<SimulatedProfile>,
+}
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct SimulatedProfile {
+
+    /// Name of the profile.
+    id: String,
+
+    /// If the load order of the profile is built automatically.
+    automatic_load_order: bool,
+
+    /// Mods of the profile, in load order.
+    load_order: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Getters, MutGetters, Setters, Serialize, Deserialize)]
+#[getset(get = "pub", get_mut = "pub", set = "pub")]
+pub struct SimulatedPack {
+
+    /// File name of the pack.
+    name: String,
+
+    /// Type of the pack.
+    pack_type: SimulatedPackType,
+
+    /// Folder the pack is in.
+    location: SimulatedPackLocation,
+
+    /// Steam Workshop id of the pack. Only used for packs in the content folder.
+    steam_id: Option<String>,
+
+    /// If the pack is enabled.
+    enabled: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SimulatedPackType {
+    #[default]
+    Mod,
+    Movie,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SimulatedPackLocation {
+    #[default]
+    Data,
+    Content,
+    Secondary,
+}
+
+/// Result of a simulation.
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct SimulationResult {
+
+    /// Game config after scanning the fake install.
+    game_config: GameConfig,
+
+    /// Load order built from the fake install.
+    load_order: LoadOrder,
+
+    /// Folder lines written to the mod list file.
+    folder_list: String,
+
+    /// Pack lines written to the mod list file.
+    pack_list: String,
+
+    /// Path of the mod list file (or user script, for older games) written by the simulation.
+    mod_list_file_path: PathBuf,
+
+    /// Arguments that would be passed to the game's executable.
+    launch_args: String,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl SimulationFixture {
+
+    /// This function loads a fixture from a json file.
+    pub fn load(path: &Path) -> Result<Self> {
+        from_reader(BufReader::new(File::open(path)?)).map_err(From::from)
+    }
+
+    /// This function runs the simulation in the provided folder, which should be empty.
+    ///
+    /// Configs are not saved, so this doesn't interfere with the user's ones.
+    pub fn run(&self, root: &Path) -> Result<SimulationResult> {
+        let game = SUPPORTED_GAMES.game(&self.game_key).ok_or_else(|| anyhow!("Unsupported game in fixture: {}.", self.game_key))?;
+        let game_path = root.join(GAME_FOLDER);
+        let secondary_paths = self.create_fake_install(game, root, &game_path)?;
+
+        let mut game_config = GameConfig::default();
+        game_config.set_game_key(game.key().to_owned());
+        game_config.set_category_rules(self.category_rules.to_vec());
+        game_config.scan_mods(game, &game_path, &Some(secondary_paths), &mut PackScanCache::default(), true)?;
+
+        for pack in &self.packs {
+            if let Some(modd) = game_config.mods_mut().get_mut(&pack.name) {
+                modd.set_enabled(pack.enabled);
+            }
+        }
+
+        let game_data_path = game.data_path(&game_path)?;
+        let mut load_order = LoadOrder::default();
+        load_order.set_automatic(self.automatic_load_order);
+        load_order.set_mods(self.load_order.to_vec());
+
+        // Same as loading a profile from the UI.
+        if let Some(ref profile) = self.profile {
+            let profile = profile.to_profile(game);
+            game_config.enable_only_load_order_mods(profile.load_order());
+            load_order = profile.load_order().clone();
+        }
+
+        load_order.update(&game_config, &game_data_path);
+
+        let mut folder_list = String::new();
+        let mut pack_list = String::new();
+        load_order.build_load_order_string(&game_config, game, &game_data_path, &mut pack_list, &mut folder_list);
+
+        // Older games write to their config folder, so we use a fake one within the root folder.
+        let scripts_path = root.join(SCRIPTS_FOLDER);
+        DirBuilder::new().recursive(true).create(&scripts_path)?;
+
+        let mod_list_file_path = mod_list_file_path(game, &game_path, &scripts_path);
+        write_mod_list_file(game, &mod_list_file_path, &folder_list, &pack_list)?;
+
+        Ok(SimulationResult {
+            game_config,
+            load_order,
+            folder_list,
+            pack_list,
+            mod_list_file_path,
+            launch_args: launch_args(game, &self.extra_args),
+        })
+    }
+
+    /// This function creates the folders and packs of the fake install, returning the paths of the packs in the secondary folder.
+    fn create_fake_install(&self, game: &GameInfo, root: &Path, game_path: &Path) -> Result<Vec<PathBuf>> {
+        let data_path = game.data_path(game_path)?;
+        DirBuilder::new().recursive(true).create(&data_path)?;
+
+        // A vanilla pack, so the install is considered valid.
+        let vanilla_path = data_path.join(VANILLA_PACK_NAME);
+        save_fake_pack(game, &vanilla_path, PFHFileType::Release)?;
+
+        let mut manifest = BufWriter::new(File::create(data_path.join(MANIFEST_FILE_NAME))?);
+        manifest.write_all(format!("{}\t{}\t1\n", VANILLA_PACK_NAME, vanilla_path.metadata()?.len()).as_bytes())?;
+        manifest.flush()?;
+
+        let mut secondary_paths = vec![];
+        for pack in &self.packs {
+            let folder = match pack.location {
+                SimulatedPackLocation::Data => data_path.to_path_buf(),
+                SimulatedPackLocation::Content => {
+                    let steam_id = pack.steam_id.as_ref().ok_or_else(|| anyhow!("Pack {} is in the content folder, but has no steam id.", pack.name))?;
+                    game.content_path(game_path)?.join(steam_id)
+                },
+                SimulatedPackLocation::Secondary => root.join(SECONDARY_FOLDER).join(game.key()),
+            };
+
+            DirBuilder::new().recursive(true).create(&folder)?;
+
+            let path = folder.join(&pack.name);
+            let pack_type = match pack.pack_type {
+                SimulatedPackType::Mod => PFHFileType::Mod,
+                SimulatedPackType::Movie => PFHFileType::Movie,
+            };
+
+            save_fake_pack(game, &path, pack_type)?;
+
+            if pack.location == SimulatedPackLocation::Secondary {
+                secondary_paths.push(path);
+            }
+        }
+
+        Ok(secondary_paths)
+    }
+}
+
+impl SimulatedProfile {
+
+    /// This function returns the profile this simulated profile describes.
+    pub fn to_profile(&self, game: &GameInfo) -> Profile {
+        let mut load_order = LoadOrder::default();
+        load_order.set_automatic(self.automatic_load_order);
+        load_order.set_mods(self.load_order.to_vec());
+
+        let mut profile = Profile::default();
+        profile.set_id(self.id.to_owned());
+        profile.set_game(game.key().to_owned());
+        profile.set_load_order(load_order);
+        profile
+    }
+}
+
+/// This function saves an empty pack of the provided type.
+fn save_fake_pack(game: &GameInfo, path: &Path, pack_type: PFHFileType) -> Result<()> {
+    let mut pack = Pack::new_with_version(game.pfh_version_by_file_type(pack_type));
+    pack.set_pfh_file_type(pack_type);
+    pack.save(Some(path), game, &None)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use rpfm_lib::games::supported_games::KEY_WARHAMMER_3;
+
+    use crate::mod_manager::launch::USER_SCRIPT_EMPIRE_FILE_NAME;
+
+    use super::*;
+
+    /// The folder is returned alongside the result, as the files of the simulation are removed when it's dropped.
+    fn run_fixture(file_name: &str) -> (TempDir, SimulationResult) {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/simulation").join(file_name);
+        let root = tempfile::Builder::new().prefix("runcher_simulation").tempdir().unwrap();
+        let result = SimulationFixture::load(&path).unwrap().run(root.path()).unwrap();
+        (root, result)
+    }
+
+    #[test]
+    fn empire_user_script() {
+        let (_root, result) = run_fixture("empire_user_script.json");
+
+        // Automatic load orders are alphabetical, and disabled mods are left out.
+        assert_eq!(result.load_order().mods(), &["aa_ui.pack".to_owned(), "zz_units.pack".to_owned()]);
+        assert!(!result.pack_list().contains("disabled.pack"));
+
+        // Empire doesn't read a mod list file, so the mods must end up in its user script, in the same order.
+        assert_eq!(result.mod_list_file_path().file_name().unwrap().to_string_lossy(), USER_SCRIPT_EMPIRE_FILE_NAME);
+        assert!(result.mod_list_file_path().is_file());
+        assert!(result.pack_list().find("aa_ui.pack").unwrap() < result.pack_list().find("zz_units.pack").unwrap());
+        assert!(result.launch_args().is_empty());
+    }
+
+    #[test]
+    fn warhammer_3_movie_packs() {
+        let (_root, result) = run_fixture("warhammer_3_movie_packs.json");
+        let game = SUPPORTED_GAMES.game(KEY_WARHAMMER_3).unwrap();
+        assert_eq!(result.game_config().game_key(), game.key());
+
+        // Manual load orders keep the user's order, and movie packs never go in it.
+        let mods = vec!["secondary_mod.pack".to_owned(), "workshop_mod.pack".to_owned(), "local_mod.pack".to_owned()];
+        assert_eq!(result.load_order().mods(), &mods);
+
+        let positions = mods.iter().map(|mod_id| result.pack_list().find(mod_id.as_str()).unwrap()).collect::<Vec<_>>();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // Movie packs are always sorted by name, even with a manual load order.
+        assert_eq!(result.load_order().movies(), &["local_movie.pack".to_owned(), "workshop_movie.pack".to_owned()]);
+        assert!(!result.pack_list().contains("movie"));
+
+        // Movie packs outside /data are loaded by adding their folder, after the folders of the mods.
+        let movie_folder = result.folder_list().lines().position(|line| line.contains("2789900001")).unwrap();
+        let mod_folder = result.folder_list().lines().position(|line| line.contains("2789900000")).unwrap();
+        assert!(mod_folder < movie_folder);
+        assert!(result.launch_args().contains("game_startup_mode campaign_load save.save;"));
+    }
+
+    #[test]
+    fn warhammer_3_profile_and_category_rules() {
+        let (_root, result) = run_fixture("warhammer_3_profile_rules.json");
+
+        // Category rules move the new mods out of the default category when they're scanned.
+        let units = &result.game_config().categories()["Units"];
+        assert!(units.contains(&"units_b.pack".to_owned()));
+        assert!(units.contains(&"units_c.pack".to_owned()));
+
+        // Loading the profile enables only its mods, in its order, even the ones disabled before.
+        assert_eq!(result.load_order().mods(), &["units_c.pack".to_owned(), "ui_a.pack".to_owned()]);
+        assert!(!result.pack_list().contains("units_b.pack"));
+        assert!(result.pack_list().find("units_c.pack").unwrap() < result.pack_list().find("ui_a.pack").unwrap());
+    }
+}
@@ -0,0 +1,128 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the lookup of the community translations of each mod.
+//!
+//! Translations are made with RPFM's translator, which keeps the ones made by the user in its translations_local folder.
+//! The ones from the translation hub are downloaded to our translations_remote folder. Both folders use the same layout:
+//! one file per pack, in a folder per game and language.
+
+use getset::*;
+
+use std::cmp::Reverse;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use rpfm_extensions::translator::PackTranslation;
+
+use crate::settings_ui::{translations_local_path, translations_remote_path};
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// Folder a translation comes from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TranslationSource {
+
+    /// Translation made by the user with RPFM.
+    Local,
+
+    /// Translation downloaded from the translation hub.
+    Remote,
+}
+
+/// How much of a pack is translated to a language.
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct TranslationCoverage {
+
+    /// Folder the translation used at launch comes from. None if the pack has no translation.
+    source: Option<TranslationSource>,
+
+    /// Amount of texts translated and up to date.
+    translated: usize,
+
+    /// Amount of texts translated, but changed by the mod after they were translated. These are not used at launch.
+    outdated: usize,
+
+    /// Amount of texts in the pack.
+    total: usize,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl TranslationSource {
+
+    /// This function returns the key of the name of the source.
+    pub fn locale_key(&self) -> &'static str {
+        match self {
+            Self::Local => "translation_source_local",
+            Self::Remote => "translation_source_remote",
+        }
+    }
+}
+
+impl TranslationCoverage {
+
+    /// This function returns the percentage of the texts of the pack that are translated and up to date.
+    pub fn percentage(&self) -> usize {
+        if self.total == 0 {
+            0
+        } else {
+            self.translated * 100 / self.total
+        }
+    }
+}
+
+/// This function returns the translation folders, sorted so the one with the newest translation of the provided pack goes first.
+///
+/// If both folders have an equally new translation, or none of them have one, the local folder goes first.
+pub fn newest_translation_paths(game_key: &str, pack_name: &str, language: &str) -> Vec<(TranslationSource, PathBuf)> {
+    let mut paths = vec![];
+    if let Ok(path) = translations_local_path() {
+        paths.push((TranslationSource::Local, path));
+    }
+
+    if let Ok(path) = translations_remote_path() {
+        paths.push((TranslationSource::Remote, path));
+    }
+
+    // The sort is stable, so ties keep the local folder first.
+    paths.sort_by_key(|(_, path)| Reverse(translation_modified(path, game_key, pack_name, language)));
+    paths
+}
+
+/// This function returns how much of the provided pack is covered by the translation that will be used at launch.
+pub fn translation_coverage(game_key: &str, pack_name: &str, language: &str) -> TranslationCoverage {
+    for (source, path) in newest_translation_paths(game_key, pack_name, language) {
+        if let Ok(translation) = PackTranslation::load(&[path], pack_name, game_key, language) {
+            let translations = translation.translations();
+            return TranslationCoverage {
+                source: Some(source),
+                translated: translations.values().filter(|tr| !tr.value_translated().is_empty() && !*tr.needs_retranslation()).count(),
+                outdated: translations.values().filter(|tr| *tr.needs_retranslation()).count(),
+                total: translations.len(),
+            };
+        }
+    }
+
+    TranslationCoverage::default()
+}
+
+fn translation_modified(path: &Path, game_key: &str, pack_name: &str, language: &str) -> Option<SystemTime> {
+    path.join(game_key)
+        .join(language)
+        .join(format!("{pack_name}.json"))
+        .metadata().ok()?
+        .modified().ok()
+}
@@ -0,0 +1,73 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the undo snapshots.
+//!
+//! Some operations (pasting a load order, loading a profile, deleting a category,...) overwrite the mod config
+//! of a game in one click. Before doing them, we keep a copy of the config so the operation can be undone.
+
+use anyhow::{anyhow, Result};
+use getset::*;
+
+use rpfm_lib::games::GameInfo;
+
+use super::game_config::GameConfig;
+use super::load_order::LoadOrder;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct UndoSnapshot {
+
+    /// Locale key of the operation this snapshot was taken before.
+    operation: String,
+
+    /// Key of the game the snapshot belongs to.
+    game_key: String,
+
+    /// Game config as it was before the operation.
+    game_config: GameConfig,
+
+    /// Load order as it was before the operation.
+    load_order: LoadOrder,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl UndoSnapshot {
+
+    pub fn new(operation: &str, game: &GameInfo, game_config: &GameConfig, load_order: &LoadOrder) -> Self {
+        Self {
+            operation: operation.to_owned(),
+            game_key: game.key().to_owned(),
+            game_config: game_config.clone(),
+            load_order: load_order.clone(),
+        }
+    }
+
+    /// This function writes the snapshot back to disk, overwriting the current config of the game.
+    ///
+    /// It fails if the snapshot is from another game.
+    pub fn restore(&self, game: &GameInfo) -> Result<()> {
+        if self.game_key != game.key() {
+            return Err(anyhow!("The last operation was done on another game. Select that game to undo it."));
+        }
+
+        self.game_config.clone().save(game)?;
+        self.load_order.clone().save(game)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the code for managing the user scripts used by older games to load mods.
+//!
+//! These files can also contain lines written by the user, so instead of overwriting them,
+//! we only refresh a block delimited by markers, leaving the rest of the file untouched.
+
+use anyhow::Result;
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use rpfm_lib::binary::WriteBytes;
+
+const MANAGED_BLOCK_START: &str = "# Runcher managed block start. Lines between these markers are overwritten on each launch.";
+const MANAGED_BLOCK_END: &str = "# Runcher managed block end.";
+
+pub(crate) const MOD_LINE_START: &str = "mod ";
+pub(crate) const FOLDER_LINE_START: &str = "add_working_directory ";
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function updates the user script at the provided path with the provided folder and pack lists.
+///
+/// Lines already in the file are preserved, including the mod lines written by the user, and Runcher's lines are written in a block at the end of the file.
+/// User mod lines also in Runcher's block are skipped, so the same mod is not loaded twice.
+/// If both lists are empty (vanilla launch), the block is removed, leaving only the user's lines in the file.
+pub fn update_user_script(path: &Path, folder_list: &str, pack_list: &str) -> Result<()> {
+    let managed_lines = folder_list.lines()
+        .chain(pack_list.lines())
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+
+    let mut lines = if path.is_file() {
+        user_lines(&read_user_script(path)?, &managed_lines)
+    } else {
+        vec![]
+    };
+
+    if !managed_lines.is_empty() {
+        lines.push(MANAGED_BLOCK_START.to_owned());
+        lines.extend(managed_lines.iter().map(|line| line.to_string()));
+        lines.push(MANAGED_BLOCK_END.to_owned());
+    }
+
+    // If there's nothing left in the file, remove it so the game behaves as if we never touched it.
+    if lines.is_empty() {
+        if path.is_file() {
+            std::fs::remove_file(path)?;
+        }
+
+        return Ok(());
+    }
+
+    let mut data = lines.join("\n");
+    data.push('\n');
+
+    // These files need to be in UTF-16 LE, or the game will ignore them.
+    let mut file = BufWriter::new(File::create(path)?);
+    file.write_string_u16(&data)?;
+    file.flush()?;
+
+    Ok(())
+}
+
+/// This function reads an UTF-16 LE user script, ignoring the BOM if there's one.
+fn read_user_script(path: &Path) -> Result<String> {
+    let mut data = vec![];
+    BufReader::new(File::open(path)?).read_to_end(&mut data)?;
+
+    let data = data.chunks_exact(2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .collect::<Vec<_>>();
+
+    let text = String::from_utf16_lossy(&data);
+    Ok(text.trim_start_matches('\u{feff}').to_owned())
+}
+
+/// This function returns the lines of a user script that were not written by Runcher, except the mod-loading ones also in the provided managed lines.
+fn user_lines(text: &str, managed_lines: &[&str]) -> Vec<String> {
+    let mut lines = vec![];
+    let mut in_managed_block = false;
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed == MANAGED_BLOCK_START {
+            in_managed_block = true;
+        } else if trimmed == MANAGED_BLOCK_END {
+            in_managed_block = false;
+        } else if !in_managed_block {
+            let is_load_line = trimmed.starts_with(MOD_LINE_START) || trimmed.starts_with(FOLDER_LINE_START);
+            if !is_load_line || !managed_lines.contains(&trimmed) {
+                lines.push(line.to_owned());
+            }
+        }
+    }
+
+    // Remove trailing empty lines, so they don't pile up between launches.
+    while lines.last().map(|line| line.trim().is_empty()).unwrap_or(false) {
+        lines.pop();
+    }
+
+    lines
+}
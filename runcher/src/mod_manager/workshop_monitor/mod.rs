@@ -0,0 +1,114 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the monitor of the Workshop content folders.
+//!
+//! Steam downloads new subscriptions and updates into the content folder of each game, one folder per Workshop item.
+//! While Runcher stays in the tray, we periodically compare those folders with the last time we checked to report what changed.
+
+use anyhow::Result;
+use getset::*;
+
+use std::collections::HashMap;
+use std::fs::read_dir;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use rpfm_lib::games::GameInfo;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+/// State of the content folder of a game at a point in time.
+#[derive(Clone, Debug, Default)]
+pub struct WorkshopSnapshot {
+
+    /// Workshop ids of the items in the folder, with the last time any of their files was modified.
+    items: HashMap<String, u64>,
+}
+
+/// Changes in the content folder of a game between two snapshots.
+#[derive(Clone, Debug, Default, Getters)]
+#[getset(get = "pub")]
+pub struct WorkshopChanges {
+
+    /// Workshop ids of the items that weren't there before.
+    subscribed: Vec<String>,
+
+    /// Workshop ids of the items with files modified since the previous snapshot.
+    updated: Vec<String>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl WorkshopSnapshot {
+
+    /// This function reads the current state of the content folder of the provided game.
+    pub fn scan(game: &GameInfo, game_path: &Path) -> Result<Self> {
+        let mut items = HashMap::new();
+        let content_path = game.content_path(game_path)?;
+        if !content_path.is_dir() {
+            return Ok(Self { items });
+        }
+
+        for entry in read_dir(content_path)? {
+            let path = entry?.path();
+            if !path.is_dir() {
+                continue;
+            }
+
+            let id = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            items.insert(id, last_modified(&path));
+        }
+
+        Ok(Self { items })
+    }
+
+    /// This function returns the items subscribed or updated since the provided snapshot. Unsubscribed items are not reported.
+    pub fn changes_since(&self, previous: &Self) -> WorkshopChanges {
+        let mut changes = WorkshopChanges::default();
+        for (id, modified) in &self.items {
+            match previous.items.get(id) {
+                Some(previous_modified) => if modified > previous_modified {
+                    changes.updated.push(id.to_owned());
+                }
+                None => changes.subscribed.push(id.to_owned()),
+            }
+        }
+
+        changes.subscribed.sort();
+        changes.updated.sort();
+        changes
+    }
+}
+
+impl WorkshopChanges {
+    pub fn is_empty(&self) -> bool {
+        self.subscribed.is_empty() && self.updated.is_empty()
+    }
+}
+
+/// This function returns the last time a file in the provided item folder was modified, as seconds since the epoch.
+///
+/// Items only contain a few files, so we don't bother going into subfolders.
+fn last_modified(path: &Path) -> u64 {
+    read_dir(path)
+        .map(|entries| entries
+            .filter_map(|entry| entry.ok()?.metadata().ok()?.modified().ok())
+            .filter_map(|modified| modified.duration_since(UNIX_EPOCH).ok())
+            .map(|modified| modified.as_secs())
+            .max()
+            .unwrap_or_default()
+        )
+        .unwrap_or_default()
+}
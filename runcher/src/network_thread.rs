@@ -8,29 +8,33 @@
 // https://github.com/Frodo45127/runcher/blob/master/LICENSE.
 //---------------------------------------------------------------------------//
 
+use anyhow::{anyhow, Result};
 use crossbeam::channel::Sender;
 
-use std::path::PathBuf;
-
 use rpfm_lib::integrations::{git::*, log::*};
 use rpfm_lib::schema::*;
-use rpfm_ui_common::settings::error_path;
 
 use crate::CENTRAL_COMMAND;
 use crate::communications::*;
 use crate::games::{TRANSLATIONS_REPO, TRANSLATIONS_REMOTE, TRANSLATIONS_BRANCH};
-use crate::mod_manager::integrations::request_mods_data;
+use crate::mod_manager::game_quirks::GameQuirks;
+use crate::mod_manager::integrations::{check_github_mods_updates, download_github_mod, request_mods_data, search_mods};
+use crate::mod_manager::lint::LintPatterns;
+use crate::mod_manager::log_patterns::LogPatterns;
+use crate::mod_manager::mod_blacklist::ModBlacklist;
+use crate::mod_manager::offline::{detect_offline_mode, offline_mode};
+use crate::mod_manager::requirements::RequiredDlcs;
+use crate::mod_manager::script_breaks::KnownScriptBreaks;
 use crate::settings_ui::{schemas_path, translations_remote_path};
-use crate::updater_ui::check_updates_main_program;
+use crate::updater_ui::{check_updates_main_program, releases};
+
+const OFFLINE_MODE_ERROR: &str = "Runcher is in offline mode. Disable it in the settings, or check your connection and restart Runcher.";
 
 /// This is the network loop that's going to be executed in a parallel thread to the UI. No UI or "Unsafe" stuff here.
 ///
 /// All communication between this and the UI thread is done use the `CENTRAL_COMMAND` static.
 pub fn network_loop() {
 
-    // Initalize background sentry guard. This should, in theory, register crashes on the background thread.
-    let _sentry_guard = Logger::init(&error_path().unwrap_or_else(|_| PathBuf::from(".")), true, false, release_name!());
-
     //---------------------------------------------------------------------------------------//
     // Looping forever and ever...
     //---------------------------------------------------------------------------------------//
@@ -40,11 +44,24 @@ pub fn network_loop() {
         // Wait until you get something through the channel. This hangs the thread until we got something,
         // so it doesn't use processing power until we send it a message.
         let (sender, response): (Sender<Response>, Command) = CENTRAL_COMMAND.recv_network();
+
+        // In offline mode, requests fail right away instead of waiting for a timeout. Except the ones checking if we're still offline.
+        if !matches!(response, Command::Exit | Command::DetectOfflineMode | Command::UpdateRemoteLists) && offline_mode().is_some() {
+            CentralCommand::send_back(&sender, Response::Error(anyhow!(OFFLINE_MODE_ERROR)));
+            continue;
+        }
+
         match response {
 
             // Command to close the thread.
             Command::Exit => return,
 
+            // Unreachable servers take a few seconds to time out, so we check them here to not hang the UI.
+            Command::DetectOfflineMode => {
+                detect_offline_mode();
+                CentralCommand::send_back(&sender, Response::Success);
+            }
+
             // When we want to check if there is an update available for RPFM...
             Command::CheckUpdates => {
                 match check_updates_main_program() {
@@ -53,6 +70,14 @@ pub fn network_loop() {
                 }
             }
 
+            // When we want the list of releases we can install...
+            Command::GetReleases(update_channel) => {
+                match releases(update_channel) {
+                    Ok(releases) => CentralCommand::send_back(&sender, Response::VecString(releases.into_iter().map(|release| release.version).collect())),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
             // When we want to check if there is a schema's update available...
             Command::CheckSchemaUpdates => {
                 match schemas_path() {
@@ -88,8 +113,54 @@ pub fn network_loop() {
                 }
             }
 
+            Command::SearchWorkshopMods(game, tags, page) => {
+                match search_mods(&game, &tags, page) {
+                    Ok(mods_data) => CentralCommand::send_back(&sender, Response::VecMod(mods_data)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            Command::DownloadGitHubMod(repo, asset, folder, vanilla_names) => {
+                match download_github_mod(&repo, asset.as_deref(), &folder, &vanilla_names) {
+                    Ok(source) => CentralCommand::send_back(&sender, Response::GitHubSource(source)),
+                    Err(error) => CentralCommand::send_back(&sender, Response::Error(error)),
+                }
+            }
+
+            Command::CheckGitHubModsUpdates(sources) => {
+                CentralCommand::send_back(&sender, Response::VecGitHubSource(check_github_mods_updates(&sources)));
+            }
+
+            // Nobody waits for this one, so there's no response.
+            Command::UpdateRemoteLists => update_remote_lists(),
+
             // If you hit this, you fucked it up somewhere else.
             _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
         }
     }
 }
+
+/// This function updates the outdated lists downloaded from Runcher's repo.
+///
+/// Errors are only logged, as the lists we already have, or the ones shipped with the program, are still usable.
+fn update_remote_lists() {
+    if offline_mode().is_some() {
+        return;
+    }
+
+    update_remote_list("list of required DLCs", RequiredDlcs::needs_update, RequiredDlcs::update);
+    update_remote_list("game quirks", GameQuirks::needs_update, GameQuirks::update);
+    update_remote_list("lint patterns", LintPatterns::needs_update, LintPatterns::update);
+    update_remote_list("log patterns", LogPatterns::needs_update, LogPatterns::update);
+    update_remote_list("mod blacklist", ModBlacklist::needs_update, ModBlacklist::update);
+    update_remote_list("known script breaks", KnownScriptBreaks::needs_update, KnownScriptBreaks::update);
+}
+
+/// This function updates one of the lists downloaded from Runcher's repo, if it's outdated.
+fn update_remote_list(name: &str, needs_update: fn() -> bool, update: fn() -> Result<()>) {
+    if needs_update() {
+        if let Err(error) = update() {
+            info!("Failed to update the {}: {}", name, error);
+        }
+    }
+}
@@ -8,18 +8,23 @@
 // https://github.com/Frodo45127/runcher/blob/master/LICENSE.
 //---------------------------------------------------------------------------//
 
+use qt_widgets::QAction;
 use qt_widgets::QGridLayout;
 use qt_widgets::q_header_view::ResizeMode;
 use qt_widgets::QLineEdit;
+use qt_widgets::QMenu;
 use qt_widgets::QTabWidget;
 use qt_widgets::QToolButton;
 use qt_widgets::QTreeView;
 
+use qt_gui::QIcon;
 use qt_gui::QListOfQStandardItem;
 use qt_gui::QStandardItem;
 use qt_gui::QStandardItemModel;
 
 use qt_core::CaseSensitivity;
+use qt_core::ContextMenuPolicy;
+use qt_core::q_item_selection_model::SelectionFlag;
 use qt_core::QBox;
 use qt_core::QModelIndex;
 use qt_core::QPtr;
@@ -28,22 +33,26 @@ use qt_core::QSortFilterProxyModel;
 use qt_core::QString;
 use qt_core::QTimer;
 use qt_core::QVariant;
+use qt_core::SortOrder;
 
 use cpp_core::CppBox;
 use cpp_core::CppDeletable;
 
 use anyhow::Result;
 use getset::*;
+use rayon::prelude::*;
 
 use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
+use std::sync::RwLock;
 
 use rpfm_lib::files::pack::Pack;
-use rpfm_lib::games::GameInfo;
+use rpfm_lib::games::{GameInfo, pfh_file_type::PFHFileType};
 use rpfm_lib::integrations::log::error;
 
-use rpfm_ui_common::locale::qtr;
+use rpfm_ui_common::locale::{qtr, qtre};
+use rpfm_ui_common::settings::*;
 use rpfm_ui_common::utils::*;
 
 use crate::ffi::*;
@@ -57,6 +66,20 @@ mod slots;
 const VIEW_DEBUG: &str = "ui_templates/pack_list_widget.ui";
 const VIEW_RELEASE: &str = "ui/pack_list_widget.ui";
 
+/// Data role used to mark the rows of vanilla packs, which are read-only.
+pub const VALUE_IS_VANILLA: i32 = 41;
+
+/// Data role used to sort the list. Some columns are not sorted by their text, like the load order or the size.
+const VALUE_SORT: i32 = 42;
+
+/// Locale keys of the titles of the columns of the list, in order.
+const COLUMNS: [&str; 7] = ["pack_name", "pack_type", "pack_path", "load_order", "location", "steam_id", "pack_size"];
+const COLUMN_LOAD_ORDER: i32 = 3;
+const COLUMN_SIZE: i32 = 6;
+
+/// Columns hidden by default, as they're either too long or rarely used.
+pub const DEFAULT_HIDDEN_COLUMNS: &str = "2,5";
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -71,7 +94,26 @@ pub struct PackListUI {
     filter_case_sensitive_button: QPtr<QToolButton>,
     filter_timer: QBox<QTimer>,
 
+    header_context_menu: QBox<QMenu>,
+    column_actions: Vec<QPtr<QAction>>,
+
+    /// Key of the game the list was loaded for. Used to remember the hidden columns per game.
+    game_key: Rc<RwLock<String>>,
+
     automatic_order_button: QPtr<QToolButton>,
+    show_vanilla_packs_button: QPtr<QToolButton>,
+
+    context_menu: QBox<QMenu>,
+    move_to_top: QPtr<QAction>,
+    move_to_bottom: QPtr<QAction>,
+    move_to_position: QPtr<QAction>,
+    import_order: QPtr<QAction>,
+    export_launch_manifest: QPtr<QAction>,
+    group_lock: QPtr<QAction>,
+    group_unlock: QPtr<QAction>,
+    conflict_suggestions: QPtr<QAction>,
+    reveal_in_mod_list: QPtr<QAction>,
+    extract_pack: QPtr<QAction>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -92,6 +134,9 @@ impl PackListUI {
         let filter_case_sensitive_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "filter_case_sensitive_button")?;
         let automatic_order_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "automatic_order_button")?;
         automatic_order_button.set_tool_tip(&qtr("automatic_mode_tooltip"));
+        let show_vanilla_packs_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "show_vanilla_packs_button")?;
+        show_vanilla_packs_button.set_tool_tip(&qtr("show_vanilla_packs_tooltip"));
+        show_vanilla_packs_button.set_checked(setting_bool("show_vanilla_packs"));
 
         // Replace the placeholder widget.
         let main_layout: QPtr<QGridLayout> = main_widget.layout().static_downcast();
@@ -103,11 +148,45 @@ impl PackListUI {
         filter.set_source_model(&model);
         model.set_parent(&tree_view);
         tree_view.set_model(&filter);
-        tree_view.set_sorting_enabled(false);
+
+        // Filter by any column, and sort by our own values so numeric columns are not sorted alphabetically.
+        filter.set_filter_key_column(-1);
+        filter.set_sort_role(VALUE_SORT);
+        tree_view.set_sorting_enabled(true);
+        tree_view.sort_by_column_2a(COLUMN_LOAD_ORDER, SortOrder::AscendingOrder);
 
         let filter_timer = QTimer::new_1a(&main_widget);
         filter_timer.set_single_shot(true);
 
+        // Header context menu, to choose which columns are shown.
+        tree_view.header().set_context_menu_policy(ContextMenuPolicy::CustomContextMenu);
+        let header_context_menu = QMenu::from_q_widget(&main_widget);
+        let column_actions = COLUMNS.iter()
+            .map(|column| {
+                let action = header_context_menu.add_action_q_string(&qtr(column));
+                action.set_checkable(true);
+                action
+            })
+            .collect::<Vec<_>>();
+
+        // Context menu.
+        tree_view.set_context_menu_policy(ContextMenuPolicy::CustomContextMenu);
+        let context_menu = QMenu::from_q_widget(&main_widget);
+        let move_to_top = context_menu.add_action_q_string(&qtr("pack_move_to_top"));
+        let move_to_bottom = context_menu.add_action_q_string(&qtr("pack_move_to_bottom"));
+        let move_to_position = context_menu.add_action_q_string(&qtr("pack_move_to_position"));
+        let import_order = context_menu.add_action_q_string(&qtr("pack_import_order"));
+        context_menu.insert_separator(&import_order);
+        let export_launch_manifest = context_menu.add_action_q_string(&qtr("pack_export_launch_manifest"));
+        let group_lock = context_menu.add_action_q_string(&qtr("pack_group_lock"));
+        let group_unlock = context_menu.add_action_q_string(&qtr("pack_group_unlock"));
+        context_menu.insert_separator(&group_lock);
+        let conflict_suggestions = context_menu.add_action_q_string(&qtr("pack_conflict_suggestions"));
+        context_menu.insert_separator(&conflict_suggestions);
+        let reveal_in_mod_list = context_menu.add_action_q_string(&qtr("reveal_in_mod_list"));
+        context_menu.insert_separator(&reveal_in_mod_list);
+        let extract_pack = context_menu.add_action_q_string(&qtr("extract_pack"));
+
         parent.add_tab_2a(&main_widget, &qtr("pack_list_title"));
 
         let list = Rc::new(Self {
@@ -117,7 +196,25 @@ impl PackListUI {
             filter_line_edit,
             filter_case_sensitive_button,
             filter_timer,
+
+            header_context_menu,
+            column_actions,
+            game_key: Rc::new(RwLock::new(String::new())),
+
             automatic_order_button,
+            show_vanilla_packs_button,
+
+            context_menu,
+            move_to_top,
+            move_to_bottom,
+            move_to_position,
+            import_order,
+            export_launch_manifest,
+            group_lock,
+            group_unlock,
+            conflict_suggestions,
+            reveal_in_mod_list,
+            extract_pack,
         });
 
         let slots = PackListUISlots::new(&list);
@@ -130,10 +227,14 @@ impl PackListUI {
         self.filter_line_edit().text_changed().connect(slots.filter_line_edit());
         self.filter_case_sensitive_button().toggled().connect(slots.filter_case_sensitive_button());
         self.filter_timer().timeout().connect(slots.filter_trigger());
+        self.tree_view().custom_context_menu_requested().connect(slots.context_menu());
+        self.tree_view().header().custom_context_menu_requested().connect(slots.header_context_menu());
+        self.header_context_menu().triggered().connect(slots.column_toggled());
     }
 
     pub unsafe fn load(&self, game_config: &GameConfig, game_info: &GameInfo, game_path: &Path, load_order: &LoadOrder) -> Result<()> {
         self.model().clear();
+        *self.game_key().write().unwrap() = game_info.key().to_owned();
 
         let secondary_mods_path = secondary_mods_path(game_config.game_key()).unwrap_or_else(|_| PathBuf::new());
 
@@ -153,20 +254,27 @@ impl PackListUI {
                         //
                         // In practice if a bin pack loads here, there's a bug elsewhere.
                         if pack_name.ends_with(".pack") {
-                            let pack = Pack::read_and_merge(&[modd.paths()[0].to_path_buf()], true, false, false)?;
-
+                            let group = load_order.group(mod_id).map(|group| group.name().to_owned());
                             let item_name = Self::new_item();
                             let item_type = Self::new_item();
                             let item_path = Self::new_item();
                             let load_order = Self::new_item();
                             let location = Self::new_item();
                             let steam_id = Self::new_item();
+                            let size = Self::new_item();
 
                             item_name.set_text(&QString::from_std_str(&pack_name));
                             item_name.set_data_2a(&QVariant::from_q_string(&QString::from_std_str(mod_id)), VALUE_MOD_ID);
-                            item_name.set_data_2a(&QVariant::from_q_string(&QString::from_std_str((pack.pfh_file_type() as u32).to_string() + &pack_name)), 20);
+                            item_name.set_data_2a(&QVariant::from_q_string(&QString::from_std_str((*modd.pack_type() as u32).to_string() + &pack_name)), 20);
                             item_type.set_text(&QString::from_std_str(&modd.pack_type().to_string()));
                             item_path.set_text(&QString::from_std_str(&modd.paths()[0].to_string_lossy()));
+
+                            // Mark grouped packs, so it's clear why they move together.
+                            if let Some(group) = group {
+                                item_name.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("object-locked")));
+                                item_name.set_tool_tip(&qtre("pack_in_group", &[&group]));
+                            }
+
                             load_order.set_data_2a(&QVariant::from_int(index as i32), 2);
 
                             location.set_text(&QString::from_std_str(
@@ -189,12 +297,16 @@ impl PackListUI {
                                 steam_id.set_text(&QString::from_std_str(id));
                             }
 
+                            let pack_size = modd.paths()[0].metadata().map(|metadata| metadata.len()).unwrap_or(*modd.file_size());
+                            Self::set_size(&size, pack_size);
+
                             row.append_q_standard_item(&item_name.into_ptr().as_mut_raw_ptr());
                             row.append_q_standard_item(&item_type.into_ptr().as_mut_raw_ptr());
                             row.append_q_standard_item(&item_path.into_ptr().as_mut_raw_ptr());
                             row.append_q_standard_item(&load_order.into_ptr().as_mut_raw_ptr());
                             row.append_q_standard_item(&location.into_ptr().as_mut_raw_ptr());
                             row.append_q_standard_item(&steam_id.into_ptr().as_mut_raw_ptr());
+                            row.append_q_standard_item(&size.into_ptr().as_mut_raw_ptr());
 
                             self.model().append_row_q_list_of_q_standard_item(row.into_ptr().as_ref().unwrap());
                         } else {
@@ -205,10 +317,16 @@ impl PackListUI {
             }
         }
 
-        self.tree_view().hide_column(2);
-        self.tree_view().hide_column(5);
+        // Vanilla packs are optional, and only here to show where the mods end up in the full list of packs the game loads.
+        if self.show_vanilla_packs_button().is_checked() {
+            if let Err(error) = self.load_vanilla_packs(game_info, game_path) {
+                error!("Error loading vanilla Packs to UI: {}", error);
+            }
+        }
 
         self.setup_columns();
+        self.update_sort_values();
+        self.apply_hidden_columns();
         self.tree_view().header().resize_sections(ResizeMode::ResizeToContents);
 
         self.automatic_order_button().block_signals(true);
@@ -219,19 +337,146 @@ impl PackListUI {
     }
 
     pub unsafe fn setup_columns(&self) {
-        let pack_name = QStandardItem::from_q_string(&qtr("pack_name"));
-        let pack_type = QStandardItem::from_q_string(&qtr("pack_type"));
-        let pack_path = QStandardItem::from_q_string(&qtr("pack_path"));
-        let load_order = QStandardItem::from_q_string(&qtr("load_order"));
-        let location = QStandardItem::from_q_string(&qtr("location"));
-        let steam_id = QStandardItem::from_q_string(&qtr("steam_id"));
-
-        self.model.set_horizontal_header_item(0, pack_name.into_ptr());
-        self.model.set_horizontal_header_item(1, pack_type.into_ptr());
-        self.model.set_horizontal_header_item(2, pack_path.into_ptr());
-        self.model.set_horizontal_header_item(3, load_order.into_ptr());
-        self.model.set_horizontal_header_item(4, location.into_ptr());
-        self.model.set_horizontal_header_item(5, steam_id.into_ptr());
+        for (index, column) in COLUMNS.iter().enumerate() {
+            let item = QStandardItem::from_q_string(&qtr(column));
+            self.model.set_horizontal_header_item(index as i32, item.into_ptr());
+        }
+    }
+
+    /// This function hides the columns the user chose to hide for the current game.
+    pub unsafe fn apply_hidden_columns(&self) {
+        let hidden_columns = self.hidden_columns();
+        for (index, action) in self.column_actions().iter().enumerate() {
+            let hidden = hidden_columns.contains(&(index as i32));
+            self.tree_view().set_column_hidden(index as i32, hidden);
+            action.set_checked(!hidden);
+        }
+    }
+
+    /// This function saves the columns unchecked in the header's context menu as hidden for the current game, and hides them.
+    pub unsafe fn save_hidden_columns(&self) {
+        let hidden_columns = self.column_actions()
+            .iter()
+            .enumerate()
+            .filter(|(_, action)| !action.is_checked())
+            .map(|(index, _)| index.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        set_setting_string(&self.hidden_columns_setting_key(), &hidden_columns);
+        self.apply_hidden_columns();
+    }
+
+    unsafe fn hidden_columns(&self) -> Vec<i32> {
+        setting_string(&self.hidden_columns_setting_key())
+            .split(',')
+            .filter_map(|column| column.trim().parse().ok())
+            .collect()
+    }
+
+    fn hidden_columns_setting_key(&self) -> String {
+        format!("pack_list_hidden_columns_{}", self.game_key().read().unwrap())
+    }
+
+    /// This function returns if the list is shown in load order, which is required for moving packs by dragging them.
+    pub unsafe fn is_sorted_by_load_order(&self) -> bool {
+        let header = self.tree_view().header();
+        header.sort_indicator_section() == COLUMN_LOAD_ORDER && header.sort_indicator_order() == SortOrder::AscendingOrder
+    }
+
+    /// This function returns the row in the model of the provided row of the view.
+    pub unsafe fn source_row(&self, row: i32) -> i32 {
+        self.filter().map_to_source(&self.filter().index_2a(row, 0)).row()
+    }
+
+    /// This function updates the values used to sort the list. Must be called after the rows of the list are moved.
+    ///
+    /// The load order column is sorted by the position of the packs in the model, so vanilla packs are sorted where they're loaded.
+    pub unsafe fn update_sort_values(&self) {
+        for row in 0..self.model().row_count_0a() {
+            for column in 0..COLUMNS.len() as i32 {
+                let item = self.model().item_2a(row, column);
+                if item.is_null() || column == COLUMN_SIZE {
+                    continue;
+                }
+
+                if column == COLUMN_LOAD_ORDER {
+                    item.set_data_2a(&QVariant::from_int(row), VALUE_SORT);
+                } else {
+                    item.set_data_2a(&QVariant::from_q_string(&item.text().to_lower()), VALUE_SORT);
+                }
+            }
+        }
+    }
+
+    /// This function adds the vanilla packs of the game to the list, greyed out so they cannot be selected nor moved.
+    ///
+    /// They're sorted by type, from higher to lower priority. Vanilla movie packs are interleaved with the mod ones by name.
+    unsafe fn load_vanilla_packs(&self, game_info: &GameInfo, game_path: &Path) -> Result<()> {
+        let mut packs = game_info.ca_packs_paths(game_path)?
+            .par_iter()
+            .filter_map(|path| Pack::read_and_merge(&[path.to_path_buf()], true, false, false).ok().map(|pack| (path.to_path_buf(), pack.pfh_file_type())))
+            .collect::<Vec<_>>();
+
+        packs.sort_by(|(path_a, type_a), (path_b, type_b)| (*type_b as u32).cmp(&(*type_a as u32)).then(path_a.file_name().cmp(&path_b.file_name())));
+
+        for (path, pack_type) in &packs {
+            let pack_name = path.file_name().unwrap().to_string_lossy().as_ref().to_owned();
+            let row = QListOfQStandardItem::new();
+
+            let item_name = Self::new_item();
+            let item_type = Self::new_item();
+            let item_path = Self::new_item();
+            let load_order = Self::new_item();
+            let location = Self::new_item();
+            let steam_id = Self::new_item();
+            let size = Self::new_item();
+
+            item_name.set_text(&QString::from_std_str(&pack_name));
+            item_name.set_data_2a(&QVariant::from_q_string(&QString::from_std_str((*pack_type as u32).to_string() + &pack_name)), 20);
+            item_name.set_data_2a(&QVariant::from_bool(true), VALUE_IS_VANILLA);
+            item_type.set_text(&QString::from_std_str(pack_type.to_string()));
+            item_path.set_text(&QString::from_std_str(path.to_string_lossy()));
+            location.set_text(&QString::from_std_str("Vanilla"));
+            Self::set_size(&size, path.metadata().map(|metadata| metadata.len()).unwrap_or_default());
+
+            for item in [&item_name, &item_type, &item_path, &load_order, &location, &steam_id, &size] {
+                item.set_enabled(false);
+            }
+
+            row.append_q_standard_item(&item_name.into_ptr().as_mut_raw_ptr());
+            row.append_q_standard_item(&item_type.into_ptr().as_mut_raw_ptr());
+            row.append_q_standard_item(&item_path.into_ptr().as_mut_raw_ptr());
+            row.append_q_standard_item(&load_order.into_ptr().as_mut_raw_ptr());
+            row.append_q_standard_item(&location.into_ptr().as_mut_raw_ptr());
+            row.append_q_standard_item(&steam_id.into_ptr().as_mut_raw_ptr());
+            row.append_q_standard_item(&size.into_ptr().as_mut_raw_ptr());
+
+            // Movie packs go before the first movie pack with a name after theirs. The rest go at the end.
+            let position = if *pack_type == PFHFileType::Movie {
+                (0..self.model().row_count_0a()).find(|row| {
+                    self.model().item_2a(*row, 1).text().to_std_string() == PFHFileType::Movie.to_string() &&
+                        self.model().item_2a(*row, 0).text().to_std_string() > pack_name
+                })
+            } else {
+                None
+            };
+
+            match position {
+                Some(position) => self.model().insert_row_int_q_list_of_q_standard_item(position, row.into_ptr().as_ref().unwrap()),
+                None => self.model().append_row_q_list_of_q_standard_item(row.into_ptr().as_ref().unwrap()),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This function returns the paths of the mod packs in the list, in order, skipping the vanilla ones.
+    pub unsafe fn mod_pack_paths(&self) -> Vec<PathBuf> {
+        (0..self.model().row_count_0a())
+            .filter(|row| !self.model().item_2a(*row, 0).data_1a(VALUE_IS_VANILLA).to_bool())
+            .map(|row| PathBuf::from(self.model().item_2a(row, 2).text().to_std_string()))
+            .collect::<Vec<_>>()
     }
 
     /// This returns the selection REVERSED, FROM BOTTOM TO TOP.
@@ -249,6 +494,33 @@ impl PackListUI {
         indexes_visual.iter().map(|x| self.filter().map_to_source(*x)).collect::<Vec<_>>()
     }
 
+    /// This function selects the pack of the provided mod and scrolls to it. Returns false if the pack is not in the list.
+    ///
+    /// If the pack is hidden by the filter, the filter is cleared so it can be shown.
+    pub unsafe fn reveal_pack(&self, mod_id: &str) -> bool {
+        let mod_id = QString::from_std_str(mod_id);
+        let row = (0..self.model().row_count_0a())
+            .find(|row| self.model().item_2a(*row, 0).data_1a(VALUE_MOD_ID).to_string().compare_q_string(&mod_id) == 0);
+
+        match row {
+            Some(row) => {
+                let source_index = self.model().index_2a(row, 0);
+                let mut index = self.filter().map_from_source(&source_index);
+                if !index.is_valid() {
+                    self.filter_line_edit().clear();
+                    self.filter_list();
+                    index = self.filter().map_from_source(&source_index);
+                }
+
+                self.tree_view().selection_model().select_q_model_index_q_flags_selection_flag(&index, SelectionFlag::ClearAndSelect | SelectionFlag::Rows);
+                self.tree_view().scroll_to_1a(&index);
+                self.tree_view().set_focus_0a();
+                true
+            }
+            None => false,
+        }
+    }
+
     pub unsafe fn filter_list(&self) {
 
         // Set the pattern to search.
@@ -268,6 +540,11 @@ impl PackListUI {
         self.filter_timer.start_0a();
     }
 
+    unsafe fn set_size(item: &CppBox<QStandardItem>, size: u64) {
+        item.set_text(&QString::from_std_str(format!("{:.2} MB", size as f64 / 1024.0 / 1024.0)));
+        item.set_data_2a(&QVariant::from_u64(size), VALUE_SORT);
+    }
+
     unsafe fn new_item() -> CppBox<QStandardItem> {
         let item = QStandardItem::new();
         item.set_editable(false);
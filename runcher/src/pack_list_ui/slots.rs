@@ -8,6 +8,10 @@
 // https://github.com/Frodo45127/runcher/blob/master/LICENSE.
 //---------------------------------------------------------------------------//
 
+use qt_widgets::SlotOfQPoint;
+
+use qt_gui::QCursor;
+
 use qt_core::QBox;
 use qt_core::{SlotNoArgs, SlotOfQString};
 
@@ -15,6 +19,8 @@ use std::rc::Rc;
 
 use rpfm_ui_common::clone;
 
+use crate::mod_manager::profiles::active_locked_profile;
+
 use super::*;
 
 //-------------------------------------------------------------------------------//
@@ -27,6 +33,9 @@ pub struct PackListUISlots {
     filter_line_edit: QBox<SlotOfQString>,
     filter_case_sensitive_button: QBox<SlotNoArgs>,
     filter_trigger: QBox<SlotNoArgs>,
+    header_context_menu: QBox<SlotOfQPoint>,
+    column_toggled: QBox<SlotNoArgs>,
+    context_menu: QBox<SlotOfQPoint>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -51,10 +60,46 @@ impl PackListUISlots {
             view.filter_list();
         }));
 
+        let header_context_menu = SlotOfQPoint::new(&view.tree_view, clone!(
+            view => move |_| {
+            view.header_context_menu().exec_1a_mut(&QCursor::pos_0a());
+        }));
+
+        let column_toggled = SlotNoArgs::new(&view.tree_view, clone!(
+            view => move || {
+            view.save_hidden_columns();
+        }));
+
+        let context_menu = SlotOfQPoint::new(&view.tree_view, clone!(
+            view => move |_| {
+
+            // Manual edits are only allowed when the load order is not automatic, and no locked profile is active.
+            let manual = !view.automatic_order_button().is_checked() && active_locked_profile(&view.game_key().read().unwrap()).is_none();
+            let selection = view.pack_list_selection();
+            let movable = manual && !selection.is_empty() && selection.iter().all(|index| !index.data_1a(VALUE_IS_VANILLA).to_bool());
+
+            view.move_to_top.set_enabled(movable);
+            view.move_to_bottom.set_enabled(movable);
+            view.move_to_position.set_enabled(movable);
+            view.import_order.set_enabled(manual);
+
+            // Groups work in both modes, as automatic sorting respects them.
+            let mods = selection.iter().filter(|index| !index.data_1a(VALUE_IS_VANILLA).to_bool()).count();
+            view.group_lock.set_enabled(mods > 1);
+            view.group_unlock.set_enabled(mods > 0);
+            view.reveal_in_mod_list.set_enabled(mods == 1);
+            view.extract_pack.set_enabled(mods == 1);
+
+            view.context_menu().exec_1a_mut(&QCursor::pos_0a());
+        }));
+
         Self {
             filter_line_edit,
             filter_case_sensitive_button,
             filter_trigger,
+            header_context_menu,
+            column_toggled,
+            context_menu,
         }
     }
 }
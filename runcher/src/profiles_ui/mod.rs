@@ -9,14 +9,16 @@
 //---------------------------------------------------------------------------//
 
 #[cfg(target_os = "windows")] use qt_widgets::QCheckBox;
-use qt_widgets::QDialog;
+use qt_widgets::{QDialog, q_dialog::DialogCode};
 use qt_widgets::QDialogButtonBox;
 use qt_widgets::q_dialog_button_box::StandardButton;
-use qt_widgets::{QFileDialog, q_file_dialog::{FileMode, Option as QFileDialogOption}};
+use qt_widgets::{QFileDialog, q_file_dialog::{AcceptMode, FileMode, Option as QFileDialogOption}};
 use qt_widgets::QGroupBox;
+use qt_widgets::{QInputDialog, q_input_dialog::InputMode};
 use qt_widgets::QLabel;
 use qt_widgets::QLineEdit;
 use qt_widgets::QListView;
+use qt_widgets::{QMessageBox, q_message_box};
 use qt_widgets::QToolButton;
 use qt_widgets::QWidget;
 
@@ -43,10 +45,12 @@ use std::path::{PathBuf, Path};
 use std::rc::Rc;
 
 use rpfm_ui_common::clone;
-use rpfm_ui_common::locale::qtr;
+use rpfm_ui_common::locale::{qtr, tr, tre};
 use rpfm_ui_common::utils::*;
 
 use crate::AppUI;
+use crate::mod_manager::env_vars::parse_env_vars;
+use crate::mod_manager::profiles::{active_locked_profile, set_active_locked_profile, Profile};
 use crate::profiles_ui::slots::ProfilesUISlots;
 
 const VIEW_DEBUG: &str = "ui_templates/profile_manager_dialog.ui";
@@ -74,7 +78,9 @@ pub struct ProfilesUI {
     rename_profile_button: QPtr<QToolButton>,
     delete_profile_button: QPtr<QToolButton>,
     shortcut_button: QPtr<QToolButton>,
-
+    export_button: QPtr<QToolButton>,
+    import_button: QPtr<QToolButton>,
+    env_vars_button: QPtr<QToolButton>,
 }
 
 //---------------------------------------------------------------------------//
@@ -95,6 +101,9 @@ impl ProfilesUI {
         let rename_profile_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "rename_button")?;
         let delete_profile_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "delete_button")?;
         let shortcut_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "shortcut_button")?;
+        let export_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "export_button")?;
+        let import_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "import_button")?;
+        let env_vars_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "env_vars_button")?;
         let profiles_list_view: QPtr<QListView> = find_widget(&main_widget.static_upcast(), "profiles_list_view")?;
         let profiles_list_model = QStandardItemModel::new_1a(&profiles_list_view);
         profiles_list_view.set_model(&profiles_list_model);
@@ -104,11 +113,16 @@ impl ProfilesUI {
         rename_profile_button.set_tool_tip(&qtr("profile_rename"));
         delete_profile_button.set_tool_tip(&qtr("profile_delete"));
         shortcut_button.set_tool_tip(&qtr("profile_shortcut_new"));
+        export_button.set_tool_tip(&qtr("profile_export"));
+        import_button.set_tool_tip(&qtr("profile_import"));
+        env_vars_button.set_tool_tip(&qtr("profile_env_vars"));
 
         // Disable the buttons.
         delete_profile_button.set_enabled(false);
         rename_profile_button.set_enabled(false);
         shortcut_button.set_enabled(false);
+        export_button.set_enabled(false);
+        env_vars_button.set_enabled(false);
 
         let ui = Rc::new(Self {
             main_widget,
@@ -118,6 +132,9 @@ impl ProfilesUI {
             rename_profile_button,
             delete_profile_button,
             shortcut_button,
+            export_button,
+            import_button,
+            env_vars_button,
         });
 
         let slots = ProfilesUISlots::new(&ui, app_ui);
@@ -137,6 +154,9 @@ impl ProfilesUI {
         self.rename_profile_button().released().connect(slots.profile_rename());
         self.delete_profile_button().released().connect(slots.profile_delete());
         self.shortcut_button().released().connect(slots.profile_shorcut());
+        self.export_button().released().connect(slots.profile_export());
+        self.import_button().released().connect(slots.profile_import());
+        self.env_vars_button().released().connect(slots.profile_env_vars());
     }
 
     pub unsafe fn load_data(&self, app_ui: &Rc<AppUI>) {
@@ -164,6 +184,31 @@ impl ProfilesUI {
             details.push_str(&format!("<li>Profile ID/Name: {}</li>", profile.id()));
             details.push_str(&format!("<li>Game: {}</li>", profile.game()));
 
+            if let Some(mct_preset) = profile.mct_preset() {
+                details.push_str(&format!("<li>MCT Preset: {}</li>", mct_preset));
+            }
+
+            if *profile.read_only() {
+                details.push_str("<li>Read-only: this profile cannot be overwritten.</li>");
+            }
+
+            if *profile.locked() {
+                details.push_str("<li>Locked: the mods and load order of this profile cannot be changed while it's loaded, until it's unlocked.</li>");
+            }
+
+            if !profile.env_vars().is_empty() {
+                details.push_str(&format!("<li>Environment Variables: <code>{}</code></li>", profile.env_vars().replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")));
+            }
+
+            // Players can compare this to make sure they're using the same unmodified profile.
+            if let Some(fingerprint) = profile.fingerprint() {
+                if profile.signature_is_valid() {
+                    details.push_str(&format!("<li>Signature Fingerprint: <b>{}</b></li>", fingerprint));
+                } else {
+                    details.push_str(&format!("<li>Signature Fingerprint: <b>{}</b> <i>(Invalid: the profile has been modified after being exported)</i></li>", fingerprint));
+                }
+            }
+
             if profile.load_order().mods().is_empty() {
                 details.push_str("<li>Profile contains an empty load order.</li>");
             } else if let Some(ref game_config) = *app_ui.game_config().read().unwrap() {
@@ -283,6 +328,11 @@ impl ProfilesUI {
                     profile.set_id(new_name.to_owned());
                     profile.save(&game, &new_name)?;
 
+                    // Keep the lock pointing to the profile, or it'd stay active with no profile to unlock.
+                    if active_locked_profile(game.key()).is_some_and(|locked_profile| locked_profile == current_name) {
+                        set_active_locked_profile(game.key(), Some(&new_name));
+                    }
+
                     profiles.insert(new_name.to_owned(), profile);
                 }
             }
@@ -301,6 +351,10 @@ impl ProfilesUI {
             let selection = self.list_selection();
             let index = &selection[0];
             let name = index.data_1a(2).to_string().to_std_string();
+
+            if active_locked_profile(app_ui.game_selected().read().unwrap().key()).is_some_and(|locked_profile| locked_profile == name) {
+                return Err(anyhow!(tre("profile_locked_error", &[&name])));
+            }
             let row = index.row();
 
             // Remove it from the view.
@@ -319,6 +373,111 @@ impl ProfilesUI {
         Ok(())
     }
 
+    pub unsafe fn export_profile(&self, app_ui: &Rc<AppUI>) -> Result<()> {
+        let selection = self.list_selection();
+        let index = &selection[0];
+        let name = index.data_1a(2).to_string().to_std_string();
+        let profile = app_ui.game_profiles().read().unwrap().get(&name).cloned().ok_or_else(|| anyhow!("No profile with said name found for the game selected."))?;
+
+        // Read-only exports are meant for sharing a profile with a group, so nobody changes it by accident.
+        let dialog = QMessageBox::from_q_widget(&self.dialog());
+        dialog.set_window_title(&qtr("profile_export"));
+        dialog.set_icon(q_message_box::Icon::Question);
+        dialog.set_text(&qtr("profile_export_read_only"));
+
+        let read_only_button = dialog.add_button_q_string_button_role(&qtr("profile_export_as_read_only"), q_message_box::ButtonRole::YesRole);
+        dialog.add_button_q_string_button_role(&qtr("profile_export_as_editable"), q_message_box::ButtonRole::NoRole);
+        dialog.add_button_standard_button(q_message_box::StandardButton::Cancel);
+        dialog.set_default_button_q_push_button(&read_only_button);
+        dialog.exec();
+
+        let read_only = match dialog.button_role(&dialog.clicked_button()) {
+            q_message_box::ButtonRole::YesRole => true,
+            q_message_box::ButtonRole::NoRole => false,
+            _ => return Ok(()),
+        };
+
+        let file_dialog = QFileDialog::from_q_widget_q_string(&self.dialog(), &qtr("profile_export_select_file"));
+        file_dialog.set_accept_mode(AcceptMode::AcceptSave);
+        file_dialog.set_name_filter(&QString::from_std_str("Runcher Profile (*.json)"));
+        file_dialog.set_default_suffix(&QString::from_std_str("json"));
+        file_dialog.select_file(&QString::from_std_str(format!("{}.json", name)));
+
+        if file_dialog.exec() == 1 {
+            let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            profile.export(&path, read_only)?;
+
+            // Reload the exported file to show its fingerprint, so the user can share it with the group.
+            let game = app_ui.game_selected().read().unwrap().clone();
+            let exported = Profile::import(&game, &path)?;
+            show_dialog(&self.dialog(), tre("profile_export_success", &[&exported.fingerprint().unwrap_or_default()]), true);
+        }
+
+        Ok(())
+    }
+
+    pub unsafe fn edit_env_vars(&self, app_ui: &Rc<AppUI>) -> Result<()> {
+        let selection = self.list_selection();
+        let index = &selection[0];
+        let name = index.data_1a(2).to_string().to_std_string();
+        let current_env_vars = app_ui.game_profiles().read().unwrap().get(&name).map(|profile| profile.env_vars().to_owned()).ok_or_else(|| anyhow!("No profile with said name found for the game selected."))?;
+
+        let dialog = QInputDialog::new_1a(&self.dialog());
+        dialog.set_window_title(&qtr("profile_env_vars"));
+        dialog.set_label_text(&qtr("profile_env_vars_label"));
+        dialog.set_input_mode(InputMode::TextInput);
+        dialog.set_text_value(&QString::from_std_str(&current_env_vars));
+
+        if dialog.exec() == DialogCode::Accepted.to_int() {
+            let env_vars = dialog.text_value().to_std_string().trim().to_owned();
+
+            // Check them now, so mistakes are found here and not when launching the game.
+            parse_env_vars(&env_vars)?;
+
+            if let Some(profile) = app_ui.game_profiles().write().unwrap().get_mut(&name) {
+                let game = app_ui.game_selected().read().unwrap();
+                profile.set_env_vars(env_vars);
+                profile.save(&game, &name)?;
+            }
+
+            // Reload the detailed view to show the new variables.
+            let selection = self.profiles_list_view().selection_model().selection();
+            self.profiles_list_view().selection_model().select_q_item_selection_q_flags_selection_flag(&selection, SelectionFlag::Toggle.into());
+            self.profiles_list_view().selection_model().select_q_item_selection_q_flags_selection_flag(&selection, SelectionFlag::Toggle.into());
+        }
+
+        Ok(())
+    }
+
+    pub unsafe fn import_profile(&self, app_ui: &Rc<AppUI>) -> Result<()> {
+        let file_dialog = QFileDialog::from_q_widget_q_string(&self.dialog(), &qtr("profile_import_select_file"));
+        file_dialog.set_file_mode(FileMode::ExistingFile);
+        file_dialog.set_name_filter(&QString::from_std_str("Runcher Profile (*.json)"));
+
+        if file_dialog.exec() == 1 {
+            let path = PathBuf::from(file_dialog.selected_files().at(0).to_std_string());
+            let game = app_ui.game_selected().read().unwrap().clone();
+            let mut profile = Profile::import(&game, &path)?;
+
+            if app_ui.game_profiles().read().unwrap().contains_key(profile.id()) {
+                return Err(anyhow!(tre("profile_import_name_in_use", &[profile.id()])));
+            }
+
+            let id = profile.id().to_owned();
+            profile.save(&game, &id)?;
+
+            // Reload the lists, so the new profile can be loaded right away.
+            app_ui.reload_profiles_list(&game);
+            self.profiles_list_model().clear();
+            self.load_data(app_ui);
+
+            let fingerprint = profile.fingerprint().unwrap_or_else(|| tr("profile_import_unsigned"));
+            show_dialog(&self.dialog(), tre("profile_import_success", &[&id, &fingerprint]), true);
+        }
+
+        Ok(())
+    }
+
     pub unsafe fn create_shortcut(&self, app_ui: &Rc<AppUI>) -> Result<()> {
         let selection = self.list_selection();
         let index = &selection[0];
@@ -35,6 +35,9 @@ pub struct ProfilesUISlots {
     profile_rename: QBox<SlotNoArgs>,
     profile_delete: QBox<SlotNoArgs>,
     profile_shorcut: QBox<SlotNoArgs>,
+    profile_export: QBox<SlotNoArgs>,
+    profile_import: QBox<SlotNoArgs>,
+    profile_env_vars: QBox<SlotNoArgs>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -65,6 +68,8 @@ impl ProfilesUISlots {
                     ui.delete_profile_button().set_enabled(true);
                     ui.rename_profile_button().set_enabled(true);
                     ui.shortcut_button().set_enabled(true);
+                    ui.export_button().set_enabled(true);
+                    ui.env_vars_button().set_enabled(true);
                 }
 
                 // If nothing is loaded, means we're selecting multiple things, or none.
@@ -76,6 +81,8 @@ impl ProfilesUISlots {
                     ui.delete_profile_button().set_enabled(false);
                     ui.rename_profile_button().set_enabled(false);
                     ui.shortcut_button().set_enabled(false);
+                    ui.export_button().set_enabled(false);
+                    ui.env_vars_button().set_enabled(false);
                 }
             }
         ));
@@ -107,12 +114,42 @@ impl ProfilesUISlots {
             }
         ));
 
+        let profile_export = SlotNoArgs::new(ui.main_widget(), clone!(
+            app_ui,
+            ui => move || {
+                if let Err(error) = ui.export_profile(&app_ui) {
+                    show_dialog(ui.main_widget(), error, false);
+                }
+            }
+        ));
+
+        let profile_import = SlotNoArgs::new(ui.main_widget(), clone!(
+            app_ui,
+            ui => move || {
+                if let Err(error) = ui.import_profile(&app_ui) {
+                    show_dialog(ui.main_widget(), error, false);
+                }
+            }
+        ));
+
+        let profile_env_vars = SlotNoArgs::new(ui.main_widget(), clone!(
+            app_ui,
+            ui => move || {
+                if let Err(error) = ui.edit_env_vars(&app_ui) {
+                    show_dialog(ui.main_widget(), error, false);
+                }
+            }
+        ));
+
         Self {
             update_details,
 
             profile_rename,
             profile_delete,
             profile_shorcut,
+            profile_export,
+            profile_import,
+            profile_env_vars,
         }
     }
 }
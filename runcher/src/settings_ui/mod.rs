@@ -14,31 +14,37 @@ use qt_widgets::QCheckBox;
 use qt_widgets::QComboBox;
 use qt_widgets::QDialog;
 use qt_widgets::QDialogButtonBox;
+use qt_widgets::QDoubleSpinBox;
 use qt_widgets::q_header_view::ResizeMode;
 use qt_widgets::q_dialog_button_box::{ButtonRole, StandardButton};
 use qt_widgets::{QFrame, q_frame::Shape};
 use qt_widgets::{QFileDialog, q_file_dialog::{FileMode, Option as QFileDialogOption}};
 use qt_widgets::QGridLayout;
 use qt_widgets::QGroupBox;
+use qt_widgets::QKeySequenceEdit;
 use qt_widgets::QLabel;
 use qt_widgets::QLineEdit;
 use qt_widgets::QMainWindow;
 use qt_widgets::QMenu;
 use qt_widgets::QPushButton;
+use qt_widgets::QSpinBox;
 use qt_widgets::QTableView;
 use qt_widgets::QToolButton;
 
 use qt_gui::QIcon;
+use qt_gui::QKeySequence;
 use qt_gui::QListOfQStandardItem;
 use qt_gui::QStandardItem;
 use qt_gui::QStandardItemModel;
 
+use qt_core::ItemDataRole;
 use qt_core::QBox;
 use qt_core::QCoreApplication;
 use qt_core::QFlags;
 use qt_core::QObject;
 use qt_core::QPtr;
 use qt_core::QString;
+use qt_core::QVariant;
 
 use anyhow::{anyhow, Result};
 use directories::ProjectDirs;
@@ -52,14 +58,24 @@ use std::rc::Rc;
 use std::time::UNIX_EPOCH;
 
 use rpfm_lib::games::{GameInfo, supported_games::{KEY_ARENA, KEY_WARHAMMER_3}};
+use rpfm_lib::integrations::log::warn;
+use rpfm_lib::utils::files_from_subdir;
 
 use rpfm_ui_common::locale::*;
 use rpfm_ui_common::settings::*;
 use rpfm_ui_common::tools::*;
 use rpfm_ui_common::utils::*;
 
+use crate::FALLBACK_LOCALE_EN;
 use crate::ffi::*;
+use crate::locale_loader::{available_locales, locale_completeness};
+use crate::logging::*;
+use crate::mod_manager::game_definitions::executable_path;
+use crate::mod_manager::hooks::{Hook, HookFailurePolicy, Hooks, HookStage};
+use crate::mod_manager::masks::masks_path;
+use crate::pack_list_ui::DEFAULT_HIDDEN_COLUMNS;
 use crate::SUPPORTED_GAMES;
+use crate::themes::*;
 use crate::updater_ui::*;
 
 use self::slots::SettingsUISlots;
@@ -73,8 +89,75 @@ pub const SLASH_DMY_DATE_FORMAT_STR: &str = "[day]/[month]/[year]";
 pub const SLASH_MDY_DATE_FORMAT_STR: &str = "[month]/[day]/[year]";
 pub const SLASH_YMD_DATE_FORMAT_STR: &str = "[year]/[month]/[day]";
 
+/// What to do with Runcher's window once a game has been launched.
+pub const POST_LAUNCH_KEEP_OPEN: &str = "keep_open";
+pub const POST_LAUNCH_MINIMIZE_TO_TRAY: &str = "minimize_to_tray";
+pub const POST_LAUNCH_CLOSE: &str = "close";
+
+/// What double-clicking a mod in the Mod List does.
+pub const MOD_DOUBLE_CLICK_TOGGLE: &str = "toggle";
+pub const MOD_DOUBLE_CLICK_OPEN_WORKSHOP: &str = "open_workshop";
+pub const MOD_DOUBLE_CLICK_OPEN_FOLDER: &str = "open_folder";
+pub const MOD_DOUBLE_CLICK_OPEN_RPFM: &str = "open_rpfm";
+
+/// Which game is loaded when Runcher starts without a game passed through the cli.
+pub const STARTUP_GAME_DEFAULT: &str = "default";
+pub const STARTUP_GAME_LAST_USED: &str = "last_used";
+pub const STARTUP_GAME_ASK: &str = "ask";
+
+/// How the games are launched. Direct launches go through workshopper and cmd, the Steam API one has workshopper run the game itself,
+/// and the Steam one asks the Steam client to launch the game.
+pub const LAUNCH_MODE_DIRECT: &str = "direct";
+pub const LAUNCH_MODE_STEAM_API: &str = "steam_api";
+pub const LAUNCH_MODE_STEAM: &str = "steam";
+
 const TRANSLATIONS_LOCAL_FOLDER: &str = "translations_local";
 const TRANSLATIONS_REMOTE_FOLDER: &str = "translations_remote";
+const GAME_CONFIG_FOLDER: &str = "game_config";
+const PROFILES_FOLDER: &str = "profiles";
+const SAVE_BACKUPS_FOLDER: &str = "save_backups";
+const TEMP_PACKS_FOLDER: &str = "temp_packs";
+const MASKS_FOLDER: &str = "masks";
+
+/// List of configurable shortcuts, as pairs of setting key (also used as locale key) and default key sequence.
+pub const SHORTCUTS: [(&str, &str); 9] = [
+    ("shortcut_launch_game", "Ctrl+Return"),
+    ("shortcut_reload", "F5"),
+    ("shortcut_next_game", "Ctrl+PgDown"),
+    ("shortcut_previous_game", "Ctrl+PgUp"),
+    ("shortcut_toggle_selected_mods", "Ctrl+Space"),
+    ("shortcut_focus_filter", "Ctrl+F"),
+    ("shortcut_move_pack_up", "Ctrl+Up"),
+    ("shortcut_move_pack_down", "Ctrl+Down"),
+    ("shortcut_undo", "Ctrl+Z"),
+];
+
+/// Stages a hook can run at, as pairs of stage and locale key, in the order they're shown in the settings.
+const HOOK_STAGES: [(HookStage, &str); 2] = [
+    (HookStage::PreLaunch, "hooks_stage_pre_launch"),
+    (HookStage::PostExit, "hooks_stage_post_exit"),
+];
+
+/// Failure policies of the hooks, as pairs of policy and locale key, in the order they're shown in the settings.
+const HOOK_FAILURE_POLICIES: [(HookFailurePolicy, &str); 3] = [
+    (HookFailurePolicy::Ignore, "hooks_failure_policy_ignore"),
+    (HookFailurePolicy::Warn, "hooks_failure_policy_warn"),
+    (HookFailurePolicy::Abort, "hooks_failure_policy_abort"),
+];
+
+/// List of Windows compatibility modes the games can be launched with, as pairs of display name and compatibility layer.
+pub const COMPATIBILITY_MODES: [(&str, &str); 10] = [
+    ("--", ""),
+    ("Windows 95", "WIN95"),
+    ("Windows 98", "WIN98"),
+    ("Windows XP SP2", "WINXPSP2"),
+    ("Windows XP SP3", "WINXPSP3"),
+    ("Windows Vista", "VISTARTM"),
+    ("Windows Vista SP1", "VISTASP1"),
+    ("Windows Vista SP2", "VISTASP2"),
+    ("Windows 7", "WIN7RTM"),
+    ("Windows 8", "WIN8RTM"),
+];
 
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
@@ -86,13 +169,26 @@ pub struct SettingsUI {
     dialog: QPtr<QDialog>,
 
     font_data: Rc<RefCell<(String, i32)>>,
+    accent_color: Rc<RefCell<String>>,
 
     paths_games_line_edits: BTreeMap<String, QBox<QLineEdit>>,
     paths_games_buttons: BTreeMap<String, QBox<QToolButton>>,
     paths_games_lock_checkboxes: BTreeMap<String, QBox<QCheckBox>>,
+    paths_games_elevated_checkboxes: BTreeMap<String, QBox<QCheckBox>>,
+    paths_games_compatibility_comboboxes: BTreeMap<String, QBox<QComboBox>>,
+    paths_games_launch_mode_comboboxes: BTreeMap<String, QBox<QComboBox>>,
+    paths_games_skip_intro_videos_line_edits: BTreeMap<String, QBox<QLineEdit>>,
+    paths_games_config_path_line_edits: BTreeMap<String, QBox<QLineEdit>>,
+    paths_games_env_vars_line_edits: BTreeMap<String, QBox<QLineEdit>>,
 
     secondary_mods_folder_line_edit: QBox<QLineEdit>,
     secondary_mods_folder_button: QBox<QToolButton>,
+    sync_folder_line_edit: QBox<QLineEdit>,
+    sync_folder_button: QBox<QToolButton>,
+    save_backups_folder_line_edit: QBox<QLineEdit>,
+    save_backups_folder_button: QBox<QToolButton>,
+    generated_files_folder_line_edit: QBox<QLineEdit>,
+    generated_files_folder_button: QBox<QToolButton>,
 
     tools_tableview: QPtr<QTableView>,
     tools_model: QBox<QStandardItemModel>,
@@ -100,6 +196,12 @@ pub struct SettingsUI {
     tools_add: QPtr<QAction>,
     tools_remove: QPtr<QAction>,
 
+    hooks_tableview: QPtr<QTableView>,
+    hooks_model: QBox<QStandardItemModel>,
+    hooks_context_menu: QBox<QMenu>,
+    hooks_add: QPtr<QAction>,
+    hooks_remove: QPtr<QAction>,
+
     steam_api_key_line_edit: QPtr<QLineEdit>,
 
     language_combobox: QPtr<QComboBox>,
@@ -108,9 +210,34 @@ pub struct SettingsUI {
     date_format_combobox: QPtr<QComboBox>,
     check_updates_on_start_checkbox: QPtr<QCheckBox>,
     check_schema_updates_on_start_checkbox: QPtr<QCheckBox>,
-    dark_mode_checkbox: QPtr<QCheckBox>,
+    theme_combobox: QPtr<QComboBox>,
+    accent_color_button: QPtr<QToolButton>,
     open_workshop_link_in_steam_checkbox: QPtr<QCheckBox>,
     check_logs_checkbox: QPtr<QCheckBox>,
+    launch_countdown_checkbox: QPtr<QCheckBox>,
+    tray_mode_checkbox: QPtr<QCheckBox>,
+    post_launch_behavior_combobox: QPtr<QComboBox>,
+    mod_double_click_action_combobox: QPtr<QComboBox>,
+    startup_game_combobox: QPtr<QComboBox>,
+    show_generated_packs_checkbox: QPtr<QCheckBox>,
+    apply_conflict_suggestions_checkbox: QPtr<QCheckBox>,
+    check_script_collisions_checkbox: QPtr<QCheckBox>,
+    check_other_managers_checkbox: QPtr<QCheckBox>,
+    enable_sentry_checkbox: QPtr<QCheckBox>,
+    recently_added_days_spinbox: QPtr<QSpinBox>,
+    check_shadowed_packs_checkbox: QPtr<QCheckBox>,
+    offline_mode_checkbox: QPtr<QCheckBox>,
+    backup_saves_before_launch_checkbox: QPtr<QCheckBox>,
+    enable_api_server_checkbox: QPtr<QCheckBox>,
+    unit_multiplier_decimals_spinbox: QPtr<QSpinBox>,
+    unit_multiplier_step_spinbox: QPtr<QDoubleSpinBox>,
+    backup_saves_retention_spinbox: QPtr<QSpinBox>,
+    backup_saves_count_spinbox: QPtr<QSpinBox>,
+
+    shortcuts_key_sequence_edits: BTreeMap<String, QBox<QKeySequenceEdit>>,
+
+    log_level_combobox: QBox<QComboBox>,
+    log_module_level_comboboxes: BTreeMap<String, QBox<QComboBox>>,
 
     font_button: QBox<QPushButton>,
     restore_default_button: QPtr<QPushButton>,
@@ -161,6 +288,18 @@ impl SettingsUI {
         let tools_add = tools_context_menu.add_action_q_string(&qtr("tools_add"));
         let tools_remove = tools_context_menu.add_action_q_string(&qtr("tools_remove"));
 
+        let hooks_groupbox: QPtr<QGroupBox> = find_widget(&main_widget.static_upcast(), "hooks_groupbox")?;
+        let hooks_tableview: QPtr<QTableView> = find_widget(&main_widget.static_upcast(), "hooks_tableview")?;
+        let hooks_model = QStandardItemModel::new_1a(&hooks_tableview);
+        hooks_tableview.set_model(&hooks_model);
+        hooks_groupbox.set_title(&qtr("hooks_title"));
+        hooks_groupbox.set_tool_tip(&qtr("hooks_title_tt"));
+        game_selector_item_delegate_safe(&hooks_tableview.static_upcast::<QObject>().as_ptr(), 3);
+
+        let hooks_context_menu = QMenu::from_q_widget(&main_widget);
+        let hooks_add = hooks_context_menu.add_action_q_string(&qtr("hooks_add"));
+        let hooks_remove = hooks_context_menu.add_action_q_string(&qtr("hooks_remove"));
+
         let paths_groupbox: QPtr<QGroupBox> = find_widget(&main_widget.static_upcast(), "paths_groupbox")?;
         let language_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "language_label")?;
         let default_game_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "default_game_label")?;
@@ -169,9 +308,28 @@ impl SettingsUI {
         let steam_api_key_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "steam_api_key_label")?;
         let check_updates_on_start_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "check_updates_on_start_label")?;
         let check_schema_updates_on_start_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "check_schema_updates_on_start_label")?;
-        let dark_mode_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "dark_mode_label")?;
+        let theme_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "theme_label")?;
         let open_workshop_link_in_steam_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "open_workshop_link_in_steam_label")?;
         let check_logs_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "check_logs_label")?;
+        let launch_countdown_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "launch_countdown_label")?;
+        let tray_mode_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "tray_mode_label")?;
+        let post_launch_behavior_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "post_launch_behavior_label")?;
+        let mod_double_click_action_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "mod_double_click_action_label")?;
+        let startup_game_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "startup_game_label")?;
+        let show_generated_packs_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "show_generated_packs_label")?;
+        let apply_conflict_suggestions_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "apply_conflict_suggestions_label")?;
+        let check_script_collisions_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "check_script_collisions_label")?;
+        let check_other_managers_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "check_other_managers_label")?;
+        let enable_sentry_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "enable_sentry_label")?;
+        let recently_added_days_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "recently_added_days_label")?;
+        let check_shadowed_packs_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "check_shadowed_packs_label")?;
+        let offline_mode_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "offline_mode_label")?;
+        let backup_saves_before_launch_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "backup_saves_before_launch_label")?;
+        let enable_api_server_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "enable_api_server_label")?;
+        let unit_multiplier_decimals_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "unit_multiplier_decimals_label")?;
+        let unit_multiplier_step_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "unit_multiplier_step_label")?;
+        let backup_saves_retention_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "backup_saves_retention_label")?;
+        let backup_saves_count_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "backup_saves_count_label")?;
         let language_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "language_combobox")?;
         let default_game_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "default_game_combobox")?;
         let update_chanel_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "update_chanel_combobox")?;
@@ -179,9 +337,29 @@ impl SettingsUI {
         let steam_api_key_line_edit: QPtr<QLineEdit> = find_widget(&main_widget.static_upcast(), "steam_api_key_line_edit")?;
         let check_updates_on_start_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "check_updates_on_start_checkbox")?;
         let check_schema_updates_on_start_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "check_schema_updates_on_start_checkbox")?;
-        let dark_mode_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "dark_mode_checkbox")?;
+        let theme_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "theme_combobox")?;
+        let accent_color_button: QPtr<QToolButton> = find_widget(&main_widget.static_upcast(), "accent_color_button")?;
         let open_workshop_link_in_steam_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "open_workshop_link_in_steam_checkbox")?;
         let check_logs_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "check_logs_checkbox")?;
+        let launch_countdown_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "launch_countdown_checkbox")?;
+        let tray_mode_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "tray_mode_checkbox")?;
+        let post_launch_behavior_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "post_launch_behavior_combobox")?;
+        let mod_double_click_action_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "mod_double_click_action_combobox")?;
+        let startup_game_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "startup_game_combobox")?;
+        let show_generated_packs_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "show_generated_packs_checkbox")?;
+        let apply_conflict_suggestions_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "apply_conflict_suggestions_checkbox")?;
+        let check_script_collisions_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "check_script_collisions_checkbox")?;
+        let check_other_managers_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "check_other_managers_checkbox")?;
+        let enable_sentry_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "enable_sentry_checkbox")?;
+        let recently_added_days_spinbox: QPtr<QSpinBox> = find_widget(&main_widget.static_upcast(), "recently_added_days_spinbox")?;
+        let check_shadowed_packs_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "check_shadowed_packs_checkbox")?;
+        let offline_mode_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "offline_mode_checkbox")?;
+        let backup_saves_before_launch_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "backup_saves_before_launch_checkbox")?;
+        let enable_api_server_checkbox: QPtr<QCheckBox> = find_widget(&main_widget.static_upcast(), "enable_api_server_checkbox")?;
+        let unit_multiplier_decimals_spinbox: QPtr<QSpinBox> = find_widget(&main_widget.static_upcast(), "unit_multiplier_decimals_spinbox")?;
+        let unit_multiplier_step_spinbox: QPtr<QDoubleSpinBox> = find_widget(&main_widget.static_upcast(), "unit_multiplier_step_spinbox")?;
+        let backup_saves_retention_spinbox: QPtr<QSpinBox> = find_widget(&main_widget.static_upcast(), "backup_saves_retention_spinbox")?;
+        let backup_saves_count_spinbox: QPtr<QSpinBox> = find_widget(&main_widget.static_upcast(), "backup_saves_count_spinbox")?;
         let paths_layout: QPtr<QGridLayout> = paths_groupbox.layout().static_downcast();
         update_chanel_combobox.add_item_q_string(&QString::from_std_str(STABLE));
         update_chanel_combobox.add_item_q_string(&QString::from_std_str(BETA));
@@ -189,6 +367,17 @@ impl SettingsUI {
         date_format_combobox.add_item_q_string(&QString::from_std_str(SLASH_MDY_DATE_FORMAT_STR));
         date_format_combobox.add_item_q_string(&QString::from_std_str(SLASH_YMD_DATE_FORMAT_STR));
 
+        // Built-in themes use their key as data, user themes their file name.
+        theme_combobox.add_item_q_string_q_variant(&qtr("theme_system"), &QVariant::from_q_string(&QString::from_std_str(THEME_SYSTEM)));
+        theme_combobox.add_item_q_string_q_variant(&qtr("theme_light"), &QVariant::from_q_string(&QString::from_std_str(THEME_LIGHT)));
+        theme_combobox.add_item_q_string_q_variant(&qtr("theme_dark"), &QVariant::from_q_string(&QString::from_std_str(THEME_DARK)));
+        for theme in user_themes() {
+            theme_combobox.add_item_q_string_q_variant(&QString::from_std_str(&theme), &QVariant::from_q_string(&QString::from_std_str(&theme)));
+        }
+
+        theme_combobox.set_tool_tip(&qtre("theme_tt", &[&themes_path().map(|path| path.to_string_lossy().to_string()).unwrap_or_default()]));
+        accent_color_button.set_tool_tip(&qtr("accent_color_tt"));
+
         paths_groupbox.set_title(&qtr("game_paths"));
         language_label.set_text(&qtr("language"));
         default_game_label.set_text(&qtr("default_game"));
@@ -197,9 +386,58 @@ impl SettingsUI {
         steam_api_key_label.set_text(&qtr("steam_api_key"));
         check_updates_on_start_label.set_text(&qtr("check_updates_on_start"));
         check_schema_updates_on_start_label.set_text(&qtr("check_schema_updates_on_start"));
-        dark_mode_label.set_text(&qtr("dark_mode"));
+        theme_label.set_text(&qtr("theme"));
         open_workshop_link_in_steam_label.set_text(&qtr("open_workshop_link_in_steam"));
         check_logs_label.set_text(&qtr("check_logs"));
+        launch_countdown_label.set_text(&qtr("launch_countdown"));
+        launch_countdown_label.set_tool_tip(&qtr("launch_countdown_tt"));
+        tray_mode_label.set_text(&qtr("tray_mode"));
+        tray_mode_label.set_tool_tip(&qtr("tray_mode_tt"));
+        post_launch_behavior_label.set_text(&qtr("post_launch_behavior"));
+        post_launch_behavior_label.set_tool_tip(&qtr("post_launch_behavior_tt"));
+        for behavior in [POST_LAUNCH_KEEP_OPEN, POST_LAUNCH_MINIMIZE_TO_TRAY, POST_LAUNCH_CLOSE] {
+            post_launch_behavior_combobox.add_item_q_string_q_variant(&qtr(&format!("post_launch_{behavior}")), &QVariant::from_q_string(&QString::from_std_str(behavior)));
+        }
+
+        mod_double_click_action_label.set_text(&qtr("mod_double_click_action"));
+        mod_double_click_action_label.set_tool_tip(&qtr("mod_double_click_action_tt"));
+        for action in [MOD_DOUBLE_CLICK_TOGGLE, MOD_DOUBLE_CLICK_OPEN_WORKSHOP, MOD_DOUBLE_CLICK_OPEN_FOLDER, MOD_DOUBLE_CLICK_OPEN_RPFM] {
+            mod_double_click_action_combobox.add_item_q_string_q_variant(&qtr(&format!("mod_double_click_{action}")), &QVariant::from_q_string(&QString::from_std_str(action)));
+        }
+
+        startup_game_label.set_text(&qtr("startup_game"));
+        startup_game_label.set_tool_tip(&qtr("startup_game_tt"));
+        for startup_game in [STARTUP_GAME_DEFAULT, STARTUP_GAME_LAST_USED, STARTUP_GAME_ASK] {
+            startup_game_combobox.add_item_q_string_q_variant(&qtr(&format!("startup_game_{startup_game}")), &QVariant::from_q_string(&QString::from_std_str(startup_game)));
+        }
+        show_generated_packs_label.set_text(&qtr("show_generated_packs"));
+        show_generated_packs_label.set_tool_tip(&qtr("show_generated_packs_tt"));
+        apply_conflict_suggestions_label.set_text(&qtr("apply_conflict_suggestions"));
+        apply_conflict_suggestions_label.set_tool_tip(&qtr("apply_conflict_suggestions_tt"));
+        check_script_collisions_label.set_text(&qtr("check_script_collisions"));
+        check_script_collisions_label.set_tool_tip(&qtr("check_script_collisions_tt"));
+        check_other_managers_label.set_text(&qtr("check_other_managers"));
+        check_other_managers_label.set_tool_tip(&qtr("check_other_managers_tt"));
+        enable_sentry_label.set_text(&qtr("enable_sentry"));
+        enable_sentry_label.set_tool_tip(&qtr("enable_sentry_tt"));
+        recently_added_days_label.set_text(&qtr("recently_added_days"));
+        recently_added_days_label.set_tool_tip(&qtr("recently_added_days_tt"));
+        check_shadowed_packs_label.set_text(&qtr("check_shadowed_packs"));
+        check_shadowed_packs_label.set_tool_tip(&qtr("check_shadowed_packs_tt"));
+        offline_mode_label.set_text(&qtr("offline_mode"));
+        offline_mode_label.set_tool_tip(&qtr("offline_mode_tt"));
+        backup_saves_before_launch_label.set_text(&qtr("backup_saves_before_launch"));
+        backup_saves_before_launch_label.set_tool_tip(&qtr("backup_saves_before_launch_tt"));
+        enable_api_server_label.set_text(&qtr("enable_api_server"));
+        enable_api_server_label.set_tool_tip(&qtr("enable_api_server_tt"));
+        unit_multiplier_decimals_label.set_text(&qtr("unit_multiplier_decimals"));
+        unit_multiplier_decimals_label.set_tool_tip(&qtr("unit_multiplier_decimals_tt"));
+        unit_multiplier_step_label.set_text(&qtr("unit_multiplier_step"));
+        unit_multiplier_step_label.set_tool_tip(&qtr("unit_multiplier_step_tt"));
+        backup_saves_retention_label.set_text(&qtr("backup_saves_retention"));
+        backup_saves_retention_label.set_tool_tip(&qtr("backup_saves_retention_tt"));
+        backup_saves_count_label.set_text(&qtr("backup_saves_count"));
+        backup_saves_count_label.set_tool_tip(&qtr("backup_saves_count_tt"));
 
         // Add one path at the beginning for the secondary mods folder.
         let secondary_mods_folder_label = QLabel::from_q_string_q_widget(&qtr("settings_secondary_mods_folder"), &paths_groupbox);
@@ -212,15 +450,54 @@ impl SettingsUI {
         paths_layout.add_widget_5a(&secondary_mods_folder_line_edit, 0, 1, 1, 1);
         paths_layout.add_widget_5a(&secondary_mods_folder_button, 0, 2, 1, 1);
 
+        // Then another one for the sync folder, used to share game configs and profiles between computers.
+        let sync_folder_label = QLabel::from_q_string_q_widget(&qtr("settings_sync_folder"), &paths_groupbox);
+        let sync_folder_line_edit = QLineEdit::from_q_widget(&paths_groupbox);
+        let sync_folder_button = QToolButton::new_1a(&paths_groupbox);
+        sync_folder_line_edit.set_placeholder_text(&qtr("settings_sync_folder_ph"));
+        sync_folder_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("folder")));
+
+        paths_layout.add_widget_5a(&sync_folder_label, 1, 0, 1, 1);
+        paths_layout.add_widget_5a(&sync_folder_line_edit, 1, 1, 1, 1);
+        paths_layout.add_widget_5a(&sync_folder_button, 1, 2, 1, 1);
+
+        // And another one for the folder where the saves are backed up before launching.
+        let save_backups_folder_label = QLabel::from_q_string_q_widget(&qtr("settings_save_backups_folder"), &paths_groupbox);
+        let save_backups_folder_line_edit = QLineEdit::from_q_widget(&paths_groupbox);
+        let save_backups_folder_button = QToolButton::new_1a(&paths_groupbox);
+        save_backups_folder_line_edit.set_placeholder_text(&qtr("settings_save_backups_folder_ph"));
+        save_backups_folder_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("folder")));
+
+        paths_layout.add_widget_5a(&save_backups_folder_label, 2, 0, 1, 1);
+        paths_layout.add_widget_5a(&save_backups_folder_line_edit, 2, 1, 1, 1);
+        paths_layout.add_widget_5a(&save_backups_folder_button, 2, 2, 1, 1);
+
+        // And another one for the folder where the packs generated on launch are written.
+        let generated_files_folder_label = QLabel::from_q_string_q_widget(&qtr("settings_generated_files_folder"), &paths_groupbox);
+        let generated_files_folder_line_edit = QLineEdit::from_q_widget(&paths_groupbox);
+        let generated_files_folder_button = QToolButton::new_1a(&paths_groupbox);
+        generated_files_folder_line_edit.set_placeholder_text(&qtr("settings_generated_files_folder_ph"));
+        generated_files_folder_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("folder")));
+
+        paths_layout.add_widget_5a(&generated_files_folder_label, 3, 0, 1, 1);
+        paths_layout.add_widget_5a(&generated_files_folder_line_edit, 3, 1, 1, 1);
+        paths_layout.add_widget_5a(&generated_files_folder_button, 3, 2, 1, 1);
+
         // TODO: Maybe add a separator here.
         let line = QFrame::new_1a(&paths_groupbox);
         line.set_frame_shape(Shape::HLine);
-        paths_layout.add_widget_5a(&line, 1, 0, 1, 3);
+        paths_layout.add_widget_5a(&line, 4, 0, 1, 3);
 
         // We automatically add a Label/LineEdit/Button for each game we support.
         let mut paths_games_line_edits = BTreeMap::new();
         let mut paths_games_buttons = BTreeMap::new();
         let mut paths_games_lock_checkboxes = BTreeMap::new();
+        let mut paths_games_elevated_checkboxes = BTreeMap::new();
+        let mut paths_games_compatibility_comboboxes = BTreeMap::new();
+        let mut paths_games_launch_mode_comboboxes = BTreeMap::new();
+        let mut paths_games_skip_intro_videos_line_edits = BTreeMap::new();
+        let mut paths_games_config_path_line_edits = BTreeMap::new();
+        let mut paths_games_env_vars_line_edits = BTreeMap::new();
 
         for (index, game) in SUPPORTED_GAMES.games_sorted().iter().enumerate() {
             if game.key() != KEY_ARENA {
@@ -229,28 +506,116 @@ impl SettingsUI {
                 let game_line_edit = QLineEdit::from_q_widget(&paths_groupbox);
                 let game_button = QToolButton::new_1a(&paths_groupbox);
                 let game_lock_checkbox = QCheckBox::from_q_string_q_widget(&qtr("updates_locked"), &paths_groupbox);
+                let game_elevated_checkbox = QCheckBox::from_q_string_q_widget(&qtr("launch_elevated"), &paths_groupbox);
+                let game_compatibility_combobox = QComboBox::new_1a(&paths_groupbox);
+                let game_launch_mode_combobox = QComboBox::new_1a(&paths_groupbox);
+                let game_skip_intro_videos_line_edit = QLineEdit::from_q_widget(&paths_groupbox);
+                let game_config_path_line_edit = QLineEdit::from_q_widget(&paths_groupbox);
+                let game_env_vars_line_edit = QLineEdit::from_q_widget(&paths_groupbox);
+                game_elevated_checkbox.set_tool_tip(&qtr("launch_elevated_tt"));
+                game_compatibility_combobox.set_tool_tip(&qtr("compatibility_mode_tt"));
+                game_launch_mode_combobox.set_tool_tip(&qtr("launch_mode_tt"));
+                game_skip_intro_videos_line_edit.set_placeholder_text(&qtr("skip_intro_videos_ph"));
+                game_skip_intro_videos_line_edit.set_tool_tip(&qtr("skip_intro_videos_tt"));
+                game_config_path_line_edit.set_placeholder_text(&qtr("config_path_override_ph"));
+                game_config_path_line_edit.set_tool_tip(&qtr("config_path_override_tt"));
+                game_env_vars_line_edit.set_placeholder_text(&qtr("launch_env_vars_ph"));
+                game_env_vars_line_edit.set_tool_tip(&qtr("launch_env_vars_tt"));
+
+                for (mode, _) in COMPATIBILITY_MODES {
+                    game_compatibility_combobox.add_item_q_string(&QString::from_std_str(mode));
+                }
+
+                for mode in [LAUNCH_MODE_DIRECT, LAUNCH_MODE_STEAM_API, LAUNCH_MODE_STEAM] {
+                    game_launch_mode_combobox.add_item_q_string_q_variant(&qtr(&format!("launch_mode_{mode}")), &QVariant::from_q_string(&QString::from_std_str(mode)));
+                }
 
                 game_line_edit.set_placeholder_text(&qtre("settings_game_line_ph", &[game.display_name()]));
                 game_button.set_icon(&QIcon::from_theme_1a(&QString::from_std_str("folder")));
 
-                paths_layout.add_widget_5a(&game_label, index as i32 + 2, 0, 1, 1);
-                paths_layout.add_widget_5a(&game_line_edit, index as i32 + 2, 1, 1, 1);
-                paths_layout.add_widget_5a(&game_button, index as i32 + 2, 2, 1, 1);
-                paths_layout.add_widget_5a(&game_lock_checkbox, index as i32 + 2, 3, 1, 1);
+                paths_layout.add_widget_5a(&game_label, index as i32 + 5, 0, 1, 1);
+                paths_layout.add_widget_5a(&game_line_edit, index as i32 + 5, 1, 1, 1);
+                paths_layout.add_widget_5a(&game_button, index as i32 + 5, 2, 1, 1);
+                paths_layout.add_widget_5a(&game_lock_checkbox, index as i32 + 5, 3, 1, 1);
+                paths_layout.add_widget_5a(&game_elevated_checkbox, index as i32 + 5, 4, 1, 1);
+                paths_layout.add_widget_5a(&game_compatibility_combobox, index as i32 + 5, 5, 1, 1);
+                paths_layout.add_widget_5a(&game_launch_mode_combobox, index as i32 + 5, 6, 1, 1);
+                paths_layout.add_widget_5a(&game_skip_intro_videos_line_edit, index as i32 + 5, 7, 1, 1);
+                paths_layout.add_widget_5a(&game_config_path_line_edit, index as i32 + 5, 8, 1, 1);
+                paths_layout.add_widget_5a(&game_env_vars_line_edit, index as i32 + 5, 9, 1, 1);
 
                 // Add the LineEdit and Button to the list.
                 paths_games_line_edits.insert(game_key.to_owned(), game_line_edit);
                 paths_games_buttons.insert(game_key.to_owned(), game_button);
                 paths_games_lock_checkboxes.insert(game_key.to_owned(), game_lock_checkbox);
+                paths_games_elevated_checkboxes.insert(game_key.to_owned(), game_elevated_checkbox);
+                paths_games_compatibility_comboboxes.insert(game_key.to_owned(), game_compatibility_combobox);
+                paths_games_launch_mode_comboboxes.insert(game_key.to_owned(), game_launch_mode_combobox);
+                paths_games_skip_intro_videos_line_edits.insert(game_key.to_owned(), game_skip_intro_videos_line_edit);
+                paths_games_config_path_line_edits.insert(game_key.to_owned(), game_config_path_line_edit);
+                paths_games_env_vars_line_edits.insert(game_key.to_owned(), game_env_vars_line_edit);
 
                 // Add the game to the default game combo.
                 default_game_combobox.add_item_q_string(&QString::from_std_str(game.display_name()));
             }
         }
 
-        if let Ok(locales) = Locale::get_available_locales() {
-            for (language, _) in locales {
-                language_combobox.add_item_q_string(&QString::from_std_str(language));
+        // Shortcuts are also added automatically, in two columns so they don't take too much space.
+        let shortcuts_groupbox: QPtr<QGroupBox> = find_widget(&main_widget.static_upcast(), "shortcuts_groupbox")?;
+        let shortcuts_layout: QPtr<QGridLayout> = shortcuts_groupbox.layout().static_downcast();
+        shortcuts_groupbox.set_title(&qtr("shortcuts_title"));
+
+        let mut shortcuts_key_sequence_edits = BTreeMap::new();
+        for (index, (key, _)) in SHORTCUTS.iter().enumerate() {
+            let row = index as i32 / 2;
+            let column = (index as i32 % 2) * 2;
+            let shortcut_label = QLabel::from_q_string_q_widget(&qtr(key), &shortcuts_groupbox);
+            let shortcut_key_sequence_edit = QKeySequenceEdit::from_q_widget(&shortcuts_groupbox);
+
+            shortcuts_layout.add_widget_5a(&shortcut_label, row, column, 1, 1);
+            shortcuts_layout.add_widget_5a(&shortcut_key_sequence_edit, row, column + 1, 1, 1);
+
+            shortcuts_key_sequence_edits.insert(key.to_string(), shortcut_key_sequence_edit);
+        }
+
+        // Log levels are also added automatically: first the global one, then one per module in three columns.
+        let logs_groupbox: QPtr<QGroupBox> = find_widget(&main_widget.static_upcast(), "logs_groupbox")?;
+        let logs_layout: QPtr<QGridLayout> = logs_groupbox.layout().static_downcast();
+        logs_groupbox.set_title(&qtr("logs_title"));
+
+        let log_level_label = QLabel::from_q_string_q_widget(&qtr("log_level"), &logs_groupbox);
+        let log_level_combobox = QComboBox::new_1a(&logs_groupbox);
+        for level in LOG_LEVELS {
+            log_level_combobox.add_item_q_string_q_variant(&QString::from_std_str(level), &QVariant::from_q_string(&QString::from_std_str(level)));
+        }
+
+        logs_layout.add_widget_5a(&log_level_label, 0, 0, 1, 1);
+        logs_layout.add_widget_5a(&log_level_combobox, 0, 1, 1, 1);
+
+        let mut log_module_level_comboboxes = BTreeMap::new();
+        for (index, module) in LOG_MODULES.iter().enumerate() {
+            let row = index as i32 / 3 + 1;
+            let column = (index as i32 % 3) * 2;
+            let module_label = QLabel::from_q_string_q_widget(&QString::from_std_str(module), &logs_groupbox);
+            let module_combobox = QComboBox::new_1a(&logs_groupbox);
+            module_combobox.add_item_q_string_q_variant(&qtr("log_level_default"), &QVariant::from_q_string(&QString::from_std_str(LOG_LEVEL_DEFAULT)));
+            for level in LOG_LEVELS {
+                module_combobox.add_item_q_string_q_variant(&QString::from_std_str(level), &QVariant::from_q_string(&QString::from_std_str(level)));
+            }
+
+            logs_layout.add_widget_5a(&module_label, row, column, 1, 1);
+            logs_layout.add_widget_5a(&module_combobox, row, column + 1, 1, 1);
+
+            log_module_level_comboboxes.insert(module.to_string(), module_combobox);
+        }
+
+        // Show how much of each locale is translated, so users know what to expect from community ones.
+        for (index, (language, file_name)) in available_locales().iter().enumerate() {
+            language_combobox.add_item_q_string(&QString::from_std_str(language));
+
+            if let Some(completeness) = locale_completeness(file_name, FALLBACK_LOCALE_EN) {
+                let tooltip = qtre("language_completeness", &[&format!("{completeness:.0}")]);
+                language_combobox.set_item_data_3a(index as i32, &QVariant::from_q_string(&tooltip), ItemDataRole::ToolTipRole.to_int());
             }
         }
 
@@ -265,6 +630,7 @@ impl SettingsUI {
         Ok(Rc::new(Self {
             dialog,
             font_data: Rc::new(RefCell::new((String::new(), -1))),
+            accent_color: Rc::new(RefCell::new(String::new())),
 
             tools_tableview,
             tools_model,
@@ -272,12 +638,30 @@ impl SettingsUI {
             tools_add,
             tools_remove,
 
+            hooks_tableview,
+            hooks_model,
+            hooks_context_menu,
+            hooks_add,
+            hooks_remove,
+
             paths_games_line_edits,
             paths_games_buttons,
             paths_games_lock_checkboxes,
+            paths_games_elevated_checkboxes,
+            paths_games_compatibility_comboboxes,
+            paths_games_launch_mode_comboboxes,
+            paths_games_skip_intro_videos_line_edits,
+            paths_games_config_path_line_edits,
+            paths_games_env_vars_line_edits,
 
             secondary_mods_folder_line_edit,
             secondary_mods_folder_button,
+            sync_folder_line_edit,
+            sync_folder_button,
+            save_backups_folder_line_edit,
+            save_backups_folder_button,
+            generated_files_folder_line_edit,
+            generated_files_folder_button,
 
             steam_api_key_line_edit,
             language_combobox,
@@ -286,9 +670,34 @@ impl SettingsUI {
             date_format_combobox,
             check_updates_on_start_checkbox,
             check_schema_updates_on_start_checkbox,
-            dark_mode_checkbox,
+            theme_combobox,
+            accent_color_button,
             open_workshop_link_in_steam_checkbox,
             check_logs_checkbox,
+            launch_countdown_checkbox,
+            tray_mode_checkbox,
+            post_launch_behavior_combobox,
+            mod_double_click_action_combobox,
+            startup_game_combobox,
+            show_generated_packs_checkbox,
+            apply_conflict_suggestions_checkbox,
+            check_script_collisions_checkbox,
+            check_other_managers_checkbox,
+            enable_sentry_checkbox,
+            recently_added_days_spinbox,
+            check_shadowed_packs_checkbox,
+            offline_mode_checkbox,
+            backup_saves_before_launch_checkbox,
+            enable_api_server_checkbox,
+            unit_multiplier_decimals_spinbox,
+            unit_multiplier_step_spinbox,
+            backup_saves_retention_spinbox,
+            backup_saves_count_spinbox,
+
+            shortcuts_key_sequence_edits,
+
+            log_level_combobox,
+            log_module_level_comboboxes,
 
             font_button,
             restore_default_button,
@@ -331,12 +740,48 @@ impl SettingsUI {
 
         self.tools_tableview().horizontal_header().resize_sections(ResizeMode::ResizeToContents);
 
+        // Hooks are also kept in a json, so they can be edited by hand.
+        let hooks = Hooks::load().unwrap_or_default();
+        self.hooks_model().clear();
+
+        self.hooks_model().set_column_count(7);
+        self.hooks_model().set_horizontal_header_item(0, QStandardItem::from_q_string(&qtr("hooks_column_name")).into_ptr());
+        self.hooks_model().set_horizontal_header_item(1, QStandardItem::from_q_string(&qtr("hooks_column_stage")).into_ptr());
+        self.hooks_model().set_horizontal_header_item(2, QStandardItem::from_q_string(&qtr("hooks_column_command")).into_ptr());
+        self.hooks_model().set_horizontal_header_item(3, QStandardItem::from_q_string(&qtr("hooks_column_games")).into_ptr());
+        self.hooks_model().set_horizontal_header_item(4, QStandardItem::from_q_string(&qtr("hooks_column_profiles")).into_ptr());
+        self.hooks_model().set_horizontal_header_item(5, QStandardItem::from_q_string(&qtr("hooks_column_timeout")).into_ptr());
+        self.hooks_model().set_horizontal_header_item(6, QStandardItem::from_q_string(&qtr("hooks_column_failure_policy")).into_ptr());
+        self.hooks_model().horizontal_header_item(2).set_tool_tip(&qtr("hooks_column_command_tt"));
+        self.hooks_model().horizontal_header_item(5).set_tool_tip(&qtr("hooks_column_timeout_tt"));
+
+        for hook in hooks.hooks() {
+            self.add_hook_row(hook);
+        }
+
+        self.hooks_tableview().horizontal_header().resize_sections(ResizeMode::ResizeToContents);
+
         let q_settings = settings();
         let secondary_mods_path = setting_string_from_q_setting(&q_settings, "secondary_mods_path");
         if !secondary_mods_path.is_empty() {
             self.secondary_mods_folder_line_edit().set_text(&QString::from_std_str(secondary_mods_path));
         }
 
+        let sync_folder_path = setting_string_from_q_setting(&q_settings, "sync_folder_path");
+        if !sync_folder_path.is_empty() {
+            self.sync_folder_line_edit().set_text(&QString::from_std_str(sync_folder_path));
+        }
+
+        let save_backups_path = setting_string_from_q_setting(&q_settings, "save_backups_path");
+        if !save_backups_path.is_empty() {
+            self.save_backups_folder_line_edit().set_text(&QString::from_std_str(save_backups_path));
+        }
+
+        let generated_files_path = setting_string_from_q_setting(&q_settings, "generated_files_path");
+        if !generated_files_path.is_empty() {
+            self.generated_files_folder_line_edit().set_text(&QString::from_std_str(generated_files_path));
+        }
+
         // Load the Game Paths, if they exists.
         for (key, path) in self.paths_games_line_edits.iter() {
             let stored_path = setting_string_from_q_setting(&q_settings, key);
@@ -362,6 +807,39 @@ impl SettingsUI {
             }
         }
 
+        // Load the launch options of each game.
+        for (key, checkbox) in self.paths_games_elevated_checkboxes.iter() {
+            checkbox.set_checked(setting_bool_from_q_setting(&q_settings, &format!("launch_elevated_{}", key)));
+        }
+
+        for (key, combobox) in self.paths_games_compatibility_comboboxes.iter() {
+            let mode = setting_string_from_q_setting(&q_settings, &format!("compatibility_mode_{}", key));
+            if let Some(index) = COMPATIBILITY_MODES.iter().position(|(name, _)| *name == mode) {
+                combobox.set_current_index(index as i32);
+            }
+        }
+
+        for (key, combobox) in self.paths_games_launch_mode_comboboxes.iter() {
+            let mode = setting_string_from_q_setting(&q_settings, &format!("launch_mode_{}", key));
+            for index in 0..combobox.count() {
+                if combobox.item_data_1a(index).to_string().to_std_string() == mode {
+                    combobox.set_current_index(index);
+                }
+            }
+        }
+
+        for (key, line_edit) in self.paths_games_skip_intro_videos_line_edits.iter() {
+            line_edit.set_text(&QString::from_std_str(setting_string_from_q_setting(&q_settings, &format!("skip_intro_videos_{}", key))));
+        }
+
+        for (key, line_edit) in self.paths_games_config_path_line_edits.iter() {
+            line_edit.set_text(&QString::from_std_str(setting_string_from_q_setting(&q_settings, &format!("config_path_override_{}", key))));
+        }
+
+        for (key, line_edit) in self.paths_games_env_vars_line_edits.iter() {
+            line_edit.set_text(&QString::from_std_str(setting_string_from_q_setting(&q_settings, &format!("launch_env_vars_{}", key))));
+        }
+
         // Get the default game.
         let default_game = setting_string_from_q_setting(&q_settings, "default_game");
         for (index, game) in SUPPORTED_GAMES.games_sorted().iter().enumerate() {
@@ -381,7 +859,7 @@ impl SettingsUI {
 
         let language_selected = setting_string("language");
         let language_selected_split = language_selected.split('_').collect::<Vec<&str>>()[0];
-        for (index, (language,_)) in Locale::get_available_locales()?.iter().enumerate() {
+        for (index, (language, _)) in available_locales().iter().enumerate() {
             if *language == language_selected_split {
                 self.language_combobox.set_current_index(index as i32);
                 break;
@@ -398,11 +876,72 @@ impl SettingsUI {
         *self.font_data.borrow_mut() = (setting_string_from_q_setting(&q_settings, "font_name"), setting_int_from_q_setting(&q_settings, "font_size"));
 
         self.steam_api_key_line_edit().set_text(&QString::from_std_str(setting_string_from_q_setting(&q_settings, "steam_api_key")));
-        self.dark_mode_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "dark_mode"));
+        let theme = setting_string_from_q_setting(&q_settings, "theme");
+        for index in 0..self.theme_combobox().count() {
+            if self.theme_combobox().item_data_1a(index).to_string().to_std_string() == theme {
+                self.theme_combobox().set_current_index(index);
+                break;
+            }
+        }
+
+        self.set_accent_color(&setting_string_from_q_setting(&q_settings, "theme_accent_color"));
         self.open_workshop_link_in_steam_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "open_workshop_link_in_steam"));
         self.check_updates_on_start_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "check_updates_on_start"));
         self.check_schema_updates_on_start_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "check_schema_updates_on_start"));
         self.check_logs_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "check_logs"));
+        self.launch_countdown_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "launch_countdown"));
+        self.tray_mode_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "tray_mode"));
+
+        let post_launch_behavior = setting_string_from_q_setting(&q_settings, "post_launch_behavior");
+        for index in 0..self.post_launch_behavior_combobox().count() {
+            if self.post_launch_behavior_combobox().item_data_1a(index).to_string().to_std_string() == post_launch_behavior {
+                self.post_launch_behavior_combobox().set_current_index(index);
+                break;
+            }
+        }
+
+        let mod_double_click_action = setting_string_from_q_setting(&q_settings, "mod_double_click_action");
+        for index in 0..self.mod_double_click_action_combobox().count() {
+            if self.mod_double_click_action_combobox().item_data_1a(index).to_string().to_std_string() == mod_double_click_action {
+                self.mod_double_click_action_combobox().set_current_index(index);
+                break;
+            }
+        }
+
+        let startup_game = setting_string_from_q_setting(&q_settings, "startup_game");
+        for index in 0..self.startup_game_combobox().count() {
+            if self.startup_game_combobox().item_data_1a(index).to_string().to_std_string() == startup_game {
+                self.startup_game_combobox().set_current_index(index);
+                break;
+            }
+        }
+        self.show_generated_packs_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "show_generated_packs"));
+        self.apply_conflict_suggestions_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "apply_conflict_suggestions"));
+        self.check_script_collisions_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "check_script_collisions"));
+        self.check_other_managers_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "check_other_managers"));
+        self.enable_sentry_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "enable_sentry"));
+        self.recently_added_days_spinbox().set_value(setting_int_from_q_setting(&q_settings, "recently_added_days"));
+        self.check_shadowed_packs_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "check_shadowed_packs"));
+        self.offline_mode_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "offline_mode"));
+        self.backup_saves_before_launch_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "backup_saves_before_launch"));
+        self.enable_api_server_checkbox().set_checked(setting_bool_from_q_setting(&q_settings, "enable_api_server"));
+        self.unit_multiplier_decimals_spinbox().set_value(setting_int_from_q_setting(&q_settings, "unit_multiplier_decimals"));
+        self.unit_multiplier_step_spinbox().set_value(unit_multiplier_step());
+        self.backup_saves_retention_spinbox().set_value(setting_int_from_q_setting(&q_settings, "backup_saves_retention"));
+        self.backup_saves_count_spinbox().set_value(setting_int_from_q_setting(&q_settings, "backup_saves_count"));
+
+        for (key, key_sequence_edit) in self.shortcuts_key_sequence_edits() {
+            let key_sequence = QKeySequence::from_q_string(&QString::from_std_str(setting_string_from_q_setting(&q_settings, key)));
+            key_sequence_edit.set_key_sequence(&key_sequence);
+        }
+
+        let log_level = QVariant::from_q_string(&QString::from_std_str(setting_string_from_q_setting(&q_settings, "log_level")));
+        self.log_level_combobox().set_current_index(self.log_level_combobox().find_data_1a(&log_level).max(0));
+
+        for (module, combobox) in self.log_module_level_comboboxes() {
+            let log_level = QVariant::from_q_string(&QString::from_std_str(setting_string_from_q_setting(&q_settings, &log_level_setting_key(module))));
+            combobox.set_current_index(combobox.find_data_1a(&log_level).max(0));
+        }
 
         Ok(())
     }
@@ -426,14 +965,70 @@ impl SettingsUI {
 
         tools.save(&None)?;
 
+        let mut hooks = Hooks::default();
+        for row in 0..self.hooks_model().row_count_0a() {
+            let stage_combobox: QPtr<QComboBox> = self.hooks_tableview().index_widget(&self.hooks_model().index_2a(row, 1)).static_downcast();
+            let failure_policy_combobox: QPtr<QComboBox> = self.hooks_tableview().index_widget(&self.hooks_model().index_2a(row, 6)).static_downcast();
+            let split = |column: i32| self.hooks_model().item_2a(row, column).text()
+                .to_std_string()
+                .split(',')
+                .map(|x| x.trim().to_string())
+                .filter(|x| !x.is_empty())
+                .collect::<Vec<String>>();
+
+            let mut hook = Hook::default();
+
+            *hook.name_mut() = self.hooks_model().item_2a(row, 0).text().to_std_string();
+            *hook.stage_mut() = HOOK_STAGES.get(stage_combobox.current_index() as usize).map(|(stage, _)| *stage).unwrap_or_default();
+            *hook.command_mut() = self.hooks_model().item_2a(row, 2).text().to_std_string();
+            *hook.games_mut() = split(3);
+            *hook.profiles_mut() = split(4);
+            *hook.timeout_mut() = self.hooks_model().item_2a(row, 5).data_1a(ItemDataRole::EditRole.to_int()).to_u_int_0a() as u64;
+            *hook.failure_policy_mut() = HOOK_FAILURE_POLICIES.get(failure_policy_combobox.current_index() as usize).map(|(policy, _)| *policy).unwrap_or_default();
+
+            // Hooks without command do nothing, so we don't keep them.
+            if !hook.command().trim().is_empty() {
+                hooks.hooks_mut().push(hook);
+            }
+        }
+
+        hooks.save()?;
+
         // For each entry, we check if it's a valid directory and save it into Settings.
         let q_settings = settings();
         set_setting_string_to_q_setting(&q_settings, "secondary_mods_path", &self.secondary_mods_folder_line_edit().text().to_std_string());
+        set_setting_string_to_q_setting(&q_settings, "sync_folder_path", &self.sync_folder_line_edit().text().to_std_string());
+        set_setting_string_to_q_setting(&q_settings, "save_backups_path", &self.save_backups_folder_line_edit().text().to_std_string());
+        set_setting_string_to_q_setting(&q_settings, "generated_files_path", &self.generated_files_folder_line_edit().text().to_std_string());
 
         for (key, line_edit) in self.paths_games_line_edits.iter() {
             set_setting_string_to_q_setting(&q_settings, key, &line_edit.text().to_std_string());
         }
 
+        for (key, checkbox) in self.paths_games_elevated_checkboxes.iter() {
+            set_setting_bool_to_q_setting(&q_settings, &format!("launch_elevated_{}", key), checkbox.is_checked());
+        }
+
+        for (key, combobox) in self.paths_games_compatibility_comboboxes.iter() {
+            set_setting_string_to_q_setting(&q_settings, &format!("compatibility_mode_{}", key), &combobox.current_text().to_std_string());
+        }
+
+        for (key, combobox) in self.paths_games_launch_mode_comboboxes.iter() {
+            set_setting_string_to_q_setting(&q_settings, &format!("launch_mode_{}", key), &combobox.current_data_0a().to_string().to_std_string());
+        }
+
+        for (key, line_edit) in self.paths_games_skip_intro_videos_line_edits.iter() {
+            set_setting_string_to_q_setting(&q_settings, &format!("skip_intro_videos_{}", key), &line_edit.text().to_std_string());
+        }
+
+        for (key, line_edit) in self.paths_games_config_path_line_edits.iter() {
+            set_setting_string_to_q_setting(&q_settings, &format!("config_path_override_{}", key), line_edit.text().to_std_string().trim());
+        }
+
+        for (key, line_edit) in self.paths_games_env_vars_line_edits.iter() {
+            set_setting_string_to_q_setting(&q_settings, &format!("launch_env_vars_{}", key), line_edit.text().to_std_string().trim());
+        }
+
         // We get his game's folder, depending on the selected game.
         let mut game = self.default_game_combobox.current_text().to_std_string();
         if let Some(index) = game.find('&') { game.remove(index); }
@@ -443,9 +1038,8 @@ impl SettingsUI {
         // We need to store the full locale filename, not just the visible name!
         let mut language = self.language_combobox.current_text().to_std_string();
         if let Some(index) = language.find('&') { language.remove(index); }
-        if let Some((_, locale)) = Locale::get_available_locales()?.iter().find(|(x, _)| &language == x) {
-            let file_name = format!("{}_{}", language, locale.language);
-            set_setting_string_to_q_setting(&q_settings, "language", &file_name);
+        if let Some((_, file_name)) = available_locales().iter().find(|(x, _)| &language == x) {
+            set_setting_string_to_q_setting(&q_settings, "language", file_name);
         }
 
         set_setting_string_to_q_setting(&q_settings, "font_name", &self.font_data.borrow().0);
@@ -454,11 +1048,40 @@ impl SettingsUI {
         set_setting_string_to_q_setting(&q_settings, "update_channel", &self.update_chanel_combobox.current_text().to_std_string());
         set_setting_string_to_q_setting(&q_settings, "date_format", &self.date_format_combobox.current_text().to_std_string());
         set_setting_string_to_q_setting(&q_settings, "steam_api_key", &self.steam_api_key_line_edit().text().to_std_string());
-        set_setting_bool_to_q_setting(&q_settings, "dark_mode", self.dark_mode_checkbox().is_checked());
+        set_setting_string_to_q_setting(&q_settings, "theme", &self.theme_combobox().current_data_0a().to_string().to_std_string());
+        set_setting_string_to_q_setting(&q_settings, "theme_accent_color", &self.accent_color.borrow());
         set_setting_bool_to_q_setting(&q_settings, "open_workshop_link_in_steam", self.open_workshop_link_in_steam_checkbox().is_checked());
         set_setting_bool_to_q_setting(&q_settings, "check_updates_on_start", self.check_updates_on_start_checkbox().is_checked());
         set_setting_bool_to_q_setting(&q_settings, "check_schema_updates_on_start", self.check_schema_updates_on_start_checkbox().is_checked());
         set_setting_bool_to_q_setting(&q_settings, "check_logs", self.check_logs_checkbox().is_checked());
+        set_setting_bool_to_q_setting(&q_settings, "launch_countdown", self.launch_countdown_checkbox().is_checked());
+        set_setting_bool_to_q_setting(&q_settings, "tray_mode", self.tray_mode_checkbox().is_checked());
+        set_setting_string_to_q_setting(&q_settings, "post_launch_behavior", &self.post_launch_behavior_combobox().current_data_0a().to_string().to_std_string());
+        set_setting_string_to_q_setting(&q_settings, "mod_double_click_action", &self.mod_double_click_action_combobox().current_data_0a().to_string().to_std_string());
+        set_setting_string_to_q_setting(&q_settings, "startup_game", &self.startup_game_combobox().current_data_0a().to_string().to_std_string());
+        set_setting_bool_to_q_setting(&q_settings, "show_generated_packs", self.show_generated_packs_checkbox().is_checked());
+        set_setting_bool_to_q_setting(&q_settings, "apply_conflict_suggestions", self.apply_conflict_suggestions_checkbox().is_checked());
+        set_setting_bool_to_q_setting(&q_settings, "check_script_collisions", self.check_script_collisions_checkbox().is_checked());
+        set_setting_bool_to_q_setting(&q_settings, "check_other_managers", self.check_other_managers_checkbox().is_checked());
+        set_setting_bool_to_q_setting(&q_settings, "enable_sentry", self.enable_sentry_checkbox().is_checked());
+        set_setting_int_to_q_setting(&q_settings, "recently_added_days", self.recently_added_days_spinbox().value());
+        set_setting_bool_to_q_setting(&q_settings, "check_shadowed_packs", self.check_shadowed_packs_checkbox().is_checked());
+        set_setting_bool_to_q_setting(&q_settings, "offline_mode", self.offline_mode_checkbox().is_checked());
+        set_setting_bool_to_q_setting(&q_settings, "backup_saves_before_launch", self.backup_saves_before_launch_checkbox().is_checked());
+        set_setting_bool_to_q_setting(&q_settings, "enable_api_server", self.enable_api_server_checkbox().is_checked());
+        set_setting_int_to_q_setting(&q_settings, "unit_multiplier_decimals", self.unit_multiplier_decimals_spinbox().value());
+        set_setting_string_to_q_setting(&q_settings, "unit_multiplier_step", &self.unit_multiplier_step_spinbox().value().to_string());
+        set_setting_int_to_q_setting(&q_settings, "backup_saves_retention", self.backup_saves_retention_spinbox().value());
+        set_setting_int_to_q_setting(&q_settings, "backup_saves_count", self.backup_saves_count_spinbox().value());
+
+        for (key, key_sequence_edit) in self.shortcuts_key_sequence_edits() {
+            set_setting_string_to_q_setting(&q_settings, key, &key_sequence_edit.key_sequence().to_string_0a().to_std_string());
+        }
+
+        set_setting_string_to_q_setting(&q_settings, "log_level", &self.log_level_combobox().current_data_0a().to_string().to_std_string());
+        for (module, combobox) in self.log_module_level_comboboxes() {
+            set_setting_string_to_q_setting(&q_settings, &log_level_setting_key(module), &combobox.current_data_0a().to_string().to_std_string());
+        }
 
         // Save the settings.
         q_settings.sync();
@@ -468,6 +1091,9 @@ impl SettingsUI {
 
     pub unsafe fn set_connections(&self, slots: &SettingsUISlots) {
         self.secondary_mods_folder_button().released().connect(slots.select_secondary_mods_path());
+        self.sync_folder_button().released().connect(slots.select_sync_folder_path());
+        self.save_backups_folder_button().released().connect(slots.select_save_backups_path());
+        self.generated_files_folder_button().released().connect(slots.select_generated_files_path());
         for (key, button) in self.paths_games_buttons.iter() {
             button.released().connect(&slots.select_game_paths()[key]);
         }
@@ -483,12 +1109,80 @@ impl SettingsUI {
         self.tools_add.triggered().connect(slots.tools_add());
         self.tools_remove.triggered().connect(slots.tools_remove());
 
+        self.hooks_tableview().custom_context_menu_requested().connect(slots.hooks_context_menu());
+        self.hooks_tableview().selection_model().selection_changed().connect(slots.hooks_enabler());
+        self.hooks_context_menu().about_to_show().connect(slots.hooks_enabler());
+
+        self.hooks_add.triggered().connect(slots.hooks_add());
+        self.hooks_remove.triggered().connect(slots.hooks_remove());
+
         self.font_button.released().connect(slots.font_settings());
+        self.theme_combobox().current_index_changed().connect(slots.preview_theme());
+        self.accent_color_button().released().connect(slots.select_accent_color());
         self.restore_default_button.released().connect(slots.restore_default());
         self.accept_button.released().connect(self.dialog.slot_accept());
         self.cancel_button.released().connect(self.dialog.slot_close());
     }
 
+    /// This function adds a row with the provided hook to the hooks table.
+    ///
+    /// Stage and failure policy are edited through comboboxes in the table, so they can only have valid values.
+    pub unsafe fn add_hook_row(&self, hook: &Hook) {
+        let row = QListOfQStandardItem::new();
+
+        let item_name = QStandardItem::new();
+        let item_stage = QStandardItem::new();
+        let item_command = QStandardItem::new();
+        let item_games = QStandardItem::new();
+        let item_profiles = QStandardItem::new();
+        let item_timeout = QStandardItem::new();
+        let item_failure_policy = QStandardItem::new();
+
+        item_name.set_text(&QString::from_std_str(hook.name()));
+        item_command.set_text(&QString::from_std_str(hook.command()));
+        item_games.set_text(&QString::from_std_str(hook.games().join(",")));
+        item_profiles.set_text(&QString::from_std_str(hook.profiles().join(",")));
+        item_timeout.set_data_2a(&QVariant::from_uint(*hook.timeout() as u32), ItemDataRole::EditRole.to_int());
+        item_stage.set_editable(false);
+        item_failure_policy.set_editable(false);
+
+        row.append_q_standard_item(&item_name.into_ptr().as_mut_raw_ptr());
+        row.append_q_standard_item(&item_stage.into_ptr().as_mut_raw_ptr());
+        row.append_q_standard_item(&item_command.into_ptr().as_mut_raw_ptr());
+        row.append_q_standard_item(&item_games.into_ptr().as_mut_raw_ptr());
+        row.append_q_standard_item(&item_profiles.into_ptr().as_mut_raw_ptr());
+        row.append_q_standard_item(&item_timeout.into_ptr().as_mut_raw_ptr());
+        row.append_q_standard_item(&item_failure_policy.into_ptr().as_mut_raw_ptr());
+
+        self.hooks_model().append_row_q_list_of_q_standard_item(row.into_ptr().as_ref().unwrap());
+        let row = self.hooks_model().row_count_0a() - 1;
+
+        let stage_combobox = QComboBox::new_1a(self.hooks_tableview());
+        for (_, key) in HOOK_STAGES {
+            stage_combobox.add_item_q_string(&qtr(key));
+        }
+        stage_combobox.set_current_index(HOOK_STAGES.iter().position(|(stage, _)| stage == hook.stage()).unwrap_or_default() as i32);
+        self.hooks_tableview().set_index_widget(&self.hooks_model().index_2a(row, 1), stage_combobox.into_ptr());
+
+        let failure_policy_combobox = QComboBox::new_1a(self.hooks_tableview());
+        for (_, key) in HOOK_FAILURE_POLICIES {
+            failure_policy_combobox.add_item_q_string(&qtr(key));
+        }
+        failure_policy_combobox.set_current_index(HOOK_FAILURE_POLICIES.iter().position(|(policy, _)| policy == hook.failure_policy()).unwrap_or_default() as i32);
+        self.hooks_tableview().set_index_widget(&self.hooks_model().index_2a(row, 6), failure_policy_combobox.into_ptr());
+    }
+
+    /// This function sets the accent color, updating the button used to pick it.
+    pub unsafe fn set_accent_color(&self, accent_color: &str) {
+        *self.accent_color.borrow_mut() = accent_color.to_owned();
+        self.accent_color_button().set_style_sheet(&QString::from_std_str(format!("background-color: {};", accent_color)));
+    }
+
+    /// This function applies the theme selected in the dialog, so the user can see it before saving it.
+    pub unsafe fn preview_theme(&self) {
+        apply_theme(&self.theme_combobox().current_data_0a().to_string().to_std_string(), &self.accent_color.borrow());
+    }
+
     unsafe fn update_entry_path(&self, game: &str) {
 
         // We check if we have a game or not. If we have it, update the `LineEdit` for that game.
@@ -533,7 +1227,22 @@ impl SettingsUI {
     }
 
     unsafe fn update_secondary_mods_path(&self) {
-        let line_edit = self.secondary_mods_folder_line_edit();
+        self.update_folder_path(self.secondary_mods_folder_line_edit());
+    }
+
+    unsafe fn update_sync_folder_path(&self) {
+        self.update_folder_path(self.sync_folder_line_edit());
+    }
+
+    unsafe fn update_save_backups_path(&self) {
+        self.update_folder_path(self.save_backups_folder_line_edit());
+    }
+
+    unsafe fn update_generated_files_path(&self) {
+        self.update_folder_path(self.generated_files_folder_line_edit());
+    }
+
+    unsafe fn update_folder_path(&self, line_edit: &QBox<QLineEdit>) {
 
         // Create the `FileDialog` and configure it.
         let title = qtr("settings_select_folder");
@@ -604,12 +1313,55 @@ pub unsafe fn init_settings(main_window: &QPtr<QMainWindow>) {
     set_setting_if_new_string(&q_settings, "steam_api_key", "");
     set_setting_if_new_string(&q_settings, "default_game", KEY_WARHAMMER_3);
     set_setting_if_new_string(&q_settings, "update_channel", "stable");
+    set_setting_if_new_string(&q_settings, "update_skipped_version", "");
     set_setting_if_new_string(&q_settings, "language", "English_en");
     set_setting_if_new_string(&q_settings, "date_format", SLASH_DMY_DATE_FORMAT_STR);
     set_setting_if_new_bool(&q_settings, "check_updates_on_start", true);
     set_setting_if_new_bool(&q_settings, "check_schema_updates_on_start", true);
-    set_setting_if_new_bool(&q_settings, "dark_mode", false);
+
+    // Old versions only had a dark mode toggle, so use it to pick the default theme.
+    let default_theme = if setting_bool_from_q_setting(&q_settings, "dark_mode") { THEME_DARK } else { THEME_SYSTEM };
+    set_setting_if_new_string(&q_settings, "theme", default_theme);
+    set_setting_if_new_string(&q_settings, "theme_accent_color", DEFAULT_ACCENT_COLOR);
     set_setting_if_new_bool(&q_settings, "check_logs", true);
+    set_setting_if_new_bool(&q_settings, "launch_countdown", false);
+    set_setting_if_new_bool(&q_settings, "tray_mode", false);
+    set_setting_if_new_string(&q_settings, "post_launch_behavior", POST_LAUNCH_KEEP_OPEN);
+    set_setting_if_new_string(&q_settings, "mod_double_click_action", MOD_DOUBLE_CLICK_TOGGLE);
+    set_setting_if_new_string(&q_settings, "startup_game", STARTUP_GAME_DEFAULT);
+    set_setting_if_new_string(&q_settings, "last_game_selected", KEY_WARHAMMER_3);
+    set_setting_if_new_string(&q_settings, "steam_user_id", "");
+    set_setting_if_new_bool(&q_settings, "show_generated_packs", false);
+    set_setting_if_new_bool(&q_settings, "apply_conflict_suggestions", false);
+    set_setting_if_new_bool(&q_settings, "check_script_collisions", true);
+    set_setting_if_new_bool(&q_settings, "check_other_managers", true);
+    set_setting_if_new_bool(&q_settings, "enable_sentry", false);
+    set_setting_if_new_int(&q_settings, "recently_added_days", 7);
+    set_setting_if_new_bool(&q_settings, "sentry_consent_asked", false);
+    set_setting_if_new_bool(&q_settings, "check_shadowed_packs", false);
+    set_setting_if_new_bool(&q_settings, "offline_mode", false);
+    set_setting_if_new_bool(&q_settings, "backup_saves_before_launch", false);
+    set_setting_if_new_bool(&q_settings, "enable_api_server", false);
+    set_setting_if_new_int(&q_settings, "unit_multiplier_decimals", 2);
+    set_setting_if_new_string(&q_settings, "unit_multiplier_step", "0.05");
+    set_setting_if_new_int(&q_settings, "backup_saves_retention", 10);
+    set_setting_if_new_int(&q_settings, "backup_saves_count", 3);
+    set_setting_if_new_bool(&q_settings, "show_vanilla_packs", false);
+    set_setting_if_new_string(&q_settings, "mod_list_grouping", "categories");
+    set_setting_if_new_bool(&q_settings, "show_ignored_mods", false);
+    set_setting_if_new_bool(&q_settings, "show_last_launched_column", true);
+    set_setting_if_new_string(&q_settings, "sync_folder_path", "");
+    set_setting_if_new_string(&q_settings, "save_backups_path", "");
+    set_setting_if_new_string(&q_settings, "generated_files_path", "");
+
+    for (key, default) in SHORTCUTS {
+        set_setting_if_new_string(&q_settings, key, default);
+    }
+
+    set_setting_if_new_string(&q_settings, "log_level", "info");
+    for module in LOG_MODULES {
+        set_setting_if_new_string(&q_settings, &log_level_setting_key(module), LOG_LEVEL_DEFAULT);
+    }
 
     for game in &SUPPORTED_GAMES.games_sorted() {
         if game.key() != KEY_ARENA {
@@ -618,8 +1370,15 @@ pub unsafe fn init_settings(main_window: &QPtr<QMainWindow>) {
             set_setting_if_new_bool(&q_settings, &format!("remove_trait_limit_{}", game.key()), false);
             set_setting_if_new_bool(&q_settings, &format!("merge_all_mods_{}", game.key()), false);
             set_setting_if_new_string(&q_settings, &format!("enable_translations_{}", game.key()), "--");
-            set_setting_if_new_f32(&q_settings, &format!("unit_multiplier_{}", game.key()), 1.0);
+            set_setting_if_new_string(&q_settings, &format!("unit_multiplier_{}", game.key()), "1");
             set_setting_if_new_string(&q_settings, &format!("universal_rebalancer_{}", game.key()), "--");
+            set_setting_if_new_bool(&q_settings, &format!("launch_elevated_{}", game.key()), false);
+            set_setting_if_new_string(&q_settings, &format!("compatibility_mode_{}", game.key()), COMPATIBILITY_MODES[0].0);
+            set_setting_if_new_string(&q_settings, &format!("launch_mode_{}", game.key()), LAUNCH_MODE_DIRECT);
+            set_setting_if_new_string(&q_settings, &format!("skip_intro_videos_{}", game.key()), "");
+            set_setting_if_new_string(&q_settings, &format!("config_path_override_{}", game.key()), "");
+            set_setting_if_new_string(&q_settings, &format!("launch_env_vars_{}", game.key()), "");
+            set_setting_if_new_string(&q_settings, &format!("pack_list_hidden_columns_{}", game.key()), DEFAULT_HIDDEN_COLUMNS);
 
             let game_path = if let Ok(Some(game_path)) = game.find_game_install_location() {
                 game_path.to_string_lossy().to_string()
@@ -656,9 +1415,11 @@ pub fn init_config_path() -> Result<()> {
 
     // Within the config path we need to create a folder to store the temp packs of each game.
     // Otherwise they interfere with each other due to being movie packs.
+    //
+    // Folders configured by the user are created when used instead, as a missing drive must not stop Runcher from starting.
     for game in SUPPORTED_GAMES.games_sorted().iter() {
         if game.key() != KEY_ARENA {
-            DirBuilder::new().recursive(true).create(config_path()?.join("temp_packs").join(game.key()))?;
+            DirBuilder::new().recursive(true).create(config_path()?.join(TEMP_PACKS_FOLDER).join(game.key()))?;
         }
     }
 
@@ -666,7 +1427,67 @@ pub fn init_config_path() -> Result<()> {
 }
 
 pub fn temp_packs_folder(game: &GameInfo) -> Result<PathBuf> {
-    Ok(config_path()?.join("temp_packs").join(game.key()))
+    Ok(generated_files_path()?.join(TEMP_PACKS_FOLDER).join(game.key()))
+}
+
+/// This function returns the folder where the masks of the provided game are written, if the user has configured a folder for generated files.
+///
+/// Without it, masks are kept in the secondary folder.
+pub fn generated_masks_folder(game: &GameInfo) -> Option<PathBuf> {
+    configured_generated_files_path().map(|path| path.join(MASKS_FOLDER).join(game.key()))
+}
+
+/// This function returns the folder where the packs Runcher generates are written.
+///
+/// If the user has configured one and it exists, that's the path used. Otherwise, it's the config path.
+pub fn generated_files_path() -> Result<PathBuf> {
+    match configured_generated_files_path() {
+        Some(path) => Ok(path),
+        None => config_path(),
+    }
+}
+
+/// This function returns the folder for generated files configured by the user, if there is one and it exists.
+///
+/// If it doesn't exist, usually because it's on a drive not connected, a warning is logged and None is returned, so we fall back to the default folders.
+fn configured_generated_files_path() -> Option<PathBuf> {
+    let generated_files_path = setting_path("generated_files_path");
+    if generated_files_path.as_os_str().is_empty() {
+        None
+    } else if generated_files_path.is_dir() {
+        Some(generated_files_path)
+    } else {
+        warn!("The folder for generated files {} doesn't exist. Using the default folders instead.", generated_files_path.to_string_lossy());
+        None
+    }
+}
+
+/// This function returns the unit multiplier of the provided game.
+///
+/// It's stored as text, always with a dot as decimal separator, so it reads the same no matter the locale of the system.
+/// Values from before it was stored as text are read the same way.
+pub fn unit_multiplier(game_key: &str) -> f64 {
+    setting_string(&format!("unit_multiplier_{}", game_key))
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| *value > 0.0)
+        .unwrap_or(1.0)
+}
+
+/// This function stores the unit multiplier of the provided game. See [unit_multiplier] for the format.
+pub fn set_unit_multiplier(game_key: &str, value: f64) {
+    set_setting_string(&format!("unit_multiplier_{}", game_key), &value.to_string());
+}
+
+/// This function returns how much the unit multiplier changes with each step of its spinbox.
+pub fn unit_multiplier_step() -> f64 {
+    setting_string("unit_multiplier_step")
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| *value > 0.0)
+        .unwrap_or(0.05)
 }
 
 pub fn schemas_path() -> Result<PathBuf> {
@@ -674,11 +1495,112 @@ pub fn schemas_path() -> Result<PathBuf> {
 }
 
 pub fn game_config_path() -> Result<PathBuf> {
-    Ok(config_path()?.join("game_config"))
+    Ok(synced_config_path()?.join(GAME_CONFIG_FOLDER))
 }
 
 pub fn profiles_path() -> Result<PathBuf> {
-    Ok(config_path()?.join("profiles"))
+    Ok(synced_config_path()?.join(PROFILES_FOLDER))
+}
+
+/// This function returns the path where the config files that can be synced between computers (game configs and profiles) are stored.
+///
+/// If the user has configured a sync folder and it exists, that's the path used. Otherwise, it's the normal config path.
+pub fn synced_config_path() -> Result<PathBuf> {
+    let sync_folder_path = setting_path("sync_folder_path");
+    if !sync_folder_path.as_os_str().is_empty() && sync_folder_path.is_dir() {
+        Ok(sync_folder_path)
+    } else {
+        config_path()
+    }
+}
+
+/// This function returns the folder where the saves are backed up before launching.
+///
+/// If the user has configured one, that's the path used. Otherwise, it's a folder in the config path.
+pub fn save_backups_path() -> Result<PathBuf> {
+    let save_backups_path = setting_path("save_backups_path");
+    if !save_backups_path.as_os_str().is_empty() {
+        Ok(save_backups_path)
+    } else {
+        config_path().map(|path| path.join(SAVE_BACKUPS_FOLDER))
+    }
+}
+
+/// This function returns the folder where the provided game keeps its config, scripts and saves.
+///
+/// If the user has overridden it for the game, like when the roaming folder is redirected to OneDrive or symlinked somewhere else, that's the path used.
+/// Otherwise, it's the one the game uses by default.
+pub fn game_appdata_path(game: &GameInfo, game_path: &Path) -> Option<PathBuf> {
+    let path_override = setting_path(&format!("config_path_override_{}", game.key()));
+    if !path_override.as_os_str().is_empty() {
+        Some(path_override)
+    } else {
+        game.config_path(game_path)
+    }
+}
+
+/// This function copies the game configs and profiles from one config folder to another, when moving them to/from a sync folder.
+///
+/// Files already in the destination folder are not overwritten, as they're probably synced from another computer.
+pub fn copy_synced_config(source: &Path, destination: &Path) -> Result<()> {
+    for folder in [GAME_CONFIG_FOLDER, PROFILES_FOLDER] {
+        let source_folder = source.join(folder);
+        let destination_folder = destination.join(folder);
+        DirBuilder::new().recursive(true).create(&destination_folder)?;
+
+        if source_folder.is_dir() {
+            for file in files_from_subdir(&source_folder, false)? {
+                if let Some(file_name) = file.file_name() {
+                    let destination_file = destination_folder.join(file_name);
+                    if !destination_file.exists() {
+                        std::fs::copy(&file, destination_file)?;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// This function returns the folders where the generated files of each game are written, with the current settings.
+///
+/// The list always has the same length and order, so the lists from before and after changing the settings can be zipped together.
+pub fn generated_files_folders() -> Vec<Option<PathBuf>> {
+    SUPPORTED_GAMES.games_sorted()
+        .iter()
+        .filter(|game| game.key() != KEY_ARENA)
+        .flat_map(|game| [temp_packs_folder(game).ok(), masks_path(game).ok()])
+        .collect()
+}
+
+/// This function moves the generated files from their old folders to their new ones, after changing where they're written.
+///
+/// Files already in the destination folders are overwritten, as they're regenerated on each launch anyway.
+pub fn move_generated_files(source: &[Option<PathBuf>], destination: &[Option<PathBuf>]) -> Result<()> {
+    for (source_folder, destination_folder) in source.iter().zip(destination.iter()) {
+        if let (Some(source_folder), Some(destination_folder)) = (source_folder, destination_folder) {
+            if source_folder == destination_folder || !source_folder.is_dir() {
+                continue;
+            }
+
+            DirBuilder::new().recursive(true).create(destination_folder)?;
+
+            for file in files_from_subdir(source_folder, false)? {
+                if let Some(file_name) = file.file_name() {
+                    let destination_file = destination_folder.join(file_name);
+
+                    // Renaming fails across drives, so in that case we copy the file and remove the original.
+                    if std::fs::rename(&file, &destination_file).is_err() {
+                        std::fs::copy(&file, &destination_file)?;
+                        std::fs::remove_file(&file)?;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
 }
 
 pub fn rpfm_config_path() -> Result<PathBuf> {
@@ -701,7 +1623,7 @@ pub fn translations_remote_path() -> Result<PathBuf> {
 }
 
 pub fn last_game_update_date(game: &GameInfo, game_path: &Path) -> Result<u64> {
-    Ok(if let Some(exe_path) = game.executable_path(game_path) {
+    Ok(if let Some(exe_path) = executable_path(game, game_path) {
         if let Ok(exe) = File::open(exe_path) {
             if cfg!(target_os = "windows") {
                 exe.metadata()?.created()?.duration_since(UNIX_EPOCH)?.as_secs()
@@ -9,18 +9,22 @@
 //---------------------------------------------------------------------------//
 
 use qt_widgets::QApplication;
+use qt_widgets::QColorDialog;
 use qt_widgets::QFontDialog;
 use qt_widgets::QMainWindow;
 use qt_widgets::SlotOfQPoint;
 
+use qt_gui::QColor;
 use qt_gui::QCursor;
 use qt_gui::QListOfQStandardItem;
 use qt_gui::QStandardItem;
 
 use qt_core::QBox;
 use qt_core::QPtr;
+use qt_core::QString;
 use qt_core::SlotNoArgs;
 use qt_core::SlotOfBool;
+use qt_core::SlotOfInt;
 
 use getset::*;
 
@@ -29,9 +33,11 @@ use std::path::PathBuf;
 use std::rc::Rc;
 
 use rpfm_ui_common::clone;
+use rpfm_ui_common::locale::qtr;
 use rpfm_ui_common::settings::*;
 use rpfm_ui_common::utils::show_dialog;
 
+use crate::mod_manager::hooks::Hook;
 use crate::settings_ui::init_settings;
 use crate::settings_ui::SettingsUI;
 
@@ -47,11 +53,21 @@ pub struct SettingsUISlots {
     tools_add: QBox<SlotNoArgs>,
     tools_remove: QBox<SlotNoArgs>,
 
+    hooks_context_menu: QBox<SlotOfQPoint>,
+    hooks_enabler: QBox<SlotNoArgs>,
+    hooks_add: QBox<SlotNoArgs>,
+    hooks_remove: QBox<SlotNoArgs>,
+
     font_settings: QBox<SlotNoArgs>,
+    preview_theme: QBox<SlotOfInt>,
+    select_accent_color: QBox<SlotNoArgs>,
     restore_default: QBox<SlotNoArgs>,
     select_game_paths: BTreeMap<String, QBox<SlotNoArgs>>,
     select_game_lock: BTreeMap<String, QBox<SlotOfBool>>,
     select_secondary_mods_path: QBox<SlotNoArgs>,
+    select_sync_folder_path: QBox<SlotNoArgs>,
+    select_save_backups_path: QBox<SlotNoArgs>,
+    select_generated_files_path: QBox<SlotNoArgs>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -111,6 +127,46 @@ impl SettingsUISlots {
             }
         }));
 
+        let hooks_context_menu = SlotOfQPoint::new(ui.dialog(), clone!(
+            ui => move |_| {
+            ui.hooks_context_menu().exec_1a_mut(&QCursor::pos_0a());
+        }));
+
+        let hooks_enabler = SlotNoArgs::new(ui.dialog(), clone!(
+            ui => move || {
+            let selection = ui.hooks_tableview.selection_model().selection();
+
+            ui.hooks_remove.set_enabled(selection.count_0a() > 0);
+        }));
+
+        let hooks_add = SlotNoArgs::new(ui.dialog(), clone!(
+            ui => move || {
+            ui.add_hook_row(&Hook::default());
+        }));
+
+        let hooks_remove = SlotNoArgs::new(ui.dialog(), clone!(
+            ui => move || {
+            let selection = ui.hooks_tableview.selection_model().selection();
+            let mut sel_sort = (0..selection.count_0a())
+                .flat_map(|index| {
+                    let sel_range = selection.value_1a(index);
+                    let indexes = sel_range.indexes();
+                    let mut rows = indexes.iter().map(|index| index.row()).collect::<Vec<_>>();
+                    rows.sort();
+                    rows.dedup();
+                    rows
+                })
+                .collect::<Vec<_>>();
+
+            sel_sort.sort();
+            sel_sort.dedup();
+            sel_sort.reverse();
+
+            for row in &sel_sort {
+                ui.hooks_model().remove_row_1a(*row);
+            }
+        }));
+
         let font_settings = SlotNoArgs::new(&ui.dialog, clone!(mut ui => move || {
             let font_changed: *mut bool = &mut false;
             let current_font = QApplication::font();
@@ -120,6 +176,21 @@ impl SettingsUISlots {
             }
         }));
 
+        let preview_theme = SlotOfInt::new(&ui.dialog, clone!(
+            ui => move |_| {
+            ui.preview_theme();
+        }));
+
+        let select_accent_color = SlotNoArgs::new(&ui.dialog, clone!(
+            ui => move || {
+            let current_color = QColor::from_q_string(&QString::from_std_str(&*ui.accent_color.borrow()));
+            let new_color = QColorDialog::get_color_3a(&current_color, &ui.dialog, &qtr("accent_color_tt"));
+            if new_color.is_valid() {
+                ui.set_accent_color(&new_color.name_0a().to_std_string());
+                ui.preview_theme();
+            }
+        }));
+
         let restore_default = SlotNoArgs::new(&ui.dialog, clone!(
             ui => move || {
 
@@ -201,17 +272,42 @@ impl SettingsUISlots {
             ui.update_secondary_mods_path();
         }));
 
+        let select_sync_folder_path = SlotNoArgs::new(&ui.dialog, clone!(
+            ui => move || {
+            ui.update_sync_folder_path();
+        }));
+
+        let select_save_backups_path = SlotNoArgs::new(&ui.dialog, clone!(
+            ui => move || {
+            ui.update_save_backups_path();
+        }));
+
+        let select_generated_files_path = SlotNoArgs::new(&ui.dialog, clone!(
+            ui => move || {
+            ui.update_generated_files_path();
+        }));
+
         Self {
             tools_context_menu,
             tools_enabler,
             tools_add,
             tools_remove,
 
+            hooks_context_menu,
+            hooks_enabler,
+            hooks_add,
+            hooks_remove,
+
             font_settings,
+            preview_theme,
+            select_accent_color,
             restore_default,
             select_game_paths,
             select_game_lock,
             select_secondary_mods_path,
+            select_sync_folder_path,
+            select_save_backups_path,
+            select_generated_files_path,
         }
     }
 }
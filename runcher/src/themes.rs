@@ -0,0 +1,232 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the theme engine.
+//!
+//! Themes are either the built-in light and dark ones, one following the OS theme, or user-provided QSS files in the themes folder.
+//! User themes can use the `{assets_path}` and `{accent_color}` placeholders, which are replaced when loading them.
+
+use qt_widgets::QApplication;
+
+use qt_gui::QColor;
+use qt_gui::QPalette;
+use qt_gui::q_palette::{ColorGroup, ColorRole};
+
+use qt_core::q_settings::Format;
+use qt_core::QCoreApplication;
+use qt_core::QSettings;
+use qt_core::QString;
+use qt_core::QVariant;
+
+use anyhow::Result;
+
+use std::fs::{DirBuilder, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::thread;
+
+use rpfm_ui_common::ASSETS_PATH;
+use rpfm_ui_common::settings::*;
+use rpfm_ui_common::utils::*;
+
+use crate::{DARK_PALETTE, LIGHT_PALETTE, LIGHT_STYLE_SHEET};
+
+pub const THEME_SYSTEM: &str = "system";
+pub const THEME_LIGHT: &str = "light";
+pub const THEME_DARK: &str = "dark";
+
+/// Accent color of the built-in dark theme.
+pub const DEFAULT_ACCENT_COLOR: &str = "#E67E22";
+
+const THEMES_FOLDER: &str = "themes";
+const THEME_EXTENSION: &str = "qss";
+
+const WINDOWS_THEME_KEY: &str = "HKEY_CURRENT_USER\\Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize";
+const WINDOWS_THEME_VALUE: &str = "AppsUseLightTheme";
+
+const PORTAL_COLOR_SCHEME_UNKNOWN: u8 = 0;
+const PORTAL_COLOR_SCHEME_LIGHT: u8 = 1;
+const PORTAL_COLOR_SCHEME_DARK: u8 = 2;
+
+/// If the last theme applied was a dark one. Used to detect changes in the OS theme.
+static DARK_THEME_APPLIED: AtomicBool = AtomicBool::new(false);
+
+/// Last color scheme read from the XDG Desktop Portal.
+static PORTAL_COLOR_SCHEME: AtomicU8 = AtomicU8::new(PORTAL_COLOR_SCHEME_UNKNOWN);
+
+/// If there's a thread reading the color scheme from the XDG Desktop Portal.
+static PORTAL_PROBE_RUNNING: AtomicBool = AtomicBool::new(false);
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+/// This function returns the path of the folder where user themes are stored, creating it if it doesn't exist.
+pub fn themes_path() -> Result<PathBuf> {
+    let path = config_path()?.join(THEMES_FOLDER);
+    DirBuilder::new().recursive(true).create(&path)?;
+    Ok(path)
+}
+
+/// This function returns the file names of the user themes in the themes folder.
+pub fn user_themes() -> Vec<String> {
+    let mut themes = themes_path().ok()
+        .and_then(|path| std::fs::read_dir(path).ok())
+        .map(|entries| entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file() && path.extension().map(|extension| extension == THEME_EXTENSION).unwrap_or(false))
+            .filter_map(|path| path.file_name().map(|name| name.to_string_lossy().to_string()))
+            .collect::<Vec<_>>()
+        )
+        .unwrap_or_default();
+
+    themes.sort();
+    themes
+}
+
+/// This function returns if the OS is using a dark theme.
+///
+/// On Windows this is read from the registry. On Linux, from the XDG Desktop Portal. If we cannot find out, we assume it's light.
+///
+/// Calling the portal may take a while, so on Linux it's only called here the first time. After that,
+/// this returns the color scheme read by the last [refresh_system_theme] call.
+pub unsafe fn system_uses_dark_theme() -> bool {
+    if cfg!(target_os = "windows") {
+        let q_settings = QSettings::from_q_string_format(&QString::from_std_str(WINDOWS_THEME_KEY), Format::NativeFormat);
+        q_settings.value_2a(&QString::from_std_str(WINDOWS_THEME_VALUE), &QVariant::from_int(1)).to_int_0a() == 0
+    } else if cfg!(target_os = "linux") {
+        match PORTAL_COLOR_SCHEME.load(Ordering::SeqCst) {
+            PORTAL_COLOR_SCHEME_UNKNOWN => {
+                let dark = portal_prefers_dark();
+                store_portal_color_scheme(dark);
+                dark
+            }
+            color_scheme => color_scheme == PORTAL_COLOR_SCHEME_DARK,
+        }
+    } else {
+        false
+    }
+}
+
+/// This function reads again the color scheme of the OS in a background thread, so checking for changes in it doesn't block the UI.
+///
+/// Only Linux needs this. If the previous read is still running, this does nothing.
+pub fn refresh_system_theme() {
+    if cfg!(target_os = "linux") && !PORTAL_PROBE_RUNNING.swap(true, Ordering::SeqCst) {
+        thread::spawn(|| {
+            store_portal_color_scheme(portal_prefers_dark());
+            PORTAL_PROBE_RUNNING.store(false, Ordering::SeqCst);
+        });
+    }
+}
+
+/// This function asks the XDG Desktop Portal if the user prefers dark themes.
+///
+/// The portal returns 1 for "prefer dark", 2 for "prefer light" and 0 for "no preference".
+fn portal_prefers_dark() -> bool {
+    Command::new("gdbus")
+        .args(["call", "--session", "--timeout", "5", "--dest", "org.freedesktop.portal.Desktop", "--object-path", "/org/freedesktop/portal/desktop", "--method", "org.freedesktop.portal.Settings.Read", "org.freedesktop.appearance", "color-scheme"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).contains("uint32 1"))
+        .unwrap_or(false)
+}
+
+fn store_portal_color_scheme(dark: bool) {
+    PORTAL_COLOR_SCHEME.store(if dark { PORTAL_COLOR_SCHEME_DARK } else { PORTAL_COLOR_SCHEME_LIGHT }, Ordering::SeqCst);
+}
+
+/// This function returns if the provided theme is a dark one. User themes follow the OS theme.
+pub unsafe fn theme_is_dark(theme: &str) -> bool {
+    match theme {
+        THEME_LIGHT => false,
+        THEME_DARK => true,
+        _ => system_uses_dark_theme(),
+    }
+}
+
+/// This function returns if the OS theme changed since the last time we applied a theme following it.
+pub unsafe fn system_theme_changed() -> bool {
+    let theme = setting_string("theme");
+    theme != THEME_LIGHT && theme != THEME_DARK && system_uses_dark_theme() != DARK_THEME_APPLIED.load(Ordering::SeqCst)
+}
+
+/// This function applies the provided theme with the provided accent color, returning if the applied theme is a dark one.
+///
+/// Invalid accent colors fallback to the default one, and user themes that fail to load fallback to the system theme.
+pub unsafe fn apply_theme(theme: &str, accent_color: &str) -> bool {
+    let app = QCoreApplication::instance();
+    let qapp = app.static_downcast::<QApplication>();
+
+    let accent = QColor::from_q_string(&QString::from_std_str(accent_color));
+    let accent_color = if accent.is_valid() { accent_color.to_owned() } else { DEFAULT_ACCENT_COLOR.to_owned() };
+    let accent = QColor::from_q_string(&QString::from_std_str(&accent_color));
+
+    let user_style_sheet = match theme {
+        THEME_SYSTEM | THEME_LIGHT | THEME_DARK => None,
+        _ => themes_path().ok().and_then(|path| load_style_sheet(&path.join(theme), &accent_color).ok()),
+    };
+
+    let use_dark_theme = theme_is_dark(theme);
+
+    // Initialize the globals before applying anything.
+    let light_style_sheet = ref_from_atomic(&*LIGHT_STYLE_SHEET);
+    let palette = if use_dark_theme {
+        QPalette::new_copy(ref_from_atomic(&*DARK_PALETTE))
+    } else {
+        QPalette::new_copy(ref_from_atomic(&*LIGHT_PALETTE))
+    };
+
+    for group in [ColorGroup::Active, ColorGroup::Inactive] {
+        palette.set_color_3a(group, ColorRole::Highlight, &accent);
+        palette.set_color_3a(group, ColorRole::Link, &accent);
+    }
+
+    // On Windows, the native style ignores the palette, so we need fusion for dark themes.
+    if cfg!(target_os = "windows") {
+        let style = if use_dark_theme { "fusion" } else { "windowsvista" };
+        QApplication::set_style_q_string(&QString::from_std_str(style));
+    }
+
+    QApplication::set_palette_1a(&palette);
+
+    match user_style_sheet {
+        Some(style_sheet) => qapp.set_style_sheet(&QString::from_std_str(style_sheet)),
+        None => if use_dark_theme {
+            if let Ok(dark_style_sheet) = dark_style_sheet(&accent_color) {
+                qapp.set_style_sheet(&QString::from_std_str(dark_style_sheet));
+            }
+        } else {
+            qapp.set_style_sheet(light_style_sheet);
+        }
+    }
+
+    DARK_THEME_APPLIED.store(use_dark_theme, Ordering::SeqCst);
+    use_dark_theme
+}
+
+/// This function returns the stylesheet used for the built-in dark theme, with the provided accent color.
+fn dark_style_sheet(accent_color: &str) -> Result<String> {
+    Ok(load_style_sheet(&ASSETS_PATH.join("dark-theme.qss"), accent_color)?.replace(DEFAULT_ACCENT_COLOR, accent_color))
+}
+
+/// This function loads a stylesheet from disk, replacing its placeholders.
+fn load_style_sheet(path: &Path, accent_color: &str) -> Result<String> {
+    let mut file = File::open(path)?;
+    let mut string = String::new();
+    file.read_to_string(&mut string)?;
+    Ok(string
+        .replace("{assets_path}", &ASSETS_PATH.to_string_lossy().replace('\\', "/"))
+        .replace("{accent_color}", accent_color))
+}
@@ -0,0 +1,79 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/runcher.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/runcher/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module containing the tray icon, used to keep Runcher out of the way while a game is running.
+//!
+//! In tray mode the icon is always shown, so Runcher can stay resident to launch games and report Workshop changes without its window open.
+
+use qt_widgets::QAction;
+use qt_widgets::QMainWindow;
+use qt_widgets::QMenu;
+use qt_widgets::QSystemTrayIcon;
+
+use qt_gui::QIcon;
+
+use qt_core::QBox;
+use qt_core::QPtr;
+use qt_core::QString;
+
+use getset::*;
+
+use std::rc::Rc;
+
+use rpfm_ui_common::ASSETS_PATH;
+use rpfm_ui_common::locale::qtr;
+
+//-------------------------------------------------------------------------------//
+//                              Enums & Structs
+//-------------------------------------------------------------------------------//
+
+#[derive(Debug, Getters)]
+#[getset(get = "pub")]
+pub struct TrayUI {
+    tray_icon: QBox<QSystemTrayIcon>,
+    menu: QBox<QMenu>,
+    launch_menu: QPtr<QMenu>,
+    restore: QPtr<QAction>,
+    quit: QPtr<QAction>,
+}
+
+//-------------------------------------------------------------------------------//
+//                             Implementations
+//-------------------------------------------------------------------------------//
+
+impl TrayUI {
+
+    /// This function creates the tray icon. Unless tray mode is enabled, it's hidden until the main window is minimized to the tray.
+    pub unsafe fn new(main_window: &QBox<QMainWindow>) -> Rc<Self> {
+        let icon = QIcon::from_q_string(&QString::from_std_str(format!("{}/icons/runcher.png", ASSETS_PATH.to_string_lossy())));
+        let tray_icon = QSystemTrayIcon::from_q_icon_q_object(&icon, main_window);
+        tray_icon.set_tool_tip(&QString::from_std_str("The Runcher"));
+
+        let menu = QMenu::from_q_widget(main_window);
+        let launch_menu = menu.add_menu_q_string(&qtr("tray_launch"));
+        menu.add_separator();
+        let restore = menu.add_action_q_string(&qtr("tray_restore"));
+        let quit = menu.add_action_q_string(&qtr("tray_quit"));
+        tray_icon.set_context_menu(&menu);
+
+        Rc::new(Self {
+            tray_icon,
+            menu,
+            launch_menu,
+            restore,
+            quit,
+        })
+    }
+
+    /// This function returns if the system has a tray we can put the icon in.
+    pub unsafe fn is_available() -> bool {
+        QSystemTrayIcon::is_system_tray_available()
+    }
+}
@@ -13,11 +13,16 @@ Module with all the code to interact with Updates and the Main Program Update Ch
 !*/
 
 use qt_widgets::q_dialog_button_box::StandardButton;
+use qt_widgets::QApplication;
+use qt_widgets::QComboBox;
 use qt_widgets::QDialog;
 use qt_widgets::{QWidget, QPushButton, QDialogButtonBox, QLabel, QGroupBox};
 
 use qt_core::QBox;
 use qt_core::QPtr;
+use qt_core::QString;
+use qt_core::QVariant;
+use qt_core::SlotNoArgs;
 
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
@@ -26,8 +31,12 @@ use rpfm_lib::integrations::git::GitResponse;
 use self_update::{backends::github::ReleaseList, Download, get_target, cargo_crate_version, Move, update::Release};
 use tempfile::Builder;
 
+use std::cell::RefCell;
 use std::env::current_exe;
 use std::fs::{DirBuilder, File};
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command as SystemCommand, exit};
 use std::rc::Rc;
 
 use rpfm_lib::utils::files_from_subdir;
@@ -40,6 +49,7 @@ use rpfm_ui_common::utils::*;
 use crate::AppUI;
 use crate::CENTRAL_COMMAND;
 use crate::communications::*;
+use crate::mod_manager::offline::offline_mode;
 use crate::updater_ui::slots::UpdaterUISlots;
 
 const UPDATE_EXTENSION: &str = "zip";
@@ -50,6 +60,10 @@ const UPDATE_FOLDER_PREFIX: &str = "updates";
 
 const CHANGELOG_FILE: &str = "CHANGELOG.txt";
 
+/// Folder, next to the executable, where the files replaced by the last update are kept, so they can be restored if the update breaks something.
+const UPDATE_BACKUP_FOLDER: &str = "update_backup";
+const UPDATE_BACKUP_VERSION_FILE: &str = "version.txt";
+
 pub const STABLE: &str = "Stable";
 pub const BETA: &str = "Beta";
 
@@ -66,10 +80,19 @@ mod slots;
 #[getset(get = "pub")]
 pub struct UpdaterUI {
     main_widget: QBox<QWidget>,
+    info_label: QPtr<QLabel>,
     update_schemas_button: QPtr<QPushButton>,
     update_program_button: QPtr<QPushButton>,
+    skip_version_button: QPtr<QPushButton>,
+    channel_combobox: QPtr<QComboBox>,
+    releases_combobox: QPtr<QComboBox>,
+    install_release_button: QPtr<QPushButton>,
+    restore_backup_button: QPtr<QPushButton>,
     accept_button: QPtr<QPushButton>,
     cancel_button: QPtr<QPushButton>,
+
+    /// Version of the last update found, used to skip it.
+    available_version: RefCell<String>,
 }
 
 /// This enum controls the channels through where RPFM will try to update.
@@ -109,16 +132,20 @@ impl UpdaterUI {
     pub unsafe fn new_with_precheck(app_ui: &Rc<AppUI>) -> Result<()> {
         let mut update_available = false;
 
-        let updates_for_program = if setting_bool("check_updates_on_start") {
+        // Offline, these checks would just fail after a timeout.
+        let offline = offline_mode().is_some();
+        let updates_for_program = if setting_bool("check_updates_on_start") && !offline {
             let receiver = CENTRAL_COMMAND.send_network(Command::CheckUpdates);
             let response = CENTRAL_COMMAND.recv_try(&receiver);
             match response {
                 Response::APIResponse(response) => {
                     match response {
-                        APIResponse::NewStableUpdate(_) |
-                        APIResponse::NewBetaUpdate(_) |
-                        APIResponse::NewUpdateHotfix(_) => {
-                            update_available |= true;
+
+                        // Skipped versions do not trigger the dialog on start, but they're still shown as available in it.
+                        APIResponse::NewStableUpdate(ref last_release) |
+                        APIResponse::NewBetaUpdate(ref last_release) |
+                        APIResponse::NewUpdateHotfix(ref last_release) => {
+                            update_available |= setting_string("update_skipped_version") != *last_release;
                         }
                         _ => {},
                     }
@@ -132,7 +159,7 @@ impl UpdaterUI {
             None
         };
 
-        let updates_for_schema = if setting_bool("check_schema_updates_on_start") {
+        let updates_for_schema = if setting_bool("check_schema_updates_on_start") && !offline {
             let receiver = CENTRAL_COMMAND.send_network(Command::CheckSchemaUpdates);
             let response = CENTRAL_COMMAND.recv_try(&receiver);
             match response {
@@ -173,85 +200,85 @@ impl UpdaterUI {
         let info_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "info_label")?;
         let update_schemas_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "update_schemas_label")?;
         let update_program_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "update_program_label")?;
+        let channel_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "channel_label")?;
+        let releases_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "releases_label")?;
+        let restore_backup_label: QPtr<QLabel> = find_widget(&main_widget.static_upcast(), "restore_backup_label")?;
         let update_schemas_button: QPtr<QPushButton> = find_widget(&main_widget.static_upcast(), "update_schemas_button")?;
         let update_program_button: QPtr<QPushButton> = find_widget(&main_widget.static_upcast(), "update_program_button")?;
+        let skip_version_button: QPtr<QPushButton> = find_widget(&main_widget.static_upcast(), "skip_version_button")?;
+        let channel_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "channel_combobox")?;
+        let releases_combobox: QPtr<QComboBox> = find_widget(&main_widget.static_upcast(), "releases_combobox")?;
+        let install_release_button: QPtr<QPushButton> = find_widget(&main_widget.static_upcast(), "install_release_button")?;
+        let restore_backup_button: QPtr<QPushButton> = find_widget(&main_widget.static_upcast(), "restore_backup_button")?;
         let button_box: QPtr<QDialogButtonBox> = find_widget(&main_widget.static_upcast(), "button_box")?;
         let accept_button: QPtr<QPushButton> = button_box.button(StandardButton::Ok);
         let cancel_button: QPtr<QPushButton> = button_box.button(StandardButton::Cancel);
 
-        let changelog_path = PROGRAM_PATH.join(CHANGELOG_FILE);
-
         info_groupbox.set_title(&qtr("updater_info_title"));
-        info_label.set_text(&qtre("updater_info", &[&changelog_path.to_string_lossy(), &setting_string("update_channel")]));
         info_label.set_open_external_links(true);
 
         update_program_label.set_text(&qtr("updater_update_program"));
         update_schemas_label.set_text(&qtr("updater_update_schemas"));
+        channel_label.set_text(&qtr("update_channel"));
+        releases_label.set_text(&qtr("updater_releases"));
+        restore_backup_label.set_text(&qtr("updater_restore_backup"));
 
         update_program_button.set_text(&qtr("updater_update_program_checking"));
         update_schemas_button.set_text(&qtr("updater_update_schemas_checking"));
+        skip_version_button.set_text(&qtr("updater_skip_version"));
+        install_release_button.set_text(&qtr("updater_install_release"));
 
         update_program_button.set_enabled(false);
         update_schemas_button.set_enabled(false);
 
-        // Show the dialog before checking for updates.
-        main_widget.static_downcast::<QDialog>().set_window_title(&qtr("updater_title"));
-        main_widget.static_downcast::<QDialog>().show();
+        channel_combobox.add_item_q_string(&QString::from_std_str(STABLE));
+        channel_combobox.add_item_q_string(&QString::from_std_str(BETA));
+        channel_combobox.set_current_index(if update_channel() == UpdateChannel::Beta { 1 } else { 0 });
 
-        // If we have prechecks done, do not re-check for updates on them.
-        match precheck_program {
-            Some(response) => {
-                match response {
-                    APIResponse::NewStableUpdate(last_release) |
-                    APIResponse::NewBetaUpdate(last_release) |
-                    APIResponse::NewUpdateHotfix(last_release) => {
-                        update_program_button.set_text(&qtre("updater_update_program_available", &[&last_release]));
-                        update_program_button.set_enabled(true);
-                    }
-                    APIResponse::NoUpdate |
-                    APIResponse::UnknownVersion => {
-                        update_program_button.set_text(&qtr("updater_update_program_no_updates"));
-                    }
-                }
+        match update_backup_version() {
+            Some(version) => {
+                restore_backup_button.set_text(&qtre("updater_restore_backup_available", &[&version]));
+                restore_backup_button.set_enabled(true);
             }
-            None => {
-                let receiver = CENTRAL_COMMAND.send_network(Command::CheckUpdates);
-                let response = CENTRAL_COMMAND.recv_try(&receiver);
-                match response {
-                    Response::APIResponse(response) => {
-                        match response {
-                            APIResponse::NewStableUpdate(last_release) |
-                            APIResponse::NewBetaUpdate(last_release) |
-                            APIResponse::NewUpdateHotfix(last_release) => {
-                                update_program_button.set_text(&qtre("updater_update_program_available", &[&last_release]));
-                                update_program_button.set_enabled(true);
-                            }
-                            APIResponse::NoUpdate |
-                            APIResponse::UnknownVersion => {
-                                update_program_button.set_text(&qtr("updater_update_program_no_updates"));
-                            }
-                        }
-                    }
-
-                    Response::Error(_) => {
-                        update_program_button.set_text(&qtr("updater_update_program_no_updates"));
-                    }
-                    _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
-                }
-            },
+            None => restore_backup_button.set_text(&qtr("updater_restore_backup_none")),
         }
 
+        let ui = Rc::new(Self {
+            main_widget,
+            info_label,
+            update_schemas_button,
+            update_program_button,
+            skip_version_button,
+            channel_combobox,
+            releases_combobox,
+            install_release_button,
+            restore_backup_button,
+            accept_button,
+            cancel_button,
+            available_version: RefCell::new(String::new()),
+        });
+
+        ui.update_info();
+
+        // Show the dialog before checking for updates.
+        ui.dialog().set_window_title(&qtr("updater_title"));
+        ui.dialog().show();
+
+        // If we have prechecks done, do not re-check for updates on them.
+        ui.check_program_updates(precheck_program);
+        ui.load_releases();
+
         match precheck_schema {
             Some(response) => {
                 match response {
                     GitResponse::NoLocalFiles |
                     GitResponse::NewUpdate |
                     GitResponse::Diverged => {
-                        update_schemas_button.set_text(&qtr("updater_update_schemas_available"));
-                        update_schemas_button.set_enabled(true);
+                        ui.update_schemas_button.set_text(&qtr("updater_update_schemas_available"));
+                        ui.update_schemas_button.set_enabled(true);
                     }
                     GitResponse::NoUpdate => {
-                        update_schemas_button.set_text(&qtr("updater_update_schemas_no_updates"));
+                        ui.update_schemas_button.set_text(&qtr("updater_update_schemas_no_updates"));
                     }
                 }
             }
@@ -264,31 +291,23 @@ impl UpdaterUI {
                             GitResponse::NoLocalFiles |
                             GitResponse::NewUpdate |
                             GitResponse::Diverged => {
-                                update_schemas_button.set_text(&qtr("updater_update_schemas_available"));
-                                update_schemas_button.set_enabled(true);
+                                ui.update_schemas_button.set_text(&qtr("updater_update_schemas_available"));
+                                ui.update_schemas_button.set_enabled(true);
                             }
                             GitResponse::NoUpdate => {
-                                update_schemas_button.set_text(&qtr("updater_update_schemas_no_updates"));
+                                ui.update_schemas_button.set_text(&qtr("updater_update_schemas_no_updates"));
                             }
                         }
                     }
 
                     Response::Error(_) => {
-                        update_schemas_button.set_text(&qtr("updater_update_schemas_no_updates"));
+                        ui.update_schemas_button.set_text(&qtr("updater_update_schemas_no_updates"));
                     }
                     _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
                 }
             },
         }
 
-        let ui = Rc::new(Self {
-            main_widget,
-            update_schemas_button,
-            update_program_button,
-            accept_button,
-            cancel_button,
-        });
-
         let slots = UpdaterUISlots::new(&ui, app_ui);
         ui.set_connections(&slots);
 
@@ -298,6 +317,10 @@ impl UpdaterUI {
     pub unsafe fn set_connections(&self, slots: &UpdaterUISlots) {
         self.update_program_button.released().connect(slots.update_program());
         self.update_schemas_button.released().connect(slots.update_schemas());
+        self.skip_version_button.released().connect(slots.skip_version());
+        self.channel_combobox.current_index_changed().connect(slots.change_channel());
+        self.install_release_button.released().connect(slots.install_release());
+        self.restore_backup_button.released().connect(slots.restore_backup());
 
         self.accept_button.released().connect(self.dialog().slot_accept());
         self.cancel_button.released().connect(self.dialog().slot_close());
@@ -306,6 +329,87 @@ impl UpdaterUI {
     pub unsafe fn dialog(&self) -> QPtr<QDialog> {
         self.main_widget().static_downcast::<QDialog>()
     }
+
+    /// This function updates the info text with the current update channel.
+    pub unsafe fn update_info(&self) {
+        let changelog_path = PROGRAM_PATH.join(CHANGELOG_FILE);
+        self.info_label.set_text(&qtre("updater_info", &[&changelog_path.to_string_lossy(), &update_channel().to_string()]));
+    }
+
+    /// This function updates the program update button with the provided result of an update check. If none is provided, it checks for updates.
+    pub unsafe fn check_program_updates(&self, precheck: Option<APIResponse>) {
+        let response = match precheck {
+            Some(response) => Some(response),
+            None => {
+                let receiver = CENTRAL_COMMAND.send_network(Command::CheckUpdates);
+                let response = CENTRAL_COMMAND.recv_try(&receiver);
+                match response {
+                    Response::APIResponse(response) => Some(response),
+                    Response::Error(_) => None,
+                    _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+                }
+            }
+        };
+
+        match response {
+            Some(APIResponse::NewStableUpdate(last_release)) |
+            Some(APIResponse::NewBetaUpdate(last_release)) |
+            Some(APIResponse::NewUpdateHotfix(last_release)) => {
+                self.update_program_button.set_text(&qtre("updater_update_program_available", &[&last_release]));
+                self.update_program_button.set_enabled(true);
+                self.skip_version_button.set_enabled(setting_string("update_skipped_version") != last_release);
+                *self.available_version.borrow_mut() = last_release;
+            }
+            _ => {
+                self.update_program_button.set_text(&qtr("updater_update_program_no_updates"));
+                self.update_program_button.set_enabled(false);
+                self.skip_version_button.set_enabled(false);
+            }
+        }
+    }
+
+    /// This function loads the list of releases of the current update channel, so the user can install any of them.
+    pub unsafe fn load_releases(&self) {
+        self.releases_combobox.clear();
+
+        let receiver = CENTRAL_COMMAND.send_network(Command::GetReleases(update_channel()));
+        let response = CENTRAL_COMMAND.recv_try(&receiver);
+        match response {
+            Response::VecString(versions) => {
+                for version in &versions {
+                    let text = if version == cargo_crate_version!() {
+                        qtre("updater_release_current", &[version])
+                    } else {
+                        QString::from_std_str(version)
+                    };
+
+                    self.releases_combobox.add_item_q_string_q_variant(&text, &QVariant::from_q_string(&QString::from_std_str(version)));
+                }
+            }
+            Response::Error(_) => {},
+            _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+        }
+
+        self.install_release_button.set_enabled(self.releases_combobox.count() > 0);
+    }
+
+    /// This function turns the provided button into a restart button, for after the program files have been replaced.
+    pub unsafe fn enable_restart(&self, button: &QPtr<QPushButton>, text_key: &str) {
+        button.set_text(&qtr(text_key));
+        button.set_enabled(true);
+        button.disconnect();
+        button.released().connect(&SlotNoArgs::new(self.main_widget(), move || {
+
+            // Make sure we close both threads and the window. In windows the main window doesn't get closed for some reason.
+            CENTRAL_COMMAND.send_background(Command::Exit);
+            CENTRAL_COMMAND.send_network(Command::Exit);
+            QApplication::close_all_windows();
+
+            let exe_path = current_exe().unwrap();
+            SystemCommand::new(exe_path).spawn().unwrap();
+            exit(10);
+        }));
+    }
 }
 
 //---------------------------------------------------------------------------//
@@ -313,9 +417,17 @@ impl UpdaterUI {
 //---------------------------------------------------------------------------//
 
 /// This function takes care of updating RPFM itself when a new version comes out.
-pub fn update_main_program() -> Result<()> {
-    let update_channel = update_channel();
-    let last_release = last_release(update_channel)?;
+///
+/// If a version is provided, that version is installed instead of the last one, which allows rolling back to previous releases.
+/// The files replaced are backed up, so they can be restored with [restore_update_backup].
+pub fn update_main_program(version: Option<&str>) -> Result<()> {
+    let last_release = match version {
+        Some(version) => releases(UpdateChannel::Beta)?
+            .into_iter()
+            .find(|release| release.version == version)
+            .ok_or_else(|| anyhow!("Release {} not found.", version))?,
+        None => last_release(update_channel())?,
+    };
 
     // Get the download for our architecture.
     let asset = last_release.asset_for(get_target(), None).ok_or_else(|| anyhow!("No download available for your architecture."))?;
@@ -344,6 +456,16 @@ pub fn update_main_program() -> Result<()> {
     let mut dest_base_path = current_exe()?;
     dest_base_path.pop();
 
+    // Only the last update is kept as backup, so clean the previous one before backing up the current files.
+    let backup_path = dest_base_path.join(UPDATE_BACKUP_FOLDER);
+    if backup_path.is_dir() {
+        std::fs::remove_dir_all(&backup_path)?;
+    }
+
+    DirBuilder::new().recursive(true).create(&backup_path)?;
+    let mut backup_version = File::create(backup_path.join(UPDATE_BACKUP_VERSION_FILE))?;
+    backup_version.write_all(cargo_crate_version!().as_bytes())?;
+
     for updated_file in &files_from_subdir(tmp_dir.path(), true)? {
 
         // Ignore the downloaded ZIP.
@@ -367,6 +489,15 @@ pub fn update_main_program() -> Result<()> {
         dest_folder.pop();
         DirBuilder::new().recursive(true).create(&dest_folder)?;
 
+        if dest_file.is_file() {
+            let backup_file = backup_path.join(tmp_file_relative);
+            if let Some(backup_folder) = backup_file.parent() {
+                DirBuilder::new().recursive(true).create(backup_folder)?;
+            }
+
+            std::fs::copy(&dest_file, &backup_file)?;
+        }
+
         Move::from_source(updated_file)
             .replace_using_temp(&tmp_file)
             .to_dest(&dest_file)?;
@@ -379,6 +510,43 @@ pub fn update_main_program() -> Result<()> {
     Ok(())
 }
 
+/// This function restores the files backed up by the last update, returning to the version we had before it.
+pub fn restore_update_backup() -> Result<()> {
+    let mut dest_base_path = current_exe()?;
+    dest_base_path.pop();
+
+    let backup_path = dest_base_path.join(UPDATE_BACKUP_FOLDER);
+    if update_backup_version().is_none() {
+        return Err(anyhow!("There is no backup to restore."));
+    }
+
+    for backup_file in &files_from_subdir(&backup_path, true)? {
+        let backup_file_relative = backup_file.strip_prefix(&backup_path)?;
+        if backup_file_relative == Path::new(UPDATE_BACKUP_VERSION_FILE) {
+            continue;
+        }
+
+        let mut tmp_file = backup_file.to_path_buf();
+        tmp_file.set_file_name(format!("{}_replacement_tmp", backup_file.file_name().unwrap().to_string_lossy()));
+
+        Move::from_source(backup_file)
+            .replace_using_temp(&tmp_file)
+            .to_dest(&dest_base_path.join(backup_file_relative))?;
+    }
+
+    // The backup is consumed by the restore, so remove it to not restore it twice.
+    std::fs::remove_dir_all(&backup_path)?;
+
+    Ok(())
+}
+
+/// This function returns the version backed up by the last update, if there is a backup.
+pub fn update_backup_version() -> Option<String> {
+    let mut backup_path = current_exe().ok()?;
+    backup_path.pop();
+    std::fs::read_to_string(backup_path.join(UPDATE_BACKUP_FOLDER).join(UPDATE_BACKUP_VERSION_FILE)).ok()
+}
+
 /// This function takes care of checking for new RPFM updates.
 ///
 /// Also, this has a special behavior: If we have a beta version and we have the stable channel selected,
@@ -458,21 +626,28 @@ pub fn check_updates_main_program() -> Result<APIResponse> {
 
 /// This function returns the last release available, according to our update channel.
 pub fn last_release(update_channel: UpdateChannel) -> Result<Release> {
+    match releases(update_channel)?.first() {
+        Some(last_release) => Ok(last_release.clone()),
+        None => Err(anyhow!("Failed to get last release (should never happen)."))
+    }
+}
+
+/// This function returns all the releases available in our update channel, from newer to older.
+pub fn releases(update_channel: UpdateChannel) -> Result<Vec<Release>> {
     let releases = ReleaseList::configure()
         .repo_owner(REPO_OWNER)
         .repo_name(REPO_NAME)
         .build()?
         .fetch()?;
 
-    match releases.iter().find(|release| {
-        match update_channel {
-            UpdateChannel::Stable => release.version.split('.').collect::<Vec<&str>>()[2].parse::<i32>().unwrap_or(0) < 99,
-            UpdateChannel::Beta => true
-        }
-    }) {
-        Some(last_release) => Ok(last_release.clone()),
-        None => Err(anyhow!("Failed to get last release (should never happen)."))
-    }
+    Ok(releases.into_iter()
+        .filter(|release| {
+            match update_channel {
+                UpdateChannel::Stable => release.version.split('.').nth(2).and_then(|x| x.parse::<i32>().ok()).unwrap_or(0) < 99,
+                UpdateChannel::Beta => true
+            }
+        })
+        .collect())
 }
 
 /// This function returns the currently selected update channel.
@@ -8,24 +8,22 @@
 // https://github.com/Frodo45127/runcher/blob/master/LICENSE.
 //---------------------------------------------------------------------------//
 
-use qt_widgets::QApplication;
-
 use qt_core::QBox;
 use qt_core::SlotNoArgs;
 
 use getset::*;
 
-use std::env::current_exe;
-use std::process::{Command as SystemCommand, exit};
 use std::rc::Rc;
 
 use rpfm_ui_common::clone;
 use rpfm_ui_common::locale::qtr;
+use rpfm_ui_common::settings::set_setting_string;
 use rpfm_ui_common::utils::show_dialog;
 
 use crate::app_ui::AppUI;
 use crate::CENTRAL_COMMAND;
 use crate::communications::*;
+use crate::mod_manager::game_definitions::schema_file_name;
 
 use super::UpdaterUI;
 
@@ -38,6 +36,10 @@ use super::UpdaterUI;
 pub struct UpdaterUISlots {
     update_program: QBox<SlotNoArgs>,
     update_schemas: QBox<SlotNoArgs>,
+    skip_version: QBox<SlotNoArgs>,
+    change_channel: QBox<SlotNoArgs>,
+    install_release: QBox<SlotNoArgs>,
+    restore_backup: QBox<SlotNoArgs>,
 }
 
 //-------------------------------------------------------------------------------//
@@ -49,30 +51,15 @@ impl UpdaterUISlots {
     pub unsafe fn new(ui: &Rc<UpdaterUI>, app_ui: &Rc<AppUI>) -> Self {
         let update_program = SlotNoArgs::new(ui.main_widget(), clone!(
             ui => move || {
-                let receiver = CENTRAL_COMMAND.send_background(Command::UpdateMainProgram);
+                let receiver = CENTRAL_COMMAND.send_background(Command::UpdateMainProgram(None));
                 ui.update_program_button.set_text(&qtr("updater_update_schemas_updating"));
                 ui.update_program_button.set_enabled(false);
 
                 let response = CENTRAL_COMMAND.recv_try(&receiver);
                 match response {
-                    Response::Success => {
-                        ui.update_program_button.set_text(&qtr("updater_update_program_updated"));
-
-                        // Re-enable the button so it can be used to restart the program.
-                        ui.update_program_button.set_enabled(true);
-                        ui.update_program_button.disconnect();
-                        ui.update_program_button.released().connect(&SlotNoArgs::new(ui.main_widget(), move || {
-
-                            // Make sure we close both threads and the window. In windows the main window doesn't get closed for some reason.
-                            CENTRAL_COMMAND.send_background(Command::Exit);
-                            CENTRAL_COMMAND.send_network(Command::Exit);
-                            QApplication::close_all_windows();
-
-                            let exe_path = current_exe().unwrap();
-                            SystemCommand::new(exe_path).spawn().unwrap();
-                            exit(10);
-                        }));
-                    },
+
+                    // Re-enable the button so it can be used to restart the program.
+                    Response::Success => ui.enable_restart(&ui.update_program_button, "updater_update_program_updated"),
                     Response::Error(error) => {
                         show_dialog(ui.dialog(), error, false);
                         ui.update_program_button.set_text(&qtr("updater_update_program_error"));
@@ -82,10 +69,63 @@ impl UpdaterUISlots {
             }
         ));
 
+        let skip_version = SlotNoArgs::new(ui.main_widget(), clone!(
+            ui => move || {
+                set_setting_string("update_skipped_version", &ui.available_version.borrow());
+                ui.skip_version_button.set_enabled(false);
+            }
+        ));
+
+        let change_channel = SlotNoArgs::new(ui.main_widget(), clone!(
+            ui => move || {
+                set_setting_string("update_channel", &ui.channel_combobox.current_text().to_std_string());
+                ui.update_info();
+                ui.check_program_updates(None);
+                ui.load_releases();
+            }
+        ));
+
+        let install_release = SlotNoArgs::new(ui.main_widget(), clone!(
+            ui => move || {
+                let version = ui.releases_combobox.current_data_0a().to_string().to_std_string();
+                let receiver = CENTRAL_COMMAND.send_background(Command::UpdateMainProgram(Some(version)));
+                ui.install_release_button.set_text(&qtr("updater_update_program_updating"));
+                ui.install_release_button.set_enabled(false);
+
+                let response = CENTRAL_COMMAND.recv_try(&receiver);
+                match response {
+                    Response::Success => ui.enable_restart(&ui.install_release_button, "updater_update_program_updated"),
+                    Response::Error(error) => {
+                        show_dialog(ui.dialog(), error, false);
+                        ui.install_release_button.set_text(&qtr("updater_install_release"));
+                        ui.install_release_button.set_enabled(true);
+                    }
+                    _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+                }
+            }
+        ));
+
+        let restore_backup = SlotNoArgs::new(ui.main_widget(), clone!(
+            ui => move || {
+                let receiver = CENTRAL_COMMAND.send_background(Command::RestoreUpdateBackup);
+                ui.restore_backup_button.set_enabled(false);
+
+                let response = CENTRAL_COMMAND.recv_try(&receiver);
+                match response {
+                    Response::Success => ui.enable_restart(&ui.restore_backup_button, "updater_restore_backup_restored"),
+                    Response::Error(error) => {
+                        show_dialog(ui.dialog(), error, false);
+                        ui.restore_backup_button.set_enabled(true);
+                    }
+                    _ => panic!("{THREADS_COMMUNICATION_ERROR}{response:?}"),
+                }
+            }
+        ));
+
         let update_schemas = SlotNoArgs::new(ui.main_widget(), clone!(
             app_ui,
             ui => move || {
-                let receiver = CENTRAL_COMMAND.send_background(Command::UpdateSchemas(app_ui.game_selected().read().unwrap().schema_file_name().to_owned()));
+                let receiver = CENTRAL_COMMAND.send_background(Command::UpdateSchemas(schema_file_name(&app_ui.game_selected().read().unwrap())));
                 ui.update_schemas_button.set_text(&qtr("updater_update_schemas_updating"));
                 ui.update_schemas_button.set_enabled(false);
 
@@ -113,6 +153,10 @@ impl UpdaterUISlots {
         Self {
             update_program,
             update_schemas,
+            skip_version,
+            change_channel,
+            install_release,
+            restore_backup,
         }
     }
 }
@@ -38,6 +38,36 @@ pub enum Commands {
         /// List of published file ids, separated by comma. If empty, all subscribed items are downloaded.
         #[arg(short, long, required = false, value_name = "PUBLISHED_FILE_IDS")]
         published_file_ids: Option<String>,
+
+        /// If we should subscribe to the provided items before downloading them. Only works if published file ids are provided.
+        #[arg(long, required = false)]
+        subscribe: bool,
+    },
+
+    ExportMapBin {
+
+        /// Path of the Pack containing the map.
+        #[arg(short, long, required = true, value_name = "PATH")]
+        pack_path: PathBuf,
+
+        /// Name of the map folder to export. Only needed if the Pack contains more than one map.
+        #[arg(short, long, required = false, value_name = "MAP_NAME")]
+        map_name: Option<String>,
+
+        /// Path where the map bin will be saved.
+        #[arg(short, long, required = true, value_name = "OUTPUT_PATH")]
+        output_path: PathBuf,
+    },
+
+    ForceRedownload {
+
+        /// SteamId/AppId of the game.
+        #[arg(short, long, value_name = "STEAM_ID")]
+        steam_id: u32,
+
+        /// List of published file ids, separated by comma.
+        #[arg(short, long, required = true, value_name = "PUBLISHED_FILE_IDS")]
+        published_file_ids: String,
     },
 
     GetPublishedFileDetails {
@@ -55,6 +85,36 @@ pub enum Commands {
         ipc_channel: String,
     },
 
+    SearchItems {
+
+        /// SteamId/AppId of the game whose workshop we're going to search.
+        #[arg(short, long, value_name = "STEAM_ID")]
+        steam_id: u32,
+
+        /// List of tags the items must have, separated by comma. If empty, all items are returned.
+        #[arg(short, long, required = false, value_name = "TAGS")]
+        tags: Option<String>,
+
+        /// Page of results to return. Each page contains up to 50 items.
+        #[arg(short, long, default_value_t = 1, value_name = "PAGE")]
+        page: u32,
+
+        /// Name of the IPC channel the response will be sent through.
+        #[arg(short, long, value_name = "IPC_CHANNEL")]
+        ipc_channel: String,
+    },
+
+    Sync {
+
+        /// SteamId/AppId of the game we're going to upload the mods for.
+        #[arg(short, long, value_name = "STEAM_ID")]
+        steam_id: u32,
+
+        /// Path of the json manifest describing the packs to upload or update.
+        #[arg(short, long, required = true, value_name = "MANIFEST_PATH")]
+        manifest_path: PathBuf,
+    },
+
     Launch {
 
         /// If we're going to pass the command as base64 string. Use this when any of those includes special characters.
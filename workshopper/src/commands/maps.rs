@@ -0,0 +1,89 @@
+//---------------------------------------------------------------------------//
+// Copyright (c) 2017-2024 Ismael Gutiérrez González. All rights reserved.
+//
+// This file is part of the Rusted Launcher (Runcher) project,
+// which can be found here: https://github.com/Frodo45127/rpfm.
+//
+// This file is licensed under the MIT license, which can be found here:
+// https://github.com/Frodo45127/rpfm/blob/master/LICENSE.
+//---------------------------------------------------------------------------//
+
+//! Module to convert Shogun 2 battle map packs back into the bin format used by the legacy Workshop uploader.
+//!
+//! Map bins are zlib-compressed lists of files, encoded like this:
+//! - Null-terminated StringU16: File name, relative to the map folder.
+//! - u64: File data size.
+//! - [u8; size]: File data.
+//! - Then at the end there is an u32 with a 0A.
+//!
+//! Runcher turns them into packs with the files under battleterrain/presets/map_name/, so we just do the reverse here.
+
+use anyhow::{anyhow, Result};
+use flate2::{Compression, write::ZlibEncoder};
+
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use rpfm_lib::binary::WriteBytes;
+use rpfm_lib::files::{Container, ContainerPath, pack::Pack};
+use rpfm_lib::integrations::log::info;
+
+const MAP_PRESETS_PATH: &str = "battleterrain/presets/";
+
+/// Marker at the end of the file list of a map bin.
+const MAP_BIN_END_MARKER: u32 = 0x0A;
+
+//---------------------------------------------------------------------------//
+//                        Generic public functions
+//---------------------------------------------------------------------------//
+
+/// This function exports a battle map from the provided pack into a map bin.
+///
+/// If the pack contains more than one map, the name of the map to export is required.
+pub fn export_map_bin(pack_path: &Path, map_name: &Option<String>, output_path: &Path) -> Result<()> {
+    let mut pack = Pack::read_and_merge(&[pack_path.to_path_buf()], true, false, false)?;
+
+    let map_names = pack.paths_raw()
+        .iter()
+        .filter_map(|path| path.to_lowercase().strip_prefix(MAP_PRESETS_PATH)?.split_once('/').map(|(name, _)| name.to_owned()))
+        .collect::<BTreeSet<_>>();
+
+    let map_name = match map_name {
+        Some(map_name) => map_names.iter()
+            .find(|name| name.eq_ignore_ascii_case(map_name))
+            .ok_or_else(|| anyhow!("The map {} is not in the pack. Maps in the pack: {}.", map_name, map_names.iter().cloned().collect::<Vec<_>>().join(", ")))?
+            .to_owned(),
+        None => match map_names.len() {
+            0 => return Err(anyhow!("The pack doesn't contain any battle map.")),
+            1 => map_names.first().unwrap().to_owned(),
+            _ => return Err(anyhow!("The pack contains more than one map. Select the one to export. Maps in the pack: {}.", map_names.iter().cloned().collect::<Vec<_>>().join(", "))),
+        }
+    };
+
+    // Sort the files, so exporting the same pack twice gives the same bin.
+    let map_path = format!("{MAP_PRESETS_PATH}{map_name}/");
+    let mut files = pack.files_by_path_mut(&ContainerPath::Folder(map_path.to_owned()), true);
+    files.sort_by(|a, b| a.path_in_container_raw().cmp(b.path_in_container_raw()));
+
+    let mut data = vec![];
+    for file in files {
+        let file_name = file.path_in_container_raw()[map_path.len()..].to_owned();
+        let file_data = file.encode(&None, false, false, true)?.unwrap_or_default();
+
+        info!("Adding file {} ({} bytes).", file_name, file_data.len());
+        data.write_string_u16_0terminated(&file_name)?;
+        data.write_u64(file_data.len() as u64)?;
+        data.write_all(&file_data)?;
+    }
+
+    data.write_u32(MAP_BIN_END_MARKER)?;
+
+    let mut encoder = ZlibEncoder::new(BufWriter::new(File::create(output_path)?), Compression::default());
+    encoder.write_all(&data)?;
+    encoder.finish()?.flush()?;
+
+    info!("Map {} exported to {}.", map_name, output_path.to_string_lossy());
+    Ok(())
+}
@@ -15,11 +15,13 @@ use interprocess::local_socket::{GenericNamespaced, prelude::*};
 use steamworks::Client;
 
 use std::io::Write;
+use std::path::Path;
 #[cfg(target_os = "windows")] use std::os::windows::process::CommandExt;
 use std::process::Command;
 
 use rpfm_lib::integrations::log::info;
 
+pub mod maps;
 pub mod ugc;
 
 #[cfg(target_os = "windows")] const CREATE_NO_WINDOW: u32 = 0x08000000;
@@ -48,6 +50,11 @@ pub fn launch_game(base64: bool, steam_id: u32, command: &str) -> Result<()> {
     // Launch the game.
     let mut game_command = Command::parse(command)?;
 
+    // If we got the game's exe instead of a shell command, games expect to be run from their own folder.
+    if let Some(game_folder) = Path::new(game_command.get_program()).parent().filter(|folder| folder.is_dir()) {
+        game_command.current_dir(game_folder);
+    }
+
     // This disables the terminal when executing the command.
     #[cfg(target_os = "windows")]game_command.creation_flags(CREATE_NO_WINDOW);
     let mut handle = game_command.spawn()?;
@@ -14,20 +14,24 @@ use crossbeam::channel::{Sender, Receiver, TryRecvError, unbounded};
 use indicatif::{ProgressBar, ProgressState, ProgressStyle};
 use interprocess::local_socket::{GenericNamespaced, prelude::*};
 use serde::{Deserialize, Serialize};
-use serde_json::to_string_pretty;
-use steamworks::{AppId, Client, ClientManager, DownloadItemResult, FileType, PublishedFileId, PublishedFileVisibility, QueryResult, SingleClient, SteamId, UpdateStatus, UpdateWatchHandle, UGC};
+use serde_json::{from_reader, to_string_pretty};
+use steamworks::{AppId, AppIDs, Client, ClientManager, DownloadItemResult, FileType, ItemState, PublishedFileId, PublishedFileVisibility, QueryResult, SingleClient, SteamId, UGCQueryType, UGCType, UpdateStatus, UpdateWatchHandle, UGC};
 
 use std::fmt::Write as FmtWrite;
 use std::fs::{DirBuilder, File};
-use std::io::{BufWriter, Write};
+use std::io::{BufReader, BufWriter, Write};
 use std::path::{Path, PathBuf};
 use std::thread::JoinHandle;
+use std::time::Duration;
 
 use rpfm_lib::{games::GameInfo, integrations::log::{error, info, warn}};
 use rpfm_lib::utils::path_to_absolute_path;
 
 const TOTAL_WAR_BASE_TAG: &str = "mod";
 
+/// Time to wait for Steam to finish re-downloading an item. Big mods can take a while on slow connections.
+const FORCE_REDOWNLOAD_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
 //-------------------------------------------------------------------------------//
 //                              Enums & Structs
 //-------------------------------------------------------------------------------//
@@ -56,6 +60,9 @@ pub struct QueryResultDerive {
     pub num_downvotes: u32,
     pub score: f32,
     pub num_children: u32,
+
+    /// Download/update state of the item in the user's Steam client, as Steam's item state flags. 0 if unknown.
+    pub item_state: u32,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
@@ -86,6 +93,38 @@ pub enum FileTypeDerive {
     GameManagedItem,
 }
 
+/// Manifest describing multiple packs to upload or update in one run of the `sync` command.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SyncManifest {
+    pub items: Vec<SyncItem>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SyncItem {
+
+    /// Path of the pack. Relative paths are relative to the folder of the manifest.
+    pub path: PathBuf,
+
+    /// Title the mod will receive.
+    pub title: String,
+
+    /// Description the mod will receive.
+    pub description: Option<String>,
+
+    /// Tags the mod will receive.
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    /// Changelog for this release.
+    pub changelog: Option<String>,
+
+    /// PublishedFileId of the mod. If none, the pack is uploaded as a new mod.
+    pub published_file_id: Option<u64>,
+
+    /// New visibility status.
+    pub visibility: Option<u32>,
+}
+
 #[derive(Debug)]
 pub enum SteamWorksThreadMessage {
     QueryResults(Vec<QueryResult>),
@@ -123,7 +162,10 @@ impl From<&QueryResult> for QueryResultDerive {
             num_upvotes: value.num_upvotes.clone(),
             num_downvotes: value.num_downvotes.clone(),
             score: value.score.clone(),
-            num_children: value.num_children.clone()
+            num_children: value.num_children.clone(),
+
+            // This is not part of the query results. It needs to be asked separately to the UGC api.
+            item_state: 0,
         }
     }
 }
@@ -189,7 +231,14 @@ pub fn published_file_details(steam_id: u32, published_file_ids: &str, ipc_chann
     let response = rx_query.recv()?;
     match response {
         SteamWorksThreadMessage::QueryResults(results) => {
-            let results = results.iter().map(|result| QueryResultDerive::from(result)).collect::<Vec<_>>();
+            let results = results.iter()
+                .map(|result| {
+                    let mut result_derive = QueryResultDerive::from(result);
+                    result_derive.item_state = ugc.item_state(result.published_file_id).bits();
+                    result_derive
+                })
+                .collect::<Vec<_>>();
+
             if let Ok(message) = to_string_pretty(&results) {
 
                 if let Ok(mut stream) = LocalSocketStream::connect(ipc_channel.to_ns_name::<GenericNamespaced>()?) {
@@ -224,6 +273,51 @@ pub fn published_file_details(steam_id: u32, published_file_ids: &str, ipc_chann
     };
 }
 
+/// This function searches the Workshop of a game for items with all the provided tags, and sends them through the IPC channel.
+pub fn search_items(steam_id: u32, tags: &Option<String>, page: u32, ipc_channel: &str) -> Result<()> {
+    let tags = tags.as_ref()
+        .map(|tags| tags.split(",")
+            .map(|tag| tag.trim().to_owned())
+            .filter(|tag| !tag.is_empty())
+            .collect::<Vec<_>>()
+        )
+        .unwrap_or_default();
+
+    info!("Searching Workshop items with tags {:?}, page {}.", tags, page);
+
+    // Initialize the API.
+    let (client, tx, callback_thread) = init(steam_id, Some(ipc_channel))?;
+    let ugc = client.ugc();
+
+    // Create the query and request the results.
+    let (tx_query, rx_query): (Sender<SteamWorksThreadMessage>, Receiver<SteamWorksThreadMessage>) = unbounded();
+    query_items_by_tags(&ugc, tx_query, steam_id, &tags, page);
+
+    let response = rx_query.recv()?;
+    match response {
+        SteamWorksThreadMessage::QueryResults(results) => {
+            let results = results.iter().map(|result| QueryResultDerive::from(result)).collect::<Vec<_>>();
+            let message = to_string_pretty(&results).unwrap_or_else(|_| "{}".to_owned());
+
+            if let Ok(mut stream) = LocalSocketStream::connect(ipc_channel.to_ns_name::<GenericNamespaced>()?) {
+                let _ = stream.write(message.as_bytes());
+            }
+
+            finish(tx, callback_thread)
+        },
+        SteamWorksThreadMessage::Error(error) => {
+
+            if let Ok(mut stream) = LocalSocketStream::connect(ipc_channel.to_ns_name::<GenericNamespaced>()?) {
+                let _ = stream.write(b"{}");
+            }
+
+            finish(tx, callback_thread)?;
+            Err(error)
+        },
+        _ => panic!("{response:?}")
+    }
+}
+
 /// This function is used to upload a new mod to the Workshop. For updating mods, do not use this. Use update instead.
 pub fn upload(
     base64: bool,
@@ -241,31 +335,12 @@ pub fn upload(
     let ugc = client.ugc();
 
     // Create the item.
-    let (tx_query, rx_query): (Sender<SteamWorksThreadMessage>, Receiver<SteamWorksThreadMessage>) = unbounded();
-    create_item(&ugc, tx_query, steam_id);
-
-    let response = rx_query.recv()?;
-    let published_file_id = match response {
-        SteamWorksThreadMessage::PublishedFileId(id) => id,
-        SteamWorksThreadMessage::Error(error) => {
-            finish(tx, callback_thread)?;
-            return Err(error)
-        },
-        _ => panic!("{response:?}")
-    };
-
-    // We need to subscribe ourself to the item. Otherwise we'll not get it's data in a data request.
-    let (tx_query, rx_query): (Sender<SteamWorksThreadMessage>, Receiver<SteamWorksThreadMessage>) = unbounded();
-    subscribe_item(&ugc, tx_query, published_file_id);
-
-    let response = rx_query.recv()?;
-    match response {
-        SteamWorksThreadMessage::Ok => {},
-        SteamWorksThreadMessage::Error(error) => {
+    let published_file_id = match create_and_subscribe_item(&ugc, steam_id) {
+        Ok(published_file_id) => published_file_id,
+        Err(error) => {
             finish(tx, callback_thread)?;
             return Err(error)
-        },
-        _ => panic!("{response:?}")
+        }
     };
 
     // Finally update it with the local file.
@@ -293,192 +368,83 @@ pub fn update(
     let (client, tx, callback_thread) = api.unwrap_or_else(|| init(steam_id, None))?;
     let ugc = ugc.unwrap_or_else(|| client.ugc());
 
-    // Sanitize the pack_path.
-    let pack_path = path_to_absolute_path(pack_path, true);
+    let result = update_item(&ugc, base64, published_file_id, steam_id, pack_path, title, description, tags, changelog, visibility);
+    finish(tx, callback_thread)?;
+    result
+}
 
-    // Prepare the preview path. We replicate the same behavior as the vanilla launcher.
-    let mut preview_path = pack_path.to_path_buf();
-    preview_path.set_extension("png");
+/// This function uploads or updates all the packs described in the provided manifest with a single client, reporting the result of each one.
+///
+/// Packs uploaded for the first time get their PublishedFileId written back to the manifest, so the next sync updates them instead of uploading them again.
+pub fn sync(steam_id: u32, manifest_path: &Path) -> Result<()> {
+    let mut manifest: SyncManifest = from_reader(BufReader::new(File::open(manifest_path)?))?;
+    let base_path = manifest_path.parent().map(|path| path.to_path_buf()).unwrap_or_default();
+    let total = manifest.items.len();
 
-    let (tx_query, rx_query): (Sender<SteamWorksThreadMessage>, Receiver<SteamWorksThreadMessage>) = unbounded();
+    // Initialize the API.
+    let (client, tx, callback_thread) = init(steam_id, None)?;
+    let ugc = client.ugc();
 
-    // If we're in base64 mode, decode the problematic fields.
-    let title = if base64 {
-        String::from_utf8(BASE64_STANDARD.decode(title)?)?
-    } else {
-        title.to_owned()
-    };
+    let mut results = Vec::with_capacity(total);
+    for index in 0..total {
+        let title = manifest.items[index].title.to_owned();
+        info!("Syncing item {} of {}: {}.", index + 1, total, title);
+
+        // Check the files before creating anything, so a typo in the manifest doesn't leave an empty item in the workshop.
+        let pack_path = path_to_absolute_path(&base_path.join(&manifest.items[index].path), true);
+        let mut preview_path = pack_path.to_path_buf();
+        preview_path.set_extension("png");
+
+        let result = if !pack_path.is_file() {
+            Err(anyhow!("Pack not found: {}", pack_path.to_string_lossy()))
+        } else if !preview_path.is_file() {
+            Err(anyhow!("Preview not found: {}", preview_path.to_string_lossy()))
+        } else {
+            match manifest.items[index].published_file_id {
+                Some(published_file_id) => Ok(PublishedFileId(published_file_id)),
+
+                // Save the id as soon as we have it. Otherwise, if something fails later, the next sync creates the item again.
+                None => create_and_subscribe_item(&ugc, steam_id).and_then(|published_file_id| {
+                    manifest.items[index].published_file_id = Some(published_file_id.0);
+                    save_sync_manifest(&manifest, manifest_path)?;
+                    Ok(published_file_id)
+                }),
+            }
+        };
 
-    let mut description = description.clone();
-    let mut changelog = changelog.clone();
-    if base64 {
-        if let Some(ref mut description) = description {
-            *description = String::from_utf8(BASE64_STANDARD.decode(description.clone())?)?;
-        }
+        let item = &manifest.items[index];
+        let result = result.and_then(|published_file_id| update_item(&ugc, false, published_file_id, steam_id, &pack_path, &item.title, &item.description, &item.tags, &item.changelog, &item.visibility));
 
-        if let Some(ref mut changelog) = changelog {
-            *changelog = String::from_utf8(BASE64_STANDARD.decode(changelog.clone())?)?;
+        if let Err(ref error) = result {
+            error!("Failed to sync item {}: {}", title, error);
         }
-    }
-
-    // TODO: Make this only trigger when doing it on a Total War game.
-
-    // NOTE: CA seems to be doing a "copy pack and preview to folder, then upload" thing, to get both uploaded.
-    // We want to keep this behavior because otherwise downloaded mods have no preview.
-    let upload_path = if cfg!(debug_assertions) {
-        PathBuf::from("./mod_uploads/")
-    } else {
-        let mut upload_path = std::env::current_exe().unwrap();
-        upload_path.pop();
-        upload_path.push("mod_uploads");
-        upload_path
-    };
-
-    info!("Copying pack and preview from {} to {}", pack_path.to_string_lossy(), upload_path.to_string_lossy());
 
-    // Clean the mod_uploads folder.
-    if upload_path.is_dir() {
-        std::fs::remove_dir_all(&upload_path)?;
+        results.push((title, item.published_file_id, result));
     }
 
-    DirBuilder::new().recursive(true).create(&upload_path)?;
-
-    // Copy the pack and preview to the upload folder.
-    let mut pack_path_dest = upload_path.to_path_buf();
-    pack_path_dest.push(pack_path.file_name().unwrap());
-
-    let mut preview_path_dest = upload_path.to_path_buf();
-    preview_path_dest.push(preview_path.file_name().unwrap());
-
-    std::fs::copy(&pack_path, pack_path_dest)?;
-    std::fs::copy(&preview_path, &preview_path_dest)?;
-
-    info!("Copying done, preparing upload.");
-
-    let update_handle = upload_item_content(&ugc, tx_query, steam_id, published_file_id, &upload_path, &preview_path, &title, &description, tags, &changelog, visibility);
-
-    // Initialize the progress bar. The upload is a 5-step process, and the bar should come at 3 and 4.
-    let mut bar: Option<ProgressBar> = None;
-    let mut prev_status = UpdateStatus::Invalid;
-    let mut prev_total = 0;
-
-    // We loop keeping painting the progress to the terminal until we're done.
-    loop {
-
-        match rx_query.try_recv() {
-            Ok(response) => match response {
-                SteamWorksThreadMessage::Ok => {
-
-                    // If stuff happened too quickly and the commit didn't trigger, do it here.
-                    if let Some(ref bar) = bar {
-                        bar.finish();
-                    }
-
-                    info!("Upload done, deleting temp files.");
-
-                    // Delete the folder once it's done so we don't occupy space we shouldn't.
-                    if upload_path.is_dir() {
-                        std::fs::remove_dir_all(&upload_path)?;
-                    }
-
-                    info!("Temp files deleted.");
-
-                    return finish(tx, callback_thread)
-                },
-                SteamWorksThreadMessage::Error(error) => {
-                    finish(tx, callback_thread)?;
-                    return Err(error)
-                },
-                _ => panic!("{response:?}")
-            }
-
-            // If it's empty, paint to the console the progress, wait 20 ms and try again.
-            Err(TryRecvError::Empty) => {
-                let (status, loaded, total) = update_handle.progress();
-                match status {
-                    UpdateStatus::PreparingConfig => {
-                        if prev_status != UpdateStatus::PreparingConfig {
-                            prev_status = UpdateStatus::PreparingConfig;
-                            info!("Preparing config...");
-                        }
-                    },
-                    UpdateStatus::PreparingContent => {
-                        if prev_status != UpdateStatus::PreparingContent {
-                            prev_status = UpdateStatus::PreparingContent;
-                            info!("Preparing content...");
-                        }
-                    },
-                    UpdateStatus::UploadingContent => {
-                        if prev_status != UpdateStatus::UploadingContent {
-                            prev_status = UpdateStatus::UploadingContent;
-                        }
-
-                        // Total takes some time to update after changing status.
-                        if prev_total == 0 && total > 0 {
-                            info!("Uploading content of size: {}.", total);
-                            prev_total = total;
-                            bar = Some(progress_bar(total));
-                        }
-
-                        if let Some(ref bar) = bar {
-                            bar.set_position(loaded);
-                        }
-                    },
-                    UpdateStatus::UploadingPreviewFile => {
-                        if prev_status != UpdateStatus::UploadingPreviewFile {
-                            prev_status = UpdateStatus::UploadingPreviewFile;
-
-                            // Fill the previous bar before making the new one.
-                            if let Some(ref bar) = bar {
-                                bar.finish();
-                            }
-
-                            prev_total = 0;
-                        }
-
-                        // Total takes some time to update after changing status.
-                        if prev_total == 0 && total > 0 {
-                            info!("Uploading preview file of size: {}.", total);
-                            prev_total = total;
-                            bar = Some(progress_bar(total));
-                        }
-
-                        if let Some(ref bar) = bar {
-                            bar.set_position(loaded);
-                        }
-                    },
-                    UpdateStatus::CommittingChanges => {
-                        if prev_status != UpdateStatus::CommittingChanges {
-                            prev_status = UpdateStatus::CommittingChanges;
-
-                            // Fill the previous bar before killing it.
-                            if let Some(ref bar) = bar {
-                                bar.finish();
-                            }
-
-                            bar = None;
-
-                            info!("Committing changes...");
-                        }
-                    },
-
-                    // Invalid is usually completed. So just return Ok.
-                    UpdateStatus::Invalid => {
-                        info!("Invalid UpdateStatus. This is an error, or the upload finished.");
-                    },
-                }
-
-                std::thread::sleep(std::time::Duration::from_millis(20));
-            },
+    finish(tx, callback_thread)?;
 
-            // This is a bug.
-            Err(TryRecvError::Disconnected) => panic!("Thread disconected."),
+    info!("Sync results:");
+    for (title, published_file_id, result) in &results {
+        let published_file_id = published_file_id.map(|id| id.to_string()).unwrap_or_else(|| "-".to_owned());
+        match result {
+            Ok(_) => info!("- {} ({}): synced.", title, published_file_id),
+            Err(error) => error!("- {} ({}): {}", title, published_file_id, error),
         }
     }
+
+    let failed = results.iter().filter(|(_, _, result)| result.is_err()).count();
+    if failed == 0 {
+        Ok(())
+    } else {
+        Err(anyhow!("{} of {} items failed to sync.", failed, total))
+    }
 }
 
 /// This function tries to download all mods a user has subscribed to from a game.
-pub fn download_subscribed_mods(steam_id: u32, published_file_ids: Option<String>) -> Result<()> {
+///
+/// If subscribe is true, it subscribes to the provided mods before downloading them.
+pub fn download_subscribed_mods(steam_id: u32, published_file_ids: Option<String>, subscribe: bool) -> Result<()> {
 
     // Initialize the API.
     let (client, tx, callback_thread) = init(steam_id, None)?;
@@ -486,7 +452,19 @@ pub fn download_subscribed_mods(steam_id: u32, published_file_ids: Option<String
 
     // Get the published_file_ids.
     let published_file_ids = match published_file_ids {
-        Some(ids) => ids.split(",").filter_map(|x| x.parse::<u64>().ok()).map(|x| PublishedFileId(x)).collect(),
+        Some(ids) => {
+            let published_file_ids = ids.split(",").filter_map(|x| x.parse::<u64>().ok()).map(|x| PublishedFileId(x)).collect::<Vec<_>>();
+
+            if subscribe {
+                for published_file_id in &published_file_ids {
+                    let (tx_query, rx_query): (Sender<SteamWorksThreadMessage>, Receiver<SteamWorksThreadMessage>) = unbounded();
+                    subscribe_item(&ugc, tx_query, *published_file_id);
+                    let _ = rx_query.recv()?;
+                }
+            }
+
+            published_file_ids
+        }
         None => ugc.subscribed_items(),
     };
 
@@ -533,12 +511,112 @@ pub fn download_subscribed_mods(steam_id: u32, published_file_ids: Option<String
     Ok(())
 }
 
-//---------------------------------------------------------------------------//
-//                      UGC (Workshop) private functions
-//---------------------------------------------------------------------------//
+/// This function forces a clean re-download of the provided mods.
+///
+/// It unsubscribes from them, removes whatever is left of them in the content folder, then resubscribes and downloads them again.
+/// This is the usual fix for corrupted downloads, as Steam doesn't re-download items it thinks are already installed.
+pub fn force_redownload(steam_id: u32, published_file_ids: &str) -> Result<()> {
 
-/// This function initializes the client and callback thread. DO NOT CALL IT IF THERE'S ALREADY A CLIENT ALIVE.
-fn init(steam_id: u32, channel: Option<&str>) -> Result<(Client, Sender<SteamWorksThreadMessage>, JoinHandle<()>)> {
+    // Initialize the API.
+    let (client, tx, callback_thread) = init(steam_id, None)?;
+    let ugc = client.ugc();
+
+    let published_file_ids = published_file_ids.split(",")
+        .filter_map(|x| x.parse::<u64>().ok())
+        .map(|x| PublishedFileId(x))
+        .collect::<Vec<_>>();
+
+    let mut failed = vec![];
+    for published_file_id in published_file_ids {
+        info!("Forcing re-download of workshop item with ID: {}.", published_file_id.0);
+
+        // Get the install folder before unsubscribing, as Steam forgets about it after that.
+        let install_folder = ugc.item_install_info(published_file_id).map(|info| PathBuf::from(info.folder));
+
+        let (tx_query, rx_query): (Sender<SteamWorksThreadMessage>, Receiver<SteamWorksThreadMessage>) = unbounded();
+        unsubscribe_item(&ugc, tx_query, published_file_id);
+        if let SteamWorksThreadMessage::Error(_) = rx_query.recv()? {
+            failed.push(published_file_id.0);
+            continue;
+        }
+
+        // Legacy items are a file, not a folder, so we need to check both.
+        if let Some(install_folder) = install_folder {
+            let result = if install_folder.is_dir() {
+                std::fs::remove_dir_all(&install_folder)
+            } else if install_folder.is_file() {
+                std::fs::remove_file(&install_folder)
+            } else {
+                Ok(())
+            };
+
+            if let Err(error) = result {
+                warn!("Failed to remove the old files of workshop item with ID {}: {}.", published_file_id.0, error);
+            }
+        }
+
+        let (tx_query, rx_query): (Sender<SteamWorksThreadMessage>, Receiver<SteamWorksThreadMessage>) = unbounded();
+        subscribe_item(&ugc, tx_query, published_file_id);
+        if let SteamWorksThreadMessage::Error(_) = rx_query.recv()? {
+            failed.push(published_file_id.0);
+            continue;
+        }
+
+        // Register the callback before asking for the download, so we don't miss it if the download finishes right away.
+        let (tx_callback, rx_callback): (Sender<SteamWorksThreadMessage>, Receiver<SteamWorksThreadMessage>) = unbounded();
+        let _cb = client.register_callback(move |d: DownloadItemResult| {
+            if d.published_file_id == published_file_id {
+                match d.error {
+                    Some(error) => {
+                        error!("Error downloading workshop item with ID {}: {}", published_file_id.0, error);
+                        let _ = tx_callback.send(SteamWorksThreadMessage::Error(error.into()));
+                    }
+                    None => {
+                        info!("Workshop item with ID {} downloaded.", published_file_id.0);
+                        let _ = tx_callback.send(SteamWorksThreadMessage::Ok);
+                    }
+                }
+            }
+        });
+
+        if ugc.download_item(published_file_id, true) {
+            match rx_callback.recv_timeout(FORCE_REDOWNLOAD_TIMEOUT) {
+                Ok(SteamWorksThreadMessage::Error(_)) => failed.push(published_file_id.0),
+                Ok(_) => {
+
+                    // Steam sometimes reports the download as done while the item still needs an update. Make sure it's really installed.
+                    let state = ugc.item_state(published_file_id);
+                    if !state.contains(ItemState::INSTALLED) || state.contains(ItemState::NEEDS_UPDATE) {
+                        error!("Workshop item with ID {} was downloaded, but Steam doesn't report it as installed.", published_file_id.0);
+                        failed.push(published_file_id.0);
+                    }
+                }
+                Err(_) => {
+                    error!("Timeout while downloading workshop item with ID {}.", published_file_id.0);
+                    failed.push(published_file_id.0);
+                }
+            }
+        } else {
+            error!("Steam refused to download workshop item with ID {}.", published_file_id.0);
+            failed.push(published_file_id.0);
+        }
+    }
+
+    finish(tx, callback_thread)?;
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!("Failed to re-download the following workshop items: {}.", failed.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(", ")))
+    }
+}
+
+//---------------------------------------------------------------------------//
+//                      UGC (Workshop) private functions
+//---------------------------------------------------------------------------//
+
+/// This function initializes the client and callback thread. DO NOT CALL IT IF THERE'S ALREADY A CLIENT ALIVE.
+fn init(steam_id: u32, channel: Option<&str>) -> Result<(Client, Sender<SteamWorksThreadMessage>, JoinHandle<()>)> {
     let (client, single) = match Client::init_app(steam_id) {
         Ok(client) => client,
         Err(error) => {
@@ -610,7 +688,6 @@ fn subscribe_item(ugc: &UGC<ClientManager>, sender: Sender<SteamWorksThreadMessa
 /// Function to unsubscribe from an specific item in the workshop.
 ///
 /// This function does NOT finish the background thread.
-#[allow(dead_code)]
 fn unsubscribe_item(ugc: &UGC<ClientManager>, sender: Sender<SteamWorksThreadMessage>, published_file_id: PublishedFileId) {
     ugc.unsubscribe_item(
         published_file_id,
@@ -662,6 +739,34 @@ fn get_published_file_details(ugc: &UGC<ClientManager>, sender: Sender<SteamWork
     }
 }
 
+/// Function to retrieve the items of a game's workshop with all the provided tags, most popular first.
+fn query_items_by_tags(ugc: &UGC<ClientManager>, sender: Sender<SteamWorksThreadMessage>, app_id: u32, tags: &[String], page: u32) {
+    match ugc.query_all(UGCQueryType::RankedByTrend, UGCType::Items, AppIDs::ConsumerAppId(AppId(app_id)), page) {
+        Ok(mut handle) => {
+            for tag in tags {
+                handle = handle.require_tag(tag);
+            }
+
+            handle.fetch(move |results| {
+                match results {
+                    Ok(results) => {
+                        info!("Search results retrieved from workshop.");
+
+                        let processed_results = results.iter().flatten().collect::<Vec<_>>();
+                        let _ = sender.send(SteamWorksThreadMessage::QueryResults(processed_results));
+                    }
+
+                    Err(error) => {
+                        error!("search-items call failed: {}", error);
+                        let _ = sender.send(SteamWorksThreadMessage::Error(From::from(error)));
+                    },
+                }
+            });
+        }
+        Err(error) => { let _ = sender.send(SteamWorksThreadMessage::Error(From::from(error))); },
+    }
+}
+
 /// Function to create an item in a specific workshop.
 ///
 /// This only creates the item. You need to upload a pack after this.
@@ -688,6 +793,41 @@ fn create_item(ugc: &UGC<ClientManager>, sender: Sender<SteamWorksThreadMessage>
     );
 }
 
+/// This function saves the provided sync manifest to the provided path.
+fn save_sync_manifest(manifest: &SyncManifest, manifest_path: &Path) -> Result<()> {
+    let mut file = BufWriter::new(File::create(manifest_path)?);
+    file.write_all(to_string_pretty(manifest)?.as_bytes())?;
+    file.flush()?;
+    Ok(())
+}
+
+/// This function creates a new item on the Workshop, and subscribes us to it.
+///
+/// We need to subscribe ourself to the item. Otherwise we'll not get it's data in a data request.
+///
+/// This function does NOT finish the background thread.
+fn create_and_subscribe_item(ugc: &UGC<ClientManager>, steam_id: u32) -> Result<PublishedFileId> {
+    let (tx_query, rx_query): (Sender<SteamWorksThreadMessage>, Receiver<SteamWorksThreadMessage>) = unbounded();
+    create_item(ugc, tx_query, steam_id);
+
+    let response = rx_query.recv()?;
+    let published_file_id = match response {
+        SteamWorksThreadMessage::PublishedFileId(id) => id,
+        SteamWorksThreadMessage::Error(error) => return Err(error),
+        _ => panic!("{response:?}")
+    };
+
+    let (tx_query, rx_query): (Sender<SteamWorksThreadMessage>, Receiver<SteamWorksThreadMessage>) = unbounded();
+    subscribe_item(ugc, tx_query, published_file_id);
+
+    let response = rx_query.recv()?;
+    match response {
+        SteamWorksThreadMessage::Ok => Ok(published_file_id),
+        SteamWorksThreadMessage::Error(error) => Err(error),
+        _ => panic!("{response:?}")
+    }
+}
+
 /// Function to upload an item to the workshop. This requires the item to already exists on the workshop.
 fn upload_item_content(
     ugc: &UGC<ClientManager>,
@@ -798,6 +938,203 @@ fn upload_item_content(
     )
 }
 
+/// This function updates an existing item on the Workshop with the provided pack.
+///
+/// This function does NOT finish the background thread, so multiple items can be updated with the same client.
+fn update_item(
+    ugc: &UGC<ClientManager>,
+    base64: bool,
+    published_file_id: PublishedFileId,
+    steam_id: u32,
+    pack_path: &Path,
+    title: &str,
+    description: &Option<String>,
+    tags: &[String],
+    changelog: &Option<String>,
+    visibility: &Option<u32>,
+) -> Result<()> {
+
+    // Sanitize the pack_path.
+    let pack_path = path_to_absolute_path(pack_path, true);
+
+    // Prepare the preview path. We replicate the same behavior as the vanilla launcher.
+    let mut preview_path = pack_path.to_path_buf();
+    preview_path.set_extension("png");
+
+    let (tx_query, rx_query): (Sender<SteamWorksThreadMessage>, Receiver<SteamWorksThreadMessage>) = unbounded();
+
+    // If we're in base64 mode, decode the problematic fields.
+    let title = if base64 {
+        String::from_utf8(BASE64_STANDARD.decode(title)?)?
+    } else {
+        title.to_owned()
+    };
+
+    let mut description = description.clone();
+    let mut changelog = changelog.clone();
+    if base64 {
+        if let Some(ref mut description) = description {
+            *description = String::from_utf8(BASE64_STANDARD.decode(description.clone())?)?;
+        }
+
+        if let Some(ref mut changelog) = changelog {
+            *changelog = String::from_utf8(BASE64_STANDARD.decode(changelog.clone())?)?;
+        }
+    }
+
+    // TODO: Make this only trigger when doing it on a Total War game.
+
+    // NOTE: CA seems to be doing a "copy pack and preview to folder, then upload" thing, to get both uploaded.
+    // We want to keep this behavior because otherwise downloaded mods have no preview.
+    let upload_path = if cfg!(debug_assertions) {
+        PathBuf::from("./mod_uploads/")
+    } else {
+        let mut upload_path = std::env::current_exe().unwrap();
+        upload_path.pop();
+        upload_path.push("mod_uploads");
+        upload_path
+    };
+
+    info!("Copying pack and preview from {} to {}", pack_path.to_string_lossy(), upload_path.to_string_lossy());
+
+    // Clean the mod_uploads folder.
+    if upload_path.is_dir() {
+        std::fs::remove_dir_all(&upload_path)?;
+    }
+
+    DirBuilder::new().recursive(true).create(&upload_path)?;
+
+    // Copy the pack and preview to the upload folder.
+    let mut pack_path_dest = upload_path.to_path_buf();
+    pack_path_dest.push(pack_path.file_name().unwrap());
+
+    let mut preview_path_dest = upload_path.to_path_buf();
+    preview_path_dest.push(preview_path.file_name().unwrap());
+
+    std::fs::copy(&pack_path, pack_path_dest)?;
+    std::fs::copy(&preview_path, &preview_path_dest)?;
+
+    info!("Copying done, preparing upload.");
+
+    let update_handle = upload_item_content(ugc, tx_query, steam_id, published_file_id, &upload_path, &preview_path, &title, &description, tags, &changelog, visibility);
+
+    // Initialize the progress bar. The upload is a 5-step process, and the bar should come at 3 and 4.
+    let mut bar: Option<ProgressBar> = None;
+    let mut prev_status = UpdateStatus::Invalid;
+    let mut prev_total = 0;
+
+    // We loop keeping painting the progress to the terminal until we're done.
+    loop {
+
+        match rx_query.try_recv() {
+            Ok(response) => match response {
+                SteamWorksThreadMessage::Ok => {
+
+                    // If stuff happened too quickly and the commit didn't trigger, do it here.
+                    if let Some(ref bar) = bar {
+                        bar.finish();
+                    }
+
+                    info!("Upload done, deleting temp files.");
+
+                    // Delete the folder once it's done so we don't occupy space we shouldn't.
+                    if upload_path.is_dir() {
+                        std::fs::remove_dir_all(&upload_path)?;
+                    }
+
+                    info!("Temp files deleted.");
+
+                    return Ok(())
+                },
+                SteamWorksThreadMessage::Error(error) => return Err(error),
+                _ => panic!("{response:?}")
+            }
+
+            // If it's empty, paint to the console the progress, wait 20 ms and try again.
+            Err(TryRecvError::Empty) => {
+                let (status, loaded, total) = update_handle.progress();
+                match status {
+                    UpdateStatus::PreparingConfig => {
+                        if prev_status != UpdateStatus::PreparingConfig {
+                            prev_status = UpdateStatus::PreparingConfig;
+                            info!("Preparing config...");
+                        }
+                    },
+                    UpdateStatus::PreparingContent => {
+                        if prev_status != UpdateStatus::PreparingContent {
+                            prev_status = UpdateStatus::PreparingContent;
+                            info!("Preparing content...");
+                        }
+                    },
+                    UpdateStatus::UploadingContent => {
+                        if prev_status != UpdateStatus::UploadingContent {
+                            prev_status = UpdateStatus::UploadingContent;
+                        }
+
+                        // Total takes some time to update after changing status.
+                        if prev_total == 0 && total > 0 {
+                            info!("Uploading content of size: {}.", total);
+                            prev_total = total;
+                            bar = Some(progress_bar(total));
+                        }
+
+                        if let Some(ref bar) = bar {
+                            bar.set_position(loaded);
+                        }
+                    },
+                    UpdateStatus::UploadingPreviewFile => {
+                        if prev_status != UpdateStatus::UploadingPreviewFile {
+                            prev_status = UpdateStatus::UploadingPreviewFile;
+
+                            // Fill the previous bar before making the new one.
+                            if let Some(ref bar) = bar {
+                                bar.finish();
+                            }
+
+                            prev_total = 0;
+                        }
+
+                        // Total takes some time to update after changing status.
+                        if prev_total == 0 && total > 0 {
+                            info!("Uploading preview file of size: {}.", total);
+                            prev_total = total;
+                            bar = Some(progress_bar(total));
+                        }
+
+                        if let Some(ref bar) = bar {
+                            bar.set_position(loaded);
+                        }
+                    },
+                    UpdateStatus::CommittingChanges => {
+                        if prev_status != UpdateStatus::CommittingChanges {
+                            prev_status = UpdateStatus::CommittingChanges;
+
+                            // Fill the previous bar before killing it.
+                            if let Some(ref bar) = bar {
+                                bar.finish();
+                            }
+
+                            bar = None;
+
+                            info!("Committing changes...");
+                        }
+                    },
+
+                    // Invalid is usually completed. So just return Ok.
+                    UpdateStatus::Invalid => {
+                        info!("Invalid UpdateStatus. This is an error, or the upload finished.");
+                    },
+                }
+
+                std::thread::sleep(std::time::Duration::from_millis(20));
+            },
+
+            // This is a bug.
+            Err(TryRecvError::Disconnected) => panic!("Thread disconected."),
+        }
+    }
+}
+
 /// This just initializes a nice progress bar for the uploads.
 fn progress_bar(total: u64) -> ProgressBar {
     let bar = ProgressBar::new(total);
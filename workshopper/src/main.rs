@@ -46,8 +46,12 @@ fn main() {
 
     // Execute the commands.
     let (result, wait): (Result<()>, bool) = match cli.command {
-        Commands::DownloadSubscribedItems { steam_id, published_file_ids } => (crate::commands::ugc::download_subscribed_mods(steam_id, published_file_ids), true),
+        Commands::DownloadSubscribedItems { steam_id, published_file_ids, subscribe } => (crate::commands::ugc::download_subscribed_mods(steam_id, published_file_ids, subscribe), true),
+        Commands::ExportMapBin { pack_path, map_name, output_path } => (crate::commands::maps::export_map_bin(&pack_path, &map_name, &output_path), false),
+        Commands::ForceRedownload { steam_id, published_file_ids } => (crate::commands::ugc::force_redownload(steam_id, &published_file_ids), true),
         Commands::GetPublishedFileDetails { steam_id, published_file_ids, ipc_channel } => (crate::commands::ugc::published_file_details(steam_id, &published_file_ids, &ipc_channel), false),
+        Commands::SearchItems { steam_id, tags, page, ipc_channel } => (crate::commands::ugc::search_items(steam_id, &tags, page, &ipc_channel), false),
+        Commands::Sync { steam_id, manifest_path } => (crate::commands::ugc::sync(steam_id, &manifest_path), true),
         Commands::Launch { base64, steam_id, command } => (crate::commands::launch_game(base64, steam_id, &command), false),
         Commands::Upload { base64, steam_id, file_path, title, description, tags, changelog, visibility } => (crate::commands::ugc::upload(base64, steam_id, &file_path, &title, &description, &tags, &changelog, &visibility), true),
         Commands::Update { base64, steam_id, published_file_id, file_path, title, description, tags, changelog, visibility } => (crate::commands::ugc::update(None, None, base64, PublishedFileId(published_file_id), steam_id, &file_path, &title, &description, &tags, &changelog, &visibility), true),